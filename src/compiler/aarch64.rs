@@ -0,0 +1,3890 @@
+//! AArch64 backend: lowers AIR to GNU as syntax for Linux.
+//!
+//! The code mirrors the x86-64 backend in `codegen` instruction for instruction
+//! where it can. Its registers stand in for the x86-64 ones as follows:
+//!
+//! | x86-64          | AArch64  | role                                   |
+//! |-----------------|----------|----------------------------------------|
+//! | rax             | x9       | scratch and result                     |
+//! | rbx             | x20      | scratch that survives libc calls       |
+//! | rcx, rdx        | x11, x12 | scratch                                |
+//! | r12             | x19      | current closure env_end pointer        |
+//! | r13, r14, r15   | x21-x23  | env clone scratch                      |
+//! | rdi..r9         | x0..x7   | arguments                              |
+//! | rbp             | x29      | frame pointer                          |
+//!
+//! x16 and x17 hold addresses too large for an immediate offset. The stack
+//! pointer stays 16-byte aligned, so every push takes a 16-byte slot.
+
+use crate::compiler::air;
+use crate::compiler::air::{
+    AirAdd, AirAddF64, AirArg, AirArgAt, AirArgCount, AirArrayGet, AirArrayLen, AirArraySet,
+    AirByteAt, AirCallPtr, AirCallPtrTarget, AirChanRecv, AirChanSend, AirChecked, AirClose,
+    AirConcat, AirDivF64, AirDivInt, AirF64ToInt, AirField, AirFunction, AirGetenv, AirIntToF64,
+    AirJump, AirJumpArgs, AirJumpClosure, AirJumpCmpF64, AirJumpCmpStr, AirJumpEq, AirJumpGt,
+    AirJumpLt, AirJumpTable, AirLabel, AirMapInsert, AirMapLen, AirMapLookup, AirMapNext,
+    AirMapRemove, AirMul, AirMulF64, AirNeg, AirNewArray, AirNewChan, AirNewClosure, AirNewMap,
+    AirOp, AirOpen, AirPin, AirRead, AirReturn, AirSlice, AirSpawn, AirStmt, AirStrLen, AirSub,
+    AirSubF64, AirSysExit, AirThread, AirValue, AirYield, Lit, SigKind, ENTRY_FUNCTION_NAME,
+};
+use crate::compiler::builtins;
+use crate::compiler::builtins::AirRuntimeHelper;
+use crate::compiler::codegen::{
+    array_elem_class, chan_elem_class, map_block_size, map_index_offset, Artifacts,
+    ARRAY_CLASS_ARRAY, ARRAY_CLASS_OFFSET, ARRAY_CLASS_STR, ARRAY_HEADER_SIZE, ARRAY_LEN_OFFSET,
+    CHAN_CLASS_ENV, CHAN_CLASS_OFFSET, CHAN_RECEIVERS_OFFSET, CHAN_REFS_OFFSET, CHAN_SIZE,
+    CHAN_VALUES_OFFSET, DEADLOCK_MESSAGE, EINVAL, ENOMEM, ENV_METADATA_DEEP_COPY_OFFSET,
+    ENV_METADATA_ENV_SIZE_OFFSET, ENV_METADATA_HEAP_SIZE_OFFSET, ENV_METADATA_NUM_REMAINING_OFFSET,
+    ENV_METADATA_RELEASE_OFFSET, ENV_METADATA_SIZE, ENV_METADATA_UNWRAPPER_OFFSET, ERRNO_LOCATION,
+    FNV_OFFSET_BASIS, FNV_PRIME, FUTEX_WAIT_PRIVATE, FUTEX_WAKE_PRIVATE, HEAP_ARENA_LABEL,
+    HEAP_ARENA_SIZE, HEAP_FREE_LISTS_LABEL, HEAP_LOCK_LABEL, HEAP_MAX_SMALL_SIZE, LOCK_SPINS,
+    MAP_ANONYMOUS, MAP_CAPACITY_OFFSET, MAP_COUNT_OFFSET, MAP_ENTRY_SIZE, MAP_HEADER_SIZE,
+    MAP_INITIAL_CAPACITY, MAP_INT_HASH_MULTIPLIER, MAP_KEY_CLASS_OFFSET, MAP_PRIVATE,
+    MAP_VALUE_CLASS_OFFSET, PROCESS_ARGS_LABEL, PROT_READ, PROT_WRITE, QUEUE_NODE_SIZE,
+    SCHED_IDLE_OFFSET, SCHED_LOCK_LABEL, SCHED_STATE_LABEL, SCHED_WAITING_OFFSET,
+    SCHED_WAKEUPS_OFFSET, SCHED_WORKERS_OFFSET, STR_ALLOC_SIZE_OFFSET, STR_HEADER_SIZE,
+    STR_LEN_OFFSET, VARIADIC_FLOAT_REGS,
+};
+use crate::compiler::error::{Code, Error};
+use crate::compiler::span::Span;
+use crate::compiler::target::{
+    copy_helper, emit_runtime_helper_once, emit_runtime_helpers, ReleaseCopy, Runtime,
+};
+use std::collections::HashMap;
+use std::io::Write;
+
+const WORD_SIZE: usize = 8;
+const FRAME_RECORD_SIZE: usize = 16;
+pub const CLOSURE_ENV_REG: &str = "x19";
+pub const ARG_REGS: [&str; 8] = ["x0", "x1", "x2", "x3", "x4", "x5", "x6", "x7"];
+pub const SYSCALL_WRITE: i32 = 64;
+pub const SYSCALL_FUTEX: i32 = 98;
+pub const SYSCALL_SCHED_YIELD: i32 = 124;
+pub const SYSCALL_MUNMAP: i32 = 215;
+pub const SYSCALL_MMAP: i32 = 222;
+
+pub fn write_preamble<W: Write>(out: &mut W) -> Result<(), Error> {
+    writeln!(out, ".text")?;
+    writeln!(out, ".p2align 2")?;
+    Ok(())
+}
+
+pub fn function<W: Write>(
+    air: AirFunction,
+    artifacts: &mut Artifacts,
+    out: &mut W,
+) -> Result<(), Error> {
+    emit_runtime_helpers::<Backend, W>(&air, artifacts, out)?;
+    if !artifacts.builtins_used.insert(air.sig.name.clone()) {
+        return Ok(());
+    }
+    if emit_builtin_function(&air, out)? {
+        return Ok(());
+    }
+    let save_process_args = air.sig.name == ENTRY_FUNCTION_NAME && artifacts.uses_process_args;
+    if save_process_args {
+        emit_runtime_helper_once::<Backend, W>(AirRuntimeHelper::ProcessArgs, artifacts, out)?;
+    }
+    let frame = FrameLayout::build(&air);
+    let mut emitter = FunctionEmitter::new(air, out, frame);
+    emitter.save_process_args = save_process_args;
+    emitter.emit_function()?;
+    Ok(())
+}
+
+/// Writes the runtime helpers in AArch64 assembly.
+pub struct Backend;
+
+impl Runtime for Backend {
+    fn emit_helper<W: Write>(
+        helper: AirRuntimeHelper,
+        threaded: bool,
+        out: &mut W,
+    ) -> Result<(), Error> {
+        match helper {
+            AirRuntimeHelper::AllocHeapPtr => emit_alloc_heap_ptr(out, threaded),
+            AirRuntimeHelper::FreeHeapPtr => emit_free_heap_ptr(out, threaded),
+            AirRuntimeHelper::ReleaseHeapPtr => emit_release_heap_ptr(out),
+            AirRuntimeHelper::DeepCopyHeapPtr => emit_deepcopy_heap_ptr(out),
+            AirRuntimeHelper::MemcpyHelper => emit_memcpy_helper(out),
+            AirRuntimeHelper::ReleaseStrPtr => emit_release_str_ptr(out),
+            AirRuntimeHelper::CopyStrPtr => emit_copy_str_ptr(out),
+            AirRuntimeHelper::AllocStrPtr => emit_alloc_str_ptr(out),
+            AirRuntimeHelper::ConcatStrPtr => emit_concat_str_ptr(out),
+            AirRuntimeHelper::SliceStrPtr => emit_slice_str_ptr(out),
+            AirRuntimeHelper::CmpStrPtr => emit_cmp_str_ptr(out),
+            AirRuntimeHelper::CStrToStrPtr => emit_cstr_to_str_ptr(out),
+            AirRuntimeHelper::ProcessArgs => emit_process_args(out),
+            AirRuntimeHelper::ReleaseArrayPtr => emit_release_array_ptr(out),
+            AirRuntimeHelper::ReleaseArrayElemPtr => emit_release_array_elem_ptr(out),
+            AirRuntimeHelper::CopyArrayPtr => emit_copy_array_ptr(out),
+            AirRuntimeHelper::CopyArrayElemPtr => emit_copy_array_elem_ptr(out),
+            AirRuntimeHelper::MapNewPtr => emit_map_new_ptr(out),
+            AirRuntimeHelper::MapHashPtr => emit_map_hash_ptr(out),
+            AirRuntimeHelper::MapFindPtr => emit_map_find_ptr(out),
+            AirRuntimeHelper::MapInsertPtr => emit_map_insert_ptr(out),
+            AirRuntimeHelper::MapGrowPtr => emit_map_grow_ptr(out),
+            AirRuntimeHelper::MapReindexPtr => emit_map_reindex_ptr(out),
+            AirRuntimeHelper::MapRemovePtr => emit_map_remove_ptr(out),
+            AirRuntimeHelper::MapReleasePtr => emit_map_release_ptr(out),
+            AirRuntimeHelper::MapCopyPtr => emit_map_copy_ptr(out),
+            AirRuntimeHelper::QueuePushPtr => emit_queue_push_ptr(out),
+            AirRuntimeHelper::QueuePopPtr => emit_queue_pop_ptr(out),
+            AirRuntimeHelper::SchedNextPtr => emit_sched_next_ptr(out, threaded),
+            AirRuntimeHelper::SchedReadyPtr => emit_sched_ready_ptr(out, threaded),
+            AirRuntimeHelper::ThreadNewPtr => emit_thread_new_ptr(out),
+            AirRuntimeHelper::ChanNewPtr => emit_chan_new_ptr(out),
+            AirRuntimeHelper::ChanSendPtr => emit_chan_send_ptr(out, threaded),
+            AirRuntimeHelper::ChanRecvPtr => emit_chan_recv_ptr(out, threaded),
+            AirRuntimeHelper::ChanReleasePtr => emit_chan_release_ptr(out, threaded),
+            AirRuntimeHelper::ChanCopyPtr => emit_chan_copy_ptr(out, threaded),
+        }
+    }
+
+    // Unlike the x86-64 one, this string copy moves its bytes with
+    // memcpy_helper.
+    fn helper_calls(helper: AirRuntimeHelper) -> &'static [AirRuntimeHelper] {
+        match helper {
+            AirRuntimeHelper::CopyStrPtr => &[AirRuntimeHelper::MemcpyHelper],
+            _ => &[],
+        }
+    }
+}
+
+fn emit_builtin_function<W: Write>(air: &AirFunction, out: &mut W) -> Result<bool, Error> {
+    if !air.items.is_empty() {
+        return Ok(false);
+    }
+    match air.sig.name.as_str() {
+        "release_heap_ptr" => emit_release_heap_ptr(out)?,
+        "deepcopy_heap_ptr" => emit_deepcopy_heap_ptr(out)?,
+        "memcpy_helper" => emit_memcpy_helper(out)?,
+        _ => return Ok(false),
+    }
+    Ok(true)
+}
+
+pub fn emit_data<W: Write>(string_literals: &[(String, String)], out: &mut W) -> Result<(), Error> {
+    if string_literals.is_empty() {
+        return Ok(());
+    }
+    writeln!(out, ".section .rodata")?;
+    for (label, literal) in string_literals {
+        writeln!(out, ".p2align 3")?;
+        writeln!(
+            out,
+            "    .quad 0, {} // static strings have no allocation to free",
+            literal.len()
+        )?;
+        writeln!(out, "{}:", label)?;
+        writeln!(out, "    .asciz \"{}\"", escape_asciz(literal))?;
+    }
+    Ok(())
+}
+
+fn escape_asciz(literal: &str) -> String {
+    let mut escaped = String::new();
+    for &byte in literal.as_bytes() {
+        match byte {
+            b'"' => escaped.push_str("\\\""),
+            b'\\' => escaped.push_str("\\\\"),
+            b' '..=b'~' => escaped.push(byte as char),
+            other => escaped.push_str(&format!("\\{:03o}", other)),
+        }
+    }
+    escaped
+}
+
+fn emit_release_heap_ptr<W: Write>(out: &mut W) -> Result<(), Error> {
+    writeln!(out, ".global release_heap_ptr")?;
+    writeln!(out, "release_heap_ptr:")?;
+    writeln!(out, "    stp x29, x30, [sp, #-16]! // save caller frame")?;
+    writeln!(out, "    mov x29, sp // establish frame")?;
+    writeln!(
+        out,
+        "    ldr x11, [x0, #{}] // load env size metadata",
+        ENV_METADATA_ENV_SIZE_OFFSET
+    )?;
+    writeln!(
+        out,
+        "    ldr x12, [x0, #{}] // load heap size metadata",
+        ENV_METADATA_HEAP_SIZE_OFFSET
+    )?;
+    writeln!(out, "    sub x0, x0, x11 // compute env base pointer")?;
+    writeln!(out, "    mov x1, x12 // heap size to free")?;
+    writeln!(out, "    bl free_heap_ptr // return env to the allocator")?;
+    writeln!(out, "    ldp x29, x30, [sp], #16")?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+// Returns in x0 a block of at least x0 bytes, or 0 when the kernel will not
+// map one. Only x0 to x5 and x8 to x13 are clobbered. A `threaded` allocator lets one thread in at a time.
+fn emit_alloc_heap_ptr<W: Write>(out: &mut W, threaded: bool) -> Result<(), Error> {
+    writeln!(out, ".global alloc_heap_ptr")?;
+    writeln!(out, "alloc_heap_ptr:")?;
+    if threaded {
+        emit_locked_call(out, HEAP_LOCK_LABEL, "alloc_heap_ptr")?;
+    }
+    writeln!(out, "    add x0, x0, #7 // round size up to whole words")?;
+    writeln!(out, "    and x0, x0, #-8")?;
+    writeln!(out, "    cmp x0, #{} // small block?", HEAP_MAX_SMALL_SIZE)?;
+    writeln!(out, "    b.hi alloc_heap_ptr_large")?;
+    emit_label_address(out, "x9", HEAP_FREE_LISTS_LABEL)?;
+    writeln!(
+        out,
+        "    ldr x10, [x9, x0] // head of this size's free list"
+    )?;
+    writeln!(out, "    cbz x10, alloc_heap_ptr_bump")?;
+    writeln!(out, "    ldr x11, [x10] // next free block")?;
+    writeln!(out, "    str x11, [x9, x0] // pop the head")?;
+    writeln!(out, "    mov x0, x10")?;
+    writeln!(out, "    ret")?;
+    writeln!(out, "alloc_heap_ptr_bump:")?;
+    emit_label_address(out, "x9", HEAP_ARENA_LABEL)?;
+    writeln!(out, "    ldp x10, x11, [x9] // arena next and end")?;
+    writeln!(out, "    add x12, x10, x0 // end of the new block")?;
+    writeln!(out, "    cmp x12, x11")?;
+    writeln!(out, "    b.hi alloc_heap_ptr_refill")?;
+    writeln!(out, "    str x12, [x9] // bump past the block")?;
+    writeln!(out, "    mov x0, x10")?;
+    writeln!(out, "    ret")?;
+    writeln!(out, "alloc_heap_ptr_refill:")?;
+    writeln!(out, "    mov x13, x0 // keep block size across the syscall")?;
+    writeln!(out, "    mov x1, #{} // arena size", HEAP_ARENA_SIZE)?;
+    emit_anonymous_mmap(out, "map a new arena")?;
+    writeln!(out, "    cmn x0, #4095 // mmap failed?")?;
+    writeln!(out, "    b.hs alloc_heap_ptr_failed")?;
+    writeln!(
+        out,
+        "    add x12, x0, x13 // block is the start of the arena"
+    )?;
+    writeln!(out, "    mov x11, #{}", HEAP_ARENA_SIZE)?;
+    writeln!(out, "    add x11, x0, x11 // end of the arena")?;
+    writeln!(out, "    stp x12, x11, [x9]")?;
+    writeln!(out, "    ret")?;
+    writeln!(out, "alloc_heap_ptr_large:")?;
+    writeln!(out, "    mov x1, x0 // length = block size")?;
+    emit_anonymous_mmap(out, "map a large block on its own")?;
+    writeln!(out, "    cmn x0, #4095 // mmap failed?")?;
+    writeln!(out, "    b.hs alloc_heap_ptr_failed")?;
+    writeln!(out, "    ret")?;
+    writeln!(out, "alloc_heap_ptr_failed:")?;
+    writeln!(out, "    mov x0, #0 // no memory left")?;
+    writeln!(out, "    ret")?;
+    writeln!(out, ".data")?;
+    writeln!(out, ".p2align 3")?;
+    writeln!(out, "{}:", HEAP_FREE_LISTS_LABEL)?;
+    writeln!(out, "    .zero {}", HEAP_MAX_SMALL_SIZE + WORD_SIZE)?;
+    writeln!(out, "{}:", HEAP_ARENA_LABEL)?;
+    writeln!(out, "    .quad 0, 0")?;
+    if threaded {
+        writeln!(out, "{}:", HEAP_LOCK_LABEL)?;
+        writeln!(out, "    .quad 0")?;
+    }
+    writeln!(out, ".text")?;
+    Ok(())
+}
+
+// Emits the entry of the helper `name`, which takes `lock`, calls the body at
+// `name_locked` that follows and lets go of the lock again. x8 to x11 are
+// clobbered before the body runs.
+fn emit_locked_call<W: Write>(out: &mut W, lock: &str, name: &str) -> Result<(), Error> {
+    emit_helper_prologue(out, &[])?;
+    emit_lock(out, lock, &format!("{}_lock", name))?;
+    writeln!(out, "    bl {}_locked", name)?;
+    emit_unlock(out, lock)?;
+    emit_helper_epilogue(out, &[])?;
+    writeln!(out, "    ret")?;
+    writeln!(out, "{}_locked:", name)?;
+    Ok(())
+}
+
+// Spins at the label `spin` until this thread holds the lock word at `lock`,
+// yielding the CPU now and then so a holder that lost it can finish. Only x8
+// to x11 are clobbered.
+fn emit_lock<W: Write>(out: &mut W, lock: &str, spin: &str) -> Result<(), Error> {
+    emit_label_address(out, "x9", lock)?;
+    writeln!(out, "{}:", spin)?;
+    writeln!(out, "    ldaxr x10, [x9]")?;
+    writeln!(out, "    cbnz x10, {}_busy", spin)?;
+    writeln!(out, "    mov x10, #1")?;
+    writeln!(out, "    stxr w11, x10, [x9] // try to take the lock")?;
+    writeln!(out, "    cbnz w11, {}", spin)?;
+    writeln!(out, "    b {}_held", spin)?;
+    writeln!(out, "{}_busy:", spin)?;
+    writeln!(
+        out,
+        "    mov x11, #{} // checks before yielding",
+        LOCK_SPINS
+    )?;
+    writeln!(out, "{}_wait:", spin)?;
+    writeln!(out, "    yield // another thread holds it")?;
+    writeln!(out, "    ldr x10, [x9]")?;
+    writeln!(out, "    cbz x10, {}", spin)?;
+    writeln!(out, "    subs x11, x11, #1")?;
+    writeln!(out, "    b.ne {}_wait", spin)?;
+    writeln!(out, "    mov x10, x0 // the syscall returns in x0")?;
+    writeln!(
+        out,
+        "    mov x8, #{} // sched_yield syscall",
+        SYSCALL_SCHED_YIELD
+    )?;
+    writeln!(out, "    svc #0 // let the holder run")?;
+    writeln!(out, "    mov x0, x10")?;
+    writeln!(out, "    b {}_busy", spin)?;
+    writeln!(out, "{}_held:", spin)?;
+    Ok(())
+}
+
+fn emit_unlock<W: Write>(out: &mut W, lock: &str) -> Result<(), Error> {
+    emit_label_address(out, "x9", lock)?;
+    writeln!(out, "    stlr xzr, [x9] // let other threads in")?;
+    Ok(())
+}
+
+// Takes back the block at x0 that was allocated with x1 bytes.
+fn emit_free_heap_ptr<W: Write>(out: &mut W, threaded: bool) -> Result<(), Error> {
+    writeln!(out, ".global free_heap_ptr")?;
+    writeln!(out, "free_heap_ptr:")?;
+    if threaded {
+        emit_locked_call(out, HEAP_LOCK_LABEL, "free_heap_ptr")?;
+    }
+    writeln!(out, "    add x1, x1, #7 // round size up to whole words")?;
+    writeln!(out, "    and x1, x1, #-8")?;
+    writeln!(out, "    cmp x1, #{} // small block?", HEAP_MAX_SMALL_SIZE)?;
+    writeln!(out, "    b.hi free_heap_ptr_large")?;
+    emit_label_address(out, "x9", HEAP_FREE_LISTS_LABEL)?;
+    writeln!(out, "    ldr x10, [x9, x1] // current head")?;
+    writeln!(out, "    str x10, [x0] // link it behind the block")?;
+    writeln!(out, "    str x0, [x9, x1] // block is the new head")?;
+    writeln!(out, "    ret")?;
+    writeln!(out, "free_heap_ptr_large:")?;
+    writeln!(out, "    mov x8, #{} // munmap syscall", SYSCALL_MUNMAP)?;
+    writeln!(out, "    svc #0")?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+fn emit_label_address<W: Write>(out: &mut W, reg: &str, label: &str) -> Result<(), Error> {
+    writeln!(out, "    adrp {}, {}", reg, label)?;
+    writeln!(out, "    add {}, {}, :lo12:{}", reg, reg, label)?;
+    Ok(())
+}
+
+// Maps x1 bytes of anonymous read/write memory into x0.
+fn emit_anonymous_mmap<W: Write>(out: &mut W, comment: &str) -> Result<(), Error> {
+    writeln!(out, "    mov x0, #0 // addr hint so kernel picks mmap base")?;
+    writeln!(
+        out,
+        "    mov x2, #{} // prot = read/write",
+        PROT_READ | PROT_WRITE
+    )?;
+    writeln!(
+        out,
+        "    mov x3, #{} // flags = private & anonymous",
+        MAP_PRIVATE | MAP_ANONYMOUS
+    )?;
+    writeln!(out, "    mov x4, #-1 // fd = -1")?;
+    writeln!(out, "    mov x5, #0 // offset = 0")?;
+    writeln!(out, "    mov x8, #{} // mmap syscall", SYSCALL_MMAP)?;
+    writeln!(out, "    svc #0 // {}", comment)?;
+    Ok(())
+}
+
+// Frees the string at x0. Its header holds the allocation size, or 0 for
+// literals, which are left alone.
+fn emit_release_str_ptr<W: Write>(out: &mut W) -> Result<(), Error> {
+    writeln!(out, ".global release_str_ptr")?;
+    writeln!(out, "release_str_ptr:")?;
+    writeln!(out, "    cbz x0, release_str_ptr_done // nothing to free")?;
+    writeln!(
+        out,
+        "    ldr x1, [x0, #-{}] // allocation size header",
+        STR_ALLOC_SIZE_OFFSET
+    )?;
+    writeln!(
+        out,
+        "    cbz x1, release_str_ptr_done // literals have no allocation"
+    )?;
+    writeln!(
+        out,
+        "    sub x0, x0, #{} // block starts at the header",
+        STR_HEADER_SIZE
+    )?;
+    writeln!(out, "    b free_heap_ptr // return string to the allocator")?;
+    writeln!(out, "release_str_ptr_done:")?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+// Returns in x0 a string owned separately from the one at x0. Literals are
+// shared rather than copied.
+fn emit_copy_str_ptr<W: Write>(out: &mut W) -> Result<(), Error> {
+    writeln!(out, ".global copy_str_ptr")?;
+    writeln!(out, "copy_str_ptr:")?;
+    writeln!(out, "    cbz x0, copy_str_ptr_done")?;
+    writeln!(
+        out,
+        "    ldr x1, [x0, #-{}] // allocation size header",
+        STR_ALLOC_SIZE_OFFSET
+    )?;
+    writeln!(
+        out,
+        "    cbz x1, copy_str_ptr_done // literals are never freed"
+    )?;
+    writeln!(out, "    stp x29, x30, [sp, #-16]! // save caller frame")?;
+    writeln!(out, "    mov x29, sp // establish frame")?;
+    writeln!(
+        out,
+        "    stp x20, x21, [sp, #-16]! // preserve callee-saved registers"
+    )?;
+    writeln!(
+        out,
+        "    sub x20, x0, #{} // source block, header included",
+        STR_HEADER_SIZE
+    )?;
+    writeln!(out, "    mov x21, x1 // allocation size")?;
+    writeln!(out, "    mov x0, x1")?;
+    writeln!(out, "    bl alloc_heap_ptr // allocate the copy")?;
+    writeln!(out, "    mov x1, x20 // memcpy src")?;
+    writeln!(out, "    mov x2, x21 // memcpy length")?;
+    writeln!(out, "    mov x20, x0 // copy block")?;
+    writeln!(out, "    bl memcpy_helper // duplicate header and bytes")?;
+    writeln!(
+        out,
+        "    add x0, x20, #{} // copied string starts after its header",
+        STR_HEADER_SIZE
+    )?;
+    writeln!(out, "    ldp x20, x21, [sp], #16")?;
+    writeln!(out, "    ldp x29, x30, [sp], #16")?;
+    writeln!(out, "copy_str_ptr_done:")?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+// Frees the array at x0 along with the strings and arrays it holds.
+fn emit_release_array_ptr<W: Write>(out: &mut W) -> Result<(), Error> {
+    writeln!(out, ".global release_array_ptr")?;
+    writeln!(out, "release_array_ptr:")?;
+    writeln!(out, "    cbz x0, release_array_ptr_done // nothing to free")?;
+    writeln!(out, "    stp x29, x30, [sp, #-16]! // save caller frame")?;
+    writeln!(out, "    mov x29, sp // establish frame")?;
+    writeln!(
+        out,
+        "    stp x20, x21, [sp, #-16]! // preserve callee-saved registers"
+    )?;
+    writeln!(out, "    stp x22, x23, [sp, #-16]!")?;
+    writeln!(out, "    mov x20, x0 // array")?;
+    writeln!(
+        out,
+        "    ldr x21, [x20, #-{}] // elements left to release",
+        ARRAY_LEN_OFFSET
+    )?;
+    writeln!(
+        out,
+        "    ldr x22, [x20, #-{}] // element class",
+        ARRAY_CLASS_OFFSET
+    )?;
+    writeln!(
+        out,
+        "    cbz x22, release_array_ptr_free // plain elements own nothing"
+    )?;
+    writeln!(out, "release_array_ptr_loop:")?;
+    writeln!(out, "    cbz x21, release_array_ptr_free")?;
+    writeln!(out, "    sub x21, x21, #1")?;
+    writeln!(out, "    ldr x0, [x20, x21, lsl #3]")?;
+    writeln!(out, "    mov x1, x22")?;
+    writeln!(out, "    bl release_array_elem_ptr")?;
+    writeln!(out, "    b release_array_ptr_loop")?;
+    writeln!(out, "release_array_ptr_free:")?;
+    writeln!(out, "    ldr x1, [x20, #-{}] // length", ARRAY_LEN_OFFSET)?;
+    writeln!(out, "    lsl x1, x1, #3")?;
+    writeln!(
+        out,
+        "    add x1, x1, #{} // block size, header included",
+        ARRAY_HEADER_SIZE
+    )?;
+    writeln!(
+        out,
+        "    sub x0, x20, #{} // block starts at the header",
+        ARRAY_HEADER_SIZE
+    )?;
+    writeln!(out, "    ldp x22, x23, [sp], #16")?;
+    writeln!(out, "    ldp x20, x21, [sp], #16")?;
+    writeln!(out, "    ldp x29, x30, [sp], #16")?;
+    writeln!(
+        out,
+        "    b free_heap_ptr // return the array to the allocator"
+    )?;
+    writeln!(out, "release_array_ptr_done:")?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+// Releases the element at x0 of an array whose element class is in x1.
+fn emit_release_array_elem_ptr<W: Write>(out: &mut W) -> Result<(), Error> {
+    writeln!(out, ".global release_array_elem_ptr")?;
+    writeln!(out, "release_array_elem_ptr:")?;
+    writeln!(out, "    cmp x1, #{} // string element?", ARRAY_CLASS_STR)?;
+    writeln!(out, "    b.eq release_str_ptr")?;
+    writeln!(out, "    cmp x1, #{} // array element?", ARRAY_CLASS_ARRAY)?;
+    writeln!(out, "    b.eq release_array_ptr")?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+// Returns in x0 a deep copy of the array at x0, so the copy owns its strings
+// and arrays separately.
+fn emit_copy_array_ptr<W: Write>(out: &mut W) -> Result<(), Error> {
+    writeln!(out, ".global copy_array_ptr")?;
+    writeln!(out, "copy_array_ptr:")?;
+    writeln!(out, "    cbz x0, copy_array_ptr_done // nothing to copy")?;
+    writeln!(out, "    stp x29, x30, [sp, #-16]! // save caller frame")?;
+    writeln!(out, "    mov x29, sp // establish frame")?;
+    writeln!(
+        out,
+        "    stp x20, x21, [sp, #-16]! // preserve callee-saved registers"
+    )?;
+    writeln!(out, "    stp x22, x23, [sp, #-16]!")?;
+    writeln!(out, "    mov x20, x0 // source array")?;
+    writeln!(out, "    ldr x21, [x20, #-{}] // length", ARRAY_LEN_OFFSET)?;
+    writeln!(out, "    lsl x21, x21, #3")?;
+    writeln!(
+        out,
+        "    add x21, x21, #{} // block size, header included",
+        ARRAY_HEADER_SIZE
+    )?;
+    writeln!(out, "    mov x0, x21")?;
+    writeln!(out, "    bl alloc_heap_ptr // allocate the copy")?;
+    writeln!(
+        out,
+        "    sub x1, x20, #{} // memcpy src, header included",
+        ARRAY_HEADER_SIZE
+    )?;
+    writeln!(out, "    mov x2, x21 // memcpy length")?;
+    writeln!(out, "    mov x20, x0 // copy block")?;
+    writeln!(out, "    bl memcpy_helper // duplicate header and elements")?;
+    writeln!(
+        out,
+        "    add x20, x20, #{} // copied array starts after its header",
+        ARRAY_HEADER_SIZE
+    )?;
+    writeln!(
+        out,
+        "    ldr x21, [x20, #-{}] // elements left to copy",
+        ARRAY_LEN_OFFSET
+    )?;
+    writeln!(
+        out,
+        "    ldr x22, [x20, #-{}] // element class",
+        ARRAY_CLASS_OFFSET
+    )?;
+    writeln!(
+        out,
+        "    cbz x22, copy_array_ptr_copied // plain elements are copied already"
+    )?;
+    writeln!(out, "copy_array_ptr_loop:")?;
+    writeln!(out, "    cbz x21, copy_array_ptr_copied")?;
+    writeln!(out, "    sub x21, x21, #1")?;
+    writeln!(
+        out,
+        "    ldr x0, [x20, x21, lsl #3] // element shared with the source"
+    )?;
+    writeln!(out, "    cmp x22, #{} // string element?", ARRAY_CLASS_STR)?;
+    writeln!(out, "    b.eq copy_array_ptr_str")?;
+    writeln!(out, "    bl copy_array_ptr")?;
+    writeln!(out, "    b copy_array_ptr_store")?;
+    writeln!(out, "copy_array_ptr_str:")?;
+    writeln!(out, "    bl copy_str_ptr")?;
+    writeln!(out, "copy_array_ptr_store:")?;
+    writeln!(
+        out,
+        "    str x0, [x20, x21, lsl #3] // element owned by the copy"
+    )?;
+    writeln!(out, "    b copy_array_ptr_loop")?;
+    writeln!(out, "copy_array_ptr_copied:")?;
+    writeln!(out, "    mov x0, x20")?;
+    writeln!(out, "    ldp x22, x23, [sp], #16")?;
+    writeln!(out, "    ldp x20, x21, [sp], #16")?;
+    writeln!(out, "    ldp x29, x30, [sp], #16")?;
+    writeln!(out, "copy_array_ptr_done:")?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+// Returns in x0 a copy of the element at x0 of an array whose element class
+// is in x1.
+fn emit_copy_array_elem_ptr<W: Write>(out: &mut W) -> Result<(), Error> {
+    writeln!(out, ".global copy_array_elem_ptr")?;
+    writeln!(out, "copy_array_elem_ptr:")?;
+    writeln!(out, "    cmp x1, #{} // string element?", ARRAY_CLASS_STR)?;
+    writeln!(out, "    b.eq copy_str_ptr")?;
+    writeln!(out, "    cmp x1, #{} // array element?", ARRAY_CLASS_ARRAY)?;
+    writeln!(out, "    b.eq copy_array_ptr")?;
+    writeln!(out, "    ret // plain elements are copied by value")?;
+    Ok(())
+}
+
+// Saves the frame record and the callee-saved pairs starting at each of
+// `pairs`, so a helper can keep state across calls.
+fn emit_helper_prologue<W: Write>(out: &mut W, pairs: &[usize]) -> Result<(), Error> {
+    writeln!(out, "    stp x29, x30, [sp, #-16]! // save caller frame")?;
+    writeln!(out, "    mov x29, sp // establish frame")?;
+    for first in pairs {
+        writeln!(
+            out,
+            "    stp x{}, x{}, [sp, #-16]! // preserve callee-saved registers",
+            first,
+            first + 1
+        )?;
+    }
+    Ok(())
+}
+
+// Restores what `emit_helper_prologue` saved for the same `pairs`.
+fn emit_helper_epilogue<W: Write>(out: &mut W, pairs: &[usize]) -> Result<(), Error> {
+    for first in pairs.iter().rev() {
+        writeln!(out, "    ldp x{}, x{}, [sp], #16", first, first + 1)?;
+    }
+    writeln!(out, "    ldp x29, x30, [sp], #16")?;
+    Ok(())
+}
+
+// Loads the 64-bit `value` into `reg` a halfword at a time.
+fn emit_load_imm64<W: Write>(
+    out: &mut W,
+    reg: &str,
+    value: u64,
+    comment: &str,
+) -> Result<(), Error> {
+    writeln!(out, "    movz {reg}, #{:#x} // {comment}", value & 0xffff)?;
+    for shift in [16, 32, 48] {
+        let chunk = (value >> shift) & 0xffff;
+        if chunk != 0 {
+            writeln!(out, "    movk {reg}, #{chunk:#x}, lsl #{shift}")?;
+        }
+    }
+    Ok(())
+}
+
+// Returns in x0 an empty map whose keys have the element class in x0 and
+// whose values have the one in x1.
+fn emit_map_new_ptr<W: Write>(out: &mut W) -> Result<(), Error> {
+    writeln!(out, ".global map_new_ptr")?;
+    writeln!(out, "map_new_ptr:")?;
+    emit_helper_prologue(out, &[20])?;
+    writeln!(out, "    mov x20, x0 // key class")?;
+    writeln!(out, "    mov x21, x1 // value class")?;
+    writeln!(
+        out,
+        "    mov x0, #{} // header, entries and index",
+        map_block_size(MAP_INITIAL_CAPACITY)
+    )?;
+    writeln!(out, "    bl alloc_heap_ptr")?;
+    writeln!(
+        out,
+        "    add x0, x0, #{} // map starts after its header",
+        MAP_HEADER_SIZE
+    )?;
+    writeln!(
+        out,
+        "    stur x20, [x0, #-{}] // key class header",
+        MAP_KEY_CLASS_OFFSET
+    )?;
+    writeln!(
+        out,
+        "    stur x21, [x0, #-{}] // value class header",
+        MAP_VALUE_CLASS_OFFSET
+    )?;
+    writeln!(out, "    mov x9, #{}", MAP_INITIAL_CAPACITY)?;
+    writeln!(
+        out,
+        "    stur x9, [x0, #-{}] // capacity header",
+        MAP_CAPACITY_OFFSET
+    )?;
+    writeln!(
+        out,
+        "    stur xzr, [x0, #-{}] // no entries yet",
+        MAP_COUNT_OFFSET
+    )?;
+    writeln!(
+        out,
+        "    add x10, x0, #{} // index follows the entries",
+        map_index_offset(MAP_INITIAL_CAPACITY)
+    )?;
+    writeln!(out, "map_new_ptr_clear:")?;
+    writeln!(out, "    str xzr, [x10], #8 // every slot starts empty")?;
+    writeln!(out, "    subs x9, x9, #1")?;
+    writeln!(out, "    b.ne map_new_ptr_clear")?;
+    emit_helper_epilogue(out, &[20])?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+// Returns in x0 the hash of the key at x0 whose element class is in x1:
+// FNV-1a over the bytes of a string, and a multiplicative mix of anything
+// else. Clobbers x9 to x12.
+fn emit_map_hash_ptr<W: Write>(out: &mut W) -> Result<(), Error> {
+    writeln!(out, ".global map_hash_ptr")?;
+    writeln!(out, "map_hash_ptr:")?;
+    writeln!(out, "    cmp x1, #{} // string key?", ARRAY_CLASS_STR)?;
+    writeln!(out, "    b.eq map_hash_ptr_str")?;
+    emit_load_imm64(
+        out,
+        "x9",
+        MAP_INT_HASH_MULTIPLIER,
+        "golden ratio multiplier",
+    )?;
+    writeln!(out, "    mul x0, x0, x9")?;
+    writeln!(
+        out,
+        "    eor x0, x0, x0, lsr #32 // fold the well mixed high bits down"
+    )?;
+    writeln!(out, "    ret")?;
+    writeln!(out, "map_hash_ptr_str:")?;
+    writeln!(
+        out,
+        "    ldur x10, [x0, #-{}] // bytes to hash",
+        STR_LEN_OFFSET
+    )?;
+    emit_load_imm64(out, "x9", FNV_OFFSET_BASIS, "FNV offset basis")?;
+    emit_load_imm64(out, "x11", FNV_PRIME, "FNV prime")?;
+    writeln!(out, "map_hash_ptr_loop:")?;
+    writeln!(out, "    cbz x10, map_hash_ptr_done")?;
+    writeln!(out, "    ldrb w12, [x0], #1")?;
+    writeln!(out, "    eor x9, x9, x12")?;
+    writeln!(out, "    mul x9, x9, x11")?;
+    writeln!(out, "    sub x10, x10, #1")?;
+    writeln!(out, "    b map_hash_ptr_loop")?;
+    writeln!(out, "map_hash_ptr_done:")?;
+    writeln!(out, "    mov x0, x9")?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+// Looks up the key at x1 in the map at x0. Returns in x0 the address of the
+// entry holding it, or 0, and in x1 the address of the index slot that
+// refers to that entry or would.
+fn emit_map_find_ptr<W: Write>(out: &mut W) -> Result<(), Error> {
+    writeln!(out, ".global map_find_ptr")?;
+    writeln!(out, "map_find_ptr:")?;
+    emit_helper_prologue(out, &[20, 22, 24])?;
+    writeln!(out, "    mov x20, x0 // map")?;
+    writeln!(out, "    mov x21, x1 // key")?;
+    writeln!(out, "    mov x0, x21")?;
+    writeln!(
+        out,
+        "    ldur x1, [x20, #-{}] // key class",
+        MAP_KEY_CLASS_OFFSET
+    )?;
+    writeln!(out, "    bl map_hash_ptr")?;
+    writeln!(
+        out,
+        "    ldur x22, [x20, #-{}] // capacity",
+        MAP_CAPACITY_OFFSET
+    )?;
+    writeln!(out, "    add x24, x20, x22, lsl #3 // index start")?;
+    writeln!(out, "    sub x22, x22, #1 // slot mask")?;
+    writeln!(out, "    and x23, x0, x22 // first slot to probe")?;
+    writeln!(out, "map_find_ptr_probe:")?;
+    writeln!(out, "    add x1, x24, x23, lsl #3 // slot address")?;
+    writeln!(out, "    ldr x0, [x1] // entry position plus one")?;
+    writeln!(
+        out,
+        "    cbz x0, map_find_ptr_done // no entry; x1 is the empty slot"
+    )?;
+    writeln!(out, "    sub x0, x0, #1")?;
+    writeln!(out, "    add x0, x20, x0, lsl #4 // entry address")?;
+    writeln!(
+        out,
+        "    ldur x9, [x20, #-{}] // key class",
+        MAP_KEY_CLASS_OFFSET
+    )?;
+    writeln!(out, "    cmp x9, #{} // string keys?", ARRAY_CLASS_STR)?;
+    writeln!(out, "    b.eq map_find_ptr_str")?;
+    writeln!(out, "    ldr x9, [x0] // key of the entry")?;
+    writeln!(out, "    cmp x9, x21")?;
+    writeln!(out, "    b.eq map_find_ptr_done")?;
+    writeln!(out, "    b map_find_ptr_next")?;
+    writeln!(out, "map_find_ptr_str:")?;
+    writeln!(out, "    mov x25, x0 // entry")?;
+    writeln!(out, "    ldr x0, [x25] // key of the entry")?;
+    writeln!(out, "    mov x1, x21")?;
+    writeln!(out, "    bl cmp_str_ptr")?;
+    writeln!(out, "    cbz x0, map_find_ptr_found_str // same bytes?")?;
+    writeln!(out, "map_find_ptr_next:")?;
+    writeln!(out, "    add x23, x23, #1")?;
+    writeln!(out, "    and x23, x23, x22 // wrap around the index")?;
+    writeln!(out, "    b map_find_ptr_probe")?;
+    writeln!(out, "map_find_ptr_found_str:")?;
+    writeln!(out, "    mov x0, x25")?;
+    writeln!(out, "    add x1, x24, x23, lsl #3 // slot address")?;
+    writeln!(out, "map_find_ptr_done:")?;
+    emit_helper_epilogue(out, &[20, 22, 24])?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+// Stores the value at x2 under the key at x1 in the map at x0, taking both,
+// and returns the map in x0. A value already under the key is released
+// along with the new key; otherwise the entry is appended, growing the map
+// into a new block when it is full.
+fn emit_map_insert_ptr<W: Write>(out: &mut W) -> Result<(), Error> {
+    writeln!(out, ".global map_insert_ptr")?;
+    writeln!(out, "map_insert_ptr:")?;
+    emit_helper_prologue(out, &[20, 22])?;
+    writeln!(out, "    mov x20, x0 // map")?;
+    writeln!(out, "    mov x21, x1 // key")?;
+    writeln!(out, "    mov x22, x2 // value")?;
+    writeln!(out, "    bl map_find_ptr")?;
+    writeln!(out, "    cbz x0, map_insert_ptr_absent")?;
+    writeln!(out, "    ldr x9, [x0, #{}] // old value", WORD_SIZE)?;
+    writeln!(
+        out,
+        "    str x22, [x0, #{}] // store the new one",
+        WORD_SIZE
+    )?;
+    writeln!(out, "    mov x0, x9")?;
+    writeln!(
+        out,
+        "    ldur x1, [x20, #-{}] // value class",
+        MAP_VALUE_CLASS_OFFSET
+    )?;
+    writeln!(out, "    bl release_array_elem_ptr")?;
+    writeln!(out, "    mov x0, x21")?;
+    writeln!(
+        out,
+        "    ldur x1, [x20, #-{}] // key class",
+        MAP_KEY_CLASS_OFFSET
+    )?;
+    writeln!(
+        out,
+        "    bl release_array_elem_ptr // the entry keeps its own key"
+    )?;
+    writeln!(out, "    b map_insert_ptr_done")?;
+    writeln!(out, "map_insert_ptr_absent:")?;
+    writeln!(out, "    mov x23, x1 // empty slot")?;
+    writeln!(out, "    ldur x9, [x20, #-{}] // entries", MAP_COUNT_OFFSET)?;
+    writeln!(
+        out,
+        "    ldur x10, [x20, #-{}] // capacity",
+        MAP_CAPACITY_OFFSET
+    )?;
+    writeln!(out, "    cmp x10, x9, lsl #1 // room for another entry?")?;
+    writeln!(out, "    b.gt map_insert_ptr_room")?;
+    writeln!(out, "    mov x0, x20")?;
+    writeln!(out, "    bl map_grow_ptr")?;
+    writeln!(out, "    mov x20, x0")?;
+    writeln!(out, "    mov x1, x21")?;
+    writeln!(out, "    bl map_find_ptr // empty slot in the new index")?;
+    writeln!(out, "    mov x23, x1")?;
+    writeln!(out, "map_insert_ptr_room:")?;
+    writeln!(out, "    ldur x9, [x20, #-{}] // entries", MAP_COUNT_OFFSET)?;
+    writeln!(out, "    add x10, x20, x9, lsl #4 // first free entry")?;
+    writeln!(out, "    stp x21, x22, [x10]")?;
+    writeln!(out, "    add x9, x9, #1")?;
+    writeln!(out, "    stur x9, [x20, #-{}]", MAP_COUNT_OFFSET)?;
+    writeln!(out, "    str x9, [x23] // slot refers to the entry")?;
+    writeln!(out, "map_insert_ptr_done:")?;
+    writeln!(out, "    mov x0, x20")?;
+    emit_helper_epilogue(out, &[20, 22])?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+// Moves the map at x0 into a block with twice the capacity, frees the old
+// block and returns the new map in x0.
+fn emit_map_grow_ptr<W: Write>(out: &mut W) -> Result<(), Error> {
+    writeln!(out, ".global map_grow_ptr")?;
+    writeln!(out, "map_grow_ptr:")?;
+    emit_helper_prologue(out, &[20, 22])?;
+    writeln!(out, "    mov x20, x0 // old map")?;
+    writeln!(
+        out,
+        "    ldur x21, [x20, #-{}] // old capacity",
+        MAP_CAPACITY_OFFSET
+    )?;
+    writeln!(
+        out,
+        "    lsl x0, x21, #5 // entries and index of twice the slots"
+    )?;
+    writeln!(
+        out,
+        "    add x0, x0, #{} // header included",
+        MAP_HEADER_SIZE
+    )?;
+    writeln!(out, "    bl alloc_heap_ptr")?;
+    writeln!(
+        out,
+        "    add x22, x0, #{} // new map starts after its header",
+        MAP_HEADER_SIZE
+    )?;
+    for (offset, what) in [
+        (MAP_KEY_CLASS_OFFSET, "key class"),
+        (MAP_VALUE_CLASS_OFFSET, "value class"),
+    ] {
+        writeln!(out, "    ldur x9, [x20, #-{}] // {}", offset, what)?;
+        writeln!(out, "    stur x9, [x22, #-{}]", offset)?;
+    }
+    writeln!(out, "    lsl x9, x21, #1")?;
+    writeln!(
+        out,
+        "    stur x9, [x22, #-{}] // doubled capacity",
+        MAP_CAPACITY_OFFSET
+    )?;
+    writeln!(out, "    ldur x9, [x20, #-{}] // entries", MAP_COUNT_OFFSET)?;
+    writeln!(out, "    stur x9, [x22, #-{}]", MAP_COUNT_OFFSET)?;
+    writeln!(out, "    mov x0, x22 // memcpy dst")?;
+    writeln!(out, "    mov x1, x20 // memcpy src")?;
+    writeln!(out, "    lsl x2, x9, #4 // entry bytes")?;
+    writeln!(out, "    bl memcpy_helper // entries keep their order")?;
+    writeln!(
+        out,
+        "    sub x0, x20, #{} // old block starts at the header",
+        MAP_HEADER_SIZE
+    )?;
+    writeln!(out, "    lsl x1, x21, #4")?;
+    writeln!(
+        out,
+        "    add x1, x1, #{} // old block size",
+        MAP_HEADER_SIZE
+    )?;
+    writeln!(out, "    bl free_heap_ptr")?;
+    writeln!(out, "    mov x0, x22")?;
+    writeln!(out, "    bl map_reindex_ptr")?;
+    writeln!(out, "    mov x0, x22")?;
+    emit_helper_epilogue(out, &[20, 22])?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+// Empties the index of the map at x0 and points a slot at every entry again.
+fn emit_map_reindex_ptr<W: Write>(out: &mut W) -> Result<(), Error> {
+    writeln!(out, ".global map_reindex_ptr")?;
+    writeln!(out, "map_reindex_ptr:")?;
+    emit_helper_prologue(out, &[20, 22])?;
+    writeln!(out, "    mov x20, x0 // map")?;
+    writeln!(
+        out,
+        "    ldur x21, [x20, #-{}] // capacity",
+        MAP_CAPACITY_OFFSET
+    )?;
+    writeln!(out, "    add x23, x20, x21, lsl #3 // index start")?;
+    writeln!(out, "    mov x9, x23")?;
+    writeln!(out, "    mov x10, x21")?;
+    writeln!(out, "map_reindex_ptr_clear:")?;
+    writeln!(out, "    str xzr, [x9], #8 // empty every slot")?;
+    writeln!(out, "    subs x10, x10, #1")?;
+    writeln!(out, "    b.ne map_reindex_ptr_clear")?;
+    writeln!(out, "    mov x22, #0 // entry position")?;
+    writeln!(out, "map_reindex_ptr_loop:")?;
+    writeln!(out, "    ldur x9, [x20, #-{}] // entries", MAP_COUNT_OFFSET)?;
+    writeln!(out, "    cmp x22, x9 // past the last entry?")?;
+    writeln!(out, "    b.ge map_reindex_ptr_done")?;
+    writeln!(out, "    add x9, x20, x22, lsl #4")?;
+    writeln!(out, "    ldr x0, [x9] // key of the entry")?;
+    writeln!(
+        out,
+        "    ldur x1, [x20, #-{}] // key class",
+        MAP_KEY_CLASS_OFFSET
+    )?;
+    writeln!(out, "    bl map_hash_ptr")?;
+    writeln!(out, "    sub x10, x21, #1 // slot mask")?;
+    writeln!(out, "map_reindex_ptr_probe:")?;
+    writeln!(out, "    and x0, x0, x10 // wrap around the index")?;
+    writeln!(out, "    add x11, x23, x0, lsl #3 // slot address")?;
+    writeln!(out, "    ldr x12, [x11]")?;
+    writeln!(out, "    cbz x12, map_reindex_ptr_place // empty slot?")?;
+    writeln!(out, "    add x0, x0, #1")?;
+    writeln!(out, "    b map_reindex_ptr_probe")?;
+    writeln!(out, "map_reindex_ptr_place:")?;
+    writeln!(out, "    add x22, x22, #1")?;
+    writeln!(out, "    str x22, [x11] // entry position plus one")?;
+    writeln!(out, "    b map_reindex_ptr_loop")?;
+    writeln!(out, "map_reindex_ptr_done:")?;
+    emit_helper_epilogue(out, &[20, 22])?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+// Drops the entry under the key at x1 from the map at x0, if there is one,
+// and returns the map in x0. The key is taken, and later entries move down
+// so the rest keep their order.
+fn emit_map_remove_ptr<W: Write>(out: &mut W) -> Result<(), Error> {
+    writeln!(out, ".global map_remove_ptr")?;
+    writeln!(out, "map_remove_ptr:")?;
+    emit_helper_prologue(out, &[20, 22])?;
+    writeln!(out, "    mov x20, x0 // map")?;
+    writeln!(out, "    mov x21, x1 // key")?;
+    writeln!(out, "    bl map_find_ptr")?;
+    writeln!(out, "    mov x22, x0 // entry, if any")?;
+    writeln!(out, "    mov x0, x21")?;
+    writeln!(
+        out,
+        "    ldur x1, [x20, #-{}] // key class",
+        MAP_KEY_CLASS_OFFSET
+    )?;
+    writeln!(out, "    bl release_array_elem_ptr // release the key")?;
+    writeln!(out, "    cbz x22, map_remove_ptr_done")?;
+    writeln!(out, "    ldr x0, [x22] // key of the entry")?;
+    writeln!(
+        out,
+        "    ldur x1, [x20, #-{}] // key class",
+        MAP_KEY_CLASS_OFFSET
+    )?;
+    writeln!(out, "    bl release_array_elem_ptr")?;
+    writeln!(
+        out,
+        "    ldr x0, [x22, #{}] // value of the entry",
+        WORD_SIZE
+    )?;
+    writeln!(
+        out,
+        "    ldur x1, [x20, #-{}] // value class",
+        MAP_VALUE_CLASS_OFFSET
+    )?;
+    writeln!(out, "    bl release_array_elem_ptr")?;
+    writeln!(out, "    ldur x9, [x20, #-{}] // entries", MAP_COUNT_OFFSET)?;
+    writeln!(out, "    sub x9, x9, #1")?;
+    writeln!(out, "    stur x9, [x20, #-{}]", MAP_COUNT_OFFSET)?;
+    writeln!(
+        out,
+        "    add x2, x20, x9, lsl #4 // start of the old last entry"
+    )?;
+    writeln!(out, "    sub x2, x2, x22 // bytes after the dropped entry")?;
+    writeln!(out, "    mov x0, x22 // memcpy dst")?;
+    writeln!(
+        out,
+        "    add x1, x22, #{} // memcpy src, the next entry",
+        MAP_ENTRY_SIZE
+    )?;
+    writeln!(
+        out,
+        "    bl memcpy_helper // forward copy moves entries down safely"
+    )?;
+    writeln!(out, "    mov x0, x20")?;
+    writeln!(
+        out,
+        "    bl map_reindex_ptr // positions after the entry moved"
+    )?;
+    writeln!(out, "map_remove_ptr_done:")?;
+    writeln!(out, "    mov x0, x20")?;
+    emit_helper_epilogue(out, &[20, 22])?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+// Frees the map at x0 along with the keys and values it holds.
+fn emit_map_release_ptr<W: Write>(out: &mut W) -> Result<(), Error> {
+    writeln!(out, ".global map_release_ptr")?;
+    writeln!(out, "map_release_ptr:")?;
+    writeln!(out, "    cbz x0, map_release_ptr_done // nothing to free")?;
+    emit_helper_prologue(out, &[20])?;
+    writeln!(out, "    mov x20, x0 // map")?;
+    writeln!(
+        out,
+        "    ldur x21, [x20, #-{}] // entries left to release",
+        MAP_COUNT_OFFSET
+    )?;
+    writeln!(out, "map_release_ptr_loop:")?;
+    writeln!(out, "    cbz x21, map_release_ptr_free")?;
+    writeln!(out, "    sub x21, x21, #1")?;
+    for (offset, class_offset) in [
+        (0, MAP_KEY_CLASS_OFFSET),
+        (WORD_SIZE, MAP_VALUE_CLASS_OFFSET),
+    ] {
+        writeln!(out, "    add x9, x20, x21, lsl #4 // entry address")?;
+        writeln!(out, "    ldr x0, [x9, #{}]", offset)?;
+        writeln!(out, "    ldur x1, [x20, #-{}]", class_offset)?;
+        writeln!(out, "    bl release_array_elem_ptr")?;
+    }
+    writeln!(out, "    b map_release_ptr_loop")?;
+    writeln!(out, "map_release_ptr_free:")?;
+    writeln!(
+        out,
+        "    ldur x1, [x20, #-{}] // capacity",
+        MAP_CAPACITY_OFFSET
+    )?;
+    writeln!(out, "    lsl x1, x1, #4")?;
+    writeln!(
+        out,
+        "    add x1, x1, #{} // block size, header included",
+        MAP_HEADER_SIZE
+    )?;
+    writeln!(
+        out,
+        "    sub x0, x20, #{} // block starts at the header",
+        MAP_HEADER_SIZE
+    )?;
+    emit_helper_epilogue(out, &[20])?;
+    writeln!(
+        out,
+        "    b free_heap_ptr // return the map to the allocator"
+    )?;
+    writeln!(out, "map_release_ptr_done:")?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+// Returns in x0 a deep copy of the map at x0, so the copy owns its keys and
+// values separately.
+fn emit_map_copy_ptr<W: Write>(out: &mut W) -> Result<(), Error> {
+    writeln!(out, ".global map_copy_ptr")?;
+    writeln!(out, "map_copy_ptr:")?;
+    writeln!(out, "    cbz x0, map_copy_ptr_done // nothing to copy")?;
+    emit_helper_prologue(out, &[20, 22])?;
+    writeln!(out, "    mov x20, x0 // source map")?;
+    writeln!(
+        out,
+        "    ldur x21, [x20, #-{}] // capacity",
+        MAP_CAPACITY_OFFSET
+    )?;
+    writeln!(out, "    lsl x21, x21, #4")?;
+    writeln!(
+        out,
+        "    add x21, x21, #{} // block size, header included",
+        MAP_HEADER_SIZE
+    )?;
+    writeln!(out, "    mov x0, x21")?;
+    writeln!(out, "    bl alloc_heap_ptr // allocate the copy")?;
+    writeln!(
+        out,
+        "    sub x1, x20, #{} // memcpy src, header included",
+        MAP_HEADER_SIZE
+    )?;
+    writeln!(out, "    mov x2, x21 // memcpy length")?;
+    writeln!(out, "    mov x20, x0 // copy block")?;
+    writeln!(
+        out,
+        "    bl memcpy_helper // duplicate header, entries and index"
+    )?;
+    writeln!(
+        out,
+        "    add x20, x20, #{} // copied map starts after its header",
+        MAP_HEADER_SIZE
+    )?;
+    writeln!(
+        out,
+        "    ldur x21, [x20, #-{}] // entries left to copy",
+        MAP_COUNT_OFFSET
+    )?;
+    writeln!(out, "map_copy_ptr_loop:")?;
+    writeln!(out, "    cbz x21, map_copy_ptr_copied")?;
+    writeln!(out, "    sub x21, x21, #1")?;
+    writeln!(out, "    add x22, x20, x21, lsl #4 // entry address")?;
+    for (offset, class_offset) in [
+        (0, MAP_KEY_CLASS_OFFSET),
+        (WORD_SIZE, MAP_VALUE_CLASS_OFFSET),
+    ] {
+        writeln!(
+            out,
+            "    ldr x0, [x22, #{}] // shared with the source",
+            offset
+        )?;
+        writeln!(out, "    ldur x1, [x20, #-{}]", class_offset)?;
+        writeln!(out, "    bl copy_array_elem_ptr")?;
+        writeln!(out, "    str x0, [x22, #{}] // owned by the copy", offset)?;
+    }
+    writeln!(out, "    b map_copy_ptr_loop")?;
+    writeln!(out, "map_copy_ptr_copied:")?;
+    writeln!(out, "    mov x0, x20")?;
+    emit_helper_epilogue(out, &[20, 22])?;
+    writeln!(out, "map_copy_ptr_done:")?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+// Appends the word in x1 to the queue whose head and tail are at x0.
+fn emit_queue_push_ptr<W: Write>(out: &mut W) -> Result<(), Error> {
+    writeln!(out, ".global queue_push_ptr")?;
+    writeln!(out, "queue_push_ptr:")?;
+    emit_helper_prologue(out, &[20])?;
+    writeln!(out, "    mov x20, x0 // queue")?;
+    writeln!(out, "    mov x21, x1 // word to queue")?;
+    writeln!(out, "    mov x0, #{} // node size", QUEUE_NODE_SIZE)?;
+    writeln!(out, "    bl alloc_heap_ptr")?;
+    writeln!(out, "    str xzr, [x0] // last node")?;
+    writeln!(out, "    str x21, [x0, #{}]", WORD_SIZE)?;
+    writeln!(out, "    ldr x11, [x20, #{}] // tail", WORD_SIZE)?;
+    writeln!(out, "    cbz x11, queue_push_ptr_empty")?;
+    writeln!(out, "    str x0, [x11] // link behind the tail")?;
+    writeln!(out, "    b queue_push_ptr_linked")?;
+    writeln!(out, "queue_push_ptr_empty:")?;
+    writeln!(out, "    str x0, [x20] // node is the head too")?;
+    writeln!(out, "queue_push_ptr_linked:")?;
+    writeln!(
+        out,
+        "    str x0, [x20, #{}] // node is the new tail",
+        WORD_SIZE
+    )?;
+    emit_helper_epilogue(out, &[20])?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+// Unlinks the head of the non-empty queue at x0 and returns in x0 the word
+// it held.
+fn emit_queue_pop_ptr<W: Write>(out: &mut W) -> Result<(), Error> {
+    writeln!(out, ".global queue_pop_ptr")?;
+    writeln!(out, "queue_pop_ptr:")?;
+    emit_helper_prologue(out, &[20])?;
+    writeln!(out, "    mov x12, x0 // queue")?;
+    writeln!(out, "    ldr x0, [x12] // head node")?;
+    writeln!(out, "    ldr x11, [x0] // next node")?;
+    writeln!(out, "    str x11, [x12] // next node is the new head")?;
+    writeln!(out, "    cbnz x11, queue_pop_ptr_unlinked")?;
+    writeln!(out, "    str xzr, [x12, #{}] // queue is empty", WORD_SIZE)?;
+    writeln!(out, "queue_pop_ptr_unlinked:")?;
+    writeln!(
+        out,
+        "    ldr x20, [x0, #{}] // word the node held",
+        WORD_SIZE
+    )?;
+    writeln!(out, "    mov x1, #{} // node size", QUEUE_NODE_SIZE)?;
+    writeln!(out, "    bl free_heap_ptr")?;
+    writeln!(out, "    mov x0, x20")?;
+    emit_helper_epilogue(out, &[20])?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+// Generated code branches here once its frame is gone. Enters the next
+// closure in the run queue, or exits when nothing is left to run: with
+// status 0 when no coroutine is parked on a channel, and with a deadlock
+// message and status 1 otherwise. A `threaded` scheduler only exits once
+// every thread has nothing to run; until then an idle thread sleeps until a
+// coroutine becomes runnable.
+fn emit_sched_next_ptr<W: Write>(out: &mut W, threaded: bool) -> Result<(), Error> {
+    writeln!(out, ".data")?;
+    writeln!(out, ".p2align 3")?;
+    writeln!(out, "{}:", SCHED_STATE_LABEL)?;
+    writeln!(out, "    .quad 0, 0, 0, 1, 0, 0")?;
+    if threaded {
+        writeln!(out, "{}:", SCHED_LOCK_LABEL)?;
+        writeln!(out, "    .quad 0")?;
+    }
+    writeln!(out, ".section .rodata")?;
+    writeln!(out, "sched_deadlock_message:")?;
+    writeln!(out, "    .ascii \"{}\"", escape_asciz(DEADLOCK_MESSAGE))?;
+    writeln!(out, ".text")?;
+    writeln!(out, ".p2align 2")?;
+    writeln!(out, ".global sched_next_ptr")?;
+    writeln!(out, "sched_next_ptr:")?;
+    if threaded {
+        emit_lock(out, SCHED_LOCK_LABEL, "sched_next_ptr_lock")?;
+    }
+    writeln!(out, "sched_next_ptr_pick:")?;
+    emit_label_address(out, "x0", SCHED_STATE_LABEL)?;
+    writeln!(out, "    ldr x9, [x0] // anything to run?")?;
+    writeln!(out, "    cbz x9, sched_next_ptr_idle")?;
+    writeln!(out, "    bl queue_pop_ptr // env_end of the next coroutine")?;
+    if threaded {
+        emit_unlock(out, SCHED_LOCK_LABEL)?;
+    }
+    writeln!(
+        out,
+        "    ldr x9, [x0, #{}] // load its entry point",
+        ENV_METADATA_UNWRAPPER_OFFSET
+    )?;
+    writeln!(out, "    br x9")?;
+    writeln!(out, "sched_next_ptr_idle:")?;
+    if threaded {
+        writeln!(out, "    ldr x12, [x0, #{}]", SCHED_IDLE_OFFSET)?;
+        writeln!(
+            out,
+            "    add x12, x12, #1 // this thread has nothing to run"
+        )?;
+        writeln!(out, "    ldr x11, [x0, #{}]", SCHED_WORKERS_OFFSET)?;
+        writeln!(out, "    cmp x12, x11 // neither has any other?")?;
+        writeln!(out, "    b.eq sched_next_ptr_stop")?;
+        writeln!(out, "    str x12, [x0, #{}]", SCHED_IDLE_OFFSET)?;
+        writeln!(
+            out,
+            "    ldr w2, [x0, #{}] // wakeups so far",
+            SCHED_WAKEUPS_OFFSET
+        )?;
+        writeln!(out, "    add x0, x0, #{}", SCHED_WAKEUPS_OFFSET)?;
+        emit_unlock(out, SCHED_LOCK_LABEL)?;
+        writeln!(out, "    mov x1, #{} // wait", FUTEX_WAIT_PRIVATE)?;
+        writeln!(out, "    mov x3, #0 // no timeout")?;
+        writeln!(out, "    mov x8, #{} // futex syscall", SYSCALL_FUTEX)?;
+        writeln!(
+            out,
+            "    svc #0 // sleep unless a coroutine became runnable since"
+        )?;
+        emit_lock(out, SCHED_LOCK_LABEL, "sched_next_ptr_relock")?;
+        emit_label_address(out, "x0", SCHED_STATE_LABEL)?;
+        writeln!(out, "    ldr x12, [x0, #{}]", SCHED_IDLE_OFFSET)?;
+        writeln!(out, "    sub x12, x12, #1")?;
+        writeln!(out, "    str x12, [x0, #{}]", SCHED_IDLE_OFFSET)?;
+        writeln!(out, "    b sched_next_ptr_pick")?;
+        writeln!(out, "sched_next_ptr_stop:")?;
+    }
+    writeln!(
+        out,
+        "    ldr x9, [x0, #{}] // anyone parked?",
+        SCHED_WAITING_OFFSET
+    )?;
+    writeln!(out, "    cbnz x9, sched_next_ptr_deadlock")?;
+    writeln!(out, "    mov x0, #0 // exit code")?;
+    writeln!(out, "    bl exit // call libc exit to flush buffers")?;
+    writeln!(out, "sched_next_ptr_deadlock:")?;
+    writeln!(out, "    mov x0, #2 // stderr")?;
+    emit_label_address(out, "x1", "sched_deadlock_message")?;
+    writeln!(
+        out,
+        "    mov x2, #{} // message length",
+        DEADLOCK_MESSAGE.len()
+    )?;
+    writeln!(out, "    mov x8, #{} // write syscall", SYSCALL_WRITE)?;
+    writeln!(out, "    svc #0")?;
+    writeln!(out, "    mov x0, #1 // exit code")?;
+    writeln!(out, "    bl exit")?;
+    Ok(())
+}
+
+// Puts the closure at x0 at the back of the run queue. A `threaded`
+// scheduler also wakes a thread that has nothing to run, and
+// `sched_ready_ptr_locked` does the same for callers that already hold the
+// scheduler's lock.
+fn emit_sched_ready_ptr<W: Write>(out: &mut W, threaded: bool) -> Result<(), Error> {
+    writeln!(out, ".global sched_ready_ptr")?;
+    writeln!(out, "sched_ready_ptr:")?;
+    if !threaded {
+        writeln!(out, "    mov x1, x0")?;
+        emit_label_address(out, "x0", SCHED_STATE_LABEL)?;
+        writeln!(out, "    b queue_push_ptr")?;
+        return Ok(());
+    }
+    emit_locked_call(out, SCHED_LOCK_LABEL, "sched_ready_ptr")?;
+    emit_helper_prologue(out, &[])?;
+    writeln!(out, "    mov x1, x0")?;
+    emit_label_address(out, "x0", SCHED_STATE_LABEL)?;
+    writeln!(out, "    bl queue_push_ptr")?;
+    emit_label_address(out, "x12", SCHED_STATE_LABEL)?;
+    writeln!(out, "    ldr w9, [x12, #{}]", SCHED_WAKEUPS_OFFSET)?;
+    writeln!(out, "    add w9, w9, #1 // sleepers see a new count")?;
+    writeln!(out, "    str w9, [x12, #{}]", SCHED_WAKEUPS_OFFSET)?;
+    writeln!(
+        out,
+        "    ldr x9, [x12, #{}] // anyone asleep?",
+        SCHED_IDLE_OFFSET
+    )?;
+    writeln!(out, "    cbz x9, sched_ready_ptr_done")?;
+    writeln!(out, "    add x0, x12, #{}", SCHED_WAKEUPS_OFFSET)?;
+    writeln!(out, "    mov x1, #{} // wake", FUTEX_WAKE_PRIVATE)?;
+    writeln!(
+        out,
+        "    mov x2, #1 // one thread is enough for one coroutine"
+    )?;
+    writeln!(out, "    mov x8, #{} // futex syscall", SYSCALL_FUTEX)?;
+    writeln!(out, "    svc #0")?;
+    writeln!(out, "sched_ready_ptr_done:")?;
+    emit_helper_epilogue(out, &[])?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+// Starts a thread that enters the closure at x0 and then takes its turn
+// running coroutines. When no thread can be started the closure is queued as
+// a coroutine instead.
+fn emit_thread_new_ptr<W: Write>(out: &mut W) -> Result<(), Error> {
+    writeln!(out, ".global thread_new_ptr")?;
+    writeln!(out, "thread_new_ptr:")?;
+    emit_helper_prologue(out, &[20])?;
+    writeln!(out, "    mov x20, x0 // task")?;
+    emit_lock(out, SCHED_LOCK_LABEL, "thread_new_ptr_lock")?;
+    emit_label_address(out, "x12", SCHED_STATE_LABEL)?;
+    writeln!(out, "    ldr x10, [x12, #{}]", SCHED_WORKERS_OFFSET)?;
+    writeln!(
+        out,
+        "    add x10, x10, #1 // busy until it runs out of work"
+    )?;
+    writeln!(out, "    str x10, [x12, #{}]", SCHED_WORKERS_OFFSET)?;
+    emit_unlock(out, SCHED_LOCK_LABEL)?;
+    writeln!(out, "    sub sp, sp, #16 // room for the thread id")?;
+    writeln!(out, "    mov x0, sp")?;
+    writeln!(out, "    mov x1, #0 // default attributes")?;
+    emit_label_address(out, "x2", "thread_start_ptr")?;
+    writeln!(out, "    mov x3, x20 // task")?;
+    writeln!(out, "    bl pthread_create")?;
+    writeln!(out, "    add sp, sp, #16")?;
+    writeln!(out, "    cbz w0, thread_new_ptr_done")?;
+    emit_lock(out, SCHED_LOCK_LABEL, "thread_new_ptr_undo")?;
+    emit_label_address(out, "x12", SCHED_STATE_LABEL)?;
+    writeln!(out, "    ldr x10, [x12, #{}]", SCHED_WORKERS_OFFSET)?;
+    writeln!(out, "    sub x10, x10, #1")?;
+    writeln!(out, "    str x10, [x12, #{}]", SCHED_WORKERS_OFFSET)?;
+    emit_unlock(out, SCHED_LOCK_LABEL)?;
+    writeln!(out, "    mov x0, x20")?;
+    writeln!(
+        out,
+        "    bl sched_ready_ptr // run it on the threads there are"
+    )?;
+    writeln!(out, "thread_new_ptr_done:")?;
+    emit_helper_epilogue(out, &[20])?;
+    writeln!(out, "    ret")?;
+    writeln!(out, "thread_start_ptr:")?;
+    writeln!(
+        out,
+        "    ldr x9, [x0, #{}] // enter the task",
+        ENV_METADATA_UNWRAPPER_OFFSET
+    )?;
+    writeln!(out, "    br x9")?;
+    Ok(())
+}
+
+// Returns in x0 an empty channel for values of the class in x0, with one
+// reference.
+fn emit_chan_new_ptr<W: Write>(out: &mut W) -> Result<(), Error> {
+    writeln!(out, ".global chan_new_ptr")?;
+    writeln!(out, "chan_new_ptr:")?;
+    emit_helper_prologue(out, &[20])?;
+    writeln!(out, "    mov x20, x0 // value class")?;
+    writeln!(out, "    mov x0, #{} // channel size", CHAN_SIZE)?;
+    writeln!(out, "    bl alloc_heap_ptr")?;
+    writeln!(out, "    mov x9, #1 // one reference")?;
+    writeln!(out, "    str x9, [x0, #{}]", CHAN_REFS_OFFSET)?;
+    writeln!(out, "    str x20, [x0, #{}]", CHAN_CLASS_OFFSET)?;
+    for offset in [CHAN_VALUES_OFFSET, CHAN_RECEIVERS_OFFSET] {
+        writeln!(out, "    stp xzr, xzr, [x0, #{}] // empty queue", offset)?;
+    }
+    emit_helper_epilogue(out, &[20])?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+// Hands the value in x1 to the first receiver parked on the channel at x0
+// and makes it runnable, or queues the value when nobody is waiting.
+fn emit_chan_send_ptr<W: Write>(out: &mut W, threaded: bool) -> Result<(), Error> {
+    writeln!(out, ".global chan_send_ptr")?;
+    writeln!(out, "chan_send_ptr:")?;
+    let ready = if threaded {
+        emit_locked_call(out, SCHED_LOCK_LABEL, "chan_send_ptr")?;
+        "sched_ready_ptr_locked"
+    } else {
+        "sched_ready_ptr"
+    };
+    emit_helper_prologue(out, &[20])?;
+    writeln!(out, "    mov x20, x0 // channel")?;
+    writeln!(out, "    mov x21, x1 // value")?;
+    writeln!(
+        out,
+        "    ldr x9, [x20, #{}] // anyone receiving?",
+        CHAN_RECEIVERS_OFFSET
+    )?;
+    writeln!(out, "    cbz x9, chan_send_ptr_queue")?;
+    writeln!(out, "    add x0, x20, #{}", CHAN_RECEIVERS_OFFSET)?;
+    writeln!(out, "    bl queue_pop_ptr // the receiver's continuation")?;
+    writeln!(
+        out,
+        "    stur x21, [x0, #-{}] // pass the value",
+        WORD_SIZE * 2
+    )?;
+    writeln!(
+        out,
+        "    stur x20, [x0, #-{}] // and the channel",
+        WORD_SIZE
+    )?;
+    writeln!(out, "    bl {} // the receiver can run again", ready)?;
+    emit_label_address(out, "x11", SCHED_STATE_LABEL)?;
+    writeln!(out, "    ldr x9, [x11, #{}]", SCHED_WAITING_OFFSET)?;
+    writeln!(out, "    sub x9, x9, #1 // one coroutine fewer parked")?;
+    writeln!(out, "    str x9, [x11, #{}]", SCHED_WAITING_OFFSET)?;
+    writeln!(out, "    b chan_send_ptr_done")?;
+    writeln!(out, "chan_send_ptr_queue:")?;
+    writeln!(out, "    add x0, x20, #{}", CHAN_VALUES_OFFSET)?;
+    writeln!(out, "    mov x1, x21")?;
+    writeln!(
+        out,
+        "    bl queue_push_ptr // keep it for the next receiver"
+    )?;
+    writeln!(out, "chan_send_ptr_done:")?;
+    emit_helper_epilogue(out, &[20])?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+// Takes the oldest value queued on the channel at x0 into x0 and sets x1 to
+// 1. When none is queued it parks the continuation in x1 on the channel
+// instead and sets x1 to 0.
+fn emit_chan_recv_ptr<W: Write>(out: &mut W, threaded: bool) -> Result<(), Error> {
+    writeln!(out, ".global chan_recv_ptr")?;
+    writeln!(out, "chan_recv_ptr:")?;
+    if threaded {
+        emit_locked_call(out, SCHED_LOCK_LABEL, "chan_recv_ptr")?;
+    }
+    emit_helper_prologue(out, &[])?;
+    writeln!(
+        out,
+        "    ldr x9, [x0, #{}] // any value queued?",
+        CHAN_VALUES_OFFSET
+    )?;
+    writeln!(out, "    cbz x9, chan_recv_ptr_park")?;
+    writeln!(out, "    add x0, x0, #{}", CHAN_VALUES_OFFSET)?;
+    writeln!(out, "    bl queue_pop_ptr")?;
+    writeln!(out, "    mov x1, #1 // got a value")?;
+    emit_helper_epilogue(out, &[])?;
+    writeln!(out, "    ret")?;
+    writeln!(out, "chan_recv_ptr_park:")?;
+    writeln!(out, "    add x0, x0, #{}", CHAN_RECEIVERS_OFFSET)?;
+    writeln!(out, "    bl queue_push_ptr // wait for a sender")?;
+    emit_label_address(out, "x11", SCHED_STATE_LABEL)?;
+    writeln!(out, "    ldr x9, [x11, #{}]", SCHED_WAITING_OFFSET)?;
+    writeln!(out, "    add x9, x9, #1 // one more coroutine parked")?;
+    writeln!(out, "    str x9, [x11, #{}]", SCHED_WAITING_OFFSET)?;
+    writeln!(out, "    mov x1, #0 // parked")?;
+    emit_helper_epilogue(out, &[])?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+// Drops a reference to the channel at x0. The last reference releases the
+// values still queued on it, by class, and frees the channel.
+fn emit_chan_release_ptr<W: Write>(out: &mut W, threaded: bool) -> Result<(), Error> {
+    writeln!(out, ".global chan_release_ptr")?;
+    writeln!(out, "chan_release_ptr:")?;
+    if threaded {
+        // Once the count reaches zero no other thread can reach the channel,
+        // so only the count itself has to change atomically.
+        writeln!(out, "    add x12, x0, #{}", CHAN_REFS_OFFSET)?;
+        writeln!(out, "chan_release_ptr_retry:")?;
+        writeln!(out, "    ldaxr x9, [x12]")?;
+        writeln!(out, "    sub x9, x9, #1 // drop a reference")?;
+        writeln!(out, "    stlxr w10, x9, [x12]")?;
+        writeln!(out, "    cbnz w10, chan_release_ptr_retry")?;
+        writeln!(out, "    cbnz x9, chan_release_ptr_done")?;
+    } else {
+        writeln!(out, "    ldr x9, [x0, #{}]", CHAN_REFS_OFFSET)?;
+        writeln!(out, "    subs x9, x9, #1 // drop a reference")?;
+        writeln!(out, "    str x9, [x0, #{}]", CHAN_REFS_OFFSET)?;
+        writeln!(out, "    b.ne chan_release_ptr_done")?;
+    }
+    emit_helper_prologue(out, &[])?;
+    // Environments release themselves through generated code, which keeps
+    // nothing in registers, so the channel stays on the stack.
+    writeln!(out, "    str x0, [sp, #-16]! // channel")?;
+    writeln!(out, "chan_release_ptr_loop:")?;
+    writeln!(out, "    ldr x12, [sp]")?;
+    writeln!(
+        out,
+        "    ldr x9, [x12, #{}] // values left?",
+        CHAN_VALUES_OFFSET
+    )?;
+    writeln!(out, "    cbz x9, chan_release_ptr_free")?;
+    writeln!(out, "    add x0, x12, #{}", CHAN_VALUES_OFFSET)?;
+    writeln!(out, "    bl queue_pop_ptr // value")?;
+    writeln!(out, "    ldr x12, [sp]")?;
+    writeln!(
+        out,
+        "    ldr x1, [x12, #{}] // value class",
+        CHAN_CLASS_OFFSET
+    )?;
+    writeln!(out, "    cmp x1, #{} // environment?", CHAN_CLASS_ENV)?;
+    writeln!(out, "    b.eq chan_release_ptr_env")?;
+    writeln!(out, "    bl release_array_elem_ptr")?;
+    writeln!(out, "    b chan_release_ptr_loop")?;
+    writeln!(out, "chan_release_ptr_env:")?;
+    writeln!(
+        out,
+        "    ldr x10, [x0, #{}] // its release helper",
+        ENV_METADATA_RELEASE_OFFSET
+    )?;
+    writeln!(out, "    blr x10")?;
+    writeln!(out, "    b chan_release_ptr_loop")?;
+    writeln!(out, "chan_release_ptr_free:")?;
+    writeln!(out, "    ldr x0, [sp], #16")?;
+    writeln!(out, "    mov x1, #{} // channel size", CHAN_SIZE)?;
+    emit_helper_epilogue(out, &[])?;
+    writeln!(
+        out,
+        "    b free_heap_ptr // return the channel to the allocator"
+    )?;
+    writeln!(out, "chan_release_ptr_done:")?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+// Adds a reference to the channel at x0 and returns it in x0. Copies share
+// the one channel.
+fn emit_chan_copy_ptr<W: Write>(out: &mut W, threaded: bool) -> Result<(), Error> {
+    writeln!(out, ".global chan_copy_ptr")?;
+    writeln!(out, "chan_copy_ptr:")?;
+    if threaded {
+        writeln!(out, "    add x12, x0, #{}", CHAN_REFS_OFFSET)?;
+        writeln!(out, "chan_copy_ptr_retry:")?;
+        writeln!(out, "    ldxr x9, [x12]")?;
+        writeln!(out, "    add x9, x9, #1 // one more reference")?;
+        writeln!(out, "    stxr w10, x9, [x12]")?;
+        writeln!(out, "    cbnz w10, chan_copy_ptr_retry")?;
+    } else {
+        writeln!(out, "    ldr x9, [x0, #{}]", CHAN_REFS_OFFSET)?;
+        writeln!(out, "    add x9, x9, #1 // one more reference")?;
+        writeln!(out, "    str x9, [x0, #{}]", CHAN_REFS_OFFSET)?;
+    }
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+// Returns in x0 a new string of x0 bytes with its header and terminator in
+// place, leaving the bytes for the caller, or 0 when there is no memory for
+// it.
+fn emit_alloc_str_ptr<W: Write>(out: &mut W) -> Result<(), Error> {
+    writeln!(out, ".global alloc_str_ptr")?;
+    writeln!(out, "alloc_str_ptr:")?;
+    writeln!(out, "    stp x29, x30, [sp, #-16]! // save caller frame")?;
+    writeln!(out, "    mov x29, sp // establish frame")?;
+    writeln!(
+        out,
+        "    stp x20, x21, [sp, #-16]! // preserve callee-saved registers"
+    )?;
+    writeln!(out, "    mov x20, x0 // string length")?;
+    writeln!(
+        out,
+        "    add x0, x20, #{} // header, bytes and terminator",
+        STR_HEADER_SIZE + 1
+    )?;
+    writeln!(out, "    bl alloc_heap_ptr")?;
+    writeln!(out, "    cbz x0, alloc_str_ptr_done // out of memory")?;
+    writeln!(out, "    add x9, x20, #{}", STR_HEADER_SIZE + 1)?;
+    writeln!(out, "    str x9, [x0] // allocation size header")?;
+    writeln!(
+        out,
+        "    str x20, [x0, #{}] // length header",
+        STR_HEADER_SIZE - STR_LEN_OFFSET
+    )?;
+    writeln!(
+        out,
+        "    add x0, x0, #{} // string bytes follow the header",
+        STR_HEADER_SIZE
+    )?;
+    writeln!(out, "    strb wzr, [x0, x20] // terminator")?;
+    writeln!(out, "alloc_str_ptr_done:")?;
+    writeln!(out, "    ldp x20, x21, [sp], #16")?;
+    writeln!(out, "    ldp x29, x30, [sp], #16")?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+// Copies x12 bytes from x13 to x11 one at a time, advancing both pointers.
+fn emit_copy_bytes<W: Write>(out: &mut W, label: &str) -> Result<(), Error> {
+    writeln!(out, "{label}:")?;
+    writeln!(out, "    cbz x12, {label}_done")?;
+    writeln!(out, "    ldrb w9, [x13], #1")?;
+    writeln!(out, "    strb w9, [x11], #1")?;
+    writeln!(out, "    sub x12, x12, #1")?;
+    writeln!(out, "    b {label}")?;
+    writeln!(out, "{label}_done:")?;
+    Ok(())
+}
+
+// Returns in x0 a new string holding the bytes of the string at x0 followed
+// by those of the one at x1.
+fn emit_concat_str_ptr<W: Write>(out: &mut W) -> Result<(), Error> {
+    writeln!(out, ".global concat_str_ptr")?;
+    writeln!(out, "concat_str_ptr:")?;
+    writeln!(out, "    stp x29, x30, [sp, #-16]! // save caller frame")?;
+    writeln!(out, "    mov x29, sp // establish frame")?;
+    writeln!(
+        out,
+        "    stp x20, x21, [sp, #-16]! // preserve callee-saved registers"
+    )?;
+    writeln!(out, "    mov x20, x0 // left string")?;
+    writeln!(out, "    mov x21, x1 // right string")?;
+    writeln!(
+        out,
+        "    ldr x9, [x20, #-{}] // left length",
+        STR_LEN_OFFSET
+    )?;
+    writeln!(
+        out,
+        "    ldr x10, [x21, #-{}] // right length",
+        STR_LEN_OFFSET
+    )?;
+    writeln!(out, "    add x0, x9, x10 // combined length")?;
+    writeln!(out, "    bl alloc_str_ptr")?;
+    writeln!(out, "    mov x11, x0 // copy destination")?;
+    writeln!(out, "    mov x13, x20")?;
+    writeln!(out, "    ldr x12, [x20, #-{}]", STR_LEN_OFFSET)?;
+    emit_copy_bytes(out, "concat_str_ptr_left")?;
+    writeln!(out, "    mov x13, x21")?;
+    writeln!(out, "    ldr x12, [x21, #-{}]", STR_LEN_OFFSET)?;
+    emit_copy_bytes(out, "concat_str_ptr_right")?;
+    writeln!(out, "    ldp x20, x21, [sp], #16")?;
+    writeln!(out, "    ldp x29, x30, [sp], #16")?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+// Returns in x0 a new string holding the bytes of the string at x0 from x1
+// up to x2. The range is checked by the caller.
+fn emit_slice_str_ptr<W: Write>(out: &mut W) -> Result<(), Error> {
+    writeln!(out, ".global slice_str_ptr")?;
+    writeln!(out, "slice_str_ptr:")?;
+    writeln!(out, "    stp x29, x30, [sp, #-16]! // save caller frame")?;
+    writeln!(out, "    mov x29, sp // establish frame")?;
+    writeln!(
+        out,
+        "    stp x20, x21, [sp, #-16]! // preserve callee-saved registers"
+    )?;
+    writeln!(out, "    add x20, x0, x1 // first byte of the slice")?;
+    writeln!(out, "    sub x21, x2, x1 // slice length")?;
+    writeln!(out, "    mov x0, x21")?;
+    writeln!(out, "    bl alloc_str_ptr")?;
+    writeln!(out, "    mov x11, x0 // copy destination")?;
+    writeln!(out, "    mov x13, x20")?;
+    writeln!(out, "    mov x12, x21")?;
+    emit_copy_bytes(out, "slice_str_ptr_copy")?;
+    writeln!(out, "    ldp x20, x21, [sp], #16")?;
+    writeln!(out, "    ldp x29, x30, [sp], #16")?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+// Returns in x0 a new string holding the bytes of the NUL-terminated C
+// string at x0.
+fn emit_cstr_to_str_ptr<W: Write>(out: &mut W) -> Result<(), Error> {
+    writeln!(out, ".global cstr_to_str_ptr")?;
+    writeln!(out, "cstr_to_str_ptr:")?;
+    writeln!(out, "    stp x29, x30, [sp, #-16]! // save caller frame")?;
+    writeln!(out, "    mov x29, sp // establish frame")?;
+    writeln!(
+        out,
+        "    stp x20, x21, [sp, #-16]! // preserve callee-saved registers"
+    )?;
+    writeln!(out, "    mov x20, x0 // C string")?;
+    writeln!(out, "    mov x21, #0 // length so far")?;
+    writeln!(out, "cstr_to_str_ptr_len:")?;
+    writeln!(out, "    ldrb w9, [x20, x21]")?;
+    writeln!(out, "    cbz w9, cstr_to_str_ptr_alloc // terminator?")?;
+    writeln!(out, "    add x21, x21, #1")?;
+    writeln!(out, "    b cstr_to_str_ptr_len")?;
+    writeln!(out, "cstr_to_str_ptr_alloc:")?;
+    writeln!(out, "    mov x0, x21")?;
+    writeln!(out, "    bl alloc_str_ptr")?;
+    writeln!(out, "    mov x11, x0 // copy destination")?;
+    writeln!(out, "    mov x13, x20")?;
+    writeln!(out, "    mov x12, x21")?;
+    emit_copy_bytes(out, "cstr_to_str_ptr_copy")?;
+    writeln!(out, "    ldp x20, x21, [sp], #16")?;
+    writeln!(out, "    ldp x29, x30, [sp], #16")?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+// The words `_start` saves argc and argv into.
+fn emit_process_args<W: Write>(out: &mut W) -> Result<(), Error> {
+    writeln!(out, ".data")?;
+    writeln!(out, ".p2align 3")?;
+    writeln!(out, "{}:", PROCESS_ARGS_LABEL)?;
+    writeln!(out, "    .quad 0, 0")?;
+    writeln!(out, ".text")?;
+    Ok(())
+}
+
+// Sets x0 to -1, 0 or 1 as the string at x0 sorts before, equal to or after
+// the one at x1, byte by byte and then by length.
+fn emit_cmp_str_ptr<W: Write>(out: &mut W) -> Result<(), Error> {
+    writeln!(out, ".global cmp_str_ptr")?;
+    writeln!(out, "cmp_str_ptr:")?;
+    writeln!(out, "    ldr x9, [x0, #-{}] // left length", STR_LEN_OFFSET)?;
+    writeln!(
+        out,
+        "    ldr x10, [x1, #-{}] // right length",
+        STR_LEN_OFFSET
+    )?;
+    writeln!(out, "    cmp x9, x10")?;
+    writeln!(out, "    csel x11, x9, x10, ls // bytes both strings have")?;
+    writeln!(out, "cmp_str_ptr_loop:")?;
+    writeln!(out, "    cbz x11, cmp_str_ptr_lengths")?;
+    writeln!(out, "    ldrb w12, [x0], #1")?;
+    writeln!(out, "    ldrb w13, [x1], #1")?;
+    writeln!(out, "    cmp w12, w13")?;
+    writeln!(out, "    b.lo cmp_str_ptr_less")?;
+    writeln!(out, "    b.hi cmp_str_ptr_greater")?;
+    writeln!(out, "    sub x11, x11, #1")?;
+    writeln!(out, "    b cmp_str_ptr_loop")?;
+    writeln!(out, "cmp_str_ptr_lengths:")?;
+    writeln!(out, "    cmp x9, x10 // a prefix sorts first")?;
+    writeln!(out, "    b.lo cmp_str_ptr_less")?;
+    writeln!(out, "    b.hi cmp_str_ptr_greater")?;
+    writeln!(out, "    mov x0, #0")?;
+    writeln!(out, "    ret")?;
+    writeln!(out, "cmp_str_ptr_less:")?;
+    writeln!(out, "    mov x0, #-1")?;
+    writeln!(out, "    ret")?;
+    writeln!(out, "cmp_str_ptr_greater:")?;
+    writeln!(out, "    mov x0, #1")?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+fn emit_deepcopy_heap_ptr<W: Write>(out: &mut W) -> Result<(), Error> {
+    writeln!(out, ".global deepcopy_heap_ptr")?;
+    writeln!(out, "deepcopy_heap_ptr:")?;
+    writeln!(
+        out,
+        "    stp x29, x30, [sp, #-16]! // prologue: save executor frame pointer"
+    )?;
+    writeln!(out, "    mov x29, sp // prologue: establish new frame")?;
+    writeln!(
+        out,
+        "    stp x19, x20, [sp, #-16]! // preserve callee-saved registers"
+    )?;
+    writeln!(out, "    stp x21, x22, [sp, #-16]!")?;
+    writeln!(out, "    str x23, [sp, #-16]!")?;
+    writeln!(out, "    mov x19, x0 // capture env_end pointer")?;
+    writeln!(
+        out,
+        "    ldr x21, [x19, #{}] // load env size metadata",
+        ENV_METADATA_ENV_SIZE_OFFSET
+    )?;
+    writeln!(
+        out,
+        "    ldr x23, [x19, #{}] // load heap size metadata",
+        ENV_METADATA_HEAP_SIZE_OFFSET
+    )?;
+    writeln!(out, "    sub x20, x19, x21 // compute env base pointer")?;
+    writeln!(out, "    mov x0, x23 // length = heap size")?;
+    writeln!(out, "    bl alloc_heap_ptr // allocate new closure env")?;
+    writeln!(out, "    mov x22, x0 // new env base pointer")?;
+    writeln!(out, "    mov x1, x20 // memcpy src")?;
+    writeln!(out, "    mov x2, x23 // memcpy length")?;
+    writeln!(out, "    bl memcpy_helper // copy env contents")?;
+    writeln!(out, "    add x23, x22, x21 // new env_end pointer")?;
+    writeln!(
+        out,
+        "    ldr x9, [x23, #{}] // load deep copy helper entry",
+        ENV_METADATA_DEEP_COPY_OFFSET
+    )?;
+    writeln!(out, "    mov x0, x23 // pass new env_end pointer")?;
+    writeln!(out, "    blr x9 // invoke helper")?;
+    writeln!(out, "    mov x0, x23 // return new env_end pointer")?;
+    writeln!(out, "    ldr x23, [sp], #16")?;
+    writeln!(out, "    ldp x21, x22, [sp], #16")?;
+    writeln!(out, "    ldp x19, x20, [sp], #16")?;
+    writeln!(out, "    ldp x29, x30, [sp], #16")?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+fn emit_memcpy_helper<W: Write>(out: &mut W) -> Result<(), Error> {
+    writeln!(out, ".global memcpy_helper")?;
+    writeln!(out, "memcpy_helper:")?;
+    writeln!(out, "    mov x11, #0 // counter = 0")?;
+    writeln!(out, "internal_memcpy_loop:")?;
+    writeln!(out, "    cmp x11, x2 // counter < count?")?;
+    writeln!(out, "    b.ge internal_memcpy_done")?;
+    writeln!(out, "    ldr x9, [x1, x11] // load 8 bytes from source")?;
+    writeln!(out, "    str x9, [x0, x11] // store 8 bytes to destination")?;
+    writeln!(out, "    add x11, x11, #8 // advance counter by 8")?;
+    writeln!(out, "    b internal_memcpy_loop")?;
+    writeln!(out, "internal_memcpy_done:")?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+struct FrameLayout {
+    // Distance below x29 of each binding's slot.
+    bindings: HashMap<String, usize>,
+    stack_size: usize,
+}
+
+impl FrameLayout {
+    fn build(air: &AirFunction) -> Self {
+        let mut bindings = HashMap::new();
+        let mut next_offset = 0;
+        let names = air
+            .sig
+            .params
+            .iter()
+            .map(|param| param.name.as_str())
+            .chain(air.items.iter().filter_map(binding_name));
+        for name in names {
+            next_offset += WORD_SIZE;
+            bindings.insert(name.to_string(), next_offset);
+        }
+        Self {
+            bindings,
+            stack_size: next_offset.next_multiple_of(16),
+        }
+    }
+
+    fn slot(&self, name: &str) -> Result<usize, Error> {
+        self.bindings.get(name).copied().ok_or_else(|| {
+            Error::new(
+                Code::Codegen,
+                format!("unknown binding '{}'", name),
+                Span::unknown(),
+            )
+        })
+    }
+}
+
+fn binding_name(stmt: &AirStmt) -> Option<&str> {
+    match stmt.as_op() {
+        Some(AirOp::NewClosure(s)) => Some(&s.name),
+        Some(AirOp::CloneClosure(s)) => Some(&s.dst),
+        Some(AirOp::Field(field)) => Some(field.result.as_str()),
+        Some(AirOp::CopyField(field)) => Some(field.result.as_str()),
+        Some(AirOp::CopyStr(copy)) => Some(copy.dst.as_str()),
+        Some(AirOp::NewArray(array)) => Some(array.name.as_str()),
+        Some(AirOp::CopyArray(copy)) => Some(copy.dst.as_str()),
+        Some(AirOp::NewMap(map)) => Some(map.name.as_str()),
+        Some(AirOp::CopyMap(copy)) => Some(copy.dst.as_str()),
+        Some(AirOp::CopyChan(copy)) => Some(copy.dst.as_str()),
+        _ => None,
+    }
+}
+
+struct FunctionEmitter<'a, W: Write> {
+    air: AirFunction,
+    out: &'a mut W,
+    frame: FrameLayout,
+    terminated: bool,
+    label_counter: usize,
+    save_process_args: bool,
+}
+
+impl<'a, W: Write> FunctionEmitter<'a, W> {
+    fn new(air: AirFunction, out: &'a mut W, frame: FrameLayout) -> Self {
+        Self {
+            air,
+            out,
+            frame,
+            terminated: false,
+            label_counter: 0,
+            save_process_args: false,
+        }
+    }
+
+    fn emit_function(&mut self) -> Result<(), Error> {
+        writeln!(self.out, ".global {}", self.air.sig.name)?;
+        writeln!(self.out, "{}:", self.air.sig.name)?;
+        writeln!(
+            self.out,
+            "    stp x29, x30, [sp, #-16]! // save executor frame pointer"
+        )?;
+        writeln!(self.out, "    mov x29, sp // establish new frame base")?;
+        if self.frame.stack_size > 0 {
+            self.adjust_sp(
+                "sub",
+                self.frame.stack_size,
+                "reserve stack space for locals",
+            )?;
+        }
+        if self.save_process_args {
+            self.emit_save_process_args()?;
+        }
+        self.store_params()?;
+        self.emit_block()
+    }
+
+    /// Saves the argc and argv the kernel left above `_start`'s frame record.
+    fn emit_save_process_args(&mut self) -> Result<(), Error> {
+        emit_label_address(self.out, "x11", PROCESS_ARGS_LABEL)?;
+        writeln!(
+            self.out,
+            "    ldr x9, [x29, #{}] // argc",
+            FRAME_RECORD_SIZE
+        )?;
+        writeln!(
+            self.out,
+            "    add x10, x29, #{} // argv",
+            FRAME_RECORD_SIZE + WORD_SIZE
+        )?;
+        writeln!(self.out, "    stp x9, x10, [x11]")?;
+        Ok(())
+    }
+
+    fn store_params(&mut self) -> Result<(), Error> {
+        let params: Vec<String> = self
+            .air
+            .sig
+            .params
+            .iter()
+            .map(|param| param.name.clone())
+            .collect();
+        for (idx, name) in params.iter().enumerate() {
+            let slot = self.frame.slot(name)?;
+            if idx < ARG_REGS.len() {
+                self.store_slot(ARG_REGS[idx], slot, &format!("store {} arg in frame", name))?;
+            } else {
+                // Spilled arguments sit right above the saved frame record.
+                let offset = FRAME_RECORD_SIZE + (idx - ARG_REGS.len()) * WORD_SIZE;
+                self.access(
+                    "ldr",
+                    "x9",
+                    "x29",
+                    offset as i64,
+                    &format!("load spilled {} arg", name),
+                )?;
+                self.store_slot("x9", slot, "store spilled arg")?;
+            }
+        }
+        Ok(())
+    }
+
+    fn emit_block(&mut self) -> Result<(), Error> {
+        let statements = self.air.items.clone();
+        for stmt in statements {
+            if self.terminated {
+                if let AirStmt::Label(_) = stmt {
+                    self.terminated = false;
+                } else {
+                    continue;
+                }
+            }
+            match &stmt {
+                AirStmt::Label(label) => self.emit_label(label)?,
+                AirStmt::Op(op) => self.emit_air_op(op.as_ref())?,
+            }
+        }
+        Ok(())
+    }
+
+    fn emit_air_op(&mut self, op: &AirOp) -> Result<(), Error> {
+        match op {
+            AirOp::NewClosure(closure) => {
+                self.emit_new_closure(closure)?;
+                self.store_binding_value(&closure.name)
+            }
+            AirOp::CloneClosure(clone) => {
+                self.emit_clone_closure(clone)?;
+                self.store_binding_value(&clone.dst)
+            }
+            AirOp::Jump(jump) => self.emit_jump(jump),
+            AirOp::JumpEqInt(eq) => self.emit_eq_int_jump(eq),
+            AirOp::JumpEqStr(eq) => self.emit_eq_str_jump(eq),
+            AirOp::JumpCmpStr(cmp) => self.emit_cmp_str_jump(cmp),
+            AirOp::JumpLt(jump) => self.emit_lt_jump(jump),
+            AirOp::JumpGt(jump) => self.emit_gt_jump(jump),
+            AirOp::JumpTable(jump) => self.emit_jump_table(jump),
+            AirOp::JumpEqF64(jump) => self.emit_float_compare_jump(jump, "b.eq"),
+            AirOp::JumpLtF64(jump) => self.emit_float_compare_jump(jump, "b.mi"),
+            AirOp::JumpGtF64(jump) => self.emit_float_compare_jump(jump, "b.gt"),
+            AirOp::ReleaseHeap(release) => self.emit_release_heap_ptr(&release.name),
+            AirOp::ReleaseStr(release) => self.emit_release_str_ptr(&release.name),
+            AirOp::CopyStr(copy) => self.emit_copy_str(copy),
+            AirOp::NewArray(array) => self.emit_new_array(array),
+            AirOp::ReleaseArray(release) => self.emit_release_array_ptr(&release.name),
+            AirOp::CopyArray(copy) => self.emit_copy_array(copy),
+            AirOp::ArrayLen(op) => self.emit_array_len(op),
+            AirOp::ArrayGet(op) => self.emit_array_get(op),
+            AirOp::ArraySet(op) => self.emit_array_set(op),
+            AirOp::NewMap(map) => self.emit_new_map(map),
+            AirOp::ReleaseMap(release) => self.emit_release_map_ptr(&release.name),
+            AirOp::CopyMap(copy) => self.emit_copy_map(copy),
+            AirOp::ReleaseSum(release) => self.emit_release_closure(&release.name),
+            AirOp::MapInsert(op) => self.emit_map_insert(op),
+            AirOp::MapLookup(op) => self.emit_map_lookup(op),
+            AirOp::MapRemove(op) => self.emit_map_remove(op),
+            AirOp::MapLen(op) => self.emit_map_len(op),
+            AirOp::MapNext(op) => self.emit_map_next(op),
+            AirOp::ReleaseChan(release) => self.emit_release_chan_ptr(&release.name),
+            AirOp::CopyChan(copy) => self.emit_copy_chan(copy),
+            AirOp::NewChan(op) => self.emit_new_chan(op),
+            AirOp::ChanSend(op) => self.emit_chan_send(op),
+            AirOp::ChanRecv(op) => self.emit_chan_recv(op),
+            AirOp::Spawn(op) => self.emit_spawn(op),
+            AirOp::Thread(op) => self.emit_thread(op),
+            AirOp::Yield(op) => self.emit_yield(op),
+            AirOp::Done(_) => self.emit_sched_next(),
+            AirOp::Pin(pin) => self.emit_pin(pin),
+            AirOp::Field(field) => self.emit_get_field(field),
+            AirOp::SetField(set) => self.emit_set_field(set),
+            AirOp::CopyField(field) => self.emit_copy_field(field),
+            AirOp::Add(op) => self.emit_add(op),
+            AirOp::Sub(op) => self.emit_sub(op),
+            AirOp::Mul(op) => self.emit_mul(op),
+            AirOp::DivInt(op) => self.emit_division(op, false),
+            AirOp::RemInt(op) => self.emit_division(op, true),
+            AirOp::And(op) => {
+                self.emit_binary_op(&op.input_a, &op.input_b, &op.target, "and", "bitwise and")
+            }
+            AirOp::Or(op) => {
+                self.emit_binary_op(&op.input_a, &op.input_b, &op.target, "orr", "bitwise or")
+            }
+            AirOp::Xor(op) => {
+                self.emit_binary_op(&op.input_a, &op.input_b, &op.target, "eor", "bitwise xor")
+            }
+            AirOp::Shl(op) => {
+                self.emit_binary_op(&op.input_a, &op.input_b, &op.target, "lsl", "shift left")
+            }
+            AirOp::Shr(op) => self.emit_binary_op(
+                &op.input_a,
+                &op.input_b,
+                &op.target,
+                "asr",
+                "shift right keeping the sign",
+            ),
+            AirOp::Neg(op) => self.emit_neg(op),
+            AirOp::AddChecked(op) => self.emit_checked_op(op, "adds", "add second integer"),
+            AirOp::SubChecked(op) => self.emit_checked_op(op, "subs", "subtract subtrahend"),
+            AirOp::MulChecked(op) => self.emit_checked_mul(op),
+            AirOp::AddF64(op) => self.emit_add_f64(op),
+            AirOp::SubF64(op) => self.emit_sub_f64(op),
+            AirOp::MulF64(op) => self.emit_mul_f64(op),
+            AirOp::DivF64(op) => self.emit_div_f64(op),
+            AirOp::NegF64(op) => {
+                self.emit_float_unary_op(&op.input, &op.target, "fneg", "flip the sign")
+            }
+            AirOp::SqrtF64(op) => {
+                self.emit_float_unary_op(&op.input, &op.target, "fsqrt", "square root")
+            }
+            AirOp::IntToF64(op) => self.emit_int_to_f64(op),
+            AirOp::F64ToInt(op) => self.emit_f64_to_int(op),
+            AirOp::StrLen(op) => self.emit_strlen(op),
+            AirOp::Concat(op) => self.emit_concat(op),
+            AirOp::Slice(op) => self.emit_slice(op),
+            AirOp::ByteAt(op) => self.emit_byte_at(op),
+            AirOp::Printf(op) => self.emit_libc_op(
+                builtins::Builtin::Printf,
+                &op.args,
+                &op.arg_kinds,
+                &op.target,
+                &op.release,
+            ),
+            AirOp::Sprintf(op) => self.emit_libc_op(
+                builtins::Builtin::Sprintf,
+                &op.args,
+                &op.arg_kinds,
+                &op.target,
+                &op.release,
+            ),
+            AirOp::Write(op) => self.emit_libc_op(
+                builtins::Builtin::Write,
+                &op.args,
+                &op.arg_kinds,
+                &op.target,
+                &op.release,
+            ),
+            AirOp::WriteFd(op) => self.emit_libc_op(
+                builtins::Builtin::WriteFd,
+                &op.args,
+                &op.arg_kinds,
+                &op.target,
+                &op.release,
+            ),
+            AirOp::Read(op) => self.emit_read(op),
+            AirOp::Open(op) => self.emit_open(op),
+            AirOp::Close(op) => self.emit_close(op),
+            AirOp::ArgCount(op) => self.emit_arg_count(op),
+            AirOp::ArgAt(op) => self.emit_arg_at(op),
+            AirOp::Getenv(op) => self.emit_getenv(op),
+            AirOp::CallPtr(call) => self.emit_call_ptr(call),
+            AirOp::SysExit(syscall) => self.emit_exit_syscall(syscall),
+            AirOp::JumpArgs(call) => self.emit_jump_args(call),
+            AirOp::JumpClosure(jump) => self.emit_jump_closure(jump),
+            AirOp::Return(ret) => self.emit_return(ret),
+        }
+    }
+
+    fn emit_pin(&mut self, pin: &AirPin) -> Result<(), Error> {
+        self.load_value_into_reg(&pin.value, CLOSURE_ENV_REG)
+    }
+
+    fn emit_set_field(&mut self, set: &air::AirSetField) -> Result<(), Error> {
+        self.load_arg_into_reg(&set.value, "x11")?;
+        self.store_field(CLOSURE_ENV_REG, set.offset, "x11")
+    }
+
+    fn emit_clone_closure(&mut self, clone: &air::AirCloneClosure) -> Result<(), Error> {
+        let slot = self.frame.slot(&clone.src)?;
+        self.load_slot(
+            "x20",
+            slot,
+            &format!(
+                "original closure {} to {} env_end pointer for clone",
+                clone.src, clone.dst
+            ),
+        )?;
+        self.emit_clone_env_from_env_end("x20", CLOSURE_ENV_REG)?;
+        writeln!(
+            self.out,
+            "    mov x9, {} // copy cloned env_end pointer",
+            CLOSURE_ENV_REG
+        )?;
+        Ok(())
+    }
+
+    fn emit_eq_int_jump(&mut self, eq: &AirJumpEq) -> Result<(), Error> {
+        if eq.args.len() < 2 {
+            return Err(Error::new(
+                Code::Codegen,
+                "eq builtin requires two arguments",
+                Span::unknown(),
+            ));
+        }
+        self.load_arg_into_reg(&eq.args[0], "x9")?;
+        self.load_arg_into_reg(&eq.args[1], "x20")?;
+        writeln!(self.out, "    cmp x9, x20")?;
+        writeln!(self.out, "    b.eq {}", eq.target)?;
+        Ok(())
+    }
+
+    fn emit_eq_str_jump(&mut self, eq: &AirJumpEq) -> Result<(), Error> {
+        if eq.args.len() < 2 {
+            return Err(Error::new(
+                Code::Codegen,
+                "eqs builtin requires two arguments",
+                Span::unknown(),
+            ));
+        }
+        let false_label = self.new_label("eqs_false");
+        let loop_label = self.new_label("eqs_loop");
+        self.load_arg_into_reg(&eq.args[0], "x14")?;
+        self.load_arg_into_reg(&eq.args[1], "x15")?;
+        writeln!(
+            self.out,
+            "    ldr x9, [x14, #-{}] // first string length",
+            STR_LEN_OFFSET
+        )?;
+        writeln!(
+            self.out,
+            "    ldr x12, [x15, #-{}] // second string length",
+            STR_LEN_OFFSET
+        )?;
+        writeln!(self.out, "    cmp x9, x12")?;
+        writeln!(self.out, "    b.ne {} // lengths differ", false_label)?;
+        writeln!(self.out, "{}:", loop_label)?;
+        writeln!(self.out, "    cbz x9, {} // every byte matched", eq.target)?;
+        writeln!(self.out, "    ldrb w10, [x14]")?;
+        writeln!(self.out, "    ldrb w12, [x15]")?;
+        writeln!(self.out, "    cmp w10, w12")?;
+        writeln!(self.out, "    b.ne {} // bytes differ", false_label)?;
+        writeln!(self.out, "    sub x9, x9, #1")?;
+        writeln!(self.out, "    add x14, x14, #1")?;
+        writeln!(self.out, "    add x15, x15, #1")?;
+        writeln!(self.out, "    b {}", loop_label)?;
+        writeln!(self.out, "{}:", false_label)?;
+        Ok(())
+    }
+
+    fn emit_read(&mut self, op: &AirRead) -> Result<(), Error> {
+        let size_ok_label = self.new_label("read_size_ok");
+        let eof_label = self.new_label("read_eof");
+        let err_label = self.new_label("read_err");
+        let buffer_ok_label = self.new_label("read_buffer_ok");
+        self.load_arg_into_reg(&op.fd, "x22")?;
+        self.load_arg_into_reg(&op.max, "x21")?;
+        writeln!(self.out, "    cmp x21, #0 // negative size?")?;
+        writeln!(self.out, "    b.ge {}", size_ok_label)?;
+        writeln!(self.out, "    mov x9, #{} // EINVAL", EINVAL)?;
+        self.emit_releases(&[], &[&op.ok_target, &op.eof_target], true)?;
+        self.emit_value_jump(&op.err_target, true)?;
+
+        writeln!(self.out, "{}:", size_ok_label)?;
+        writeln!(self.out, "    mov x0, x21")?;
+        writeln!(
+            self.out,
+            "    bl {} // buffer for the bytes",
+            AirRuntimeHelper::AllocStrPtr.name()
+        )?;
+        writeln!(
+            self.out,
+            "    cbnz x0, {} // out of memory?",
+            buffer_ok_label
+        )?;
+        writeln!(self.out, "    mov x9, #{} // ENOMEM", ENOMEM)?;
+        self.emit_releases(&[], &[&op.ok_target, &op.eof_target], true)?;
+        self.emit_value_jump(&op.err_target, true)?;
+
+        writeln!(self.out, "{}:", buffer_ok_label)?;
+        writeln!(self.out, "    mov x23, x0")?;
+        writeln!(self.out, "    mov x0, x22 // fd")?;
+        writeln!(self.out, "    mov x1, x23 // buffer start")?;
+        writeln!(self.out, "    mov x2, x21 // most bytes to read")?;
+        writeln!(self.out, "    bl read // invoke libc read")?;
+        writeln!(self.out, "    cmp x0, #0")?;
+        writeln!(self.out, "    b.lt {}", err_label)?;
+        writeln!(self.out, "    b.eq {}", eof_label)?;
+        writeln!(
+            self.out,
+            "    str x0, [x23, #-{}] // length header",
+            STR_LEN_OFFSET
+        )?;
+        writeln!(self.out, "    strb wzr, [x23, x0] // terminator")?;
+        writeln!(self.out, "    mov x9, x23")?;
+        self.emit_releases(&[], &[&op.eof_target, &op.err_target], true)?;
+        self.emit_value_jump(&op.ok_target, true)?;
+
+        writeln!(self.out, "{}:", eof_label)?;
+        self.emit_release_read_buffer()?;
+        self.emit_releases(&[], &[&op.ok_target, &op.err_target], false)?;
+        self.emit_value_jump(&op.eof_target, false)?;
+
+        writeln!(self.out, "{}:", err_label)?;
+        self.emit_load_errno()?;
+        writeln!(self.out, "    mov x21, x9 // keep errno across the release")?;
+        self.emit_release_read_buffer()?;
+        writeln!(self.out, "    mov x9, x21")?;
+        self.emit_releases(&[], &[&op.ok_target, &op.eof_target], true)?;
+        self.emit_value_jump(&op.err_target, true)
+    }
+
+    fn emit_release_read_buffer(&mut self) -> Result<(), Error> {
+        writeln!(self.out, "    mov x0, x23")?;
+        writeln!(
+            self.out,
+            "    bl {} // release unused read buffer",
+            AirRuntimeHelper::ReleaseStrPtr.name()
+        )?;
+        Ok(())
+    }
+
+    fn emit_open(&mut self, op: &AirOpen) -> Result<(), Error> {
+        let err_label = self.new_label("open_err");
+        self.load_arg_into_reg(&op.path, "x0")?;
+        self.load_arg_into_reg(&op.flags, "x1")?;
+        self.load_arg_into_reg(&op.mode, "x2")?;
+        writeln!(self.out, "    bl open // invoke libc open")?;
+        writeln!(self.out, "    cmp w0, #0")?;
+        writeln!(self.out, "    b.lt {}", err_label)?;
+        writeln!(self.out, "    mov w9, w0 // zero-extend the fd")?;
+        self.emit_releases(&op.release, &[&op.err_target], true)?;
+        self.emit_value_jump(&op.ok_target, true)?;
+
+        writeln!(self.out, "{}:", err_label)?;
+        self.emit_load_errno()?;
+        self.emit_releases(&op.release, &[&op.ok_target], true)?;
+        self.emit_value_jump(&op.err_target, true)
+    }
+
+    fn emit_close(&mut self, op: &AirClose) -> Result<(), Error> {
+        let err_label = self.new_label("close_err");
+        self.load_arg_into_reg(&op.fd, "x0")?;
+        writeln!(self.out, "    bl close // invoke libc close")?;
+        writeln!(self.out, "    cmp w0, #0")?;
+        writeln!(self.out, "    b.lt {}", err_label)?;
+        self.emit_releases(&[], &[&op.err_target], false)?;
+        self.emit_value_jump(&op.ok_target, false)?;
+
+        writeln!(self.out, "{}:", err_label)?;
+        self.emit_load_errno()?;
+        self.emit_releases(&[], &[&op.ok_target], true)?;
+        self.emit_value_jump(&op.err_target, true)
+    }
+
+    fn emit_arg_count(&mut self, op: &AirArgCount) -> Result<(), Error> {
+        emit_label_address(self.out, "x11", PROCESS_ARGS_LABEL)?;
+        writeln!(self.out, "    ldr x9, [x11] // argc")?;
+        self.emit_value_jump(&op.target, true)
+    }
+
+    fn emit_arg_at(&mut self, op: &AirArgAt) -> Result<(), Error> {
+        let none_label = self.new_label("arg_none");
+        self.load_arg_into_reg(&op.index, "x1")?;
+        emit_label_address(self.out, "x11", PROCESS_ARGS_LABEL)?;
+        writeln!(self.out, "    cmp x1, #0 // index before the arguments?")?;
+        writeln!(self.out, "    b.lt {}", none_label)?;
+        writeln!(self.out, "    ldr x12, [x11] // argc")?;
+        writeln!(self.out, "    cmp x1, x12 // index past the arguments?")?;
+        writeln!(self.out, "    b.ge {}", none_label)?;
+        writeln!(self.out, "    ldr x12, [x11, #8] // argv")?;
+        writeln!(self.out, "    ldr x0, [x12, x1, lsl #3]")?;
+        writeln!(
+            self.out,
+            "    bl {} // copy the argument",
+            AirRuntimeHelper::CStrToStrPtr.name()
+        )?;
+        writeln!(self.out, "    mov x9, x0")?;
+        self.emit_releases(&[], &[&op.none_target], true)?;
+        self.emit_value_jump(&op.one_target, true)?;
+
+        writeln!(self.out, "{}:", none_label)?;
+        self.emit_releases(&[], &[&op.one_target], false)?;
+        self.emit_value_jump(&op.none_target, false)
+    }
+
+    fn emit_getenv(&mut self, op: &AirGetenv) -> Result<(), Error> {
+        let missing_label = self.new_label("getenv_missing");
+        self.load_arg_into_reg(&op.name, "x0")?;
+        writeln!(self.out, "    bl getenv // invoke libc getenv")?;
+        writeln!(self.out, "    cbz x0, {}", missing_label)?;
+        writeln!(
+            self.out,
+            "    bl {} // copy the value",
+            AirRuntimeHelper::CStrToStrPtr.name()
+        )?;
+        writeln!(self.out, "    mov x9, x0")?;
+        self.emit_releases(&op.release, &[&op.missing_target], true)?;
+        self.emit_value_jump(&op.found_target, true)?;
+
+        writeln!(self.out, "{}:", missing_label)?;
+        self.emit_releases(&op.release, &[&op.found_target], false)?;
+        self.emit_value_jump(&op.missing_target, false)
+    }
+
+    /// Loads the errno a failed libc call left into x9.
+    fn emit_load_errno(&mut self) -> Result<(), Error> {
+        writeln!(self.out, "    bl {ERRNO_LOCATION}")?;
+        writeln!(self.out, "    ldr w9, [x0] // errno")?;
+        Ok(())
+    }
+
+    fn emit_cmp_str_jump(&mut self, cmp: &AirJumpCmpStr) -> Result<(), Error> {
+        if cmp.args.len() < 2 {
+            return Err(Error::new(
+                Code::Codegen,
+                "cmps builtin requires two arguments",
+                Span::unknown(),
+            ));
+        }
+        self.load_arg_into_reg(&cmp.args[0], "x0")?;
+        self.load_arg_into_reg(&cmp.args[1], "x1")?;
+        writeln!(
+            self.out,
+            "    bl {} // order the strings",
+            AirRuntimeHelper::CmpStrPtr.name()
+        )?;
+        writeln!(self.out, "    cmp x0, #0")?;
+        writeln!(self.out, "    b.lt {}", cmp.lt_target)?;
+        writeln!(self.out, "    b.gt {}", cmp.gt_target)?;
+        Ok(())
+    }
+
+    fn emit_lt_jump(&mut self, jump: &AirJumpLt) -> Result<(), Error> {
+        self.emit_compare_jump(&jump.left, &jump.right, "b.lt", &jump.target)
+    }
+
+    fn emit_gt_jump(&mut self, jump: &AirJumpGt) -> Result<(), Error> {
+        self.emit_compare_jump(&jump.left, &jump.right, "b.gt", &jump.target)
+    }
+
+    /// Each entry of the table is a single 4-byte `b`.
+    fn emit_jump_table(&mut self, jump: &AirJumpTable) -> Result<(), Error> {
+        let table = self.new_label("jump_table");
+        self.load_value_into_reg(&jump.index, "x9")?;
+        writeln!(self.out, "    adr x10, {} // load jump table", table)?;
+        writeln!(
+            self.out,
+            "    add x10, x10, x9, lsl #2 // address of the entry"
+        )?;
+        writeln!(self.out, "    br x10")?;
+        writeln!(self.out, "{}:", table)?;
+        for target in &jump.targets {
+            writeln!(self.out, "    b {}", target)?;
+        }
+        Ok(())
+    }
+
+    fn emit_compare_jump(
+        &mut self,
+        left: &AirValue,
+        right: &AirValue,
+        branch: &str,
+        target: &str,
+    ) -> Result<(), Error> {
+        self.load_value_into_reg(left, "x9")?;
+        self.load_value_into_reg(right, "x20")?;
+        writeln!(self.out, "    cmp x9, x20")?;
+        writeln!(self.out, "    {} {}", branch, target)?;
+        Ok(())
+    }
+
+    /// Unordered operands clear Z and N and set V, so NaN fails `b.eq`,
+    /// `b.mi` and `b.gt` alike and falls through.
+    fn emit_float_compare_jump(&mut self, jump: &AirJumpCmpF64, branch: &str) -> Result<(), Error> {
+        self.load_arg_into_float(&jump.input_a, "d0")?;
+        self.load_arg_into_float(&jump.input_b, "d1")?;
+        writeln!(self.out, "    fcmp d0, d1")?;
+        writeln!(self.out, "    {} {}", branch, jump.target)?;
+        Ok(())
+    }
+
+    fn emit_add(&mut self, op: &AirAdd) -> Result<(), Error> {
+        self.emit_binary_op(
+            &op.input_a,
+            &op.input_b,
+            &op.target,
+            "add",
+            "add second integer",
+        )
+    }
+
+    fn emit_sub(&mut self, op: &AirSub) -> Result<(), Error> {
+        self.emit_binary_op(
+            &op.input_a,
+            &op.input_b,
+            &op.target,
+            "sub",
+            "subtract subtrahend",
+        )
+    }
+
+    fn emit_mul(&mut self, op: &AirMul) -> Result<(), Error> {
+        self.emit_binary_op(
+            &op.input_a,
+            &op.input_b,
+            &op.target,
+            "mul",
+            "multiply by multiplier",
+        )
+    }
+
+    /// Divides, or takes the error continuation for a zero divisor or for
+    /// the one quotient that overflows, `i64::MIN / -1`. `sdiv` itself never
+    /// traps, and `msub` gives the remainder 0 in that case.
+    fn emit_division(&mut self, op: &AirDivInt, remainder: bool) -> Result<(), Error> {
+        let err_label = self.new_label("div_err");
+        self.load_arg_into_reg(&op.input_a, "x9")?;
+        self.load_arg_into_reg(&op.input_b, "x20")?;
+        writeln!(
+            self.out,
+            "    cbz x20, {} // check divisor for division by zero",
+            err_label
+        )?;
+        if remainder {
+            writeln!(self.out, "    sdiv x10, x9, x20 // divide by divisor")?;
+            writeln!(self.out, "    msub x9, x10, x20, x9 // keep the remainder")?;
+        } else {
+            let divide_label = self.new_label("div_divide");
+            writeln!(self.out, "    cmn x20, #1")?;
+            writeln!(self.out, "    b.ne {}", divide_label)?;
+            self.load_imm("x10", i64::MIN, "smallest int")?;
+            writeln!(self.out, "    cmp x9, x10")?;
+            writeln!(self.out, "    b.eq {} // its negation overflows", err_label)?;
+            writeln!(self.out, "{}:", divide_label)?;
+            writeln!(self.out, "    sdiv x9, x9, x20 // divide by divisor")?;
+        }
+        self.emit_releases(&[], &[&op.err_target], true)?;
+        self.emit_value_jump(&op.ok_target, true)?;
+
+        writeln!(self.out, "{}:", err_label)?;
+        self.emit_release_closure(&op.ok_target)?;
+        self.emit_value_jump(&op.err_target, false)
+    }
+
+    fn emit_neg(&mut self, op: &AirNeg) -> Result<(), Error> {
+        self.load_arg_into_reg(&op.input, "x9")?;
+        writeln!(self.out, "    neg x9, x9 // negate")?;
+        self.emit_value_jump(&op.target, true)
+    }
+
+    /// Passes the wrapped result to the overflow continuation when the
+    /// flag-setting form of the operation sets V.
+    fn emit_checked_op(
+        &mut self,
+        op: &AirChecked,
+        opcode: &str,
+        comment: &str,
+    ) -> Result<(), Error> {
+        let overflow_label = self.new_label("overflow");
+        self.load_arg_into_reg(&op.input_a, "x9")?;
+        self.load_arg_into_reg(&op.input_b, "x20")?;
+        writeln!(self.out, "    {} x9, x9, x20 // {}", opcode, comment)?;
+        writeln!(self.out, "    b.vs {}", overflow_label)?;
+        self.emit_checked_exits(op, &overflow_label)
+    }
+
+    /// `mul` sets no flags, so the product overflowed when its high half is
+    /// not the sign extension of the low half.
+    fn emit_checked_mul(&mut self, op: &AirChecked) -> Result<(), Error> {
+        let overflow_label = self.new_label("overflow");
+        self.load_arg_into_reg(&op.input_a, "x9")?;
+        self.load_arg_into_reg(&op.input_b, "x20")?;
+        writeln!(
+            self.out,
+            "    smulh x10, x9, x20 // high half of the product"
+        )?;
+        writeln!(self.out, "    mul x9, x9, x20 // multiply by multiplier")?;
+        writeln!(self.out, "    cmp x10, x9, asr #63")?;
+        writeln!(self.out, "    b.ne {}", overflow_label)?;
+        self.emit_checked_exits(op, &overflow_label)
+    }
+
+    fn emit_checked_exits(&mut self, op: &AirChecked, overflow_label: &str) -> Result<(), Error> {
+        self.emit_releases(&[], &[&op.overflow_target], true)?;
+        self.emit_value_jump(&op.ok_target, true)?;
+
+        writeln!(self.out, "{}:", overflow_label)?;
+        self.emit_releases(&[], &[&op.ok_target], true)?;
+        self.emit_value_jump(&op.overflow_target, true)
+    }
+
+    fn emit_add_f64(&mut self, op: &AirAddF64) -> Result<(), Error> {
+        self.emit_float_binary_op(
+            &op.input_a,
+            &op.input_b,
+            &op.target,
+            "fadd",
+            "add second float",
+        )
+    }
+
+    fn emit_sub_f64(&mut self, op: &AirSubF64) -> Result<(), Error> {
+        self.emit_float_binary_op(
+            &op.input_a,
+            &op.input_b,
+            &op.target,
+            "fsub",
+            "subtract subtrahend float",
+        )
+    }
+
+    fn emit_mul_f64(&mut self, op: &AirMulF64) -> Result<(), Error> {
+        self.emit_float_binary_op(
+            &op.input_a,
+            &op.input_b,
+            &op.target,
+            "fmul",
+            "multiply by multiplier float",
+        )
+    }
+
+    fn emit_div_f64(&mut self, op: &AirDivF64) -> Result<(), Error> {
+        self.emit_float_binary_op(
+            &op.input_a,
+            &op.input_b,
+            &op.target,
+            "fdiv",
+            "divide by divisor float",
+        )
+    }
+
+    fn emit_int_to_f64(&mut self, op: &AirIntToF64) -> Result<(), Error> {
+        self.load_arg_into_reg(&op.input, "x9")?;
+        writeln!(self.out, "    scvtf d0, x9 // convert int to float")?;
+        writeln!(self.out, "    fmov x9, d0 // move float result to x9")?;
+        self.emit_value_jump(&op.target, true)
+    }
+
+    /// `fcvtzs` saturates and turns NaN into 0, so the range is checked first:
+    /// -2^63 is exact and 2^63 is the first float past the largest int.
+    fn emit_f64_to_int(&mut self, op: &AirF64ToInt) -> Result<(), Error> {
+        let err_label = self.new_label("ftoi_err");
+        let limit = -(i64::MIN as f64);
+        self.load_arg_into_float(&op.input, "d0")?;
+        self.load_imm("x10", (-limit).to_bits() as i64, "smallest int as a float")?;
+        writeln!(self.out, "    fmov d1, x10")?;
+        writeln!(self.out, "    fcmp d0, d1")?;
+        writeln!(
+            self.out,
+            "    b.lt {} // below the range, or NaN",
+            err_label
+        )?;
+        self.load_imm("x10", limit.to_bits() as i64, "first float past the range")?;
+        writeln!(self.out, "    fmov d1, x10")?;
+        writeln!(self.out, "    fcmp d0, d1")?;
+        writeln!(self.out, "    b.ge {}", err_label)?;
+        writeln!(self.out, "    fcvtzs x9, d0 // truncate toward zero")?;
+        self.emit_releases(&[], &[&op.err_target], true)?;
+        self.emit_value_jump(&op.ok_target, true)?;
+
+        writeln!(self.out, "{}:", err_label)?;
+        self.emit_release_closure(&op.ok_target)?;
+        self.emit_value_jump(&op.err_target, false)
+    }
+
+    fn emit_binary_op(
+        &mut self,
+        input_a: &AirArg,
+        input_b: &AirArg,
+        target: &str,
+        opcode: &str,
+        comment: &str,
+    ) -> Result<(), Error> {
+        self.load_arg_into_reg(input_a, "x9")?;
+        self.load_arg_into_reg(input_b, "x20")?;
+        writeln!(self.out, "    {} x9, x9, x20 // {}", opcode, comment)?;
+        self.emit_value_jump(target, true)
+    }
+
+    fn emit_float_binary_op(
+        &mut self,
+        input_a: &AirArg,
+        input_b: &AirArg,
+        target: &str,
+        opcode: &str,
+        comment: &str,
+    ) -> Result<(), Error> {
+        self.load_arg_into_float(input_a, "d0")?;
+        self.load_arg_into_float(input_b, "d1")?;
+        writeln!(self.out, "    {} d0, d0, d1 // {}", opcode, comment)?;
+        writeln!(self.out, "    fmov x9, d0 // move float result to x9")?;
+        self.emit_value_jump(target, true)
+    }
+
+    fn emit_float_unary_op(
+        &mut self,
+        input: &AirArg,
+        target: &str,
+        opcode: &str,
+        comment: &str,
+    ) -> Result<(), Error> {
+        self.load_arg_into_float(input, "d0")?;
+        writeln!(self.out, "    {} d0, d0 // {}", opcode, comment)?;
+        writeln!(self.out, "    fmov x9, d0 // move float result to x9")?;
+        self.emit_value_jump(target, true)
+    }
+
+    fn emit_libc_op(
+        &mut self,
+        builtin: builtins::Builtin,
+        args: &[AirArg],
+        arg_kinds: &[SigKind],
+        target: &str,
+        release: &[String],
+    ) -> Result<(), Error> {
+        let has_result = self.emit_libc_call(builtin, args, arg_kinds)?;
+        self.emit_releases(release, &[], has_result)?;
+        self.emit_value_jump(target, has_result)
+    }
+
+    /// Releases the strings an op consumed and the continuations it does not
+    /// take, keeping the result in x9 when there is one.
+    fn emit_strlen(&mut self, op: &AirStrLen) -> Result<(), Error> {
+        self.load_arg_into_reg(&op.input, "x9")?;
+        writeln!(
+            self.out,
+            "    ldr x9, [x9, #-{}] // string length",
+            STR_LEN_OFFSET
+        )?;
+        self.emit_releases(&op.release, &[], true)?;
+        self.emit_value_jump(&op.target, true)
+    }
+
+    fn emit_concat(&mut self, op: &AirConcat) -> Result<(), Error> {
+        self.load_arg_into_reg(&op.input_a, "x0")?;
+        self.load_arg_into_reg(&op.input_b, "x1")?;
+        writeln!(
+            self.out,
+            "    bl {} // join the strings",
+            AirRuntimeHelper::ConcatStrPtr.name()
+        )?;
+        writeln!(self.out, "    mov x9, x0")?;
+        self.emit_releases(&op.release, &[], true)?;
+        self.emit_value_jump(&op.target, true)
+    }
+
+    fn emit_slice(&mut self, op: &AirSlice) -> Result<(), Error> {
+        let err_label = self.new_label("slice_err");
+        self.load_arg_into_reg(&op.input, "x0")?;
+        self.load_arg_into_reg(&op.start, "x1")?;
+        self.load_arg_into_reg(&op.end, "x2")?;
+        writeln!(self.out, "    cmp x1, #0 // start before the string?")?;
+        writeln!(self.out, "    b.lt {}", err_label)?;
+        writeln!(self.out, "    cmp x2, x1 // end before start?")?;
+        writeln!(self.out, "    b.lt {}", err_label)?;
+        writeln!(
+            self.out,
+            "    ldr x10, [x0, #-{}] // string length",
+            STR_LEN_OFFSET
+        )?;
+        writeln!(self.out, "    cmp x2, x10 // end past the string?")?;
+        writeln!(self.out, "    b.gt {}", err_label)?;
+        writeln!(
+            self.out,
+            "    bl {} // copy the range",
+            AirRuntimeHelper::SliceStrPtr.name()
+        )?;
+        writeln!(self.out, "    mov x9, x0")?;
+        self.emit_releases(&op.release, &[&op.err_target], true)?;
+        self.emit_value_jump(&op.ok_target, true)?;
+
+        writeln!(self.out, "{}:", err_label)?;
+        self.emit_releases(&op.release, &[&op.ok_target], false)?;
+        self.emit_value_jump(&op.err_target, false)
+    }
+
+    fn emit_byte_at(&mut self, op: &AirByteAt) -> Result<(), Error> {
+        let err_label = self.new_label("byteat_err");
+        self.load_arg_into_reg(&op.input, "x13")?;
+        self.load_arg_into_reg(&op.index, "x14")?;
+        writeln!(self.out, "    cmp x14, #0 // index before the string?")?;
+        writeln!(self.out, "    b.lt {}", err_label)?;
+        writeln!(
+            self.out,
+            "    ldr x10, [x13, #-{}] // string length",
+            STR_LEN_OFFSET
+        )?;
+        writeln!(self.out, "    cmp x14, x10 // index past the string?")?;
+        writeln!(self.out, "    b.ge {}", err_label)?;
+        writeln!(self.out, "    ldrb w9, [x13, x14] // load the byte")?;
+        self.emit_releases(&op.release, &[&op.err_target], true)?;
+        self.emit_value_jump(&op.ok_target, true)?;
+
+        writeln!(self.out, "{}:", err_label)?;
+        self.emit_releases(&op.release, &[&op.ok_target], false)?;
+        self.emit_value_jump(&op.err_target, false)
+    }
+
+    fn emit_new_array(&mut self, array: &AirNewArray) -> Result<(), Error> {
+        let len = array.items.len();
+        self.load_imm(
+            "x0",
+            (ARRAY_HEADER_SIZE + len * WORD_SIZE) as i64,
+            "header and elements",
+        )?;
+        writeln!(
+            self.out,
+            "    bl {} // allocate the array",
+            AirRuntimeHelper::AllocHeapPtr.name()
+        )?;
+        writeln!(self.out, "    mov x20, x0")?;
+        self.load_imm("x10", array_elem_class(&array.elem), "element class")?;
+        writeln!(self.out, "    str x10, [x20] // element class header")?;
+        self.load_imm("x10", len as i64, "length")?;
+        writeln!(
+            self.out,
+            "    str x10, [x20, #{}] // length header",
+            ARRAY_HEADER_SIZE - ARRAY_LEN_OFFSET
+        )?;
+        writeln!(
+            self.out,
+            "    add x20, x20, #{} // array starts after its header",
+            ARRAY_HEADER_SIZE
+        )?;
+        for (idx, item) in array.items.iter().enumerate() {
+            self.load_arg_into_reg(item, "x9")?;
+            writeln!(
+                self.out,
+                "    str x9, [x20, #{}] // element {}",
+                idx * WORD_SIZE,
+                idx
+            )?;
+        }
+        writeln!(self.out, "    mov x9, x20")?;
+        self.store_binding_value(&array.name)
+    }
+
+    fn emit_array_len(&mut self, op: &AirArrayLen) -> Result<(), Error> {
+        self.load_arg_into_reg(&op.input, "x0")?;
+        writeln!(
+            self.out,
+            "    ldr x20, [x0, #-{}] // array length",
+            ARRAY_LEN_OFFSET
+        )?;
+        writeln!(
+            self.out,
+            "    bl {} // release the array",
+            AirRuntimeHelper::ReleaseArrayPtr.name()
+        )?;
+        writeln!(self.out, "    mov x9, x20")?;
+        self.emit_value_jump(&op.target, true)
+    }
+
+    /// Leaves the address of element `index` in x14 when it is in bounds of
+    /// the array in x21, and branches to `out_label` otherwise.
+    fn emit_array_bounds_check(&mut self, index: &AirArg, out_label: &str) -> Result<(), Error> {
+        self.load_arg_into_reg(index, "x14")?;
+        writeln!(self.out, "    cmp x14, #0 // index before the array?")?;
+        writeln!(self.out, "    b.lt {}", out_label)?;
+        writeln!(
+            self.out,
+            "    ldr x10, [x21, #-{}] // array length",
+            ARRAY_LEN_OFFSET
+        )?;
+        writeln!(self.out, "    cmp x14, x10 // index past the array?")?;
+        writeln!(self.out, "    b.ge {}", out_label)?;
+        writeln!(self.out, "    add x14, x21, x14, lsl #3 // element address")?;
+        Ok(())
+    }
+
+    fn emit_release_array_in_x21(&mut self) -> Result<(), Error> {
+        writeln!(self.out, "    mov x0, x21")?;
+        writeln!(
+            self.out,
+            "    bl {} // release the array",
+            AirRuntimeHelper::ReleaseArrayPtr.name()
+        )?;
+        Ok(())
+    }
+
+    /// Releases the element at x0 as the array in x21 would.
+    fn emit_release_array_elem(&mut self, what: &str) -> Result<(), Error> {
+        writeln!(
+            self.out,
+            "    ldr x1, [x21, #-{}] // element class",
+            ARRAY_CLASS_OFFSET
+        )?;
+        writeln!(
+            self.out,
+            "    bl {} // release the {}",
+            AirRuntimeHelper::ReleaseArrayElemPtr.name(),
+            what
+        )?;
+        Ok(())
+    }
+
+    fn emit_array_get(&mut self, op: &AirArrayGet) -> Result<(), Error> {
+        let none_label = self.new_label("get_none");
+        self.load_arg_into_reg(&op.input, "x21")?;
+        self.emit_array_bounds_check(&op.index, &none_label)?;
+        writeln!(self.out, "    ldr x23, [x14] // take the element")?;
+        writeln!(
+            self.out,
+            "    str xzr, [x14] // the array no longer owns it"
+        )?;
+        self.emit_release_array_in_x21()?;
+        writeln!(self.out, "    mov x9, x23")?;
+        self.emit_releases(&[], &[&op.none_target], true)?;
+        self.emit_value_jump(&op.one_target, true)?;
+
+        writeln!(self.out, "{}:", none_label)?;
+        self.emit_release_array_in_x21()?;
+        self.emit_releases(&[], &[&op.one_target], false)?;
+        self.emit_value_jump(&op.none_target, false)
+    }
+
+    fn emit_array_set(&mut self, op: &AirArraySet) -> Result<(), Error> {
+        let err_label = self.new_label("set_err");
+        self.load_arg_into_reg(&op.input, "x21")?;
+        self.load_arg_into_reg(&op.value, "x23")?;
+        self.emit_array_bounds_check(&op.index, &err_label)?;
+        writeln!(self.out, "    ldr x0, [x14] // old element")?;
+        writeln!(self.out, "    str x23, [x14] // store the new one")?;
+        self.emit_release_array_elem("old element")?;
+        writeln!(self.out, "    mov x9, x21")?;
+        self.emit_releases(&[], &[&op.err_target], true)?;
+        self.emit_value_jump(&op.ok_target, true)?;
+
+        writeln!(self.out, "{}:", err_label)?;
+        writeln!(self.out, "    mov x0, x23")?;
+        self.emit_release_array_elem("unused value")?;
+        self.emit_release_array_in_x21()?;
+        self.emit_releases(&[], &[&op.ok_target], false)?;
+        self.emit_value_jump(&op.err_target, false)
+    }
+
+    fn emit_new_map(&mut self, map: &AirNewMap) -> Result<(), Error> {
+        self.load_imm("x0", array_elem_class(&map.key), "key class")?;
+        self.load_imm("x1", array_elem_class(&map.value), "value class")?;
+        writeln!(
+            self.out,
+            "    bl {} // allocate the map",
+            AirRuntimeHelper::MapNewPtr.name()
+        )?;
+        for (key, value) in &map.entries {
+            writeln!(self.out, "    mov x20, x0")?;
+            self.load_arg_into_reg(key, "x1")?;
+            self.load_arg_into_reg(value, "x2")?;
+            writeln!(self.out, "    mov x0, x20")?;
+            writeln!(
+                self.out,
+                "    bl {} // add the entry",
+                AirRuntimeHelper::MapInsertPtr.name()
+            )?;
+        }
+        writeln!(self.out, "    mov x9, x0")?;
+        self.store_binding_value(&map.name)
+    }
+
+    fn emit_map_insert(&mut self, op: &AirMapInsert) -> Result<(), Error> {
+        self.load_arg_into_reg(&op.input, "x0")?;
+        self.load_arg_into_reg(&op.key, "x1")?;
+        self.load_arg_into_reg(&op.value, "x2")?;
+        writeln!(
+            self.out,
+            "    bl {} // store the entry",
+            AirRuntimeHelper::MapInsertPtr.name()
+        )?;
+        writeln!(self.out, "    mov x9, x0")?;
+        self.emit_value_jump(&op.target, true)
+    }
+
+    fn emit_map_remove(&mut self, op: &AirMapRemove) -> Result<(), Error> {
+        self.load_arg_into_reg(&op.input, "x0")?;
+        self.load_arg_into_reg(&op.key, "x1")?;
+        writeln!(
+            self.out,
+            "    bl {} // drop the entry",
+            AirRuntimeHelper::MapRemovePtr.name()
+        )?;
+        writeln!(self.out, "    mov x9, x0")?;
+        self.emit_value_jump(&op.target, true)
+    }
+
+    fn emit_map_len(&mut self, op: &AirMapLen) -> Result<(), Error> {
+        self.load_arg_into_reg(&op.input, "x21")?;
+        writeln!(
+            self.out,
+            "    ldur x22, [x21, #-{}] // number of entries",
+            MAP_COUNT_OFFSET
+        )?;
+        self.emit_values_jump(&op.target, &["x22", "x21"])
+    }
+
+    fn emit_map_lookup(&mut self, op: &AirMapLookup) -> Result<(), Error> {
+        let missing_label = self.new_label("lookup_missing");
+        self.load_arg_into_reg(&op.input, "x21")?;
+        self.load_arg_into_reg(&op.key, "x23")?;
+        writeln!(self.out, "    mov x0, x21")?;
+        writeln!(self.out, "    mov x1, x23")?;
+        writeln!(
+            self.out,
+            "    bl {} // look for the key",
+            AirRuntimeHelper::MapFindPtr.name()
+        )?;
+        writeln!(self.out, "    cbz x0, {}", missing_label)?;
+        writeln!(
+            self.out,
+            "    ldr x0, [x0, #{}] // value of the entry",
+            WORD_SIZE
+        )?;
+        self.emit_map_elem_helper(
+            AirRuntimeHelper::CopyArrayElemPtr,
+            MAP_VALUE_CLASS_OFFSET,
+            "copy the value",
+        )?;
+        writeln!(self.out, "    mov x20, x0")?;
+        self.emit_release_map_key_in_x23()?;
+        self.emit_releases(&[], &[&op.missing_target], false)?;
+        self.emit_values_jump(&op.found_target, &["x20", "x21"])?;
+
+        writeln!(self.out, "{}:", missing_label)?;
+        self.emit_release_map_key_in_x23()?;
+        self.emit_releases(&[], &[&op.found_target], false)?;
+        self.emit_values_jump(&op.missing_target, &["x21"])
+    }
+
+    fn emit_map_next(&mut self, op: &AirMapNext) -> Result<(), Error> {
+        let done_label = self.new_label("mapnext_done");
+        self.load_arg_into_reg(&op.input, "x21")?;
+        self.load_arg_into_reg(&op.cursor, "x20")?;
+        writeln!(self.out, "    cmp x20, #0 // cursor before the entries?")?;
+        writeln!(self.out, "    b.lt {}", done_label)?;
+        writeln!(
+            self.out,
+            "    ldur x10, [x21, #-{}] // number of entries",
+            MAP_COUNT_OFFSET
+        )?;
+        writeln!(self.out, "    cmp x20, x10 // cursor past the entries?")?;
+        writeln!(self.out, "    b.ge {}", done_label)?;
+        for (offset, class_offset, reg, what) in [
+            (0, MAP_KEY_CLASS_OFFSET, "x22", "copy the key"),
+            (WORD_SIZE, MAP_VALUE_CLASS_OFFSET, "x23", "copy the value"),
+        ] {
+            writeln!(self.out, "    add x10, x21, x20, lsl #4 // entry address")?;
+            writeln!(self.out, "    ldr x0, [x10, #{}]", offset)?;
+            self.emit_map_elem_helper(AirRuntimeHelper::CopyArrayElemPtr, class_offset, what)?;
+            writeln!(self.out, "    mov {}, x0", reg)?;
+        }
+        writeln!(self.out, "    add x20, x20, #1 // cursor of the next entry")?;
+        self.emit_releases(&[], &[&op.done_target], false)?;
+        self.emit_values_jump(&op.entry_target, &["x22", "x23", "x20", "x21"])?;
+
+        writeln!(self.out, "{}:", done_label)?;
+        self.emit_releases(&[], &[&op.entry_target], false)?;
+        self.emit_values_jump(&op.done_target, &["x21"])
+    }
+
+    /// Calls `helper` on x0 with the key or value class of the map in x21.
+    fn emit_map_elem_helper(
+        &mut self,
+        helper: AirRuntimeHelper,
+        class_offset: usize,
+        what: &str,
+    ) -> Result<(), Error> {
+        writeln!(self.out, "    ldur x1, [x21, #-{}]", class_offset)?;
+        writeln!(self.out, "    bl {} // {}", helper.name(), what)?;
+        Ok(())
+    }
+
+    /// Releases the lookup key in x23 as the map in x21 would.
+    fn emit_release_map_key_in_x23(&mut self) -> Result<(), Error> {
+        writeln!(self.out, "    mov x0, x23")?;
+        self.emit_map_elem_helper(
+            AirRuntimeHelper::ReleaseArrayElemPtr,
+            MAP_KEY_CLASS_OFFSET,
+            "release the key",
+        )
+    }
+
+    fn emit_value_jump(&mut self, target: &str, has_result: bool) -> Result<(), Error> {
+        let results: &[&str] = if has_result { &["x9"] } else { &[] };
+        self.emit_values_jump(target, results)
+    }
+
+    /// Jumps to the continuation `target` with the values in `regs` as its
+    /// arguments, in order.
+    fn emit_values_jump(&mut self, target: &str, regs: &[&str]) -> Result<(), Error> {
+        let slot = self.frame.slot(target)?;
+        self.load_slot(CLOSURE_ENV_REG, slot, "load continuation env_end pointer")?;
+        for (idx, reg) in regs.iter().enumerate() {
+            self.store_field(CLOSURE_ENV_REG, -((regs.len() - idx) as isize), reg)?;
+        }
+        writeln!(
+            self.out,
+            "    ldr x9, [{}, #{}] // load continuation entry point",
+            CLOSURE_ENV_REG, ENV_METADATA_UNWRAPPER_OFFSET
+        )?;
+        writeln!(
+            self.out,
+            "    mov x0, {} // pass env_end pointer to continuation",
+            CLOSURE_ENV_REG
+        )?;
+        self.emit_leave("unwind before jumping")?;
+        writeln!(self.out, "    br x9")?;
+        self.terminated = true;
+        Ok(())
+    }
+
+    fn emit_jump_closure(&mut self, jump: &AirJumpClosure) -> Result<(), Error> {
+        let slot = self.frame.slot(&jump.env_end)?;
+        self.load_slot(
+            "x20",
+            slot,
+            &format!("load {} closure env_end pointer", jump.env_end),
+        )?;
+        let total_args = jump.args.len();
+        for (idx, arg) in jump.args.iter().enumerate() {
+            self.load_arg_into_reg(arg, "x9")?;
+            self.store_field("x20", -((total_args - idx) as isize), "x9")?;
+        }
+        writeln!(
+            self.out,
+            "    mov x0, x20 // pass env_end pointer to closure"
+        )?;
+        writeln!(
+            self.out,
+            "    ldr x9, [x0, #{}] // load closure unwrapper entry point",
+            ENV_METADATA_UNWRAPPER_OFFSET
+        )?;
+        self.emit_leave("unwind before jumping")?;
+        writeln!(self.out, "    br x9 // tail call into closure")?;
+        self.terminated = true;
+        Ok(())
+    }
+
+    fn emit_exit_syscall(&mut self, syscall: &AirSysExit) -> Result<(), Error> {
+        writeln!(self.out, "    // load exit code")?;
+        match syscall.args.first() {
+            Some(code) => self.load_arg_into_reg(code, "x0")?,
+            None => writeln!(self.out, "    mov x0, #0 // exit code")?,
+        }
+        // Call libc exit() instead of the raw exit syscall so stdout is flushed
+        writeln!(self.out, "    bl exit // call libc exit to flush buffers")?;
+        self.terminated = true;
+        Ok(())
+    }
+
+    fn emit_get_field(&mut self, field: &AirField) -> Result<(), Error> {
+        let offset = field_offset(field.offset);
+        self.access(
+            "ldr",
+            "x9",
+            CLOSURE_ENV_REG,
+            offset,
+            &format!("load {} env field", field.result),
+        )?;
+        self.store_binding_value(&field.result)
+    }
+
+    fn emit_release_heap_ptr(&mut self, name: &str) -> Result<(), Error> {
+        if let Ok(slot) = self.frame.slot(name) {
+            self.load_slot(
+                "x0",
+                slot,
+                &format!("load {} closure env_end pointer", name),
+            )?;
+        } else {
+            writeln!(
+                self.out,
+                "    mov x0, {} // use pinned {} env_end pointer",
+                CLOSURE_ENV_REG, name
+            )?;
+        }
+        writeln!(
+            self.out,
+            "    bl {} // release {} closure environment",
+            AirRuntimeHelper::ReleaseHeapPtr.name(),
+            name
+        )?;
+        Ok(())
+    }
+
+    fn emit_new_chan(&mut self, op: &AirNewChan) -> Result<(), Error> {
+        writeln!(
+            self.out,
+            "    mov x0, #{} // class of the values",
+            chan_elem_class(&op.elem)
+        )?;
+        writeln!(
+            self.out,
+            "    bl {} // make the channel",
+            AirRuntimeHelper::ChanNewPtr.name()
+        )?;
+        writeln!(self.out, "    mov x9, x0")?;
+        self.emit_value_jump(&op.target, true)
+    }
+
+    fn emit_chan_send(&mut self, op: &AirChanSend) -> Result<(), Error> {
+        self.load_arg_into_reg(&op.channel, "x21")?;
+        self.load_arg_into_reg(&op.value, "x1")?;
+        writeln!(self.out, "    mov x0, x21")?;
+        writeln!(
+            self.out,
+            "    bl {} // hand the value over or queue it",
+            AirRuntimeHelper::ChanSendPtr.name()
+        )?;
+        self.emit_values_jump(&op.target, &["x21"])
+    }
+
+    fn emit_chan_recv(&mut self, op: &AirChanRecv) -> Result<(), Error> {
+        let park_label = self.new_label("recv_park");
+        self.load_arg_into_reg(&op.channel, "x21")?;
+        self.load_value_into_reg(&AirValue::Binding(op.target.clone()), "x1")?;
+        writeln!(self.out, "    mov x0, x21")?;
+        writeln!(
+            self.out,
+            "    bl {} // take a queued value or park",
+            AirRuntimeHelper::ChanRecvPtr.name()
+        )?;
+        writeln!(self.out, "    cbz x1, {} // parked?", park_label)?;
+        writeln!(self.out, "    mov x9, x0")?;
+        self.emit_values_jump(&op.target, &["x9", "x21"])?;
+
+        writeln!(self.out, "{}:", park_label)?;
+        self.emit_sched_next()
+    }
+
+    fn emit_spawn(&mut self, op: &AirSpawn) -> Result<(), Error> {
+        self.emit_run_later(&op.task)?;
+        self.emit_values_jump(&op.target, &[])
+    }
+
+    fn emit_thread(&mut self, op: &AirThread) -> Result<(), Error> {
+        self.load_value_into_reg(&AirValue::Binding(op.task.clone()), "x0")?;
+        writeln!(
+            self.out,
+            "    bl {} // run {} on a thread of its own",
+            AirRuntimeHelper::ThreadNewPtr.name(),
+            op.task
+        )?;
+        self.emit_values_jump(&op.target, &[])
+    }
+
+    fn emit_yield(&mut self, op: &AirYield) -> Result<(), Error> {
+        self.emit_run_later(&op.target)?;
+        self.emit_sched_next()
+    }
+
+    /// Puts the closure bound to `name` at the back of the run queue.
+    fn emit_run_later(&mut self, name: &str) -> Result<(), Error> {
+        self.load_value_into_reg(&AirValue::Binding(name.to_string()), "x0")?;
+        writeln!(
+            self.out,
+            "    bl {} // run {} later",
+            AirRuntimeHelper::SchedReadyPtr.name(),
+            name
+        )?;
+        Ok(())
+    }
+
+    /// Gives control to the next coroutine in the run queue.
+    fn emit_sched_next(&mut self) -> Result<(), Error> {
+        self.emit_leave("unwind before switching")?;
+        writeln!(
+            self.out,
+            "    b {} // run the next coroutine",
+            AirRuntimeHelper::SchedNextPtr.name()
+        )?;
+        self.terminated = true;
+        Ok(())
+    }
+
+    fn emit_copy_field(&mut self, field: &AirField) -> Result<(), Error> {
+        let offset = field_offset(field.offset);
+        let helper = copy_helper(&field.kind);
+        self.access("ldr", "x0", CLOSURE_ENV_REG, offset, "load field pointer")?;
+        writeln!(
+            self.out,
+            "    bl {} // duplicate heap pointer",
+            helper.name()
+        )?;
+        writeln!(self.out, "    mov x9, x0")?;
+        self.access(
+            "str",
+            "x9",
+            CLOSURE_ENV_REG,
+            offset,
+            "store duplicated pointer",
+        )?;
+        self.store_binding_value(&field.result)
+    }
+
+    fn emit_call_ptr(&mut self, call: &AirCallPtr) -> Result<(), Error> {
+        let AirCallPtrTarget::Binding(name) = &call.target;
+        self.emit_release_closure(name)
+    }
+
+    fn emit_label(&mut self, label: &AirLabel) -> Result<(), Error> {
+        writeln!(self.out, "{}:", label.name)?;
+        Ok(())
+    }
+
+    fn emit_jump(&mut self, jump: &AirJump) -> Result<(), Error> {
+        writeln!(self.out, "    b {}", jump.target)?;
+        Ok(())
+    }
+
+    fn emit_return(&mut self, ret: &AirReturn) -> Result<(), Error> {
+        if let Some(name) = &ret.value {
+            let slot = self.frame.slot(name)?;
+            self.load_slot("x0", slot, "load return value")?;
+        }
+        self.emit_leave("restore caller frame")?;
+        writeln!(self.out, "    ret")?;
+        writeln!(self.out)?;
+        self.terminated = true;
+        Ok(())
+    }
+
+    fn emit_new_closure(&mut self, c: &AirNewClosure) -> Result<(), Error> {
+        let kinds = c.target.param_kinds();
+        let env_size = kinds.len() * WORD_SIZE;
+        let heap_size = env_size + ENV_METADATA_SIZE;
+
+        self.emit_alloc(heap_size)?;
+        writeln!(self.out, "    mov x20, x9 // closure env base pointer")?;
+
+        for (idx, arg) in c.args.iter().take(kinds.len()).enumerate() {
+            self.load_arg_into_reg(arg, "x9")?;
+            let offset = (idx * WORD_SIZE) as i64;
+            self.access("str", "x9", "x20", offset, "capture arg into env")?;
+        }
+
+        writeln!(
+            self.out,
+            "    mov {}, x20 // env_end pointer before metadata",
+            CLOSURE_ENV_REG
+        )?;
+        if env_size > 0 {
+            self.load_imm("x16", env_size as i64, "env payload size")?;
+            writeln!(
+                self.out,
+                "    add {reg}, {reg}, x16 // move pointer past env payload",
+                reg = CLOSURE_ENV_REG
+            )?;
+        }
+
+        self.load_imm("x9", env_size as i64, "store env size metadata")?;
+        self.store_metadata(ENV_METADATA_ENV_SIZE_OFFSET, "env size metadata")?;
+        self.load_imm("x9", heap_size as i64, "store heap size metadata")?;
+        self.store_metadata(ENV_METADATA_HEAP_SIZE_OFFSET, "heap size metadata")?;
+
+        self.load_address("x9", &c.unwrapper_label(), "load unwrapper entry point")?;
+        self.store_metadata(
+            ENV_METADATA_UNWRAPPER_OFFSET,
+            "store unwrapper entry in metadata",
+        )?;
+        self.load_address(
+            "x9",
+            &c.deep_release_label(),
+            "load release helper entry point",
+        )?;
+        self.store_metadata(
+            ENV_METADATA_RELEASE_OFFSET,
+            "store release pointer in metadata",
+        )?;
+        self.load_address(
+            "x9",
+            &c.deepcopy_label(),
+            "load deep copy helper entry point",
+        )?;
+        self.store_metadata(
+            ENV_METADATA_DEEP_COPY_OFFSET,
+            "store deep copy pointer in metadata",
+        )?;
+
+        let num_remaining = kinds.len().saturating_sub(c.args.len());
+        self.load_imm("x9", num_remaining as i64, "num_remaining")?;
+        self.store_metadata(ENV_METADATA_NUM_REMAINING_OFFSET, "store num_remaining")?;
+
+        writeln!(
+            self.out,
+            "    mov x9, {} // copy {} closure env_end to x9",
+            CLOSURE_ENV_REG, c.name
+        )?;
+        Ok(())
+    }
+
+    fn store_metadata(&mut self, offset: usize, comment: &str) -> Result<(), Error> {
+        writeln!(
+            self.out,
+            "    str x9, [{}, #{}] // {}",
+            CLOSURE_ENV_REG, offset, comment
+        )?;
+        Ok(())
+    }
+
+    fn store_binding_value(&mut self, name: &str) -> Result<(), Error> {
+        let slot = self.frame.slot(name)?;
+        self.store_slot("x9", slot, "store value")
+    }
+
+    fn emit_jump_args(&mut self, ja: &AirJumpArgs) -> Result<(), Error> {
+        let stack_bytes = self.move_args_to_registers(&ja.args, 0)?;
+        if stack_bytes > 0 {
+            // Leave the spilled arguments on the stack for the callee and
+            // restore the caller's frame record in place of a full unwind.
+            writeln!(
+                self.out,
+                "    ldp x29, x30, [x29] // unwind, keeping stack args"
+            )?;
+        } else {
+            self.emit_leave("unwind before named jump")?;
+        }
+        writeln!(self.out, "    b {}", ja.target.name)?;
+        self.terminated = true;
+        Ok(())
+    }
+
+    fn emit_libc_call(
+        &mut self,
+        builtin: builtins::Builtin,
+        args: &[AirArg],
+        _arg_kinds: &[SigKind],
+    ) -> Result<bool, Error> {
+        if args.is_empty() {
+            let message = match builtin {
+                builtins::Builtin::Write => "write requires a buffer before the continuation",
+                builtins::Builtin::WriteFd => {
+                    "writefd requires an fd and a buffer before the continuation"
+                }
+                _ => "printf requires a format string before the continuation",
+            };
+            let message = message.replace("printf", builtin.name());
+            return Err(Error::new(Code::Codegen, message, Span::unknown()));
+        }
+        match builtin {
+            builtins::Builtin::Printf => {
+                let stack_bytes = self.move_variadic_args(args, 0)?;
+                writeln!(self.out, "    bl printf // invoke libc printf")?;
+                self.cleanup_libc_stack(stack_bytes)?;
+                Ok(false)
+            }
+            builtins::Builtin::Sprintf => {
+                let stack_bytes = self.move_variadic_args(args, 2)?;
+                writeln!(self.out, "    mov x0, #0 // measure only")?;
+                writeln!(self.out, "    mov x1, #0")?;
+                writeln!(self.out, "    bl snprintf // invoke libc snprintf")?;
+                self.cleanup_libc_stack(stack_bytes)?;
+                writeln!(self.out, "    mov x20, x0 // formatted length")?;
+                writeln!(
+                    self.out,
+                    "    add x0, x20, #{} // header, bytes and terminator",
+                    STR_HEADER_SIZE + 1
+                )?;
+                writeln!(
+                    self.out,
+                    "    bl {} // allocate the string",
+                    AirRuntimeHelper::AllocHeapPtr.name()
+                )?;
+                writeln!(self.out, "    mov x21, x0 // string block")?;
+                writeln!(self.out, "    add x9, x20, #{}", STR_HEADER_SIZE + 1)?;
+                writeln!(self.out, "    str x9, [x21] // allocation size header")?;
+                writeln!(
+                    self.out,
+                    "    str x20, [x21, #{}] // length header",
+                    STR_HEADER_SIZE - STR_LEN_OFFSET
+                )?;
+                let stack_bytes = self.move_variadic_args(args, 2)?;
+                writeln!(
+                    self.out,
+                    "    add x0, x21, #{} // string bytes follow the header",
+                    STR_HEADER_SIZE
+                )?;
+                writeln!(self.out, "    add x1, x20, #1 // room for the terminator")?;
+                writeln!(self.out, "    bl snprintf // invoke libc snprintf")?;
+                self.cleanup_libc_stack(stack_bytes)?;
+                writeln!(
+                    self.out,
+                    "    add x9, x21, #{} // return formatted string pointer",
+                    STR_HEADER_SIZE
+                )?;
+                Ok(true)
+            }
+            builtins::Builtin::Write => {
+                self.load_arg_into_reg(&args[0], "x13")?;
+                writeln!(
+                    self.out,
+                    "    ldr x2, [x13, #-{}] // length to write",
+                    STR_LEN_OFFSET
+                )?;
+                writeln!(self.out, "    mov x1, x13 // buffer start")?;
+                writeln!(self.out, "    mov x0, #1 // stdout fd")?;
+                writeln!(self.out, "    bl write // invoke libc write")?;
+                Ok(false)
+            }
+            builtins::Builtin::WriteFd => {
+                if args.len() < 2 {
+                    return Err(Error::new(
+                        Code::Codegen,
+                        "writefd requires an fd and a buffer before the continuation",
+                        Span::unknown(),
+                    ));
+                }
+                self.load_arg_into_reg(&args[1], "x13")?;
+                writeln!(
+                    self.out,
+                    "    ldr x2, [x13, #-{}] // length to write",
+                    STR_LEN_OFFSET
+                )?;
+                writeln!(self.out, "    mov x1, x13 // buffer start")?;
+                self.load_arg_into_reg(&args[0], "x0")?;
+                writeln!(self.out, "    bl write // invoke libc write")?;
+                Ok(false)
+            }
+            _ => Err(Error::new(
+                Code::Codegen,
+                format!("unsupported libc call '{}'", builtin.name()),
+                Span::unknown(),
+            )),
+        }
+    }
+
+    fn cleanup_libc_stack(&mut self, stack_bytes: usize) -> Result<(), Error> {
+        if stack_bytes > 0 {
+            self.adjust_sp("add", stack_bytes, "pop stack args after libc call")?;
+        }
+        Ok(())
+    }
+
+    /// Loads `args` into the argument registers starting at `first_reg` and
+    /// stores the rest in a stack area laid out the way AAPCS64 expects.
+    /// Returns the size of that area.
+    fn move_args_to_registers(
+        &mut self,
+        args: &[AirArg],
+        first_reg: usize,
+    ) -> Result<usize, Error> {
+        let reg_slots = ARG_REGS.len() - first_reg;
+        let spilled = args.len().saturating_sub(reg_slots);
+        let stack_bytes = (spilled * WORD_SIZE).next_multiple_of(16);
+        if stack_bytes > 0 {
+            self.adjust_sp("sub", stack_bytes, "reserve stack args")?;
+            for (idx, arg) in args.iter().skip(reg_slots).enumerate() {
+                self.load_arg_into_reg(arg, "x9")?;
+                self.access("str", "x9", "sp", (idx * WORD_SIZE) as i64, "stack arg")?;
+            }
+        }
+        for (idx, arg) in args.iter().take(reg_slots).enumerate() {
+            self.load_arg_into_reg(arg, ARG_REGS[first_reg + idx])?;
+        }
+        Ok(stack_bytes)
+    }
+
+    /// Like `move_args_to_registers`, but f64 arguments go in d0-d7 as the
+    /// procedure call standard has variadic callees read them on Linux.
+    fn move_variadic_args(&mut self, args: &[AirArg], first_reg: usize) -> Result<usize, Error> {
+        let mut int_regs = ARG_REGS[first_reg..].iter();
+        let mut float_count = 0;
+        let mut loads = Vec::new();
+        let mut stack = Vec::new();
+        for arg in args {
+            let reg = match arg.kind {
+                SigKind::F64 if float_count < VARIADIC_FLOAT_REGS => {
+                    float_count += 1;
+                    Some(format!("d{}", float_count - 1))
+                }
+                SigKind::F64 => None,
+                _ => int_regs.next().map(|reg| reg.to_string()),
+            };
+            match reg {
+                Some(reg) => loads.push((arg, reg)),
+                None => stack.push(arg),
+            }
+        }
+        let stack_bytes = (stack.len() * WORD_SIZE).next_multiple_of(16);
+        if stack_bytes > 0 {
+            self.adjust_sp("sub", stack_bytes, "reserve stack args")?;
+            for (idx, arg) in stack.into_iter().enumerate() {
+                self.load_arg_into_reg(arg, "x9")?;
+                self.access("str", "x9", "sp", (idx * WORD_SIZE) as i64, "stack arg")?;
+            }
+        }
+        for (arg, reg) in loads {
+            if matches!(arg.kind, SigKind::F64) {
+                self.load_arg_into_float(arg, &reg)?;
+            } else {
+                self.load_arg_into_reg(arg, &reg)?;
+            }
+        }
+        Ok(stack_bytes)
+    }
+
+    fn emit_alloc(&mut self, size: usize) -> Result<(), Error> {
+        self.load_imm("x0", size.max(1) as i64, "length for allocation")?;
+        writeln!(
+            self.out,
+            "    bl {} // allocate env block",
+            AirRuntimeHelper::AllocHeapPtr.name()
+        )?;
+        writeln!(self.out, "    mov x9, x0")?;
+        Ok(())
+    }
+
+    fn emit_clone_env_from_env_end(&mut self, src: &str, dst: &str) -> Result<(), Error> {
+        writeln!(
+            self.out,
+            "    mov x20, {} // clone source env_end pointer",
+            src
+        )?;
+        writeln!(
+            self.out,
+            "    ldr x21, [x20, #{}] // load env size metadata for clone",
+            ENV_METADATA_ENV_SIZE_OFFSET
+        )?;
+        writeln!(
+            self.out,
+            "    ldr x22, [x20, #{}] // load heap size metadata for clone",
+            ENV_METADATA_HEAP_SIZE_OFFSET
+        )?;
+        writeln!(
+            self.out,
+            "    sub x19, x20, x21 // env base pointer for clone source"
+        )?;
+        writeln!(self.out, "    mov x0, x22 // length for cloned environment")?;
+        writeln!(
+            self.out,
+            "    bl {} // allocate cloned env block",
+            AirRuntimeHelper::AllocHeapPtr.name()
+        )?;
+        writeln!(
+            self.out,
+            "    mov x23, x0 // cloned closure env base pointer"
+        )?;
+
+        let loop_label = self.new_label("clone_copy_loop");
+        let done_label = self.new_label("clone_copy_done");
+        writeln!(self.out, "    mov x11, #0 // bytes copied")?;
+        writeln!(self.out, "{}:", loop_label)?;
+        writeln!(self.out, "    cmp x11, x22")?;
+        writeln!(self.out, "    b.ge {}", done_label)?;
+        writeln!(
+            self.out,
+            "    ldrb w12, [x19, x11] // duplicate closure env data"
+        )?;
+        writeln!(self.out, "    strb w12, [x23, x11]")?;
+        writeln!(self.out, "    add x11, x11, #1")?;
+        writeln!(self.out, "    b {}", loop_label)?;
+        writeln!(self.out, "{}:", done_label)?;
+
+        writeln!(
+            self.out,
+            "    add x20, x23, x21 // compute cloned env_end pointer"
+        )?;
+        writeln!(self.out, "    mov {}, x20 // cloned env_end pointer", dst)?;
+        writeln!(
+            self.out,
+            "    ldr x9, [{}, #{}] // load deepcopy helper entry point",
+            dst, ENV_METADATA_DEEP_COPY_OFFSET
+        )?;
+        writeln!(
+            self.out,
+            "    str {}, [sp, #-16]! // preserve cloned env_end pointer",
+            dst
+        )?;
+        writeln!(
+            self.out,
+            "    mov x0, {} // pass env_end pointer to deepcopy helper",
+            dst
+        )?;
+        writeln!(self.out, "    blr x9 // deepcopy reference fields")?;
+        writeln!(
+            self.out,
+            "    ldr {}, [sp], #16 // restore cloned env_end pointer",
+            dst
+        )?;
+        Ok(())
+    }
+
+    fn load_value_into_reg(&mut self, value: &AirValue, reg: &str) -> Result<(), Error> {
+        match value {
+            AirValue::Binding(name) => {
+                let slot = self.frame.slot(name)?;
+                self.load_slot(reg, slot, "load operand")
+            }
+            AirValue::Literal(value) => self.load_imm(reg, *value, "operand literal"),
+        }
+    }
+
+    fn load_arg_into_reg(&mut self, arg: &AirArg, reg: &str) -> Result<(), Error> {
+        match &arg.literal {
+            Some(Lit::Int(value)) => self.load_imm(reg, *value as i64, "operand literal"),
+            Some(Lit::Str(_)) => self.load_address(reg, &arg.name, "point to string literal"),
+            Some(Lit::F64(value)) => {
+                self.load_imm(reg, value.to_bits() as i64, "load literal float bits")
+            }
+            None => self.load_value_into_reg(&AirValue::Binding(arg.name.clone()), reg),
+        }
+    }
+
+    fn load_arg_into_float(&mut self, arg: &AirArg, dreg: &str) -> Result<(), Error> {
+        match &arg.literal {
+            Some(Lit::Int(value)) => {
+                self.load_imm("x9", *value as i64, "load literal integer for float")?;
+                writeln!(
+                    self.out,
+                    "    scvtf {}, x9 // convert literal to float",
+                    dreg
+                )?;
+                Ok(())
+            }
+            Some(Lit::Str(_)) => Err(Error::new(
+                Code::Codegen,
+                format!(
+                    "cannot use string literal '{}' in float operation",
+                    arg.name
+                ),
+                Span::unknown(),
+            )),
+            Some(Lit::F64(value)) => {
+                self.load_imm("x9", value.to_bits() as i64, "load literal float bits")?;
+                writeln!(self.out, "    fmov {}, x9 // load float literal", dreg)?;
+                Ok(())
+            }
+            None => {
+                let slot = self.frame.slot(&arg.name)?;
+                self.load_slot(dreg, slot, "load float operand")
+            }
+        }
+    }
+
+    fn load_slot(&mut self, reg: &str, slot: usize, comment: &str) -> Result<(), Error> {
+        self.access("ldr", reg, "x29", -(slot as i64), comment)
+    }
+
+    fn store_slot(&mut self, reg: &str, slot: usize, comment: &str) -> Result<(), Error> {
+        self.access("str", reg, "x29", -(slot as i64), comment)
+    }
+
+    fn store_field(&mut self, base: &str, offset: isize, reg: &str) -> Result<(), Error> {
+        self.access("str", reg, base, field_offset(offset), "store env field")
+    }
+
+    /// Emits a 64-bit `ldr` or `str` of `reg` at `base + offset`, picking the
+    /// addressing form that can encode `offset`.
+    fn access(
+        &mut self,
+        op: &str,
+        reg: &str,
+        base: &str,
+        offset: i64,
+        comment: &str,
+    ) -> Result<(), Error> {
+        if (0..=32760).contains(&offset) && offset % 8 == 0 {
+            writeln!(self.out, "    {op} {reg}, [{base}, #{offset}] // {comment}")?;
+        } else if (-256..=255).contains(&offset) {
+            let unscaled = if op == "ldr" { "ldur" } else { "stur" };
+            writeln!(
+                self.out,
+                "    {unscaled} {reg}, [{base}, #{offset}] // {comment}"
+            )?;
+        } else {
+            self.load_imm("x17", offset, "large offset")?;
+            writeln!(self.out, "    {op} {reg}, [{base}, x17] // {comment}")?;
+        }
+        Ok(())
+    }
+
+    fn load_imm(&mut self, reg: &str, value: i64, comment: &str) -> Result<(), Error> {
+        if (-65536..65536).contains(&value) {
+            writeln!(self.out, "    mov {reg}, #{value} // {comment}")?;
+            return Ok(());
+        }
+        let bits = value as u64;
+        writeln!(
+            self.out,
+            "    movz {reg}, #{:#x} // {comment}",
+            bits & 0xffff
+        )?;
+        for shift in [16, 32, 48] {
+            let chunk = (bits >> shift) & 0xffff;
+            if chunk != 0 {
+                writeln!(self.out, "    movk {reg}, #{chunk:#x}, lsl #{shift}")?;
+            }
+        }
+        Ok(())
+    }
+
+    fn load_address(&mut self, reg: &str, label: &str, comment: &str) -> Result<(), Error> {
+        writeln!(self.out, "    adrp {reg}, {label} // {comment}")?;
+        writeln!(self.out, "    add {reg}, {reg}, :lo12:{label}")?;
+        Ok(())
+    }
+
+    fn adjust_sp(&mut self, op: &str, bytes: usize, comment: &str) -> Result<(), Error> {
+        if bytes < 4096 {
+            writeln!(self.out, "    {op} sp, sp, #{bytes} // {comment}")?;
+        } else {
+            self.load_imm("x16", bytes as i64, comment)?;
+            writeln!(self.out, "    {op} sp, sp, x16")?;
+        }
+        Ok(())
+    }
+
+    fn emit_leave(&mut self, comment: &str) -> Result<(), Error> {
+        writeln!(self.out, "    mov sp, x29 // {comment}")?;
+        writeln!(self.out, "    ldp x29, x30, [sp], #16")?;
+        Ok(())
+    }
+
+    fn new_label(&mut self, suffix: &str) -> String {
+        let idx = self.label_counter;
+        self.label_counter += 1;
+        format!(
+            "{}_{}_{}",
+            crate::sanitize_function_name(&self.air.sig.name),
+            suffix,
+            idx
+        )
+    }
+}
+
+impl<W: Write> ReleaseCopy for FunctionEmitter<'_, W> {
+    fn call_helper_on(
+        &mut self,
+        helper: AirRuntimeHelper,
+        name: &str,
+        note: &str,
+    ) -> Result<(), Error> {
+        self.load_value_into_reg(&AirValue::Binding(name.to_string()), "x0")?;
+        writeln!(self.out, "    bl {} // {}", helper.name(), note)?;
+        Ok(())
+    }
+
+    fn store_helper_result(&mut self, dst: &str) -> Result<(), Error> {
+        writeln!(self.out, "    mov x9, x0")?;
+        self.store_binding_value(dst)
+    }
+
+    fn keep_result(&mut self) -> Result<(), Error> {
+        writeln!(self.out, "    mov x22, x9 // keep result across releases")?;
+        Ok(())
+    }
+
+    fn restore_result(&mut self) -> Result<(), Error> {
+        writeln!(self.out, "    mov x9, x22 // restore result")?;
+        Ok(())
+    }
+
+    fn emit_release_closure(&mut self, name: &str) -> Result<(), Error> {
+        self.load_value_into_reg(&AirValue::Binding(name.to_string()), "x0")?;
+        writeln!(
+            self.out,
+            "    stp {}, x22, [sp, #-16]! // keep the pinned env and a kept result",
+            CLOSURE_ENV_REG
+        )?;
+        writeln!(
+            self.out,
+            "    ldr x10, [x0, #{}] // load {} release helper",
+            ENV_METADATA_RELEASE_OFFSET, name
+        )?;
+        writeln!(
+            self.out,
+            "    blr x10 // release {} and what it holds",
+            name
+        )?;
+        writeln!(self.out, "    ldp {}, x22, [sp], #16", CLOSURE_ENV_REG)?;
+        Ok(())
+    }
+}
+
+fn field_offset(offset: isize) -> i64 {
+    (offset * WORD_SIZE as isize) as i64
+}
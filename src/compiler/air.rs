@@ -1,4 +1,4 @@
-use std::collections::{BTreeSet, HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 
 pub use crate::compiler::air_ast::*;
 use crate::compiler::builtins;
@@ -57,6 +57,7 @@ pub struct FunctionLowerer {
     pending: HashMap<String, hir::Function>,
     lowered: HashSet<String>,
     in_progress: HashSet<String>,
+    records: HashSet<String>,
     generated: Vec<AirFunction>,
 }
 
@@ -66,10 +67,49 @@ impl FunctionLowerer {
             pending: functions,
             lowered: HashSet::new(),
             in_progress: HashSet::new(),
+            records: HashSet::new(),
             generated: Vec::new(),
         }
     }
 
+    /// Generates the helpers whose labels a record's metadata points to. A
+    /// record is laid out like a closure environment holding every field, so
+    /// its release and deepcopy helpers are those of a function taking them.
+    pub fn ensure_record(&mut self, label: &str, fields: &[SigItem]) {
+        if !self.records.insert(label.to_string()) {
+            return;
+        }
+        let env_param = SigItem {
+            name: "env_end".to_string(),
+            kind: SigKind::Int,
+            has_bang: false,
+        };
+        // Records are never entered, but every environment names an unwrapper.
+        let unwrapper = AirFunction {
+            sig: FunctionSig {
+                name: closure_unwrapper_label(label),
+                params: vec![env_param],
+                generics: BTreeSet::new(),
+                builtin: None,
+            },
+            items: vec![AirStmt::op(AirOp::Return(AirReturn { value: None }))],
+            span: Span::unknown(),
+        };
+        let layout = AirFunction {
+            sig: FunctionSig {
+                name: label.to_string(),
+                params: fields.to_vec(),
+                generics: BTreeSet::new(),
+                builtin: None,
+            },
+            items: Vec::new(),
+            span: Span::unknown(),
+        };
+        self.generated.push(unwrapper);
+        self.generated.extend(build_deep_release_helper(&layout));
+        self.generated.extend(build_deep_copy_helper(&layout));
+    }
+
     pub fn ensure(&mut self, name: &str, symbols: &mut SymbolRegistry) -> Result<(), Error> {
         if self.lowered.contains(name) || self.in_progress.contains(name) {
             return Ok(());
@@ -103,7 +143,13 @@ pub struct AirLowerContext<'a> {
     function_lowerer: &'a mut FunctionLowerer,
     locals: HashSet<String>,
     generated_functions: Vec<AirFunction>,
-    unused_params: HashSet<String>,
+    unused_params: HashMap<String, SigKind>,
+    owned_strs: HashSet<String>,
+    owned_arrays: HashSet<String>,
+    owned_maps: HashSet<String>,
+    owned_chans: HashSet<String>,
+    owned_records: HashSet<String>,
+    owned_copies: usize,
     literals: HashMap<String, Lit>,
     closure_remaining: HashMap<String, Vec<SigKind>>, // TODO: Why is this needed?
     remaining_uses: HashMap<String, usize>,
@@ -120,7 +166,13 @@ impl<'a> AirLowerContext<'a> {
             function_lowerer,
             locals: HashSet::new(),
             generated_functions: Vec::new(),
-            unused_params: HashSet::new(),
+            unused_params: HashMap::new(),
+            owned_strs: HashSet::new(),
+            owned_arrays: HashSet::new(),
+            owned_maps: HashSet::new(),
+            owned_chans: HashSet::new(),
+            owned_records: HashSet::new(),
+            owned_copies: 0,
             literals: HashMap::new(),
             closure_remaining: HashMap::new(),
             remaining_uses,
@@ -146,37 +198,181 @@ impl<'a> AirLowerContext<'a> {
     }
 }
 
-fn collect_unused_param_refs(params: &[SigItem]) -> HashSet<String> {
+fn collect_unused_param_refs(params: &[SigItem]) -> HashMap<String, SigKind> {
     params
         .iter()
-        .filter(|param| matches!(param.kind, SigKind::Sig(_)))
-        .map(|param| param.name.clone())
+        .filter(|param| is_owned_type(&param.kind))
+        .map(|param| (param.name.clone(), param.kind.clone()))
         .collect()
 }
 
-fn mark_target(unused: &mut HashSet<String>, target: &AirExecTarget) {
+fn mark_target(unused: &mut HashMap<String, SigKind>, target: &AirExecTarget) {
     if let AirExecTarget::Closure { name } = target {
         unused.remove(name);
     }
 }
 
-fn mark_args(unused: &mut HashSet<String>, args: &[AirArg]) {
+fn mark_args(unused: &mut HashMap<String, SigKind>, args: &[AirArg]) {
     for arg in args {
         unused.remove(&arg.name);
     }
 }
 
-fn take_release_statements(unused: &mut HashSet<String>) -> Vec<AirStmt> {
-    let names: BTreeSet<_> = unused.drain().collect();
-    if names.is_empty() {
-        return Vec::new();
-    }
+fn take_release_statements(unused: &mut HashMap<String, SigKind>) -> Vec<AirStmt> {
+    let names: BTreeMap<_, _> = unused.drain().collect();
     names
         .into_iter()
-        .map(|name| AirStmt::op(AirOp::ReleaseHeap(AirReleaseHeap { name })))
+        .flat_map(|(name, kind)| release_statements(name, &kind))
         .collect()
 }
 
+fn release_statements(name: String, kind: &SigKind) -> Vec<AirStmt> {
+    match kind {
+        SigKind::Str => vec![AirStmt::op(AirOp::ReleaseStr(AirReleaseStr { name }))],
+        SigKind::Array(_) => vec![AirStmt::op(AirOp::ReleaseArray(AirReleaseArray { name }))],
+        SigKind::Map(..) => vec![AirStmt::op(AirOp::ReleaseMap(AirReleaseMap { name }))],
+        SigKind::Chan(_) => vec![AirStmt::op(AirOp::ReleaseChan(AirReleaseChan { name }))],
+        SigKind::Record { fields, .. } => release_record_statements(name, &fields.items, None),
+        SigKind::Sum { .. } => vec![AirStmt::op(AirOp::ReleaseSum(AirReleaseSum { name }))],
+        _ => vec![release_closure_statement(name)],
+    }
+}
+
+/// Releases a closure along with the values it captured.
+fn release_closure_statement(name: String) -> AirStmt {
+    AirStmt::op(AirOp::CallPtr(AirCallPtr {
+        target: AirCallPtrTarget::Binding(name),
+    }))
+}
+
+/// Releases a record together with the strings, arrays, maps and records it owns,
+/// except the field at `moved_out`. Releasing the heap block alone is shallow,
+/// so the owned fields are read out first and released after it.
+fn release_record_statements(
+    name: String,
+    fields: &[SigItem],
+    moved_out: Option<usize>,
+) -> Vec<AirStmt> {
+    let record_end = format!("__{name}_record");
+    let field_count = fields.len() as isize;
+    let owned = fields
+        .iter()
+        .enumerate()
+        .filter(|(idx, field)| is_owned_type(&field.kind) && moved_out != Some(*idx))
+        .map(|(idx, field)| (idx, format!("__{name}_field_{idx}"), field.kind.clone()))
+        .collect::<Vec<_>>();
+    let mut stmts = vec![AirStmt::op(AirOp::Pin(AirPin {
+        result: record_end.clone(),
+        value: AirValue::Binding(name.clone()),
+    }))];
+    for (idx, field_name, kind) in &owned {
+        stmts.push(AirStmt::op(AirOp::Field(AirField {
+            result: field_name.clone(),
+            ptr: record_end.clone(),
+            offset: *idx as isize - field_count,
+            kind: kind.clone(),
+        })));
+    }
+    stmts.push(AirStmt::op(AirOp::ReleaseHeap(AirReleaseHeap { name })));
+    for (_, field_name, kind) in owned {
+        stmts.extend(release_statements(field_name, &kind));
+    }
+    stmts
+}
+
+/// Gives every use of an owned string, array, map, channel, record or closure
+/// except the last its own copy, so that each consumer can release what it was
+/// handed. A copy of a channel is another reference to the same one.
+fn copy_shared_owned_args(
+    ctx: &mut AirLowerContext,
+    args: &mut [AirArg],
+    statements: &mut Vec<AirStmt>,
+) {
+    for idx in 0..args.len() {
+        let name = args[idx].name.clone();
+        let is_array = ctx.owned_arrays.contains(&name);
+        let is_map = ctx.owned_maps.contains(&name);
+        let is_chan = ctx.owned_chans.contains(&name);
+        let is_record = ctx.owned_records.contains(&name);
+        let is_closure = matches!(args[idx].kind, SigKind::Sig(_));
+        if args[idx].literal.is_some()
+            || !(is_array
+                || is_map
+                || is_chan
+                || is_record
+                || is_closure
+                || ctx.owned_strs.contains(&name))
+        {
+            continue;
+        }
+        let used_again = args[idx + 1..].iter().any(|arg| arg.name == name)
+            || ctx
+                .remaining_uses
+                .get(&name)
+                .is_some_and(|count| *count > 0);
+        if !used_again {
+            continue;
+        }
+        let dst = format!("__{}_copy_{}", name, ctx.owned_copies);
+        ctx.owned_copies += 1;
+        if is_array {
+            statements.push(AirStmt::op(AirOp::CopyArray(AirCopyArray {
+                src: name,
+                dst: dst.clone(),
+            })));
+            ctx.owned_arrays.insert(dst.clone());
+        } else if is_map {
+            statements.push(AirStmt::op(AirOp::CopyMap(AirCopyMap {
+                src: name,
+                dst: dst.clone(),
+            })));
+            ctx.owned_maps.insert(dst.clone());
+        } else if is_chan {
+            statements.push(AirStmt::op(AirOp::CopyChan(AirCopyChan {
+                src: name,
+                dst: dst.clone(),
+            })));
+            ctx.owned_chans.insert(dst.clone());
+        } else if is_record {
+            statements.push(AirStmt::op(AirOp::CloneClosure(AirCloneClosure {
+                src: name,
+                dst: dst.clone(),
+                remaining: Vec::new(),
+            })));
+            ctx.owned_records.insert(dst.clone());
+        } else if is_closure {
+            let remaining = ctx.closure_remaining.get(&name).cloned();
+            statements.push(AirStmt::op(AirOp::CloneClosure(AirCloneClosure {
+                src: name,
+                dst: dst.clone(),
+                remaining: remaining.clone().unwrap_or_default(),
+            })));
+            ctx.locals.insert(dst.clone());
+            if let Some(remaining) = remaining {
+                ctx.closure_remaining.insert(dst.clone(), remaining);
+            }
+        } else {
+            statements.push(AirStmt::op(AirOp::CopyStr(AirCopyStr {
+                src: name,
+                dst: dst.clone(),
+            })));
+            ctx.owned_strs.insert(dst.clone());
+        }
+        args[idx].name = dst;
+    }
+}
+
+/// Lists the owned strings among `args` that a builtin consumes.
+fn consumed_strs(owned_strs: &HashSet<String>, args: &[AirArg]) -> Vec<String> {
+    let mut names = Vec::new();
+    for arg in args {
+        if arg.literal.is_none() && owned_strs.contains(&arg.name) && !names.contains(&arg.name) {
+            names.push(arg.name.clone());
+        }
+    }
+    names
+}
+
 fn prepare_args(
     ctx: &mut AirLowerContext,
     args: &[String],
@@ -257,6 +453,7 @@ pub fn entry_function(
             builtin: None,
         },
         items,
+        span: Span::unknown(),
     });
     Ok(ctx.into_generated_functions())
 }
@@ -281,6 +478,24 @@ pub fn lower_function(
             ctx.closure_remaining
                 .insert(param.name.clone(), signature.kinds());
         }
+        match param.kind {
+            SigKind::Str => {
+                ctx.owned_strs.insert(param.name.clone());
+            }
+            SigKind::Array(_) => {
+                ctx.owned_arrays.insert(param.name.clone());
+            }
+            SigKind::Map(..) => {
+                ctx.owned_maps.insert(param.name.clone());
+            }
+            SigKind::Chan(_) => {
+                ctx.owned_chans.insert(param.name.clone());
+            }
+            SigKind::Record { .. } | SigKind::Sum { .. } => {
+                ctx.owned_records.insert(param.name.clone());
+            }
+            _ => {}
+        }
     }
     ctx.unused_params = collect_unused_param_refs(&params);
 
@@ -293,6 +508,7 @@ pub fn lower_function(
     let function = AirFunction {
         sig,
         items: lowered_items,
+        span: func.span,
     };
 
     let mut functions: Vec<AirFunction> = vec![function.clone()];
@@ -338,13 +554,20 @@ fn air_sig_kind_from_hir(kind: &SigKind, generics: &BTreeSet<String>) -> SigKind
             items: air_sig_items_from_hir(&signature.items, generics),
             generics: signature.generics.clone(),
         }),
-        SigKind::GenericInst { name, args } => SigKind::GenericInst {
+        SigKind::GenericInst { name, args, span } => SigKind::GenericInst {
             name: name.clone(),
+            span: *span,
             args: args
                 .iter()
                 .map(|arg| air_sig_kind_from_hir(arg, generics))
                 .collect(),
         },
+        SigKind::Array(elem) => SigKind::Array(Box::new(air_sig_kind_from_hir(elem, generics))),
+        SigKind::Map(key, value) => SigKind::Map(
+            Box::new(air_sig_kind_from_hir(key, generics)),
+            Box::new(air_sig_kind_from_hir(value, generics)),
+        ),
+        SigKind::Chan(elem) => SigKind::Chan(Box::new(air_sig_kind_from_hir(elem, generics))),
         other => other.clone(),
     }
 }
@@ -377,6 +600,12 @@ fn lower_block_item(
                 lower_new_closure(&closure, ctx)?
             }
         }
+        hir::BlockItem::ArrayDef(array) => lower_array_def(&array, ctx),
+        hir::BlockItem::MapDef(map) => lower_map_def(&map, ctx),
+        hir::BlockItem::NewRecord(record) => lower_new_record(&record, ctx),
+        hir::BlockItem::FieldDef(field) => lower_field_def(&field, ctx),
+        hir::BlockItem::NewVariant(variant) => lower_new_variant(&variant, ctx),
+        hir::BlockItem::Case(case) => lower_case(&case, ctx)?,
         hir::BlockItem::Exec(exec) => lower_exec(&exec, ctx)?,
         _ => unreachable!("unexpected block item: {:#?}", item),
     };
@@ -399,12 +628,376 @@ fn count_block_uses(items: &[hir::BlockItem]) -> HashMap<String, usize> {
                     *uses.entry(arg.clone()).or_insert(0) += 1;
                 }
             }
+            hir::BlockItem::ArrayDef(array) => {
+                for item in &array.items {
+                    *uses.entry(item.clone()).or_insert(0) += 1;
+                }
+            }
+            hir::BlockItem::MapDef(map) => {
+                for (key, value) in &map.entries {
+                    *uses.entry(key.clone()).or_insert(0) += 1;
+                    *uses.entry(value.clone()).or_insert(0) += 1;
+                }
+            }
+            hir::BlockItem::NewRecord(record) => {
+                for field in &record.fields {
+                    *uses.entry(field.clone()).or_insert(0) += 1;
+                }
+            }
+            hir::BlockItem::FieldDef(field) => {
+                *uses.entry(field.record.clone()).or_insert(0) += 1;
+            }
+            hir::BlockItem::NewVariant(variant) => {
+                for value in &variant.payload {
+                    *uses.entry(value.clone()).or_insert(0) += 1;
+                }
+            }
+            hir::BlockItem::Case(case) => {
+                *uses.entry(case.value.clone()).or_insert(0) += 1;
+                for arm in &case.arms {
+                    *uses.entry(arm.clone()).or_insert(0) += 1;
+                }
+            }
             _ => {}
         }
     }
     uses
 }
 
+/// Builds an array from its items, which move into it unless they are used
+/// again later.
+fn lower_array_def(array: &hir::ArrayDef, ctx: &mut AirLowerContext) -> Vec<AirStmt> {
+    for item in &array.items {
+        ctx.count_remaining_use(item);
+    }
+    let mut items = array
+        .items
+        .iter()
+        .map(|item| AirArg {
+            name: item.clone(),
+            kind: array.elem.clone(),
+            literal: literal_for_arg(item, &ctx.literals),
+        })
+        .collect::<Vec<_>>();
+    mark_args(&mut ctx.unused_params, &items);
+    let mut block_items = Vec::new();
+    copy_shared_owned_args(ctx, &mut items, &mut block_items);
+    ctx.locals.insert(array.name.clone());
+    ctx.owned_arrays.insert(array.name.clone());
+    block_items.push(AirStmt::op(AirOp::NewArray(AirNewArray {
+        name: array.name.clone(),
+        elem: array.elem.clone(),
+        items,
+    })));
+    block_items
+}
+
+/// Builds a map from its entries. Like array items, the keys and values move
+/// into it unless they are used again later.
+fn lower_map_def(map: &hir::MapDef, ctx: &mut AirLowerContext) -> Vec<AirStmt> {
+    let mut args = Vec::with_capacity(map.entries.len() * 2);
+    for (key, value) in &map.entries {
+        for (name, kind) in [(key, &map.key), (value, &map.value)] {
+            ctx.count_remaining_use(name);
+            args.push(AirArg {
+                name: name.clone(),
+                kind: kind.clone(),
+                literal: literal_for_arg(name, &ctx.literals),
+            });
+        }
+    }
+    mark_args(&mut ctx.unused_params, &args);
+    let mut block_items = Vec::new();
+    copy_shared_owned_args(ctx, &mut args, &mut block_items);
+    ctx.locals.insert(map.name.clone());
+    ctx.owned_maps.insert(map.name.clone());
+    let mut args = args.into_iter();
+    let entries = std::iter::from_fn(|| Some((args.next()?, args.next()?))).collect();
+    block_items.push(AirStmt::op(AirOp::NewMap(AirNewMap {
+        name: map.name.clone(),
+        key: map.key.clone(),
+        value: map.value.clone(),
+        entries,
+    })));
+    block_items
+}
+
+/// Builds a record from one value per field. Like array items, the values move
+/// into it unless they are used again later.
+fn lower_new_record(record: &hir::NewRecord, ctx: &mut AirLowerContext) -> Vec<AirStmt> {
+    let SigKind::Record {
+        name: label,
+        fields,
+    } = &record.record
+    else {
+        unreachable!("records are built from record kinds");
+    };
+    for value in &record.fields {
+        ctx.count_remaining_use(value);
+    }
+    let mut args = record
+        .fields
+        .iter()
+        .zip(&fields.items)
+        .map(|(value, field)| AirArg {
+            name: value.clone(),
+            kind: field.kind.clone(),
+            literal: literal_for_arg(value, &ctx.literals),
+        })
+        .collect::<Vec<_>>();
+    mark_args(&mut ctx.unused_params, &args);
+    let mut block_items = Vec::new();
+    copy_shared_owned_args(ctx, &mut args, &mut block_items);
+    ctx.function_lowerer.ensure_record(label, &fields.items);
+    ctx.locals.insert(record.name.clone());
+    ctx.owned_records.insert(record.name.clone());
+    // A record nobody takes is released at the next jump.
+    ctx.unused_params
+        .insert(record.name.clone(), record.record.clone());
+    block_items.push(AirStmt::op(AirOp::NewClosure(AirNewClosure {
+        name: record.name.clone(),
+        target: FunctionSig {
+            name: label.clone(),
+            params: fields.items.clone(),
+            generics: BTreeSet::new(),
+            builtin: None,
+        },
+        args,
+    })));
+    block_items
+}
+
+/// Reads a record field. The last read of a record moves the field out and
+/// releases the rest of it; earlier reads of owned fields take a copy.
+fn lower_field_def(field: &hir::FieldDef, ctx: &mut AirLowerContext) -> Vec<AirStmt> {
+    let SigKind::Record { fields, .. } = &field.kind else {
+        unreachable!("fields are only read from records");
+    };
+    let last_read =
+        ctx.count_remaining_use(&field.record) <= 1 && ctx.owned_records.contains(&field.record);
+    let record_end = format!("__{}_record", field.name);
+    let kind = fields.items[field.index].kind.clone();
+    let copied = is_owned_type(&kind) && !last_read;
+    let read = if copied {
+        format!("__{}_shared", field.name)
+    } else {
+        field.name.clone()
+    };
+    let mut block_items = vec![
+        AirStmt::op(AirOp::Pin(AirPin {
+            result: record_end.clone(),
+            value: AirValue::Binding(field.record.clone()),
+        })),
+        AirStmt::op(AirOp::Field(AirField {
+            result: read.clone(),
+            ptr: record_end,
+            offset: field.index as isize - fields.items.len() as isize,
+            kind: kind.clone(),
+        })),
+    ];
+    if copied {
+        let dst = field.name.clone();
+        block_items.push(AirStmt::op(match &kind {
+            SigKind::Str => AirOp::CopyStr(AirCopyStr { src: read, dst }),
+            SigKind::Array(_) => AirOp::CopyArray(AirCopyArray { src: read, dst }),
+            SigKind::Map(..) => AirOp::CopyMap(AirCopyMap { src: read, dst }),
+            SigKind::Chan(_) => AirOp::CopyChan(AirCopyChan { src: read, dst }),
+            _ => AirOp::CloneClosure(AirCloneClosure {
+                src: read,
+                dst,
+                remaining: Vec::new(),
+            }),
+        }));
+    }
+    if is_owned_type(&kind) {
+        match &kind {
+            SigKind::Str => {
+                ctx.owned_strs.insert(field.name.clone());
+            }
+            SigKind::Array(_) => {
+                ctx.owned_arrays.insert(field.name.clone());
+            }
+            SigKind::Map(..) => {
+                ctx.owned_maps.insert(field.name.clone());
+            }
+            SigKind::Chan(_) => {
+                ctx.owned_chans.insert(field.name.clone());
+            }
+            SigKind::Record { .. } | SigKind::Sum { .. } => {
+                ctx.owned_records.insert(field.name.clone());
+            }
+            SigKind::Sig(signature) => {
+                ctx.closure_remaining
+                    .insert(field.name.clone(), signature.kinds());
+            }
+            _ => {}
+        }
+    }
+    ctx.locals.insert(field.name.clone());
+    if last_read {
+        ctx.unused_params.remove(&field.record);
+        block_items.extend(release_record_statements(
+            field.record.clone(),
+            &fields.items,
+            Some(field.index),
+        ));
+    }
+    block_items
+}
+
+/// The label and fields of the record that holds variant `tag` of `sum`: its
+/// payload followed by the tag, so that the tag is always the last word.
+fn variant_layout(sum: &SigKind, tag: usize) -> (String, Vec<SigItem>) {
+    let SigKind::Sum { name, variants } = sum else {
+        unreachable!("variants belong to sums");
+    };
+    let variant = &variants.items[tag];
+    let SigKind::Sig(payload) = &variant.kind else {
+        unreachable!("variants hold payload signatures");
+    };
+    let mut fields = payload.items.clone();
+    fields.push(SigItem {
+        name: "__tag".to_string(),
+        kind: SigKind::Int,
+        has_bang: false,
+    });
+    (format!("{}__{}", name, variant.name), fields)
+}
+
+/// Builds a sum value. Each variant is a record of its own, so the release and
+/// deepcopy helpers its metadata names handle exactly what that variant holds.
+fn lower_new_variant(variant: &hir::NewVariant, ctx: &mut AirLowerContext) -> Vec<AirStmt> {
+    let (label, fields) = variant_layout(&variant.sum, variant.tag);
+    for value in &variant.payload {
+        ctx.count_remaining_use(value);
+    }
+    let mut args = variant
+        .payload
+        .iter()
+        .zip(&fields)
+        .map(|(value, field)| AirArg {
+            name: value.clone(),
+            kind: field.kind.clone(),
+            literal: literal_for_arg(value, &ctx.literals),
+        })
+        .collect::<Vec<_>>();
+    mark_args(&mut ctx.unused_params, &args);
+    let mut block_items = Vec::new();
+    copy_shared_owned_args(ctx, &mut args, &mut block_items);
+    args.push(AirArg {
+        name: format!("__{}_tag", variant.name),
+        kind: SigKind::Int,
+        literal: Some(Lit::Int(variant.tag as isize)),
+    });
+    ctx.function_lowerer.ensure_record(&label, &fields);
+    ctx.locals.insert(variant.name.clone());
+    ctx.owned_records.insert(variant.name.clone());
+    ctx.unused_params
+        .insert(variant.name.clone(), variant.sum.clone());
+    block_items.push(AirStmt::op(AirOp::NewClosure(AirNewClosure {
+        name: variant.name.clone(),
+        target: FunctionSig {
+            name: label,
+            params: fields,
+            generics: BTreeSet::new(),
+            builtin: None,
+        },
+        args,
+    })));
+    block_items
+}
+
+/// Jumps through a table indexed by the tag to one branch per variant. Each
+/// branch releases the arms it does not take, moves the payload out of the
+/// value and passes it to its arm. A value used again is copied first.
+fn lower_case(case: &hir::Case, ctx: &mut AirLowerContext) -> Result<Vec<AirStmt>, Error> {
+    let SigKind::Sum { variants, .. } = &case.sum else {
+        unreachable!("cases analyse sums");
+    };
+    ctx.count_remaining_use(&case.value);
+    for arm in &case.arms {
+        ctx.count_remaining_use(arm);
+    }
+    let mut block_items = Vec::new();
+    prepare_args(ctx, &case.arms, &mut block_items)?;
+    let mut value = vec![AirArg {
+        name: case.value.clone(),
+        kind: case.sum.clone(),
+        literal: None,
+    }];
+    mark_args(&mut ctx.unused_params, &value);
+    for arm in &case.arms {
+        mark_target(
+            &mut ctx.unused_params,
+            &AirExecTarget::Closure { name: arm.clone() },
+        );
+    }
+    copy_shared_owned_args(ctx, &mut value, &mut block_items);
+    let value = value.remove(0).name;
+    block_items.extend(take_release_statements(&mut ctx.unused_params));
+    let sum_end = format!("__{value}_sum");
+    let tag = format!("__{value}_tag");
+    block_items.push(AirStmt::op(AirOp::Pin(AirPin {
+        result: sum_end.clone(),
+        value: AirValue::Binding(value.clone()),
+    })));
+    block_items.push(AirStmt::op(AirOp::Field(AirField {
+        result: tag.clone(),
+        ptr: sum_end.clone(),
+        offset: -1,
+        kind: SigKind::Int,
+    })));
+
+    let labels = case
+        .arms
+        .iter()
+        .map(|arm| format!("{}_case", crate::sanitize_function_name(arm)))
+        .collect::<Vec<_>>();
+    block_items.push(AirStmt::op(AirOp::JumpTable(AirJumpTable {
+        index: AirValue::Binding(tag),
+        targets: labels.clone(),
+    })));
+    for (taken, (label, variant)) in labels.into_iter().zip(&variants.items).enumerate() {
+        let SigKind::Sig(payload) = &variant.kind else {
+            unreachable!("variants hold payload signatures");
+        };
+        block_items.push(AirStmt::Label(AirLabel { name: label }));
+        block_items.push(AirStmt::op(AirOp::Pin(AirPin {
+            result: sum_end.clone(),
+            value: AirValue::Binding(value.clone()),
+        })));
+        let word_count = payload.items.len() as isize + 1;
+        let mut args = Vec::with_capacity(payload.items.len());
+        for (idx, item) in payload.items.iter().enumerate() {
+            let name = format!("__{value}_{taken}_{idx}");
+            block_items.push(AirStmt::op(AirOp::Field(AirField {
+                result: name.clone(),
+                ptr: sum_end.clone(),
+                offset: idx as isize - word_count,
+                kind: item.kind.clone(),
+            })));
+            args.push(AirArg {
+                name,
+                kind: item.kind.clone(),
+                literal: None,
+            });
+        }
+        block_items.push(AirStmt::op(AirOp::ReleaseHeap(AirReleaseHeap {
+            name: value.clone(),
+        })));
+        for (idx, arm) in case.arms.iter().enumerate() {
+            if idx != taken {
+                block_items.push(release_closure_statement(arm.clone()));
+            }
+        }
+        block_items.push(AirStmt::op(AirOp::JumpClosure(AirJumpClosure {
+            env_end: case.arms[taken].clone(),
+            args,
+        })));
+    }
+    Ok(block_items)
+}
+
 fn ensure_target(
     ctx: &mut AirLowerContext,
     args: &[String],
@@ -451,10 +1044,11 @@ fn lower_new_closure(
     for arg in &closure.args {
         ctx.count_remaining_use(arg);
     }
-    let (mut block_items, target, args) = ensure_target(ctx, &closure.args, &closure.of)?;
+    let (mut block_items, target, mut args) = ensure_target(ctx, &closure.args, &closure.of)?;
     ctx.locals.insert(closure.name.clone());
     mark_target(&mut ctx.unused_params, &target);
     mark_args(&mut ctx.unused_params, &args);
+    copy_shared_owned_args(ctx, &mut args, &mut block_items);
 
     let new_remaining =
         closure_remaining_after_applying(&ctx.closure_remaining, &target, args.len());
@@ -497,6 +1091,7 @@ fn lower_closure_curry(
         })?;
 
     let (mut block_items, _, _) = ensure_target(ctx, &closure.args, &closure.of)?;
+    let last_use = ctx.count_remaining_use(&closure.of) <= 1;
 
     let applied = closure.args.len().min(existing_remaining.len());
     let mut args = Vec::with_capacity(closure.args.len());
@@ -522,23 +1117,79 @@ fn lower_closure_curry(
         dst: closure.name.clone(),
         remaining: existing_remaining.clone(),
     })));
+    // The copy takes over from a closure nothing uses again.
+    if last_use {
+        block_items.push(release_closure_statement(closure.of.clone()));
+    }
 
     let mut stored_args = Vec::with_capacity(args.len());
     for (idx, arg) in args.iter().enumerate() {
         let arg_use_count = ctx.count_remaining_use(&arg.name);
-        let should_clone_arg = matches!(arg.kind, SigKind::Sig(_)) && arg_use_count > 1;
-        if should_clone_arg {
-            let arg_remaining = ctx
-                .closure_remaining
-                .get(&arg.name)
-                .cloned()
-                .ok_or_else(|| {
-                    Error::new(
-                        Code::Internal,
-                        format!("missing closure signature for '{}'", arg.name),
-                        Span::unknown(),
-                    )
-                })?;
+        let should_clone_arg = matches!(
+            arg.kind,
+            SigKind::Sig(_) | SigKind::Record { .. } | SigKind::Sum { .. }
+        ) && arg_use_count > 1;
+        let should_copy_str =
+            arg.literal.is_none() && ctx.owned_strs.contains(&arg.name) && arg_use_count > 1;
+        let should_copy_array = ctx.owned_arrays.contains(&arg.name) && arg_use_count > 1;
+        let should_copy_map = ctx.owned_maps.contains(&arg.name) && arg_use_count > 1;
+        let should_copy_chan = ctx.owned_chans.contains(&arg.name) && arg_use_count > 1;
+        if should_copy_chan {
+            let copy_name = format!("__{}_arg_copy_{}", closure.name, idx);
+            block_items.push(AirStmt::op(AirOp::CopyChan(AirCopyChan {
+                src: arg.name.clone(),
+                dst: copy_name.clone(),
+            })));
+            stored_args.push(AirArg {
+                name: copy_name,
+                kind: arg.kind.clone(),
+                literal: None,
+            });
+        } else if should_copy_map {
+            let copy_name = format!("__{}_arg_copy_{}", closure.name, idx);
+            block_items.push(AirStmt::op(AirOp::CopyMap(AirCopyMap {
+                src: arg.name.clone(),
+                dst: copy_name.clone(),
+            })));
+            stored_args.push(AirArg {
+                name: copy_name,
+                kind: arg.kind.clone(),
+                literal: None,
+            });
+        } else if should_copy_array {
+            let copy_name = format!("__{}_arg_copy_{}", closure.name, idx);
+            block_items.push(AirStmt::op(AirOp::CopyArray(AirCopyArray {
+                src: arg.name.clone(),
+                dst: copy_name.clone(),
+            })));
+            stored_args.push(AirArg {
+                name: copy_name,
+                kind: arg.kind.clone(),
+                literal: None,
+            });
+        } else if should_copy_str {
+            let copy_name = format!("__{}_arg_copy_{}", closure.name, idx);
+            block_items.push(AirStmt::op(AirOp::CopyStr(AirCopyStr {
+                src: arg.name.clone(),
+                dst: copy_name.clone(),
+            })));
+            stored_args.push(AirArg {
+                name: copy_name,
+                kind: arg.kind.clone(),
+                literal: None,
+            });
+        } else if should_clone_arg {
+            let arg_remaining = match arg.kind {
+                SigKind::Record { .. } | SigKind::Sum { .. } => Some(Vec::new()),
+                _ => ctx.closure_remaining.get(&arg.name).cloned(),
+            }
+            .ok_or_else(|| {
+                Error::new(
+                    Code::Internal,
+                    format!("missing closure signature for '{}'", arg.name),
+                    Span::unknown(),
+                )
+            })?;
             let clone_name = format!("__{}_arg_clone_{}", closure.name, idx);
             block_items.push(AirStmt::op(AirOp::CloneClosure(AirCloneClosure {
                 src: arg.name.clone(),
@@ -595,14 +1246,17 @@ fn lower_exec(exec: &hir::Exec, ctx: &mut AirLowerContext) -> Result<Vec<AirStmt
     for arg in &exec.args {
         ctx.count_remaining_use(arg);
     }
-    let (mut block_items, target, args) = ensure_target(ctx, &exec.args, &exec.of)?;
+    let (mut block_items, target, mut args) = ensure_target(ctx, &exec.args, &exec.of)?;
     mark_target(&mut ctx.unused_params, &target);
     mark_args(&mut ctx.unused_params, &args);
+    copy_shared_owned_args(ctx, &mut args, &mut block_items);
 
     if let AirExecTarget::Function(sig) = &target {
         if let Some(builtin) = sig.builtin {
             if builtin.is_call() {
-                let builtin_items = lower_builtin_call(sig, builtin, args, &mut ctx.unused_params)?;
+                let release = consumed_strs(&ctx.owned_strs, &args);
+                let builtin_items =
+                    lower_builtin_call(sig, builtin, args, release, &mut ctx.unused_params)?;
                 block_items.extend(builtin_items);
                 return Ok(block_items);
             }
@@ -617,8 +1271,12 @@ fn lower_exec(exec: &hir::Exec, ctx: &mut AirLowerContext) -> Result<Vec<AirStmt
                         );
                     }
                 }
+                if builtin == builtins::Builtin::Chan {
+                    resolve_chan_elem(ctx, &mut args);
+                }
+                let release = consumed_strs(&ctx.owned_strs, &args);
                 block_items.extend(take_release_statements(&mut ctx.unused_params));
-                block_items.extend(build_builtin_statements(sig, builtin, args));
+                block_items.extend(build_builtin_statements(sig, builtin, args, release));
                 return Ok(block_items);
             }
         }
@@ -642,11 +1300,25 @@ fn lower_exec(exec: &hir::Exec, ctx: &mut AirLowerContext) -> Result<Vec<AirStmt
     Ok(block_items)
 }
 
+/// Gives the continuation of `@chan` the kinds its closure takes, so the new
+/// channel knows how to release values still queued on it. The builtin's own
+/// signature only says `@chan<T>`.
+fn resolve_chan_elem(ctx: &AirLowerContext, args: &mut [AirArg]) {
+    if let Some([SigKind::Chan(elem)]) = args
+        .first()
+        .and_then(|ok| ctx.closure_remaining.get(&ok.name))
+        .map(Vec::as_slice)
+    {
+        args[0].kind = SigKind::tuple([SigKind::Chan(elem.clone())]);
+    }
+}
+
 fn lower_builtin_call(
     sig: &FunctionSig,
     builtin: builtins::Builtin,
     args: Vec<AirArg>,
-    unused_params: &mut HashSet<String>,
+    release: Vec<String>,
+    unused_params: &mut HashMap<String, SigKind>,
 ) -> Result<Vec<AirStmt>, Error> {
     let mut stmts = Vec::new();
 
@@ -663,7 +1335,7 @@ fn lower_builtin_call(
     }
 
     stmts.extend(take_release_statements(unused_params));
-    stmts.push(AirStmt::op(call_op(builtin, args)));
+    stmts.push(AirStmt::op(call_op(builtin, args, release)));
 
     Ok(stmts)
 }
@@ -694,6 +1366,7 @@ fn build_closure_unwrapper(function: &AirFunction) -> Option<AirFunction> {
         function.sig.clone(),
         env_param,
         function.sig.params.clone(),
+        function.span,
     ))
 }
 
@@ -781,6 +1454,7 @@ fn build_unwrapper_function(
     target_sig: FunctionSig,
     env_param: SigItem,
     field_sig_items: Vec<SigItem>,
+    span: Span,
 ) -> AirFunction {
     let env_end_reg = "__env_end".to_string();
     let env_word_count = env_word_count_from_params(&field_sig_items);
@@ -818,7 +1492,17 @@ fn build_unwrapper_function(
 
     if let Some(builtin) = target_sig.builtin {
         if builtin.is_call() || is_inline_builtin(builtin) {
-            items.extend(build_builtin_statements(&target_sig, builtin, builtin_args));
+            let release = field_sig_items
+                .iter()
+                .filter(|item| matches!(item.kind, SigKind::Str))
+                .map(|item| item.name.clone())
+                .collect();
+            items.extend(build_builtin_statements(
+                &target_sig,
+                builtin,
+                builtin_args,
+                release,
+            ));
         } else {
             items.push(AirStmt::op(AirOp::JumpArgs(AirJumpArgs {
                 target: target_sig.clone(),
@@ -840,6 +1524,7 @@ fn build_unwrapper_function(
             builtin: None,
         },
         items,
+        span,
     }
 }
 
@@ -868,7 +1553,7 @@ fn build_deep_release_helper(function: &AirFunction) -> Option<AirFunction> {
         .iter()
         .enumerate()
         .filter_map(|(idx, param)| {
-            if !is_reference_type(&param.kind) {
+            if !is_owned_type(&param.kind) {
                 return None;
             }
             let offset_from_end = env_word_count.saturating_sub(offsets[idx]);
@@ -903,9 +1588,31 @@ fn build_deep_release_helper(function: &AirFunction) -> Option<AirFunction> {
                 offset: *offset,
                 kind: kind.clone(),
             })));
-            items.push(AirStmt::op(AirOp::CallPtr(AirCallPtr {
-                target: AirCallPtrTarget::Binding(location),
-            })));
+            if matches!(kind, SigKind::Str) {
+                items.push(AirStmt::op(AirOp::ReleaseStr(AirReleaseStr {
+                    name: location,
+                })));
+            } else if matches!(kind, SigKind::Array(_)) {
+                items.push(AirStmt::op(AirOp::ReleaseArray(AirReleaseArray {
+                    name: location,
+                })));
+            } else if matches!(kind, SigKind::Map(..)) {
+                items.push(AirStmt::op(AirOp::ReleaseMap(AirReleaseMap {
+                    name: location,
+                })));
+            } else if matches!(kind, SigKind::Chan(_)) {
+                items.push(AirStmt::op(AirOp::ReleaseChan(AirReleaseChan {
+                    name: location,
+                })));
+            } else if matches!(kind, SigKind::Sum { .. }) {
+                items.push(AirStmt::op(AirOp::ReleaseSum(AirReleaseSum {
+                    name: location,
+                })));
+            } else {
+                items.push(AirStmt::op(AirOp::CallPtr(AirCallPtr {
+                    target: AirCallPtrTarget::Binding(location),
+                })));
+            }
             items.push(AirStmt::Label(AirLabel { name: skip_label }));
         }
     }
@@ -924,6 +1631,7 @@ fn build_deep_release_helper(function: &AirFunction) -> Option<AirFunction> {
             builtin: None,
         },
         items,
+        span: function.span,
     })
 }
 
@@ -951,7 +1659,7 @@ fn build_deep_copy_helper(function: &AirFunction) -> Option<AirFunction> {
         .iter()
         .enumerate()
         .filter_map(|(idx, param)| {
-            if !is_reference_type(&param.kind) {
+            if !is_owned_type(&param.kind) {
                 return None;
             }
             let env_offset_from_start = offsets[idx];
@@ -995,6 +1703,7 @@ fn build_deep_copy_helper(function: &AirFunction) -> Option<AirFunction> {
             builtin: None,
         },
         items,
+        span: function.span,
     })
 }
 
@@ -1015,11 +1724,21 @@ fn suffix_word_counts(kinds: &[SigKind]) -> Vec<usize> {
     (0..len).map(|idx| len - idx).collect()
 }
 
-fn is_reference_type(ty: &SigKind) -> bool {
-    matches!(ty, SigKind::Sig(_))
+/// Whether a value of this kind owns heap memory that travels with it.
+fn is_owned_type(ty: &SigKind) -> bool {
+    matches!(
+        ty,
+        SigKind::Sig(_)
+            | SigKind::Str
+            | SigKind::Array(_)
+            | SigKind::Map(..)
+            | SigKind::Chan(_)
+            | SigKind::Record { .. }
+            | SigKind::Sum { .. }
+    )
 }
 
-fn instruction_op(builtin: builtins::Builtin, args: Vec<AirArg>) -> AirOp {
+fn instruction_op(builtin: builtins::Builtin, args: Vec<AirArg>, release: Vec<String>) -> AirOp {
     let arg_len = args.len();
     let continuation_target = args
         .last()
@@ -1053,6 +1772,14 @@ fn instruction_op(builtin: builtins::Builtin, args: Vec<AirArg>) -> AirOp {
                 target: continuation_target,
             })
         }
+        builtins::Builtin::SubF64 => {
+            let (input_a, input_b) = binary_input_args(builtin.name(), inputs);
+            AirOp::SubF64(AirSubF64 {
+                input_a,
+                input_b,
+                target: continuation_target,
+            })
+        }
         builtins::Builtin::Mul => {
             let (input_a, input_b) = binary_input_args(builtin.name(), inputs);
             AirOp::Mul(AirMul {
@@ -1084,6 +1811,84 @@ fn instruction_op(builtin: builtins::Builtin, args: Vec<AirArg>) -> AirOp {
                 ok_target: continuation_target,
             })
         }
+        builtins::Builtin::Rem => {
+            let err_target = args
+                .get(arg_len - 2)
+                .expect("rem requires an error continuation")
+                .name
+                .clone();
+            let (input_a, input_b) =
+                binary_input_args(builtin.name(), args[..arg_len - 2].to_vec());
+            AirOp::RemInt(AirDivInt {
+                input_a,
+                input_b,
+                err_target,
+                ok_target: continuation_target,
+            })
+        }
+        builtins::Builtin::And => {
+            let (input_a, input_b) = binary_input_args(builtin.name(), inputs);
+            AirOp::And(AirAnd {
+                input_a,
+                input_b,
+                target: continuation_target,
+            })
+        }
+        builtins::Builtin::Or => {
+            let (input_a, input_b) = binary_input_args(builtin.name(), inputs);
+            AirOp::Or(AirOr {
+                input_a,
+                input_b,
+                target: continuation_target,
+            })
+        }
+        builtins::Builtin::Xor => {
+            let (input_a, input_b) = binary_input_args(builtin.name(), inputs);
+            AirOp::Xor(AirXor {
+                input_a,
+                input_b,
+                target: continuation_target,
+            })
+        }
+        builtins::Builtin::Shl => {
+            let (input_a, input_b) = binary_input_args(builtin.name(), inputs);
+            AirOp::Shl(AirShl {
+                input_a,
+                input_b,
+                target: continuation_target,
+            })
+        }
+        builtins::Builtin::Shr => {
+            let (input_a, input_b) = binary_input_args(builtin.name(), inputs);
+            AirOp::Shr(AirShr {
+                input_a,
+                input_b,
+                target: continuation_target,
+            })
+        }
+        builtins::Builtin::Neg => {
+            let input = inputs.into_iter().next().expect("neg requires an int");
+            AirOp::Neg(AirNeg {
+                input,
+                target: continuation_target,
+            })
+        }
+        builtins::Builtin::AddC | builtins::Builtin::SubC | builtins::Builtin::MulC => {
+            let [input_a, input_b, overflow]: [AirArg; 3] = inputs
+                .try_into()
+                .expect("checked arithmetic requires two ints and an overflow continuation");
+            let checked = AirChecked {
+                input_a,
+                input_b,
+                overflow_target: overflow.name,
+                ok_target: continuation_target,
+            };
+            match builtin {
+                builtins::Builtin::AddC => AirOp::AddChecked(checked),
+                builtins::Builtin::SubC => AirOp::SubChecked(checked),
+                _ => AirOp::MulChecked(checked),
+            }
+        }
         builtins::Builtin::DivF64 => {
             let (input_a, input_b) = binary_input_args(builtin.name(), inputs);
             AirOp::DivF64(AirDivF64 {
@@ -1092,6 +1897,37 @@ fn instruction_op(builtin: builtins::Builtin, args: Vec<AirArg>) -> AirOp {
                 target: continuation_target,
             })
         }
+        builtins::Builtin::NegF64 => {
+            let input = inputs.into_iter().next().expect("negf64 requires a float");
+            AirOp::NegF64(AirNegF64 {
+                input,
+                target: continuation_target,
+            })
+        }
+        builtins::Builtin::SqrtF64 => {
+            let input = inputs.into_iter().next().expect("sqrtf64 requires a float");
+            AirOp::SqrtF64(AirSqrtF64 {
+                input,
+                target: continuation_target,
+            })
+        }
+        builtins::Builtin::IntToF64 => {
+            let input = inputs.into_iter().next().expect("itof requires an int");
+            AirOp::IntToF64(AirIntToF64 {
+                input,
+                target: continuation_target,
+            })
+        }
+        builtins::Builtin::F64ToInt => {
+            let [input, err]: [AirArg; 2] = inputs
+                .try_into()
+                .expect("ftoi requires a float and an error continuation");
+            AirOp::F64ToInt(AirF64ToInt {
+                input,
+                err_target: err.name,
+                ok_target: continuation_target,
+            })
+        }
         builtins::Builtin::Eq | builtins::Builtin::Eqi => AirOp::JumpEqInt(AirJumpEq {
             args: inputs,
             target: continuation_target,
@@ -1100,22 +1936,195 @@ fn instruction_op(builtin: builtins::Builtin, args: Vec<AirArg>) -> AirOp {
             args: inputs,
             target: continuation_target,
         }),
-        builtins::Builtin::Lt => {
-            let (left, right) = binary_operands(builtin.name(), inputs);
-            AirOp::JumpLt(AirJumpLt {
-                left,
-                right,
+        builtins::Builtin::Strlen => {
+            let input = inputs.into_iter().next().expect("strlen requires a string");
+            AirOp::StrLen(AirStrLen {
+                input,
+                target: continuation_target,
+                release,
+            })
+        }
+        builtins::Builtin::Concat => {
+            let (input_a, input_b) = binary_input_args(builtin.name(), inputs);
+            AirOp::Concat(AirConcat {
+                input_a,
+                input_b,
+                target: continuation_target,
+                release,
+            })
+        }
+        builtins::Builtin::Slice => {
+            let err_target = args
+                .get(arg_len - 2)
+                .expect("slice requires an error continuation")
+                .name
+                .clone();
+            let [input, start, end]: [AirArg; 3] = args[..arg_len - 2]
+                .to_vec()
+                .try_into()
+                .expect("slice requires a string, a start and an end");
+            AirOp::Slice(AirSlice {
+                input,
+                start,
+                end,
+                err_target,
+                ok_target: continuation_target,
+                release,
+            })
+        }
+        builtins::Builtin::ByteAt => {
+            let err_target = args
+                .get(arg_len - 2)
+                .expect("byteat requires an error continuation")
+                .name
+                .clone();
+            let (input, index) = binary_input_args(builtin.name(), args[..arg_len - 2].to_vec());
+            AirOp::ByteAt(AirByteAt {
+                input,
+                index,
+                err_target,
+                ok_target: continuation_target,
+                release,
+            })
+        }
+        builtins::Builtin::Read => {
+            let [fd, max, ok, eof, err]: [AirArg; 5] = args
+                .try_into()
+                .expect("read requires an fd, a size and three continuations");
+            AirOp::Read(AirRead {
+                fd,
+                max,
+                ok_target: ok.name,
+                eof_target: eof.name,
+                err_target: err.name,
+            })
+        }
+        builtins::Builtin::Open => {
+            let [path, flags, mode, err]: [AirArg; 4] = args[..arg_len - 1]
+                .to_vec()
+                .try_into()
+                .expect("open requires a path, flags, a mode and an error continuation");
+            AirOp::Open(AirOpen {
+                path,
+                flags,
+                mode,
+                err_target: err.name,
+                ok_target: continuation_target,
+                release,
+            })
+        }
+        builtins::Builtin::Close => {
+            let [fd, err]: [AirArg; 2] = inputs
+                .try_into()
+                .expect("close requires an fd and an error continuation");
+            AirOp::Close(AirClose {
+                fd,
+                err_target: err.name,
+                ok_target: continuation_target,
+            })
+        }
+        builtins::Builtin::Argc => AirOp::ArgCount(AirArgCount {
+            target: continuation_target,
+        }),
+        builtins::Builtin::Arg => {
+            let [index, one, none]: [AirArg; 3] = args
+                .try_into()
+                .expect("arg requires an index and two continuations");
+            AirOp::ArgAt(AirArgAt {
+                index,
+                one_target: one.name,
+                none_target: none.name,
+            })
+        }
+        builtins::Builtin::Getenv => {
+            let [name, found, missing]: [AirArg; 3] = args
+                .try_into()
+                .expect("getenv requires a name and two continuations");
+            AirOp::Getenv(AirGetenv {
+                name,
+                found_target: found.name,
+                missing_target: missing.name,
+                release,
+            })
+        }
+        builtins::Builtin::ArrLen => {
+            let input = inputs.into_iter().next().expect("arrlen requires an array");
+            AirOp::ArrayLen(AirArrayLen {
+                input,
+                target: continuation_target,
+            })
+        }
+        builtins::Builtin::Get => {
+            let [input, index, one, none]: [AirArg; 4] = args
+                .try_into()
+                .expect("get requires an array, an index and two continuations");
+            AirOp::ArrayGet(AirArrayGet {
+                input,
+                index,
+                one_target: one.name,
+                none_target: none.name,
+            })
+        }
+        builtins::Builtin::Set => {
+            let [input, index, value, err]: [AirArg; 4] = inputs
+                .try_into()
+                .expect("set requires an array, an index, a value and an error continuation");
+            AirOp::ArraySet(AirArraySet {
+                input,
+                index,
+                value,
+                err_target: err.name,
+                ok_target: continuation_target,
+            })
+        }
+        builtins::Builtin::Insert => {
+            let [input, key, value]: [AirArg; 3] = inputs
+                .try_into()
+                .expect("insert requires a map, a key and a value");
+            AirOp::MapInsert(AirMapInsert {
+                input,
+                key,
+                value,
+                target: continuation_target,
+            })
+        }
+        builtins::Builtin::Lookup => {
+            let [input, key, found, missing]: [AirArg; 4] = args
+                .try_into()
+                .expect("lookup requires a map, a key and two continuations");
+            AirOp::MapLookup(AirMapLookup {
+                input,
+                key,
+                found_target: found.name,
+                missing_target: missing.name,
+            })
+        }
+        builtins::Builtin::Remove => {
+            let (input, key) = binary_input_args(builtin.name(), inputs);
+            AirOp::MapRemove(AirMapRemove {
+                input,
+                key,
                 target: continuation_target,
             })
         }
-        builtins::Builtin::Gt => {
-            let (left, right) = binary_operands(builtin.name(), inputs);
-            AirOp::JumpGt(AirJumpGt {
-                left,
-                right,
+        builtins::Builtin::MapLen => {
+            let input = inputs.into_iter().next().expect("maplen requires a map");
+            AirOp::MapLen(AirMapLen {
+                input,
                 target: continuation_target,
             })
         }
+        builtins::Builtin::MapNext => {
+            let [input, cursor, entry, done]: [AirArg; 4] = args
+                .try_into()
+                .expect("mapnext requires a map, a cursor and two continuations");
+            AirOp::MapNext(AirMapNext {
+                input,
+                cursor,
+                entry_target: entry.name,
+                done_target: done.name,
+            })
+        }
         _ => unreachable!("unexpected instruction op: {}", builtin.name()),
     }
 }
@@ -1148,14 +2157,20 @@ fn arg_to_operand(arg: AirArg) -> AirValue {
     }
 }
 
-fn call_op(builtin: builtins::Builtin, args: Vec<AirArg>) -> AirOp {
+fn call_op(builtin: builtins::Builtin, args: Vec<AirArg>, release: Vec<String>) -> AirOp {
     let arg_len = args.len();
     let continuation_target = args
         .last()
         .expect("builtin invocation requires a continuation target")
         .name
         .clone();
-    let call_args = args[..arg_len - 1].to_vec();
+    let mut call_args = args[..arg_len - 1].to_vec();
+    if matches!(
+        builtin,
+        builtins::Builtin::Printf | builtins::Builtin::Sprintf
+    ) {
+        apply_format_kinds(&mut call_args);
+    }
     let arg_kinds = call_args
         .iter()
         .map(|arg| arg.kind.clone())
@@ -1166,91 +2181,241 @@ fn call_op(builtin: builtins::Builtin, args: Vec<AirArg>) -> AirOp {
             args: call_args,
             arg_kinds,
             target: continuation_target,
+            release,
         }),
         builtins::Builtin::Sprintf => AirOp::Sprintf(AirSprintf {
             args: call_args,
             arg_kinds,
             target: continuation_target,
+            release,
         }),
         builtins::Builtin::Write => AirOp::Write(AirWrite {
             args: call_args,
             arg_kinds,
             target: continuation_target,
+            release,
+        }),
+        builtins::Builtin::WriteFd => AirOp::WriteFd(AirWrite {
+            args: call_args,
+            arg_kinds,
+            target: continuation_target,
+            release,
         }),
         builtins::Builtin::Exit => AirOp::SysExit(AirSysExit { args }),
         _ => unreachable!("unexpected call op: {}", builtin.name()),
     }
 }
 
+/// Variadic arguments are lowered as ints; the format's conversions tell
+/// which ones are floats, so they can be passed the way libc reads them.
+fn apply_format_kinds(args: &mut [AirArg]) {
+    let Some((format, rest)) = args.split_first_mut() else {
+        return;
+    };
+    let Some(Lit::Str(format)) = &format.literal else {
+        return;
+    };
+    let Ok(slots) = builtins::format_slots(format) else {
+        return;
+    };
+    for (arg, slot) in rest.iter_mut().zip(slots) {
+        if slot.arg == builtins::FormatArg::F64 {
+            arg.kind = SigKind::F64;
+        }
+    }
+}
+
 fn build_conditional_builtin_bridge(
     sig: &FunctionSig,
     builtin: builtins::Builtin,
     args: Vec<AirArg>,
+    release: Vec<String>,
 ) -> Vec<AirStmt> {
-    let arg_len = args.len();
-    let true_cont = &args[arg_len - 2];
-    let false_cont = &args[arg_len - 1];
-    let inputs = args[..arg_len - 2].to_vec();
-    let true_label = conditional_builtin_branch_label(sig, true_cont, "true");
-    let false_label = conditional_builtin_branch_label(sig, false_cont, "false");
-
-    let eq_jump = if matches!(builtin, builtins::Builtin::Eqs) {
-        AirOp::JumpEqStr(AirJumpEq {
-            args: inputs.clone(),
-            target: true_label.clone(),
-        })
+    let branch_count = if builtin == builtins::Builtin::Cmps {
+        3
     } else {
-        AirOp::JumpEqInt(AirJumpEq {
-            args: inputs.clone(),
-            target: true_label.clone(),
-        })
+        2
+    };
+    let (inputs, continuations) = args.split_at(args.len() - branch_count);
+    let inputs = inputs.to_vec();
+
+    // Branches in layout order, as (label, continuation index); the jump
+    // falls through into the first one.
+    let (jump, branches) = if builtin == builtins::Builtin::Cmps {
+        let lt_label = conditional_builtin_branch_label(sig, &continuations[0], "lt");
+        let eq_label = conditional_builtin_branch_label(sig, &continuations[1], "eq");
+        let gt_label = conditional_builtin_branch_label(sig, &continuations[2], "gt");
+        let jump = AirOp::JumpCmpStr(AirJumpCmpStr {
+            args: inputs,
+            lt_target: lt_label.clone(),
+            gt_target: gt_label.clone(),
+        });
+        (jump, vec![(eq_label, 1), (lt_label, 0), (gt_label, 2)])
+    } else {
+        let true_label = conditional_builtin_branch_label(sig, &continuations[0], "true");
+        let false_label = conditional_builtin_branch_label(sig, &continuations[1], "false");
+        // `le`, `ge` and `ne` jump on the opposite test, into the false branch.
+        let (builtin, negated) = match builtin {
+            builtins::Builtin::Le => (builtins::Builtin::Gt, true),
+            builtins::Builtin::Ge => (builtins::Builtin::Lt, true),
+            builtins::Builtin::Ne => (builtins::Builtin::Eqi, true),
+            builtin => (builtin, false),
+        };
+        let (jump_label, branches) = if negated {
+            (false_label.clone(), vec![(true_label, 0), (false_label, 1)])
+        } else {
+            (true_label.clone(), vec![(false_label, 1), (true_label, 0)])
+        };
+        let jump = match builtin {
+            builtins::Builtin::Eqs => AirOp::JumpEqStr(AirJumpEq {
+                args: inputs,
+                target: jump_label.clone(),
+            }),
+            builtins::Builtin::Lt => {
+                let (left, right) = binary_operands(builtin.name(), inputs);
+                AirOp::JumpLt(AirJumpLt {
+                    left,
+                    right,
+                    target: jump_label.clone(),
+                })
+            }
+            builtins::Builtin::Gt => {
+                let (left, right) = binary_operands(builtin.name(), inputs);
+                AirOp::JumpGt(AirJumpGt {
+                    left,
+                    right,
+                    target: jump_label.clone(),
+                })
+            }
+            builtins::Builtin::EqF64 | builtins::Builtin::LtF64 | builtins::Builtin::GtF64 => {
+                let (input_a, input_b) = binary_input_args(builtin.name(), inputs);
+                let jump = AirJumpCmpF64 {
+                    input_a,
+                    input_b,
+                    target: jump_label.clone(),
+                };
+                match builtin {
+                    builtins::Builtin::EqF64 => AirOp::JumpEqF64(jump),
+                    builtins::Builtin::LtF64 => AirOp::JumpLtF64(jump),
+                    _ => AirOp::JumpGtF64(jump),
+                }
+            }
+            _ => AirOp::JumpEqInt(AirJumpEq {
+                args: inputs,
+                target: jump_label.clone(),
+            }),
+        };
+        (jump, branches)
     };
 
-    vec![
-        AirStmt::op(eq_jump),
-        AirStmt::Label(AirLabel {
-            name: false_label.clone(),
-        }),
-        AirStmt::op(AirOp::ReleaseHeap(AirReleaseHeap {
-            name: true_cont.name.clone(),
-        })),
-        AirStmt::op(AirOp::JumpClosure(AirJumpClosure {
-            env_end: false_cont.name.clone(),
-            args: Vec::new(),
-        })),
-        AirStmt::Label(AirLabel {
-            name: true_label.clone(),
-        }),
-        AirStmt::op(AirOp::ReleaseHeap(AirReleaseHeap {
-            name: false_cont.name.clone(),
-        })),
-        AirStmt::op(AirOp::JumpClosure(AirJumpClosure {
-            env_end: true_cont.name.clone(),
+    let mut stmts = vec![AirStmt::op(jump)];
+    for (label, taken) in branches {
+        stmts.push(AirStmt::Label(AirLabel { name: label }));
+        stmts.extend(
+            release
+                .iter()
+                .map(|name| AirStmt::op(AirOp::ReleaseStr(AirReleaseStr { name: name.clone() }))),
+        );
+        for (idx, continuation) in continuations.iter().enumerate() {
+            if idx != taken {
+                stmts.push(release_closure_statement(continuation.name.clone()));
+            }
+        }
+        stmts.push(AirStmt::op(AirOp::JumpClosure(AirJumpClosure {
+            env_end: continuations[taken].name.clone(),
             args: Vec::new(),
-        })),
-    ]
+        })));
+    }
+    stmts
 }
 
 fn build_builtin_statements(
     sig: &FunctionSig,
     builtin: builtins::Builtin,
     args: Vec<AirArg>,
+    release: Vec<String>,
 ) -> Vec<AirStmt> {
     if builtin.is_conditional() {
-        return build_conditional_builtin_bridge(sig, builtin, args);
+        return build_conditional_builtin_bridge(sig, builtin, args, release);
     }
 
     if builtin.is_instruction() {
-        return vec![AirStmt::op(instruction_op(builtin, args))];
+        return vec![AirStmt::op(instruction_op(builtin, args, release))];
     }
 
     if builtin.is_libc_call() {
-        return vec![AirStmt::op(call_op(builtin, args))];
+        return vec![AirStmt::op(call_op(builtin, args, release))];
+    }
+
+    if builtin.is_coroutine() {
+        return vec![AirStmt::op(coroutine_op(builtin, args))];
     }
 
     vec![AirStmt::op(AirOp::SysExit(AirSysExit { args }))]
 }
 
+fn coroutine_op(builtin: builtins::Builtin, args: Vec<AirArg>) -> AirOp {
+    match builtin {
+        builtins::Builtin::Chan => {
+            let [ok]: [AirArg; 1] = args.try_into().expect("chan requires a continuation");
+            let elem = match ok.kind {
+                SigKind::Sig(signature) => match signature.kinds().as_slice() {
+                    [SigKind::Chan(elem)] => elem.as_ref().clone(),
+                    _ => SigKind::Int,
+                },
+                _ => SigKind::Int,
+            };
+            AirOp::NewChan(AirNewChan {
+                elem,
+                target: ok.name,
+            })
+        }
+        builtins::Builtin::Send => {
+            let [channel, value, ok]: [AirArg; 3] = args
+                .try_into()
+                .expect("send requires a channel, a value and a continuation");
+            AirOp::ChanSend(AirChanSend {
+                channel,
+                value,
+                target: ok.name,
+            })
+        }
+        builtins::Builtin::Recv => {
+            let [channel, ok]: [AirArg; 2] = args
+                .try_into()
+                .expect("recv requires a channel and a continuation");
+            AirOp::ChanRecv(AirChanRecv {
+                channel,
+                target: ok.name,
+            })
+        }
+        builtins::Builtin::Spawn => {
+            let [task, ok]: [AirArg; 2] = args
+                .try_into()
+                .expect("spawn requires a task and a continuation");
+            AirOp::Spawn(AirSpawn {
+                task: task.name,
+                target: ok.name,
+            })
+        }
+        builtins::Builtin::Thread => {
+            let [task, ok]: [AirArg; 2] = args
+                .try_into()
+                .expect("thread requires a task and a continuation");
+            AirOp::Thread(AirThread {
+                task: task.name,
+                target: ok.name,
+            })
+        }
+        builtins::Builtin::Yield => {
+            let [ok]: [AirArg; 1] = args.try_into().expect("yield requires a continuation");
+            AirOp::Yield(AirYield { target: ok.name })
+        }
+        builtins::Builtin::Done => AirOp::Done(AirDone),
+        _ => unreachable!("unexpected coroutine op: {}", builtin.name()),
+    }
+}
+
 fn is_inline_builtin(builtin: builtins::Builtin) -> bool {
     matches!(
         builtin,
@@ -1258,13 +2423,60 @@ fn is_inline_builtin(builtin: builtins::Builtin) -> bool {
             | builtins::Builtin::Sub
             | builtins::Builtin::Mul
             | builtins::Builtin::Div
+            | builtins::Builtin::Rem
+            | builtins::Builtin::And
+            | builtins::Builtin::Or
+            | builtins::Builtin::Xor
+            | builtins::Builtin::Shl
+            | builtins::Builtin::Shr
+            | builtins::Builtin::Neg
+            | builtins::Builtin::AddC
+            | builtins::Builtin::SubC
+            | builtins::Builtin::MulC
             | builtins::Builtin::AddF64
+            | builtins::Builtin::SubF64
             | builtins::Builtin::MulF64
             | builtins::Builtin::DivF64
+            | builtins::Builtin::NegF64
+            | builtins::Builtin::SqrtF64
+            | builtins::Builtin::IntToF64
+            | builtins::Builtin::F64ToInt
             | builtins::Builtin::Eq
             | builtins::Builtin::Eqi
             | builtins::Builtin::Eqs
             | builtins::Builtin::Lt
             | builtins::Builtin::Gt
+            | builtins::Builtin::Le
+            | builtins::Builtin::Ge
+            | builtins::Builtin::Ne
+            | builtins::Builtin::EqF64
+            | builtins::Builtin::LtF64
+            | builtins::Builtin::GtF64
+            | builtins::Builtin::Strlen
+            | builtins::Builtin::Concat
+            | builtins::Builtin::Slice
+            | builtins::Builtin::ByteAt
+            | builtins::Builtin::Cmps
+            | builtins::Builtin::Read
+            | builtins::Builtin::Open
+            | builtins::Builtin::Close
+            | builtins::Builtin::Argc
+            | builtins::Builtin::Arg
+            | builtins::Builtin::Getenv
+            | builtins::Builtin::ArrLen
+            | builtins::Builtin::Get
+            | builtins::Builtin::Set
+            | builtins::Builtin::Insert
+            | builtins::Builtin::Lookup
+            | builtins::Builtin::Remove
+            | builtins::Builtin::MapLen
+            | builtins::Builtin::MapNext
+            | builtins::Builtin::Chan
+            | builtins::Builtin::Send
+            | builtins::Builtin::Recv
+            | builtins::Builtin::Spawn
+            | builtins::Builtin::Thread
+            | builtins::Builtin::Yield
+            | builtins::Builtin::Done
     )
 }
@@ -1,5 +1,6 @@
 use crate::compiler::builtins;
 pub use crate::compiler::hir::{Lit, SigItem, SigKind};
+use crate::compiler::span::Span;
 use std::collections::BTreeSet;
 
 #[derive(Debug, Clone)]
@@ -32,36 +33,90 @@ pub enum AirExecTarget {
 pub struct AirFunction {
     pub sig: FunctionSig,
     pub items: Vec<AirStmt>,
+    pub span: Span,
 }
 
-impl AirFunction {
-    pub fn builtin_internal_array_str_nth() -> Self {
-        Self {
-            sig: FunctionSig {
-                name: "internal_array_str_nth".to_string(),
-                params: Vec::new(),
-                generics: BTreeSet::new(),
-                builtin: None,
-            },
-            items: Vec::new(),
-        }
-    }
+#[derive(Clone, Debug)]
+pub struct AirReleaseHeap {
+    pub name: String,
+}
 
-    pub fn builtin_internal_array_str() -> Self {
-        Self {
-            sig: FunctionSig {
-                name: "internal_array_str".to_string(),
-                params: Vec::new(),
-                generics: BTreeSet::new(),
-                builtin: None,
-            },
-            items: Vec::new(),
-        }
-    }
+/// Frees a string unless it is a literal.
+#[derive(Clone, Debug)]
+pub struct AirReleaseStr {
+    pub name: String,
 }
 
+/// Binds `dst` to a copy of `src` that is owned separately.
 #[derive(Clone, Debug)]
-pub struct AirReleaseHeap {
+pub struct AirCopyStr {
+    pub src: String,
+    pub dst: String,
+}
+
+/// Binds `name` to a new array holding `items`, which it takes ownership of.
+#[derive(Clone, Debug)]
+pub struct AirNewArray {
+    pub name: String,
+    pub elem: SigKind,
+    pub items: Vec<AirArg>,
+}
+
+/// Frees an array along with the strings and arrays it holds.
+#[derive(Clone, Debug)]
+pub struct AirReleaseArray {
+    pub name: String,
+}
+
+/// Binds `dst` to a deep copy of the array `src`.
+#[derive(Clone, Debug)]
+pub struct AirCopyArray {
+    pub src: String,
+    pub dst: String,
+}
+
+/// Binds `name` to a new map from `key` to `value` kinds holding `entries`,
+/// which it takes ownership of. Later entries replace earlier equal keys.
+#[derive(Clone, Debug)]
+pub struct AirNewMap {
+    pub name: String,
+    pub key: SigKind,
+    pub value: SigKind,
+    pub entries: Vec<(AirArg, AirArg)>,
+}
+
+/// Frees a map along with the keys and values it holds.
+#[derive(Clone, Debug)]
+pub struct AirReleaseMap {
+    pub name: String,
+}
+
+/// Binds `dst` to a deep copy of the map `src`.
+#[derive(Clone, Debug)]
+pub struct AirCopyMap {
+    pub src: String,
+    pub dst: String,
+}
+
+/// Drops a reference to a channel. The last one frees it along with the
+/// values still queued on it.
+#[derive(Clone, Debug)]
+pub struct AirReleaseChan {
+    pub name: String,
+}
+
+/// Binds `dst` to another reference to the channel `src`. Channels are
+/// shared rather than copied, so both see the same queue.
+#[derive(Clone, Debug)]
+pub struct AirCopyChan {
+    pub src: String,
+    pub dst: String,
+}
+
+/// Frees a sum value along with what its variant holds, through the release
+/// helper named in its metadata.
+#[derive(Clone, Debug)]
+pub struct AirReleaseSum {
     pub name: String,
 }
 
@@ -98,6 +153,13 @@ pub struct AirJumpEq {
     pub target: String,
 }
 
+/// Jumps to `targets[index]`. The index is always in range.
+#[derive(Clone, Debug)]
+pub struct AirJumpTable {
+    pub index: AirValue,
+    pub targets: Vec<String>,
+}
+
 #[derive(Clone, Debug)]
 pub struct AirJumpLt {
     pub left: AirValue,
@@ -148,25 +210,87 @@ pub enum AirOp {
     JumpEqStr(AirJumpEq),
     JumpLt(AirJumpLt),
     JumpGt(AirJumpGt),
+    JumpCmpStr(AirJumpCmpStr),
+    JumpEqF64(AirJumpCmpF64),
+    JumpLtF64(AirJumpCmpF64),
+    JumpGtF64(AirJumpCmpF64),
+    JumpTable(AirJumpTable),
 
     Add(AirAdd),
     Sub(AirSub),
     Mul(AirMul),
     DivInt(AirDivInt),
+    RemInt(AirDivInt),
+    And(AirAnd),
+    Or(AirOr),
+    Xor(AirXor),
+    Shl(AirShl),
+    Shr(AirShr),
+    Neg(AirNeg),
+    AddChecked(AirChecked),
+    SubChecked(AirChecked),
+    MulChecked(AirChecked),
     AddF64(AirAddF64),
+    SubF64(AirSubF64),
     MulF64(AirMulF64),
     DivF64(AirDivF64),
+    NegF64(AirNegF64),
+    SqrtF64(AirSqrtF64),
+    IntToF64(AirIntToF64),
+    F64ToInt(AirF64ToInt),
+
+    StrLen(AirStrLen),
+    Concat(AirConcat),
+    Slice(AirSlice),
+    ByteAt(AirByteAt),
+
+    ArrayLen(AirArrayLen),
+    ArrayGet(AirArrayGet),
+    ArraySet(AirArraySet),
+
+    MapInsert(AirMapInsert),
+    MapLookup(AirMapLookup),
+    MapRemove(AirMapRemove),
+    MapLen(AirMapLen),
+    MapNext(AirMapNext),
+
+    NewChan(AirNewChan),
+    ChanSend(AirChanSend),
+    ChanRecv(AirChanRecv),
+    Spawn(AirSpawn),
+    Thread(AirThread),
+    Yield(AirYield),
+    Done(AirDone),
 
     SysExit(AirSysExit),
 
     Printf(AirPrintf),
     Sprintf(AirSprintf),
     Write(AirWrite),
+    /// `@writefd`: the first argument is the fd, the second the string.
+    WriteFd(AirWrite),
+    Read(AirRead),
+    Open(AirOpen),
+    Close(AirClose),
+    ArgCount(AirArgCount),
+    ArgAt(AirArgAt),
+    Getenv(AirGetenv),
 
     CallPtr(AirCallPtr),
     NewClosure(AirNewClosure),
     CloneClosure(AirCloneClosure),
     ReleaseHeap(AirReleaseHeap),
+    ReleaseStr(AirReleaseStr),
+    CopyStr(AirCopyStr),
+    NewArray(AirNewArray),
+    ReleaseArray(AirReleaseArray),
+    CopyArray(AirCopyArray),
+    NewMap(AirNewMap),
+    ReleaseMap(AirReleaseMap),
+    CopyMap(AirCopyMap),
+    ReleaseChan(AirReleaseChan),
+    CopyChan(AirCopyChan),
+    ReleaseSum(AirReleaseSum),
     Pin(AirPin),
     Field(AirField),
     CopyField(AirField),
@@ -217,6 +341,59 @@ pub struct AirDivInt {
     pub ok_target: String,
 }
 
+#[derive(Clone, Debug)]
+pub struct AirAnd {
+    pub input_a: AirArg,
+    pub input_b: AirArg,
+    pub target: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct AirOr {
+    pub input_a: AirArg,
+    pub input_b: AirArg,
+    pub target: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct AirXor {
+    pub input_a: AirArg,
+    pub input_b: AirArg,
+    pub target: String,
+}
+
+/// Shifts left by the low six bits of `input_b`.
+#[derive(Clone, Debug)]
+pub struct AirShl {
+    pub input_a: AirArg,
+    pub input_b: AirArg,
+    pub target: String,
+}
+
+/// Shifts right by the low six bits of `input_b`, copying the sign bit in.
+#[derive(Clone, Debug)]
+pub struct AirShr {
+    pub input_a: AirArg,
+    pub input_b: AirArg,
+    pub target: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct AirNeg {
+    pub input: AirArg,
+    pub target: String,
+}
+
+/// Integer arithmetic that takes `overflow_target` with the wrapped result
+/// when the exact one does not fit in an int.
+#[derive(Clone, Debug)]
+pub struct AirChecked {
+    pub input_a: AirArg,
+    pub input_b: AirArg,
+    pub overflow_target: String,
+    pub ok_target: String,
+}
+
 #[derive(Clone, Debug)]
 pub struct AirAddF64 {
     pub input_a: AirArg,
@@ -224,6 +401,13 @@ pub struct AirAddF64 {
     pub target: String,
 }
 
+#[derive(Clone, Debug)]
+pub struct AirSubF64 {
+    pub input_a: AirArg,
+    pub input_b: AirArg,
+    pub target: String,
+}
+
 #[derive(Clone, Debug)]
 pub struct AirMulF64 {
     pub input_a: AirArg,
@@ -238,6 +422,33 @@ pub struct AirDivF64 {
     pub target: String,
 }
 
+#[derive(Clone, Debug)]
+pub struct AirNegF64 {
+    pub input: AirArg,
+    pub target: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct AirSqrtF64 {
+    pub input: AirArg,
+    pub target: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct AirIntToF64 {
+    pub input: AirArg,
+    pub target: String,
+}
+
+/// Truncates `input` toward zero, or takes `err_target` when it is NaN or
+/// outside the int range.
+#[derive(Clone, Debug)]
+pub struct AirF64ToInt {
+    pub input: AirArg,
+    pub err_target: String,
+    pub ok_target: String,
+}
+
 #[derive(Clone, Debug)]
 pub struct AirJumpGt {
     pub left: AirValue,
@@ -245,11 +456,201 @@ pub struct AirJumpGt {
     pub target: String,
 }
 
+/// Jumps to `target` when the float comparison holds. Comparisons involving
+/// NaN never hold, so they fall through.
+#[derive(Clone, Debug)]
+pub struct AirJumpCmpF64 {
+    pub input_a: AirArg,
+    pub input_b: AirArg,
+    pub target: String,
+}
+
+/// Jumps to `lt_target` or `gt_target` when the first string sorts before or
+/// after the second, falling through when they are equal.
+#[derive(Clone, Debug)]
+pub struct AirJumpCmpStr {
+    pub args: Vec<AirArg>,
+    pub lt_target: String,
+    pub gt_target: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct AirStrLen {
+    pub input: AirArg,
+    pub target: String,
+    /// Owned strings the op consumes, freed before jumping to `target`.
+    pub release: Vec<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct AirConcat {
+    pub input_a: AirArg,
+    pub input_b: AirArg,
+    pub target: String,
+    /// Owned strings the op consumes, freed before jumping to `target`.
+    pub release: Vec<String>,
+}
+
+/// Copies the bytes of `input` from `start` up to `end` into a new string,
+/// or takes `err_target` when the range does not fit.
+#[derive(Clone, Debug)]
+pub struct AirSlice {
+    pub input: AirArg,
+    pub start: AirArg,
+    pub end: AirArg,
+    pub err_target: String,
+    pub ok_target: String,
+    /// Owned strings the op consumes, freed on either path.
+    pub release: Vec<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct AirByteAt {
+    pub input: AirArg,
+    pub index: AirArg,
+    pub err_target: String,
+    pub ok_target: String,
+    /// Owned strings the op consumes, freed on either path.
+    pub release: Vec<String>,
+}
+
+/// Passes the length of `input` to `target`, consuming the array.
+#[derive(Clone, Debug)]
+pub struct AirArrayLen {
+    pub input: AirArg,
+    pub target: String,
+}
+
+/// Moves the element at `index` out of `input` and passes it to `one_target`,
+/// or takes `none_target` when the index is out of bounds. The array is
+/// consumed on either path.
+#[derive(Clone, Debug)]
+pub struct AirArrayGet {
+    pub input: AirArg,
+    pub index: AirArg,
+    pub one_target: String,
+    pub none_target: String,
+}
+
+/// Replaces the element at `index` of `input` with `value`, releasing the old
+/// one, and passes the array on to `ok_target`. An index out of bounds takes
+/// `err_target` and releases both the array and the value.
+#[derive(Clone, Debug)]
+pub struct AirArraySet {
+    pub input: AirArg,
+    pub index: AirArg,
+    pub value: AirArg,
+    pub err_target: String,
+    pub ok_target: String,
+}
+
+/// Stores `value` under `key` in `input`, releasing any value and key it
+/// replaces, and passes the map on to `target`.
+#[derive(Clone, Debug)]
+pub struct AirMapInsert {
+    pub input: AirArg,
+    pub key: AirArg,
+    pub value: AirArg,
+    pub target: String,
+}
+
+/// Passes a copy of the value under `key` and then the map to `found_target`,
+/// or just the map to `missing_target`. The key is consumed.
+#[derive(Clone, Debug)]
+pub struct AirMapLookup {
+    pub input: AirArg,
+    pub key: AirArg,
+    pub found_target: String,
+    pub missing_target: String,
+}
+
+/// Drops the entry under `key` from `input`, if there is one, and passes the
+/// map on to `target`. The key is consumed.
+#[derive(Clone, Debug)]
+pub struct AirMapRemove {
+    pub input: AirArg,
+    pub key: AirArg,
+    pub target: String,
+}
+
+/// Passes the number of entries in `input` and then the map to `target`.
+#[derive(Clone, Debug)]
+pub struct AirMapLen {
+    pub input: AirArg,
+    pub target: String,
+}
+
+/// Passes copies of the key and value of entry `cursor`, the next cursor and
+/// the map to `entry_target`, or just the map to `done_target` once `cursor`
+/// is past the last entry.
+#[derive(Clone, Debug)]
+pub struct AirMapNext {
+    pub input: AirArg,
+    pub cursor: AirArg,
+    pub entry_target: String,
+    pub done_target: String,
+}
+
+/// Passes a new channel for values of kind `elem` to `target`.
+#[derive(Clone, Debug)]
+pub struct AirNewChan {
+    pub elem: SigKind,
+    pub target: String,
+}
+
+/// Hands `value` to the first coroutine waiting on `channel`, queueing that
+/// coroutine to run, or queues the value when none is waiting. Then passes
+/// the channel on to `target`. The value is consumed.
+#[derive(Clone, Debug)]
+pub struct AirChanSend {
+    pub channel: AirArg,
+    pub value: AirArg,
+    pub target: String,
+}
+
+/// Passes the oldest value queued on `channel` and then the channel to
+/// `target`. When nothing is queued, `target` waits on the channel and the
+/// next runnable coroutine runs instead.
+#[derive(Clone, Debug)]
+pub struct AirChanRecv {
+    pub channel: AirArg,
+    pub target: String,
+}
+
+/// Queues the closure `task` behind the runnable coroutines and jumps to
+/// `target`.
+#[derive(Clone, Debug)]
+pub struct AirSpawn {
+    pub task: String,
+    pub target: String,
+}
+
+/// Starts the closure `task` on a new OS thread, which then takes its turn
+/// running coroutines, and jumps to `target`.
+#[derive(Clone, Debug)]
+pub struct AirThread {
+    pub task: String,
+    pub target: String,
+}
+
+/// Queues `target` behind the runnable coroutines and runs the first of them.
+#[derive(Clone, Debug)]
+pub struct AirYield {
+    pub target: String,
+}
+
+/// Ends the running coroutine and runs the next one. The program exits once
+/// none is left to run.
+#[derive(Clone, Debug)]
+pub struct AirDone;
+
 #[derive(Clone, Debug)]
 pub struct AirPrintf {
     pub args: Vec<AirArg>,
     pub arg_kinds: Vec<SigKind>,
     pub target: String,
+    /// Owned strings the call consumes, freed before jumping to `target`.
+    pub release: Vec<String>,
 }
 
 #[derive(Clone, Debug)]
@@ -257,6 +658,8 @@ pub struct AirSprintf {
     pub args: Vec<AirArg>,
     pub arg_kinds: Vec<SigKind>,
     pub target: String,
+    /// Owned strings the call consumes, freed before jumping to `target`.
+    pub release: Vec<String>,
 }
 
 #[derive(Clone, Debug)]
@@ -264,6 +667,63 @@ pub struct AirWrite {
     pub args: Vec<AirArg>,
     pub arg_kinds: Vec<SigKind>,
     pub target: String,
+    /// Owned strings the call consumes, freed before jumping to `target`.
+    pub release: Vec<String>,
+}
+
+/// Reads at most `max` bytes from `fd` into a new string, taking
+/// `eof_target` when there is nothing left and `err_target` with the errno
+/// when the read fails.
+#[derive(Clone, Debug)]
+pub struct AirRead {
+    pub fd: AirArg,
+    pub max: AirArg,
+    pub ok_target: String,
+    pub eof_target: String,
+    pub err_target: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct AirOpen {
+    pub path: AirArg,
+    pub flags: AirArg,
+    pub mode: AirArg,
+    pub err_target: String,
+    pub ok_target: String,
+    /// Owned strings the op consumes, freed on either path.
+    pub release: Vec<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct AirClose {
+    pub fd: AirArg,
+    pub err_target: String,
+    pub ok_target: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct AirArgCount {
+    pub target: String,
+}
+
+/// Copies the command line argument at `index` into a new string, or takes
+/// `none_target` when there is no such argument.
+#[derive(Clone, Debug)]
+pub struct AirArgAt {
+    pub index: AirArg,
+    pub one_target: String,
+    pub none_target: String,
+}
+
+/// Copies the value of the environment variable `name` into a new string,
+/// or takes `missing_target` when it is not set.
+#[derive(Clone, Debug)]
+pub struct AirGetenv {
+    pub name: AirArg,
+    pub found_target: String,
+    pub missing_target: String,
+    /// Owned strings the op consumes, freed on either path.
+    pub release: Vec<String>,
 }
 
 #[derive(Clone, Debug)]
@@ -0,0 +1,1318 @@
+//! Assembles the NASM subset emitted by `codegen` into x86-64 machine code.
+//!
+//! Branches and RIP-relative references always use 32-bit displacements, so one
+//! pass is enough: references to labels in the same section are patched once
+//! every label is known and everything else becomes a relocation.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::compiler::error::{Code, Error};
+use crate::compiler::span::Span;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Section {
+    Text,
+    Rodata,
+    Data,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Symbol {
+    pub name: String,
+    pub section: Section,
+    pub offset: usize,
+    pub global: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RelocKind {
+    /// `target - next_instruction`, for data references.
+    Pc32,
+    /// Like `Pc32`, but for calls and jumps that may go through a PLT.
+    Plt32,
+}
+
+/// A 32-bit field in `section` at `offset` that must hold
+/// `address(target) + addend - address(field)`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Relocation {
+    pub section: Section,
+    pub offset: usize,
+    pub target: String,
+    pub kind: RelocKind,
+    pub addend: i64,
+}
+
+/// Machine code and data ready to be written as an ELF file.
+#[derive(Clone, Debug, Default)]
+pub struct Object {
+    pub text: Vec<u8>,
+    pub rodata: Vec<u8>,
+    pub data: Vec<u8>,
+    pub symbols: Vec<Symbol>,
+    pub externs: Vec<String>,
+    pub relocations: Vec<Relocation>,
+}
+
+impl Object {
+    pub fn section(&self, section: Section) -> &[u8] {
+        match section {
+            Section::Text => &self.text,
+            Section::Rodata => &self.rodata,
+            Section::Data => &self.data,
+        }
+    }
+
+    pub fn symbol(&self, name: &str) -> Option<&Symbol> {
+        self.symbols.iter().find(|symbol| symbol.name == name)
+    }
+}
+
+pub fn assemble(source: &str) -> Result<Object, Error> {
+    let mut asm = Assembler::default();
+    for (idx, line) in source.lines().enumerate() {
+        asm.line(line)
+            .map_err(|message| internal(format!("assembly line {}: {message}", idx + 1)))?;
+    }
+    asm.finish()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Size {
+    Byte,
+    Dword,
+    Qword,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Reg {
+    num: u8,
+    size: Size,
+    // spl/bpl/sil/dil only exist with a REX prefix.
+    needs_rex: bool,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Mem {
+    size: Option<Size>,
+    base: Option<u8>,
+    index: Option<u8>,
+    disp: i64,
+    // RIP-relative reference to this label.
+    label: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Operand {
+    Reg(Reg),
+    Xmm(u8),
+    Imm(i64),
+    Mem(Mem),
+    Label(String),
+}
+
+enum Rm<'a> {
+    Reg(u8),
+    Mem(&'a Mem),
+}
+
+// A label reference inside an instruction being encoded.
+struct PendingRef {
+    pos: usize,
+    label: String,
+    kind: RelocKind,
+}
+
+#[derive(Default)]
+struct Inst {
+    bytes: Vec<u8>,
+    reference: Option<PendingRef>,
+}
+
+struct Fixup {
+    section: Section,
+    offset: usize,
+    end: usize,
+    label: String,
+    kind: RelocKind,
+}
+
+#[derive(Default)]
+struct Assembler {
+    text: Vec<u8>,
+    rodata: Vec<u8>,
+    data: Vec<u8>,
+    current: Option<Section>,
+    labels: HashMap<String, (Section, usize)>,
+    label_order: Vec<String>,
+    globals: HashSet<String>,
+    externs: Vec<String>,
+    fixups: Vec<Fixup>,
+}
+
+impl Assembler {
+    fn line(&mut self, line: &str) -> Result<(), String> {
+        let line = strip_comment(line).trim();
+        if line.is_empty() {
+            return Ok(());
+        }
+        if let Some(label) = line.strip_suffix(':') {
+            if is_identifier(label) {
+                return self.define_label(label);
+            }
+        }
+
+        let (mnemonic, rest) = match line.split_once(char::is_whitespace) {
+            Some((mnemonic, rest)) => (mnemonic, rest.trim()),
+            None => (line, ""),
+        };
+        match mnemonic {
+            "bits" if rest == "64" => Ok(()),
+            "default" if rest == "rel" => Ok(()),
+            "section" => {
+                self.current = Some(match rest {
+                    ".text" => Section::Text,
+                    ".rodata" => Section::Rodata,
+                    ".data" => Section::Data,
+                    other => return Err(format!("unsupported section `{other}`")),
+                });
+                Ok(())
+            }
+            "global" => {
+                self.globals.insert(rest.to_string());
+                Ok(())
+            }
+            "extern" => {
+                if !self.externs.iter().any(|name| name == rest) {
+                    self.externs.push(rest.to_string());
+                }
+                Ok(())
+            }
+            "db" => {
+                let bytes = parse_db(rest)?;
+                self.section_bytes()?.extend(bytes);
+                Ok(())
+            }
+            "dq" => {
+                for value in rest.split(',') {
+                    let value = parse_number(value.trim())
+                        .ok_or_else(|| format!("invalid quadword `{}`", value.trim()))?;
+                    self.section_bytes()?
+                        .extend_from_slice(&value.to_le_bytes());
+                }
+                Ok(())
+            }
+            "times" => {
+                let (count, body) = rest
+                    .split_once(char::is_whitespace)
+                    .ok_or("`times` expects a count and a line to repeat")?;
+                let count = parse_number(count)
+                    .and_then(|count| usize::try_from(count).ok())
+                    .ok_or_else(|| format!("invalid repeat count `{count}`"))?;
+                for _ in 0..count {
+                    self.line(body)?;
+                }
+                Ok(())
+            }
+            "lock" => {
+                let (mnemonic, rest) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+                let mut inst =
+                    instruction(mnemonic, rest.trim()).map_err(|err| format!("`{line}`: {err}"))?;
+                inst.bytes.insert(0, 0xf0);
+                if let Some(reference) = &mut inst.reference {
+                    reference.pos += 1;
+                }
+                self.push(inst)
+            }
+            _ => {
+                let inst = instruction(mnemonic, rest).map_err(|err| format!("`{line}`: {err}"))?;
+                self.push(inst)
+            }
+        }
+    }
+
+    fn define_label(&mut self, label: &str) -> Result<(), String> {
+        let section = self.current_section()?;
+        let offset = self.section_bytes()?.len();
+        if self
+            .labels
+            .insert(label.to_string(), (section, offset))
+            .is_some()
+        {
+            return Err(format!("label `{label}` is defined twice"));
+        }
+        self.label_order.push(label.to_string());
+        Ok(())
+    }
+
+    fn push(&mut self, inst: Inst) -> Result<(), String> {
+        let section = self.current_section()?;
+        let bytes = self.section_bytes()?;
+        let start = bytes.len();
+        bytes.extend_from_slice(&inst.bytes);
+        let end = bytes.len();
+        if let Some(reference) = inst.reference {
+            self.fixups.push(Fixup {
+                section,
+                offset: start + reference.pos,
+                end,
+                label: reference.label,
+                kind: reference.kind,
+            });
+        }
+        Ok(())
+    }
+
+    fn current_section(&self) -> Result<Section, String> {
+        self.current
+            .ok_or_else(|| "code or data before the first `section`".to_string())
+    }
+
+    fn section_bytes(&mut self) -> Result<&mut Vec<u8>, String> {
+        Ok(match self.current_section()? {
+            Section::Text => &mut self.text,
+            Section::Rodata => &mut self.rodata,
+            Section::Data => &mut self.data,
+        })
+    }
+
+    fn finish(mut self) -> Result<Object, Error> {
+        let mut relocations = Vec::new();
+        for fixup in std::mem::take(&mut self.fixups) {
+            let local = self.labels.get(&fixup.label).copied();
+            match local {
+                Some((section, target)) if section == fixup.section => {
+                    let value = target as i64 - fixup.end as i64;
+                    let value = i32::try_from(value).map_err(|_| {
+                        internal(format!("`{}` is out of rel32 range", fixup.label))
+                    })?;
+                    let bytes = match fixup.section {
+                        Section::Text => &mut self.text,
+                        Section::Rodata => &mut self.rodata,
+                        Section::Data => &mut self.data,
+                    };
+                    bytes[fixup.offset..fixup.offset + 4].copy_from_slice(&value.to_le_bytes());
+                }
+                Some(_) => relocations.push(Relocation {
+                    section: fixup.section,
+                    offset: fixup.offset,
+                    target: fixup.label,
+                    kind: fixup.kind,
+                    addend: fixup.offset as i64 - fixup.end as i64,
+                }),
+                None if self.externs.contains(&fixup.label) => relocations.push(Relocation {
+                    section: fixup.section,
+                    offset: fixup.offset,
+                    target: fixup.label,
+                    kind: fixup.kind,
+                    addend: fixup.offset as i64 - fixup.end as i64,
+                }),
+                None => return Err(internal(format!("undefined label `{}`", fixup.label))),
+            }
+        }
+
+        for name in &self.globals {
+            if !self.labels.contains_key(name) {
+                return Err(internal(format!("global `{name}` is never defined")));
+            }
+        }
+        let symbols = self
+            .label_order
+            .iter()
+            .map(|name| {
+                let (section, offset) = self.labels[name];
+                Symbol {
+                    name: name.clone(),
+                    section,
+                    offset,
+                    global: self.globals.contains(name),
+                }
+            })
+            .collect();
+        Ok(Object {
+            text: self.text,
+            rodata: self.rodata,
+            data: self.data,
+            symbols,
+            externs: self.externs,
+            relocations,
+        })
+    }
+}
+
+fn internal(message: String) -> Error {
+    Error::new(Code::Codegen, message, Span::unknown())
+}
+
+fn strip_comment(line: &str) -> &str {
+    let mut quote = None;
+    for (idx, ch) in line.char_indices() {
+        match (quote, ch) {
+            (None, '"' | '\'' | '`') => quote = Some(ch),
+            (Some(open), ch) if ch == open => quote = None,
+            (None, ';') => return &line[..idx],
+            _ => {}
+        }
+    }
+    line
+}
+
+fn split_operands(rest: &str) -> Vec<&str> {
+    if rest.is_empty() {
+        return Vec::new();
+    }
+    rest.split(',').map(str::trim).collect()
+}
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(ch) if ch.is_ascii_alphabetic() || ch == '_' || ch == '.')
+        && chars.all(|ch| ch.is_ascii_alphanumeric() || ch == '_' || ch == '.')
+}
+
+// Quoted strings follow NASM: '...' and "..." are taken literally, `...`
+// understands C escapes.
+fn parse_db(rest: &str) -> Result<Vec<u8>, String> {
+    let mut bytes = Vec::new();
+    let mut chars = rest.char_indices().peekable();
+    while let Some(&(start, ch)) = chars.peek() {
+        match ch {
+            ' ' | '\t' | ',' => {
+                chars.next();
+            }
+            '"' | '\'' | '`' => {
+                chars.next();
+                let mut escaped = false;
+                let mut closed = false;
+                for (_, next) in chars.by_ref() {
+                    if ch == '`' && escaped {
+                        bytes.push(match next {
+                            'n' => b'\n',
+                            't' => b'\t',
+                            'r' => b'\r',
+                            '0' => 0,
+                            other => other as u8,
+                        });
+                        escaped = false;
+                    } else if ch == '`' && next == '\\' {
+                        escaped = true;
+                    } else if next == ch {
+                        closed = true;
+                        break;
+                    } else {
+                        let mut buf = [0; 4];
+                        bytes.extend_from_slice(next.encode_utf8(&mut buf).as_bytes());
+                    }
+                }
+                if !closed {
+                    return Err("unterminated string in `db`".to_string());
+                }
+            }
+            _ => {
+                let end = rest[start..]
+                    .find(',')
+                    .map(|idx| start + idx)
+                    .unwrap_or(rest.len());
+                let value = parse_number(rest[start..end].trim())
+                    .ok_or_else(|| format!("invalid `db` value `{}`", &rest[start..end]))?;
+                let byte = u8::try_from(value)
+                    .or_else(|_| i8::try_from(value).map(|value| value as u8))
+                    .map_err(|_| format!("`db` value {value} does not fit a byte"))?;
+                bytes.push(byte);
+                while chars.peek().is_some_and(|&(idx, _)| idx < end) {
+                    chars.next();
+                }
+            }
+        }
+    }
+    Ok(bytes)
+}
+
+fn parse_number(text: &str) -> Option<i64> {
+    let (negative, digits) = match text.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, text),
+    };
+    let value = match digits
+        .strip_prefix("0x")
+        .or_else(|| digits.strip_prefix("0X"))
+    {
+        Some(hex) => u64::from_str_radix(hex, 16).ok()? as i64,
+        None => digits.parse::<u64>().ok()? as i64,
+    };
+    Some(if negative {
+        value.wrapping_neg()
+    } else {
+        value
+    })
+}
+
+fn parse_register(name: &str) -> Option<Reg> {
+    const QWORD: [&str; 16] = [
+        "rax", "rcx", "rdx", "rbx", "rsp", "rbp", "rsi", "rdi", "r8", "r9", "r10", "r11", "r12",
+        "r13", "r14", "r15",
+    ];
+    const DWORD: [&str; 16] = [
+        "eax", "ecx", "edx", "ebx", "esp", "ebp", "esi", "edi", "r8d", "r9d", "r10d", "r11d",
+        "r12d", "r13d", "r14d", "r15d",
+    ];
+    const BYTE: [&str; 16] = [
+        "al", "cl", "dl", "bl", "spl", "bpl", "sil", "dil", "r8b", "r9b", "r10b", "r11b", "r12b",
+        "r13b", "r14b", "r15b",
+    ];
+    let find = |names: &[&str; 16]| names.iter().position(|reg| *reg == name);
+    if let Some(num) = find(&QWORD) {
+        return Some(Reg {
+            num: num as u8,
+            size: Size::Qword,
+            needs_rex: false,
+        });
+    }
+    if let Some(num) = find(&DWORD) {
+        return Some(Reg {
+            num: num as u8,
+            size: Size::Dword,
+            needs_rex: false,
+        });
+    }
+    find(&BYTE).map(|num| Reg {
+        num: num as u8,
+        size: Size::Byte,
+        needs_rex: (4..8).contains(&num),
+    })
+}
+
+fn parse_xmm(name: &str) -> Option<u8> {
+    name.strip_prefix("xmm")?
+        .parse::<u8>()
+        .ok()
+        .filter(|num| *num < 16)
+}
+
+fn parse_operand(text: &str) -> Result<Operand, String> {
+    let (size, rest) = match text.split_once(char::is_whitespace) {
+        Some(("byte", rest)) => (Some(Size::Byte), rest.trim()),
+        Some(("dword", rest)) => (Some(Size::Dword), rest.trim()),
+        Some(("qword", rest)) => (Some(Size::Qword), rest.trim()),
+        _ => (None, text),
+    };
+    if let Some(inner) = rest
+        .strip_prefix('[')
+        .and_then(|rest| rest.strip_suffix(']'))
+    {
+        return parse_memory(inner, size).map(Operand::Mem);
+    }
+    if size.is_some() {
+        return Err(format!("size keyword on a non-memory operand `{text}`"));
+    }
+    if let Some(reg) = parse_register(rest) {
+        return Ok(Operand::Reg(reg));
+    }
+    if let Some(xmm) = parse_xmm(rest) {
+        return Ok(Operand::Xmm(xmm));
+    }
+    if let Some(value) = parse_number(rest) {
+        return Ok(Operand::Imm(value));
+    }
+    if is_identifier(rest) {
+        return Ok(Operand::Label(rest.to_string()));
+    }
+    // Jumps to labels are always rel32, so `near` changes nothing.
+    if let Some(label) = rest.strip_prefix("near ").map(str::trim) {
+        if is_identifier(label) {
+            return Ok(Operand::Label(label.to_string()));
+        }
+    }
+    Err(format!("invalid operand `{text}`"))
+}
+
+fn parse_memory(inner: &str, size: Option<Size>) -> Result<Mem, String> {
+    let inner = inner.trim();
+    let inner = inner.strip_prefix("rel ").unwrap_or(inner).trim();
+    let mut mem = Mem {
+        size,
+        base: None,
+        index: None,
+        disp: 0,
+        label: None,
+    };
+    let mut term = String::new();
+    let mut sign = 1;
+    let apply = |term: &str, sign: i64, mem: &mut Mem| -> Result<(), String> {
+        let term = term.trim();
+        if let Some(reg) = parse_register(term) {
+            if reg.size != Size::Qword || sign < 0 {
+                return Err(format!("invalid address register `{term}`"));
+            }
+            if mem.base.is_none() {
+                mem.base = Some(reg.num);
+            } else if mem.index.is_none() {
+                mem.index = Some(reg.num);
+            } else {
+                return Err("too many address registers".to_string());
+            }
+        } else if let Some(value) = parse_number(term) {
+            mem.disp += sign * value;
+        } else if is_identifier(term) && mem.label.is_none() && sign > 0 {
+            mem.label = Some(term.to_string());
+        } else {
+            return Err(format!("invalid address term `{term}`"));
+        }
+        Ok(())
+    };
+    for ch in inner.chars() {
+        if ch == '+' || ch == '-' {
+            if !term.trim().is_empty() {
+                apply(&term, sign, &mut mem)?;
+            }
+            term.clear();
+            sign = if ch == '-' { -1 } else { 1 };
+        } else {
+            term.push(ch);
+        }
+    }
+    apply(&term, sign, &mut mem)?;
+    if mem.label.is_some() && (mem.base.is_some() || mem.index.is_some()) {
+        return Err("labels cannot be combined with address registers".to_string());
+    }
+    if mem.index == Some(4) {
+        return Err("rsp cannot be an index register".to_string());
+    }
+    Ok(mem)
+}
+
+fn instruction(mnemonic: &str, rest: &str) -> Result<Inst, String> {
+    let operands = split_operands(rest)
+        .into_iter()
+        .map(parse_operand)
+        .collect::<Result<Vec<_>, _>>()?;
+    encode(mnemonic, &operands)
+}
+
+fn jcc_code(mnemonic: &str) -> Option<u8> {
+    Some(match mnemonic {
+        "jo" => 0x0,
+        "jno" => 0x1,
+        "jb" | "jc" | "jnae" => 0x2,
+        "jae" | "jnb" | "jnc" => 0x3,
+        "je" | "jz" => 0x4,
+        "jne" | "jnz" => 0x5,
+        "jbe" | "jna" => 0x6,
+        "ja" | "jnbe" => 0x7,
+        "js" => 0x8,
+        "jns" => 0x9,
+        "jp" | "jpe" => 0xa,
+        "jnp" | "jpo" => 0xb,
+        "jl" | "jnge" => 0xc,
+        "jge" | "jnl" => 0xd,
+        "jle" | "jng" => 0xe,
+        "jg" | "jnle" => 0xf,
+        _ => return None,
+    })
+}
+
+fn alu_code(mnemonic: &str) -> Option<u8> {
+    Some(match mnemonic {
+        "add" => 0,
+        "or" => 1,
+        "adc" => 2,
+        "sbb" => 3,
+        "and" => 4,
+        "sub" => 5,
+        "xor" => 6,
+        "cmp" => 7,
+        _ => return None,
+    })
+}
+
+fn sse_code(mnemonic: &str) -> Option<(u8, u8)> {
+    Some(match mnemonic {
+        "addsd" => (0xf2, 0x58),
+        "mulsd" => (0xf2, 0x59),
+        "subsd" => (0xf2, 0x5c),
+        "divsd" => (0xf2, 0x5e),
+        "sqrtsd" => (0xf2, 0x51),
+        "ucomisd" => (0x66, 0x2e),
+        "comisd" => (0x66, 0x2f),
+        "xorpd" => (0x66, 0x57),
+        _ => return None,
+    })
+}
+
+fn encode(mnemonic: &str, ops: &[Operand]) -> Result<Inst, String> {
+    use Operand::*;
+
+    if let Some(code) = jcc_code(mnemonic) {
+        return match ops {
+            [Label(label)] => Ok(rel32(&[0x0f, 0x80 + code], label, RelocKind::Pc32)),
+            _ => Err("expected a label".to_string()),
+        };
+    }
+    if let Some(code) = alu_code(mnemonic) {
+        return encode_alu(code, ops);
+    }
+    if let Some((prefix, opcode)) = sse_code(mnemonic) {
+        return match ops {
+            [Xmm(dst), Xmm(src)] => Ok(rm_inst(
+                &[prefix],
+                false,
+                &[0x0f, opcode],
+                *dst,
+                Rm::Reg(*src),
+                &[],
+                false,
+            )),
+            [Xmm(dst), Mem(mem)] => Ok(rm_inst(
+                &[prefix],
+                false,
+                &[0x0f, opcode],
+                *dst,
+                Rm::Mem(mem),
+                &[],
+                false,
+            )),
+            _ => Err("expected xmm, xmm/m64".to_string()),
+        };
+    }
+
+    match (mnemonic, ops) {
+        ("mov", [Reg(dst), Reg(src)]) if dst.size == src.size => Ok(sized(
+            dst.size,
+            0x88,
+            src.num,
+            Rm::Reg(dst.num),
+            &[],
+            dst.needs_rex || src.needs_rex,
+        )),
+        ("mov", [Reg(dst), Mem(mem)]) => {
+            check_size(mem, dst.size)?;
+            Ok(sized(
+                dst.size,
+                0x8a,
+                dst.num,
+                Rm::Mem(mem),
+                &[],
+                dst.needs_rex,
+            ))
+        }
+        ("mov", [Mem(mem), Reg(src)]) => {
+            check_size(mem, src.size)?;
+            Ok(sized(
+                src.size,
+                0x88,
+                src.num,
+                Rm::Mem(mem),
+                &[],
+                src.needs_rex,
+            ))
+        }
+        ("mov", [Reg(dst), Imm(value)]) => match dst.size {
+            Size::Qword => match i32::try_from(*value) {
+                Ok(imm) => Ok(rm_inst(
+                    &[],
+                    true,
+                    &[0xc7],
+                    0,
+                    Rm::Reg(dst.num),
+                    &imm.to_le_bytes(),
+                    false,
+                )),
+                Err(_) => {
+                    let mut inst = Inst::default();
+                    inst.bytes.push(rex(true, 0, 0, dst.num));
+                    inst.bytes.push(0xb8 + (dst.num & 7));
+                    inst.bytes.extend_from_slice(&value.to_le_bytes());
+                    Ok(inst)
+                }
+            },
+            Size::Dword => {
+                let imm = imm32(*value)?;
+                let mut inst = Inst::default();
+                if dst.num >= 8 {
+                    inst.bytes.push(rex(false, 0, 0, dst.num));
+                }
+                inst.bytes.push(0xb8 + (dst.num & 7));
+                inst.bytes.extend_from_slice(&imm.to_le_bytes());
+                Ok(inst)
+            }
+            Size::Byte => Ok(rm_inst(
+                &[],
+                false,
+                &[0xc6],
+                0,
+                Rm::Reg(dst.num),
+                &[imm8(*value)?],
+                dst.needs_rex,
+            )),
+        },
+        ("mov", [Mem(mem), Imm(value)]) => match mem.size {
+            Some(Size::Byte) => Ok(rm_inst(
+                &[],
+                false,
+                &[0xc6],
+                0,
+                Rm::Mem(mem),
+                &[imm8(*value)?],
+                false,
+            )),
+            Some(size) => Ok(sized(
+                size,
+                0xc6,
+                0,
+                Rm::Mem(mem),
+                &imm32(*value)?.to_le_bytes(),
+                false,
+            )),
+            None => Err("operation size not specified".to_string()),
+        },
+        ("movzx" | "movsx", [Reg(dst), src]) if dst.size != Size::Byte => {
+            let opcode = if mnemonic == "movzx" { 0xb6 } else { 0xbe };
+            let (rm, force) = match src {
+                Reg(src) if src.size == Size::Byte => (Rm::Reg(src.num), src.needs_rex),
+                Mem(mem) if mem.size == Some(Size::Byte) => (Rm::Mem(mem), false),
+                _ => return Err("expected a byte source".to_string()),
+            };
+            Ok(rm_inst(
+                &[],
+                dst.size == Size::Qword,
+                &[0x0f, opcode],
+                dst.num,
+                rm,
+                &[],
+                force,
+            ))
+        }
+        ("lea", [Reg(dst), Mem(mem)]) if dst.size == Size::Qword => Ok(rm_inst(
+            &[],
+            true,
+            &[0x8d],
+            dst.num,
+            Rm::Mem(mem),
+            &[],
+            false,
+        )),
+        ("test", [Reg(a), Reg(b)]) if a.size == b.size => Ok(sized(
+            a.size,
+            0x84,
+            b.num,
+            Rm::Reg(a.num),
+            &[],
+            a.needs_rex || b.needs_rex,
+        )),
+        ("xchg", [Reg(reg), Mem(mem)] | [Mem(mem), Reg(reg)]) => {
+            check_size(mem, reg.size)?;
+            Ok(sized(
+                reg.size,
+                0x86,
+                reg.num,
+                Rm::Mem(mem),
+                &[],
+                reg.needs_rex,
+            ))
+        }
+        ("push", [Reg(reg)]) if reg.size == Size::Qword => Ok(short_reg(0x50, reg.num)),
+        ("pop", [Reg(reg)]) if reg.size == Size::Qword => Ok(short_reg(0x58, reg.num)),
+        ("push", [Imm(value)]) => {
+            let mut inst = Inst::default();
+            inst.bytes.push(0x68);
+            inst.bytes.extend_from_slice(&imm32(*value)?.to_le_bytes());
+            Ok(inst)
+        }
+        ("inc" | "dec" | "not" | "neg" | "mul" | "imul" | "div" | "idiv", [target]) => {
+            let (opcode, ext) = match mnemonic {
+                "inc" => (0xfe, 0),
+                "dec" => (0xfe, 1),
+                "not" => (0xf6, 2),
+                "neg" => (0xf6, 3),
+                "mul" => (0xf6, 4),
+                "imul" => (0xf6, 5),
+                "div" => (0xf6, 6),
+                _ => (0xf6, 7),
+            };
+            unary(opcode, ext, target)
+        }
+        ("imul", [Reg(dst), src]) if dst.size != Size::Byte => {
+            let rm = match src {
+                Reg(src) if src.size == dst.size => Rm::Reg(src.num),
+                Mem(mem) => Rm::Mem(mem),
+                _ => return Err("expected a register or memory source".to_string()),
+            };
+            Ok(rm_inst(
+                &[],
+                dst.size == Size::Qword,
+                &[0x0f, 0xaf],
+                dst.num,
+                rm,
+                &[],
+                false,
+            ))
+        }
+        ("shl" | "shr" | "sar", [Reg(dst), count]) => {
+            let ext = match mnemonic {
+                "shl" => 4,
+                "shr" => 5,
+                _ => 7,
+            };
+            match count {
+                Imm(value) => Ok(sized(
+                    dst.size,
+                    0xc0,
+                    ext,
+                    Rm::Reg(dst.num),
+                    &[imm8(*value)?],
+                    dst.needs_rex,
+                )),
+                Reg(count) if count.num == 1 && count.size == Size::Byte => Ok(sized(
+                    dst.size,
+                    0xd2,
+                    ext,
+                    Rm::Reg(dst.num),
+                    &[],
+                    dst.needs_rex,
+                )),
+                _ => Err("shift count must be an immediate or cl".to_string()),
+            }
+        }
+        ("call", [Label(label)]) => Ok(rel32(&[0xe8], label, RelocKind::Plt32)),
+        ("jmp", [Label(label)]) => Ok(rel32(&[0xe9], label, RelocKind::Plt32)),
+        ("call", [Reg(reg)]) if reg.size == Size::Qword => Ok(rm_inst(
+            &[],
+            false,
+            &[0xff],
+            2,
+            Rm::Reg(reg.num),
+            &[],
+            false,
+        )),
+        ("jmp", [Reg(reg)]) if reg.size == Size::Qword => Ok(rm_inst(
+            &[],
+            false,
+            &[0xff],
+            4,
+            Rm::Reg(reg.num),
+            &[],
+            false,
+        )),
+        ("call", [Mem(mem)]) => Ok(rm_inst(&[], false, &[0xff], 2, Rm::Mem(mem), &[], false)),
+        ("jmp", [Mem(mem)]) => Ok(rm_inst(&[], false, &[0xff], 4, Rm::Mem(mem), &[], false)),
+        ("movq", [Reg(dst), Xmm(src)]) if dst.size == Size::Qword => Ok(rm_inst(
+            &[0x66],
+            true,
+            &[0x0f, 0x7e],
+            *src,
+            Rm::Reg(dst.num),
+            &[],
+            false,
+        )),
+        ("movq", [Xmm(dst), Reg(src)]) if src.size == Size::Qword => Ok(rm_inst(
+            &[0x66],
+            true,
+            &[0x0f, 0x6e],
+            *dst,
+            Rm::Reg(src.num),
+            &[],
+            false,
+        )),
+        ("movsd", [Xmm(dst), Xmm(src)]) => Ok(rm_inst(
+            &[0xf2],
+            false,
+            &[0x0f, 0x10],
+            *dst,
+            Rm::Reg(*src),
+            &[],
+            false,
+        )),
+        ("movsd", [Xmm(dst), Mem(mem)]) => Ok(rm_inst(
+            &[0xf2],
+            false,
+            &[0x0f, 0x10],
+            *dst,
+            Rm::Mem(mem),
+            &[],
+            false,
+        )),
+        ("movsd", [Mem(mem), Xmm(src)]) => Ok(rm_inst(
+            &[0xf2],
+            false,
+            &[0x0f, 0x11],
+            *src,
+            Rm::Mem(mem),
+            &[],
+            false,
+        )),
+        ("cvtsi2sd", [Xmm(dst), Reg(src)]) if src.size == Size::Qword => Ok(rm_inst(
+            &[0xf2],
+            true,
+            &[0x0f, 0x2a],
+            *dst,
+            Rm::Reg(src.num),
+            &[],
+            false,
+        )),
+        ("cvttsd2si", [Reg(dst), Xmm(src)]) if dst.size == Size::Qword => Ok(rm_inst(
+            &[0xf2],
+            true,
+            &[0x0f, 0x2c],
+            dst.num,
+            Rm::Reg(*src),
+            &[],
+            false,
+        )),
+        ("rep", [Label(op)]) if op == "movsb" => Ok(raw(&[0xf3, 0xa4])),
+        ("rep", [Label(op)]) if op == "stosb" => Ok(raw(&[0xf3, 0xaa])),
+        ("cqo", []) => Ok(raw(&[0x48, 0x99])),
+        ("leave", []) => Ok(raw(&[0xc9])),
+        ("ret", []) => Ok(raw(&[0xc3])),
+        ("syscall", []) => Ok(raw(&[0x0f, 0x05])),
+        ("cld", []) => Ok(raw(&[0xfc])),
+        ("pause", []) => Ok(raw(&[0xf3, 0x90])),
+        ("nop", []) => Ok(raw(&[0x90])),
+        ("ud2", []) => Ok(raw(&[0x0f, 0x0b])),
+        _ => Err("unsupported instruction".to_string()),
+    }
+}
+
+fn encode_alu(code: u8, ops: &[Operand]) -> Result<Inst, String> {
+    use Operand::*;
+
+    let base = code * 8;
+    match ops {
+        [Reg(dst), Reg(src)] if dst.size == src.size => Ok(sized(
+            dst.size,
+            base,
+            src.num,
+            Rm::Reg(dst.num),
+            &[],
+            dst.needs_rex || src.needs_rex,
+        )),
+        [Reg(dst), Mem(mem)] => {
+            check_size(mem, dst.size)?;
+            Ok(sized(
+                dst.size,
+                base + 2,
+                dst.num,
+                Rm::Mem(mem),
+                &[],
+                dst.needs_rex,
+            ))
+        }
+        [Mem(mem), Reg(src)] => {
+            check_size(mem, src.size)?;
+            Ok(sized(
+                src.size,
+                base,
+                src.num,
+                Rm::Mem(mem),
+                &[],
+                src.needs_rex,
+            ))
+        }
+        [Reg(dst), Imm(value)] => alu_imm(code, dst.size, Rm::Reg(dst.num), *value, dst.needs_rex),
+        [Mem(mem), Imm(value)] => {
+            let size = mem.size.ok_or("operation size not specified")?;
+            alu_imm(code, size, Rm::Mem(mem), *value, false)
+        }
+        _ => Err("unsupported operands".to_string()),
+    }
+}
+
+fn alu_imm(code: u8, size: Size, rm: Rm, value: i64, force_rex: bool) -> Result<Inst, String> {
+    if size == Size::Byte {
+        return Ok(rm_inst(
+            &[],
+            false,
+            &[0x80],
+            code,
+            rm,
+            &[imm8(value)?],
+            force_rex,
+        ));
+    }
+    let wide = size == Size::Qword;
+    Ok(match i8::try_from(value) {
+        Ok(imm) => rm_inst(&[], wide, &[0x83], code, rm, &[imm as u8], force_rex),
+        Err(_) => rm_inst(
+            &[],
+            wide,
+            &[0x81],
+            code,
+            rm,
+            &imm32(value)?.to_le_bytes(),
+            force_rex,
+        ),
+    })
+}
+
+fn unary(opcode: u8, ext: u8, target: &Operand) -> Result<Inst, String> {
+    match target {
+        Operand::Reg(reg) => Ok(sized(
+            reg.size,
+            opcode,
+            ext,
+            Rm::Reg(reg.num),
+            &[],
+            reg.needs_rex,
+        )),
+        Operand::Mem(mem) => {
+            let size = mem.size.ok_or("operation size not specified")?;
+            Ok(sized(size, opcode, ext, Rm::Mem(mem), &[], false))
+        }
+        _ => Err("expected a register or memory operand".to_string()),
+    }
+}
+
+fn check_size(mem: &Mem, size: Size) -> Result<(), String> {
+    match mem.size {
+        Some(mem_size) if mem_size != size => Err("operand sizes do not match".to_string()),
+        _ => Ok(()),
+    }
+}
+
+fn imm32(value: i64) -> Result<i32, String> {
+    i32::try_from(value)
+        .or_else(|_| u32::try_from(value).map(|value| value as i32))
+        .map_err(|_| format!("immediate {value} does not fit 32 bits"))
+}
+
+fn imm8(value: i64) -> Result<u8, String> {
+    u8::try_from(value)
+        .or_else(|_| i8::try_from(value).map(|value| value as u8))
+        .map_err(|_| format!("immediate {value} does not fit a byte"))
+}
+
+fn raw(bytes: &[u8]) -> Inst {
+    Inst {
+        bytes: bytes.to_vec(),
+        reference: None,
+    }
+}
+
+fn rex(wide: bool, reg: u8, index: u8, base: u8) -> u8 {
+    0x40 | (u8::from(wide) << 3) | ((reg >> 3) << 2) | ((index >> 3) << 1) | (base >> 3)
+}
+
+fn short_reg(opcode: u8, num: u8) -> Inst {
+    let mut inst = Inst::default();
+    if num >= 8 {
+        inst.bytes.push(rex(false, 0, 0, num));
+    }
+    inst.bytes.push(opcode + (num & 7));
+    inst
+}
+
+fn rel32(opcode: &[u8], label: &str, kind: RelocKind) -> Inst {
+    let mut bytes = opcode.to_vec();
+    let pos = bytes.len();
+    bytes.extend_from_slice(&[0; 4]);
+    Inst {
+        bytes,
+        reference: Some(PendingRef {
+            pos,
+            label: label.to_string(),
+            kind,
+        }),
+    }
+}
+
+// `opcode` is the byte-sized form; wider forms are the next opcode, with REX.W
+// for 64 bits.
+fn sized(size: Size, opcode: u8, reg: u8, rm: Rm, imm: &[u8], force_rex: bool) -> Inst {
+    match size {
+        Size::Byte => rm_inst(&[], false, &[opcode], reg, rm, imm, force_rex),
+        Size::Dword => rm_inst(&[], false, &[opcode + 1], reg, rm, imm, force_rex),
+        Size::Qword => rm_inst(&[], true, &[opcode + 1], reg, rm, imm, force_rex),
+    }
+}
+
+fn rm_inst(
+    prefixes: &[u8],
+    wide: bool,
+    opcode: &[u8],
+    reg: u8,
+    rm: Rm,
+    imm: &[u8],
+    force_rex: bool,
+) -> Inst {
+    let mut inst = Inst::default();
+    inst.bytes.extend_from_slice(prefixes);
+    let (index, base) = match &rm {
+        Rm::Reg(num) => (0, *num),
+        Rm::Mem(mem) => (mem.index.unwrap_or(0), mem.base.unwrap_or(0)),
+    };
+    let rex = rex(wide, reg, index, base);
+    if rex != 0x40 || force_rex {
+        inst.bytes.push(rex);
+    }
+    inst.bytes.extend_from_slice(opcode);
+    let reg = (reg & 7) << 3;
+    match rm {
+        Rm::Reg(num) => inst.bytes.push(0xc0 | reg | (num & 7)),
+        Rm::Mem(mem) => {
+            if let Some(label) = &mem.label {
+                inst.bytes.push(reg | 0b101);
+                let pos = inst.bytes.len();
+                inst.bytes
+                    .extend_from_slice(&(mem.disp as i32).to_le_bytes());
+                inst.reference = Some(PendingRef {
+                    pos,
+                    label: label.clone(),
+                    kind: RelocKind::Pc32,
+                });
+            } else {
+                let base = mem.base.unwrap_or(5);
+                let disp = mem.disp as i32;
+                let (mode, disp_bytes): (u8, Vec<u8>) = if mem.base.is_none() {
+                    (0b00, disp.to_le_bytes().to_vec())
+                } else if disp == 0 && base & 7 != 5 {
+                    (0b00, Vec::new())
+                } else if let Ok(disp) = i8::try_from(disp) {
+                    (0b01, vec![disp as u8])
+                } else {
+                    (0b10, disp.to_le_bytes().to_vec())
+                };
+                match mem.index {
+                    Some(index) => {
+                        inst.bytes.push((mode << 6) | reg | 0b100);
+                        inst.bytes.push(((index & 7) << 3) | (base & 7));
+                    }
+                    None if base & 7 == 4 => {
+                        inst.bytes.push((mode << 6) | reg | 0b100);
+                        inst.bytes.push(0x24);
+                    }
+                    None if mem.base.is_none() => {
+                        inst.bytes.push(reg | 0b100);
+                        inst.bytes.push(0x25);
+                    }
+                    None => inst.bytes.push((mode << 6) | reg | (base & 7)),
+                }
+                inst.bytes.extend_from_slice(&disp_bytes);
+            }
+        }
+    }
+    inst.bytes.extend_from_slice(imm);
+    inst
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bytes(line: &str) -> Vec<u8> {
+        let object = assemble(&format!("section .text\n{line}\n")).expect("assembles");
+        object.text
+    }
+
+    // Expected bytes were checked against GNU as.
+    #[test]
+    fn encodes_the_instructions_codegen_emits() {
+        let cases: &[(&str, &[u8])] = &[
+            ("mov rbp, rsp", &[0x48, 0x89, 0xe5]),
+            ("mov r12, [rbp-8]", &[0x4c, 0x8b, 0x65, 0xf8]),
+            ("mov [rbp-8], rdi", &[0x48, 0x89, 0x7d, 0xf8]),
+            ("mov rdi, 0", &[0x48, 0xc7, 0xc7, 0, 0, 0, 0]),
+            (
+                "mov rax, 0x123456789",
+                &[0x48, 0xb8, 0x89, 0x67, 0x45, 0x23, 0x01, 0, 0, 0],
+            ),
+            ("mov qword [r12+24], rax", &[0x49, 0x89, 0x44, 0x24, 0x18]),
+            (
+                "mov qword [r12+40], 1",
+                &[0x49, 0xc7, 0x44, 0x24, 0x28, 1, 0, 0, 0],
+            ),
+            ("mov rax, [rbp]", &[0x48, 0x8b, 0x45, 0x00]),
+            ("mov [rsp], rax", &[0x48, 0x89, 0x04, 0x24]),
+            ("mov [rdi+rcx], rax", &[0x48, 0x89, 0x04, 0x0f]),
+            ("mov dl, byte [r8+rcx]", &[0x41, 0x8a, 0x14, 0x08]),
+            ("mov al, byte [r10]", &[0x41, 0x8a, 0x02]),
+            ("mov sil, al", &[0x40, 0x88, 0xc6]),
+            ("push rbp", &[0x55]),
+            ("push r12", &[0x41, 0x54]),
+            ("pop r15", &[0x41, 0x5f]),
+            ("sub rsp, 16", &[0x48, 0x83, 0xec, 0x10]),
+            ("sub rsp, 4096", &[0x48, 0x81, 0xec, 0x00, 0x10, 0, 0]),
+            ("sub rdi, rcx", &[0x48, 0x29, 0xcf]),
+            ("add rax, r14", &[0x4c, 0x01, 0xf0]),
+            ("and rax, 15", &[0x48, 0x83, 0xe0, 0x0f]),
+            ("and rsp, -16", &[0x48, 0x83, 0xe4, 0xf0]),
+            ("xor rcx, rcx", &[0x48, 0x31, 0xc9]),
+            ("xor r9, r9", &[0x4d, 0x31, 0xc9]),
+            ("cmp rax, rbx", &[0x48, 0x39, 0xd8]),
+            ("cmp dl, 0", &[0x80, 0xfa, 0x00]),
+            ("cmp al, dl", &[0x38, 0xd0]),
+            ("test al, al", &[0x84, 0xc0]),
+            ("inc rcx", &[0x48, 0xff, 0xc1]),
+            ("imul rax, rbx", &[0x48, 0x0f, 0xaf, 0xc3]),
+            ("idiv rbx", &[0x48, 0xf7, 0xfb]),
+            ("cqo", &[0x48, 0x99]),
+            ("lea rax, [rbp-16]", &[0x48, 0x8d, 0x45, 0xf0]),
+            ("call rax", &[0xff, 0xd0]),
+            ("jmp r11", &[0x41, 0xff, 0xe3]),
+            ("rep movsb", &[0xf3, 0xa4]),
+            ("xchg rax, [rcx]", &[0x48, 0x87, 0x01]),
+            ("lock dec qword [rdi]", &[0xf0, 0x48, 0xff, 0x0f]),
+            ("lock inc dword [rdi+40]", &[0xf0, 0xff, 0x47, 0x28]),
+            ("pause", &[0xf3, 0x90]),
+            ("movq rax, xmm0", &[0x66, 0x48, 0x0f, 0x7e, 0xc0]),
+            ("movq xmm1, rax", &[0x66, 0x48, 0x0f, 0x6e, 0xc8]),
+            ("movsd xmm0, [rbp-24]", &[0xf2, 0x0f, 0x10, 0x45, 0xe8]),
+            ("divsd xmm0, xmm1", &[0xf2, 0x0f, 0x5e, 0xc1]),
+            ("leave", &[0xc9]),
+            ("ret", &[0xc3]),
+            ("syscall", &[0x0f, 0x05]),
+        ];
+        for (line, expected) in cases {
+            assert_eq!(bytes(line), *expected, "encoding of `{line}`");
+        }
+    }
+
+    #[test]
+    fn resolves_local_branches_and_relocates_the_rest() {
+        let object = assemble(
+            "bits 64\ndefault rel\nsection .text\nglobal _start\n_start:\n    jmp done ; skip\n    call exit\ndone:\n    lea rax, [rel msg]\n    jg _start\nextern exit\nsection .rodata\nmsg:\n    db \"hi;\", 10, 0\n",
+        )
+        .expect("assembles");
+        assert_eq!(&object.text[..5], &[0xe9, 5, 0, 0, 0]);
+        assert_eq!(&object.text[17..23], &[0x0f, 0x8f, 0xe9, 0xff, 0xff, 0xff]);
+        assert_eq!(object.rodata, b"hi;\n\0");
+        assert_eq!(
+            object.relocations,
+            [
+                Relocation {
+                    section: Section::Text,
+                    offset: 6,
+                    target: "exit".to_string(),
+                    kind: RelocKind::Plt32,
+                    addend: -4,
+                },
+                Relocation {
+                    section: Section::Text,
+                    offset: 13,
+                    target: "msg".to_string(),
+                    kind: RelocKind::Pc32,
+                    addend: -4,
+                },
+            ]
+        );
+        assert!(object.symbol("_start").expect("defined").global);
+        assert!(!object.symbol("done").expect("defined").global);
+    }
+
+    #[test]
+    fn assembles_quadwords() {
+        let object =
+            assemble("section .data\ntable:\n    times 2 dq 0\n    dq 1, -1\n").expect("assembles");
+        let mut expected = vec![0; 16];
+        expected.extend_from_slice(&1i64.to_le_bytes());
+        expected.extend_from_slice(&(-1i64).to_le_bytes());
+        assert_eq!(object.data, expected);
+    }
+
+    #[test]
+    fn reports_unsupported_lines() {
+        let err = assemble("section .text\n    mov rax, rbx, rcx\n").expect_err("invalid");
+        assert_eq!(
+            err.message,
+            "assembly line 2: `mov rax, rbx, rcx`: unsupported instruction"
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use crate::compiler::refinement::Bound;
 use crate::compiler::span::Span;
 use std::collections::BTreeSet;
 use std::hash::{Hash, Hasher};
@@ -10,16 +11,41 @@ pub enum BlockItem {
         path: String,
         span: Span,
     },
+    // `@"path.rgo"`: pulls another file's top-level items in at this point.
+    SourceImport {
+        path: String,
+        span: Span,
+    },
     SigDef {
         name: String,
         sig: Signature,
         span: Span,
     },
+    // `point: {x: int, y: int}`: a record type with named fields.
+    RecordDef {
+        name: String,
+        fields: Signature,
+        span: Span,
+    },
+    // `shape: {circle(r: int), empty}`: a sum type. Each variant is an item
+    // whose kind is the signature of its payload.
+    SumDef {
+        name: String,
+        variants: Signature,
+        span: Span,
+    },
     FunctionDef {
         name: String,
         lambda: Lambda,
         span: Span,
     },
+    // `is_odd: (n: int, yes: (), no: ()) ...`: declares a root function whose
+    // definition comes later, so that functions can call each other.
+    FunctionDecl {
+        name: String,
+        params: Signature,
+        span: Span,
+    },
     LitDef {
         name: String,
         literal: Literal,
@@ -32,6 +58,7 @@ pub enum BlockItem {
     },
     Lambda(Lambda),
     Ident(Ident),
+    Case(Case),
     ScopeCapture {
         params: Signature,
         continuation: Block,
@@ -44,17 +71,38 @@ impl BlockItem {
     pub fn span(&self) -> Span {
         match self {
             BlockItem::Import { span, .. }
+            | BlockItem::SourceImport { span, .. }
             | BlockItem::SigDef { span, .. }
+            | BlockItem::RecordDef { span, .. }
+            | BlockItem::SumDef { span, .. }
             | BlockItem::FunctionDef { span, .. }
+            | BlockItem::FunctionDecl { span, .. }
             | BlockItem::LitDef { span, .. }
             | BlockItem::IdentDef { span, .. } => *span,
             BlockItem::ScopeCapture { span, .. } => *span,
             BlockItem::Ident(ident) => ident.span,
             BlockItem::Lambda(lambda) => lambda.span,
+            BlockItem::Case(case) => case.span,
         }
     }
 }
 
+/// `s { circle(r: int) { ... } empty { ... } }`: runs the arm named after the
+/// variant `s` holds, with its payload bound to the arm's parameters.
+#[derive(Debug, Clone)]
+pub struct Case {
+    pub value: Term,
+    pub arms: Vec<CaseArm>,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone)]
+pub struct CaseArm {
+    pub variant: String,
+    pub lambda: Lambda,
+    pub span: Span,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum SigKind {
     Byte,
@@ -64,10 +112,18 @@ pub enum SigKind {
     Variadic, // TODO: This is before we have staged DSL
     CompileTimeInt,
     CompileTimeStr,
-    Ident(SigIdent),                                  // `foo`, `str`, `list`
-    Sig(Signature), // Nested tuple signature: `(int, b:int, tail:list)`
-    GenericInst { name: String, args: Vec<SigKind> }, // Generic instantiation: `arr<int, list>`
-    Generic(String), // Unbound generic type parameter: `T`
+    Ident(SigIdent),                 // `foo`, `str`, `list`
+    Sig(Signature),                  // Nested tuple signature: `(int, b:int, tail:list)`
+    Array(Box<SigKind>),             // Array of elements: `[int]`
+    Map(Box<SigKind>, Box<SigKind>), // Map from keys to values: `[str: int]`
+    Chan(Box<SigKind>),              // Channel between coroutines: `@chan<int>`
+    GenericInst {
+        name: String,
+        args: Vec<SigKind>,
+        span: Span,
+    }, // Generic instantiation: `arr<int, list>`
+    Generic(String),                 // Unbound generic type parameter: `T`
+    Refined(Box<SigKind>, Vec<Bound>), // Refined primitive: `int(ne: 0)`
 }
 
 impl SigKind {
@@ -175,6 +231,9 @@ pub enum Term {
     Lit(Literal),
     Lambda(Lambda),
     Ident(Ident),
+    Array(ArrayLit),
+    Map(MapLit),
+    Field(FieldAccess),
 }
 
 impl Term {
@@ -183,10 +242,36 @@ impl Term {
             Term::Lit(literal) => literal.span,
             Term::Ident(ident) => ident.span,
             Term::Lambda(lambda) => lambda.span,
+            Term::Array(array) => array.span,
+            Term::Map(map) => map.span,
+            Term::Field(field) => field.span,
         }
     }
 }
 
+/// An array literal: `[1, 2, 3]`. Items are built at runtime, so they may name bindings.
+#[derive(Debug, Clone)]
+pub struct ArrayLit {
+    pub items: Vec<Term>,
+    pub span: Span,
+}
+
+/// A map literal: `["a": 1, "b": 2]`, or `[:]` when empty. Later entries
+/// replace earlier ones with the same key.
+#[derive(Debug, Clone)]
+pub struct MapLit {
+    pub entries: Vec<(Term, Term)>,
+    pub span: Span,
+}
+
+/// A field read from a record: `p.x`.
+#[derive(Debug, Clone)]
+pub struct FieldAccess {
+    pub record: Box<Term>,
+    pub field: String,
+    pub span: Span,
+}
+
 #[derive(Debug, Clone)]
 pub struct Literal {
     pub value: Lit,
@@ -14,18 +14,66 @@ pub enum Builtin {
     Sub,
     Mul,
     Div,
+    Rem,
+    And,
+    Or,
+    Xor,
+    Shl,
+    Shr,
+    Neg,
+    AddC,
+    SubC,
+    MulC,
     AddF64,
+    SubF64,
     MulF64,
     DivF64,
+    NegF64,
+    SqrtF64,
+    IntToF64,
+    F64ToInt,
     Eq,
     Eqi,
     Eqs,
     Lt,
     Gt,
+    Le,
+    Ge,
+    Ne,
+    EqF64,
+    LtF64,
+    GtF64,
     Write,
+    WriteFd,
+    Read,
+    Open,
+    Close,
+    Argc,
+    Arg,
+    Getenv,
     Exit,
     Printf,
     Sprintf,
+    Strlen,
+    Concat,
+    Slice,
+    ByteAt,
+    Cmps,
+    ArrLen,
+    Get,
+    Set,
+    Insert,
+    Lookup,
+    Remove,
+    MapLen,
+    MapNext,
+    Chan,
+    Send,
+    Recv,
+    Spawn,
+    Thread,
+    Yield,
+    Done,
 }
 
 impl Builtin {
@@ -36,18 +84,66 @@ impl Builtin {
             "mul" => Some(Builtin::Mul),
             "div" => Some(Builtin::Div),
             "divint" => Some(Builtin::Div),
+            "rem" => Some(Builtin::Rem),
+            "and" => Some(Builtin::And),
+            "or" => Some(Builtin::Or),
+            "xor" => Some(Builtin::Xor),
+            "shl" => Some(Builtin::Shl),
+            "shr" => Some(Builtin::Shr),
+            "neg" => Some(Builtin::Neg),
+            "addc" => Some(Builtin::AddC),
+            "subc" => Some(Builtin::SubC),
+            "mulc" => Some(Builtin::MulC),
             "addf64" => Some(Builtin::AddF64),
+            "subf64" => Some(Builtin::SubF64),
             "mulf64" => Some(Builtin::MulF64),
             "divf64" => Some(Builtin::DivF64),
+            "negf64" => Some(Builtin::NegF64),
+            "sqrtf64" => Some(Builtin::SqrtF64),
+            "itof" => Some(Builtin::IntToF64),
+            "ftoi" => Some(Builtin::F64ToInt),
             "eq" => Some(Builtin::Eqi),
             "eqi" => Some(Builtin::Eqi),
             "eqs" => Some(Builtin::Eqs),
             "lt" => Some(Builtin::Lt),
             "gt" => Some(Builtin::Gt),
+            "le" => Some(Builtin::Le),
+            "ge" => Some(Builtin::Ge),
+            "ne" => Some(Builtin::Ne),
+            "eqf64" => Some(Builtin::EqF64),
+            "ltf64" => Some(Builtin::LtF64),
+            "gtf64" => Some(Builtin::GtF64),
             "write" => Some(Builtin::Write),
+            "writefd" => Some(Builtin::WriteFd),
+            "read" => Some(Builtin::Read),
+            "open" => Some(Builtin::Open),
+            "close" => Some(Builtin::Close),
+            "argc" => Some(Builtin::Argc),
+            "arg" => Some(Builtin::Arg),
+            "getenv" => Some(Builtin::Getenv),
             "exit" => Some(Builtin::Exit),
             "printf" => Some(Builtin::Printf),
             "sprintf" => Some(Builtin::Sprintf),
+            "strlen" => Some(Builtin::Strlen),
+            "concat" => Some(Builtin::Concat),
+            "slice" => Some(Builtin::Slice),
+            "byteat" => Some(Builtin::ByteAt),
+            "cmps" => Some(Builtin::Cmps),
+            "arrlen" => Some(Builtin::ArrLen),
+            "get" => Some(Builtin::Get),
+            "set" => Some(Builtin::Set),
+            "insert" => Some(Builtin::Insert),
+            "lookup" => Some(Builtin::Lookup),
+            "remove" => Some(Builtin::Remove),
+            "maplen" => Some(Builtin::MapLen),
+            "mapnext" => Some(Builtin::MapNext),
+            "chan" => Some(Builtin::Chan),
+            "send" => Some(Builtin::Send),
+            "recv" => Some(Builtin::Recv),
+            "spawn" => Some(Builtin::Spawn),
+            "thread" => Some(Builtin::Thread),
+            "yield" => Some(Builtin::Yield),
+            "done" => Some(Builtin::Done),
             _ => None,
         }
     }
@@ -58,32 +154,145 @@ impl Builtin {
             Builtin::Sub => "sub",
             Builtin::Mul => "mul",
             Builtin::Div => "div",
+            Builtin::Rem => "rem",
+            Builtin::And => "and",
+            Builtin::Or => "or",
+            Builtin::Xor => "xor",
+            Builtin::Shl => "shl",
+            Builtin::Shr => "shr",
+            Builtin::Neg => "neg",
+            Builtin::AddC => "addc",
+            Builtin::SubC => "subc",
+            Builtin::MulC => "mulc",
             Builtin::AddF64 => "addf64",
+            Builtin::SubF64 => "subf64",
             Builtin::MulF64 => "mulf64",
             Builtin::DivF64 => "divf64",
+            Builtin::NegF64 => "negf64",
+            Builtin::SqrtF64 => "sqrtf64",
+            Builtin::IntToF64 => "itof",
+            Builtin::F64ToInt => "ftoi",
             Builtin::Eq => "eq",
             Builtin::Eqi => "eq",
             Builtin::Eqs => "eqs",
             Builtin::Lt => "lt",
             Builtin::Gt => "gt",
+            Builtin::Le => "le",
+            Builtin::Ge => "ge",
+            Builtin::Ne => "ne",
+            Builtin::EqF64 => "eqf64",
+            Builtin::LtF64 => "ltf64",
+            Builtin::GtF64 => "gtf64",
             Builtin::Write => "write",
+            Builtin::WriteFd => "writefd",
+            Builtin::Read => "read",
+            Builtin::Open => "open",
+            Builtin::Close => "close",
+            Builtin::Argc => "argc",
+            Builtin::Arg => "arg",
+            Builtin::Getenv => "getenv",
             Builtin::Exit => "exit",
             Builtin::Printf => "printf",
             Builtin::Sprintf => "sprintf",
+            Builtin::Strlen => "strlen",
+            Builtin::Concat => "concat",
+            Builtin::Slice => "slice",
+            Builtin::ByteAt => "byteat",
+            Builtin::Cmps => "cmps",
+            Builtin::ArrLen => "arrlen",
+            Builtin::Get => "get",
+            Builtin::Set => "set",
+            Builtin::Insert => "insert",
+            Builtin::Lookup => "lookup",
+            Builtin::Remove => "remove",
+            Builtin::MapLen => "maplen",
+            Builtin::MapNext => "mapnext",
+            Builtin::Chan => "chan",
+            Builtin::Send => "send",
+            Builtin::Recv => "recv",
+            Builtin::Spawn => "spawn",
+            Builtin::Thread => "thread",
+            Builtin::Yield => "yield",
+            Builtin::Done => "done",
         }
     }
 
     pub fn signature(self) -> Signature {
         match self {
-            Builtin::Add | Builtin::Sub | Builtin::Mul => math_binary_sig(SigKind::Int),
-            Builtin::Div => div_sig(),
-            Builtin::AddF64 | Builtin::MulF64 | Builtin::DivF64 => math_binary_sig(SigKind::F64),
-            Builtin::Eq | Builtin::Eqi | Builtin::Lt | Builtin::Gt => comparison_sig(SigKind::Int),
+            Builtin::Add
+            | Builtin::Sub
+            | Builtin::Mul
+            | Builtin::And
+            | Builtin::Or
+            | Builtin::Xor
+            | Builtin::Shl
+            | Builtin::Shr => math_binary_sig(SigKind::Int),
+            Builtin::Div | Builtin::Rem => div_sig(),
+            Builtin::Neg => math_unary_sig(SigKind::Int, SigKind::Int),
+            // `overflow` gets the wrapped result.
+            Builtin::AddC | Builtin::SubC | Builtin::MulC => checked_sig(),
+            Builtin::AddF64 | Builtin::SubF64 | Builtin::MulF64 | Builtin::DivF64 => {
+                math_binary_sig(SigKind::F64)
+            }
+            Builtin::NegF64 | Builtin::SqrtF64 => math_unary_sig(SigKind::F64, SigKind::F64),
+            Builtin::IntToF64 => math_unary_sig(SigKind::Int, SigKind::F64),
+            // NaN and values outside the int range have no int to become.
+            Builtin::F64ToInt => sig_from_items(vec![
+                sig_item("value", SigKind::F64),
+                sig_item("err", SigKind::tuple([])),
+                sig_item("ok", SigKind::tuple([SigKind::Int])),
+            ]),
+            Builtin::Eq
+            | Builtin::Eqi
+            | Builtin::Lt
+            | Builtin::Gt
+            | Builtin::Le
+            | Builtin::Ge
+            | Builtin::Ne => comparison_sig(SigKind::Int),
             Builtin::Eqs => comparison_sig(SigKind::Str),
+            // Comparisons with NaN are false, so they take `err`.
+            Builtin::EqF64 | Builtin::LtF64 | Builtin::GtF64 => comparison_sig(SigKind::F64),
             Builtin::Write => sig_from_items(vec![
                 sig_item("value", SigKind::Str),
                 sig_item("ok", SigKind::tuple([])),
             ]),
+            Builtin::WriteFd => sig_from_items(vec![
+                sig_item("fd", SigKind::Int),
+                sig_item("value", SigKind::Str),
+                sig_item("ok", SigKind::tuple([])),
+            ]),
+            // A read of at most `max` bytes; `err` gets the errno.
+            Builtin::Read => sig_from_items(vec![
+                sig_item("fd", SigKind::Int),
+                sig_item("max", SigKind::Int),
+                sig_item("ok", SigKind::tuple([SigKind::Str])),
+                sig_item("eof", SigKind::tuple([])),
+                sig_item("err", SigKind::tuple([SigKind::Int])),
+            ]),
+            Builtin::Open => sig_from_items(vec![
+                sig_item("path", SigKind::Str),
+                sig_item("flags", SigKind::Int),
+                sig_item("mode", SigKind::Int),
+                sig_item("err", SigKind::tuple([SigKind::Int])),
+                sig_item("ok", SigKind::tuple([SigKind::Int])),
+            ]),
+            Builtin::Close => sig_from_items(vec![
+                sig_item("fd", SigKind::Int),
+                sig_item("err", SigKind::tuple([SigKind::Int])),
+                sig_item("ok", SigKind::tuple([])),
+            ]),
+            Builtin::Argc => sig_from_items(vec![sig_item("ok", SigKind::tuple([SigKind::Int]))]),
+            // Indexed like `argv`, so the program's own path comes first.
+            Builtin::Arg => sig_from_items(vec![
+                sig_item("index", SigKind::Int),
+                sig_item("one", SigKind::tuple([SigKind::Str])),
+                sig_item("none", SigKind::tuple([])),
+            ]),
+            Builtin::Getenv => sig_from_items(vec![
+                sig_item("name", SigKind::Str),
+                sig_item("found", SigKind::tuple([SigKind::Str])),
+                sig_item("missing", SigKind::tuple([])),
+            ]),
             Builtin::Exit => sig_from_items(vec![sig_item("code", SigKind::Int)]),
             Builtin::Printf => sig_from_items(vec![
                 sig_item("format", SigKind::CompileTimeStr),
@@ -95,18 +304,152 @@ impl Builtin {
                 sig_item("args", SigKind::Variadic),
                 sig_item("ok", SigKind::tuple([SigKind::Str])),
             ]),
+            Builtin::Strlen => sig_from_items(vec![
+                sig_item("value", SigKind::Str),
+                sig_item("ok", SigKind::tuple([SigKind::Int])),
+            ]),
+            Builtin::Concat => sig_from_items(vec![
+                sig_item("left", SigKind::Str),
+                sig_item("right", SigKind::Str),
+                sig_item("ok", SigKind::tuple([SigKind::Str])),
+            ]),
+            Builtin::Slice => sig_from_items(vec![
+                sig_item("value", SigKind::Str),
+                sig_item("start", SigKind::Int),
+                sig_item("end", SigKind::Int),
+                sig_item("err", SigKind::tuple([])),
+                sig_item("ok", SigKind::tuple([SigKind::Str])),
+            ]),
+            // The byte comes back as an int so it can meet the comparisons.
+            Builtin::ByteAt => sig_from_items(vec![
+                sig_item("value", SigKind::Str),
+                sig_item("index", SigKind::Int),
+                sig_item("err", SigKind::tuple([])),
+                sig_item("ok", SigKind::tuple([SigKind::Int])),
+            ]),
+            Builtin::Cmps => sig_from_items(vec![
+                sig_item("left", SigKind::Str),
+                sig_item("right", SigKind::Str),
+                sig_item("lt", SigKind::tuple([])),
+                sig_item("eq", SigKind::tuple([])),
+                sig_item("gt", SigKind::tuple([])),
+            ]),
+            Builtin::ArrLen => array_sig(vec![
+                sig_item("array", array_of_t()),
+                sig_item("ok", SigKind::tuple([SigKind::Int])),
+            ]),
+            // `get` moves the element out, so the array is gone on either path.
+            Builtin::Get => array_sig(vec![
+                sig_item("array", array_of_t()),
+                sig_item("index", SigKind::Int),
+                sig_item("one", SigKind::tuple([SigKind::Generic("T".to_string())])),
+                sig_item("none", SigKind::tuple([])),
+            ]),
+            Builtin::Set => array_sig(vec![
+                sig_item("array", array_of_t()),
+                sig_item("index", SigKind::Int),
+                sig_item("value", SigKind::Generic("T".to_string())),
+                sig_item("err", SigKind::tuple([])),
+                sig_item("ok", SigKind::tuple([array_of_t()])),
+            ]),
+            // Map builtins take the map and hand it back, so it can be threaded
+            // through a chain of operations without copies.
+            Builtin::Insert => map_sig(vec![
+                sig_item("map", map_of_k_v()),
+                sig_item("key", SigKind::Generic("K".to_string())),
+                sig_item("value", SigKind::Generic("V".to_string())),
+                sig_item("ok", SigKind::tuple([map_of_k_v()])),
+            ]),
+            // `found` gets a copy of the value; the map keeps its own.
+            Builtin::Lookup => map_sig(vec![
+                sig_item("map", map_of_k_v()),
+                sig_item("key", SigKind::Generic("K".to_string())),
+                sig_item(
+                    "found",
+                    SigKind::tuple([SigKind::Generic("V".to_string()), map_of_k_v()]),
+                ),
+                sig_item("missing", SigKind::tuple([map_of_k_v()])),
+            ]),
+            Builtin::Remove => map_sig(vec![
+                sig_item("map", map_of_k_v()),
+                sig_item("key", SigKind::Generic("K".to_string())),
+                sig_item("ok", SigKind::tuple([map_of_k_v()])),
+            ]),
+            Builtin::MapLen => map_sig(vec![
+                sig_item("map", map_of_k_v()),
+                sig_item("ok", SigKind::tuple([SigKind::Int, map_of_k_v()])),
+            ]),
+            // Entries come in insertion order; `entry` gets copies of the one
+            // at `cursor` and the cursor of the next.
+            Builtin::MapNext => map_sig(vec![
+                sig_item("map", map_of_k_v()),
+                sig_item("cursor", SigKind::Int),
+                sig_item(
+                    "entry",
+                    SigKind::tuple([
+                        SigKind::Generic("K".to_string()),
+                        SigKind::Generic("V".to_string()),
+                        SigKind::Int,
+                        map_of_k_v(),
+                    ]),
+                ),
+                sig_item("done", SigKind::tuple([map_of_k_v()])),
+            ]),
+            Builtin::Chan => chan_sig(vec![sig_item("ok", SigKind::tuple([chan_of_t()]))]),
+            // Channels are unbounded, so a send never waits. The value moves
+            // into the channel and the channel comes back like a map does.
+            Builtin::Send => chan_sig(vec![
+                sig_item("channel", chan_of_t()),
+                sig_item("value", SigKind::Generic("T".to_string())),
+                sig_item("ok", SigKind::tuple([chan_of_t()])),
+            ]),
+            // An empty channel parks `ok` until another coroutine sends.
+            Builtin::Recv => chan_sig(vec![
+                sig_item("channel", chan_of_t()),
+                sig_item(
+                    "ok",
+                    SigKind::tuple([SigKind::Generic("T".to_string()), chan_of_t()]),
+                ),
+            ]),
+            // `task` joins the back of the run queue and `ok` runs right away.
+            Builtin::Spawn => sig_from_items(vec![
+                sig_item("task", SigKind::tuple([])),
+                sig_item("ok", SigKind::tuple([])),
+            ]),
+            // `task` starts on a new OS thread and `ok` runs right away.
+            Builtin::Thread => sig_from_items(vec![
+                sig_item("task", SigKind::tuple([])),
+                sig_item("ok", SigKind::tuple([])),
+            ]),
+            Builtin::Yield => sig_from_items(vec![sig_item("ok", SigKind::tuple([]))]),
+            // Ends the running coroutine; the program exits once none is left.
+            Builtin::Done => sig_from_items(Vec::new()),
         }
     }
 
     pub fn is_call(self) -> bool {
         matches!(
             self,
-            Builtin::Printf | Builtin::Sprintf | Builtin::Write | Builtin::Exit
+            Builtin::Printf | Builtin::Sprintf | Builtin::Write | Builtin::WriteFd | Builtin::Exit
         )
     }
 
     pub fn is_conditional(self) -> bool {
-        matches!(self, Builtin::Eq | Builtin::Eqi | Builtin::Eqs)
+        matches!(
+            self,
+            Builtin::Eq
+                | Builtin::Eqi
+                | Builtin::Eqs
+                | Builtin::Lt
+                | Builtin::Gt
+                | Builtin::Le
+                | Builtin::Ge
+                | Builtin::Ne
+                | Builtin::EqF64
+                | Builtin::LtF64
+                | Builtin::GtF64
+                | Builtin::Cmps
+        )
     }
 
     pub fn is_instruction(self) -> bool {
@@ -116,18 +459,63 @@ impl Builtin {
                 | Builtin::Sub
                 | Builtin::Mul
                 | Builtin::Div
+                | Builtin::Rem
+                | Builtin::And
+                | Builtin::Or
+                | Builtin::Xor
+                | Builtin::Shl
+                | Builtin::Shr
+                | Builtin::Neg
+                | Builtin::AddC
+                | Builtin::SubC
+                | Builtin::MulC
                 | Builtin::AddF64
+                | Builtin::SubF64
                 | Builtin::MulF64
                 | Builtin::DivF64
-                | Builtin::Lt
-                | Builtin::Gt
+                | Builtin::NegF64
+                | Builtin::SqrtF64
+                | Builtin::IntToF64
+                | Builtin::F64ToInt
+                | Builtin::Strlen
+                | Builtin::Concat
+                | Builtin::Slice
+                | Builtin::ByteAt
+                | Builtin::Read
+                | Builtin::Open
+                | Builtin::Close
+                | Builtin::Argc
+                | Builtin::Arg
+                | Builtin::Getenv
+                | Builtin::ArrLen
+                | Builtin::Get
+                | Builtin::Set
+                | Builtin::Insert
+                | Builtin::Lookup
+                | Builtin::Remove
+                | Builtin::MapLen
+                | Builtin::MapNext
+        )
+    }
+
+    /// Builtins that make channels or hand control to the scheduler.
+    pub fn is_coroutine(self) -> bool {
+        matches!(
+            self,
+            Builtin::Chan
+                | Builtin::Send
+                | Builtin::Recv
+                | Builtin::Spawn
+                | Builtin::Thread
+                | Builtin::Yield
+                | Builtin::Done
         )
     }
 
     pub fn is_libc_call(self) -> bool {
         matches!(
             self,
-            Builtin::Printf | Builtin::Sprintf | Builtin::Write | Builtin::Exit
+            Builtin::Printf | Builtin::Sprintf | Builtin::Write | Builtin::WriteFd | Builtin::Exit
         )
     }
 }
@@ -145,6 +533,98 @@ pub fn get_spec(name: &str) -> Option<BuiltinSpec> {
     }
 }
 
+/// What a printf conversion reads from the argument list.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FormatArg {
+    Int,
+    Str,
+    F64,
+    Pointer,
+}
+
+impl FormatArg {
+    pub fn describe(self) -> &'static str {
+        match self {
+            FormatArg::Int => "an int",
+            FormatArg::Str => "a str",
+            FormatArg::F64 => "an f64",
+            FormatArg::Pointer => "a pointer",
+        }
+    }
+}
+
+/// One argument a format string consumes, with the conversion that reads it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FormatSlot {
+    pub conversion: String,
+    pub arg: FormatArg,
+}
+
+/// Lists the arguments `format` reads, in order, the way libc printf parses
+/// it. A `*` width or precision takes an int slot of its own. Every argument
+/// is 64 bits wide, so integer conversions may only take a 64-bit length
+/// modifier and the others none, apart from the `l` printf ignores on `%f`.
+pub fn format_slots(format: &str) -> Result<Vec<FormatSlot>, String> {
+    let mut slots = Vec::new();
+    let mut chars = format.char_indices().peekable();
+    while let Some((start, ch)) = chars.next() {
+        if ch != '%' {
+            continue;
+        }
+        let mut stars = 0;
+        let mut length = String::new();
+        let conversion = loop {
+            let Some((idx, next)) = chars.next() else {
+                return Err(format!(
+                    "format string ends in the middle of `{}`",
+                    &format[start..]
+                ));
+            };
+            match next {
+                '-' | '+' | ' ' | '#' | '0'..='9' | '.' | '*' if !length.is_empty() => {
+                    return Err(format!(
+                        "`{}` puts its length modifier before `{next}`",
+                        &format[start..idx + next.len_utf8()]
+                    ));
+                }
+                '-' | '+' | ' ' | '#' | '0'..='9' | '.' => {}
+                '*' => stars += 1,
+                'h' | 'l' | 'q' | 'j' | 'z' | 't' | 'L' => length.push(next),
+                _ => break &format[start..idx + next.len_utf8()],
+            }
+        };
+        let arg = match conversion.chars().last() {
+            Some('%') if conversion == "%%" => continue,
+            Some('d' | 'i' | 'u' | 'x' | 'X' | 'o' | 'c') => FormatArg::Int,
+            Some('s') => FormatArg::Str,
+            Some('f' | 'F' | 'e' | 'E' | 'g' | 'G') => FormatArg::F64,
+            Some('p') => FormatArg::Pointer,
+            _ => return Err(format!("`{conversion}` is not a supported conversion")),
+        };
+        let lengths: &[&str] = match conversion.chars().last() {
+            Some('d' | 'i' | 'u' | 'x' | 'X' | 'o') => &["", "l", "ll", "q", "j", "z", "t"],
+            Some('f' | 'F' | 'e' | 'E' | 'g' | 'G') => &["", "l"],
+            _ => &[""],
+        };
+        if !lengths.contains(&length.as_str()) {
+            return Err(format!(
+                "`{conversion}` does not take the length modifier `{length}`"
+            ));
+        }
+        for _ in 0..stars {
+            slots.push(FormatSlot {
+                conversion: conversion.to_string(),
+                arg: FormatArg::Int,
+            });
+        }
+        slots.push(FormatSlot {
+            conversion: conversion.to_string(),
+            arg,
+        });
+    }
+    Ok(slots)
+}
+
 fn sig_item(name: &str, ty: SigKind) -> SigItem {
     SigItem {
         name: name.to_string(),
@@ -167,6 +647,46 @@ fn sig_from_items(items: Vec<SigItem>) -> Signature {
     }
 }
 
+/// The signature of an array builtin, generic over the element kind `T`.
+fn array_sig(items: Vec<SigItem>) -> Signature {
+    Signature {
+        items,
+        generics: BTreeSet::from(["T".to_string()]),
+    }
+}
+
+fn array_of_t() -> SigKind {
+    SigKind::Array(Box::new(SigKind::Generic("T".to_string())))
+}
+
+/// The signature of a map builtin, generic over the key kind `K` and the value
+/// kind `V`.
+fn map_sig(items: Vec<SigItem>) -> Signature {
+    Signature {
+        items,
+        generics: BTreeSet::from(["K".to_string(), "V".to_string()]),
+    }
+}
+
+fn map_of_k_v() -> SigKind {
+    SigKind::Map(
+        Box::new(SigKind::Generic("K".to_string())),
+        Box::new(SigKind::Generic("V".to_string())),
+    )
+}
+
+/// The signature of a channel builtin, generic over the element kind `T`.
+fn chan_sig(items: Vec<SigItem>) -> Signature {
+    Signature {
+        items,
+        generics: BTreeSet::from(["T".to_string()]),
+    }
+}
+
+fn chan_of_t() -> SigKind {
+    SigKind::Chan(Box::new(SigKind::Generic("T".to_string())))
+}
+
 fn comparison_sig(arg_kind: SigKind) -> Signature {
     sig_from_items(vec![
         sig_item("left", arg_kind.clone()),
@@ -176,6 +696,15 @@ fn comparison_sig(arg_kind: SigKind) -> Signature {
     ])
 }
 
+fn checked_sig() -> Signature {
+    sig_from_items(vec![
+        sig_item("x", SigKind::Int),
+        sig_item("y", SigKind::Int),
+        sig_item("overflow", SigKind::tuple([SigKind::Int])),
+        sig_item("ok", SigKind::tuple([SigKind::Int])),
+    ])
+}
+
 fn div_sig() -> Signature {
     let err_sig = tuple_sig(vec![sig_item("res", SigKind::Int)]);
     let ok_sig = tuple_sig(vec![sig_item("res", SigKind::Int)]);
@@ -223,11 +752,195 @@ mod tests {
         assert_eq!(tuple.items[0].kind, SigKind::F64);
     }
 
+    #[test]
+    fn array_builtins_are_generic_over_the_element() {
+        let get = Builtin::from_name("get").expect("get builtin should exist");
+        let sig = get.signature();
+        assert!(sig.generics.contains("T"));
+        assert_eq!(
+            sig.items[0].kind,
+            SigKind::Array(Box::new(SigKind::Generic("T".to_string())))
+        );
+        assert_eq!(
+            sig.items[2].kind,
+            SigKind::tuple([SigKind::Generic("T".to_string())])
+        );
+        let set = Builtin::from_name("set").expect("set builtin should exist");
+        assert_eq!(
+            set.signature().items[4].kind,
+            SigKind::tuple([array_of_t()])
+        );
+        assert!(Builtin::from_name("arrlen").is_some_and(Builtin::is_instruction));
+    }
+
+    #[test]
+    fn map_builtins_hand_the_map_back() {
+        let lookup = Builtin::from_name("lookup").expect("lookup builtin should exist");
+        let sig = lookup.signature();
+        assert!(sig.generics.contains("K") && sig.generics.contains("V"));
+        assert_eq!(sig.items[0].kind, map_of_k_v());
+        assert_eq!(
+            sig.items[2].kind,
+            SigKind::tuple([SigKind::Generic("V".to_string()), map_of_k_v()])
+        );
+        assert_eq!(sig.items[3].kind, SigKind::tuple([map_of_k_v()]));
+        let next = Builtin::from_name("mapnext").expect("mapnext builtin should exist");
+        let SigKind::Sig(entry) = &next.signature().items[2].kind else {
+            panic!("entry should be a continuation");
+        };
+        assert_eq!(entry.items.len(), 4);
+        assert_eq!(entry.items[3].kind, map_of_k_v());
+        assert!(Builtin::from_name("maplen").is_some_and(Builtin::is_instruction));
+    }
+
+    #[test]
+    fn channel_builtins_hand_the_channel_back() {
+        let recv = Builtin::from_name("recv").expect("recv builtin should exist");
+        let sig = recv.signature();
+        assert!(sig.generics.contains("T"));
+        assert_eq!(sig.items[0].kind, chan_of_t());
+        assert_eq!(
+            sig.items[1].kind,
+            SigKind::tuple([SigKind::Generic("T".to_string()), chan_of_t()])
+        );
+        let send = Builtin::from_name("send").expect("send builtin should exist");
+        assert_eq!(
+            send.signature().items[2].kind,
+            SigKind::tuple([chan_of_t()])
+        );
+        assert!(Builtin::from_name("done").is_some_and(|done| done.signature().items.is_empty()));
+        assert!(["chan", "send", "recv", "spawn", "yield", "done"]
+            .into_iter()
+            .all(|name| Builtin::from_name(name).is_some_and(Builtin::is_coroutine)));
+    }
+
     #[test]
     fn builtin_variants_exist_for_float_ops() {
         assert!(Builtin::from_name("mulf64").is_some());
         assert!(Builtin::from_name("divf64").is_some());
+        assert!(Builtin::from_name("subf64").is_some_and(Builtin::is_instruction));
+        assert!(Builtin::from_name("ltf64").is_some_and(Builtin::is_conditional));
+        assert_eq!(
+            Builtin::from_name("sqrtf64").map(|b| b.signature().items.len()),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn float_conversions_cross_between_int_and_f64() {
+        let itof = Builtin::from_name("itof")
+            .expect("itof builtin should exist")
+            .signature();
+        assert_eq!(itof.items[0].kind, SigKind::Int);
+        assert_eq!(itof.items[1].kind, SigKind::tuple([SigKind::F64]));
+
+        let ftoi = Builtin::from_name("ftoi")
+            .expect("ftoi builtin should exist")
+            .signature();
+        let names: Vec<&str> = ftoi.items.iter().map(|item| item.name.as_str()).collect();
+        assert_eq!(names, vec!["value", "err", "ok"]);
+        assert_eq!(ftoi.items[2].kind, SigKind::tuple([SigKind::Int]));
+    }
+
+    #[test]
+    fn checked_arithmetic_takes_overflow_before_ok() {
+        let addc = Builtin::from_name("addc")
+            .expect("addc builtin should exist")
+            .signature();
+        let names: Vec<&str> = addc.items.iter().map(|item| item.name.as_str()).collect();
+        assert_eq!(names, vec!["x", "y", "overflow", "ok"]);
+        assert_eq!(addc.items[2].kind, SigKind::tuple([SigKind::Int]));
+        assert_eq!(
+            Builtin::from_name("rem").map(Builtin::signature),
+            Some(div_sig())
+        );
+        assert!(Builtin::from_name("shr").is_some_and(Builtin::is_instruction));
+        assert!(["le", "ge", "ne"]
+            .iter()
+            .all(|name| Builtin::from_name(name).is_some_and(Builtin::is_conditional)));
+    }
+
+    #[test]
+    fn string_builtins_take_error_continuations_before_ok() {
+        let slice = Builtin::from_name("slice")
+            .expect("slice builtin should exist")
+            .signature();
+        let names: Vec<&str> = slice.items.iter().map(|item| item.name.as_str()).collect();
+        assert_eq!(names, vec!["value", "start", "end", "err", "ok"]);
+
+        let cmps = Builtin::from_name("cmps")
+            .expect("cmps builtin should exist")
+            .signature();
+        assert_eq!(cmps.items.len(), 5);
+        assert!(Builtin::Cmps.is_conditional());
+        assert!(Builtin::ByteAt.is_instruction());
     }
+
+    #[test]
+    fn read_takes_ok_eof_and_err_continuations() {
+        let read = Builtin::from_name("read")
+            .expect("read builtin should exist")
+            .signature();
+        let names: Vec<&str> = read.items.iter().map(|item| item.name.as_str()).collect();
+        assert_eq!(names, vec!["fd", "max", "ok", "eof", "err"]);
+        assert!(Builtin::Read.is_instruction());
+        assert!(Builtin::WriteFd.is_libc_call());
+    }
+
+    #[test]
+    fn format_slots_follow_conversions() {
+        let slots = format_slots("%-*d%% %s=%.2f %p").expect("valid format");
+        let args: Vec<FormatArg> = slots.iter().map(|slot| slot.arg).collect();
+        assert_eq!(
+            args,
+            vec![
+                FormatArg::Int,
+                FormatArg::Int,
+                FormatArg::Str,
+                FormatArg::F64,
+                FormatArg::Pointer
+            ]
+        );
+        assert_eq!(slots[0].conversion, "%-*d");
+        assert_eq!(slots[3].conversion, "%.2f");
+    }
+
+    #[test]
+    fn format_slots_reject_unknown_conversions() {
+        assert_eq!(
+            format_slots("%n").unwrap_err(),
+            "`%n` is not a supported conversion"
+        );
+        assert_eq!(
+            format_slots("50%").unwrap_err(),
+            "format string ends in the middle of `%`"
+        );
+    }
+
+    #[test]
+    fn format_slots_check_length_modifiers() {
+        for format in ["%ld", "%lld", "%jx", "%zu", "%td", "%qi", "%lf", "%.2lg"] {
+            assert!(format_slots(format).is_ok(), "{format}");
+        }
+        for (format, message) in [
+            ("%hd", "`%hd` does not take the length modifier `h`"),
+            ("%hhu", "`%hhu` does not take the length modifier `hh`"),
+            ("%Lf", "`%Lf` does not take the length modifier `L`"),
+            ("%zf", "`%zf` does not take the length modifier `z`"),
+            ("%ls", "`%ls` does not take the length modifier `l`"),
+            ("%lc", "`%lc` does not take the length modifier `l`"),
+            ("%lp", "`%lp` does not take the length modifier `l`"),
+            ("%lzd", "`%lzd` does not take the length modifier `lz`"),
+            ("%l5d", "`%l5` puts its length modifier before `5`"),
+        ] {
+            assert_eq!(format_slots(format).unwrap_err(), message);
+        }
+    }
+}
+
+fn math_unary_sig(arg_kind: SigKind, result_kind: SigKind) -> Signature {
+    let result_sig = tuple_sig(vec![sig_item("res", result_kind)]);
+    sig_from_items(vec![sig_item("x", arg_kind), sig_item("ok", result_sig)])
 }
 
 fn math_binary_sig(arg_kind: SigKind) -> Signature {
@@ -241,17 +954,83 @@ fn math_binary_sig(arg_kind: SigKind) -> Signature {
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum AirRuntimeHelper {
+    AllocHeapPtr,
+    FreeHeapPtr,
     ReleaseHeapPtr,
     DeepCopyHeapPtr,
     MemcpyHelper,
+    ReleaseStrPtr,
+    CopyStrPtr,
+    AllocStrPtr,
+    ConcatStrPtr,
+    SliceStrPtr,
+    CmpStrPtr,
+    CStrToStrPtr,
+    ProcessArgs,
+    ReleaseArrayPtr,
+    ReleaseArrayElemPtr,
+    CopyArrayPtr,
+    CopyArrayElemPtr,
+    MapNewPtr,
+    MapHashPtr,
+    MapFindPtr,
+    MapInsertPtr,
+    MapGrowPtr,
+    MapReindexPtr,
+    MapRemovePtr,
+    MapReleasePtr,
+    MapCopyPtr,
+    QueuePushPtr,
+    QueuePopPtr,
+    SchedNextPtr,
+    SchedReadyPtr,
+    ThreadNewPtr,
+    ChanNewPtr,
+    ChanSendPtr,
+    ChanRecvPtr,
+    ChanReleasePtr,
+    ChanCopyPtr,
 }
 
 impl AirRuntimeHelper {
     pub fn name(&self) -> &'static str {
         match self {
+            AirRuntimeHelper::AllocHeapPtr => "alloc_heap_ptr",
+            AirRuntimeHelper::FreeHeapPtr => "free_heap_ptr",
             AirRuntimeHelper::ReleaseHeapPtr => "release_heap_ptr",
             AirRuntimeHelper::DeepCopyHeapPtr => "deepcopy_heap_ptr",
             AirRuntimeHelper::MemcpyHelper => "memcpy_helper",
+            AirRuntimeHelper::ReleaseStrPtr => "release_str_ptr",
+            AirRuntimeHelper::CopyStrPtr => "copy_str_ptr",
+            AirRuntimeHelper::AllocStrPtr => "alloc_str_ptr",
+            AirRuntimeHelper::ConcatStrPtr => "concat_str_ptr",
+            AirRuntimeHelper::SliceStrPtr => "slice_str_ptr",
+            AirRuntimeHelper::CmpStrPtr => "cmp_str_ptr",
+            AirRuntimeHelper::CStrToStrPtr => "cstr_to_str_ptr",
+            AirRuntimeHelper::ProcessArgs => "process_args",
+            AirRuntimeHelper::ReleaseArrayPtr => "release_array_ptr",
+            AirRuntimeHelper::ReleaseArrayElemPtr => "release_array_elem_ptr",
+            AirRuntimeHelper::CopyArrayPtr => "copy_array_ptr",
+            AirRuntimeHelper::CopyArrayElemPtr => "copy_array_elem_ptr",
+            AirRuntimeHelper::MapNewPtr => "map_new_ptr",
+            AirRuntimeHelper::MapHashPtr => "map_hash_ptr",
+            AirRuntimeHelper::MapFindPtr => "map_find_ptr",
+            AirRuntimeHelper::MapInsertPtr => "map_insert_ptr",
+            AirRuntimeHelper::MapGrowPtr => "map_grow_ptr",
+            AirRuntimeHelper::MapReindexPtr => "map_reindex_ptr",
+            AirRuntimeHelper::MapRemovePtr => "map_remove_ptr",
+            AirRuntimeHelper::MapReleasePtr => "map_release_ptr",
+            AirRuntimeHelper::MapCopyPtr => "map_copy_ptr",
+            AirRuntimeHelper::QueuePushPtr => "queue_push_ptr",
+            AirRuntimeHelper::QueuePopPtr => "queue_pop_ptr",
+            AirRuntimeHelper::SchedNextPtr => "sched_next_ptr",
+            AirRuntimeHelper::SchedReadyPtr => "sched_ready_ptr",
+            AirRuntimeHelper::ThreadNewPtr => "thread_new_ptr",
+            AirRuntimeHelper::ChanNewPtr => "chan_new_ptr",
+            AirRuntimeHelper::ChanSendPtr => "chan_send_ptr",
+            AirRuntimeHelper::ChanRecvPtr => "chan_recv_ptr",
+            AirRuntimeHelper::ChanReleasePtr => "chan_release_ptr",
+            AirRuntimeHelper::ChanCopyPtr => "chan_copy_ptr",
         }
     }
 }
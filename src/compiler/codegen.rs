@@ -1,19 +1,27 @@
 use crate::compiler::air;
 use crate::compiler::air::{
-    AirAdd, AirAddF64, AirArg, AirCallPtr, AirCallPtrTarget, AirDivF64, AirDivInt, AirField,
-    AirFunction, AirJump, AirJumpArgs, AirJumpClosure, AirJumpEq, AirJumpGt, AirJumpLt, AirLabel,
-    AirMul, AirMulF64, AirNewClosure, AirOp, AirPin, AirReturn, AirStmt, AirSub, AirSysExit,
-    AirValue, Lit, SigKind,
+    AirAdd, AirAddF64, AirArg, AirArgAt, AirArgCount, AirArrayGet, AirArrayLen, AirArraySet,
+    AirByteAt, AirCallPtr, AirCallPtrTarget, AirChanRecv, AirChanSend, AirChecked, AirClose,
+    AirConcat, AirDivF64, AirDivInt, AirF64ToInt, AirField, AirFunction, AirGetenv, AirIntToF64,
+    AirJump, AirJumpArgs, AirJumpClosure, AirJumpCmpF64, AirJumpCmpStr, AirJumpEq, AirJumpGt,
+    AirJumpLt, AirJumpTable, AirLabel, AirMapInsert, AirMapLen, AirMapLookup, AirMapNext,
+    AirMapRemove, AirMul, AirMulF64, AirNeg, AirNegF64, AirNewArray, AirNewChan, AirNewClosure,
+    AirNewMap, AirOp, AirOpen, AirPin, AirRead, AirReturn, AirSlice, AirSpawn, AirSqrtF64, AirStmt,
+    AirStrLen, AirSub, AirSubF64, AirSysExit, AirThread, AirValue, AirYield, Lit, SigKind,
+    ENTRY_FUNCTION_NAME,
 };
 use crate::compiler::builtins;
 use crate::compiler::builtins::AirRuntimeHelper;
 use crate::compiler::error::{Code, Error};
 use crate::compiler::runtime;
 use crate::compiler::span::Span;
+use crate::compiler::target::{
+    copy_helper, emit_runtime_helper_once, emit_runtime_helpers, ReleaseCopy, Runtime,
+};
 use std::collections::{HashMap, HashSet};
 use std::io::Write;
 
-const WORD_SIZE: usize = 8;
+pub const WORD_SIZE: usize = 8;
 pub const ENV_METADATA_UNWRAPPER_OFFSET: usize = 0;
 pub const ENV_METADATA_RELEASE_OFFSET: usize = WORD_SIZE;
 pub const ENV_METADATA_DEEP_COPY_OFFSET: usize = WORD_SIZE * 2;
@@ -23,20 +31,231 @@ pub const ENV_METADATA_NUM_REMAINING_OFFSET: usize = WORD_SIZE * 5;
 pub const ENV_METADATA_SIZE: usize = WORD_SIZE * 6;
 pub const CLOSURE_ENV_REG: &str = "r12";
 pub const ARG_REGS: [&str; 6] = ["rdi", "rsi", "rdx", "rcx", "r8", "r9"];
+/// Vector registers a variadic call can take f64 arguments in.
+pub const VARIADIC_FLOAT_REGS: usize = 8;
+/// The sign bit of an f64, which is also the int `cvttsd2si` gives up with.
+pub const F64_SIGN_BIT: u64 = 1 << 63;
+pub const SYSCALL_WRITE: i32 = 1;
 pub const SYSCALL_MMAP: i32 = 9;
 pub const SYSCALL_MUNMAP: i32 = 11;
 pub const SYSCALL_EXIT: i32 = 60;
+pub const SYSCALL_SCHED_YIELD: i32 = 24;
+pub const SYSCALL_FUTEX: i32 = 202;
+pub const FUTEX_WAIT_PRIVATE: i32 = 128;
+pub const FUTEX_WAKE_PRIVATE: i32 = 129;
 pub const PROT_READ: i32 = 1;
 pub const PROT_WRITE: i32 = 2;
 pub const MAP_PRIVATE: i32 = 2;
 pub const MAP_ANONYMOUS: i32 = 32;
-pub const FMT_BUFFER_SIZE: usize = 1024;
+/// Blocks up to this many bytes are carved out of shared arenas and recycled
+/// through a free list per size; larger ones get a mapping of their own.
+pub const HEAP_MAX_SMALL_SIZE: usize = 1024;
+/// Bytes mapped at a time for the arena small blocks are carved out of.
+pub const HEAP_ARENA_SIZE: usize = 1 << 20;
+/// Heads of the free lists, one word per multiple of `WORD_SIZE` up to
+/// `HEAP_MAX_SMALL_SIZE`, so a block's rounded size is its head's offset.
+pub const HEAP_FREE_LISTS_LABEL: &str = "heap_free_lists";
+/// Next free byte of the current arena, followed by the word holding its end.
+pub const HEAP_ARENA_LABEL: &str = "heap_arena";
+/// The word threads spin on to take turns in the allocator, when the program
+/// starts any.
+pub const HEAP_LOCK_LABEL: &str = "heap_lock";
+/// How many times a thread checks a held lock before it gives the CPU to the
+/// thread holding it.
+pub const LOCK_SPINS: i32 = 100;
+/// A `str` points at its first byte, behind a header holding the size of its
+/// allocation (0 for literals, which are never freed) and then its length in
+/// bytes. A NUL still follows the bytes so libc can read them as `%s`.
+pub const STR_HEADER_SIZE: usize = WORD_SIZE * 2;
+/// Distance back from a string pointer to its allocation size.
+pub const STR_ALLOC_SIZE_OFFSET: usize = WORD_SIZE * 2;
+/// Distance back from a string pointer to its length.
+pub const STR_LEN_OFFSET: usize = WORD_SIZE;
+/// An array points at its first element, one word each, behind a header
+/// holding the class of its elements and then its length.
+pub const ARRAY_HEADER_SIZE: usize = WORD_SIZE * 2;
+/// Distance back from an array pointer to the class of its elements.
+pub const ARRAY_CLASS_OFFSET: usize = WORD_SIZE * 2;
+/// Distance back from an array pointer to its length.
+pub const ARRAY_LEN_OFFSET: usize = WORD_SIZE;
+/// Elements that own nothing, such as ints, bytes and floats.
+pub const ARRAY_CLASS_PLAIN: i64 = 0;
+/// Elements that are strings, released and copied with the array.
+pub const ARRAY_CLASS_STR: i64 = 1;
+/// Elements that are arrays themselves.
+pub const ARRAY_CLASS_ARRAY: i64 = 2;
+/// A map points at its entries, a key word and a value word each in
+/// insertion order, behind a header holding the classes of its keys and
+/// values, its capacity and its number of entries. Keys and values use the
+/// array element classes. The entries have room for half the capacity and are
+/// followed by the index: one word per slot holding an entry's position plus
+/// one, or 0 for an empty slot, probed linearly from the key's hash.
+pub const MAP_HEADER_SIZE: usize = WORD_SIZE * 4;
+/// Distance back from a map pointer to the class of its keys.
+pub const MAP_KEY_CLASS_OFFSET: usize = WORD_SIZE * 4;
+/// Distance back from a map pointer to the class of its values.
+pub const MAP_VALUE_CLASS_OFFSET: usize = WORD_SIZE * 3;
+/// Distance back from a map pointer to the number of index slots, a power of two.
+pub const MAP_CAPACITY_OFFSET: usize = WORD_SIZE * 2;
+/// Distance back from a map pointer to its number of entries.
+pub const MAP_COUNT_OFFSET: usize = WORD_SIZE;
+/// Bytes per entry: the key and then the value.
+pub const MAP_ENTRY_SIZE: usize = WORD_SIZE * 2;
+/// Index slots of a new map.
+pub const MAP_INITIAL_CAPACITY: usize = 8;
+/// Multiplier mixing int keys before they pick a slot: 2^64 over the golden ratio.
+pub const MAP_INT_HASH_MULTIPLIER: u64 = 0x9e37_79b9_7f4a_7c15;
+/// Start and multiplier of the 64-bit FNV-1a hash of str keys.
+pub const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+pub const FNV_PRIME: u64 = 0x100_0000_01b3;
+
+/// Bytes in the block of a map with `capacity` index slots: the header, room
+/// for half as many entries and the index.
+pub fn map_block_size(capacity: usize) -> usize {
+    MAP_HEADER_SIZE + map_index_offset(capacity) + capacity * WORD_SIZE
+}
+
+/// Distance from a map pointer to its index, past room for `capacity / 2` entries.
+pub fn map_index_offset(capacity: usize) -> usize {
+    capacity / 2 * MAP_ENTRY_SIZE
+}
+/// libc function returning the address of the calling thread's errno.
+pub const ERRNO_LOCATION: &str = "__errno_location";
+/// The errno `@read` reports for a negative size, as `read(2)` would for a
+/// bad argument.
+pub const EINVAL: i64 = 22;
+/// The errno `@read` reports when there is no memory for its buffer.
+pub const ENOMEM: i64 = 12;
+/// The argc and argv pointer `_start` finds on its stack, saved for `@argc`
+/// and `@arg`.
+pub const PROCESS_ARGS_LABEL: &str = "process_args";
+
+/// Bytes in a channel block: its reference count, the class of the values it
+/// carries, and the head and tail of its queue of values and of receivers.
+pub const CHAN_SIZE: usize = WORD_SIZE * 6;
+/// Where a channel keeps its reference count.
+pub const CHAN_REFS_OFFSET: usize = 0;
+/// Where a channel keeps the class of its values.
+pub const CHAN_CLASS_OFFSET: usize = WORD_SIZE;
+/// Where a channel's queue of values sent but not yet received starts.
+pub const CHAN_VALUES_OFFSET: usize = WORD_SIZE * 2;
+/// Where a channel's queue of parked receivers starts.
+pub const CHAN_RECEIVERS_OFFSET: usize = WORD_SIZE * 4;
+/// The channel class of values that own an environment, which release
+/// themselves through its metadata.
+pub const CHAN_CLASS_ENV: i64 = 3;
+/// Bytes in a queue node: the next node and the word it holds.
+pub const QUEUE_NODE_SIZE: usize = WORD_SIZE * 2;
+/// The run queue of coroutines ready to continue, followed by the number of
+/// coroutines parked on a channel, the number of threads running coroutines,
+/// how many of those have nothing to run, and the counter they sleep on.
+pub const SCHED_STATE_LABEL: &str = "sched_state";
+/// Where the scheduler keeps the number of parked coroutines.
+pub const SCHED_WAITING_OFFSET: usize = WORD_SIZE * 2;
+/// Where the scheduler counts the threads running coroutines, the main one
+/// included.
+pub const SCHED_WORKERS_OFFSET: usize = WORD_SIZE * 3;
+/// Where the scheduler counts the threads with nothing to run.
+pub const SCHED_IDLE_OFFSET: usize = WORD_SIZE * 4;
+/// Where the futex word idle threads sleep on lives. It changes whenever a
+/// coroutine becomes runnable.
+pub const SCHED_WAKEUPS_OFFSET: usize = WORD_SIZE * 5;
+/// The word threads spin on to take turns with the run queue and channels,
+/// when the program starts any.
+pub const SCHED_LOCK_LABEL: &str = "sched_lock";
+/// What a program prints when nothing can run but some coroutine still waits.
+pub const DEADLOCK_MESSAGE: &str = "deadlock: every coroutine is waiting on a channel\n";
+
+/// The class stored in the header of an array holding `elem`s.
+pub fn array_elem_class(elem: &SigKind) -> i64 {
+    match elem {
+        SigKind::Str => ARRAY_CLASS_STR,
+        SigKind::Array(_) => ARRAY_CLASS_ARRAY,
+        _ => ARRAY_CLASS_PLAIN,
+    }
+}
+
+/// The class stored in a channel carrying `elem`s.
+pub fn chan_elem_class(elem: &SigKind) -> i64 {
+    match elem {
+        SigKind::Sig(_) | SigKind::Record { .. } | SigKind::Sum { .. } => CHAN_CLASS_ENV,
+        _ => array_elem_class(elem),
+    }
+}
+
+/// Runtime helpers every map op may end up in, directly or through another
+/// helper.
+pub(crate) const MAP_HELPERS: [AirRuntimeHelper; 10] = [
+    AirRuntimeHelper::CopyArrayElemPtr,
+    AirRuntimeHelper::MapNewPtr,
+    AirRuntimeHelper::MapHashPtr,
+    AirRuntimeHelper::MapFindPtr,
+    AirRuntimeHelper::MapInsertPtr,
+    AirRuntimeHelper::MapGrowPtr,
+    AirRuntimeHelper::MapReindexPtr,
+    AirRuntimeHelper::MapRemovePtr,
+    AirRuntimeHelper::MapReleasePtr,
+    AirRuntimeHelper::MapCopyPtr,
+];
+
+/// Whether `op` creates, uses or disposes of a map.
+pub fn is_map_op(op: &AirOp) -> bool {
+    match op {
+        AirOp::NewMap(_)
+        | AirOp::ReleaseMap(_)
+        | AirOp::CopyMap(_)
+        | AirOp::MapInsert(_)
+        | AirOp::MapLookup(_)
+        | AirOp::MapRemove(_)
+        | AirOp::MapLen(_)
+        | AirOp::MapNext(_) => true,
+        AirOp::CopyField(field) => matches!(field.kind, SigKind::Map(_, _)),
+        _ => false,
+    }
+}
+
+/// Runtime helpers every channel or scheduler op may end up in, directly or
+/// through another helper.
+pub(crate) const CHAN_HELPERS: [AirRuntimeHelper; 9] = [
+    AirRuntimeHelper::QueuePushPtr,
+    AirRuntimeHelper::QueuePopPtr,
+    AirRuntimeHelper::SchedNextPtr,
+    AirRuntimeHelper::SchedReadyPtr,
+    AirRuntimeHelper::ChanNewPtr,
+    AirRuntimeHelper::ChanSendPtr,
+    AirRuntimeHelper::ChanRecvPtr,
+    AirRuntimeHelper::ChanReleasePtr,
+    AirRuntimeHelper::ChanCopyPtr,
+];
+
+/// Whether `op` creates, uses or disposes of a channel, or hands control to
+/// the scheduler.
+pub fn is_chan_op(op: &AirOp) -> bool {
+    match op {
+        AirOp::NewChan(_)
+        | AirOp::ReleaseChan(_)
+        | AirOp::CopyChan(_)
+        | AirOp::ChanSend(_)
+        | AirOp::ChanRecv(_)
+        | AirOp::Spawn(_)
+        | AirOp::Thread(_)
+        | AirOp::Yield(_)
+        | AirOp::Done(_) => true,
+        AirOp::CopyField(field) => matches!(field.kind, SigKind::Chan(_)),
+        _ => false,
+    }
+}
 
 #[derive(Debug, Default)]
 pub struct Artifacts {
     string_literals: Vec<(String, String)>,
     pub externs: HashSet<String>,
-    builtins_used: HashSet<String>,
+    pub(crate) builtins_used: HashSet<String>,
+    /// Whether `_start` has to save argc and argv for `@argc` or `@arg`.
+    pub(crate) uses_process_args: bool,
+    /// Whether `@thread` is used, so the allocator and scheduler have to lock
+    /// what threads share.
+    pub(crate) uses_threads: bool,
 }
 
 impl Artifacts {
@@ -57,11 +276,36 @@ impl Artifacts {
                 self.externs.insert("printf".to_string());
             }
             Some(AirOp::Sprintf(_)) => {
-                self.externs.insert("sprintf".to_string());
+                self.externs.insert("snprintf".to_string());
             }
-            Some(AirOp::Write(_)) => {
+            Some(AirOp::Write(_)) | Some(AirOp::WriteFd(_)) => {
                 self.externs.insert("write".to_string());
             }
+            Some(AirOp::Read(_)) => {
+                self.externs.insert("read".to_string());
+                self.externs.insert(ERRNO_LOCATION.to_string());
+                self.externs
+                    .insert(AirRuntimeHelper::AllocStrPtr.name().to_string());
+            }
+            Some(AirOp::Open(_)) => {
+                self.externs.insert("open".to_string());
+                self.externs.insert(ERRNO_LOCATION.to_string());
+            }
+            Some(AirOp::Close(_)) => {
+                self.externs.insert("close".to_string());
+                self.externs.insert(ERRNO_LOCATION.to_string());
+            }
+            Some(AirOp::ArgCount(_)) => self.uses_process_args = true,
+            Some(AirOp::ArgAt(_)) => {
+                self.uses_process_args = true;
+                self.externs
+                    .insert(AirRuntimeHelper::CStrToStrPtr.name().to_string());
+            }
+            Some(AirOp::Getenv(_)) => {
+                self.externs.insert("getenv".to_string());
+                self.externs
+                    .insert(AirRuntimeHelper::CStrToStrPtr.name().to_string());
+            }
             Some(AirOp::SysExit(_)) => {
                 // Call libc exit instead of raw syscall to ensure proper cleanup and flushing
                 self.externs.insert("exit".to_string());
@@ -74,10 +318,80 @@ impl Artifacts {
                 self.externs
                     .insert(AirRuntimeHelper::ReleaseHeapPtr.name().to_string());
             }
+            Some(AirOp::CopyField(field)) if matches!(field.kind, SigKind::Str) => {
+                self.externs
+                    .insert(AirRuntimeHelper::CopyStrPtr.name().to_string());
+            }
+            Some(AirOp::CopyField(field)) if matches!(field.kind, SigKind::Array(_)) => {
+                self.externs
+                    .insert(AirRuntimeHelper::CopyArrayPtr.name().to_string());
+            }
+            Some(AirOp::CopyField(field)) if matches!(field.kind, SigKind::Map(_, _)) => {
+                self.externs
+                    .insert(AirRuntimeHelper::MapCopyPtr.name().to_string());
+            }
             Some(AirOp::CopyField(_)) => {
                 self.externs
                     .insert(AirRuntimeHelper::DeepCopyHeapPtr.name().to_string());
             }
+            Some(AirOp::ReleaseStr(_)) => {
+                self.externs
+                    .insert(AirRuntimeHelper::ReleaseStrPtr.name().to_string());
+            }
+            Some(AirOp::CopyStr(_)) => {
+                self.externs
+                    .insert(AirRuntimeHelper::CopyStrPtr.name().to_string());
+            }
+            Some(AirOp::NewClosure(_))
+            | Some(AirOp::CloneClosure(_))
+            | Some(AirOp::NewArray(_)) => {
+                self.externs
+                    .insert(AirRuntimeHelper::AllocHeapPtr.name().to_string());
+            }
+            Some(AirOp::ReleaseArray(_)) | Some(AirOp::ArrayLen(_)) | Some(AirOp::ArrayGet(_)) => {
+                self.externs
+                    .insert(AirRuntimeHelper::ReleaseArrayPtr.name().to_string());
+            }
+            Some(AirOp::ArraySet(_)) => {
+                self.externs
+                    .insert(AirRuntimeHelper::ReleaseArrayPtr.name().to_string());
+                self.externs
+                    .insert(AirRuntimeHelper::ReleaseArrayElemPtr.name().to_string());
+            }
+            Some(AirOp::CopyArray(_)) => {
+                self.externs
+                    .insert(AirRuntimeHelper::CopyArrayPtr.name().to_string());
+            }
+            Some(AirOp::Concat(_)) => {
+                self.externs
+                    .insert(AirRuntimeHelper::ConcatStrPtr.name().to_string());
+            }
+            Some(AirOp::Slice(_)) => {
+                self.externs
+                    .insert(AirRuntimeHelper::SliceStrPtr.name().to_string());
+            }
+            Some(AirOp::JumpCmpStr(_)) => {
+                self.externs
+                    .insert(AirRuntimeHelper::CmpStrPtr.name().to_string());
+            }
+            Some(op) if is_map_op(op) => {
+                for helper in MAP_HELPERS {
+                    self.externs.insert(helper.name().to_string());
+                }
+            }
+            Some(op) if is_chan_op(op) => {
+                for helper in CHAN_HELPERS {
+                    self.externs.insert(helper.name().to_string());
+                }
+                // The scheduler exits through libc once nothing is left to run.
+                self.externs.insert("exit".to_string());
+                if matches!(op, AirOp::Thread(_)) {
+                    self.uses_threads = true;
+                    self.externs
+                        .insert(AirRuntimeHelper::ThreadNewPtr.name().to_string());
+                    self.externs.insert("pthread_create".to_string());
+                }
+            }
             _ => {}
         }
     }
@@ -113,15 +427,34 @@ impl Artifacts {
             AirOp::JumpEqInt(eq) | AirOp::JumpEqStr(eq) => {
                 self.collect_literals_in_args(&eq.args);
             }
+            AirOp::JumpCmpStr(cmp) => self.collect_literals_in_args(&cmp.args),
+            AirOp::StrLen(op) => self.collect_literals_in_args(std::slice::from_ref(&op.input)),
+            AirOp::Concat(op) => self.collect_literals_in_binary_inputs(&op.input_a, &op.input_b),
+            AirOp::Slice(op) => self.collect_literals_in_args(std::slice::from_ref(&op.input)),
+            AirOp::ByteAt(op) => self.collect_literals_in_args(std::slice::from_ref(&op.input)),
+            AirOp::NewArray(array) => self.collect_literals_in_args(&array.items),
+            AirOp::ArraySet(op) => self.collect_literals_in_args(std::slice::from_ref(&op.value)),
+            AirOp::NewMap(map) => {
+                for (key, value) in &map.entries {
+                    self.collect_literals_in_binary_inputs(key, value);
+                }
+            }
+            AirOp::MapInsert(op) => self.collect_literals_in_binary_inputs(&op.key, &op.value),
+            AirOp::MapLookup(op) => self.collect_literals_in_args(std::slice::from_ref(&op.key)),
+            AirOp::MapRemove(op) => self.collect_literals_in_args(std::slice::from_ref(&op.key)),
             AirOp::Add(op) => self.collect_literals_in_binary_inputs(&op.input_a, &op.input_b),
             AirOp::Sub(op) => self.collect_literals_in_binary_inputs(&op.input_a, &op.input_b),
             AirOp::Mul(op) => self.collect_literals_in_binary_inputs(&op.input_a, &op.input_b),
             AirOp::DivInt(op) => self.collect_literals_in_binary_inputs(&op.input_a, &op.input_b),
+            AirOp::RemInt(op) => self.collect_literals_in_binary_inputs(&op.input_a, &op.input_b),
             AirOp::Printf(call) => self.collect_literals_in_args(&call.args),
             AirOp::Sprintf(call) => self.collect_literals_in_args(&call.args),
-            AirOp::Write(call) => self.collect_literals_in_args(&call.args),
+            AirOp::Write(call) | AirOp::WriteFd(call) => self.collect_literals_in_args(&call.args),
+            AirOp::Open(op) => self.collect_literals_in_args(std::slice::from_ref(&op.path)),
+            AirOp::Getenv(op) => self.collect_literals_in_args(std::slice::from_ref(&op.name)),
             AirOp::JumpArgs(call) => self.collect_literals_in_args(&call.args),
             AirOp::SysExit(syscall) => self.collect_literals_in_args(&syscall.args),
+            AirOp::ChanSend(op) => self.collect_literals_in_args(std::slice::from_ref(&op.value)),
             _ => {}
         }
     }
@@ -155,58 +488,71 @@ pub fn function<W: Write>(
     artifacts: &mut Artifacts,
     out: &mut W,
 ) -> Result<(), Error> {
-    emit_runtime_helpers(&air, artifacts, out)?;
+    emit_runtime_helpers::<Backend, W>(&air, artifacts, out)?;
     if !artifacts.builtins_used.insert(air.sig.name.clone()) {
         return Ok(());
     }
     if runtime::emit_builtin_function(&air, out)? {
         return Ok(());
     }
+    let save_process_args = air.sig.name == ENTRY_FUNCTION_NAME && artifacts.uses_process_args;
+    if save_process_args {
+        emit_runtime_helper_once::<Backend, W>(AirRuntimeHelper::ProcessArgs, artifacts, out)?;
+    }
     let frame = FrameLayout::build(&air)?;
     let mut emitter = FunctionEmitter::new(air.clone(), out, frame);
+    emitter.save_process_args = save_process_args;
     emitter.emit_function()?;
     Ok(())
 }
 
-fn emit_runtime_helpers<W: Write>(
-    air: &AirFunction,
-    artifacts: &mut Artifacts,
-    out: &mut W,
-) -> Result<(), Error> {
-    let mut needs_release = false;
-    let mut needs_deepcopy = false;
-    for stmt in &air.items {
-        match stmt.as_op() {
-            Some(AirOp::ReleaseHeap(_)) => needs_release = true,
-            Some(AirOp::CopyField(_)) => needs_deepcopy = true,
-            Some(AirOp::CallPtr(_)) => needs_release = true,
-            _ => {}
-        }
-    }
-
-    if needs_release {
-        emit_runtime_helper_once(AirRuntimeHelper::ReleaseHeapPtr, artifacts, out)?;
-    }
-    if needs_deepcopy {
-        emit_runtime_helper_once(AirRuntimeHelper::DeepCopyHeapPtr, artifacts, out)?;
-        emit_runtime_helper_once(AirRuntimeHelper::MemcpyHelper, artifacts, out)?;
-    }
-    Ok(())
-}
+/// Writes the runtime helpers in x86-64 assembly.
+pub struct Backend;
 
-fn emit_runtime_helper_once<W: Write>(
-    helper: AirRuntimeHelper,
-    artifacts: &mut Artifacts,
-    out: &mut W,
-) -> Result<(), Error> {
-    if !artifacts.builtins_used.insert(helper.name().to_string()) {
-        return Ok(());
-    }
-    artifacts.externs.remove(helper.name());
-    match helper {
-        AirRuntimeHelper::ReleaseHeapPtr => runtime::emit_release_heap_ptr(out),
-        AirRuntimeHelper::DeepCopyHeapPtr => runtime::emit_deepcopy_heap_ptr(out),
-        AirRuntimeHelper::MemcpyHelper => runtime::emit_memcpy_helper(out),
+impl Runtime for Backend {
+    fn emit_helper<W: Write>(
+        helper: AirRuntimeHelper,
+        threaded: bool,
+        out: &mut W,
+    ) -> Result<(), Error> {
+        match helper {
+            AirRuntimeHelper::AllocHeapPtr => runtime::emit_alloc_heap_ptr(out, threaded),
+            AirRuntimeHelper::FreeHeapPtr => runtime::emit_free_heap_ptr(out, threaded),
+            AirRuntimeHelper::ReleaseHeapPtr => runtime::emit_release_heap_ptr(out),
+            AirRuntimeHelper::DeepCopyHeapPtr => runtime::emit_deepcopy_heap_ptr(out),
+            AirRuntimeHelper::MemcpyHelper => runtime::emit_memcpy_helper(out),
+            AirRuntimeHelper::ReleaseStrPtr => runtime::emit_release_str_ptr(out),
+            AirRuntimeHelper::CopyStrPtr => runtime::emit_copy_str_ptr(out),
+            AirRuntimeHelper::AllocStrPtr => runtime::emit_alloc_str_ptr(out),
+            AirRuntimeHelper::ConcatStrPtr => runtime::emit_concat_str_ptr(out),
+            AirRuntimeHelper::SliceStrPtr => runtime::emit_slice_str_ptr(out),
+            AirRuntimeHelper::CmpStrPtr => runtime::emit_cmp_str_ptr(out),
+            AirRuntimeHelper::CStrToStrPtr => runtime::emit_cstr_to_str_ptr(out),
+            AirRuntimeHelper::ProcessArgs => runtime::emit_process_args(out),
+            AirRuntimeHelper::ReleaseArrayPtr => runtime::emit_release_array_ptr(out),
+            AirRuntimeHelper::ReleaseArrayElemPtr => runtime::emit_release_array_elem_ptr(out),
+            AirRuntimeHelper::CopyArrayPtr => runtime::emit_copy_array_ptr(out),
+            AirRuntimeHelper::CopyArrayElemPtr => runtime::emit_copy_array_elem_ptr(out),
+            AirRuntimeHelper::MapNewPtr => runtime::emit_map_new_ptr(out),
+            AirRuntimeHelper::MapHashPtr => runtime::emit_map_hash_ptr(out),
+            AirRuntimeHelper::MapFindPtr => runtime::emit_map_find_ptr(out),
+            AirRuntimeHelper::MapInsertPtr => runtime::emit_map_insert_ptr(out),
+            AirRuntimeHelper::MapGrowPtr => runtime::emit_map_grow_ptr(out),
+            AirRuntimeHelper::MapReindexPtr => runtime::emit_map_reindex_ptr(out),
+            AirRuntimeHelper::MapRemovePtr => runtime::emit_map_remove_ptr(out),
+            AirRuntimeHelper::MapReleasePtr => runtime::emit_map_release_ptr(out),
+            AirRuntimeHelper::MapCopyPtr => runtime::emit_map_copy_ptr(out),
+            AirRuntimeHelper::QueuePushPtr => runtime::emit_queue_push_ptr(out),
+            AirRuntimeHelper::QueuePopPtr => runtime::emit_queue_pop_ptr(out),
+            AirRuntimeHelper::SchedNextPtr => runtime::emit_sched_next_ptr(out, threaded),
+            AirRuntimeHelper::SchedReadyPtr => runtime::emit_sched_ready_ptr(out, threaded),
+            AirRuntimeHelper::ThreadNewPtr => runtime::emit_thread_new_ptr(out),
+            AirRuntimeHelper::ChanNewPtr => runtime::emit_chan_new_ptr(out),
+            AirRuntimeHelper::ChanSendPtr => runtime::emit_chan_send_ptr(out, threaded),
+            AirRuntimeHelper::ChanRecvPtr => runtime::emit_chan_recv_ptr(out, threaded),
+            AirRuntimeHelper::ChanReleasePtr => runtime::emit_chan_release_ptr(out, threaded),
+            AirRuntimeHelper::ChanCopyPtr => runtime::emit_chan_copy_ptr(out, threaded),
+        }
     }
 }
 
@@ -228,6 +574,11 @@ pub fn emit_data<W: Write>(string_literals: &[(String, String)], out: &mut W) ->
     }
     writeln!(out, "section .rodata")?;
     for (label, literal) in string_literals {
+        writeln!(
+            out,
+            "    dq 0, {} ; static strings have no allocation to free",
+            literal.len()
+        )?;
         writeln!(out, "{}:", label)?;
         let escaped = crate::escape_literal_for_rodata(literal);
         writeln!(out, "    db {}, 0", escaped)?;
@@ -297,13 +648,18 @@ fn air_statement_binding_info(stmt: &AirStmt) -> Option<&str> {
         Some(AirOp::CloneClosure(s)) => Some(&s.dst),
         Some(AirOp::Field(field)) => Some(field.result.as_str()),
         Some(AirOp::CopyField(field)) => Some(field.result.as_str()),
+        Some(AirOp::CopyStr(copy)) => Some(copy.dst.as_str()),
+        Some(AirOp::NewArray(array)) => Some(array.name.as_str()),
+        Some(AirOp::CopyArray(copy)) => Some(copy.dst.as_str()),
+        Some(AirOp::NewMap(map)) => Some(map.name.as_str()),
+        Some(AirOp::CopyMap(copy)) => Some(copy.dst.as_str()),
+        Some(AirOp::CopyChan(copy)) => Some(copy.dst.as_str()),
         _ => None,
     }
 }
 
 #[derive(Clone, Copy, Debug)]
 struct ArgSplit {
-    reg_slots: usize,
     stack_bytes: usize,
 }
 
@@ -312,8 +668,8 @@ struct FunctionEmitter<'a, W: Write> {
     out: &'a mut W,
     frame: FrameLayout,
     terminated: bool,
-    write_loop_counter: usize,
     label_counter: usize,
+    save_process_args: bool,
 }
 
 impl<'a, W: Write> FunctionEmitter<'a, W> {
@@ -323,8 +679,8 @@ impl<'a, W: Write> FunctionEmitter<'a, W> {
             out,
             frame,
             terminated: false,
-            write_loop_counter: 0,
             label_counter: 0,
+            save_process_args: false,
         }
     }
 
@@ -340,11 +696,24 @@ impl<'a, W: Write> FunctionEmitter<'a, W> {
                 self.frame.stack_size
             )?;
         }
+        if self.save_process_args {
+            self.emit_save_process_args()?;
+        }
         self.store_params()?;
         self.emit_block()?;
         Ok(())
     }
 
+    /// Saves the argc and argv the kernel left above `_start`'s return slot.
+    fn emit_save_process_args(&mut self) -> Result<(), Error> {
+        writeln!(self.out, "    lea rcx, [{}]", PROCESS_ARGS_LABEL)?;
+        writeln!(self.out, "    mov rax, [rbp+8] ; argc")?;
+        writeln!(self.out, "    mov [rcx], rax")?;
+        writeln!(self.out, "    lea rax, [rbp+16] ; argv")?;
+        writeln!(self.out, "    mov [rcx+8], rax")?;
+        Ok(())
+    }
+
     fn emit_pin(&mut self, pin: &AirPin) -> Result<(), Error> {
         self.load_value_into_reg(&pin.value, CLOSURE_ENV_REG)?;
         Ok(())
@@ -432,7 +801,34 @@ impl<'a, W: Write> FunctionEmitter<'a, W> {
             AirOp::JumpEqInt(eq) => self.emit_eq_int_jump(eq),
             AirOp::JumpEqStr(eq) => self.emit_eq_str_jump(eq),
             AirOp::JumpLt(jump) => self.emit_lt_jump(jump),
+            AirOp::JumpCmpStr(cmp) => self.emit_cmp_str_jump(cmp),
             AirOp::ReleaseHeap(release) => self.emit_release_heap_ptr(&release.name),
+            AirOp::ReleaseStr(release) => self.emit_release_str_ptr(&release.name),
+            AirOp::CopyStr(copy) => self.emit_copy_str(copy),
+            AirOp::NewArray(array) => self.emit_new_array(array),
+            AirOp::ReleaseArray(release) => self.emit_release_array_ptr(&release.name),
+            AirOp::CopyArray(copy) => self.emit_copy_array(copy),
+            AirOp::ArrayLen(op) => self.emit_array_len(op),
+            AirOp::ArrayGet(op) => self.emit_array_get(op),
+            AirOp::ArraySet(op) => self.emit_array_set(op),
+            AirOp::NewMap(map) => self.emit_new_map(map),
+            AirOp::ReleaseMap(release) => self.emit_release_map_ptr(&release.name),
+            AirOp::CopyMap(copy) => self.emit_copy_map(copy),
+            AirOp::ReleaseSum(release) => self.emit_release_closure(&release.name),
+            AirOp::MapInsert(op) => self.emit_map_insert(op),
+            AirOp::MapLookup(op) => self.emit_map_lookup(op),
+            AirOp::MapRemove(op) => self.emit_map_remove(op),
+            AirOp::MapLen(op) => self.emit_map_len(op),
+            AirOp::MapNext(op) => self.emit_map_next(op),
+            AirOp::ReleaseChan(release) => self.emit_release_chan_ptr(&release.name),
+            AirOp::CopyChan(copy) => self.emit_copy_chan(copy),
+            AirOp::NewChan(op) => self.emit_new_chan(op),
+            AirOp::ChanSend(op) => self.emit_chan_send(op),
+            AirOp::ChanRecv(op) => self.emit_chan_recv(op),
+            AirOp::Spawn(op) => self.emit_spawn(op),
+            AirOp::Thread(op) => self.emit_thread(op),
+            AirOp::Yield(op) => self.emit_yield(op),
+            AirOp::Done(_) => self.emit_sched_next(),
             AirOp::Pin(pin) => self.emit_pin(pin),
             AirOp::Field(field) => self.emit_get_field(field),
             AirOp::SetField(set) => self.emit_set_field(set),
@@ -440,29 +836,82 @@ impl<'a, W: Write> FunctionEmitter<'a, W> {
             AirOp::Add(op) => self.emit_add(op),
             AirOp::Sub(op) => self.emit_sub(op),
             AirOp::Mul(op) => self.emit_mul(op),
-            AirOp::DivInt(op) => self.emit_div_int(op),
+            AirOp::DivInt(op) => self.emit_division(op, false),
+            AirOp::RemInt(op) => self.emit_division(op, true),
+            AirOp::And(op) => {
+                self.emit_binary_op(&op.input_a, &op.input_b, &op.target, "and", "bitwise and")
+            }
+            AirOp::Or(op) => {
+                self.emit_binary_op(&op.input_a, &op.input_b, &op.target, "or", "bitwise or")
+            }
+            AirOp::Xor(op) => {
+                self.emit_binary_op(&op.input_a, &op.input_b, &op.target, "xor", "bitwise xor")
+            }
+            AirOp::Shl(op) => {
+                self.emit_binary_op(&op.input_a, &op.input_b, &op.target, "shl", "shift left")
+            }
+            AirOp::Shr(op) => self.emit_binary_op(
+                &op.input_a,
+                &op.input_b,
+                &op.target,
+                "sar",
+                "shift right keeping the sign",
+            ),
+            AirOp::Neg(op) => self.emit_neg(op),
+            AirOp::AddChecked(op) => self.emit_checked_op(op, "add", "add second integer"),
+            AirOp::SubChecked(op) => self.emit_checked_op(op, "sub", "subtract subtrahend"),
+            AirOp::MulChecked(op) => self.emit_checked_op(op, "imul", "multiply by multiplier"),
             AirOp::AddF64(op) => self.emit_add_f64(op),
+            AirOp::SubF64(op) => self.emit_sub_f64(op),
             AirOp::MulF64(op) => self.emit_mul_f64(op),
             AirOp::DivF64(op) => self.emit_div_f64(op),
+            AirOp::NegF64(op) => self.emit_neg_f64(op),
+            AirOp::SqrtF64(op) => self.emit_sqrt_f64(op),
+            AirOp::IntToF64(op) => self.emit_int_to_f64(op),
+            AirOp::F64ToInt(op) => self.emit_f64_to_int(op),
+            AirOp::StrLen(op) => self.emit_strlen(op),
+            AirOp::Concat(op) => self.emit_concat(op),
+            AirOp::Slice(op) => self.emit_slice(op),
+            AirOp::ByteAt(op) => self.emit_byte_at(op),
             AirOp::JumpGt(jump) => self.emit_gt_jump(jump),
+            AirOp::JumpTable(jump) => self.emit_jump_table(jump),
+            AirOp::JumpEqF64(jump) => self.emit_eq_f64_jump(jump),
+            AirOp::JumpLtF64(jump) => self.emit_lt_f64_jump(jump),
+            AirOp::JumpGtF64(jump) => self.emit_gt_f64_jump(jump),
             AirOp::Printf(op) => self.emit_libc_op(
                 builtins::Builtin::Printf,
                 &op.args,
                 &op.arg_kinds,
                 &op.target,
+                &op.release,
             ),
             AirOp::Sprintf(op) => self.emit_libc_op(
                 builtins::Builtin::Sprintf,
                 &op.args,
                 &op.arg_kinds,
                 &op.target,
+                &op.release,
             ),
             AirOp::Write(op) => self.emit_libc_op(
                 builtins::Builtin::Write,
                 &op.args,
                 &op.arg_kinds,
                 &op.target,
+                &op.release,
+            ),
+            AirOp::WriteFd(op) => self.emit_libc_op(
+                builtins::Builtin::WriteFd,
+                &op.args,
+                &op.arg_kinds,
+                &op.target,
+                &op.release,
             ),
+            AirOp::Read(op) => self.emit_read(op),
+            AirOp::Open(op) => self.emit_open(op),
+            AirOp::Close(op) => self.emit_close(op),
+            AirOp::ArgCount(op) => self.emit_arg_count(op),
+            AirOp::ArgAt(op) => self.emit_arg_at(op),
+            AirOp::Getenv(op) => self.emit_getenv(op),
             AirOp::CallPtr(call) => self.emit_call_ptr(call),
             AirOp::SysExit(syscall) => self.emit_exit_syscall(syscall),
             AirOp::JumpArgs(call) => self.emit_jump_args(call),
@@ -535,6 +984,27 @@ impl<'a, W: Write> FunctionEmitter<'a, W> {
         Ok(())
     }
 
+    fn emit_cmp_str_jump(&mut self, cmp: &AirJumpCmpStr) -> Result<(), Error> {
+        if cmp.args.len() < 2 {
+            return Err(Error::new(
+                Code::Codegen,
+                "cmps builtin requires two arguments",
+                Span::unknown(),
+            ));
+        }
+        self.load_arg_into_reg(&cmp.args[0], "rdi")?;
+        self.load_arg_into_reg(&cmp.args[1], "rsi")?;
+        writeln!(
+            self.out,
+            "    call {} ; order the strings",
+            AirRuntimeHelper::CmpStrPtr.name()
+        )?;
+        writeln!(self.out, "    cmp rax, 0")?;
+        writeln!(self.out, "    jl {}", cmp.lt_target)?;
+        writeln!(self.out, "    jg {}", cmp.gt_target)?;
+        Ok(())
+    }
+
     fn emit_lt_jump(&mut self, jump: &AirJumpLt) -> Result<(), Error> {
         self.load_value_into_reg(&jump.left, "rax")?;
         self.load_value_into_reg(&jump.right, "rbx")?;
@@ -550,6 +1020,54 @@ impl<'a, W: Write> FunctionEmitter<'a, W> {
         writeln!(self.out, "    jg {}", jump.target)?;
         Ok(())
     }
+
+    /// Each entry of the table is a 5-byte `jmp near`, so the index times five
+    /// is its offset.
+    fn emit_jump_table(&mut self, jump: &AirJumpTable) -> Result<(), Error> {
+        let table = self.new_label("jump_table");
+        self.load_value_into_reg(&jump.index, "rax")?;
+        writeln!(self.out, "    lea rbx, [rel {}] ; load jump table", table)?;
+        writeln!(self.out, "    mov rcx, rax")?;
+        writeln!(self.out, "    shl rax, 2")?;
+        writeln!(self.out, "    add rax, rcx ; offset of the entry")?;
+        writeln!(self.out, "    add rax, rbx")?;
+        writeln!(self.out, "    jmp rax")?;
+        writeln!(self.out, "{}:", table)?;
+        for target in &jump.targets {
+            writeln!(self.out, "    jmp near {}", target)?;
+        }
+        Ok(())
+    }
+
+    fn emit_eq_f64_jump(&mut self, jump: &AirJumpCmpF64) -> Result<(), Error> {
+        let skip_label = self.new_label("eqf64_skip");
+        self.load_arg_into_xmm(&jump.input_a, "xmm0")?;
+        self.load_arg_into_xmm(&jump.input_b, "xmm1")?;
+        writeln!(self.out, "    ucomisd xmm0, xmm1")?;
+        writeln!(self.out, "    jp {} ; NaN is equal to nothing", skip_label)?;
+        writeln!(self.out, "    je {}", jump.target)?;
+        writeln!(self.out, "{}:", skip_label)?;
+        Ok(())
+    }
+
+    /// `ja` is false for unordered operands, so NaN falls through; the
+    /// less-than test swaps the operands to use it too.
+    fn emit_lt_f64_jump(&mut self, jump: &AirJumpCmpF64) -> Result<(), Error> {
+        self.load_arg_into_xmm(&jump.input_a, "xmm0")?;
+        self.load_arg_into_xmm(&jump.input_b, "xmm1")?;
+        writeln!(self.out, "    ucomisd xmm1, xmm0")?;
+        writeln!(self.out, "    ja {}", jump.target)?;
+        Ok(())
+    }
+
+    fn emit_gt_f64_jump(&mut self, jump: &AirJumpCmpF64) -> Result<(), Error> {
+        self.load_arg_into_xmm(&jump.input_a, "xmm0")?;
+        self.load_arg_into_xmm(&jump.input_b, "xmm1")?;
+        writeln!(self.out, "    ucomisd xmm0, xmm1")?;
+        writeln!(self.out, "    ja {}", jump.target)?;
+        Ok(())
+    }
+
     fn emit_add(&mut self, op: &AirAdd) -> Result<(), Error> {
         self.emit_binary_op(
             &op.input_a,
@@ -557,7 +1075,6 @@ impl<'a, W: Write> FunctionEmitter<'a, W> {
             &op.target,
             "add",
             "add second integer",
-            false,
         )
     }
 
@@ -568,7 +1085,6 @@ impl<'a, W: Write> FunctionEmitter<'a, W> {
             &op.target,
             "sub",
             "subtract subtrahend",
-            false,
         )
     }
 
@@ -577,119 +1093,861 @@ impl<'a, W: Write> FunctionEmitter<'a, W> {
             &op.input_a,
             &op.input_b,
             &op.target,
-            "mul",
+            "imul",
             "multiply by multiplier",
-            false,
         )
     }
 
-    fn emit_div_int(&mut self, op: &AirDivInt) -> Result<(), Error> {
-        self.load_arg_into_reg(&op.input_b, "rbx")?;
+    /// Divides, or takes the error continuation for a zero divisor. `idiv`
+    /// faults on `i64::MIN / -1`, so a divisor of -1 is handled apart: the
+    /// quotient overflows only for that dividend, and the remainder is 0.
+    fn emit_division(&mut self, op: &AirDivInt, remainder: bool) -> Result<(), Error> {
+        let err_label = self.new_label("div_err");
+        let divide_label = self.new_label("div_divide");
+        let ok_label = self.new_label("div_ok");
+        self.load_arg_into_reg(&op.input_a, "rax")?;
+        self.load_arg_into_reg(&op.input_b, "rbx")?;
+        writeln!(
+            self.out,
+            "    cmp rbx, 0 ; check divisor for division by zero"
+        )?;
+        writeln!(self.out, "    je {}", err_label)?;
+        writeln!(self.out, "    cmp rbx, -1")?;
+        writeln!(self.out, "    jne {}", divide_label)?;
+        if remainder {
+            writeln!(self.out, "    mov rax, 0 ; anything mod -1 is 0")?;
+        } else {
+            writeln!(self.out, "    mov rcx, {:#x} ; smallest int", i64::MIN)?;
+            writeln!(self.out, "    cmp rax, rcx")?;
+            writeln!(self.out, "    je {} ; its negation overflows", err_label)?;
+            writeln!(self.out, "    neg rax ; dividing by -1 negates")?;
+        }
+        writeln!(self.out, "    jmp {}", ok_label)?;
+
+        writeln!(self.out, "{}:", divide_label)?;
+        writeln!(self.out, "    cqo ; sign extend dividend")?;
+        writeln!(self.out, "    idiv rbx ; divide by divisor")?;
+        if remainder {
+            writeln!(self.out, "    mov rax, rdx ; keep the remainder")?;
+        }
+        writeln!(self.out, "{}:", ok_label)?;
+        self.emit_releases(&[], &[&op.err_target], true)?;
+        self.emit_value_jump(&op.ok_target, true)?;
+
+        writeln!(self.out, "{}:", err_label)?;
+        self.emit_release_closure(&op.ok_target)?;
+        self.emit_value_jump(&op.err_target, false)
+    }
+
+    fn emit_add_f64(&mut self, op: &AirAddF64) -> Result<(), Error> {
+        self.emit_float_binary_op(
+            &op.input_a,
+            &op.input_b,
+            &op.target,
+            "addsd",
+            "add second float",
+        )
+    }
+
+    fn emit_sub_f64(&mut self, op: &AirSubF64) -> Result<(), Error> {
+        self.emit_float_binary_op(
+            &op.input_a,
+            &op.input_b,
+            &op.target,
+            "subsd",
+            "subtract subtrahend float",
+        )
+    }
+
+    fn emit_mul_f64(&mut self, op: &AirMulF64) -> Result<(), Error> {
+        self.emit_float_binary_op(
+            &op.input_a,
+            &op.input_b,
+            &op.target,
+            "mulsd",
+            "multiply by multiplier float",
+        )
+    }
+
+    fn emit_div_f64(&mut self, op: &AirDivF64) -> Result<(), Error> {
+        self.emit_float_binary_op(
+            &op.input_a,
+            &op.input_b,
+            &op.target,
+            "divsd",
+            "divide by divisor float",
+        )
+    }
+
+    fn emit_binary_op(
+        &mut self,
+        input_a: &AirArg,
+        input_b: &AirArg,
+        target: &str,
+        opcode: &str,
+        second_comment: &str,
+    ) -> Result<(), Error> {
+        self.load_arg_into_reg(input_a, "rax")?;
+        if matches!(opcode, "shl" | "sar") {
+            // Shifts take their count in cl and use its low six bits.
+            self.load_arg_into_reg(input_b, "rcx")?;
+            writeln!(self.out, "    {} rax, cl ; {}", opcode, second_comment)?;
+        } else {
+            self.load_arg_into_reg(input_b, "rbx")?;
+            writeln!(self.out, "    {} rax, rbx ; {}", opcode, second_comment)?;
+        }
+        self.emit_value_jump(target, true)?;
+        Ok(())
+    }
+
+    fn emit_neg(&mut self, op: &AirNeg) -> Result<(), Error> {
+        self.load_arg_into_reg(&op.input, "rax")?;
+        writeln!(self.out, "    neg rax ; negate")?;
+        self.emit_value_jump(&op.target, true)
+    }
+
+    /// Passes the wrapped result to the overflow continuation when the
+    /// operation sets the overflow flag.
+    fn emit_checked_op(
+        &mut self,
+        op: &AirChecked,
+        opcode: &str,
+        comment: &str,
+    ) -> Result<(), Error> {
+        let overflow_label = self.new_label("overflow");
+        self.load_arg_into_reg(&op.input_a, "rax")?;
+        self.load_arg_into_reg(&op.input_b, "rbx")?;
+        writeln!(self.out, "    {} rax, rbx ; {}", opcode, comment)?;
+        writeln!(self.out, "    jo {}", overflow_label)?;
+        self.emit_releases(&[], &[&op.overflow_target], true)?;
+        self.emit_value_jump(&op.ok_target, true)?;
+
+        writeln!(self.out, "{}:", overflow_label)?;
+        self.emit_releases(&[], &[&op.ok_target], true)?;
+        self.emit_value_jump(&op.overflow_target, true)
+    }
+
+    fn emit_neg_f64(&mut self, op: &AirNegF64) -> Result<(), Error> {
+        self.load_arg_into_reg(&op.input, "rax")?;
+        writeln!(
+            self.out,
+            "    mov rcx, {:#x} ; float sign bit",
+            F64_SIGN_BIT
+        )?;
+        writeln!(self.out, "    xor rax, rcx ; flip the sign")?;
+        self.emit_value_jump(&op.target, true)
+    }
+
+    fn emit_sqrt_f64(&mut self, op: &AirSqrtF64) -> Result<(), Error> {
+        self.load_arg_into_xmm(&op.input, "xmm0")?;
+        writeln!(self.out, "    sqrtsd xmm0, xmm0 ; square root")?;
+        writeln!(self.out, "    movq rax, xmm0 ; move float result to rax")?;
+        self.emit_value_jump(&op.target, true)
+    }
+
+    fn emit_int_to_f64(&mut self, op: &AirIntToF64) -> Result<(), Error> {
+        self.load_arg_into_reg(&op.input, "rax")?;
+        writeln!(self.out, "    cvtsi2sd xmm0, rax ; convert int to float")?;
+        writeln!(self.out, "    movq rax, xmm0 ; move float result to rax")?;
+        self.emit_value_jump(&op.target, true)
+    }
+
+    /// `cvttsd2si` gives `i64::MIN` for NaN and out-of-range values, so that
+    /// result only counts when the input really was -2^63.
+    fn emit_f64_to_int(&mut self, op: &AirF64ToInt) -> Result<(), Error> {
+        let ok_label = self.new_label("ftoi_ok");
+        let err_label = self.new_label("ftoi_err");
+        self.load_arg_into_xmm(&op.input, "xmm0")?;
+        writeln!(self.out, "    cvttsd2si rax, xmm0 ; truncate toward zero")?;
+        writeln!(
+            self.out,
+            "    mov rcx, {:#x} ; integer indefinite",
+            F64_SIGN_BIT
+        )?;
+        writeln!(self.out, "    cmp rax, rcx")?;
+        writeln!(self.out, "    jne {}", ok_label)?;
+        writeln!(
+            self.out,
+            "    mov rcx, {:#x} ; -2^63 as a float",
+            (i64::MIN as f64).to_bits()
+        )?;
+        writeln!(self.out, "    movq xmm1, rcx")?;
+        writeln!(self.out, "    ucomisd xmm0, xmm1")?;
+        writeln!(self.out, "    jp {} ; NaN has no int", err_label)?;
+        writeln!(self.out, "    je {}", ok_label)?;
+
+        writeln!(self.out, "{}:", err_label)?;
+        self.emit_release_closure(&op.ok_target)?;
+        self.emit_value_jump(&op.err_target, false)?;
+
+        writeln!(self.out, "{}:", ok_label)?;
+        self.emit_releases(&[], &[&op.err_target], true)?;
+        self.emit_value_jump(&op.ok_target, true)
+    }
+
+    fn emit_float_binary_op(
+        &mut self,
+        input_a: &AirArg,
+        input_b: &AirArg,
+        target: &str,
+        opcode: &str,
+        comment: &str,
+    ) -> Result<(), Error> {
+        self.load_arg_into_xmm(input_a, "xmm0")?;
+        self.load_arg_into_xmm(input_b, "xmm1")?;
+        writeln!(
+            self.out,
+            "    {opcode} xmm0, xmm1 ; {comment}",
+            opcode = opcode,
+            comment = comment
+        )?;
+        writeln!(self.out, "    movq rax, xmm0 ; move float result to rax")?;
+        self.emit_value_jump(target, true)?;
+        Ok(())
+    }
+
+    fn emit_libc_op(
+        &mut self,
+        builtin: builtins::Builtin,
+        args: &[AirArg],
+        arg_kinds: &[SigKind],
+        target: &str,
+        release: &[String],
+    ) -> Result<(), Error> {
+        let has_result = self.emit_libc_call(builtin, args, arg_kinds)?;
+        self.emit_releases(release, &[], has_result)?;
+        self.emit_value_jump(target, has_result)?;
+        Ok(())
+    }
+
+    /// Releases the strings an op consumed and the continuations it does not
+    /// take, keeping the result in rax when there is one.
+    fn emit_strlen(&mut self, op: &AirStrLen) -> Result<(), Error> {
+        self.load_arg_into_reg(&op.input, "rax")?;
+        writeln!(
+            self.out,
+            "    mov rax, [rax-{}] ; string length",
+            STR_LEN_OFFSET
+        )?;
+        self.emit_releases(&op.release, &[], true)?;
+        self.emit_value_jump(&op.target, true)
+    }
+
+    fn emit_concat(&mut self, op: &AirConcat) -> Result<(), Error> {
+        self.load_arg_into_reg(&op.input_a, "rdi")?;
+        self.load_arg_into_reg(&op.input_b, "rsi")?;
+        writeln!(
+            self.out,
+            "    call {} ; join the strings",
+            AirRuntimeHelper::ConcatStrPtr.name()
+        )?;
+        self.emit_releases(&op.release, &[], true)?;
+        self.emit_value_jump(&op.target, true)
+    }
+
+    fn emit_slice(&mut self, op: &AirSlice) -> Result<(), Error> {
+        let err_label = self.new_label("slice_err");
+        self.load_arg_into_reg(&op.input, "rdi")?;
+        self.load_arg_into_reg(&op.start, "rsi")?;
+        self.load_arg_into_reg(&op.end, "rdx")?;
+        writeln!(self.out, "    cmp rsi, 0 ; start before the string?")?;
+        writeln!(self.out, "    jl {}", err_label)?;
+        writeln!(self.out, "    cmp rdx, rsi ; end before start?")?;
+        writeln!(self.out, "    jl {}", err_label)?;
+        writeln!(
+            self.out,
+            "    mov rcx, [rdi-{}] ; string length",
+            STR_LEN_OFFSET
+        )?;
+        writeln!(self.out, "    cmp rdx, rcx ; end past the string?")?;
+        writeln!(self.out, "    jg {}", err_label)?;
+        writeln!(
+            self.out,
+            "    call {} ; copy the range",
+            AirRuntimeHelper::SliceStrPtr.name()
+        )?;
+        self.emit_releases(&op.release, &[&op.err_target], true)?;
+        self.emit_value_jump(&op.ok_target, true)?;
+
+        writeln!(self.out, "{}:", err_label)?;
+        self.emit_releases(&op.release, &[&op.ok_target], false)?;
+        self.emit_value_jump(&op.err_target, false)
+    }
+
+    fn emit_byte_at(&mut self, op: &AirByteAt) -> Result<(), Error> {
+        let err_label = self.new_label("byteat_err");
+        self.load_arg_into_reg(&op.input, "rdi")?;
+        self.load_arg_into_reg(&op.index, "rsi")?;
+        writeln!(self.out, "    cmp rsi, 0 ; index before the string?")?;
+        writeln!(self.out, "    jl {}", err_label)?;
+        writeln!(
+            self.out,
+            "    mov rcx, [rdi-{}] ; string length",
+            STR_LEN_OFFSET
+        )?;
+        writeln!(self.out, "    cmp rsi, rcx ; index past the string?")?;
+        writeln!(self.out, "    jge {}", err_label)?;
+        writeln!(self.out, "    movzx rax, byte [rdi+rsi] ; load the byte")?;
+        self.emit_releases(&op.release, &[&op.err_target], true)?;
+        self.emit_value_jump(&op.ok_target, true)?;
+
+        writeln!(self.out, "{}:", err_label)?;
+        self.emit_releases(&op.release, &[&op.ok_target], false)?;
+        self.emit_value_jump(&op.err_target, false)
+    }
+
+    fn emit_new_array(&mut self, array: &AirNewArray) -> Result<(), Error> {
+        let len = array.items.len();
+        writeln!(
+            self.out,
+            "    mov rdi, {} ; header and elements",
+            ARRAY_HEADER_SIZE + len * WORD_SIZE
+        )?;
+        writeln!(
+            self.out,
+            "    call {} ; allocate the array",
+            AirRuntimeHelper::AllocHeapPtr.name()
+        )?;
+        writeln!(self.out, "    mov rbx, rax")?;
+        writeln!(
+            self.out,
+            "    mov qword [rbx], {} ; element class header",
+            array_elem_class(&array.elem)
+        )?;
+        writeln!(
+            self.out,
+            "    mov qword [rbx+{}], {} ; length header",
+            ARRAY_HEADER_SIZE - ARRAY_LEN_OFFSET,
+            len
+        )?;
+        writeln!(
+            self.out,
+            "    add rbx, {} ; array starts after its header",
+            ARRAY_HEADER_SIZE
+        )?;
+        for (idx, item) in array.items.iter().enumerate() {
+            self.load_arg_into_reg(item, "rax")?;
+            writeln!(
+                self.out,
+                "    mov [rbx+{}], rax ; element {}",
+                idx * WORD_SIZE,
+                idx
+            )?;
+        }
+        writeln!(self.out, "    mov rax, rbx")?;
+        self.store_binding_value(&array.name)
+    }
+
+    fn emit_array_len(&mut self, op: &AirArrayLen) -> Result<(), Error> {
+        self.load_arg_into_reg(&op.input, "rdi")?;
+        writeln!(
+            self.out,
+            "    mov rbx, [rdi-{}] ; array length",
+            ARRAY_LEN_OFFSET
+        )?;
+        writeln!(
+            self.out,
+            "    call {} ; release the array",
+            AirRuntimeHelper::ReleaseArrayPtr.name()
+        )?;
+        writeln!(self.out, "    mov rax, rbx")?;
+        self.emit_value_jump(&op.target, true)
+    }
+
+    /// Leaves `index` in rsi when it is in bounds of the array in r13, and
+    /// jumps to `out_label` otherwise.
+    fn emit_array_bounds_check(&mut self, index: &AirArg, out_label: &str) -> Result<(), Error> {
+        self.load_arg_into_reg(index, "rsi")?;
+        writeln!(self.out, "    cmp rsi, 0 ; index before the array?")?;
+        writeln!(self.out, "    jl {}", out_label)?;
+        writeln!(
+            self.out,
+            "    mov rcx, [r13-{}] ; array length",
+            ARRAY_LEN_OFFSET
+        )?;
+        writeln!(self.out, "    cmp rsi, rcx ; index past the array?")?;
+        writeln!(self.out, "    jge {}", out_label)?;
+        writeln!(self.out, "    shl rsi, 3 ; element offset")?;
+        writeln!(self.out, "    add rsi, r13 ; element address")?;
+        Ok(())
+    }
+
+    fn emit_release_array_in_r13(&mut self) -> Result<(), Error> {
+        writeln!(self.out, "    mov rdi, r13")?;
+        writeln!(
+            self.out,
+            "    call {} ; release the array",
+            AirRuntimeHelper::ReleaseArrayPtr.name()
+        )?;
+        Ok(())
+    }
+
+    fn emit_array_get(&mut self, op: &AirArrayGet) -> Result<(), Error> {
+        let none_label = self.new_label("get_none");
+        self.load_arg_into_reg(&op.input, "r13")?;
+        self.emit_array_bounds_check(&op.index, &none_label)?;
+        writeln!(self.out, "    mov r14, [rsi] ; take the element")?;
+        writeln!(
+            self.out,
+            "    mov qword [rsi], 0 ; the array no longer owns it"
+        )?;
+        self.emit_release_array_in_r13()?;
+        writeln!(self.out, "    mov rax, r14")?;
+        self.emit_releases(&[], &[&op.none_target], true)?;
+        self.emit_value_jump(&op.one_target, true)?;
+
+        writeln!(self.out, "{}:", none_label)?;
+        self.emit_release_array_in_r13()?;
+        self.emit_releases(&[], &[&op.one_target], false)?;
+        self.emit_value_jump(&op.none_target, false)
+    }
+
+    fn emit_array_set(&mut self, op: &AirArraySet) -> Result<(), Error> {
+        let err_label = self.new_label("set_err");
+        self.load_arg_into_reg(&op.input, "r13")?;
+        self.load_arg_into_reg(&op.value, "r14")?;
+        self.emit_array_bounds_check(&op.index, &err_label)?;
+        writeln!(self.out, "    mov rdi, [rsi] ; old element")?;
+        writeln!(self.out, "    mov [rsi], r14 ; store the new one")?;
+        self.emit_release_array_elem("old element")?;
+        writeln!(self.out, "    mov rax, r13")?;
+        self.emit_releases(&[], &[&op.err_target], true)?;
+        self.emit_value_jump(&op.ok_target, true)?;
+
+        writeln!(self.out, "{}:", err_label)?;
+        writeln!(self.out, "    mov rdi, r14")?;
+        self.emit_release_array_elem("unused value")?;
+        self.emit_release_array_in_r13()?;
+        self.emit_releases(&[], &[&op.ok_target], false)?;
+        self.emit_value_jump(&op.err_target, false)
+    }
+
+    /// Releases the element at rdi as the array in r13 would.
+    fn emit_release_array_elem(&mut self, what: &str) -> Result<(), Error> {
+        writeln!(
+            self.out,
+            "    mov rsi, [r13-{}] ; element class",
+            ARRAY_CLASS_OFFSET
+        )?;
+        writeln!(
+            self.out,
+            "    call {} ; release the {}",
+            AirRuntimeHelper::ReleaseArrayElemPtr.name(),
+            what
+        )?;
+        Ok(())
+    }
+
+    fn emit_new_map(&mut self, map: &AirNewMap) -> Result<(), Error> {
+        writeln!(
+            self.out,
+            "    mov rdi, {} ; key class",
+            array_elem_class(&map.key)
+        )?;
+        writeln!(
+            self.out,
+            "    mov rsi, {} ; value class",
+            array_elem_class(&map.value)
+        )?;
+        writeln!(
+            self.out,
+            "    call {} ; allocate the map",
+            AirRuntimeHelper::MapNewPtr.name()
+        )?;
+        for (key, value) in &map.entries {
+            writeln!(self.out, "    mov rbx, rax")?;
+            self.load_arg_into_reg(key, "rsi")?;
+            self.load_arg_into_reg(value, "rdx")?;
+            writeln!(self.out, "    mov rdi, rbx")?;
+            writeln!(
+                self.out,
+                "    call {} ; add the entry",
+                AirRuntimeHelper::MapInsertPtr.name()
+            )?;
+        }
+        self.store_binding_value(&map.name)
+    }
+
+    fn emit_new_chan(&mut self, op: &AirNewChan) -> Result<(), Error> {
+        writeln!(
+            self.out,
+            "    mov rdi, {} ; class of the values",
+            chan_elem_class(&op.elem)
+        )?;
+        writeln!(
+            self.out,
+            "    call {} ; make the channel",
+            AirRuntimeHelper::ChanNewPtr.name()
+        )?;
+        self.emit_value_jump(&op.target, true)
+    }
+
+    fn emit_chan_send(&mut self, op: &AirChanSend) -> Result<(), Error> {
+        self.load_arg_into_reg(&op.channel, "r13")?;
+        self.load_arg_into_reg(&op.value, "rsi")?;
+        writeln!(self.out, "    mov rdi, r13")?;
+        writeln!(
+            self.out,
+            "    call {} ; hand the value over or queue it",
+            AirRuntimeHelper::ChanSendPtr.name()
+        )?;
+        self.emit_values_jump(&op.target, &["r13"])
+    }
+
+    fn emit_chan_recv(&mut self, op: &AirChanRecv) -> Result<(), Error> {
+        let park_label = self.new_label("recv_park");
+        self.load_arg_into_reg(&op.channel, "r13")?;
+        self.load_value_into_reg(&AirValue::Binding(op.target.clone()), "rsi")?;
+        writeln!(self.out, "    mov rdi, r13")?;
+        writeln!(
+            self.out,
+            "    call {} ; take a queued value or park",
+            AirRuntimeHelper::ChanRecvPtr.name()
+        )?;
+        writeln!(self.out, "    test rdx, rdx ; was a value waiting?")?;
+        writeln!(self.out, "    jz {}", park_label)?;
+        self.emit_values_jump(&op.target, &["rax", "r13"])?;
+
+        writeln!(self.out, "{}:", park_label)?;
+        self.emit_sched_next()
+    }
+
+    fn emit_spawn(&mut self, op: &AirSpawn) -> Result<(), Error> {
+        self.emit_run_later(&op.task)?;
+        self.emit_values_jump(&op.target, &[])
+    }
+
+    fn emit_thread(&mut self, op: &AirThread) -> Result<(), Error> {
+        self.load_value_into_reg(&AirValue::Binding(op.task.clone()), "rdi")?;
+        writeln!(
+            self.out,
+            "    call {} ; run {} on a thread of its own",
+            AirRuntimeHelper::ThreadNewPtr.name(),
+            op.task
+        )?;
+        self.emit_values_jump(&op.target, &[])
+    }
+
+    fn emit_yield(&mut self, op: &AirYield) -> Result<(), Error> {
+        self.emit_run_later(&op.target)?;
+        self.emit_sched_next()
+    }
+
+    /// Puts the closure bound to `name` at the back of the run queue.
+    fn emit_run_later(&mut self, name: &str) -> Result<(), Error> {
+        self.load_value_into_reg(&AirValue::Binding(name.to_string()), "rdi")?;
+        writeln!(
+            self.out,
+            "    call {} ; run {} later",
+            AirRuntimeHelper::SchedReadyPtr.name(),
+            name
+        )?;
+        Ok(())
+    }
+
+    /// Gives control to the next coroutine in the run queue.
+    fn emit_sched_next(&mut self) -> Result<(), Error> {
+        writeln!(self.out, "    leave ; unwind before switching")?;
+        writeln!(
+            self.out,
+            "    jmp {} ; run the next coroutine",
+            AirRuntimeHelper::SchedNextPtr.name()
+        )?;
+        self.terminated = true;
+        Ok(())
+    }
+
+    fn emit_map_insert(&mut self, op: &AirMapInsert) -> Result<(), Error> {
+        self.load_arg_into_reg(&op.input, "rdi")?;
+        self.load_arg_into_reg(&op.key, "rsi")?;
+        self.load_arg_into_reg(&op.value, "rdx")?;
+        writeln!(
+            self.out,
+            "    call {} ; store the entry",
+            AirRuntimeHelper::MapInsertPtr.name()
+        )?;
+        self.emit_value_jump(&op.target, true)
+    }
+
+    fn emit_map_remove(&mut self, op: &AirMapRemove) -> Result<(), Error> {
+        self.load_arg_into_reg(&op.input, "rdi")?;
+        self.load_arg_into_reg(&op.key, "rsi")?;
+        writeln!(
+            self.out,
+            "    call {} ; drop the entry",
+            AirRuntimeHelper::MapRemovePtr.name()
+        )?;
+        self.emit_value_jump(&op.target, true)
+    }
 
-        let ok_label = self.new_label("div_ok");
+    fn emit_map_len(&mut self, op: &AirMapLen) -> Result<(), Error> {
+        self.load_arg_into_reg(&op.input, "r13")?;
         writeln!(
             self.out,
-            "    cmp rbx, 0 ; check divisor for division by zero"
+            "    mov r14, [r13-{}] ; number of entries",
+            MAP_COUNT_OFFSET
         )?;
-        writeln!(self.out, "    jne {}", ok_label)?;
+        self.emit_values_jump(&op.target, &["r14", "r13"])
+    }
 
-        self.emit_release_heap_ptr(&op.ok_target)?;
-        self.emit_value_jump(&op.err_target, false)?;
+    fn emit_map_lookup(&mut self, op: &AirMapLookup) -> Result<(), Error> {
+        let missing_label = self.new_label("lookup_missing");
+        self.load_arg_into_reg(&op.input, "r13")?;
+        self.load_arg_into_reg(&op.key, "r14")?;
+        writeln!(self.out, "    mov rdi, r13")?;
+        writeln!(self.out, "    mov rsi, r14")?;
+        writeln!(
+            self.out,
+            "    call {} ; look for the key",
+            AirRuntimeHelper::MapFindPtr.name()
+        )?;
+        writeln!(self.out, "    test rax, rax")?;
+        writeln!(self.out, "    jz {}", missing_label)?;
+        writeln!(
+            self.out,
+            "    mov rdi, [rax+{}] ; value of the entry",
+            WORD_SIZE
+        )?;
+        self.emit_map_elem_helper(
+            AirRuntimeHelper::CopyArrayElemPtr,
+            MAP_VALUE_CLASS_OFFSET,
+            "copy the value",
+        )?;
+        writeln!(self.out, "    mov r15, rax")?;
+        self.emit_release_map_key_in_r14()?;
+        self.emit_releases(&[], &[&op.missing_target], false)?;
+        self.emit_values_jump(&op.found_target, &["r15", "r13"])?;
+
+        writeln!(self.out, "{}:", missing_label)?;
+        self.emit_release_map_key_in_r14()?;
+        self.emit_releases(&[], &[&op.found_target], false)?;
+        self.emit_values_jump(&op.missing_target, &["r13"])
+    }
+
+    fn emit_map_next(&mut self, op: &AirMapNext) -> Result<(), Error> {
+        let done_label = self.new_label("mapnext_done");
+        self.load_arg_into_reg(&op.input, "r13")?;
+        self.load_arg_into_reg(&op.cursor, "rbx")?;
+        writeln!(self.out, "    cmp rbx, 0 ; cursor before the entries?")?;
+        writeln!(self.out, "    jl {}", done_label)?;
+        writeln!(
+            self.out,
+            "    cmp rbx, [r13-{}] ; cursor past the entries?",
+            MAP_COUNT_OFFSET
+        )?;
+        writeln!(self.out, "    jge {}", done_label)?;
+        for (offset, class_offset, reg, what) in [
+            (0, MAP_KEY_CLASS_OFFSET, "r14", "copy the key"),
+            (WORD_SIZE, MAP_VALUE_CLASS_OFFSET, "r15", "copy the value"),
+        ] {
+            writeln!(self.out, "    mov rcx, rbx")?;
+            writeln!(self.out, "    shl rcx, 4 ; entry offset")?;
+            writeln!(self.out, "    add rcx, r13")?;
+            writeln!(self.out, "    mov rdi, [rcx+{}]", offset)?;
+            self.emit_map_elem_helper(AirRuntimeHelper::CopyArrayElemPtr, class_offset, what)?;
+            writeln!(self.out, "    mov {}, rax", reg)?;
+        }
+        writeln!(self.out, "    inc rbx ; cursor of the next entry")?;
+        self.emit_releases(&[], &[&op.done_target], false)?;
+        self.emit_values_jump(&op.entry_target, &["r14", "r15", "rbx", "r13"])?;
 
-        writeln!(self.out, "{}:", ok_label)?;
-        self.emit_release_heap_ptr(&op.err_target)?;
-        self.load_arg_into_reg(&op.input_a, "rax")?;
-        self.load_arg_into_reg(&op.input_b, "rbx")?;
-        writeln!(self.out, "    cqo ; sign extend dividend")?;
-        writeln!(self.out, "    idiv rbx ; divide by divisor")?;
-        self.emit_value_jump(&op.ok_target, true)
+        writeln!(self.out, "{}:", done_label)?;
+        self.emit_releases(&[], &[&op.entry_target], false)?;
+        self.emit_values_jump(&op.done_target, &["r13"])
     }
 
-    fn emit_add_f64(&mut self, op: &AirAddF64) -> Result<(), Error> {
-        self.emit_float_binary_op(
-            &op.input_a,
-            &op.input_b,
-            &op.target,
-            "addsd",
-            "add second float",
-        )
+    /// Calls `helper` on rdi with the key or value class of the map in r13.
+    fn emit_map_elem_helper(
+        &mut self,
+        helper: AirRuntimeHelper,
+        class_offset: usize,
+        what: &str,
+    ) -> Result<(), Error> {
+        writeln!(self.out, "    mov rsi, [r13-{}]", class_offset)?;
+        writeln!(self.out, "    call {} ; {}", helper.name(), what)?;
+        Ok(())
     }
 
-    fn emit_mul_f64(&mut self, op: &AirMulF64) -> Result<(), Error> {
-        self.emit_float_binary_op(
-            &op.input_a,
-            &op.input_b,
-            &op.target,
-            "mulsd",
-            "multiply by multiplier float",
+    /// Releases the lookup key in r14 as the map in r13 would.
+    fn emit_release_map_key_in_r14(&mut self) -> Result<(), Error> {
+        writeln!(self.out, "    mov rdi, r14")?;
+        self.emit_map_elem_helper(
+            AirRuntimeHelper::ReleaseArrayElemPtr,
+            MAP_KEY_CLASS_OFFSET,
+            "release the key",
         )
     }
 
-    fn emit_div_f64(&mut self, op: &AirDivF64) -> Result<(), Error> {
-        self.emit_float_binary_op(
-            &op.input_a,
-            &op.input_b,
-            &op.target,
-            "divsd",
-            "divide by divisor float",
-        )
+    fn emit_read(&mut self, op: &AirRead) -> Result<(), Error> {
+        let size_ok_label = self.new_label("read_size_ok");
+        let eof_label = self.new_label("read_eof");
+        let err_label = self.new_label("read_err");
+        let buffer_ok_label = self.new_label("read_buffer_ok");
+        self.load_arg_into_reg(&op.fd, "r14")?;
+        self.load_arg_into_reg(&op.max, "r13")?;
+        writeln!(self.out, "    cmp r13, 0 ; negative size?")?;
+        writeln!(self.out, "    jge {}", size_ok_label)?;
+        writeln!(self.out, "    mov rax, {} ; EINVAL", EINVAL)?;
+        self.emit_releases(&[], &[&op.ok_target, &op.eof_target], true)?;
+        self.emit_value_jump(&op.err_target, true)?;
+
+        writeln!(self.out, "{}:", size_ok_label)?;
+        writeln!(self.out, "    mov rdi, r13")?;
+        writeln!(
+            self.out,
+            "    call {} ; buffer for the bytes",
+            AirRuntimeHelper::AllocStrPtr.name()
+        )?;
+        writeln!(self.out, "    test rax, rax ; out of memory?")?;
+        writeln!(self.out, "    jnz {}", buffer_ok_label)?;
+        writeln!(self.out, "    mov rax, {} ; ENOMEM", ENOMEM)?;
+        self.emit_releases(&[], &[&op.ok_target, &op.eof_target], true)?;
+        self.emit_value_jump(&op.err_target, true)?;
+
+        writeln!(self.out, "{}:", buffer_ok_label)?;
+        writeln!(self.out, "    mov r15, rax")?;
+        writeln!(self.out, "    mov rdi, r14 ; fd")?;
+        writeln!(self.out, "    mov rsi, r15 ; buffer start")?;
+        writeln!(self.out, "    mov rdx, r13 ; most bytes to read")?;
+        writeln!(self.out, "    call read ; invoke libc read")?;
+        writeln!(self.out, "    cmp rax, 0")?;
+        writeln!(self.out, "    jl {}", err_label)?;
+        writeln!(self.out, "    je {}", eof_label)?;
+        writeln!(
+            self.out,
+            "    mov [r15-{}], rax ; length header",
+            STR_LEN_OFFSET
+        )?;
+        writeln!(self.out, "    mov byte [r15+rax], 0 ; terminator")?;
+        writeln!(self.out, "    mov rax, r15")?;
+        self.emit_releases(&[], &[&op.eof_target, &op.err_target], true)?;
+        self.emit_value_jump(&op.ok_target, true)?;
+
+        writeln!(self.out, "{}:", eof_label)?;
+        self.emit_release_read_buffer()?;
+        self.emit_releases(&[], &[&op.ok_target, &op.err_target], false)?;
+        self.emit_value_jump(&op.eof_target, false)?;
+
+        writeln!(self.out, "{}:", err_label)?;
+        self.emit_load_errno()?;
+        writeln!(self.out, "    mov r13, rax ; keep errno across the release")?;
+        self.emit_release_read_buffer()?;
+        writeln!(self.out, "    mov rax, r13")?;
+        self.emit_releases(&[], &[&op.ok_target, &op.eof_target], true)?;
+        self.emit_value_jump(&op.err_target, true)
+    }
+
+    fn emit_release_read_buffer(&mut self) -> Result<(), Error> {
+        writeln!(self.out, "    mov rdi, r15")?;
+        writeln!(
+            self.out,
+            "    call {} ; release unused read buffer",
+            AirRuntimeHelper::ReleaseStrPtr.name()
+        )?;
+        Ok(())
     }
 
-    fn emit_binary_op(
-        &mut self,
-        input_a: &AirArg,
-        input_b: &AirArg,
-        target: &str,
-        opcode: &str,
-        second_comment: &str,
-        is_div: bool,
-    ) -> Result<(), Error> {
-        self.load_arg_into_reg(input_a, "rax")?;
-        self.load_arg_into_reg(input_b, "rbx")?;
-        if is_div {
-            writeln!(self.out, "    cqo ; sign extend dividend")?;
-            writeln!(self.out, "    idiv rbx ; {}", second_comment)?;
-        } else {
-            writeln!(self.out, "    {} rax, rbx ; {}", opcode, second_comment)?;
-        }
-        self.emit_value_jump(target, true)?;
-        Ok(())
+    fn emit_open(&mut self, op: &AirOpen) -> Result<(), Error> {
+        let err_label = self.new_label("open_err");
+        self.load_arg_into_reg(&op.path, "rdi")?;
+        self.load_arg_into_reg(&op.flags, "rsi")?;
+        self.load_arg_into_reg(&op.mode, "rdx")?;
+        writeln!(self.out, "    xor eax, eax ; no vector args")?;
+        self.emit_variadic_libc_call("open")?;
+        writeln!(self.out, "    cmp eax, 0")?;
+        writeln!(self.out, "    jl {}", err_label)?;
+        writeln!(self.out, "    mov eax, eax ; zero-extend the fd")?;
+        self.emit_releases(&op.release, &[&op.err_target], true)?;
+        self.emit_value_jump(&op.ok_target, true)?;
+
+        writeln!(self.out, "{}:", err_label)?;
+        self.emit_load_errno()?;
+        self.emit_releases(&op.release, &[&op.ok_target], true)?;
+        self.emit_value_jump(&op.err_target, true)
+    }
+
+    fn emit_close(&mut self, op: &AirClose) -> Result<(), Error> {
+        let err_label = self.new_label("close_err");
+        self.load_arg_into_reg(&op.fd, "rdi")?;
+        writeln!(self.out, "    call close ; invoke libc close")?;
+        writeln!(self.out, "    cmp eax, 0")?;
+        writeln!(self.out, "    jl {}", err_label)?;
+        self.emit_releases(&[], &[&op.err_target], false)?;
+        self.emit_value_jump(&op.ok_target, false)?;
+
+        writeln!(self.out, "{}:", err_label)?;
+        self.emit_load_errno()?;
+        self.emit_releases(&[], &[&op.ok_target], true)?;
+        self.emit_value_jump(&op.err_target, true)
+    }
+
+    fn emit_arg_count(&mut self, op: &AirArgCount) -> Result<(), Error> {
+        writeln!(self.out, "    lea rcx, [{}]", PROCESS_ARGS_LABEL)?;
+        writeln!(self.out, "    mov rax, [rcx] ; argc")?;
+        self.emit_value_jump(&op.target, true)
+    }
+
+    fn emit_arg_at(&mut self, op: &AirArgAt) -> Result<(), Error> {
+        let none_label = self.new_label("arg_none");
+        self.load_arg_into_reg(&op.index, "rsi")?;
+        writeln!(self.out, "    lea rcx, [{}]", PROCESS_ARGS_LABEL)?;
+        writeln!(self.out, "    cmp rsi, 0 ; index before the arguments?")?;
+        writeln!(self.out, "    jl {}", none_label)?;
+        writeln!(self.out, "    mov rdx, [rcx] ; argc")?;
+        writeln!(self.out, "    cmp rsi, rdx ; index past the arguments?")?;
+        writeln!(self.out, "    jge {}", none_label)?;
+        writeln!(self.out, "    mov rdx, [rcx+8] ; argv")?;
+        writeln!(self.out, "    shl rsi, 3 ; one pointer per argument")?;
+        writeln!(self.out, "    add rdx, rsi")?;
+        writeln!(self.out, "    mov rdi, [rdx]")?;
+        writeln!(
+            self.out,
+            "    call {} ; copy the argument",
+            AirRuntimeHelper::CStrToStrPtr.name()
+        )?;
+        self.emit_releases(&[], &[&op.none_target], true)?;
+        self.emit_value_jump(&op.one_target, true)?;
+
+        writeln!(self.out, "{}:", none_label)?;
+        self.emit_releases(&[], &[&op.one_target], false)?;
+        self.emit_value_jump(&op.none_target, false)
     }
 
-    fn emit_float_binary_op(
-        &mut self,
-        input_a: &AirArg,
-        input_b: &AirArg,
-        target: &str,
-        opcode: &str,
-        comment: &str,
-    ) -> Result<(), Error> {
-        self.load_arg_into_xmm(input_a, "xmm0")?;
-        self.load_arg_into_xmm(input_b, "xmm1")?;
+    fn emit_getenv(&mut self, op: &AirGetenv) -> Result<(), Error> {
+        let missing_label = self.new_label("getenv_missing");
+        self.load_arg_into_reg(&op.name, "rdi")?;
+        writeln!(self.out, "    call getenv ; invoke libc getenv")?;
+        writeln!(self.out, "    test rax, rax")?;
+        writeln!(self.out, "    jz {}", missing_label)?;
+        writeln!(self.out, "    mov rdi, rax")?;
         writeln!(
             self.out,
-            "    {opcode} xmm0, xmm1 ; {comment}",
-            opcode = opcode,
-            comment = comment
+            "    call {} ; copy the value",
+            AirRuntimeHelper::CStrToStrPtr.name()
         )?;
-        writeln!(self.out, "    movq rax, xmm0 ; move float result to rax")?;
-        self.emit_value_jump(target, true)?;
-        Ok(())
+        self.emit_releases(&op.release, &[&op.missing_target], true)?;
+        self.emit_value_jump(&op.found_target, true)?;
+
+        writeln!(self.out, "{}:", missing_label)?;
+        self.emit_releases(&op.release, &[&op.found_target], false)?;
+        self.emit_value_jump(&op.missing_target, false)
     }
 
-    fn emit_libc_op(
-        &mut self,
-        builtin: builtins::Builtin,
-        args: &[AirArg],
-        arg_kinds: &[SigKind],
-        target: &str,
-    ) -> Result<(), Error> {
-        let has_result = self.emit_libc_call(builtin, args, arg_kinds)?;
-        self.emit_value_jump(target, has_result)?;
+    /// Loads the errno a failed libc call left into rax.
+    fn emit_load_errno(&mut self) -> Result<(), Error> {
+        writeln!(self.out, "    call {ERRNO_LOCATION}")?;
+        writeln!(self.out, "    mov eax, dword [rax] ; errno")?;
         Ok(())
     }
 
     fn emit_value_jump(&mut self, target: &str, has_result: bool) -> Result<(), Error> {
+        let results: &[&str] = if has_result { &["rax"] } else { &[] };
+        self.emit_values_jump(target, results)
+    }
+
+    /// Jumps to the continuation `target` with the values in `regs` as its
+    /// arguments, in order.
+    fn emit_values_jump(&mut self, target: &str, regs: &[&str]) -> Result<(), Error> {
         let binding = self.frame.binding(target).cloned().ok_or_else(|| {
             Error::new(
                 Code::Codegen,
@@ -703,8 +1961,8 @@ impl<'a, W: Write> FunctionEmitter<'a, W> {
             CLOSURE_ENV_REG,
             binding.slot_addr(0)
         )?;
-        if has_result {
-            self.store_at(CLOSURE_ENV_REG, -1, "rax")?;
+        for (idx, reg) in regs.iter().enumerate() {
+            self.store_at(CLOSURE_ENV_REG, -((regs.len() - idx) as isize), reg)?;
         }
         writeln!(
             self.out,
@@ -759,11 +2017,14 @@ impl<'a, W: Write> FunctionEmitter<'a, W> {
         Ok(())
     }
 
-    fn emit_exit_syscall(&mut self, _syscall: &AirSysExit) -> Result<(), Error> {
+    fn emit_exit_syscall(&mut self, syscall: &AirSysExit) -> Result<(), Error> {
         let (first_comment, _, _) = Self::exit_syscall_comments();
         writeln!(self.out, "    ; {}", first_comment)?;
+        match syscall.args.first() {
+            Some(code) => self.load_arg_into_reg(code, "rdi")?,
+            None => writeln!(self.out, "    mov rdi, 0 ; exit code")?,
+        }
         // Call libc exit() instead of raw exit syscall to ensure stdout is flushed
-        writeln!(self.out, "    mov rdi, 0 ; exit code")?;
         writeln!(self.out, "    call exit ; call libc exit to flush buffers")?;
         self.terminated = true;
         Ok(())
@@ -813,6 +2074,7 @@ impl<'a, W: Write> FunctionEmitter<'a, W> {
 
     fn emit_copy_field(&mut self, field: &AirField) -> Result<(), Error> {
         let field_addr = self.env_field_operand(CLOSURE_ENV_REG, field.offset);
+        let helper = copy_helper(&field.kind);
         writeln!(
             self.out,
             "    mov rcx, [{}] ; load field pointer",
@@ -825,7 +2087,7 @@ impl<'a, W: Write> FunctionEmitter<'a, W> {
         writeln!(
             self.out,
             "    call {} ; duplicate heap pointer",
-            AirRuntimeHelper::DeepCopyHeapPtr.name()
+            helper.name()
         )?;
         writeln!(
             self.out,
@@ -838,13 +2100,7 @@ impl<'a, W: Write> FunctionEmitter<'a, W> {
 
     fn emit_call_ptr(&mut self, call: &AirCallPtr) -> Result<(), Error> {
         let AirCallPtrTarget::Binding(name) = &call.target;
-        self.load_value_into_reg(&AirValue::Binding(name.clone()), "rdi")?;
-        writeln!(
-            self.out,
-            "    call {} ; release heap pointer",
-            AirRuntimeHelper::ReleaseHeapPtr.name()
-        )?;
-        Ok(())
+        self.emit_release_closure(name)
     }
 
     fn emit_label(&mut self, label: &AirLabel) -> Result<(), Error> {
@@ -894,15 +2150,28 @@ impl<'a, W: Write> FunctionEmitter<'a, W> {
         self.load_arg_into_reg(&args[1], "rbx")?;
         writeln!(self.out, "    mov r10, rax ; load first string pointer")?;
         writeln!(self.out, "    mov r11, rbx ; load second string pointer")?;
+        writeln!(
+            self.out,
+            "    mov rcx, [r10-{}] ; first string length",
+            STR_LEN_OFFSET
+        )?;
+        writeln!(
+            self.out,
+            "    mov rdx, [r11-{}] ; second string length",
+            STR_LEN_OFFSET
+        )?;
+        writeln!(self.out, "    cmp rcx, rdx")?;
+        writeln!(self.out, "    jne {} ; lengths differ", false_label)?;
 
         let loop_label = self.new_label("eqs_loop");
         writeln!(self.out, "{}:", loop_label)?;
+        writeln!(self.out, "    test rcx, rcx")?;
+        writeln!(self.out, "    je {} ; every byte matched", true_label)?;
         writeln!(self.out, "    mov al, byte [r10]")?;
         writeln!(self.out, "    mov dl, byte [r11]")?;
         writeln!(self.out, "    cmp al, dl")?;
         writeln!(self.out, "    jne {} ; bytes differ", false_label)?;
-        writeln!(self.out, "    test al, al")?;
-        writeln!(self.out, "    je {}", true_label)?;
+        writeln!(self.out, "    dec rcx")?;
         writeln!(self.out, "    inc r10")?;
         writeln!(self.out, "    inc r11")?;
         writeln!(self.out, "    jmp {}", loop_label)?;
@@ -1102,39 +2371,16 @@ impl<'a, W: Write> FunctionEmitter<'a, W> {
         let env_size = kinds.len() * WORD_SIZE;
         let heap_size = env_size + ENV_METADATA_SIZE;
 
-        self.emit_mmap(heap_size)?;
+        self.emit_alloc(heap_size)?;
         writeln!(self.out, "    mov rbx, rax ; closure env base pointer")?;
 
-        let mut offset_words = 0usize;
-        for (arg, kind) in args.iter().zip(kinds.iter()) {
+        for (idx, arg) in args.iter().take(kinds.len()).enumerate() {
             self.load_arg_into_reg(arg, "rax")?;
-            let kind_words = 1;
-            if kind_words == 0 {
-                continue;
-            }
-            let offset_bytes = offset_words * WORD_SIZE;
-            if matches!(*kind, SigKind::Sig(_)) {
-                writeln!(
-                    self.out,
-                    "    mov {}, rax ; shadow closure env_end pointer",
-                    CLOSURE_ENV_REG
-                )?;
-                writeln!(self.out, "    push rbx ; save env base pointer")?;
-                self.clone_closure_argument()?;
-                writeln!(self.out, "    pop rbx ; restore env base pointer")?;
-                writeln!(
-                    self.out,
-                    "    mov [rbx+{}], {} ; capture cloned closure pointer",
-                    offset_bytes, CLOSURE_ENV_REG
-                )?;
-            } else {
-                writeln!(
-                    self.out,
-                    "    mov [rbx+{}], rax ; capture arg into env",
-                    offset_bytes
-                )?;
-            }
-            offset_words += kind_words;
+            writeln!(
+                self.out,
+                "    mov [rbx+{}], rax ; capture arg into env",
+                idx * WORD_SIZE
+            )?;
         }
 
         writeln!(
@@ -1274,9 +2520,9 @@ impl<'a, W: Write> FunctionEmitter<'a, W> {
                 }
 
                 self.prepare_args(args)?;
-                let arg_split = self.move_args_to_registers(arg_kinds)?;
-                self.emit_variadic_libc_call(builtin.name())?;
-                self.cleanup_libc_stack(arg_split.stack_bytes)?;
+                writeln!(self.out, "    mov r13, rsp ; printf args in order")?;
+                self.emit_variadic_call("printf", arg_kinds, 0)?;
+                self.cleanup_libc_stack(args.len() * WORD_SIZE)?;
 
                 Ok(false)
             }
@@ -1290,37 +2536,42 @@ impl<'a, W: Write> FunctionEmitter<'a, W> {
                 }
 
                 self.prepare_args(args)?;
-
-                self.emit_mmap(FMT_BUFFER_SIZE)?;
-                writeln!(self.out, "    mov rbx, rax ; keep sprintf buffer pointer")?;
-                let arg_split = self.move_args_to_registers(arg_kinds)?;
-                if arg_split.reg_slots == ARG_REGS.len() {
-                    return Err(Error::new(
-                        Code::Codegen,
-                        "sprintf requires at least one register slot for the buffer pointer",
-                        Span::unknown(),
-                    ));
-                }
-                for i in (0..arg_split.reg_slots).rev() {
-                    let dest = ARG_REGS[i + 1];
-                    let src = ARG_REGS[i];
-                    writeln!(
-                        self.out,
-                        "    mov {}, {} ; shift sprintf args for buffer insertion",
-                        dest, src
-                    )?;
-                }
-
+                writeln!(self.out, "    mov r13, rsp ; sprintf args in order")?;
+                writeln!(self.out, "    xor rdi, rdi ; measure only")?;
+                writeln!(self.out, "    xor rsi, rsi")?;
+                self.emit_variadic_call("snprintf", arg_kinds, 2)?;
+                writeln!(self.out, "    mov rbx, rax ; formatted length")?;
                 writeln!(
                     self.out,
-                    "    mov rdi, rbx ; destination buffer for sprintf"
+                    "    lea rdi, [rbx+{}] ; header, bytes and terminator",
+                    STR_HEADER_SIZE + 1
                 )?;
-                self.emit_variadic_libc_call(builtin.name())?;
                 writeln!(
                     self.out,
-                    "    mov rax, rbx ; return formatted string pointer"
+                    "    call {} ; allocate the string",
+                    AirRuntimeHelper::AllocHeapPtr.name()
                 )?;
-                self.cleanup_libc_stack(arg_split.stack_bytes)?;
+                writeln!(self.out, "    mov r14, rax ; string block")?;
+                writeln!(self.out, "    lea rax, [rbx+{}]", STR_HEADER_SIZE + 1)?;
+                writeln!(self.out, "    mov [r14], rax ; allocation size header")?;
+                writeln!(
+                    self.out,
+                    "    mov [r14+{}], rbx ; length header",
+                    STR_HEADER_SIZE - STR_LEN_OFFSET
+                )?;
+                writeln!(
+                    self.out,
+                    "    lea rdi, [r14+{}] ; string bytes follow the header",
+                    STR_HEADER_SIZE
+                )?;
+                writeln!(self.out, "    lea rsi, [rbx+1] ; room for the terminator")?;
+                self.emit_variadic_call("snprintf", arg_kinds, 2)?;
+                writeln!(
+                    self.out,
+                    "    lea rax, [r14+{}] ; return formatted string pointer",
+                    STR_HEADER_SIZE
+                )?;
+                self.cleanup_libc_stack(args.len() * WORD_SIZE)?;
 
                 Ok(true)
             }
@@ -1336,22 +2587,12 @@ impl<'a, W: Write> FunctionEmitter<'a, W> {
                 self.prepare_args(args)?;
                 let arg_split = self.move_args_to_registers(arg_kinds)?;
 
-                writeln!(self.out, "    mov r8, rdi ; keep string pointer")?;
-                writeln!(self.out, "    xor rcx, rcx ; reset length counter")?;
-                let (loop_label, done_label) = self.next_write_loop_labels();
-                writeln!(self.out, "{}:", loop_label)?;
                 writeln!(
                     self.out,
-                    "    mov dl, byte [r8+rcx] ; load current character"
+                    "    mov rdx, [rdi-{}] ; length to write",
+                    STR_LEN_OFFSET
                 )?;
-                writeln!(self.out, "    cmp dl, 0 ; stop at terminator")?;
-                writeln!(self.out, "    je {}", done_label)?;
-                writeln!(self.out, "    inc rcx ; advance char counter")?;
-                writeln!(self.out, "    jmp {}", loop_label)?;
-                writeln!(self.out, "{}:", done_label)?;
-
-                writeln!(self.out, "    mov rdx, rcx ; length to write")?;
-                writeln!(self.out, "    mov rsi, r8 ; buffer start")?;
+                writeln!(self.out, "    mov rsi, rdi ; buffer start")?;
                 writeln!(self.out, "    mov rdi, 1 ; stdout fd")?;
 
                 writeln!(self.out, "    call write ; invoke libc write")?;
@@ -1359,6 +2600,28 @@ impl<'a, W: Write> FunctionEmitter<'a, W> {
 
                 Ok(false)
             }
+            builtins::Builtin::WriteFd => {
+                if args.len() < 2 {
+                    return Err(Error::new(
+                        Code::Codegen,
+                        "writefd requires an fd and a buffer before the continuation",
+                        Span::unknown(),
+                    ));
+                }
+
+                self.prepare_args(args)?;
+                let arg_split = self.move_args_to_registers(arg_kinds)?;
+
+                writeln!(
+                    self.out,
+                    "    mov rdx, [rsi-{}] ; length to write",
+                    STR_LEN_OFFSET
+                )?;
+                writeln!(self.out, "    call write ; invoke libc write")?;
+                self.cleanup_libc_stack(arg_split.stack_bytes)?;
+
+                Ok(false)
+            }
             _ => Err(Error::new(
                 Code::Codegen,
                 format!("unsupported libc call '{}'", builtin.name()),
@@ -1378,6 +2641,74 @@ impl<'a, W: Write> FunctionEmitter<'a, W> {
         Ok(())
     }
 
+    /// Calls the variadic libc function `name` with the words at r13 as its
+    /// arguments after the first `first_reg` integer registers, which the
+    /// caller fills. f64 words go in xmm registers, with their count in `al`;
+    /// the words stay in place for another pass.
+    fn emit_variadic_call(
+        &mut self,
+        name: &str,
+        kinds: &[SigKind],
+        first_reg: usize,
+    ) -> Result<(), Error> {
+        let mut int_regs = ARG_REGS[first_reg..].iter();
+        let mut xmm_count = 0;
+        let mut loads = Vec::new();
+        let mut stack = Vec::new();
+        for (idx, kind) in kinds.iter().enumerate() {
+            let load = match kind {
+                SigKind::F64 if xmm_count < VARIADIC_FLOAT_REGS => {
+                    xmm_count += 1;
+                    Some(("movsd", format!("xmm{}", xmm_count - 1)))
+                }
+                SigKind::F64 => None,
+                _ => int_regs.next().map(|reg| ("mov", reg.to_string())),
+            };
+            match load {
+                Some((opcode, reg)) => loads.push((opcode, reg, idx)),
+                None => stack.push(idx),
+            }
+        }
+
+        writeln!(self.out, "    mov r15, rsp ; restore point after {}", name)?;
+        if !stack.is_empty() {
+            writeln!(
+                self.out,
+                "    sub rsp, {} ; room for stack args",
+                stack.len() * WORD_SIZE
+            )?;
+        }
+        writeln!(self.out, "    and rsp, -16 ; align stack for variadic call")?;
+        for (slot, idx) in stack.iter().enumerate() {
+            writeln!(
+                self.out,
+                "    mov rax, [r13+{}] ; stack arg {}",
+                idx * WORD_SIZE,
+                slot
+            )?;
+            writeln!(self.out, "    mov [rsp+{}], rax", slot * WORD_SIZE)?;
+        }
+        for (opcode, reg, idx) in loads {
+            writeln!(
+                self.out,
+                "    {} {}, [r13+{}] ; {} arg {}",
+                opcode,
+                reg,
+                idx * WORD_SIZE,
+                name,
+                idx
+            )?;
+        }
+        writeln!(
+            self.out,
+            "    mov eax, {} ; vector registers holding args",
+            xmm_count
+        )?;
+        writeln!(self.out, "    call {} ; invoke libc {}", name, name)?;
+        writeln!(self.out, "    mov rsp, r15")?;
+        Ok(())
+    }
+
     fn emit_variadic_libc_call(&mut self, name: &str) -> Result<(), Error> {
         writeln!(self.out, "    push rbp ; helper prologue")?;
         writeln!(self.out, "    mov rbp, rsp")?;
@@ -1396,30 +2727,17 @@ impl<'a, W: Write> FunctionEmitter<'a, W> {
         Ok(())
     }
 
-    fn emit_mmap(&mut self, size: usize) -> Result<(), Error> {
-        writeln!(self.out, "    mov rax, {} ; mmap syscall", SYSCALL_MMAP)?;
-        writeln!(
-            self.out,
-            "    xor rdi, rdi ; addr hint for kernel base selection"
-        )?;
+    fn emit_alloc(&mut self, size: usize) -> Result<(), Error> {
         writeln!(
             self.out,
-            "    mov rsi, {} ; length for allocation",
+            "    mov rdi, {} ; length for allocation",
             size.max(1)
         )?;
         writeln!(
             self.out,
-            "    mov rdx, {} ; prot = read/write",
-            PROT_READ | PROT_WRITE
-        )?;
-        writeln!(
-            self.out,
-            "    mov r10, {} ; flags: private & anonymous",
-            MAP_PRIVATE | MAP_ANONYMOUS
+            "    call {} ; allocate env block",
+            AirRuntimeHelper::AllocHeapPtr.name()
         )?;
-        writeln!(self.out, "    mov r8, -1 ; fd = -1")?;
-        writeln!(self.out, "    xor r9, r9 ; offset = 0")?;
-        writeln!(self.out, "    syscall ; allocate env pages")?;
         Ok(())
     }
 
@@ -1446,10 +2764,7 @@ impl<'a, W: Write> FunctionEmitter<'a, W> {
                 stack_bytes += WORD_SIZE;
             }
         }
-        Ok(ArgSplit {
-            reg_slots: slot,
-            stack_bytes,
-        })
+        Ok(ArgSplit { stack_bytes })
     }
 
     fn emit_clone_env_from_env_end(
@@ -1480,25 +2795,12 @@ impl<'a, W: Write> FunctionEmitter<'a, W> {
             self.out,
             "    sub r12, r13 ; env base pointer for clone source"
         )?;
-        writeln!(self.out, "    mov rax, {} ; mmap syscall", SYSCALL_MMAP)?;
-        writeln!(
-            self.out,
-            "    xor rdi, rdi ; addr hint for kernel base selection"
-        )?;
-        writeln!(self.out, "    mov rsi, r14 ; length for cloned environment")?;
-        writeln!(
-            self.out,
-            "    mov rdx, {} ; prot = read/write",
-            PROT_READ | PROT_WRITE
-        )?;
+        writeln!(self.out, "    mov rdi, r14 ; length for cloned environment")?;
         writeln!(
             self.out,
-            "    mov r10, {} ; flags: private & anonymous",
-            MAP_PRIVATE | MAP_ANONYMOUS
+            "    call {} ; allocate cloned env block",
+            AirRuntimeHelper::AllocHeapPtr.name()
         )?;
-        writeln!(self.out, "    mov r8, -1 ; fd = -1")?;
-        writeln!(self.out, "    xor r9, r9 ; offset = 0")?;
-        writeln!(self.out, "    syscall ; allocate cloned env pages")?;
         writeln!(
             self.out,
             "    mov r15, rax ; cloned closure env base pointer"
@@ -1548,26 +2850,6 @@ impl<'a, W: Write> FunctionEmitter<'a, W> {
         Ok(())
     }
 
-    fn clone_closure_argument(&mut self) -> Result<(), Error> {
-        self.emit_clone_env_from_env_end(CLOSURE_ENV_REG, CLOSURE_ENV_REG)?;
-        writeln!(
-            self.out,
-            "    mov rax, {} ; copy closure env_end to rax",
-            CLOSURE_ENV_REG
-        )?;
-        Ok(())
-    }
-
-    fn next_write_loop_labels(&mut self) -> (String, String) {
-        let idx = self.write_loop_counter;
-        self.write_loop_counter += 1;
-        let prefix = crate::sanitize_function_name(&self.air.sig.name);
-        (
-            format!("{}_write_strlen_loop_{}", prefix, idx),
-            format!("{}_write_strlen_done_{}", prefix, idx),
-        )
-    }
-
     fn new_label(&mut self, suffix: &str) -> String {
         let idx = self.label_counter;
         self.label_counter += 1;
@@ -1580,6 +2862,56 @@ impl<'a, W: Write> FunctionEmitter<'a, W> {
     }
 }
 
+impl<W: Write> ReleaseCopy for FunctionEmitter<'_, W> {
+    fn call_helper_on(
+        &mut self,
+        helper: AirRuntimeHelper,
+        name: &str,
+        note: &str,
+    ) -> Result<(), Error> {
+        self.load_value_into_reg(&AirValue::Binding(name.to_string()), "rdi")?;
+        writeln!(self.out, "    call {} ; {}", helper.name(), note)?;
+        Ok(())
+    }
+
+    fn store_helper_result(&mut self, dst: &str) -> Result<(), Error> {
+        self.store_binding_value(dst)
+    }
+
+    fn keep_result(&mut self) -> Result<(), Error> {
+        writeln!(self.out, "    mov rbx, rax ; keep result across releases")?;
+        Ok(())
+    }
+
+    fn restore_result(&mut self) -> Result<(), Error> {
+        writeln!(self.out, "    mov rax, rbx ; restore result")?;
+        Ok(())
+    }
+
+    fn emit_release_closure(&mut self, name: &str) -> Result<(), Error> {
+        self.load_value_into_reg(&AirValue::Binding(name.to_string()), "rdi")?;
+        writeln!(
+            self.out,
+            "    push {} ; keep the pinned env across the helper",
+            CLOSURE_ENV_REG
+        )?;
+        writeln!(self.out, "    push rbx ; and a kept result")?;
+        writeln!(
+            self.out,
+            "    mov rax, [rdi+{}] ; load {} release helper",
+            ENV_METADATA_RELEASE_OFFSET, name
+        )?;
+        writeln!(
+            self.out,
+            "    call rax ; release {} and what it holds",
+            name
+        )?;
+        writeln!(self.out, "    pop rbx")?;
+        writeln!(self.out, "    pop {}", CLOSURE_ENV_REG)?;
+        Ok(())
+    }
+}
+
 fn align_to(value: usize, align: usize) -> usize {
     if value == 0 {
         return 0;
@@ -0,0 +1,215 @@
+use std::error::Error as StdError;
+use std::fmt::{self, Display, Formatter, Write as _};
+use std::io;
+
+use crate::compiler::error::{Code, Error};
+use crate::compiler::source_map::SourceMap;
+use crate::compiler::span::Span;
+
+/// Every independent error reported by a single compile run, in source order.
+#[derive(Debug, Clone, Default)]
+pub struct Diagnostics {
+    errors: Vec<Error>,
+}
+
+impl Diagnostics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, err: Error) {
+        self.errors.push(err);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    pub fn errors(&self) -> &[Error] {
+        &self.errors
+    }
+
+    pub fn into_errors(self) -> Vec<Error> {
+        self.errors
+    }
+
+    /// Io errors leave the input in an unknown state, so nothing after them is reported.
+    pub fn has_fatal(&self) -> bool {
+        self.errors.iter().any(|err| err.code == Code::Io)
+    }
+
+    pub fn render(&self, sources: &SourceMap) -> String {
+        let mut out = String::new();
+        for err in &self.errors {
+            out.push_str(&render(err, sources));
+            out.push('\n');
+        }
+        let count = self.errors.len();
+        if count > 1 {
+            let _ = writeln!(out, "aborting due to {count} previous errors");
+        }
+        out
+    }
+}
+
+impl From<Error> for Diagnostics {
+    fn from(err: Error) -> Self {
+        Self { errors: vec![err] }
+    }
+}
+
+impl From<io::Error> for Diagnostics {
+    fn from(err: io::Error) -> Self {
+        Error::from(err).into()
+    }
+}
+
+impl Display for Diagnostics {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (idx, err) in self.errors.iter().enumerate() {
+            if idx > 0 {
+                f.write_str("\n")?;
+            }
+            write!(f, "{err}")?;
+        }
+        Ok(())
+    }
+}
+
+impl StdError for Diagnostics {}
+
+/// Renders one error rustc-style: a header, the offending source line with the
+/// span underlined, then any notes and help.
+pub fn render(err: &Error, sources: &SourceMap) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "error[{}]: {}", err.code, err.message);
+
+    let span = err.span;
+    let line_text = (!span.is_unknown())
+        .then(|| sources.get(span.file)?.line(span.line))
+        .flatten();
+    let gutter = " ".repeat(span.line.to_string().len());
+    if let Some(text) = line_text {
+        let path = sources.name(span.file);
+        let _ = writeln!(out, "{gutter}--> {path}:{}:{}", span.line, span.column);
+        let _ = writeln!(out, "{gutter} |");
+        let _ = writeln!(out, "{} | {}", span.line, text);
+        let column = span.column.max(1) - 1;
+        let padding: String = text
+            .chars()
+            .take(column)
+            .map(|ch| if ch == '\t' { '\t' } else { ' ' })
+            .collect();
+        let width = underline_width(text, column, span);
+        let _ = writeln!(out, "{gutter} | {padding}{}", "^".repeat(width));
+    }
+
+    for note in &err.notes {
+        let _ = writeln!(out, "{gutter} = note: {note}");
+    }
+    if let Some(help) = &err.help {
+        let _ = writeln!(out, "{gutter} = help: {help}");
+    }
+    out
+}
+
+// Spans measure bytes but columns count chars, so the width is the number of
+// chars the span covers. Ranges that run past the line are cut at its end.
+// Empty spans come from positions without a known extent, so they underline the
+// token found there.
+fn underline_width(line: &str, column: usize, span: Span) -> usize {
+    if !span.is_empty() {
+        let mut covered = 0;
+        let width = line
+            .chars()
+            .skip(column)
+            .take_while(|ch| {
+                let inside = covered < span.len();
+                covered += ch.len_utf8();
+                inside
+            })
+            .count();
+        return width.max(1);
+    }
+
+    let rest: Vec<char> = line.chars().skip(column).collect();
+    let Some(&first) = rest.first() else {
+        return 1;
+    };
+    let is_word = |ch: char| ch.is_ascii_alphanumeric() || ch == '_';
+    let width = match first {
+        '"' | '\'' => rest[1..]
+            .iter()
+            .position(|&ch| ch == first)
+            .map(|end| end + 2)
+            .unwrap_or(rest.len()),
+        '@' => 1 + rest[1..].iter().take_while(|&&ch| is_word(ch)).count(),
+        ch if is_word(ch) => rest.iter().take_while(|&&ch| is_word(ch)).count(),
+        _ => 1,
+    };
+    width.max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sources(source: &str) -> SourceMap {
+        let mut sources = SourceMap::new();
+        sources.add("main.rgo", source);
+        sources
+    }
+
+    #[test]
+    fn render_underlines_identifier() {
+        let sources = sources("main: () {\n    printf(\"hi\", exit(0))\n}\n");
+        let err = Error::new(Code::HIR, "`printf` is not defined", Span::new(2, 5, 15))
+            .with_help("import it with `printf: @printf`");
+        assert_eq!(
+            render(&err, &sources),
+            "error[hir]: `printf` is not defined\n \
+             --> main.rgo:2:5\n  \
+             |\n\
+             2 |     printf(\"hi\", exit(0))\n  \
+             |     ^^^^^^\n  \
+             = help: import it with `printf: @printf`\n"
+        );
+    }
+
+    #[test]
+    fn render_underlines_whole_range() {
+        let sources = sources("main: () {\n    foo(1, 2)\n}\n");
+        let span = Span::new(2, 5, 15).to(Span::new(2, 14, 24));
+        let err = Error::new(Code::HIR, "too many arguments", span);
+        assert!(
+            render(&err, &sources).contains("2 |     foo(1, 2)\n  |     ^^^^^^^^^\n"),
+            "unexpected rendering: {}",
+            render(&err, &sources)
+        );
+    }
+
+    #[test]
+    fn render_counts_range_in_chars() {
+        let sources = sources("main: () {\n    foo(\"héllo\")\n}\n");
+        let span = Span::new(2, 9, 19).to(Span::new(2, 16, 27));
+        let err = Error::new(Code::HIR, "wrong type", span);
+        assert!(
+            render(&err, &sources).contains("2 |     foo(\"héllo\")\n  |         ^^^^^^^\n"),
+            "unexpected rendering: {}",
+            render(&err, &sources)
+        );
+    }
+
+    #[test]
+    fn render_without_position_skips_snippet() {
+        let err = Error::new(Code::Io, "broken pipe", Span::unknown()).with_note("while reading");
+        assert_eq!(
+            render(&err, &sources("main: () {}\n")),
+            "error[io]: broken pipe\n  = note: while reading\n"
+        );
+    }
+}
@@ -0,0 +1,610 @@
+//! Writes an assembled `Object` as an ELF64 relocatable object or executable.
+//!
+//! Executables that call into libc are dynamically linked: every extern gets a
+//! GOT slot that the dynamic linker fills in at load time and a `jmp [slot]`
+//! stub that calls are pointed at. Programs without externs are written as
+//! static executables.
+
+use std::collections::HashMap;
+
+use crate::compiler::assembler::{Object, RelocKind, Section};
+use crate::compiler::error::{Code, Error};
+use crate::compiler::span::Span;
+
+const DYNAMIC_LINKER: &[u8] = b"/lib64/ld-linux-x86-64.so.2\0";
+const LIBC: &str = "libc.so.6";
+const BASE: u64 = 0x40_0000;
+const PAGE: u64 = 0x1000;
+
+const EHDR_SIZE: usize = 64;
+const PHDR_SIZE: usize = 56;
+const SHDR_SIZE: usize = 64;
+const SYM_SIZE: usize = 24;
+const RELA_SIZE: usize = 24;
+const DYN_SIZE: usize = 16;
+const STUB_SIZE: usize = 8;
+
+const ET_REL: u16 = 1;
+const ET_EXEC: u16 = 2;
+const EM_X86_64: u16 = 62;
+
+const SHT_PROGBITS: u32 = 1;
+const SHT_SYMTAB: u32 = 2;
+const SHT_STRTAB: u32 = 3;
+const SHT_RELA: u32 = 4;
+const SHF_WRITE: u64 = 1;
+const SHF_ALLOC: u64 = 2;
+const SHF_EXECINSTR: u64 = 4;
+const SHF_INFO_LINK: u64 = 0x40;
+
+const PT_LOAD: u32 = 1;
+const PT_DYNAMIC: u32 = 2;
+const PT_INTERP: u32 = 3;
+const PT_PHDR: u32 = 6;
+const PT_GNU_STACK: u32 = 0x6474_e551;
+const PF_X: u32 = 1;
+const PF_W: u32 = 2;
+const PF_R: u32 = 4;
+
+const STB_LOCAL: u8 = 0;
+const STB_GLOBAL: u8 = 1;
+const STT_NOTYPE: u8 = 0;
+const STT_FUNC: u8 = 2;
+const STT_SECTION: u8 = 3;
+
+const R_X86_64_PC32: u32 = 2;
+const R_X86_64_GLOB_DAT: u32 = 6;
+const R_X86_64_PLT32: u32 = 4;
+
+const DT_NULL: u64 = 0;
+const DT_NEEDED: u64 = 1;
+const DT_HASH: u64 = 4;
+const DT_STRTAB: u64 = 5;
+const DT_SYMTAB: u64 = 6;
+const DT_RELA: u64 = 7;
+const DT_RELASZ: u64 = 8;
+const DT_RELAENT: u64 = 9;
+const DT_STRSZ: u64 = 10;
+const DT_SYMENT: u64 = 11;
+const DT_DEBUG: u64 = 21;
+
+const SECTIONS: [(Section, &str); 3] = [
+    (Section::Text, ".text"),
+    (Section::Rodata, ".rodata"),
+    (Section::Data, ".data"),
+];
+
+/// Writes `object` as a relocatable object that the system linker accepts.
+pub fn write_relocatable(object: &Object) -> Vec<u8> {
+    let mut shstrtab = StringTable::default();
+    let mut headers = vec![SectionHeader::default()];
+    let mut contents: Vec<Vec<u8>> = vec![Vec::new()];
+    let mut add = |header: SectionHeader, data: Vec<u8>, headers: &mut Vec<SectionHeader>| {
+        headers.push(header);
+        contents.push(data);
+        headers.len() - 1
+    };
+
+    let mut section_index = HashMap::new();
+    for (section, name) in SECTIONS {
+        let flags = match section {
+            Section::Text => SHF_ALLOC | SHF_EXECINSTR,
+            Section::Rodata => SHF_ALLOC,
+            Section::Data => SHF_ALLOC | SHF_WRITE,
+        };
+        let header = SectionHeader {
+            name: shstrtab.add(name),
+            kind: SHT_PROGBITS,
+            flags,
+            align: 16,
+            ..SectionHeader::default()
+        };
+        let index = add(header, object.section(section).to_vec(), &mut headers);
+        section_index.insert(section, index);
+    }
+
+    // Locals come first: section symbols, then labels, then globals and externs.
+    let mut strtab = StringTable::default();
+    let mut symtab = vec![0; SYM_SIZE];
+    let mut symbol_index = HashMap::new();
+    let mut count = 1;
+    for (section, _) in SECTIONS {
+        push_symbol(
+            &mut symtab,
+            0,
+            STB_LOCAL,
+            STT_SECTION,
+            section_index[&section] as u16,
+            0,
+        );
+        count += 1;
+    }
+    let (globals, locals): (Vec<_>, Vec<_>) =
+        object.symbols.iter().partition(|symbol| symbol.global);
+    for symbol in locals.iter().chain(&globals) {
+        let bind = if symbol.global { STB_GLOBAL } else { STB_LOCAL };
+        push_symbol(
+            &mut symtab,
+            strtab.add(&symbol.name),
+            bind,
+            STT_NOTYPE,
+            section_index[&symbol.section] as u16,
+            symbol.offset as u64,
+        );
+        symbol_index.insert(symbol.name.as_str(), count);
+        count += 1;
+    }
+    let first_global = 1 + SECTIONS.len() + locals.len();
+    for name in &object.externs {
+        push_symbol(&mut symtab, strtab.add(name), STB_GLOBAL, STT_NOTYPE, 0, 0);
+        symbol_index.insert(name.as_str(), count);
+        count += 1;
+    }
+
+    let mut rela_sections = Vec::new();
+    for (section, name) in SECTIONS {
+        let mut rela = Vec::new();
+        for reloc in object
+            .relocations
+            .iter()
+            .filter(|reloc| reloc.section == section)
+        {
+            let kind = match reloc.kind {
+                RelocKind::Pc32 => R_X86_64_PC32,
+                RelocKind::Plt32 => R_X86_64_PLT32,
+            };
+            let symbol = symbol_index[reloc.target.as_str()] as u64;
+            push_rela(&mut rela, reloc.offset as u64, symbol, kind, reloc.addend);
+        }
+        if !rela.is_empty() {
+            rela_sections.push((section, name, rela));
+        }
+    }
+    let symtab_index = headers.len() + rela_sections.len();
+    for (section, name, rela) in rela_sections {
+        let header = SectionHeader {
+            name: shstrtab.add(&format!(".rela{name}")),
+            kind: SHT_RELA,
+            flags: SHF_INFO_LINK,
+            link: symtab_index as u32,
+            info: section_index[&section] as u32,
+            align: 8,
+            entsize: RELA_SIZE as u64,
+            ..SectionHeader::default()
+        };
+        add(header, rela, &mut headers);
+    }
+
+    let strtab_index = symtab_index + 1;
+    let symtab_header = SectionHeader {
+        name: shstrtab.add(".symtab"),
+        kind: SHT_SYMTAB,
+        link: strtab_index as u32,
+        info: first_global as u32,
+        align: 8,
+        entsize: SYM_SIZE as u64,
+        ..SectionHeader::default()
+    };
+    add(symtab_header, symtab, &mut headers);
+    let strtab_header = SectionHeader {
+        name: shstrtab.add(".strtab"),
+        kind: SHT_STRTAB,
+        align: 1,
+        ..SectionHeader::default()
+    };
+    add(strtab_header, strtab.bytes, &mut headers);
+    // An empty .note.GNU-stack keeps the linker from making the stack executable.
+    let note_header = SectionHeader {
+        name: shstrtab.add(".note.GNU-stack"),
+        kind: SHT_PROGBITS,
+        align: 1,
+        ..SectionHeader::default()
+    };
+    add(note_header, Vec::new(), &mut headers);
+    let shstrtab_index = headers.len();
+    let shstrtab_header = SectionHeader {
+        name: shstrtab.add(".shstrtab"),
+        kind: SHT_STRTAB,
+        align: 1,
+        ..SectionHeader::default()
+    };
+    add(shstrtab_header, shstrtab.bytes, &mut headers);
+
+    let mut out = vec![0; EHDR_SIZE];
+    for (header, data) in headers.iter_mut().zip(&contents).skip(1) {
+        pad_to(&mut out, header.align.max(1) as usize);
+        header.offset = out.len() as u64;
+        header.size = data.len() as u64;
+        out.extend_from_slice(data);
+    }
+    pad_to(&mut out, 8);
+    let shoff = out.len() as u64;
+    for header in &headers {
+        header.write(&mut out);
+    }
+
+    let header = FileHeader {
+        kind: ET_REL,
+        entry: 0,
+        phoff: 0,
+        phnum: 0,
+        shoff,
+        shnum: headers.len() as u16,
+        shstrndx: shstrtab_index as u16,
+    };
+    header.write(&mut out[..EHDR_SIZE]);
+    out
+}
+
+/// Links `object` into an executable that starts at the global label `entry`.
+pub fn write_executable(object: &Object, entry: &str) -> Result<Vec<u8>, Error> {
+    let entry_symbol = object
+        .symbol(entry)
+        .filter(|symbol| symbol.section == Section::Text)
+        .ok_or_else(|| link_error(format!("entry point `{entry}` is not defined in .text")))?;
+    let dynamic = !object.externs.is_empty();
+    let phnum = if dynamic { 7 } else { 4 };
+
+    // Read-only segment: headers, dynamic linking tables and .rodata.
+    let mut image = vec![0; EHDR_SIZE + PHDR_SIZE * phnum];
+    let interp = image.len();
+    let mut dynstr = StringTable::default();
+    let needed = dynstr.add(LIBC);
+    let (mut hash, mut dynsym, mut rela_dyn) = (0, 0, 0);
+    if dynamic {
+        image.extend_from_slice(DYNAMIC_LINKER);
+
+        pad_to(&mut image, 8);
+        hash = image.len();
+        let nsyms = object.externs.len() as u32 + 1;
+        // One bucket with an empty chain: the executable exports nothing.
+        for word in [1, nsyms, 0] {
+            image.extend_from_slice(&word.to_le_bytes());
+        }
+        image.extend(std::iter::repeat_n(0, nsyms as usize * 4));
+
+        pad_to(&mut image, 8);
+        dynsym = image.len();
+        image.extend_from_slice(&[0; SYM_SIZE]);
+        for name in &object.externs {
+            push_symbol(&mut image, dynstr.add(name), STB_GLOBAL, STT_FUNC, 0, 0);
+        }
+    }
+    let dynstr_offset = image.len();
+    if dynamic {
+        image.extend_from_slice(&dynstr.bytes);
+        pad_to(&mut image, 8);
+        rela_dyn = image.len();
+        image.extend(std::iter::repeat_n(0, object.externs.len() * RELA_SIZE));
+    }
+    pad_to(&mut image, 16);
+    let rodata = image.len();
+    image.extend_from_slice(&object.rodata);
+    let ro_end = image.len();
+
+    // Executable segment: .text followed by one stub per extern.
+    pad_to(&mut image, PAGE as usize);
+    let text = image.len();
+    image.extend_from_slice(&object.text);
+    pad_to(&mut image, STUB_SIZE);
+    let stubs = image.len();
+    image.extend(std::iter::repeat_n(0, object.externs.len() * STUB_SIZE));
+    let text_end = image.len();
+
+    // Writable segment: .dynamic, the GOT and .data.
+    pad_to(&mut image, PAGE as usize);
+    let rw = image.len();
+    let dynamic_entries = 11;
+    let dynamic_table = image.len();
+    if dynamic {
+        image.extend(std::iter::repeat_n(0, dynamic_entries * DYN_SIZE));
+    }
+    let got = image.len();
+    image.extend(std::iter::repeat_n(0, object.externs.len() * 8));
+    pad_to(&mut image, 16);
+    let data = image.len();
+    image.extend_from_slice(&object.data);
+    let rw_end = image.len();
+
+    // The image is mapped at BASE as-is, so addresses are file offsets plus BASE.
+    let addr = |offset: usize| BASE + offset as u64;
+    let mut symbols: HashMap<&str, u64> = HashMap::new();
+    for symbol in &object.symbols {
+        let start = match symbol.section {
+            Section::Text => text,
+            Section::Rodata => rodata,
+            Section::Data => data,
+        };
+        symbols.insert(&symbol.name, addr(start + symbol.offset));
+    }
+    for (idx, name) in object.externs.iter().enumerate() {
+        symbols.insert(name, addr(stubs + idx * STUB_SIZE));
+
+        let stub = stubs + idx * STUB_SIZE;
+        let slot = got + idx * 8;
+        let disp = addr(slot) as i64 - addr(stub + 6) as i64;
+        image[stub..stub + 2].copy_from_slice(&[0xff, 0x25]);
+        image[stub + 2..stub + 6].copy_from_slice(&(disp as i32).to_le_bytes());
+
+        let mut rela = Vec::new();
+        push_rela(&mut rela, addr(slot), idx as u64 + 1, R_X86_64_GLOB_DAT, 0);
+        let at = rela_dyn + idx * RELA_SIZE;
+        image[at..at + RELA_SIZE].copy_from_slice(&rela);
+    }
+
+    for reloc in &object.relocations {
+        let start = match reloc.section {
+            Section::Text => text,
+            Section::Rodata => rodata,
+            Section::Data => data,
+        };
+        let field = start + reloc.offset;
+        let target = symbols[reloc.target.as_str()];
+        let value = target as i64 + reloc.addend - addr(field) as i64;
+        let value = i32::try_from(value)
+            .map_err(|_| link_error(format!("`{}` is out of rel32 range", reloc.target)))?;
+        image[field..field + 4].copy_from_slice(&value.to_le_bytes());
+    }
+
+    if dynamic {
+        let entries = [
+            (DT_NEEDED, u64::from(needed)),
+            (DT_HASH, addr(hash)),
+            (DT_STRTAB, addr(dynstr_offset)),
+            (DT_SYMTAB, addr(dynsym)),
+            (DT_STRSZ, dynstr.bytes.len() as u64),
+            (DT_SYMENT, SYM_SIZE as u64),
+            (DT_RELA, addr(rela_dyn)),
+            (DT_RELASZ, (object.externs.len() * RELA_SIZE) as u64),
+            (DT_RELAENT, RELA_SIZE as u64),
+            (DT_DEBUG, 0),
+            (DT_NULL, 0),
+        ];
+        debug_assert_eq!(entries.len(), dynamic_entries);
+        for (idx, (tag, value)) in entries.into_iter().enumerate() {
+            let at = dynamic_table + idx * DYN_SIZE;
+            image[at..at + 8].copy_from_slice(&tag.to_le_bytes());
+            image[at + 8..at + 16].copy_from_slice(&value.to_le_bytes());
+        }
+    }
+
+    let segment = |kind, flags, start: usize, end: usize, align| ProgramHeader {
+        kind,
+        flags,
+        offset: start as u64,
+        vaddr: addr(start),
+        size: (end - start) as u64,
+        align,
+    };
+    let phdrs_end = EHDR_SIZE + PHDR_SIZE * phnum;
+    let mut phdrs = Vec::new();
+    if dynamic {
+        phdrs.push(segment(PT_PHDR, PF_R, EHDR_SIZE, phdrs_end, 8));
+        phdrs.push(segment(
+            PT_INTERP,
+            PF_R,
+            interp,
+            interp + DYNAMIC_LINKER.len(),
+            1,
+        ));
+    }
+    phdrs.push(segment(PT_LOAD, PF_R, 0, ro_end, PAGE));
+    phdrs.push(segment(PT_LOAD, PF_R | PF_X, text, text_end, PAGE));
+    phdrs.push(segment(PT_LOAD, PF_R | PF_W, rw, rw_end.max(rw + 1), PAGE));
+    if dynamic {
+        let end = dynamic_table + dynamic_entries * DYN_SIZE;
+        phdrs.push(segment(PT_DYNAMIC, PF_R | PF_W, dynamic_table, end, 8));
+    }
+    phdrs.push(ProgramHeader {
+        kind: PT_GNU_STACK,
+        flags: PF_R | PF_W,
+        offset: 0,
+        vaddr: 0,
+        size: 0,
+        align: 16,
+    });
+    debug_assert_eq!(phdrs.len(), phnum);
+    if rw_end == rw {
+        image.push(0);
+    }
+
+    let mut headers = Vec::new();
+    for phdr in &phdrs {
+        phdr.write(&mut headers);
+    }
+    image[EHDR_SIZE..phdrs_end].copy_from_slice(&headers);
+    let header = FileHeader {
+        kind: ET_EXEC,
+        entry: addr(text + entry_symbol.offset),
+        phoff: EHDR_SIZE as u64,
+        phnum: phnum as u16,
+        shoff: 0,
+        shnum: 0,
+        shstrndx: 0,
+    };
+    header.write(&mut image[..EHDR_SIZE]);
+    Ok(image)
+}
+
+fn link_error(message: String) -> Error {
+    Error::new(Code::Codegen, message, Span::unknown())
+}
+
+fn pad_to(out: &mut Vec<u8>, align: usize) {
+    out.resize(out.len().next_multiple_of(align), 0);
+}
+
+fn push_symbol(out: &mut Vec<u8>, name: u32, bind: u8, kind: u8, section: u16, value: u64) {
+    out.extend_from_slice(&name.to_le_bytes());
+    out.push((bind << 4) | kind);
+    out.push(0);
+    out.extend_from_slice(&section.to_le_bytes());
+    out.extend_from_slice(&value.to_le_bytes());
+    out.extend_from_slice(&0u64.to_le_bytes());
+}
+
+fn push_rela(out: &mut Vec<u8>, offset: u64, symbol: u64, kind: u32, addend: i64) {
+    out.extend_from_slice(&offset.to_le_bytes());
+    out.extend_from_slice(&((symbol << 32) | u64::from(kind)).to_le_bytes());
+    out.extend_from_slice(&addend.to_le_bytes());
+}
+
+#[derive(Default)]
+struct StringTable {
+    bytes: Vec<u8>,
+}
+
+impl StringTable {
+    fn add(&mut self, name: &str) -> u32 {
+        if self.bytes.is_empty() {
+            self.bytes.push(0);
+        }
+        let offset = self.bytes.len() as u32;
+        self.bytes.extend_from_slice(name.as_bytes());
+        self.bytes.push(0);
+        offset
+    }
+}
+
+struct FileHeader {
+    kind: u16,
+    entry: u64,
+    phoff: u64,
+    phnum: u16,
+    shoff: u64,
+    shnum: u16,
+    shstrndx: u16,
+}
+
+impl FileHeader {
+    fn write(&self, out: &mut [u8]) {
+        let mut bytes = Vec::with_capacity(EHDR_SIZE);
+        // Magic, 64-bit, little-endian, version 1, System V ABI.
+        bytes.extend_from_slice(&[0x7f, b'E', b'L', b'F', 2, 1, 1, 0]);
+        bytes.extend_from_slice(&[0; 8]);
+        bytes.extend_from_slice(&self.kind.to_le_bytes());
+        bytes.extend_from_slice(&EM_X86_64.to_le_bytes());
+        bytes.extend_from_slice(&1u32.to_le_bytes());
+        bytes.extend_from_slice(&self.entry.to_le_bytes());
+        bytes.extend_from_slice(&self.phoff.to_le_bytes());
+        bytes.extend_from_slice(&self.shoff.to_le_bytes());
+        bytes.extend_from_slice(&0u32.to_le_bytes());
+        bytes.extend_from_slice(&(EHDR_SIZE as u16).to_le_bytes());
+        bytes.extend_from_slice(&(PHDR_SIZE as u16).to_le_bytes());
+        bytes.extend_from_slice(&self.phnum.to_le_bytes());
+        bytes.extend_from_slice(&(SHDR_SIZE as u16).to_le_bytes());
+        bytes.extend_from_slice(&self.shnum.to_le_bytes());
+        bytes.extend_from_slice(&self.shstrndx.to_le_bytes());
+        out.copy_from_slice(&bytes);
+    }
+}
+
+struct ProgramHeader {
+    kind: u32,
+    flags: u32,
+    offset: u64,
+    vaddr: u64,
+    size: u64,
+    align: u64,
+}
+
+impl ProgramHeader {
+    fn write(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.kind.to_le_bytes());
+        out.extend_from_slice(&self.flags.to_le_bytes());
+        out.extend_from_slice(&self.offset.to_le_bytes());
+        out.extend_from_slice(&self.vaddr.to_le_bytes());
+        out.extend_from_slice(&self.vaddr.to_le_bytes());
+        out.extend_from_slice(&self.size.to_le_bytes());
+        out.extend_from_slice(&self.size.to_le_bytes());
+        out.extend_from_slice(&self.align.to_le_bytes());
+    }
+}
+
+#[derive(Default)]
+struct SectionHeader {
+    name: u32,
+    kind: u32,
+    flags: u64,
+    offset: u64,
+    size: u64,
+    link: u32,
+    info: u32,
+    align: u64,
+    entsize: u64,
+}
+
+impl SectionHeader {
+    fn write(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.name.to_le_bytes());
+        out.extend_from_slice(&self.kind.to_le_bytes());
+        out.extend_from_slice(&self.flags.to_le_bytes());
+        out.extend_from_slice(&0u64.to_le_bytes());
+        out.extend_from_slice(&self.offset.to_le_bytes());
+        out.extend_from_slice(&self.size.to_le_bytes());
+        out.extend_from_slice(&self.link.to_le_bytes());
+        out.extend_from_slice(&self.info.to_le_bytes());
+        out.extend_from_slice(&self.align.to_le_bytes());
+        out.extend_from_slice(&self.entsize.to_le_bytes());
+    }
+}
+
+#[cfg(all(test, target_os = "linux", target_arch = "x86_64"))]
+mod tests {
+    use super::*;
+    use crate::compiler::assembler::assemble;
+    use std::os::unix::fs::PermissionsExt;
+    use std::process::Command;
+
+    fn run(name: &str, asm: &str) -> std::process::Output {
+        let object = assemble(asm).expect("assembles");
+        let exe = write_executable(&object, "_start").expect("links");
+        let path = std::env::temp_dir().join(format!("rgo-elf-{name}-{}", std::process::id()));
+        std::fs::write(&path, exe).expect("write executable");
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755))
+            .expect("make executable");
+        let output = Command::new(&path).output().expect("runs");
+        let _ = std::fs::remove_file(&path);
+        output
+    }
+
+    #[test]
+    fn static_executable_runs() {
+        let output = run(
+            "static",
+            "section .text\nglobal _start\n_start:\n    mov rax, 1\n    mov rdi, 1\n    lea rsi, [rel msg]\n    mov rdx, 3\n    syscall\n    mov rax, 60\n    mov rdi, 7\n    syscall\nsection .rodata\nmsg:\n    db \"ok\", 10\n",
+        );
+        assert_eq!(output.stdout, b"ok\n");
+        assert_eq!(output.status.code(), Some(7));
+    }
+
+    #[test]
+    fn dynamic_executable_calls_libc() {
+        let output = run(
+            "dynamic",
+            "section .text\nglobal _start\nextern puts\nextern exit\n_start:\n    and rsp, -16\n    lea rdi, [rel msg]\n    call puts\n    mov rdi, 5\n    call exit\nsection .rodata\nmsg:\n    db \"from libc\", 0\n",
+        );
+        assert_eq!(output.stdout, b"from libc\n");
+        assert_eq!(output.status.code(), Some(5));
+    }
+
+    #[test]
+    fn relocatable_object_has_symbols_and_relocations() {
+        let object = assemble(
+            "section .text\nglobal _start\nextern exit\n_start:\n    lea rdi, [rel msg]\n    call exit\nsection .rodata\nmsg:\n    db 0\n",
+        )
+        .expect("assembles");
+        let elf = write_relocatable(&object);
+        assert_eq!(&elf[..4], b"\x7fELF");
+        assert_eq!(u16::from_le_bytes([elf[16], elf[17]]), ET_REL);
+        let names = [".text", ".rela.text", ".symtab", "_start", "msg", "exit"];
+        for name in names {
+            let needle = format!("\0{name}\0");
+            assert!(
+                elf.windows(needle.len())
+                    .any(|window| window == needle.as_bytes()),
+                "missing `{name}`"
+            );
+        }
+    }
+}
@@ -12,6 +12,9 @@ pub enum Code {
     HIR,
     Resolve,
     Codegen,
+    Toolchain,
+    Runtime,
+    Ownership,
     Internal,
 }
 
@@ -24,6 +27,9 @@ impl Display for Code {
             Code::HIR => "hir",
             Code::Resolve => "resolve",
             Code::Codegen => "codegen",
+            Code::Toolchain => "toolchain",
+            Code::Runtime => "runtime",
+            Code::Ownership => "ownership",
             Code::Internal => "internal",
         };
         f.write_str(code)
@@ -35,6 +41,8 @@ pub struct Error {
     pub code: Code,
     pub message: String,
     pub span: Span,
+    pub notes: Vec<String>,
+    pub help: Option<String>,
 }
 
 impl Error {
@@ -43,16 +51,24 @@ impl Error {
             code,
             message: message.into(),
             span,
+            notes: Vec::new(),
+            help: None,
         }
     }
+
+    pub fn with_note(mut self, note: impl Into<String>) -> Self {
+        self.notes.push(note.into());
+        self
+    }
+
+    pub fn with_help(mut self, help: impl Into<String>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
 }
 
 pub fn new(code: Code, message: impl Into<String>, span: Span) -> Error {
-    Error {
-        code,
-        message: message.into(),
-        span,
-    }
+    Error::new(code, message, span)
 }
 
 impl Display for Error {
@@ -1,6 +1,7 @@
 use std::fmt;
 
 use crate::compiler::air;
+use crate::compiler::refinement;
 use crate::compiler::span::Span;
 
 pub fn render_air_functions(functions: &[air::AirFunction]) -> String {
@@ -94,6 +95,64 @@ impl fmt::Debug for air::AirStmt {
                 air::AirOp::ReleaseHeap(heap) => {
                     write!(f, "@release({})", format_binding_name(&heap.name))
                 }
+                air::AirOp::ReleaseStr(release) => {
+                    write!(f, "@releasestr({})", format_binding_name(&release.name))
+                }
+                air::AirOp::CopyStr(copy) => write!(
+                    f,
+                    "{} = @copystr({})",
+                    format_binding_name(&copy.dst),
+                    format_binding_name(&copy.src)
+                ),
+                air::AirOp::NewArray(array) => write!(
+                    f,
+                    "{} = @newarray<{}>({})",
+                    format_binding_name(&array.name),
+                    format_sig_kind(&array.elem),
+                    format_args_inline(&array.items)
+                ),
+                air::AirOp::ReleaseArray(release) => {
+                    write!(f, "@releasearray({})", format_binding_name(&release.name))
+                }
+                air::AirOp::CopyArray(copy) => write!(
+                    f,
+                    "{} = @copyarray({})",
+                    format_binding_name(&copy.dst),
+                    format_binding_name(&copy.src)
+                ),
+                air::AirOp::NewMap(map) => write!(
+                    f,
+                    "{} = @newmap<{}, {}>({})",
+                    format_binding_name(&map.name),
+                    format_sig_kind(&map.key),
+                    format_sig_kind(&map.value),
+                    map.entries
+                        .iter()
+                        .map(|(key, value)| format!("{}: {}", format_arg(key), format_arg(value)))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+                air::AirOp::ReleaseMap(release) => {
+                    write!(f, "@releasemap({})", format_binding_name(&release.name))
+                }
+                air::AirOp::ReleaseChan(release) => {
+                    write!(f, "@releasechan({})", format_binding_name(&release.name))
+                }
+                air::AirOp::CopyChan(copy) => write!(
+                    f,
+                    "{} = @copychan({})",
+                    format_binding_name(&copy.dst),
+                    format_binding_name(&copy.src)
+                ),
+                air::AirOp::ReleaseSum(release) => {
+                    write!(f, "@releasesum({})", format_binding_name(&release.name))
+                }
+                air::AirOp::CopyMap(copy) => write!(
+                    f,
+                    "{} = @copymap({})",
+                    format_binding_name(&copy.dst),
+                    format_binding_name(&copy.src)
+                ),
                 air::AirOp::SetField(set) => write!(
                     f,
                     "@setfield({}, {}, {})",
@@ -133,6 +192,13 @@ impl fmt::Debug for air::AirStmt {
                         write!(f, "@eqs({}, {})", eq.target, args)
                     }
                 }
+                air::AirOp::JumpCmpStr(cmp) => write!(
+                    f,
+                    "@cmps({}, {}, {})",
+                    cmp.lt_target,
+                    cmp.gt_target,
+                    format_args_inline(&cmp.args)
+                ),
                 air::AirOp::JumpLt(jump) => write!(
                     f,
                     "@lt({}, {}, {})",
@@ -140,6 +206,27 @@ impl fmt::Debug for air::AirStmt {
                     format_operand(&jump.left),
                     format_operand(&jump.right),
                 ),
+                air::AirOp::JumpEqF64(jump) => write!(
+                    f,
+                    "@eqf64({}, {}, {})",
+                    jump.target,
+                    format_arg(&jump.input_a),
+                    format_arg(&jump.input_b),
+                ),
+                air::AirOp::JumpLtF64(jump) => write!(
+                    f,
+                    "@ltf64({}, {}, {})",
+                    jump.target,
+                    format_arg(&jump.input_a),
+                    format_arg(&jump.input_b),
+                ),
+                air::AirOp::JumpGtF64(jump) => write!(
+                    f,
+                    "@gtf64({}, {}, {})",
+                    jump.target,
+                    format_arg(&jump.input_a),
+                    format_arg(&jump.input_b),
+                ),
                 air::AirOp::Add(op) => {
                     write!(
                         f,
@@ -161,6 +248,13 @@ impl fmt::Debug for air::AirStmt {
                         format_binary_instr_op("sub", &op.input_a, &op.input_b, &op.target)
                     )
                 }
+                air::AirOp::SubF64(op) => {
+                    write!(
+                        f,
+                        "{}",
+                        format_binary_instr_op("subf64", &op.input_a, &op.input_b, &op.target)
+                    )
+                }
                 air::AirOp::Mul(op) => {
                     write!(
                         f,
@@ -183,6 +277,57 @@ impl fmt::Debug for air::AirStmt {
                     format_arg(&op.input_a),
                     format_arg(&op.input_b)
                 ),
+                air::AirOp::RemInt(op) => write!(
+                    f,
+                    "@rem({}, {}, {}, {})",
+                    op.ok_target,
+                    op.err_target,
+                    format_arg(&op.input_a),
+                    format_arg(&op.input_b)
+                ),
+                air::AirOp::And(op) => {
+                    write!(
+                        f,
+                        "{}",
+                        format_binary_instr_op("and", &op.input_a, &op.input_b, &op.target)
+                    )
+                }
+                air::AirOp::Or(op) => {
+                    write!(
+                        f,
+                        "{}",
+                        format_binary_instr_op("or", &op.input_a, &op.input_b, &op.target)
+                    )
+                }
+                air::AirOp::Xor(op) => {
+                    write!(
+                        f,
+                        "{}",
+                        format_binary_instr_op("xor", &op.input_a, &op.input_b, &op.target)
+                    )
+                }
+                air::AirOp::Shl(op) => {
+                    write!(
+                        f,
+                        "{}",
+                        format_binary_instr_op("shl", &op.input_a, &op.input_b, &op.target)
+                    )
+                }
+                air::AirOp::Shr(op) => {
+                    write!(
+                        f,
+                        "{}",
+                        format_binary_instr_op("shr", &op.input_a, &op.input_b, &op.target)
+                    )
+                }
+                air::AirOp::Neg(op) => write!(
+                    f,
+                    "{}",
+                    format_call_op("neg", std::slice::from_ref(&op.input), &op.target, &[])
+                ),
+                air::AirOp::AddChecked(op) => write!(f, "{}", format_checked_op("addc", op)),
+                air::AirOp::SubChecked(op) => write!(f, "{}", format_checked_op("subc", op)),
+                air::AirOp::MulChecked(op) => write!(f, "{}", format_checked_op("mulc", op)),
                 air::AirOp::DivF64(op) => {
                     write!(
                         f,
@@ -190,6 +335,37 @@ impl fmt::Debug for air::AirStmt {
                         format_binary_instr_op("divf64", &op.input_a, &op.input_b, &op.target)
                     )
                 }
+                air::AirOp::NegF64(op) => write!(
+                    f,
+                    "{}",
+                    format_call_op("negf64", std::slice::from_ref(&op.input), &op.target, &[])
+                ),
+                air::AirOp::SqrtF64(op) => write!(
+                    f,
+                    "{}",
+                    format_call_op("sqrtf64", std::slice::from_ref(&op.input), &op.target, &[])
+                ),
+                air::AirOp::IntToF64(op) => write!(
+                    f,
+                    "{}",
+                    format_call_op("itof", std::slice::from_ref(&op.input), &op.target, &[])
+                ),
+                air::AirOp::F64ToInt(op) => write!(
+                    f,
+                    "{}",
+                    format_branching_op(
+                        "ftoi",
+                        std::slice::from_ref(&op.input),
+                        &[&op.err_target, &op.ok_target],
+                        &[]
+                    )
+                ),
+                air::AirOp::JumpTable(jump) => write!(
+                    f,
+                    "@jumptable({}, {})",
+                    format_operand(&jump.index),
+                    jump.targets.join(", "),
+                ),
                 air::AirOp::JumpGt(jump) => write!(
                     f,
                     "@gt({}, {}, {})",
@@ -197,15 +373,232 @@ impl fmt::Debug for air::AirStmt {
                     format_operand(&jump.left),
                     format_operand(&jump.right),
                 ),
+                air::AirOp::StrLen(op) => write!(
+                    f,
+                    "{}",
+                    format_call_op(
+                        "strlen",
+                        std::slice::from_ref(&op.input),
+                        &op.target,
+                        &op.release
+                    )
+                ),
+                air::AirOp::Concat(op) => write!(
+                    f,
+                    "{}",
+                    format_call_op(
+                        "concat",
+                        &[op.input_a.clone(), op.input_b.clone()],
+                        &op.target,
+                        &op.release
+                    )
+                ),
+                air::AirOp::Slice(op) => write!(
+                    f,
+                    "{}",
+                    format_branching_op(
+                        "slice",
+                        &[op.input.clone(), op.start.clone(), op.end.clone()],
+                        &[&op.err_target, &op.ok_target],
+                        &op.release
+                    )
+                ),
+                air::AirOp::ByteAt(op) => write!(
+                    f,
+                    "{}",
+                    format_branching_op(
+                        "byteat",
+                        &[op.input.clone(), op.index.clone()],
+                        &[&op.err_target, &op.ok_target],
+                        &op.release
+                    )
+                ),
+                air::AirOp::ArrayLen(op) => write!(
+                    f,
+                    "{}",
+                    format_call_op("arrlen", std::slice::from_ref(&op.input), &op.target, &[])
+                ),
+                air::AirOp::ArrayGet(op) => write!(
+                    f,
+                    "{}",
+                    format_branching_op(
+                        "get",
+                        &[op.input.clone(), op.index.clone()],
+                        &[&op.one_target, &op.none_target],
+                        &[]
+                    )
+                ),
+                air::AirOp::ArraySet(op) => write!(
+                    f,
+                    "{}",
+                    format_branching_op(
+                        "set",
+                        &[op.input.clone(), op.index.clone(), op.value.clone()],
+                        &[&op.err_target, &op.ok_target],
+                        &[]
+                    )
+                ),
+                air::AirOp::MapInsert(op) => write!(
+                    f,
+                    "{}",
+                    format_call_op(
+                        "insert",
+                        &[op.input.clone(), op.key.clone(), op.value.clone()],
+                        &op.target,
+                        &[]
+                    )
+                ),
+                air::AirOp::MapLookup(op) => write!(
+                    f,
+                    "{}",
+                    format_branching_op(
+                        "lookup",
+                        &[op.input.clone(), op.key.clone()],
+                        &[&op.found_target, &op.missing_target],
+                        &[]
+                    )
+                ),
+                air::AirOp::MapRemove(op) => write!(
+                    f,
+                    "{}",
+                    format_call_op(
+                        "remove",
+                        &[op.input.clone(), op.key.clone()],
+                        &op.target,
+                        &[]
+                    )
+                ),
+                air::AirOp::MapLen(op) => write!(
+                    f,
+                    "{}",
+                    format_call_op("maplen", std::slice::from_ref(&op.input), &op.target, &[])
+                ),
+                air::AirOp::MapNext(op) => write!(
+                    f,
+                    "{}",
+                    format_branching_op(
+                        "mapnext",
+                        &[op.input.clone(), op.cursor.clone()],
+                        &[&op.entry_target, &op.done_target],
+                        &[]
+                    )
+                ),
+                air::AirOp::NewChan(op) => write!(
+                    f,
+                    "@chan<{}>({})",
+                    format_sig_kind(&op.elem),
+                    format_binding_name(&op.target)
+                ),
+                air::AirOp::ChanSend(op) => write!(
+                    f,
+                    "{}",
+                    format_call_op(
+                        "send",
+                        &[op.channel.clone(), op.value.clone()],
+                        &op.target,
+                        &[]
+                    )
+                ),
+                air::AirOp::ChanRecv(op) => write!(
+                    f,
+                    "{}",
+                    format_call_op("recv", std::slice::from_ref(&op.channel), &op.target, &[])
+                ),
+                air::AirOp::Spawn(op) => write!(
+                    f,
+                    "@spawn({}, {})",
+                    format_binding_name(&op.task),
+                    format_binding_name(&op.target)
+                ),
+                air::AirOp::Thread(op) => write!(
+                    f,
+                    "@thread({}, {})",
+                    format_binding_name(&op.task),
+                    format_binding_name(&op.target)
+                ),
+                air::AirOp::Yield(op) => write!(f, "@yield({})", format_binding_name(&op.target)),
+                air::AirOp::Done(_) => write!(f, "@done()"),
                 air::AirOp::Printf(call) => {
-                    write!(f, "{}", format_call_op("printf", &call.args, &call.target))
+                    write!(
+                        f,
+                        "{}",
+                        format_call_op("printf", &call.args, &call.target, &call.release)
+                    )
                 }
                 air::AirOp::Sprintf(call) => {
-                    write!(f, "{}", format_call_op("sprintf", &call.args, &call.target))
+                    write!(
+                        f,
+                        "{}",
+                        format_call_op("sprintf", &call.args, &call.target, &call.release)
+                    )
                 }
                 air::AirOp::Write(call) => {
-                    write!(f, "{}", format_call_op("write", &call.args, &call.target))
+                    write!(
+                        f,
+                        "{}",
+                        format_call_op("write", &call.args, &call.target, &call.release)
+                    )
+                }
+                air::AirOp::WriteFd(call) => {
+                    write!(
+                        f,
+                        "{}",
+                        format_call_op("writefd", &call.args, &call.target, &call.release)
+                    )
+                }
+                air::AirOp::Read(op) => write!(
+                    f,
+                    "{}",
+                    format_branching_op(
+                        "read",
+                        &[op.fd.clone(), op.max.clone()],
+                        &[&op.ok_target, &op.eof_target, &op.err_target],
+                        &[]
+                    )
+                ),
+                air::AirOp::Open(op) => write!(
+                    f,
+                    "{}",
+                    format_branching_op(
+                        "open",
+                        &[op.path.clone(), op.flags.clone(), op.mode.clone()],
+                        &[&op.err_target, &op.ok_target],
+                        &op.release
+                    )
+                ),
+                air::AirOp::Close(op) => write!(
+                    f,
+                    "{}",
+                    format_branching_op(
+                        "close",
+                        std::slice::from_ref(&op.fd),
+                        &[&op.err_target, &op.ok_target],
+                        &[]
+                    )
+                ),
+                air::AirOp::ArgCount(op) => {
+                    write!(f, "{}", format_call_op("argc", &[], &op.target, &[]))
                 }
+                air::AirOp::ArgAt(op) => write!(
+                    f,
+                    "{}",
+                    format_branching_op(
+                        "arg",
+                        std::slice::from_ref(&op.index),
+                        &[&op.one_target, &op.none_target],
+                        &[]
+                    )
+                ),
+                air::AirOp::Getenv(op) => write!(
+                    f,
+                    "{}",
+                    format_branching_op(
+                        "getenv",
+                        std::slice::from_ref(&op.name),
+                        &[&op.found_target, &op.missing_target],
+                        &op.release
+                    )
+                ),
                 air::AirOp::JumpArgs(ja) => {
                     let args = format_args_inline(&ja.args);
                     let target = if let Some(builtin) = &ja.target.builtin {
@@ -293,6 +686,21 @@ fn format_sig_kind_inner(kind: &air::SigKind, show_names: bool) -> String {
         air::SigKind::CompileTimeInt => "int!".to_string(),
         air::SigKind::CompileTimeStr => "str!".to_string(),
         air::SigKind::Ident(ident) => ident.name.clone(),
+        air::SigKind::Array(elem) => format!("[{}]", format_sig_kind_inner(elem, show_names)),
+        air::SigKind::Map(key, value) => format!(
+            "[{}: {}]",
+            format_sig_kind_inner(key, show_names),
+            format_sig_kind_inner(value, show_names)
+        ),
+        air::SigKind::Chan(elem) => {
+            format!("@chan<{}>", format_sig_kind_inner(elem, show_names))
+        }
+        air::SigKind::Record { name, .. } | air::SigKind::Sum { name, .. } => name.clone(),
+        air::SigKind::Refined(base, bounds) => format!(
+            "{}({})",
+            format_sig_kind_inner(base, show_names),
+            refinement::format_bounds(bounds)
+        ),
         air::SigKind::Sig(sig) => {
             let items = sig
                 .items
@@ -302,7 +710,7 @@ fn format_sig_kind_inner(kind: &air::SigKind, show_names: bool) -> String {
                 .join(", ");
             format!("({})", items)
         }
-        air::SigKind::GenericInst { name, args } => {
+        air::SigKind::GenericInst { name, args, .. } => {
             let inner = args
                 .iter()
                 .map(|kind| format_sig_kind_inner(kind, show_names))
@@ -377,14 +785,45 @@ fn format_binary_instr_op(
     )
 }
 
-fn format_call_op(name: &str, args: &[air::AirArg], target: &str) -> String {
+fn format_checked_op(name: &str, op: &air::AirChecked) -> String {
+    format_branching_op(
+        name,
+        &[op.input_a.clone(), op.input_b.clone()],
+        &[&op.overflow_target, &op.ok_target],
+        &[],
+    )
+}
+
+fn format_call_op(name: &str, args: &[air::AirArg], target: &str, release: &[String]) -> String {
+    format_branching_op(name, args, &[target], release)
+}
+
+fn format_branching_op(
+    name: &str,
+    args: &[air::AirArg],
+    targets: &[&str],
+    release: &[String],
+) -> String {
     let args = format_args_inline(args);
-    let formatted_target = format_binding_name(target);
-    if args.is_empty() {
-        format!("@{}({})", name, formatted_target)
+    let formatted_targets = targets
+        .iter()
+        .map(|target| format_binding_name(target))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let call = if args.is_empty() {
+        format!("@{}({})", name, formatted_targets)
     } else {
-        format!("@{}({}, {})", name, args, formatted_target)
+        format!("@{}({}, {})", name, args, formatted_targets)
+    };
+    if release.is_empty() {
+        return call;
     }
+    let released = release
+        .iter()
+        .map(|name| format_binding_name(name))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("{} releasing({})", call, released)
 }
 
 fn format_literal(value: &str) -> String {
@@ -1,6 +1,7 @@
 use std::fmt::Write;
 
 use crate::compiler::hir::{self, Block, BlockItem, Closure, Function};
+use crate::compiler::refinement;
 pub fn render_normalized_rgo(items: &[BlockItem]) -> String {
     let mut out = String::new();
     for (i, item) in items.iter().enumerate() {
@@ -60,7 +61,63 @@ fn write_block_item(item: &BlockItem, out: &mut String, indent: usize) {
                 write!(out, "{}: {}", name, f).unwrap();
             }
         },
-        BlockItem::ClosureDef(Closure { name, of, args }) => {
+        BlockItem::ArrayDef(array) => {
+            write!(out, "{}: [", array.name).unwrap();
+            write_args(&array.items, out);
+            out.push(']');
+        }
+        BlockItem::MapDef(map) => {
+            write!(out, "{}: [", map.name).unwrap();
+            if map.entries.is_empty() {
+                out.push(':');
+            }
+            for (i, (key, value)) in map.entries.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(", ");
+                }
+                write!(out, "{}: {}", key, value).unwrap();
+            }
+            out.push(']');
+        }
+        BlockItem::NewRecord(record) => {
+            write!(out, "{}: {}(", record.name, format_sig_kind(&record.record)).unwrap();
+            write_args(&record.fields, out);
+            out.push(')');
+        }
+        BlockItem::FieldDef(field) => {
+            let field_name = match &field.kind {
+                hir::SigKind::Record { fields, .. } => fields.items[field.index].name.as_str(),
+                _ => "?",
+            };
+            write!(out, "{}: {}.{}", field.name, field.record, field_name).unwrap();
+        }
+        BlockItem::NewVariant(variant) => {
+            write!(
+                out,
+                "{}: {}(",
+                variant.name,
+                variant_name(&variant.sum, variant.tag)
+            )
+            .unwrap();
+            write_args(&variant.payload, out);
+            out.push(')');
+        }
+        BlockItem::Case(case) => {
+            write!(out, "{} {{", case.value).unwrap();
+            for (tag, arm) in case.arms.iter().enumerate() {
+                let separator = if tag == 0 { " " } else { ", " };
+                write!(
+                    out,
+                    "{}{}: {}",
+                    separator,
+                    variant_name(&case.sum, tag),
+                    arm
+                )
+                .unwrap();
+            }
+            out.push_str(" }");
+        }
+        BlockItem::ClosureDef(Closure { name, of, args, .. }) => {
             write!(out, "{}: {}(", name, of).unwrap();
             write_args(args, out);
             out.push(')');
@@ -84,6 +141,13 @@ fn write_block_item(item: &BlockItem, out: &mut String, indent: usize) {
     out.push('\n');
 }
 
+fn variant_name(sum: &hir::SigKind, tag: usize) -> &str {
+    match sum {
+        hir::SigKind::Sum { variants, .. } => variants.items[tag].name.as_str(),
+        _ => "?",
+    }
+}
+
 fn write_args(args: &[String], out: &mut String) {
     let mut first = true;
     for arg in args {
@@ -139,9 +203,15 @@ pub fn format_sig_kind(kind: &hir::SigKind) -> String {
                 .join(", ");
             format!("({})", entries)
         }
+        hir::SigKind::Array(elem) => format!("[{}]", format_sig_kind(elem)),
+        hir::SigKind::Map(key, value) => {
+            format!("[{}: {}]", format_sig_kind(key), format_sig_kind(value))
+        }
+        hir::SigKind::Chan(elem) => format!("@chan<{}>", format_sig_kind(elem)),
+        hir::SigKind::Record { name, .. } | hir::SigKind::Sum { name, .. } => name.clone(),
         hir::SigKind::Ident(ident) => ident.name.clone(),
         hir::SigKind::Variadic => "...".to_string(),
-        hir::SigKind::GenericInst { name, args } => {
+        hir::SigKind::GenericInst { name, args, .. } => {
             let entries = args
                 .iter()
                 .map(format_sig_kind)
@@ -150,6 +220,13 @@ pub fn format_sig_kind(kind: &hir::SigKind) -> String {
             format!("{name}<{entries}>")
         }
         hir::SigKind::Generic(name) => name.clone(),
+        hir::SigKind::Refined(base, bounds) => {
+            format!(
+                "{}({})",
+                format_sig_kind(base),
+                refinement::format_bounds(bounds)
+            )
+        }
     }
 }
 
@@ -5,6 +5,7 @@ use crate::compiler::error::{Code, Error};
 use crate::compiler::format_hir;
 pub use crate::compiler::hir_ast::*;
 use crate::compiler::hir_context as ctx;
+use crate::compiler::refinement::{self, Bound, BoundValue, Comparison};
 use crate::compiler::signature;
 use crate::compiler::span::Span;
 use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
@@ -29,18 +30,25 @@ impl Lowerer {
     }
 
     pub fn produce(&mut self) -> Option<BlockItem> {
-        self.ready.pop_front()
+        self.ready.pop_front().map(refinement::erase_item)
     }
 
     pub fn consume(&mut self, ctx: &mut ctx::Context, block: ast::BlockItem) -> Result<(), Error> {
         match block {
-            ast::BlockItem::Import { label, path, .. } => {
+            ast::BlockItem::Import { label, path, span } => {
                 let item = BlockItem::Import {
                     label: label.clone(),
                     path: path.clone(),
                 };
                 self.ready.push_back(item);
-                ctx::register_import(ctx, &label, &path, Span::unknown())?;
+                ctx::register_import(ctx, &label, &path, span)?;
+            }
+            ast::BlockItem::SourceImport { span, .. } => {
+                return Err(Error::new(
+                    Code::Internal,
+                    "source imports must be resolved before lowering",
+                    span,
+                ));
             }
             ast::BlockItem::FunctionDef { name, lambda, .. } => {
                 if lambda.params.is_variadic() {
@@ -57,6 +65,9 @@ impl Lowerer {
                     &self.variadic_functions,
                 )?;
             }
+            ast::BlockItem::FunctionDecl { name, params, span } => {
+                lower_function_decl(ctx, name, params, span)?;
+            }
             other => {
                 let lowered_items =
                     lower_block_item(ctx, other, &mut self.ready, &self.variadic_functions)?;
@@ -68,6 +79,120 @@ impl Lowerer {
 
         Ok(())
     }
+
+    /// Reports a function that was declared ahead of a definition that never
+    /// came.
+    pub fn finish(&self, ctx: &ctx::Context) -> Result<(), Error> {
+        let undefined = ctx
+            .forward_decls
+            .iter()
+            .min_by_key(|(_, span)| (span.line, span.column));
+        match undefined {
+            Some((name, span)) => Err(Error::new(
+                Code::HIR,
+                format!("`{name}` is declared but never defined"),
+                *span,
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+fn resolve_function_signature(
+    outer_ctx: &ctx::Context,
+    name: &str,
+    display_name: Option<&str>,
+    params: &ast::Signature,
+    is_root_def: bool,
+) -> Result<Signature, Error> {
+    let span = params.span;
+    let params = signature::ast_signature_to_hir(params.clone());
+    let mut signature_ctx = outer_ctx.enter(name, display_name, is_root_def);
+    register_generic_placeholders(&mut signature_ctx, &params.generics)?;
+    let signature = signature::resolve_signature(&params, &mut signature_ctx);
+    check_refinements(&signature_ctx, &signature, span)?;
+    Ok(signature)
+}
+
+/// Reports a refinement in `signature` that cannot hold, such as one on a
+/// record or one that allows no value.
+fn check_refinements(ctx: &ctx::Context, signature: &Signature, span: Span) -> Result<(), Error> {
+    for item in &signature.items {
+        match &item.kind {
+            SigKind::Refined(base, bounds) => {
+                let base = signature::normalize_sig_kind(base, ctx);
+                refinement::check_bounds(&base, bounds)
+                    .map_err(|message| error::new(Code::HIR, message, span))?;
+            }
+            SigKind::Sig(inner) => check_refinements(ctx, inner, span)?,
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Registers a root function ahead of its definition, under the label the
+/// definition will take over.
+fn lower_function_decl(
+    ctx: &mut ctx::Context,
+    name: String,
+    params: ast::Signature,
+    span: Span,
+) -> Result<(), Error> {
+    if params.is_variadic() {
+        return Err(Error::new(
+            Code::HIR,
+            format!("variadic function `{name}` cannot be declared ahead of its definition"),
+            span,
+        ));
+    }
+    let signature = resolve_function_signature(ctx, &name, Some(&name), &params, true)?;
+    let label = ctx.new_name_for_fn(Some(&name));
+    ctx.add_sig(&name, &label, signature, span, false)?;
+    ctx.forward_decls.insert(name, span);
+    Ok(())
+}
+
+/// The label of the function `name` declared at `decl_span`, once its
+/// definition is known to have the declared signature.
+fn declared_function_label(
+    ctx: &ctx::Context,
+    name: &str,
+    signature: &Signature,
+    decl_span: Span,
+    span: Span,
+) -> Result<String, Error> {
+    let entry = ctx.inner.get(name).ok_or_else(|| {
+        Error::new(
+            Code::Internal,
+            format!("declaration of `{name}` is missing from the root scope"),
+            span,
+        )
+    })?;
+    // Named arguments given before the definition follow the declared names.
+    let matches = match &entry.kind {
+        SigKind::Sig(declared) => {
+            declared == signature
+                && declared
+                    .items
+                    .iter()
+                    .zip(&signature.items)
+                    .all(|(left, right)| left.name == right.name)
+        }
+        _ => false,
+    };
+    if !matches {
+        return Err(Error::new(
+            Code::HIR,
+            format!("definition of `{name}` does not match its declaration"),
+            span,
+        )
+        .with_note(format!(
+            "`{name}` was declared at {}:{}",
+            decl_span.line, decl_span.column
+        )));
+    }
+    Ok(entry.name.clone())
 }
 
 fn lower_function(
@@ -79,21 +204,32 @@ fn lower_function(
     is_root_def: bool,
     variadic_functions: &HashMap<String, ast::Lambda>,
 ) -> Result<(), Error> {
-    let span = Span::unknown();
+    let span = lambda.span;
 
     let lambda_params = signature::ast_signature_to_hir(lambda.params.clone());
-    let mut signature_ctx = outer_ctx.enter(&name, display_name.as_deref(), is_root_def);
-    register_generic_placeholders(&mut signature_ctx, &lambda_params.generics)?;
-    let signature = signature::resolve_signature(&lambda_params, &mut signature_ctx);
-    let new_name = outer_ctx.new_name_for_fn(display_name.as_deref());
-    outer_ctx.add_sig(&name, &new_name, signature.clone(), span, false)?;
+    let signature = resolve_function_signature(
+        outer_ctx,
+        &name,
+        display_name.as_deref(),
+        &lambda.params,
+        is_root_def,
+    )?;
+    let decl_span = outer_ctx.forward_decls.remove(&name);
+    let new_name = match decl_span {
+        Some(decl_span) => declared_function_label(outer_ctx, &name, &signature, decl_span, span)?,
+        None => {
+            let new_name = outer_ctx.new_name_for_fn(display_name.as_deref());
+            outer_ctx.add_sig(&name, &new_name, signature.clone(), span, false)?;
+            new_name
+        }
+    };
 
     // lower_params
     let params = signature.items;
     let mut ctx = outer_ctx.enter(&name, display_name.as_deref(), is_root_def);
     register_generic_placeholders(&mut ctx, &lambda_params.generics)?;
-    for item in &params {
-        ctx.add_param(&item.name, item.kind.clone(), Span::unknown(), false)?;
+    for (item, ast_item) in params.iter().zip(&lambda.params.items) {
+        ctx.add_param(&item.name, item.kind.clone(), ast_item.span, false)?;
     }
     let mut lowered_items: Vec<BlockItem> = Vec::with_capacity(lambda.body.items.len());
 
@@ -134,12 +270,24 @@ fn lower_function(
         body: Block {
             items: lowered_items,
         },
+        span,
     };
     hoisted.push_back(BlockItem::FunctionDef(function));
 
     // TODO: ABC: This needs looking into
     // This is meta info for anyone attempting to exec this function, that there's more args than they think due to captured params
     let captures = ctx.get_captures();
+    if let (Some(capture), Some(_)) = (captures.first(), decl_span) {
+        // Calls made before the definition could not pass the capture.
+        return Err(Error::new(
+            Code::HIR,
+            format!(
+                "`{name}` is declared ahead of its definition, so it cannot capture `{}`",
+                capture.name
+            ),
+            span,
+        ));
+    }
     if !captures.is_empty() {
         if let Some(entry) = outer_ctx.get_mut(&name) {
             entry.captures = captures;
@@ -165,15 +313,14 @@ fn register_generic_placeholders(
     Ok(())
 }
 
-fn maybe_capture_name(ctx: &mut ctx::Context, name: &str) -> Result<(), Error> {
+fn maybe_capture_name(ctx: &mut ctx::Context, name: &str, use_span: Span) -> Result<(), Error> {
     if ctx.inner.contains_key(name) {
         return Ok(());
     }
     let entry: ContextEntry = ctx.outer.get(name).cloned().ok_or_else(|| {
-        Error::new(
-            Code::HIR,
-            format!("`{}` is not defined", name),
-            Span::unknown(), // TODO: Fix this
+        with_import_hint(
+            Error::new(Code::HIR, format!("`{}` is not defined", name), use_span),
+            name,
         )
     })?;
     if entry.is_builtin {
@@ -191,6 +338,16 @@ fn maybe_capture_name(ctx: &mut ctx::Context, name: &str) -> Result<(), Error> {
     Ok(())
 }
 
+// Undefined names that match a builtin were most likely meant to be imported.
+fn with_import_hint(err: Error, name: &str) -> Error {
+    if builtins::get_spec(name).is_none() {
+        return err;
+    }
+    err.with_help(format!(
+        "`{name}` is a builtin; import it with `{name}: @{name}`"
+    ))
+}
+
 // TODO: This is a mess
 fn emit_closure_for_term(
     ctx: &mut ctx::Context,
@@ -221,6 +378,7 @@ fn emit_closure_for_term(
                     name: name.to_string(),
                     of: info.of.clone(),
                     args: lowered_args,
+                    span: info.span,
                 }));
             }
 
@@ -244,12 +402,16 @@ fn lower_block_item(
             term,
             ..
         } => {
-            let lambda = ast::Lambda {
+            let mut lambda = ast::Lambda {
                 params,
                 body: continuation,
                 args: Vec::new(),
                 span: Span::unknown(),
             };
+            if let Some(fields) = destructured_record_fields(ctx, &term) {
+                lambda.args = destructure_record_args(&lambda.params, &term, fields)?;
+                return lower_exec(ctx, ast::Term::Lambda(lambda), hoisted, variadic_functions);
+            }
             let callback_term = ast::Term::Lambda(lambda);
             let exec_term = append_scope_capture_arg(term, callback_term)?;
             lower_exec(ctx, exec_term, hoisted, variadic_functions)
@@ -273,17 +435,22 @@ fn lower_block_item(
                 literal,
             }])
         }
-        ast::BlockItem::SigDef { name, sig, .. } => {
+        ast::BlockItem::SigDef { name, sig, span } => {
             ctx.add_type(
                 &name,
                 &name,
-                SigKind::Ident(SigIdent { name: name.clone() }),
-                Span::unknown(),
+                SigKind::Ident(SigIdent {
+                    name: name.clone(),
+                    span,
+                }),
+                span,
                 false,
             )?;
+            let sig_span = sig.span;
             let sig = signature::ast_signature_to_hir(sig);
             let hir_sig = signature::resolve_signature(&sig, ctx);
             let normalized_sig = signature::normalize_signature(&hir_sig, ctx);
+            check_refinements(ctx, &normalized_sig, sig_span)?;
             if let Some(entry) = ctx.get_mut(&name) {
                 entry.kind = SigKind::Sig(normalized_sig.clone());
             }
@@ -293,10 +460,51 @@ fn lower_block_item(
             };
             Ok(vec![sig_def])
         }
-        ast::BlockItem::IdentDef { name, ident, .. } => {
+        ast::BlockItem::RecordDef { name, fields, span } => {
+            lower_record_def(ctx, name, fields, span)?;
+            Ok(Vec::new())
+        }
+        ast::BlockItem::SumDef {
+            name,
+            variants,
+            span,
+        } => {
+            lower_sum_def(ctx, name, variants, span)?;
+            Ok(Vec::new())
+        }
+        ast::BlockItem::Case(case) => lower_case(ctx, case, hoisted, variadic_functions),
+        ast::BlockItem::IdentDef { name, ident, span } => {
+            if let Some((sum, tag)) = sum_variant(ctx, &ident.name) {
+                let mut lowered_items = Vec::new();
+                lower_new_variant(
+                    ctx,
+                    name.clone(),
+                    (sum.clone(), tag),
+                    ident,
+                    hoisted,
+                    &mut lowered_items,
+                    variadic_functions,
+                )?;
+                ctx.add_literal(&name, sum)?;
+                return Ok(lowered_items);
+            }
+            if let Some(record) = record_type(ctx, &ident.name) {
+                let mut lowered_items = Vec::new();
+                lower_new_record(
+                    ctx,
+                    name.clone(),
+                    record.clone(),
+                    ident,
+                    hoisted,
+                    &mut lowered_items,
+                    variadic_functions,
+                )?;
+                ctx.add_literal(&name, record)?;
+                return Ok(lowered_items);
+            }
             if ident.args.is_empty() {
                 if let Some(builtin_name) = builtin_reference_name(&ident.name) {
-                    ctx::register_import(ctx, &name, builtin_name, Span::unknown())?;
+                    ctx::register_import(ctx, &name, builtin_name, span)?;
                     return Ok(vec![BlockItem::Import {
                         label: name,
                         path: builtin_name.to_string(),
@@ -311,7 +519,7 @@ fn lower_block_item(
                     Err(error::new(
                         Code::HIR,
                         format!("could not resolve target '{}'", ident.name),
-                        Span::unknown(),
+                        ident.span,
                     ))
                 }
             } else {
@@ -341,7 +549,7 @@ fn lower_block_item(
                     return Err(error::new(
                         Code::HIR,
                         format!("could not resolve target '{}'", closure.of),
-                        Span::unknown(),
+                        closure.span,
                     ));
                 };
                 ctx.register_closure(closure);
@@ -349,7 +557,14 @@ fn lower_block_item(
                 Ok(lowered_items)
             }
         }
-        ast::BlockItem::Import { .. } | ast::BlockItem::FunctionDef { .. } => {
+        ast::BlockItem::FunctionDecl { span, .. } => Err(Error::new(
+            Code::HIR,
+            "functions can only be declared ahead of their definition at the file root",
+            span,
+        )),
+        ast::BlockItem::Import { .. }
+        | ast::BlockItem::SourceImport { .. }
+        | ast::BlockItem::FunctionDef { .. } => {
             unreachable!("imports and functions should be handled separately")
         }
     }?;
@@ -506,6 +721,19 @@ fn substitute_block_item(item: ast::BlockItem, expansion: &VariadicExpansion) ->
             lambda: substitute_lambda(lambda, expansion),
             span,
         },
+        ast::BlockItem::Case(case) => ast::BlockItem::Case(ast::Case {
+            value: substitute_term(case.value, expansion),
+            arms: case
+                .arms
+                .into_iter()
+                .map(|arm| ast::CaseArm {
+                    variant: arm.variant,
+                    lambda: substitute_lambda(arm.lambda, expansion),
+                    span: arm.span,
+                })
+                .collect(),
+            span: case.span,
+        }),
         other => other,
     }
 }
@@ -528,6 +756,32 @@ fn substitute_term(term: ast::Term, expansion: &VariadicExpansion) -> ast::Term
             .unwrap_or(ast::Term::Ident(ident)),
         ast::Term::Ident(ident) => ast::Term::Ident(substitute_ident(ident, expansion)),
         ast::Term::Lambda(lambda) => ast::Term::Lambda(substitute_lambda(lambda, expansion)),
+        ast::Term::Array(array) => ast::Term::Array(ast::ArrayLit {
+            items: array
+                .items
+                .into_iter()
+                .map(|item| substitute_term(item, expansion))
+                .collect(),
+            span: array.span,
+        }),
+        ast::Term::Map(map) => ast::Term::Map(ast::MapLit {
+            entries: map
+                .entries
+                .into_iter()
+                .map(|(key, value)| {
+                    (
+                        substitute_term(key, expansion),
+                        substitute_term(value, expansion),
+                    )
+                })
+                .collect(),
+            span: map.span,
+        }),
+        ast::Term::Field(access) => ast::Term::Field(ast::FieldAccess {
+            record: Box::new(substitute_term(*access.record, expansion)),
+            field: access.field,
+            span: access.span,
+        }),
         other => other,
     }
 }
@@ -588,30 +842,52 @@ fn lower_exec(
     let mut lowered_items: Vec<BlockItem> = Vec::new();
     let exec = match term {
         ast::Term::Ident(ast_ident) => {
-            let ast::Ident { name, args, .. } = ast_ident;
+            let ast::Ident { name, args, span } = ast_ident;
+            if record_type(ctx, &name).is_some() {
+                return Err(error::new(
+                    Code::HIR,
+                    format!("record `{name}` cannot be called"),
+                    span,
+                )
+                .with_help(format!("bind it first, as in `value: {name}(...)`")));
+            }
+            if sum_variant(ctx, &name).is_some() {
+                return Err(error::new(
+                    Code::HIR,
+                    format!("variant `{name}` cannot be called"),
+                    span,
+                )
+                .with_help(format!("bind it first, as in `value: {name}(...)`")));
+            }
             ensure_builtin_reference(ctx, &name, hoisted)?;
-            maybe_capture_name(ctx, &name)?;
+            maybe_capture_name(ctx, &name, span.head(name.len()))?;
             let (target, args) = resolve_target(
                 ctx,
                 &name,
                 args,
+                span,
                 hoisted,
                 &mut lowered_items,
                 variadic_functions,
             )?;
-            ensure_exec_args_complete(ctx, &target, args.len())?;
+            ensure_exec_args_complete(ctx, &target, args.len(), span)?;
             let of = emit_closure_for_term(ctx, &target.name, &mut lowered_items, &mut emitted);
             let args = args
                 .into_iter()
                 .map(|arg| emit_closure_for_term(ctx, &arg, &mut lowered_items, &mut emitted))
                 .collect();
-            Exec { of, args }
+            Exec { of, args, span }
         }
         ast::Term::Lambda(lambda) => {
+            let span = lambda.span;
             let target_name =
                 lower_lambda_term(ctx, lambda, hoisted, &mut lowered_items, variadic_functions)?;
             let of = emit_closure_for_term(ctx, &target_name, &mut lowered_items, &mut emitted);
-            Exec { of, args: vec![] }
+            Exec {
+                of,
+                args: vec![],
+                span,
+            }
         }
         other => unreachable!("expected exec term, got {:?}", other),
     };
@@ -630,16 +906,17 @@ fn lower_closure(
     variadic_functions: &HashMap<String, ast::Lambda>,
 ) -> Result<Closure, Error> {
     ensure_builtin_reference(ctx, &ident.name, hoisted)?;
-    maybe_capture_name(ctx, &ident.name)?;
+    maybe_capture_name(ctx, &ident.name, ident.span.head(ident.name.len()))?;
     let ast::Ident {
         name: target_name,
         args: ast_args,
-        ..
+        span,
     } = ident;
     let (target, args) = resolve_target(
         ctx,
         &target_name,
         ast_args,
+        span,
         hoisted,
         lowered_items,
         variadic_functions,
@@ -649,6 +926,7 @@ fn lower_closure(
         name,
         of: target.name,
         args,
+        span,
     })
 }
 
@@ -660,6 +938,7 @@ fn lower_lambda_term(
     variadic_functions: &HashMap<String, ast::Lambda>,
 ) -> Result<String, Error> {
     let ast_args = lambda.args.clone(); // This is because I cheated to keep the AST simpler and made the lambda contain the args...
+    let span = lambda.span;
 
     let contextual_name = ctx.new_name();
     lower_function(
@@ -676,6 +955,7 @@ fn lower_lambda_term(
         ctx,
         &contextual_name,
         ast_args,
+        span,
         hoisted,
         lowered_items,
         variadic_functions,
@@ -688,6 +968,7 @@ fn lower_lambda_term(
         name: apply_name.clone(),
         of: target_name,
         args,
+        span,
     });
 
     Ok(apply_name)
@@ -716,14 +997,47 @@ fn lower_arg(
         type_ctx.generic_bindings,
     )?;
     let arg = match term {
+        ast::Term::Ident(ast_ident) if record_type(ctx, &ast_ident.name).is_some() => {
+            let record = record_type(ctx, &ast_ident.name).expect("checked by the guard");
+            let name = ctx.new_name();
+            lower_new_record(
+                ctx,
+                name.clone(),
+                record,
+                ast_ident,
+                hoisted,
+                lowered_items,
+                variadic_functions,
+            )?;
+            name
+        }
+        ast::Term::Ident(ast_ident) if sum_variant(ctx, &ast_ident.name).is_some() => {
+            let variant = sum_variant(ctx, &ast_ident.name).expect("checked by the guard");
+            let name = ctx.new_name();
+            lower_new_variant(
+                ctx,
+                name.clone(),
+                variant,
+                ast_ident,
+                hoisted,
+                lowered_items,
+                variadic_functions,
+            )?;
+            name
+        }
         ast::Term::Ident(ast_ident) => {
             ensure_builtin_reference(ctx, &ast_ident.name, hoisted)?;
-            maybe_capture_name(ctx, &ast_ident.name)?;
+            maybe_capture_name(
+                ctx,
+                &ast_ident.name,
+                ast_ident.span.head(ast_ident.name.len()),
+            )?;
 
             let (target, args) = resolve_target(
                 ctx,
                 &ast_ident.name,
                 ast_ident.args,
+                ast_ident.span,
                 hoisted,
                 lowered_items,
                 variadic_functions,
@@ -737,6 +1051,7 @@ fn lower_arg(
                     name: new_name.clone(),
                     of: target.name,
                     args,
+                    span: ast_ident.span,
                 });
                 new_name
             }
@@ -753,10 +1068,900 @@ fn lower_arg(
         ast::Term::Lambda(lambda) => {
             lower_lambda_term(ctx, lambda, hoisted, lowered_items, variadic_functions)?
         }
+        ast::Term::Array(array) => {
+            let expected_elem = type_ctx
+                .expected_param
+                .map(|param| signature::normalize_sig_kind(&param.kind, ctx))
+                .and_then(|kind| match kind {
+                    SigKind::Array(elem) if !has_generic_kind(&elem, type_ctx.active_generics) => {
+                        Some(*elem)
+                    }
+                    _ => None,
+                });
+            lower_array_literal(
+                ctx,
+                array,
+                expected_elem,
+                hoisted,
+                lowered_items,
+                variadic_functions,
+            )?
+        }
+        ast::Term::Map(map) => {
+            let expected = type_ctx
+                .expected_param
+                .map(|param| signature::normalize_sig_kind(&param.kind, ctx))
+                .and_then(|kind| match kind {
+                    SigKind::Map(key, value)
+                        if !has_generic_kind(&key, type_ctx.active_generics)
+                            && !has_generic_kind(&value, type_ctx.active_generics) =>
+                    {
+                        Some((*key, *value))
+                    }
+                    _ => None,
+                });
+            lower_map_literal(
+                ctx,
+                map,
+                expected,
+                hoisted,
+                lowered_items,
+                variadic_functions,
+            )?
+        }
+        ast::Term::Field(access) => lower_field_access(ctx, access, lowered_items)?,
+    };
+
+    let mut seen = HashSet::new();
+    Ok(emit_closure_for_term(ctx, &arg, lowered_items, &mut seen))
+}
+
+/// Builds an array literal at runtime: each item is lowered like an argument and
+/// the array is bound to a fresh name. An empty literal takes its element kind
+/// from the parameter it is passed to, since it has no items to infer one from.
+fn lower_array_literal(
+    ctx: &mut ctx::Context,
+    array: ast::ArrayLit,
+    expected_elem: Option<SigKind>,
+    hoisted: &mut VecDeque<BlockItem>,
+    lowered_items: &mut Vec<BlockItem>,
+    variadic_functions: &HashMap<String, ast::Lambda>,
+) -> Result<String, Error> {
+    let elem = match array_elem_kind(ctx, &array)? {
+        Some(elem) => elem,
+        None => expected_elem.unwrap_or(SigKind::Int),
+    };
+    let mut items = Vec::with_capacity(array.items.len());
+    for item in array.items {
+        let mut generic_bindings = HashMap::new();
+        let type_ctx = LowerArgTypeContext {
+            expected_param: None,
+            active_generics: &BTreeSet::new(),
+            generic_bindings: &mut generic_bindings,
+        };
+        items.push(lower_arg(
+            ctx,
+            item,
+            type_ctx,
+            hoisted,
+            lowered_items,
+            variadic_functions,
+        )?);
+    }
+    let name = ctx.new_name();
+    lowered_items.push(BlockItem::ArrayDef(ArrayDef {
+        name: name.clone(),
+        elem,
+        items,
+        span: array.span,
+    }));
+    Ok(name)
+}
+
+/// The kind every item of an array literal shares, or `None` for `[]`. Items must
+/// be plain values: closures cannot be stored, as arrays only know how to release
+/// strs and other arrays.
+fn array_elem_kind(
+    ctx: &mut ctx::Context,
+    array: &ast::ArrayLit,
+) -> Result<Option<SigKind>, Error> {
+    let mut elem: Option<SigKind> = None;
+    for item in &array.items {
+        if let ast::Term::Ident(ident) = item {
+            if ident.args.is_empty() && ctx.get(&ident.name).is_none() {
+                // Left for lowering to report as undefined.
+                continue;
+            }
+        }
+        let kind = term_sig_kind(ctx, item, true)
+            .map(|kind| canonicalize_kind(&signature::normalize_sig_kind(&kind, ctx)));
+        let kind = match kind {
+            Some(kind) if is_array_elem_kind(&kind) => kind,
+            other => {
+                let found = other
+                    .map(|kind| format_hir::format_sig_kind(&kind))
+                    .unwrap_or_else(|| "a closure".to_string());
+                return Err(error::new(
+                    Code::HIR,
+                    format!(
+                        "arrays can only hold int, byte, f64, str or array items, found {found}"
+                    ),
+                    item.span(),
+                ));
+            }
+        };
+        match &elem {
+            None => elem = Some(kind),
+            Some(expected) if *expected != kind => {
+                return Err(error::new(
+                    Code::HIR,
+                    format!(
+                        "array items must all have the same type: expected {}, found {}",
+                        format_hir::format_sig_kind(expected),
+                        format_hir::format_sig_kind(&kind)
+                    ),
+                    item.span(),
+                ));
+            }
+            Some(_) => {}
+        }
+    }
+    Ok(elem)
+}
+
+/// Builds a map literal at runtime: each key and value is lowered like an
+/// argument, in order, and inserted in that order. Like `[]`, an empty `[:]`
+/// takes its kinds from the parameter it is passed to.
+fn lower_map_literal(
+    ctx: &mut ctx::Context,
+    map: ast::MapLit,
+    expected: Option<(SigKind, SigKind)>,
+    hoisted: &mut VecDeque<BlockItem>,
+    lowered_items: &mut Vec<BlockItem>,
+    variadic_functions: &HashMap<String, ast::Lambda>,
+) -> Result<String, Error> {
+    let (key, value) = match map_entry_kinds(ctx, &map)? {
+        Some(kinds) => kinds,
+        None => expected.unwrap_or((SigKind::Str, SigKind::Int)),
+    };
+    let mut entries = Vec::with_capacity(map.entries.len());
+    for (entry_key, entry_value) in map.entries {
+        let mut lowered = Vec::with_capacity(2);
+        for term in [entry_key, entry_value] {
+            let mut generic_bindings = HashMap::new();
+            let type_ctx = LowerArgTypeContext {
+                expected_param: None,
+                active_generics: &BTreeSet::new(),
+                generic_bindings: &mut generic_bindings,
+            };
+            lowered.push(lower_arg(
+                ctx,
+                term,
+                type_ctx,
+                hoisted,
+                lowered_items,
+                variadic_functions,
+            )?);
+        }
+        let entry_value = lowered.pop().expect("lowered map value");
+        let entry_key = lowered.pop().expect("lowered map key");
+        entries.push((entry_key, entry_value));
+    }
+    let name = ctx.new_name();
+    lowered_items.push(BlockItem::MapDef(MapDef {
+        name: name.clone(),
+        key,
+        value,
+        entries,
+        span: map.span,
+    }));
+    Ok(name)
+}
+
+/// The key and value kinds every entry of a map literal shares, or `None` for `[:]`.
+fn map_entry_kinds(
+    ctx: &mut ctx::Context,
+    map: &ast::MapLit,
+) -> Result<Option<(SigKind, SigKind)>, Error> {
+    let mut key: Option<SigKind> = None;
+    let mut value: Option<SigKind> = None;
+    for (entry_key, entry_value) in &map.entries {
+        for (term, is_key) in [(entry_key, true), (entry_value, false)] {
+            if let ast::Term::Ident(ident) = term {
+                if ident.args.is_empty() && ctx.get(&ident.name).is_none() {
+                    // Left for lowering to report as undefined.
+                    continue;
+                }
+            }
+            let kind = term_sig_kind(ctx, term, true)
+                .map(|kind| canonicalize_kind(&signature::normalize_sig_kind(&kind, ctx)));
+            let allowed = kind.as_ref().is_some_and(|kind| {
+                if is_key {
+                    is_map_key_kind(kind)
+                } else {
+                    is_array_elem_kind(kind)
+                }
+            });
+            let found = kind
+                .as_ref()
+                .map(format_hir::format_sig_kind)
+                .unwrap_or_else(|| "a closure".to_string());
+            let Some(kind) = kind.filter(|_| allowed) else {
+                return Err(error::new(
+                    Code::HIR,
+                    if is_key {
+                        format!("map keys must be int or str, found {found}")
+                    } else {
+                        format!(
+                            "map values can only be int, byte, f64, str or array, found {found}"
+                        )
+                    },
+                    term.span(),
+                ));
+            };
+            let slot = if is_key { &mut key } else { &mut value };
+            match slot {
+                None => *slot = Some(kind),
+                Some(expected) if *expected != kind => {
+                    return Err(error::new(
+                        Code::HIR,
+                        format!(
+                            "map {} must all have the same type: expected {}, found {}",
+                            if is_key { "keys" } else { "values" },
+                            format_hir::format_sig_kind(expected),
+                            format_hir::format_sig_kind(&kind)
+                        ),
+                        term.span(),
+                    ));
+                }
+                Some(_) => {}
+            }
+        }
+    }
+    Ok(key.zip(value))
+}
+
+fn is_map_key_kind(kind: &SigKind) -> bool {
+    matches!(kind, SigKind::Int | SigKind::Str)
+}
+
+fn is_array_elem_kind(kind: &SigKind) -> bool {
+    match kind {
+        SigKind::Int | SigKind::Byte | SigKind::F64 | SigKind::Str => true,
+        SigKind::Array(elem) => is_array_elem_kind(elem),
+        _ => false,
+    }
+}
+
+/// Channels also carry the values that own an environment, which they hand
+/// from one coroutine to another without copying.
+fn is_chan_elem_kind(kind: &SigKind) -> bool {
+    is_array_elem_kind(kind)
+        || matches!(
+            kind,
+            SigKind::Sig(_) | SigKind::Record { .. } | SigKind::Sum { .. }
+        )
+}
+
+/// Registers a record type. Its fields keep the order they are declared in,
+/// which is also their order in memory and in a destructuring capture.
+fn lower_record_def(
+    ctx: &mut ctx::Context,
+    name: String,
+    fields: ast::Signature,
+    span: Span,
+) -> Result<(), Error> {
+    let field_spans = fields
+        .items
+        .iter()
+        .map(|item| item.span)
+        .collect::<Vec<_>>();
+    let hir_fields = signature::ast_signature_to_hir(fields);
+    let hir_fields = signature::resolve_signature(&hir_fields, ctx);
+    let hir_fields = signature::normalize_signature(&hir_fields, ctx);
+    for (field, field_span) in hir_fields.items.iter().zip(field_spans) {
+        if let Err(mut err) = ensure_sig_kind_exists(ctx, &field.kind, &BTreeSet::new()) {
+            if err.span.is_unknown() {
+                err.span = field_span;
+            }
+            return Err(err);
+        }
+        if matches!(field.kind, SigKind::Variadic) {
+            return Err(error::new(
+                Code::HIR,
+                format!("field `{}` cannot be variadic", field.name),
+                field_span,
+            ));
+        }
+    }
+    // Records declared inside a function get a unique label for their helpers,
+    // which also resolves to the record so that generated code can name it.
+    let label = ctx.new_name_for_fn(Some(&name));
+    let record = SigKind::Record {
+        name: label.clone(),
+        fields: hir_fields,
+    };
+    ctx.add_type(&name, &name, record.clone(), span, false)?;
+    if label != name {
+        ctx.add_type(&label, &label, record, span, false)?;
+    }
+    Ok(())
+}
+
+/// The record kind `name` declares, when it names a record type rather than
+/// a value of one.
+fn record_type(ctx: &ctx::Context, name: &str) -> Option<SigKind> {
+    let entry = ctx.get(name)?;
+    match &entry.kind {
+        SigKind::Record { .. } if entry.is_root => Some(entry.kind.clone()),
+        _ => None,
+    }
+}
+
+/// Builds the record `record` from `ident`'s arguments, one per field, given in
+/// order or by field name.
+fn lower_new_record(
+    ctx: &mut ctx::Context,
+    name: String,
+    record: SigKind,
+    ident: ast::Ident,
+    hoisted: &mut VecDeque<BlockItem>,
+    lowered_items: &mut Vec<BlockItem>,
+    variadic_functions: &HashMap<String, ast::Lambda>,
+) -> Result<(), Error> {
+    let SigKind::Record { fields, .. } = &record else {
+        unreachable!("record_type only returns records");
+    };
+    let span = ident.span;
+    let fields = lower_field_values(
+        ctx,
+        "record",
+        fields,
+        ident,
+        hoisted,
+        lowered_items,
+        variadic_functions,
+    )?;
+    lowered_items.push(BlockItem::NewRecord(NewRecord {
+        name,
+        record: record.clone(),
+        fields,
+        span,
+    }));
+    Ok(())
+}
+
+/// Lowers `ident`'s arguments to one value per item of `fields`, taking them in
+/// order or by name. `what` says what `ident` builds, for error messages.
+fn lower_field_values(
+    ctx: &mut ctx::Context,
+    what: &str,
+    fields: &Signature,
+    ident: ast::Ident,
+    hoisted: &mut VecDeque<BlockItem>,
+    lowered_items: &mut Vec<BlockItem>,
+    variadic_functions: &HashMap<String, ast::Lambda>,
+) -> Result<Vec<String>, Error> {
+    let owner = ident.name;
+    let mut terms: Vec<Option<ast::Term>> = vec![None; fields.items.len()];
+    for arg in ident.args {
+        let index = match &arg.name {
+            Some(field) => fields
+                .items
+                .iter()
+                .position(|item| &item.name == field)
+                .ok_or_else(|| {
+                    error::new(
+                        Code::HIR,
+                        format!("{what} `{owner}` has no field named `{field}`"),
+                        arg.span,
+                    )
+                })?,
+            None => terms.iter().position(Option::is_none).ok_or_else(|| {
+                error::new(
+                    Code::HIR,
+                    format!(
+                        "{what} `{owner}` has {} fields but was given more values",
+                        fields.items.len()
+                    ),
+                    arg.span,
+                )
+            })?,
+        };
+        if terms[index].is_some() {
+            return Err(error::new(
+                Code::HIR,
+                format!(
+                    "field `{}` of `{owner}` was given more than once",
+                    fields.items[index].name
+                ),
+                arg.span,
+            ));
+        }
+        terms[index] = Some(arg.term);
+    }
+
+    let mut values = Vec::with_capacity(fields.items.len());
+    for (term, field) in terms.into_iter().zip(&fields.items) {
+        let Some(term) = term else {
+            return Err(error::new(
+                Code::HIR,
+                format!("{what} `{owner}` is missing field `{}`", field.name),
+                ident.span,
+            ));
+        };
+        let mut generic_bindings = HashMap::new();
+        let type_ctx = LowerArgTypeContext {
+            expected_param: Some(field),
+            active_generics: &BTreeSet::new(),
+            generic_bindings: &mut generic_bindings,
+        };
+        values.push(lower_arg(
+            ctx,
+            term,
+            type_ctx,
+            hoisted,
+            lowered_items,
+            variadic_functions,
+        )?);
+    }
+    Ok(values)
+}
+
+/// Registers a sum type and its variants. The variants keep the order they are
+/// declared in, which also gives each one its tag.
+fn lower_sum_def(
+    ctx: &mut ctx::Context,
+    name: String,
+    variants: ast::Signature,
+    span: Span,
+) -> Result<(), Error> {
+    let variant_spans = variants
+        .items
+        .iter()
+        .map(|item| item.span)
+        .collect::<Vec<_>>();
+    // Like records, sums declared inside a function get a unique label. The
+    // name stands for the label while the payloads resolve, so that a variant
+    // can hold a value of the sum itself.
+    let label = ctx.new_name_for_fn(Some(&name));
+    let placeholder = SigKind::Ident(SigIdent {
+        name: label.clone(),
+        span,
+    });
+    ctx.add_type(&name, &name, placeholder.clone(), span, false)?;
+    if label != name {
+        ctx.add_type(&label, &label, placeholder, span, false)?;
+    }
+    let hir_variants = signature::ast_signature_to_hir(variants);
+    let hir_variants = signature::resolve_signature(&hir_variants, ctx);
+    let hir_variants = signature::normalize_signature(&hir_variants, ctx);
+    if let Err(err) = check_sum_variants(ctx, &name, &hir_variants, &variant_spans) {
+        // Drop the placeholder so that later uses report the type as undefined.
+        ctx.inner.remove(&name);
+        ctx.inner.remove(&label);
+        return Err(err);
+    }
+    let sum = SigKind::Sum {
+        name: label.clone(),
+        variants: hir_variants,
+    };
+    for key in [&name, &label] {
+        if let Some(entry) = ctx.get_mut(key) {
+            entry.kind = sum.clone();
+        }
+    }
+    let SigKind::Sum { variants, .. } = &sum else {
+        unreachable!();
+    };
+    for (variant, variant_span) in variants.items.iter().zip(variant_spans) {
+        ctx.add_type(
+            &variant.name,
+            &variant.name,
+            sum.clone(),
+            variant_span,
+            false,
+        )?;
+    }
+    Ok(())
+}
+
+/// Checks that no variant shadows the sum and that every payload item has a
+/// known, non-variadic kind.
+fn check_sum_variants(
+    ctx: &ctx::Context,
+    sum_name: &str,
+    variants: &Signature,
+    spans: &[Span],
+) -> Result<(), Error> {
+    for (variant, span) in variants.items.iter().zip(spans) {
+        if variant.name == sum_name {
+            return Err(error::new(
+                Code::HIR,
+                format!("variant `{}` has the same name as its type", variant.name),
+                *span,
+            ));
+        }
+        let SigKind::Sig(payload) = &variant.kind else {
+            unreachable!("the parser gives every variant a payload signature");
+        };
+        for field in &payload.items {
+            if let Err(mut err) = ensure_sig_kind_exists(ctx, &field.kind, &BTreeSet::new()) {
+                if err.span.is_unknown() {
+                    err.span = *span;
+                }
+                return Err(err);
+            }
+            if matches!(field.kind, SigKind::Variadic) {
+                return Err(error::new(
+                    Code::HIR,
+                    format!("field `{}` cannot be variadic", field.name),
+                    *span,
+                ));
+            }
+            if matches!(field.kind, SigKind::Refined(..)) {
+                return Err(error::new(
+                    Code::HIR,
+                    format!("field `{}` cannot be refined", field.name),
+                    *span,
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The sum `name` is a variant of and the variant's tag, when `name` names a
+/// variant rather than a value of the sum.
+fn sum_variant(ctx: &ctx::Context, name: &str) -> Option<(SigKind, usize)> {
+    let entry = ctx.get(name)?;
+    let SigKind::Sum { variants, .. } = &entry.kind else {
+        return None;
+    };
+    if !entry.is_root {
+        return None;
+    }
+    let tag = variants
+        .items
+        .iter()
+        .position(|variant| variant.name == entry.name)?;
+    Some((unfold_sum(&entry.kind), tag))
+}
+
+/// `sum` with the payload items that hold the sum itself spelled out, so that
+/// later stages see their kind without looking the label up.
+fn unfold_sum(sum: &SigKind) -> SigKind {
+    let SigKind::Sum { name, variants } = sum else {
+        return sum.clone();
+    };
+    let mut unfolded = variants.clone();
+    for variant in &mut unfolded.items {
+        let SigKind::Sig(payload) = &mut variant.kind else {
+            continue;
+        };
+        for field in &mut payload.items {
+            if matches!(&field.kind, SigKind::Ident(ident) if &ident.name == name) {
+                field.kind = sum.clone();
+            }
+        }
+    }
+    SigKind::Sum {
+        name: name.clone(),
+        variants: unfolded,
+    }
+}
+
+/// Builds variant `tag` of `sum` from `ident`'s arguments, one per payload
+/// item, given in order or by name.
+fn lower_new_variant(
+    ctx: &mut ctx::Context,
+    name: String,
+    (sum, tag): (SigKind, usize),
+    ident: ast::Ident,
+    hoisted: &mut VecDeque<BlockItem>,
+    lowered_items: &mut Vec<BlockItem>,
+    variadic_functions: &HashMap<String, ast::Lambda>,
+) -> Result<(), Error> {
+    let SigKind::Sum { variants, .. } = &sum else {
+        unreachable!("sum_variant only returns sums");
+    };
+    let SigKind::Sig(payload) = &variants.items[tag].kind else {
+        unreachable!("variants hold payload signatures");
+    };
+    let span = ident.span;
+    let payload = lower_field_values(
+        ctx,
+        "variant",
+        payload,
+        ident,
+        hoisted,
+        lowered_items,
+        variadic_functions,
+    )?;
+    lowered_items.push(BlockItem::NewVariant(NewVariant {
+        name,
+        sum,
+        tag,
+        payload,
+        span,
+    }));
+    Ok(())
+}
+
+/// Lowers `value { circle(r: int) { ... } empty { ... } }`. Every variant needs
+/// exactly one arm, and each arm becomes a continuation taking its payload.
+fn lower_case(
+    ctx: &mut ctx::Context,
+    case: ast::Case,
+    hoisted: &mut VecDeque<BlockItem>,
+    variadic_functions: &HashMap<String, ast::Lambda>,
+) -> Result<Vec<BlockItem>, Error> {
+    let kind = match &case.value {
+        ast::Term::Ident(ident)
+            if ctx.get(&ident.name).is_some_and(|entry| {
+                entry.is_root && matches!(entry.kind, SigKind::Sum { .. })
+            }) =>
+        {
+            return Err(error::new(
+                Code::HIR,
+                format!("`{}` is a type, not a value to case on", ident.name),
+                ident.span,
+            ));
+        }
+        ast::Term::Ident(ident) => {
+            let entry = ctx.get(&ident.name).ok_or_else(|| {
+                error::new(
+                    Code::HIR,
+                    format!("`{}` is not defined", ident.name),
+                    ident.span,
+                )
+            })?;
+            signature::normalize_sig_kind(&entry.kind, ctx)
+        }
+        ast::Term::Field(access) => {
+            let (record, index) = resolve_field_access(ctx, access)?;
+            signature::normalize_sig_kind(&record_field_kind(&record, index), ctx)
+        }
+        other => {
+            return Err(error::new(
+                Code::HIR,
+                "expected a sum value before the case arms",
+                other.span(),
+            ))
+        }
+    };
+    let SigKind::Sum { name, variants } = &kind else {
+        return Err(error::new(
+            Code::HIR,
+            format!(
+                "case analysis needs a sum value, but this is {}",
+                format_hir::format_sig_kind(&kind)
+            ),
+            case.value.span(),
+        ));
+    };
+
+    let mut arms: Vec<Option<ast::CaseArm>> = vec![None; variants.items.len()];
+    for arm in case.arms {
+        let index = variants
+            .items
+            .iter()
+            .position(|variant| variant.name == arm.variant)
+            .ok_or_else(|| {
+                error::new(
+                    Code::HIR,
+                    format!("sum `{name}` has no variant named `{}`", arm.variant),
+                    arm.span,
+                )
+            })?;
+        if arms[index].is_some() {
+            return Err(error::new(
+                Code::HIR,
+                format!("variant `{}` is handled more than once", arm.variant),
+                arm.span,
+            ));
+        }
+        arms[index] = Some(arm);
+    }
+    let missing = variants
+        .items
+        .iter()
+        .zip(&arms)
+        .filter(|(_, arm)| arm.is_none())
+        .map(|(variant, _)| format!("`{}`", variant.name))
+        .collect::<Vec<_>>();
+    if !missing.is_empty() {
+        return Err(error::new(
+            Code::HIR,
+            format!(
+                "case on `{name}` is not exhaustive: missing {}",
+                missing.join(", ")
+            ),
+            case.span,
+        ));
+    }
+
+    let mut lowered_items = Vec::new();
+    let value = match case.value {
+        ast::Term::Ident(ident) => {
+            maybe_capture_name(ctx, &ident.name, ident.span)?;
+            ident.name
+        }
+        ast::Term::Field(access) => lower_field_access(ctx, access, &mut lowered_items)?,
+        _ => unreachable!("checked above"),
+    };
+    let sum = unfold_sum(&kind);
+    let SigKind::Sum { variants, .. } = &sum else {
+        unreachable!();
+    };
+    let mut lowered_arms = Vec::with_capacity(arms.len());
+    for (arm, variant) in arms.into_iter().zip(&variants.items) {
+        let arm = arm.expect("checked for exhaustiveness");
+        let mut generic_bindings = HashMap::new();
+        let type_ctx = LowerArgTypeContext {
+            expected_param: Some(variant),
+            active_generics: &BTreeSet::new(),
+            generic_bindings: &mut generic_bindings,
+        };
+        lowered_arms.push(lower_arg(
+            ctx,
+            ast::Term::Lambda(arm.lambda),
+            type_ctx,
+            hoisted,
+            &mut lowered_items,
+            variadic_functions,
+        )?);
+    }
+    lowered_items.push(BlockItem::Case(Case {
+        value,
+        sum,
+        arms: lowered_arms,
+        span: case.span,
+    }));
+    Ok(lowered_items)
+}
+
+/// The record `access` reads from and the index of the field it names.
+fn resolve_field_access(
+    ctx: &ctx::Context,
+    access: &ast::FieldAccess,
+) -> Result<(SigKind, usize), Error> {
+    let record = match access.record.as_ref() {
+        ast::Term::Ident(ident) => {
+            if record_type(ctx, &ident.name).is_some() {
+                return Err(error::new(
+                    Code::HIR,
+                    format!("`{}` is a record type, not a record", ident.name),
+                    ident.span,
+                ));
+            }
+            let entry = ctx.get(&ident.name).ok_or_else(|| {
+                error::new(
+                    Code::HIR,
+                    format!("`{}` is not defined", ident.name),
+                    ident.span,
+                )
+            })?;
+            signature::normalize_sig_kind(&entry.kind, ctx)
+        }
+        ast::Term::Field(inner) => {
+            let (record, index) = resolve_field_access(ctx, inner)?;
+            record_field_kind(&record, index)
+        }
+        other => {
+            return Err(error::new(
+                Code::HIR,
+                "expected a record before `.`",
+                other.span(),
+            ))
+        }
+    };
+    let SigKind::Record { name, fields } = &record else {
+        return Err(error::new(
+            Code::HIR,
+            format!(
+                "`.{}` reads a record field, but this is {}",
+                access.field,
+                format_hir::format_sig_kind(&record)
+            ),
+            access.record.span(),
+        ));
+    };
+    let index = fields
+        .items
+        .iter()
+        .position(|item| item.name == access.field)
+        .ok_or_else(|| {
+            error::new(
+                Code::HIR,
+                format!("record `{name}` has no field named `{}`", access.field),
+                access.span,
+            )
+        })?;
+    Ok((record, index))
+}
+
+fn record_field_kind(record: &SigKind, index: usize) -> SigKind {
+    match record {
+        SigKind::Record { fields, .. } => fields.items[index].kind.clone(),
+        _ => unreachable!("fields are only read from records"),
+    }
+}
+
+/// Reads a field into a fresh binding. The record is left whole, so reading
+/// an owned field hands out a copy of it.
+fn lower_field_access(
+    ctx: &mut ctx::Context,
+    access: ast::FieldAccess,
+    lowered_items: &mut Vec<BlockItem>,
+) -> Result<String, Error> {
+    let (kind, index) = resolve_field_access(ctx, &access)?;
+    let record = match *access.record {
+        ast::Term::Ident(ident) => {
+            maybe_capture_name(ctx, &ident.name, ident.span)?;
+            ident.name
+        }
+        ast::Term::Field(inner) => lower_field_access(ctx, inner, lowered_items)?,
+        _ => unreachable!("checked by resolve_field_access"),
     };
+    let name = ctx.new_name();
+    lowered_items.push(BlockItem::FieldDef(FieldDef {
+        name: name.clone(),
+        record,
+        kind,
+        index,
+        span: access.span,
+    }));
+    Ok(name)
+}
 
-    let mut seen = HashSet::new();
-    Ok(emit_closure_for_term(ctx, &arg, lowered_items, &mut seen))
+/// The fields of the record a scope capture destructures, when `term` names a
+/// record value rather than something to call.
+fn destructured_record_fields(ctx: &ctx::Context, term: &ast::Term) -> Option<Signature> {
+    let ast::Term::Ident(ident) = term else {
+        return None;
+    };
+    if !ident.args.is_empty() || record_type(ctx, &ident.name).is_some() {
+        return None;
+    }
+    let entry = ctx.get(&ident.name)?;
+    match signature::normalize_sig_kind(&entry.kind, ctx) {
+        SigKind::Record { fields, .. } => Some(fields),
+        _ => None,
+    }
+}
+
+/// `(x: int, y: int) = p` passes `p.x` and `p.y` to the capture, matching
+/// fields by position.
+fn destructure_record_args(
+    params: &ast::Signature,
+    term: &ast::Term,
+    fields: Signature,
+) -> Result<Vec<ast::Arg>, Error> {
+    if params.items.len() != fields.items.len() {
+        return Err(error::new(
+            Code::HIR,
+            format!(
+                "the record has {} fields but the capture lists {}",
+                fields.items.len(),
+                params.items.len()
+            ),
+            params.span,
+        ));
+    }
+    Ok(fields
+        .items
+        .into_iter()
+        .map(|field| ast::Arg {
+            name: None,
+            term: ast::Term::Field(ast::FieldAccess {
+                record: Box::new(term.clone()),
+                field: field.name,
+                span: term.span(),
+            }),
+            span: term.span(),
+        })
+        .collect())
 }
 
 fn builtin_reference_name(name: &str) -> Option<&str> {
@@ -787,6 +1992,7 @@ fn resolve_target(
     ctx: &mut ctx::Context,
     name: &str,
     ast_args: Vec<ast::Arg>,
+    span: Span,
     hoisted: &mut VecDeque<BlockItem>,
     lowered_items: &mut Vec<BlockItem>,
     variadic_functions: &HashMap<String, ast::Lambda>,
@@ -795,12 +2001,12 @@ fn resolve_target(
         error::new(
             Code::HIR,
             format!("could not resolve target '{}'", name),
-            Span::unknown(), // TODO:
+            span,
         )
     })?;
 
     for cap in &target.captures {
-        maybe_capture_name(ctx, &cap.name)?;
+        maybe_capture_name(ctx, &cap.name, span)?;
     }
 
     let args: Vec<String> = target.captures.iter().map(|cap| cap.name.clone()).collect();
@@ -822,30 +2028,52 @@ fn resolve_target(
     let resolved = resolve_call_arguments(ctx, &target, signature, ast_args, hoisted)?;
     let mut args: Vec<String> = Vec::with_capacity(resolved.target.captures.len());
     for cap in &resolved.target.captures {
-        maybe_capture_name(ctx, &cap.name)?;
+        maybe_capture_name(ctx, &cap.name, span)?;
         args.push(cap.name.clone());
     }
     let expected_params =
         signature::expected_params_for_args(&resolved.signature.items, resolved.terms.len());
+    if resolved.target.is_builtin {
+        check_format_arguments(ctx, &resolved.signature, &resolved.terms, &expected_params)?;
+    }
 
     let mut lowered = Vec::with_capacity(resolved.terms.len());
     let mut active_generics = current_scope_generics(ctx);
     active_generics.extend(resolved.signature.generics.iter().cloned());
     let mut generic_bindings = HashMap::new();
-    for (term, expected_param) in resolved.terms.into_iter().zip(expected_params) {
+    let narrowing = comparison_narrowing(ctx, &resolved.target, &resolved.terms);
+    for (index, (term, expected_param)) in
+        resolved.terms.into_iter().zip(expected_params).enumerate()
+    {
         let type_ctx = LowerArgTypeContext {
             expected_param,
             active_generics: &active_generics,
             generic_bindings: &mut generic_bindings,
         };
-        lowered.push(lower_arg(
+        // The continuations see the compared value with what they learned.
+        let narrowed = narrowing.as_ref().and_then(|narrowing| match index {
+            2 => Some((&narrowing.name, &narrowing.taken)),
+            3 => Some((&narrowing.name, &narrowing.not_taken)),
+            _ => None,
+        });
+        let saved = narrowed.and_then(|(name, kind)| {
+            ctx.get_mut(name)
+                .map(|entry| std::mem::replace(&mut entry.kind, kind.clone()))
+        });
+        let arg = lower_arg(
             ctx,
             term,
             type_ctx,
             hoisted,
             lowered_items,
             variadic_functions,
-        )?);
+        );
+        if let (Some((name, _)), Some(kind)) = (narrowed, saved) {
+            if let Some(entry) = ctx.get_mut(name) {
+                entry.kind = kind;
+            }
+        }
+        lowered.push(arg?);
     }
 
     args.extend(lowered);
@@ -859,12 +2087,79 @@ fn resolve_target(
                 total_param_count,
                 args.len()
             ),
-            Span::unknown(),
+            span,
         ));
     }
     Ok((resolved.target, args))
 }
 
+/// What a comparison builtin proves about a value it compares with a literal:
+/// `name` has kind `taken` in the first continuation and `not_taken` in the
+/// second.
+struct Narrowing {
+    name: String,
+    taken: SigKind,
+    not_taken: SigKind,
+}
+
+fn comparison_narrowing(
+    ctx: &ctx::Context,
+    target: &ContextEntry,
+    terms: &[ast::Term],
+) -> Option<Narrowing> {
+    if !target.is_builtin {
+        return None;
+    }
+    let builtin = builtins::Builtin::from_name(ctx.imports.get(&target.name)?)?;
+    let comparison = Comparison::of_builtin(builtin)?;
+    let (name, comparison, value) = match (terms.first()?, terms.get(1)?) {
+        (ast::Term::Ident(ident), literal) if ident.args.is_empty() => {
+            (&ident.name, comparison, bound_value(literal)?)
+        }
+        (literal, ast::Term::Ident(ident)) if ident.args.is_empty() => {
+            (&ident.name, comparison.flip(), bound_value(literal)?)
+        }
+        _ => return None,
+    };
+    let (base, bounds) = match signature::normalize_sig_kind(&ctx.get(name)?.kind, ctx) {
+        SigKind::Refined(base, bounds) => (*base, bounds),
+        base @ (SigKind::Int | SigKind::Str) => (base, Vec::new()),
+        _ => return None,
+    };
+    let refine = |comparison| {
+        let mut bounds = bounds.clone();
+        bounds.push(Bound {
+            comparison,
+            value: value.clone(),
+        });
+        SigKind::Refined(Box::new(base.clone()), bounds)
+    };
+    let bound = Bound {
+        comparison,
+        value: value.clone(),
+    };
+    refinement::check_bounds(&base, &[bound]).ok()?;
+    Some(Narrowing {
+        name: name.clone(),
+        taken: refine(comparison),
+        not_taken: refine(comparison.negate()),
+    })
+}
+
+fn bound_value(term: &ast::Term) -> Option<BoundValue> {
+    match term {
+        ast::Term::Lit(ast::Literal {
+            value: ast::Lit::Int(value),
+            ..
+        }) => Some(BoundValue::Int(*value as i64)),
+        ast::Term::Lit(ast::Literal {
+            value: ast::Lit::Str(value),
+            ..
+        }) => Some(BoundValue::Str(value.clone())),
+        _ => None,
+    }
+}
+
 fn current_scope_generics(ctx: &ctx::Context) -> BTreeSet<String> {
     if let Some(entry) = ctx.get(&ctx.name) {
         if let SigKind::Sig(signature) = &entry.kind {
@@ -902,6 +2197,7 @@ fn resolve_call_arguments(
     let mut resolved_terms = Vec::with_capacity(ast_args.len());
 
     for call_arg in ast_args {
+        let arg_span = call_arg.span;
         let param_index = if let Some(arg_name) = call_arg.name {
             params
                 .iter()
@@ -913,7 +2209,7 @@ fn resolve_call_arguments(
                             "function '{}' has no parameter named '{}'",
                             target.name, arg_name
                         ),
-                        Span::unknown(),
+                        arg_span,
                     )
                 })?
         } else {
@@ -926,7 +2222,7 @@ fn resolve_call_arguments(
                         target.captures.len() + params.len(),
                         target.captures.len() + resolved_indices.len() + 1
                     ),
-                    Span::unknown(),
+                    arg_span,
                 )
             })?
         };
@@ -943,7 +2239,7 @@ fn resolve_call_arguments(
                     "function '{}' argument '{}' was provided more than once",
                     target.name, dup_name
                 ),
-                Span::unknown(),
+                arg_span,
             ));
         }
 
@@ -1079,8 +2375,10 @@ fn create_named_arg_wrapper(
             items: vec![BlockItem::Exec(Exec {
                 of: target.name.clone(),
                 args: exec_args,
+                span: target.span,
             })],
         },
+        span: target.span,
     }));
 
     let wrapper_target = ctx.get(&wrapper_name).cloned().ok_or_else(|| {
@@ -1126,6 +2424,7 @@ fn ensure_exec_args_complete(
     ctx: &ctx::Context,
     target: &ContextEntry,
     arg_count: usize,
+    span: Span,
 ) -> Result<(), Error> {
     let mut seen = HashSet::new();
     let Some(signature) = signature::signature_from_kind(&target.kind, ctx, &mut seen) else {
@@ -1141,7 +2440,7 @@ fn ensure_exec_args_complete(
             "cannot execute function '{}': not all args have been provided (expected at least {}, got {})",
             target.name, required_count, arg_count
         ),
-        Span::unknown(),
+        span,
     ))
 }
 
@@ -1248,6 +2547,11 @@ fn validate_input_type(
 
     let normalized_expected = signature::normalize_sig_kind(&expected.kind, ctx);
     ensure_sig_kind_exists(ctx, &normalized_expected, active_generics)?;
+    if let SigKind::Refined(base, bounds) = &normalized_expected {
+        return validate_refined_arg(ctx, term, &normalized_expected, base, bounds);
+    }
+    validate_named_function_arg(ctx, term, &normalized_expected)?;
+    validate_generic_arg(ctx, term, &normalized_expected, active_generics)?;
 
     let expected_is_compile_time = matches!(
         normalized_expected,
@@ -1260,6 +2564,14 @@ fn validate_input_type(
     };
     let allow_idents = expected_is_compile_time
         || expected_is_unit_sig
+        || matches!(
+            normalized_expected,
+            SigKind::Array(_)
+                | SigKind::Map(..)
+                | SigKind::Chan(_)
+                | SigKind::Record { .. }
+                | SigKind::Sum { .. }
+        )
         || has_generic_kind(&normalized_expected, active_generics);
 
     let Some(actual_kind) = term_sig_kind(ctx, term, allow_idents) else {
@@ -1288,10 +2600,187 @@ fn validate_input_type(
             format_hir::format_sig_kind(&normalized_expected),
             format_hir::format_sig_kind(&normalized_actual)
         ),
-        Span::unknown(),
+        term.span(),
+    ))
+}
+
+/// Checks that `term` is known to pass `bounds`: it is a literal that passes
+/// them, or a value whose own refinement implies them.
+fn validate_refined_arg(
+    ctx: &mut ctx::Context,
+    term: &ast::Term,
+    expected: &SigKind,
+    base: &SigKind,
+    bounds: &[Bound],
+) -> Result<(), Error> {
+    let is_str = matches!(base, SigKind::Str);
+    if let Some(value) = bound_value(term) {
+        if matches!(value, BoundValue::Str(_)) == is_str && refinement::allows(bounds, &value) {
+            return Ok(());
+        }
+        return Err(error::new(
+            Code::HIR,
+            format!(
+                "`{value}` does not pass `{}`",
+                format_hir::format_sig_kind(expected)
+            ),
+            term.span(),
+        ));
+    }
+
+    let Some(actual) = term_sig_kind(ctx, term, true) else {
+        return Ok(());
+    };
+    let actual = signature::normalize_sig_kind(&actual, ctx);
+    if let SigKind::Refined(actual_base, actual_bounds) = &actual {
+        if canonicalize_kind(actual_base) == canonicalize_kind(base)
+            && refinement::implies(actual_bounds, bounds, is_str)
+        {
+            return Ok(());
+        }
+    }
+    let err = error::new(
+        Code::HIR,
+        format!(
+            "expected {}, found {}",
+            format_hir::format_sig_kind(expected),
+            format_hir::format_sig_kind(&actual)
+        ),
+        term.span(),
+    );
+    let ast::Term::Ident(ident) = term else {
+        return Err(err);
+    };
+    let help = if is_str {
+        format!(
+            "compare it first, as in `@eqs({}, \"\", empty, nonempty)`",
+            ident.name
+        )
+    } else {
+        format!(
+            "compare it first, as in `@{}({}, {}, ...)`",
+            bounds[0].comparison.name(),
+            ident.name,
+            bounds[0].value
+        )
+    };
+    Err(err.with_help(help))
+}
+
+/// Checks a function passed by name, or partially applied, against an expected
+/// function type the way a lambda's parameters are checked: every refined
+/// parameter on either side has to be refined the same way on the other, or a
+/// refined function could be called with values nobody checked.
+fn validate_named_function_arg(
+    ctx: &mut ctx::Context,
+    term: &ast::Term,
+    expected: &SigKind,
+) -> Result<(), Error> {
+    let (SigKind::Sig(_), ast::Term::Ident(ident)) = (expected, term) else {
+        return Ok(());
+    };
+    let Some(actual) = named_function_kind(ctx, ident) else {
+        return Ok(());
+    };
+    if refinements_agree(&actual, expected) {
+        return Ok(());
+    }
+    Err(error::new(
+        Code::HIR,
+        format!(
+            "expected {}, found {}",
+            format_hir::format_sig_kind(expected),
+            format_hir::format_sig_kind(&actual)
+        ),
+        term.span(),
+    ))
+}
+
+/// The function type `ident` passes when it names a function, with the
+/// parameters it applies already taken off.
+fn named_function_kind(ctx: &ctx::Context, ident: &ast::Ident) -> Option<SigKind> {
+    if sum_variant(ctx, &ident.name).is_some() || record_type(ctx, &ident.name).is_some() {
+        return None;
+    }
+    let entry = ctx.get(&ident.name)?;
+    let SigKind::Sig(mut actual) = signature::normalize_sig_kind(&entry.kind.clone(), ctx) else {
+        return None;
+    };
+    let applied = ident.args.len().min(actual.items.len());
+    actual.items.drain(0..applied);
+    Some(SigKind::Sig(actual))
+}
+
+/// Rejects a value with refinements inside its type where a generic is
+/// expected. Generics bind to the erased kind, so a `T` or channel element
+/// could otherwise hand `quot` to a caller that never proves `y` nonzero.
+fn validate_generic_arg(
+    ctx: &mut ctx::Context,
+    term: &ast::Term,
+    expected: &SigKind,
+    active_generics: &BTreeSet<String>,
+) -> Result<(), Error> {
+    if !has_generic_kind(expected, active_generics) {
+        return Ok(());
+    }
+    let actual = match term {
+        ast::Term::Ident(ident) => named_function_kind(ctx, ident),
+        _ => None,
+    }
+    .or_else(|| term_sig_kind(ctx, term, true))
+    .map(|kind| signature::normalize_sig_kind(&kind, ctx));
+    let Some(actual) = actual.filter(refines_inside) else {
+        return Ok(());
+    };
+    Err(error::new(
+        Code::HIR,
+        format!(
+            "{} cannot be passed as {}, which would drop its refinements",
+            format_hir::format_sig_kind(&actual),
+            format_hir::format_sig_kind(expected)
+        ),
+        term.span(),
     ))
 }
 
+/// Whether the refined parameters of two function types line up, looking
+/// into parameters that are functions themselves. Mismatches of any other kind
+/// are left to the checks that already cover them.
+fn refinements_agree(actual: &SigKind, expected: &SigKind) -> bool {
+    match (actual, expected) {
+        (SigKind::Refined(..), _) | (_, SigKind::Refined(..)) => same_refinement(actual, expected),
+        (SigKind::Sig(actual_sig), SigKind::Sig(expected_sig))
+            if actual_sig.items.len() == expected_sig.items.len() =>
+        {
+            actual_sig
+                .items
+                .iter()
+                .zip(&expected_sig.items)
+                .all(|(actual_item, expected_item)| {
+                    refinements_agree(&actual_item.kind, &expected_item.kind)
+                })
+        }
+        _ => true,
+    }
+}
+
+/// Whether two continuation parameters are refined the same way. A narrower
+/// one would reject values the caller is free to pass, and a wider one would
+/// claim bounds nobody checked.
+fn same_refinement(actual: &SigKind, expected: &SigKind) -> bool {
+    let (
+        SigKind::Refined(actual_base, actual_bounds),
+        SigKind::Refined(expected_base, expected_bounds),
+    ) = (actual, expected)
+    else {
+        return false;
+    };
+    let is_str = matches!(canonicalize_kind(expected_base), SigKind::Str);
+    canonicalize_kind(actual_base) == canonicalize_kind(expected_base)
+        && refinement::implies(actual_bounds, expected_bounds, is_str)
+        && refinement::implies(expected_bounds, actual_bounds, is_str)
+}
+
 fn ensure_sig_kind_exists(
     ctx: &ctx::Context,
     kind: &SigKind,
@@ -1303,10 +2792,13 @@ fn ensure_sig_kind_exists(
                 return Ok(());
             }
             if ctx.get(&ident.name).is_none() {
-                return Err(error::new(
-                    Code::HIR,
-                    format!("type `{}` is not defined", ident.name),
-                    Span::unknown(),
+                return Err(with_import_hint(
+                    error::new(
+                        Code::HIR,
+                        format!("type `{}` is not defined", ident.name),
+                        ident.span,
+                    ),
+                    &ident.name,
                 ));
             }
         }
@@ -1315,23 +2807,141 @@ fn ensure_sig_kind_exists(
                 ensure_sig_kind_exists(ctx, &item.kind, active_generics)?;
             }
         }
-        SigKind::GenericInst { name, args } => {
+        SigKind::GenericInst { name, args, span } => {
             if ctx.get(name).is_none() {
+                return Err(with_import_hint(
+                    error::new(Code::HIR, format!("type `{}` is not defined", name), *span),
+                    name,
+                ));
+            }
+            for arg in args {
+                ensure_sig_kind_exists(ctx, arg, active_generics)?;
+            }
+        }
+        SigKind::Array(elem) => ensure_sig_kind_exists(ctx, elem, active_generics)?,
+        SigKind::Map(key, value) => {
+            ensure_sig_kind_exists(ctx, key, active_generics)?;
+            ensure_sig_kind_exists(ctx, value, active_generics)?;
+            let key = canonicalize_kind(&signature::normalize_sig_kind(key, ctx));
+            if !is_map_key_kind(&key) && !has_generic_kind(&key, active_generics) {
                 return Err(error::new(
                     Code::HIR,
-                    format!("type `{}` is not defined", name),
+                    format!(
+                        "map keys must be int or str, found {}",
+                        format_hir::format_sig_kind(&key)
+                    ),
                     Span::unknown(),
                 ));
             }
-            for arg in args {
-                ensure_sig_kind_exists(ctx, arg, active_generics)?;
+            let value = canonicalize_kind(&signature::normalize_sig_kind(value, ctx));
+            if !is_array_elem_kind(&value) && !has_generic_kind(&value, active_generics) {
+                return Err(error::new(
+                    Code::HIR,
+                    format!(
+                        "map values can only be int, byte, f64, str or array, found {}",
+                        format_hir::format_sig_kind(&value)
+                    ),
+                    Span::unknown(),
+                ));
+            }
+        }
+        SigKind::Chan(elem) => {
+            ensure_sig_kind_exists(ctx, elem, active_generics)?;
+            let elem = canonicalize_kind(&signature::normalize_sig_kind(elem, ctx));
+            if !is_chan_elem_kind(&elem) && !has_generic_kind(&elem, active_generics) {
+                return Err(error::new(
+                    Code::HIR,
+                    format!(
+                        "channels can only carry int, byte, f64, str, array, closure, record or sum values, found {}",
+                        format_hir::format_sig_kind(&elem)
+                    ),
+                    Span::unknown(),
+                ));
             }
         }
+        SigKind::Refined(base, _) => ensure_sig_kind_exists(ctx, base, active_generics)?,
         _ => {}
     }
     Ok(())
 }
 
+/// Checks a literal printf-style format against the variadic arguments that
+/// follow it. Calls that have not been given their continuation yet are left
+/// alone, since their variadic arguments are not known until they are.
+fn check_format_arguments(
+    ctx: &mut ctx::Context,
+    signature: &Signature,
+    terms: &[ast::Term],
+    expected_params: &[Option<&SigItem>],
+) -> Result<(), Error> {
+    let (Some(format_item), Some(variadic_item)) =
+        (signature.items.first(), signature.items.get(1))
+    else {
+        return Ok(());
+    };
+    if !matches!(format_item.kind, SigKind::CompileTimeStr)
+        || !matches!(variadic_item.kind, SigKind::Variadic)
+        || terms.len() < signature.items.len() - 1
+    {
+        return Ok(());
+    }
+    let Some(ast::Term::Lit(ast::Literal {
+        value: ast::Lit::Str(format),
+        span: format_span,
+    })) = terms.first()
+    else {
+        return Ok(());
+    };
+    let slots =
+        builtins::format_slots(format).map_err(|msg| error::new(Code::HIR, msg, *format_span))?;
+    let values: Vec<&ast::Term> = terms
+        .iter()
+        .zip(expected_params)
+        .filter(|(_, param)| matches!(param.map(|item| &item.kind), Some(SigKind::Variadic)))
+        .map(|(term, _)| term)
+        .collect();
+    if slots.len() != values.len() {
+        return Err(error::new(
+            Code::HIR,
+            format!(
+                "format string needs {} arguments but {} were given",
+                slots.len(),
+                values.len()
+            ),
+            *format_span,
+        ));
+    }
+    for (slot, term) in slots.iter().zip(values) {
+        let Some(kind) = term_sig_kind(ctx, term, true) else {
+            continue;
+        };
+        let kind = refinement::erase_kind(&signature::normalize_sig_kind(&kind, ctx));
+        let accepted = match (slot.arg, &kind) {
+            (_, SigKind::Ident(_) | SigKind::Generic(_) | SigKind::GenericInst { .. }) => true,
+            (builtins::FormatArg::Int, SigKind::Int | SigKind::Byte | SigKind::CompileTimeInt) => {
+                true
+            }
+            (builtins::FormatArg::Str, SigKind::Str | SigKind::CompileTimeStr) => true,
+            (builtins::FormatArg::F64, SigKind::F64) => true,
+            (builtins::FormatArg::Pointer, kind) => !matches!(kind, SigKind::F64),
+            _ => false,
+        };
+        if !accepted {
+            return Err(error::new(
+                Code::HIR,
+                format!(
+                    "`{}` expects {}, found {}",
+                    slot.conversion,
+                    slot.arg.describe(),
+                    format_hir::format_sig_kind(&kind)
+                ),
+                term.span(),
+            ));
+        }
+    }
+    Ok(())
+}
+
 fn term_sig_kind(ctx: &mut ctx::Context, term: &ast::Term, allow_idents: bool) -> Option<SigKind> {
     match term {
         ast::Term::Lit(ast::Literal {
@@ -1346,8 +2956,14 @@ fn term_sig_kind(ctx: &mut ctx::Context, term: &ast::Term, allow_idents: bool) -
             value: ast::Lit::F64(_),
             ..
         }) => Some(SigKind::F64),
+        ast::Term::Ident(ast_ident) if sum_variant(ctx, &ast_ident.name).is_some() => {
+            sum_variant(ctx, &ast_ident.name).map(|(sum, _)| sum)
+        }
+        ast::Term::Ident(ast_ident) if !ast_ident.args.is_empty() => {
+            record_type(ctx, &ast_ident.name)
+        }
         ast::Term::Ident(ast_ident) => {
-            if allow_idents && ast_ident.args.is_empty() {
+            if allow_idents {
                 ctx.get(&ast_ident.name).map(|entry| entry.kind.clone())
             } else {
                 None
@@ -1362,6 +2978,21 @@ fn term_sig_kind(ctx: &mut ctx::Context, term: &ast::Term, allow_idents: bool) -
             signature.items.drain(0..drop_count);
             Some(SigKind::Sig(signature))
         }
+        // The first item stands for the rest; `array_elem_kind` checks they agree.
+        ast::Term::Array(array) => {
+            let first = array.items.first()?;
+            let elem = term_sig_kind(ctx, first, true)?;
+            Some(SigKind::Array(Box::new(elem)))
+        }
+        ast::Term::Map(map) => {
+            let (key, value) = map.entries.first()?;
+            let key = term_sig_kind(ctx, key, true)?;
+            let value = term_sig_kind(ctx, value, true)?;
+            Some(SigKind::Map(Box::new(key), Box::new(value)))
+        }
+        ast::Term::Field(access) => resolve_field_access(ctx, access)
+            .ok()
+            .map(|(record, index)| record_field_kind(&record, index)),
     }
 }
 
@@ -1420,13 +3051,19 @@ fn kind_matches(
             for (actual_item, expected_item) in
                 actual_sig.items.iter().zip(expected_sig.items.iter())
             {
-                if !kind_matches(
-                    &actual_item.kind,
-                    &expected_item.kind,
-                    false,
-                    active_generics,
-                    generic_bindings,
-                ) {
+                let item_matches = match (&actual_item.kind, &expected_item.kind) {
+                    (SigKind::Refined(..), _) | (_, SigKind::Refined(..)) => {
+                        same_refinement(&actual_item.kind, &expected_item.kind)
+                    }
+                    _ => kind_matches(
+                        &actual_item.kind,
+                        &expected_item.kind,
+                        false,
+                        active_generics,
+                        generic_bindings,
+                    ),
+                };
+                if !item_matches {
                     return false;
                 }
             }
@@ -1435,10 +3072,12 @@ fn kind_matches(
         SigKind::GenericInst {
             name: expected_name,
             args: expected_args,
+            ..
         } => {
             let SigKind::GenericInst {
                 name: actual_name,
                 args: actual_args,
+                ..
             } = actual
             else {
                 return false;
@@ -1459,6 +3098,64 @@ fn kind_matches(
             }
             true
         }
+        SigKind::Refined(base, bounds) => match actual {
+            SigKind::Refined(actual_base, actual_bounds) => {
+                canonicalize_kind(actual_base) == canonicalize_kind(base)
+                    && refinement::implies(
+                        actual_bounds,
+                        bounds,
+                        matches!(canonicalize_kind(base), SigKind::Str),
+                    )
+            }
+            _ => false,
+        },
+        // Records are nominal: two declarations with the same fields differ.
+        SigKind::Record { name, .. } => {
+            matches!(actual, SigKind::Record { name: actual_name, .. } if actual_name == name)
+        }
+        SigKind::Sum { name, .. } => {
+            matches!(actual, SigKind::Sum { name: actual_name, .. } if actual_name == name)
+        }
+        SigKind::Array(expected_elem) => {
+            let SigKind::Array(actual_elem) = actual else {
+                return false;
+            };
+            kind_matches(
+                actual_elem,
+                expected_elem,
+                matches!(actual_elem.as_ref(), SigKind::CompileTimeInt),
+                active_generics,
+                generic_bindings,
+            )
+        }
+        SigKind::Map(expected_key, expected_value) => {
+            let SigKind::Map(actual_key, actual_value) = actual else {
+                return false;
+            };
+            [(actual_key, expected_key), (actual_value, expected_value)]
+                .into_iter()
+                .all(|(actual, expected)| {
+                    kind_matches(
+                        actual,
+                        expected,
+                        matches!(actual.as_ref(), SigKind::CompileTimeInt),
+                        active_generics,
+                        generic_bindings,
+                    )
+                })
+        }
+        SigKind::Chan(expected_elem) => {
+            let SigKind::Chan(actual_elem) = actual else {
+                return false;
+            };
+            kind_matches(
+                actual_elem,
+                expected_elem,
+                matches!(actual_elem.as_ref(), SigKind::CompileTimeInt),
+                active_generics,
+                generic_bindings,
+            )
+        }
         _ => canonicalize_kind(actual) == canonicalize_kind(expected),
     }
 }
@@ -1474,6 +3171,24 @@ fn has_generic_kind(kind: &SigKind, active_generics: &BTreeSet<String>) -> bool
         SigKind::GenericInst { args, .. } => args
             .iter()
             .any(|arg| has_generic_kind(arg, active_generics)),
+        SigKind::Array(elem) => has_generic_kind(elem, active_generics),
+        SigKind::Map(key, value) => {
+            has_generic_kind(key, active_generics) || has_generic_kind(value, active_generics)
+        }
+        SigKind::Chan(elem) => has_generic_kind(elem, active_generics),
+        _ => false,
+    }
+}
+
+/// Whether a refinement sits anywhere inside `kind`, such as on the parameter
+/// of a function or on the element of a channel. One on `kind` itself does
+/// not count, since dropping it only forgets what is known about a value.
+fn refines_inside(kind: &SigKind) -> bool {
+    let refined = |kind: &SigKind| matches!(kind, SigKind::Refined(..)) || refines_inside(kind);
+    match kind {
+        SigKind::Sig(signature) => signature.items.iter().any(|item| refined(&item.kind)),
+        SigKind::Array(elem) | SigKind::Chan(elem) => refined(elem),
+        SigKind::Map(key, value) => refined(key) || refined(value),
         _ => false,
     }
 }
@@ -1510,6 +3225,7 @@ fn canonicalize_kind(kind: &SigKind) -> SigKind {
     match kind {
         SigKind::CompileTimeInt => SigKind::Int,
         SigKind::CompileTimeStr => SigKind::Str,
+        SigKind::Refined(base, _) => canonicalize_kind(base),
         SigKind::Sig(signature) => SigKind::Sig(Signature {
             items: signature
                 .items
@@ -1522,10 +3238,17 @@ fn canonicalize_kind(kind: &SigKind) -> SigKind {
                 .collect(),
             generics: signature.generics.clone(),
         }),
-        SigKind::GenericInst { name, args } => SigKind::GenericInst {
+        SigKind::GenericInst { name, args, span } => SigKind::GenericInst {
             name: name.clone(),
+            span: *span,
             args: args.iter().map(canonicalize_kind).collect(),
         },
+        SigKind::Array(elem) => SigKind::Array(Box::new(canonicalize_kind(elem))),
+        SigKind::Map(key, value) => SigKind::Map(
+            Box::new(canonicalize_kind(key)),
+            Box::new(canonicalize_kind(value)),
+        ),
+        SigKind::Chan(elem) => SigKind::Chan(Box::new(canonicalize_kind(elem))),
         other => other.clone(),
     }
 }
@@ -1537,7 +3260,7 @@ fn validate_byte_literal(term: &ast::Term, expected: &SigKind) -> Result<(), Err
 
     let ast::Term::Lit(ast::Literal {
         value: ast::Lit::Int(value),
-        ..
+        span,
     }) = term
     else {
         return Ok(());
@@ -1550,6 +3273,6 @@ fn validate_byte_literal(term: &ast::Term, expected: &SigKind) -> Result<(), Err
     Err(error::new(
         Code::HIR,
         format!("byte literal must be between 0 and 255, found {}", value),
-        Span::unknown(),
+        *span,
     ))
 }
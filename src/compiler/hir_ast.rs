@@ -1,4 +1,6 @@
 pub use crate::compiler::hir_context::{Context, ContextEntry};
+use crate::compiler::refinement::Bound;
+use crate::compiler::span::Span;
 use std::collections::BTreeSet;
 use std::hash::{Hash, Hasher};
 
@@ -13,8 +15,27 @@ pub enum SigKind {
     CompileTimeStr,
     Ident(SigIdent),
     Sig(Signature),
-    GenericInst { name: String, args: Vec<SigKind> },
+    Array(Box<SigKind>),
+    Map(Box<SigKind>, Box<SigKind>),
+    /// A channel coroutines pass values of the element kind over.
+    Chan(Box<SigKind>),
+    Record {
+        name: String,
+        fields: Signature,
+    },
+    /// Each variant is an item whose kind is the signature of its payload.
+    Sum {
+        name: String,
+        variants: Signature,
+    },
+    GenericInst {
+        name: String,
+        args: Vec<SigKind>,
+        span: Span,
+    },
     Generic(String),
+    /// A value of the kind that passes every bound. Refinements never reach AIR.
+    Refined(Box<SigKind>, Vec<Bound>),
 }
 
 impl SigKind {
@@ -109,6 +130,7 @@ impl Hash for SigItem {
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct SigIdent {
     pub name: String,
+    pub span: Span,
 }
 
 #[derive(Clone, Debug)]
@@ -155,6 +177,7 @@ pub struct Function {
     pub name: String,
     pub sig: Signature,
     pub body: Block,
+    pub span: Span,
 }
 
 #[derive(Debug, Clone)]
@@ -168,14 +191,80 @@ pub enum BlockItem {
     FunctionDef(Function),
     SigDef { name: String, sig: Signature },
     LitDef { name: String, literal: Lit },
+    ArrayDef(ArrayDef),
+    MapDef(MapDef),
+    NewRecord(NewRecord),
+    FieldDef(FieldDef),
+    NewVariant(NewVariant),
     ClosureDef(Closure),
     Exec(Exec),
+    Case(Case),
 }
 
 #[derive(Debug, Clone)]
 pub struct Exec {
     pub of: String,
     pub args: Vec<String>,
+    pub span: Span,
+}
+
+/// An array built at runtime from named items that all have the kind `elem`.
+#[derive(Debug, Clone)]
+pub struct ArrayDef {
+    pub name: String,
+    pub elem: SigKind,
+    pub items: Vec<String>,
+    pub span: Span,
+}
+
+/// A map from `key` to `value` built at runtime from named key/value pairs.
+#[derive(Debug, Clone)]
+pub struct MapDef {
+    pub name: String,
+    pub key: SigKind,
+    pub value: SigKind,
+    pub entries: Vec<(String, String)>,
+    pub span: Span,
+}
+
+/// A record of kind `record` built at runtime from one named value per field.
+#[derive(Debug, Clone)]
+pub struct NewRecord {
+    pub name: String,
+    pub record: SigKind,
+    pub fields: Vec<String>,
+    pub span: Span,
+}
+
+/// Binds `name` to field `index` of the record bound to `record`, whose kind is `kind`.
+#[derive(Debug, Clone)]
+pub struct FieldDef {
+    pub name: String,
+    pub record: String,
+    pub kind: SigKind,
+    pub index: usize,
+    pub span: Span,
+}
+
+/// A value of the sum `sum` holding variant `tag`, built from one named value
+/// per payload item.
+#[derive(Debug, Clone)]
+pub struct NewVariant {
+    pub name: String,
+    pub sum: SigKind,
+    pub tag: usize,
+    pub payload: Vec<String>,
+    pub span: Span,
+}
+
+/// Continues with the arm for the variant `value` holds. `arms` has one
+/// continuation per variant of `sum`, in declaration order.
+#[derive(Debug, Clone)]
+pub struct Case {
+    pub value: String,
+    pub sum: SigKind,
+    pub arms: Vec<String>,
+    pub span: Span,
 }
 
 #[derive(Debug, Clone)]
@@ -183,4 +272,5 @@ pub struct Closure {
     pub name: String,
     pub of: String,
     pub args: Vec<String>,
+    pub span: Span,
 }
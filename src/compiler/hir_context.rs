@@ -33,6 +33,10 @@ pub struct Context {
     params: Vec<String>,
     pub closure_defs: HashMap<String, Closure>,
     pub emitted_closures: HashSet<String>,
+    // Root functions declared with `...` and not yet defined, by name.
+    pub forward_decls: HashMap<String, Span>,
+    // The builtin each import alias stands for, e.g. `lt` for `less: @lt`.
+    pub imports: HashMap<String, String>,
 }
 
 impl Default for Context {
@@ -54,6 +58,8 @@ impl Context {
             params: Vec::new(),
             closure_defs: HashMap::new(),
             emitted_closures: HashSet::new(),
+            forward_decls: HashMap::new(),
+            imports: HashMap::new(),
         }
     }
 
@@ -80,6 +86,8 @@ impl Context {
             params: Vec::new(),
             closure_defs: HashMap::new(),
             emitted_closures: HashSet::new(),
+            forward_decls: HashMap::new(),
+            imports: self.imports.clone(),
         }
     }
 
@@ -157,12 +165,19 @@ impl Context {
     }
 
     pub fn add(&mut self, key: &str, entry: ContextEntry) -> Result<(), Error> {
-        if self.inner.contains_key(key) {
-            return Err(Error::new(
+        if let Some(existing) = self.inner.get(key) {
+            let err = Error::new(
                 Code::Resolve,
                 format!("duplicate symbol `{}` in this scope", key),
                 entry.span,
-            ));
+            );
+            if existing.span.is_unknown() {
+                return Err(err);
+            }
+            return Err(err.with_note(format!(
+                "`{}` was first defined at {}:{}",
+                key, existing.span.line, existing.span.column
+            )));
         }
         self.inner.insert(key.to_string(), entry);
         Ok(())
@@ -345,6 +360,7 @@ impl Context {
             name: closure.name.clone(),
             of: target,
             args,
+            span: closure.span,
         }
     }
 }
@@ -370,6 +386,8 @@ pub fn register_import(
     match spec {
         builtins::BuiltinSpec::Function(sig) => {
             ctx.add_sig(alias, alias, sig, span, true)?;
+            ctx.imports
+                .insert(alias.to_string(), import_path.to_string());
         }
         builtins::BuiltinSpec::Type(ty) => {
             ctx.add_type(alias, alias, ty, span, true)?;
@@ -0,0 +1,2255 @@
+//! Runs AIR functions directly, without assembling them.
+//!
+//! The interpreter models memory the way the generated code lays it out, so it
+//! can serve as a reference for the native backends. Closure environments are
+//! separately mapped blocks that end in `ENV_METADATA_SIZE` bytes of metadata,
+//! code pointers are opaque addresses, and string literals live in their own
+//! blocks. Addresses are never reused, unlike the native allocator's free
+//! lists, so reading released memory is reported instead of silently
+//! succeeding.
+//!
+//! Every closure environment is tracked from the `@newclosure` or copy that
+//! made it to its release, so releasing one twice stops the program, and with
+//! `Options::check_memory` the ones still live at exit are reported as leaks.
+//!
+//! Output from `printf` passes through a stdio-like buffer that is flushed at
+//! exit, while `write` goes straight out, which matches the order a native
+//! program produces when its stdout is a pipe. Other file descriptors are the
+//! host's own: `@open` opens a real file and `@read` reads stdin. `@arg`
+//! sees `Options::args` and `@getenv` the host's environment.
+//!
+//! Coroutines share one run queue of continuations, as in the native
+//! scheduler. A channel's reference count and value class live in its mapped
+//! block, while its queues of values and parked receivers are kept here.
+//! `@thread` queues its task like `@spawn` does: running every thread's
+//! coroutines in turn on one host thread is one of the interleavings a
+//! native program may take.
+
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::fmt;
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Write};
+use std::os::fd::AsRawFd;
+use std::os::unix::ffi::OsStrExt;
+use std::os::unix::fs::OpenOptionsExt;
+
+use crate::compiler::air::ENTRY_FUNCTION_NAME;
+use crate::compiler::air::{
+    AirArg, AirCallPtrTarget, AirChecked, AirDivInt, AirFunction, AirJumpCmpF64, AirOp, AirSpawn,
+    AirStmt, AirThread, AirValue, Lit, SigKind,
+};
+use crate::compiler::codegen::{
+    array_elem_class, chan_elem_class, map_block_size, Artifacts, ARRAY_CLASS_ARRAY,
+    ARRAY_CLASS_OFFSET, ARRAY_CLASS_STR, ARRAY_HEADER_SIZE, ARRAY_LEN_OFFSET, CHAN_CLASS_ENV,
+    CHAN_CLASS_OFFSET, CHAN_REFS_OFFSET, CHAN_SIZE, DEADLOCK_MESSAGE, EINVAL, ENOMEM,
+    ENV_METADATA_DEEP_COPY_OFFSET, ENV_METADATA_ENV_SIZE_OFFSET, ENV_METADATA_HEAP_SIZE_OFFSET,
+    ENV_METADATA_NUM_REMAINING_OFFSET, ENV_METADATA_RELEASE_OFFSET, ENV_METADATA_SIZE,
+    ENV_METADATA_UNWRAPPER_OFFSET, MAP_CAPACITY_OFFSET, MAP_COUNT_OFFSET, MAP_ENTRY_SIZE,
+    MAP_HEADER_SIZE, MAP_INITIAL_CAPACITY, MAP_KEY_CLASS_OFFSET, MAP_VALUE_CLASS_OFFSET,
+    STR_ALLOC_SIZE_OFFSET, STR_HEADER_SIZE, STR_LEN_OFFSET,
+};
+use crate::compiler::error::{Code, Error};
+use crate::compiler::span::Span;
+
+const WORD_SIZE: u64 = 8;
+const PAGE_SIZE: u64 = 4096;
+const CODE_BASE: u64 = 0x1000;
+const DATA_BASE: u64 = 0x40_0000;
+const HEAP_BASE: u64 = 0x7f00_0000_0000;
+/// Size of the stdio buffer `printf` output collects in before it is written.
+const STDIO_BUFFER_SIZE: usize = 4096;
+/// The errno for an fd the program never opened.
+const EBADF: i64 = 9;
+
+/// How `run_with` runs a program.
+#[derive(Clone, Debug, Default)]
+pub struct Options {
+    /// Flush `printf` output at every newline, the way stdio does when stdout
+    /// is a terminal.
+    pub line_buffered: bool,
+    /// Fail with a report of the closure environments still live at exit.
+    pub check_memory: bool,
+    /// The program's command line, starting with its own name.
+    pub args: Vec<String>,
+}
+
+/// Runs `functions` from the `_start` entry point, writing the program's
+/// output to `out`. Returns the status the program exits with.
+pub fn run<W: Write>(functions: &[AirFunction], out: &mut W) -> Result<i32, Error> {
+    run_with(functions, Options::default(), out)
+}
+
+/// Like `run`, with `options`.
+pub fn run_with<W: Write>(
+    functions: &[AirFunction],
+    options: Options,
+    out: &mut W,
+) -> Result<i32, Error> {
+    let mut interpreter = Interpreter::new(functions, out)?;
+    interpreter.stdout.line_buffered = options.line_buffered;
+    interpreter.check_memory = options.check_memory;
+    interpreter.args = options.args;
+    interpreter.run()
+}
+
+/// Why execution stopped before a function returned.
+enum Stop {
+    Exit(i32),
+    Fault(Error),
+}
+
+impl From<Error> for Stop {
+    fn from(err: Error) -> Self {
+        Stop::Fault(err)
+    }
+}
+
+impl From<std::io::Error> for Stop {
+    fn from(err: std::io::Error) -> Self {
+        Stop::Fault(err.into())
+    }
+}
+
+type Exec<T> = Result<T, Stop>;
+
+fn fault(message: impl Into<String>) -> Stop {
+    Stop::Fault(Error::new(Code::Runtime, message, Span::unknown()))
+}
+
+/// How a function body hands over control.
+enum Transfer {
+    Tail(usize, Vec<u64>),
+    Return(Option<u64>),
+}
+
+struct Interpreter<'a, W: Write> {
+    functions: &'a [AirFunction],
+    by_name: HashMap<&'a str, usize>,
+    labels: Vec<HashMap<&'a str, usize>>,
+    literals: HashMap<String, u64>,
+    memory: Memory,
+    /// Live closure environments by env_end.
+    envs: BTreeMap<u64, EnvOrigin>,
+    /// Released closure environments, kept to explain double releases.
+    released_envs: BTreeMap<u64, EnvOrigin>,
+    check_memory: bool,
+    stdout: Stdout<'a, W>,
+    /// Files opened with `@open`, by the fd the host gave them, so the
+    /// program sees the same fds a native run would.
+    files: HashMap<i64, File>,
+    args: Vec<String>,
+    /// Queues of the live channels, by address.
+    channels: HashMap<u64, Channel>,
+    /// Coroutines ready to continue, as the env_end of their continuations.
+    run_queue: VecDeque<u64>,
+    /// How many coroutines are parked on a channel.
+    waiting: usize,
+}
+
+impl<'a, W: Write> Interpreter<'a, W> {
+    fn new(functions: &'a [AirFunction], out: &'a mut W) -> Result<Self, Error> {
+        let mut by_name = HashMap::new();
+        let mut labels = Vec::new();
+        for (idx, function) in functions.iter().enumerate() {
+            // Later definitions of a name are skipped, as in codegen.
+            by_name.entry(function.sig.name.as_str()).or_insert(idx);
+            let mut function_labels = HashMap::new();
+            for (pc, stmt) in function.items.iter().enumerate() {
+                if let AirStmt::Label(label) = stmt {
+                    function_labels.insert(label.name.as_str(), pc);
+                }
+            }
+            labels.push(function_labels);
+        }
+
+        let mut memory = Memory::new();
+        let mut literals = HashMap::new();
+        for (label, literal) in Artifacts::collect(functions).string_literals() {
+            // A zero size header marks the string as static.
+            let mut bytes = 0u64.to_le_bytes().to_vec();
+            bytes.extend_from_slice(&(literal.len() as u64).to_le_bytes());
+            bytes.extend_from_slice(literal.as_bytes());
+            bytes.push(0);
+            let addr = memory.map_data(bytes);
+            literals.insert(label.clone(), addr + STR_HEADER_SIZE as u64);
+        }
+
+        Ok(Self {
+            functions,
+            by_name,
+            labels,
+            literals,
+            memory,
+            envs: BTreeMap::new(),
+            released_envs: BTreeMap::new(),
+            check_memory: false,
+            stdout: Stdout {
+                out,
+                buffer: Vec::new(),
+                line_buffered: false,
+            },
+            files: HashMap::new(),
+            args: Vec::new(),
+            channels: HashMap::new(),
+            run_queue: VecDeque::new(),
+            waiting: 0,
+        })
+    }
+
+    fn run(mut self) -> Result<i32, Error> {
+        let result = self
+            .function_index(ENTRY_FUNCTION_NAME)
+            .and_then(|entry| self.call(entry, Vec::new()));
+        // Flush what the program printed even when it faulted, since that is
+        // usually what shows where it went wrong.
+        self.stdout.flush()?;
+        match result {
+            Ok(_) => Err(Error::new(
+                Code::Runtime,
+                format!("`{ENTRY_FUNCTION_NAME}` returned instead of exiting"),
+                Span::unknown(),
+            )),
+            Err(Stop::Exit(_)) if self.check_memory && !self.envs.is_empty() => {
+                Err(self.leak_report())
+            }
+            Err(Stop::Exit(status)) => Ok(status),
+            Err(Stop::Fault(err)) => Err(err),
+        }
+    }
+
+    /// Lists the closure environments still live, most common origin first.
+    fn leak_report(&self) -> Error {
+        let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+        for origin in self.envs.values() {
+            *counts.entry(origin.to_string()).or_default() += 1;
+        }
+        let mut counts: Vec<(String, usize)> = counts.into_iter().collect();
+        counts.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+        let mut err = Error::new(
+            Code::Runtime,
+            match self.envs.len() {
+                1 => "1 closure environment is still live at exit".to_string(),
+                count => format!("{count} closure environments are still live at exit"),
+            },
+            Span::unknown(),
+        );
+        for (origin, count) in counts {
+            err = err.with_note(format!("{count} x {origin}"));
+        }
+        err
+    }
+
+    fn function_index(&self, name: &str) -> Exec<usize> {
+        self.by_name
+            .get(name)
+            .copied()
+            .ok_or_else(|| fault(format!("no function named `{name}`")))
+    }
+
+    fn code_address(&self, name: &str) -> Exec<u64> {
+        Ok(CODE_BASE + self.function_index(name)? as u64 * WORD_SIZE)
+    }
+
+    fn function_at(&self, addr: u64) -> Exec<usize> {
+        let idx = addr.wrapping_sub(CODE_BASE) / WORD_SIZE;
+        if addr < CODE_BASE || !addr.is_multiple_of(WORD_SIZE) || idx >= self.functions.len() as u64
+        {
+            return Err(fault(format!("jump to {addr:#x}, which is not code")));
+        }
+        Ok(idx as usize)
+    }
+
+    /// Runs `func` and whatever it tail-calls until one of them returns.
+    fn call(&mut self, mut func: usize, mut args: Vec<u64>) -> Exec<Option<u64>> {
+        loop {
+            match self.execute(func, args)? {
+                Transfer::Tail(next, next_args) => {
+                    func = next;
+                    args = next_args;
+                }
+                Transfer::Return(value) => return Ok(value),
+            }
+        }
+    }
+
+    fn execute(&mut self, func: usize, args: Vec<u64>) -> Exec<Transfer> {
+        let function = &self.functions[func];
+        let name = function.sig.name.as_str();
+        if function.items.is_empty() {
+            return Err(fault(format!("`{name}` has no AIR body to run")));
+        }
+        if args.len() != function.sig.params.len() {
+            return Err(fault(format!(
+                "`{name}` takes {} arguments but was given {}",
+                function.sig.params.len(),
+                args.len()
+            )));
+        }
+        let mut frame = Frame {
+            function: name,
+            bindings: HashMap::new(),
+        };
+        for (param, value) in function.sig.params.iter().zip(args) {
+            frame.bindings.insert(param.name.clone(), value);
+        }
+
+        let mut pc = 0;
+        while let Some(stmt) = function.items.get(pc) {
+            pc += 1;
+            let AirStmt::Op(op) = stmt else {
+                continue;
+            };
+            let branch = match op.as_ref() {
+                AirOp::Jump(jump) => Some(&jump.target),
+                AirOp::JumpEqInt(eq) => {
+                    let (left, right) = self.pair(&frame, &eq.args, "eqi")?;
+                    (left == right).then_some(&eq.target)
+                }
+                AirOp::JumpEqStr(eq) => {
+                    let (left, right) = self.pair(&frame, &eq.args, "eqs")?;
+                    let equal = self.str_bytes(left)? == self.str_bytes(right)?;
+                    equal.then_some(&eq.target)
+                }
+                AirOp::JumpCmpStr(cmp) => {
+                    let (left, right) = self.pair(&frame, &cmp.args, "cmps")?;
+                    match self.str_bytes(left)?.cmp(&self.str_bytes(right)?) {
+                        Ordering::Less => Some(&cmp.lt_target),
+                        Ordering::Equal => None,
+                        Ordering::Greater => Some(&cmp.gt_target),
+                    }
+                }
+                AirOp::JumpLt(jump) => {
+                    let left = frame.value(&jump.left)? as i64;
+                    (left < frame.value(&jump.right)? as i64).then_some(&jump.target)
+                }
+                AirOp::JumpGt(jump) => {
+                    let left = frame.value(&jump.left)? as i64;
+                    (left > frame.value(&jump.right)? as i64).then_some(&jump.target)
+                }
+                AirOp::JumpTable(jump) => {
+                    let index = frame.value(&jump.index)?;
+                    let target = usize::try_from(index)
+                        .ok()
+                        .and_then(|index| jump.targets.get(index));
+                    Some(target.ok_or_else(|| {
+                        fault(format!("`{name}` has no jump table entry {index}"))
+                    })?)
+                }
+                AirOp::JumpEqF64(jump) => {
+                    let (left, right) = self.float_pair(&frame, jump)?;
+                    (left == right).then_some(&jump.target)
+                }
+                AirOp::JumpLtF64(jump) => {
+                    let (left, right) = self.float_pair(&frame, jump)?;
+                    (left < right).then_some(&jump.target)
+                }
+                AirOp::JumpGtF64(jump) => {
+                    let (left, right) = self.float_pair(&frame, jump)?;
+                    (left > right).then_some(&jump.target)
+                }
+                op => {
+                    if let Some(transfer) = self.execute_op(&mut frame, op)? {
+                        return Ok(transfer);
+                    }
+                    None
+                }
+            };
+            if let Some(label) = branch {
+                pc = *self.labels[func]
+                    .get(label.as_str())
+                    .ok_or_else(|| fault(format!("`{name}` has no label `{label}`")))?;
+            }
+        }
+        Err(fault(format!("`{name}` ended without a jump or return")))
+    }
+
+    /// Runs one straight-line op, returning the transfer when it ends the
+    /// function.
+    fn execute_op(&mut self, frame: &mut Frame<'_>, op: &AirOp) -> Exec<Option<Transfer>> {
+        let transfer = match op {
+            AirOp::Return(ret) => {
+                let value = ret.value.as_deref().map(|name| frame.get(name));
+                Transfer::Return(value.transpose()?)
+            }
+            AirOp::JumpArgs(jump) => {
+                let target = self.function_index(&jump.target.name)?;
+                let args = self.args(frame, &jump.args)?;
+                Transfer::Tail(target, args)
+            }
+            AirOp::JumpClosure(jump) => {
+                let env_end = frame.get(&jump.env_end)?;
+                let total = jump.args.len() as u64;
+                for (idx, arg) in jump.args.iter().enumerate() {
+                    let value = self.arg(frame, arg)?;
+                    let addr = env_end.wrapping_sub((total - idx as u64) * WORD_SIZE);
+                    self.memory.write_word(addr, value)?;
+                }
+                self.enter_closure(env_end)?
+            }
+            AirOp::Add(op) => {
+                let (a, b) = (self.arg(frame, &op.input_a)?, self.arg(frame, &op.input_b)?);
+                self.continue_with(frame, &op.target, Some(a.wrapping_add(b)))?
+            }
+            AirOp::Sub(op) => {
+                let (a, b) = (self.arg(frame, &op.input_a)?, self.arg(frame, &op.input_b)?);
+                self.continue_with(frame, &op.target, Some(a.wrapping_sub(b)))?
+            }
+            AirOp::Mul(op) => {
+                let (a, b) = (self.arg(frame, &op.input_a)?, self.arg(frame, &op.input_b)?);
+                self.continue_with(frame, &op.target, Some(a.wrapping_mul(b)))?
+            }
+            AirOp::DivInt(op) => self.divide(frame, op, i64::checked_div)?,
+            // The remainder of `i64::MIN / -1` is 0 even though the quotient
+            // overflows.
+            AirOp::RemInt(op) => self.divide(frame, op, |a, b| match b {
+                -1 => Some(0),
+                _ => a.checked_rem(b),
+            })?,
+            AirOp::And(op) => {
+                let (a, b) = (self.arg(frame, &op.input_a)?, self.arg(frame, &op.input_b)?);
+                self.continue_with(frame, &op.target, Some(a & b))?
+            }
+            AirOp::Or(op) => {
+                let (a, b) = (self.arg(frame, &op.input_a)?, self.arg(frame, &op.input_b)?);
+                self.continue_with(frame, &op.target, Some(a | b))?
+            }
+            AirOp::Xor(op) => {
+                let (a, b) = (self.arg(frame, &op.input_a)?, self.arg(frame, &op.input_b)?);
+                self.continue_with(frame, &op.target, Some(a ^ b))?
+            }
+            AirOp::Shl(op) => {
+                let (a, b) = (self.arg(frame, &op.input_a)?, self.arg(frame, &op.input_b)?);
+                self.continue_with(frame, &op.target, Some(a.wrapping_shl(b as u32)))?
+            }
+            AirOp::Shr(op) => {
+                let a = self.arg(frame, &op.input_a)? as i64;
+                let b = self.arg(frame, &op.input_b)?;
+                let shifted = a.wrapping_shr(b as u32);
+                self.continue_with(frame, &op.target, Some(shifted as u64))?
+            }
+            AirOp::Neg(op) => {
+                let value = self.arg(frame, &op.input)?;
+                self.continue_with(frame, &op.target, Some(value.wrapping_neg()))?
+            }
+            AirOp::AddChecked(op) => self.checked(frame, op, i64::overflowing_add)?,
+            AirOp::SubChecked(op) => self.checked(frame, op, i64::overflowing_sub)?,
+            AirOp::MulChecked(op) => self.checked(frame, op, i64::overflowing_mul)?,
+            AirOp::StrLen(op) => {
+                let string = self.arg(frame, &op.input)?;
+                let len = self.memory.read_word(string - STR_LEN_OFFSET as u64)?;
+                self.release_strs(frame, &op.release)?;
+                self.continue_with(frame, &op.target, Some(len))?
+            }
+            AirOp::Concat(op) => {
+                let mut bytes = self.str_bytes(self.arg(frame, &op.input_a)?)?;
+                bytes.extend(self.str_bytes(self.arg(frame, &op.input_b)?)?);
+                let string = self.alloc_str(&bytes)?;
+                self.release_strs(frame, &op.release)?;
+                self.continue_with(frame, &op.target, Some(string))?
+            }
+            AirOp::Slice(op) => {
+                let bytes = self.str_bytes(self.arg(frame, &op.input)?)?;
+                let start = self.arg(frame, &op.start)? as i64;
+                let end = self.arg(frame, &op.end)? as i64;
+                let range = usize::try_from(start)
+                    .ok()
+                    .zip(usize::try_from(end).ok())
+                    .and_then(|(start, end)| bytes.get(start..end));
+                match range {
+                    Some(slice) => {
+                        let string = self.alloc_str(slice)?;
+                        self.release_strs(frame, &op.release)?;
+                        self.release_closure(frame.get(&op.err_target)?)?;
+                        self.continue_with(frame, &op.ok_target, Some(string))?
+                    }
+                    None => {
+                        self.release_strs(frame, &op.release)?;
+                        self.release_closure(frame.get(&op.ok_target)?)?;
+                        self.continue_with(frame, &op.err_target, None)?
+                    }
+                }
+            }
+            AirOp::ByteAt(op) => {
+                let bytes = self.str_bytes(self.arg(frame, &op.input)?)?;
+                let index = self.arg(frame, &op.index)? as i64;
+                let byte = usize::try_from(index)
+                    .ok()
+                    .and_then(|index| bytes.get(index).copied());
+                self.release_strs(frame, &op.release)?;
+                match byte {
+                    Some(byte) => {
+                        self.release_closure(frame.get(&op.err_target)?)?;
+                        self.continue_with(frame, &op.ok_target, Some(byte as u64))?
+                    }
+                    None => {
+                        self.release_closure(frame.get(&op.ok_target)?)?;
+                        self.continue_with(frame, &op.err_target, None)?
+                    }
+                }
+            }
+            AirOp::ArrayLen(op) => {
+                let array = self.arg(frame, &op.input)?;
+                let len = self.memory.read_word(array - ARRAY_LEN_OFFSET as u64)?;
+                self.release_array(array)?;
+                self.continue_with(frame, &op.target, Some(len))?
+            }
+            AirOp::ArrayGet(op) => {
+                let array = self.arg(frame, &op.input)?;
+                let index = self.arg(frame, &op.index)?;
+                match self.array_elem_address(array, index)? {
+                    Some(addr) => {
+                        // The element moves out, so the array must not release it.
+                        let elem = self.memory.read_word(addr)?;
+                        self.memory.write_word(addr, 0)?;
+                        self.release_array(array)?;
+                        self.release_closure(frame.get(&op.none_target)?)?;
+                        self.continue_with(frame, &op.one_target, Some(elem))?
+                    }
+                    None => {
+                        self.release_array(array)?;
+                        self.release_closure(frame.get(&op.one_target)?)?;
+                        self.continue_with(frame, &op.none_target, None)?
+                    }
+                }
+            }
+            AirOp::ArraySet(op) => {
+                let array = self.arg(frame, &op.input)?;
+                let index = self.arg(frame, &op.index)?;
+                let value = self.arg(frame, &op.value)?;
+                let class = self.memory.read_word(array - ARRAY_CLASS_OFFSET as u64)? as i64;
+                match self.array_elem_address(array, index)? {
+                    Some(addr) => {
+                        let old = self.memory.read_word(addr)?;
+                        self.memory.write_word(addr, value)?;
+                        self.release_array_elem(old, class)?;
+                        self.release_closure(frame.get(&op.err_target)?)?;
+                        self.continue_with(frame, &op.ok_target, Some(array))?
+                    }
+                    None => {
+                        self.release_array_elem(value, class)?;
+                        self.release_array(array)?;
+                        self.release_closure(frame.get(&op.ok_target)?)?;
+                        self.continue_with(frame, &op.err_target, None)?
+                    }
+                }
+            }
+            AirOp::MapInsert(op) => {
+                let map = self.arg(frame, &op.input)?;
+                let key = self.arg(frame, &op.key)?;
+                let value = self.arg(frame, &op.value)?;
+                let map = self.map_insert(map, key, value)?;
+                self.continue_with(frame, &op.target, Some(map))?
+            }
+            AirOp::MapLookup(op) => {
+                let map = self.arg(frame, &op.input)?;
+                let key = self.arg(frame, &op.key)?;
+                let entry = self.map_find(map, key)?;
+                let (key_class, value_class) = self.map_classes(map)?;
+                self.release_array_elem(key, key_class)?;
+                match entry {
+                    Some(entry) => {
+                        let value = self.memory.read_word(entry + WORD_SIZE)?;
+                        let value = self.copy_array_elem(value, value_class)?;
+                        self.release_closure(frame.get(&op.missing_target)?)?;
+                        self.continue_with_values(frame, &op.found_target, &[value, map])?
+                    }
+                    None => {
+                        self.release_closure(frame.get(&op.found_target)?)?;
+                        self.continue_with(frame, &op.missing_target, Some(map))?
+                    }
+                }
+            }
+            AirOp::MapRemove(op) => {
+                let map = self.arg(frame, &op.input)?;
+                let key = self.arg(frame, &op.key)?;
+                self.map_remove(map, key)?;
+                self.continue_with(frame, &op.target, Some(map))?
+            }
+            AirOp::MapLen(op) => {
+                let map = self.arg(frame, &op.input)?;
+                let count = self.memory.read_word(map - MAP_COUNT_OFFSET as u64)?;
+                self.continue_with_values(frame, &op.target, &[count, map])?
+            }
+            AirOp::MapNext(op) => {
+                let map = self.arg(frame, &op.input)?;
+                let cursor = self.arg(frame, &op.cursor)?;
+                let count = self.memory.read_word(map - MAP_COUNT_OFFSET as u64)?;
+                if cursor < count {
+                    let (key_class, value_class) = self.map_classes(map)?;
+                    let entry = map + cursor * MAP_ENTRY_SIZE as u64;
+                    let key = self.memory.read_word(entry)?;
+                    let key = self.copy_array_elem(key, key_class)?;
+                    let value = self.memory.read_word(entry + WORD_SIZE)?;
+                    let value = self.copy_array_elem(value, value_class)?;
+                    self.release_closure(frame.get(&op.done_target)?)?;
+                    let values = [key, value, cursor + 1, map];
+                    self.continue_with_values(frame, &op.entry_target, &values)?
+                } else {
+                    self.release_closure(frame.get(&op.entry_target)?)?;
+                    self.continue_with(frame, &op.done_target, Some(map))?
+                }
+            }
+            AirOp::NewChan(op) => {
+                let chan = self.memory.map(CHAN_SIZE as u64);
+                self.memory.write_word(chan + CHAN_REFS_OFFSET as u64, 1)?;
+                let class = chan_elem_class(&op.elem) as u64;
+                self.memory
+                    .write_word(chan + CHAN_CLASS_OFFSET as u64, class)?;
+                self.channels.insert(chan, Channel::default());
+                self.continue_with(frame, &op.target, Some(chan))?
+            }
+            AirOp::ChanSend(op) => {
+                let chan = self.arg(frame, &op.channel)?;
+                let value = self.arg(frame, &op.value)?;
+                match self.channel(chan)?.receivers.pop_front() {
+                    Some(receiver) => {
+                        self.memory
+                            .write_word(receiver.wrapping_sub(2 * WORD_SIZE), value)?;
+                        self.memory
+                            .write_word(receiver.wrapping_sub(WORD_SIZE), chan)?;
+                        self.waiting -= 1;
+                        self.run_queue.push_back(receiver);
+                    }
+                    None => self.channel(chan)?.values.push_back(value),
+                }
+                self.continue_with(frame, &op.target, Some(chan))?
+            }
+            AirOp::ChanRecv(op) => {
+                let chan = self.arg(frame, &op.channel)?;
+                match self.channel(chan)?.values.pop_front() {
+                    Some(value) => self.continue_with_values(frame, &op.target, &[value, chan])?,
+                    None => {
+                        let receiver = frame.get(&op.target)?;
+                        self.channel(chan)?.receivers.push_back(receiver);
+                        self.waiting += 1;
+                        self.schedule_next()?
+                    }
+                }
+            }
+            AirOp::Spawn(AirSpawn { task, target }) | AirOp::Thread(AirThread { task, target }) => {
+                self.run_queue.push_back(frame.get(task)?);
+                self.continue_with(frame, target, None)?
+            }
+            AirOp::Yield(op) => {
+                self.run_queue.push_back(frame.get(&op.target)?);
+                self.schedule_next()?
+            }
+            AirOp::Done(_) => self.schedule_next()?,
+            AirOp::AddF64(op) => {
+                let a = self.float_arg(frame, &op.input_a)?;
+                let b = self.float_arg(frame, &op.input_b)?;
+                self.continue_with(frame, &op.target, Some((a + b).to_bits()))?
+            }
+            AirOp::SubF64(op) => {
+                let a = self.float_arg(frame, &op.input_a)?;
+                let b = self.float_arg(frame, &op.input_b)?;
+                self.continue_with(frame, &op.target, Some((a - b).to_bits()))?
+            }
+            AirOp::MulF64(op) => {
+                let a = self.float_arg(frame, &op.input_a)?;
+                let b = self.float_arg(frame, &op.input_b)?;
+                self.continue_with(frame, &op.target, Some((a * b).to_bits()))?
+            }
+            AirOp::DivF64(op) => {
+                let a = self.float_arg(frame, &op.input_a)?;
+                let b = self.float_arg(frame, &op.input_b)?;
+                self.continue_with(frame, &op.target, Some((a / b).to_bits()))?
+            }
+            AirOp::NegF64(op) => {
+                let value = self.float_arg(frame, &op.input)?;
+                self.continue_with(frame, &op.target, Some((-value).to_bits()))?
+            }
+            AirOp::SqrtF64(op) => {
+                let value = self.float_arg(frame, &op.input)?;
+                self.continue_with(frame, &op.target, Some(value.sqrt().to_bits()))?
+            }
+            AirOp::IntToF64(op) => {
+                let value = self.arg(frame, &op.input)? as i64 as f64;
+                self.continue_with(frame, &op.target, Some(value.to_bits()))?
+            }
+            AirOp::F64ToInt(op) => {
+                let value = self.float_arg(frame, &op.input)?;
+                // -2^63 is exact and 2^63 is the first float past `i64::MAX`;
+                // NaN fails both tests.
+                let limit = -(i64::MIN as f64);
+                if value >= -limit && value < limit {
+                    self.release_closure(frame.get(&op.err_target)?)?;
+                    self.continue_with(frame, &op.ok_target, Some(value as i64 as u64))?
+                } else {
+                    self.release_closure(frame.get(&op.ok_target)?)?;
+                    self.continue_with(frame, &op.err_target, None)?
+                }
+            }
+            AirOp::SysExit(exit) => {
+                let status = match exit.args.first() {
+                    Some(arg) => self.arg(frame, arg)? as i32,
+                    None => 0,
+                };
+                return Err(Stop::Exit(status));
+            }
+            AirOp::Printf(call) => {
+                let text = self.format(frame, &call.args, "printf")?;
+                self.stdout.print(&text)?;
+                self.release_strs(frame, &call.release)?;
+                self.continue_with(frame, &call.target, None)?
+            }
+            AirOp::Sprintf(call) => {
+                let text = self.format(frame, &call.args, "sprintf")?;
+                let string = self.alloc_str(&text)?;
+                self.release_strs(frame, &call.release)?;
+                self.continue_with(frame, &call.target, Some(string))?
+            }
+            AirOp::Write(call) => {
+                let buffer = match call.args.first() {
+                    Some(arg) => self.arg(frame, arg)?,
+                    None => return Err(fault("write requires a buffer")),
+                };
+                let bytes = self.str_bytes(buffer)?;
+                self.stdout.write(&bytes)?;
+                self.release_strs(frame, &call.release)?;
+                self.continue_with(frame, &call.target, None)?
+            }
+            AirOp::WriteFd(call) => {
+                let [fd, buffer] = call.args.as_slice() else {
+                    return Err(fault("writefd requires an fd and a buffer"));
+                };
+                let fd = self.arg(frame, fd)? as i64;
+                let bytes = self.str_bytes(self.arg(frame, buffer)?)?;
+                self.write_fd(fd, &bytes)?;
+                self.release_strs(frame, &call.release)?;
+                self.continue_with(frame, &call.target, None)?
+            }
+            AirOp::Read(op) => {
+                let fd = self.arg(frame, &op.fd)? as i64;
+                let max = self.arg(frame, &op.max)? as i64;
+                match self.read_fd(fd, max) {
+                    Ok(bytes) if bytes.is_empty() => {
+                        self.release_closure(frame.get(&op.ok_target)?)?;
+                        self.release_closure(frame.get(&op.err_target)?)?;
+                        self.continue_with(frame, &op.eof_target, None)?
+                    }
+                    Ok(bytes) => {
+                        let string = self.alloc_str(&bytes)?;
+                        self.release_closure(frame.get(&op.eof_target)?)?;
+                        self.release_closure(frame.get(&op.err_target)?)?;
+                        self.continue_with(frame, &op.ok_target, Some(string))?
+                    }
+                    Err(errno) => {
+                        self.release_closure(frame.get(&op.ok_target)?)?;
+                        self.release_closure(frame.get(&op.eof_target)?)?;
+                        self.continue_with(frame, &op.err_target, Some(errno as u64))?
+                    }
+                }
+            }
+            AirOp::Open(op) => {
+                let path = self.str_bytes(self.arg(frame, &op.path)?)?;
+                let flags = self.arg(frame, &op.flags)? as i64;
+                let mode = self.arg(frame, &op.mode)? as i64;
+                let opened = open_file(&path, flags, mode);
+                self.release_strs(frame, &op.release)?;
+                match opened {
+                    Ok(file) => {
+                        let fd = i64::from(file.as_raw_fd());
+                        self.files.insert(fd, file);
+                        self.release_closure(frame.get(&op.err_target)?)?;
+                        self.continue_with(frame, &op.ok_target, Some(fd as u64))?
+                    }
+                    Err(errno) => {
+                        self.release_closure(frame.get(&op.ok_target)?)?;
+                        self.continue_with(frame, &op.err_target, Some(errno as u64))?
+                    }
+                }
+            }
+            AirOp::Close(op) => {
+                let fd = self.arg(frame, &op.fd)? as i64;
+                // Dropping the file closes it; the standard streams stay
+                // open for the interpreter's own use.
+                if self.files.remove(&fd).is_some() || (0..=2).contains(&fd) {
+                    self.release_closure(frame.get(&op.err_target)?)?;
+                    self.continue_with(frame, &op.ok_target, None)?
+                } else {
+                    self.release_closure(frame.get(&op.ok_target)?)?;
+                    self.continue_with(frame, &op.err_target, Some(EBADF as u64))?
+                }
+            }
+            AirOp::ArgCount(op) => {
+                let count = self.args.len() as u64;
+                self.continue_with(frame, &op.target, Some(count))?
+            }
+            AirOp::ArgAt(op) => {
+                let index = self.arg(frame, &op.index)? as i64;
+                let arg = usize::try_from(index)
+                    .ok()
+                    .and_then(|index| self.args.get(index))
+                    .cloned();
+                match arg {
+                    Some(arg) => {
+                        let string = self.alloc_str(arg.as_bytes())?;
+                        self.release_closure(frame.get(&op.none_target)?)?;
+                        self.continue_with(frame, &op.one_target, Some(string))?
+                    }
+                    None => {
+                        self.release_closure(frame.get(&op.one_target)?)?;
+                        self.continue_with(frame, &op.none_target, None)?
+                    }
+                }
+            }
+            AirOp::Getenv(op) => {
+                let name = self.str_bytes(self.arg(frame, &op.name)?)?;
+                let value = std::env::var_os(std::ffi::OsStr::from_bytes(&name));
+                self.release_strs(frame, &op.release)?;
+                match value {
+                    Some(value) => {
+                        let string = self.alloc_str(value.as_bytes())?;
+                        self.release_closure(frame.get(&op.missing_target)?)?;
+                        self.continue_with(frame, &op.found_target, Some(string))?
+                    }
+                    None => {
+                        self.release_closure(frame.get(&op.found_target)?)?;
+                        self.continue_with(frame, &op.missing_target, None)?
+                    }
+                }
+            }
+            AirOp::CallPtr(call) => {
+                let AirCallPtrTarget::Binding(name) = &call.target;
+                self.release_closure(frame.get(name)?)?;
+                return Ok(None);
+            }
+            AirOp::ReleaseHeap(release) => {
+                self.release(frame.get(&release.name)?)?;
+                return Ok(None);
+            }
+            AirOp::ReleaseStr(release) => {
+                self.release_str(frame.get(&release.name)?)?;
+                return Ok(None);
+            }
+            AirOp::CopyStr(copy) => {
+                let string = self.copy_str(frame.get(&copy.src)?)?;
+                frame.bindings.insert(copy.dst.clone(), string);
+                return Ok(None);
+            }
+            AirOp::NewArray(array) => {
+                let items = self.args(frame, &array.items)?;
+                let class = array_elem_class(&array.elem) as u64;
+                let addr = self.alloc_array(class, &items)?;
+                frame.bindings.insert(array.name.clone(), addr);
+                return Ok(None);
+            }
+            AirOp::ReleaseArray(release) => {
+                self.release_array(frame.get(&release.name)?)?;
+                return Ok(None);
+            }
+            AirOp::CopyArray(copy) => {
+                let array = self.copy_array(frame.get(&copy.src)?)?;
+                frame.bindings.insert(copy.dst.clone(), array);
+                return Ok(None);
+            }
+            AirOp::NewMap(map) => {
+                let key_class = array_elem_class(&map.key) as u64;
+                let value_class = array_elem_class(&map.value) as u64;
+                let mut addr =
+                    self.alloc_map(key_class, value_class, MAP_INITIAL_CAPACITY as u64)?;
+                for (key, value) in &map.entries {
+                    let key = self.arg(frame, key)?;
+                    let value = self.arg(frame, value)?;
+                    addr = self.map_insert(addr, key, value)?;
+                }
+                frame.bindings.insert(map.name.clone(), addr);
+                return Ok(None);
+            }
+            AirOp::ReleaseMap(release) => {
+                self.release_map(frame.get(&release.name)?)?;
+                return Ok(None);
+            }
+            AirOp::CopyMap(copy) => {
+                let map = self.copy_map(frame.get(&copy.src)?)?;
+                frame.bindings.insert(copy.dst.clone(), map);
+                return Ok(None);
+            }
+            AirOp::ReleaseChan(release) => {
+                self.release_chan(frame.get(&release.name)?)?;
+                return Ok(None);
+            }
+            AirOp::CopyChan(copy) => {
+                let chan = self.copy_chan(frame.get(&copy.src)?)?;
+                frame.bindings.insert(copy.dst.clone(), chan);
+                return Ok(None);
+            }
+            AirOp::ReleaseSum(release) => {
+                self.release_closure(frame.get(&release.name)?)?;
+                return Ok(None);
+            }
+            AirOp::NewClosure(closure) => {
+                let kinds = closure.target.param_kinds();
+                let env_size = kinds.len() as u64 * WORD_SIZE;
+                let heap_size = env_size + ENV_METADATA_SIZE as u64;
+                let base = self.memory.map(heap_size);
+                // Captured closures move into the new one; lowering clones
+                // any that are used again.
+                for (idx, arg) in closure.args.iter().enumerate() {
+                    let value = self.arg(frame, arg)?;
+                    self.memory
+                        .write_word(base + idx as u64 * WORD_SIZE, value)?;
+                }
+                let env_end = base + env_size;
+                let metadata = [
+                    (
+                        ENV_METADATA_UNWRAPPER_OFFSET,
+                        self.code_address(&closure.unwrapper_label())?,
+                    ),
+                    (
+                        ENV_METADATA_RELEASE_OFFSET,
+                        self.code_address(&closure.deep_release_label())?,
+                    ),
+                    (
+                        ENV_METADATA_DEEP_COPY_OFFSET,
+                        self.code_address(&closure.deepcopy_label())?,
+                    ),
+                    (ENV_METADATA_ENV_SIZE_OFFSET, env_size),
+                    (ENV_METADATA_HEAP_SIZE_OFFSET, heap_size),
+                    (
+                        ENV_METADATA_NUM_REMAINING_OFFSET,
+                        kinds.len().saturating_sub(closure.args.len()) as u64,
+                    ),
+                ];
+                for (offset, value) in metadata {
+                    self.memory.write_word(env_end + offset as u64, value)?;
+                }
+                let origin = EnvOrigin {
+                    closure: closure.target.name.clone(),
+                    made_in: frame.function.to_string(),
+                    copy: false,
+                };
+                self.envs.insert(env_end, origin);
+                frame.bindings.insert(closure.name.clone(), env_end);
+                return Ok(None);
+            }
+            AirOp::CloneClosure(clone) => {
+                let copy = self.clone_env(frame.get(&clone.src)?)?;
+                frame.bindings.insert(clone.dst.clone(), copy);
+                return Ok(None);
+            }
+            AirOp::Pin(pin) => {
+                let value = frame.value(&pin.value)?;
+                frame.bindings.insert(pin.result.clone(), value);
+                return Ok(None);
+            }
+            AirOp::Field(field) => {
+                let addr = field_address(frame.get(&field.ptr)?, field.offset);
+                let value = self.memory.read_word(addr)?;
+                frame.bindings.insert(field.result.clone(), value);
+                return Ok(None);
+            }
+            AirOp::CopyField(field) => {
+                let addr = field_address(frame.get(&field.ptr)?, field.offset);
+                let value = self.memory.read_word(addr)?;
+                let copy = match field.kind {
+                    SigKind::Str => self.copy_str(value)?,
+                    SigKind::Array(_) => self.copy_array(value)?,
+                    SigKind::Map(..) => self.copy_map(value)?,
+                    SigKind::Chan(_) => self.copy_chan(value)?,
+                    _ => self.clone_env(value)?,
+                };
+                self.memory.write_word(addr, copy)?;
+                frame.bindings.insert(field.result.clone(), copy);
+                return Ok(None);
+            }
+            AirOp::SetField(set) => {
+                let addr = field_address(frame.get(&set.env_end)?, set.offset);
+                let value = self.arg(frame, &set.value)?;
+                self.memory.write_word(addr, value)?;
+                return Ok(None);
+            }
+            AirOp::Jump(_)
+            | AirOp::JumpEqInt(_)
+            | AirOp::JumpEqStr(_)
+            | AirOp::JumpCmpStr(_)
+            | AirOp::JumpLt(_)
+            | AirOp::JumpGt(_)
+            | AirOp::JumpTable(_)
+            | AirOp::JumpEqF64(_)
+            | AirOp::JumpLtF64(_)
+            | AirOp::JumpGtF64(_) => unreachable!("branches are handled by `execute`"),
+        };
+        Ok(Some(transfer))
+    }
+
+    /// Takes `err_target` when `divide` has no result, for a zero divisor or
+    /// an overflowing quotient.
+    fn divide(
+        &mut self,
+        frame: &Frame<'_>,
+        op: &AirDivInt,
+        divide: fn(i64, i64) -> Option<i64>,
+    ) -> Exec<Transfer> {
+        let a = self.arg(frame, &op.input_a)? as i64;
+        let b = self.arg(frame, &op.input_b)? as i64;
+        match divide(a, b) {
+            Some(result) => {
+                self.release_closure(frame.get(&op.err_target)?)?;
+                self.continue_with(frame, &op.ok_target, Some(result as u64))
+            }
+            None => {
+                self.release_closure(frame.get(&op.ok_target)?)?;
+                self.continue_with(frame, &op.err_target, None)
+            }
+        }
+    }
+
+    /// Passes the wrapped result of `apply` to `overflow_target` when it
+    /// overflowed and to `ok_target` otherwise.
+    fn checked(
+        &mut self,
+        frame: &Frame<'_>,
+        op: &AirChecked,
+        apply: fn(i64, i64) -> (i64, bool),
+    ) -> Exec<Transfer> {
+        let a = self.arg(frame, &op.input_a)? as i64;
+        let b = self.arg(frame, &op.input_b)? as i64;
+        let (result, overflowed) = apply(a, b);
+        let (taken, untaken) = if overflowed {
+            (&op.overflow_target, &op.ok_target)
+        } else {
+            (&op.ok_target, &op.overflow_target)
+        };
+        self.release_closure(frame.get(untaken)?)?;
+        self.continue_with(frame, taken, Some(result as u64))
+    }
+
+    /// Passes `result`, if any, as the last argument of the closure bound to
+    /// `target` and jumps to it.
+    fn continue_with(
+        &mut self,
+        frame: &Frame<'_>,
+        target: &str,
+        result: Option<u64>,
+    ) -> Exec<Transfer> {
+        let env_end = frame.get(target)?;
+        if let Some(value) = result {
+            self.memory
+                .write_word(env_end.wrapping_sub(WORD_SIZE), value)?;
+        }
+        self.enter_closure(env_end)
+    }
+
+    /// Passes `values` as the last arguments of the closure bound to `target`,
+    /// in order, and jumps to it.
+    fn continue_with_values(
+        &mut self,
+        frame: &Frame<'_>,
+        target: &str,
+        values: &[u64],
+    ) -> Exec<Transfer> {
+        let env_end = frame.get(target)?;
+        for (idx, value) in values.iter().enumerate() {
+            let offset = (values.len() - idx) as u64 * WORD_SIZE;
+            self.memory
+                .write_word(env_end.wrapping_sub(offset), *value)?;
+        }
+        self.enter_closure(env_end)
+    }
+
+    /// Enters the next coroutine in the run queue, or exits once nothing can
+    /// run: cleanly when nobody is parked on a channel and with a deadlock
+    /// report otherwise.
+    fn schedule_next(&mut self) -> Exec<Transfer> {
+        if let Some(env_end) = self.run_queue.pop_front() {
+            return self.enter_closure(env_end);
+        }
+        if self.waiting == 0 {
+            return Err(Stop::Exit(0));
+        }
+        self.write_fd(2, DEADLOCK_MESSAGE.as_bytes())?;
+        Err(Stop::Exit(1))
+    }
+
+    fn enter_closure(&mut self, env_end: u64) -> Exec<Transfer> {
+        let unwrapper = self
+            .memory
+            .read_word(env_end + ENV_METADATA_UNWRAPPER_OFFSET as u64)?;
+        Ok(Transfer::Tail(self.function_at(unwrapper)?, vec![env_end]))
+    }
+
+    /// Copies the environment ending at `env_end` and lets its deepcopy
+    /// function copy the closures it holds. Returns the copy's env_end.
+    fn clone_env(&mut self, env_end: u64) -> Exec<u64> {
+        let env_size = self
+            .memory
+            .read_word(env_end + ENV_METADATA_ENV_SIZE_OFFSET as u64)?;
+        let heap_size = self
+            .memory
+            .read_word(env_end + ENV_METADATA_HEAP_SIZE_OFFSET as u64)?;
+        let bytes = self
+            .memory
+            .read_bytes(env_end.wrapping_sub(env_size), heap_size)?;
+        let copy = self.memory.map(heap_size);
+        self.memory.write_bytes(copy, &bytes)?;
+        let copy_end = copy + env_size;
+        if let Some(origin) = self.envs.get(&env_end) {
+            let copy = EnvOrigin {
+                copy: true,
+                ..origin.clone()
+            };
+            self.envs.insert(copy_end, copy);
+        }
+        let deepcopy = self
+            .memory
+            .read_word(copy_end + ENV_METADATA_DEEP_COPY_OFFSET as u64)?;
+        self.call(self.function_at(deepcopy)?, vec![copy_end])?;
+        Ok(copy_end)
+    }
+
+    /// Unmaps the environment ending at `env_end`, as `release_heap_ptr` does.
+    fn release(&mut self, env_end: u64) -> Exec<()> {
+        let Some(origin) = self.envs.remove(&env_end) else {
+            return Err(match self.released_envs.get(&env_end) {
+                Some(origin) => fault(format!("{origin} is released twice")),
+                None => fault(format!(
+                    "release of {env_end:#x}, which is not a closure environment"
+                )),
+            });
+        };
+        self.released_envs.insert(env_end, origin);
+        let env_size = self
+            .memory
+            .read_word(env_end + ENV_METADATA_ENV_SIZE_OFFSET as u64)?;
+        let heap_size = self
+            .memory
+            .read_word(env_end + ENV_METADATA_HEAP_SIZE_OFFSET as u64)?;
+        self.memory.unmap(env_end.wrapping_sub(env_size), heap_size)
+    }
+
+    /// Releases a closure and everything it holds by calling the release
+    /// helper named in its metadata.
+    fn release_closure(&mut self, env_end: u64) -> Exec<()> {
+        let helper = self
+            .memory
+            .read_word(env_end + ENV_METADATA_RELEASE_OFFSET as u64)?;
+        self.call(self.function_at(helper)?, vec![env_end])?;
+        Ok(())
+    }
+
+    /// Maps a string holding `bytes` and a terminator, behind the size and
+    /// length header its release and `@write` read.
+    fn alloc_str(&mut self, bytes: &[u8]) -> Exec<u64> {
+        let header = STR_HEADER_SIZE as u64;
+        let size = header + bytes.len() as u64 + 1;
+        let block = self.memory.map(size);
+        self.memory.write_word(block, size)?;
+        self.memory
+            .write_word(block + header - STR_LEN_OFFSET as u64, bytes.len() as u64)?;
+        let mut bytes = bytes.to_vec();
+        bytes.push(0);
+        self.memory.write_bytes(block + header, &bytes)?;
+        Ok(block + header)
+    }
+
+    fn str_size(&self, string: u64) -> Exec<u64> {
+        if string == 0 {
+            return Ok(0);
+        }
+        self.memory.read_word(string - STR_ALLOC_SIZE_OFFSET as u64)
+    }
+
+    /// The bytes of a string, as far as its length header says.
+    /// Writes `bytes` to `fd`, ignoring failures the way `@write` does.
+    fn write_fd(&mut self, fd: i64, bytes: &[u8]) -> Exec<()> {
+        match fd {
+            1 => self.stdout.write(bytes)?,
+            2 => {
+                let _ = std::io::stderr().write_all(bytes);
+            }
+            _ => {
+                if let Some(file) = self.files.get_mut(&fd) {
+                    let _ = file.write_all(bytes);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads at most `max` bytes from `fd`, failing with an errno, which is
+    /// ENOMEM when there is no memory for a buffer of `max` bytes.
+    fn read_fd(&mut self, fd: i64, max: i64) -> Result<Vec<u8>, i64> {
+        let max = usize::try_from(max).map_err(|_| EINVAL)?;
+        let mut buffer = Vec::new();
+        buffer.try_reserve_exact(max).map_err(|_| ENOMEM)?;
+        buffer.resize(max, 0);
+        let read = match fd {
+            0 => std::io::stdin().read(&mut buffer),
+            _ => match self.files.get_mut(&fd) {
+                Some(file) => file.read(&mut buffer),
+                None => return Err(EBADF),
+            },
+        };
+        let len = read.map_err(errno)?;
+        buffer.truncate(len);
+        Ok(buffer)
+    }
+
+    fn str_bytes(&self, string: u64) -> Exec<Vec<u8>> {
+        let len = self.memory.read_word(string - STR_LEN_OFFSET as u64)?;
+        Ok(self.memory.block(string, len)?.to_vec())
+    }
+
+    fn release_str(&mut self, string: u64) -> Exec<()> {
+        match self.str_size(string)? {
+            0 => Ok(()),
+            size => self.memory.unmap(string - STR_HEADER_SIZE as u64, size),
+        }
+    }
+
+    fn release_strs(&mut self, frame: &Frame<'_>, names: &[String]) -> Exec<()> {
+        for name in names {
+            self.release_str(frame.get(name)?)?;
+        }
+        Ok(())
+    }
+
+    /// Copies an owned string; literals are shared.
+    fn copy_str(&mut self, string: u64) -> Exec<u64> {
+        match self.str_size(string)? {
+            0 => Ok(string),
+            _ => {
+                let bytes = self.str_bytes(string)?;
+                self.alloc_str(&bytes)
+            }
+        }
+    }
+
+    /// Maps an array of `items` behind the element class and length header
+    /// its release and copy read.
+    fn alloc_array(&mut self, class: u64, items: &[u64]) -> Exec<u64> {
+        let header = ARRAY_HEADER_SIZE as u64;
+        let block = self.memory.map(header + items.len() as u64 * WORD_SIZE);
+        let array = block + header;
+        self.memory
+            .write_word(array - ARRAY_CLASS_OFFSET as u64, class)?;
+        self.memory
+            .write_word(array - ARRAY_LEN_OFFSET as u64, items.len() as u64)?;
+        for (idx, item) in items.iter().enumerate() {
+            self.memory
+                .write_word(array + idx as u64 * WORD_SIZE, *item)?;
+        }
+        Ok(array)
+    }
+
+    /// The class and elements of an array.
+    fn array_items(&self, array: u64) -> Exec<(u64, Vec<u64>)> {
+        let class = self.memory.read_word(array - ARRAY_CLASS_OFFSET as u64)?;
+        let len = self.memory.read_word(array - ARRAY_LEN_OFFSET as u64)?;
+        let items = (0..len)
+            .map(|idx| self.memory.read_word(array + idx * WORD_SIZE))
+            .collect::<Exec<_>>()?;
+        Ok((class, items))
+    }
+
+    /// The address of element `index`, or `None` when it is out of bounds.
+    fn array_elem_address(&self, array: u64, index: u64) -> Exec<Option<u64>> {
+        let len = self.memory.read_word(array - ARRAY_LEN_OFFSET as u64)?;
+        Ok((index < len).then(|| array + index * WORD_SIZE))
+    }
+
+    /// Frees an array along with the strings and arrays it holds.
+    fn release_array(&mut self, array: u64) -> Exec<()> {
+        if array == 0 {
+            return Ok(());
+        }
+        let (class, items) = self.array_items(array)?;
+        for item in &items {
+            self.release_array_elem(*item, class as i64)?;
+        }
+        let header = ARRAY_HEADER_SIZE as u64;
+        self.memory
+            .unmap(array - header, header + items.len() as u64 * WORD_SIZE)
+    }
+
+    fn release_array_elem(&mut self, elem: u64, class: i64) -> Exec<()> {
+        match class {
+            ARRAY_CLASS_STR => self.release_str(elem),
+            ARRAY_CLASS_ARRAY => self.release_array(elem),
+            _ => Ok(()),
+        }
+    }
+
+    /// Deep copies an array, so the copy owns its strings and arrays.
+    fn copy_array(&mut self, array: u64) -> Exec<u64> {
+        if array == 0 {
+            return Ok(0);
+        }
+        let (class, items) = self.array_items(array)?;
+        let items = items
+            .into_iter()
+            .map(|item| match class as i64 {
+                ARRAY_CLASS_STR => self.copy_str(item),
+                ARRAY_CLASS_ARRAY => self.copy_array(item),
+                _ => Ok(item),
+            })
+            .collect::<Exec<Vec<_>>>()?;
+        self.alloc_array(class, &items)
+    }
+
+    /// Deep copies an element of the given class, as `copy_array_elem_ptr` does.
+    fn copy_array_elem(&mut self, elem: u64, class: i64) -> Exec<u64> {
+        match class {
+            ARRAY_CLASS_STR => self.copy_str(elem),
+            ARRAY_CLASS_ARRAY => self.copy_array(elem),
+            _ => Ok(elem),
+        }
+    }
+
+    /// Maps an empty map with room for `capacity` index slots, sized like the
+    /// native one. The interpreter finds keys by scanning the entries, so it
+    /// leaves the index empty.
+    fn alloc_map(&mut self, key_class: u64, value_class: u64, capacity: u64) -> Exec<u64> {
+        let header = MAP_HEADER_SIZE as u64;
+        let block = self.memory.map(map_block_size(capacity as usize) as u64);
+        let map = block + header;
+        self.memory
+            .write_word(map - MAP_KEY_CLASS_OFFSET as u64, key_class)?;
+        self.memory
+            .write_word(map - MAP_VALUE_CLASS_OFFSET as u64, value_class)?;
+        self.memory
+            .write_word(map - MAP_CAPACITY_OFFSET as u64, capacity)?;
+        self.memory.write_word(map - MAP_COUNT_OFFSET as u64, 0)?;
+        Ok(map)
+    }
+
+    /// The classes of a map's keys and values.
+    fn map_classes(&self, map: u64) -> Exec<(i64, i64)> {
+        let key_class = self.memory.read_word(map - MAP_KEY_CLASS_OFFSET as u64)?;
+        let value_class = self.memory.read_word(map - MAP_VALUE_CLASS_OFFSET as u64)?;
+        Ok((key_class as i64, value_class as i64))
+    }
+
+    /// The keys and values of a map, in insertion order.
+    fn map_entries(&self, map: u64) -> Exec<Vec<(u64, u64)>> {
+        let count = self.memory.read_word(map - MAP_COUNT_OFFSET as u64)?;
+        (0..count)
+            .map(|idx| {
+                let entry = map + idx * MAP_ENTRY_SIZE as u64;
+                Ok((
+                    self.memory.read_word(entry)?,
+                    self.memory.read_word(entry + WORD_SIZE)?,
+                ))
+            })
+            .collect()
+    }
+
+    /// The address of the entry holding `key`, if any.
+    fn map_find(&self, map: u64, key: u64) -> Exec<Option<u64>> {
+        let (key_class, _) = self.map_classes(map)?;
+        let key_bytes = match key_class {
+            ARRAY_CLASS_STR => Some(self.str_bytes(key)?),
+            _ => None,
+        };
+        for (idx, (entry_key, _)) in self.map_entries(map)?.into_iter().enumerate() {
+            let found = match &key_bytes {
+                Some(bytes) => self.str_bytes(entry_key)? == *bytes,
+                None => entry_key == key,
+            };
+            if found {
+                return Ok(Some(map + idx as u64 * MAP_ENTRY_SIZE as u64));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Stores `value` under `key`, taking both, and returns the map, which
+    /// moves when it has to grow.
+    fn map_insert(&mut self, mut map: u64, key: u64, value: u64) -> Exec<u64> {
+        let (key_class, value_class) = self.map_classes(map)?;
+        if let Some(entry) = self.map_find(map, key)? {
+            let old = self.memory.read_word(entry + WORD_SIZE)?;
+            self.memory.write_word(entry + WORD_SIZE, value)?;
+            self.release_array_elem(old, value_class)?;
+            self.release_array_elem(key, key_class)?;
+            return Ok(map);
+        }
+        let count = self.memory.read_word(map - MAP_COUNT_OFFSET as u64)?;
+        let capacity = self.memory.read_word(map - MAP_CAPACITY_OFFSET as u64)?;
+        if count * 2 >= capacity {
+            let grown = self.alloc_map(key_class as u64, value_class as u64, capacity * 2)?;
+            let entries = self.memory.read_bytes(map, count * MAP_ENTRY_SIZE as u64)?;
+            self.memory.write_bytes(grown, &entries)?;
+            self.memory
+                .write_word(grown - MAP_COUNT_OFFSET as u64, count)?;
+            self.memory.unmap(
+                map - MAP_HEADER_SIZE as u64,
+                map_block_size(capacity as usize) as u64,
+            )?;
+            map = grown;
+        }
+        let entry = map + count * MAP_ENTRY_SIZE as u64;
+        self.memory.write_word(entry, key)?;
+        self.memory.write_word(entry + WORD_SIZE, value)?;
+        self.memory
+            .write_word(map - MAP_COUNT_OFFSET as u64, count + 1)?;
+        Ok(map)
+    }
+
+    /// Drops the entry under `key`, if any, and takes the key. Later entries
+    /// move down so the rest stay in insertion order.
+    fn map_remove(&mut self, map: u64, key: u64) -> Exec<()> {
+        let (key_class, value_class) = self.map_classes(map)?;
+        let entry = self.map_find(map, key)?;
+        self.release_array_elem(key, key_class)?;
+        let Some(entry) = entry else {
+            return Ok(());
+        };
+        self.release_array_elem(self.memory.read_word(entry)?, key_class)?;
+        let value = self.memory.read_word(entry + WORD_SIZE)?;
+        self.release_array_elem(value, value_class)?;
+        let count = self.memory.read_word(map - MAP_COUNT_OFFSET as u64)?;
+        let end = map + count * MAP_ENTRY_SIZE as u64;
+        let next = entry + MAP_ENTRY_SIZE as u64;
+        let rest = self.memory.read_bytes(next, end - next)?;
+        self.memory.write_bytes(entry, &rest)?;
+        self.memory
+            .write_word(map - MAP_COUNT_OFFSET as u64, count - 1)
+    }
+
+    /// Frees a map along with the keys and values it holds.
+    fn release_map(&mut self, map: u64) -> Exec<()> {
+        if map == 0 {
+            return Ok(());
+        }
+        let (key_class, value_class) = self.map_classes(map)?;
+        for (key, value) in self.map_entries(map)? {
+            self.release_array_elem(key, key_class)?;
+            self.release_array_elem(value, value_class)?;
+        }
+        let capacity = self.memory.read_word(map - MAP_CAPACITY_OFFSET as u64)?;
+        self.memory.unmap(
+            map - MAP_HEADER_SIZE as u64,
+            map_block_size(capacity as usize) as u64,
+        )
+    }
+
+    /// Deep copies a map, so the copy owns its keys and values.
+    fn copy_map(&mut self, map: u64) -> Exec<u64> {
+        if map == 0 {
+            return Ok(0);
+        }
+        let (key_class, value_class) = self.map_classes(map)?;
+        let capacity = self.memory.read_word(map - MAP_CAPACITY_OFFSET as u64)?;
+        let copy = self.alloc_map(key_class as u64, value_class as u64, capacity)?;
+        let entries = self.map_entries(map)?;
+        for (idx, (key, value)) in entries.iter().enumerate() {
+            let entry = copy + idx as u64 * MAP_ENTRY_SIZE as u64;
+            let key = self.copy_array_elem(*key, key_class)?;
+            let value = self.copy_array_elem(*value, value_class)?;
+            self.memory.write_word(entry, key)?;
+            self.memory.write_word(entry + WORD_SIZE, value)?;
+        }
+        self.memory
+            .write_word(copy - MAP_COUNT_OFFSET as u64, entries.len() as u64)?;
+        Ok(copy)
+    }
+
+    fn channel(&mut self, chan: u64) -> Exec<&mut Channel> {
+        self.channels
+            .get_mut(&chan)
+            .ok_or_else(|| fault(format!("{chan:#x} is not a live channel")))
+    }
+
+    /// Drops a reference to a channel. The last one releases the values still
+    /// queued on it and unmaps it, as `chan_release_ptr` does.
+    fn release_chan(&mut self, chan: u64) -> Exec<()> {
+        let refs = self.memory.read_word(chan + CHAN_REFS_OFFSET as u64)? - 1;
+        self.memory
+            .write_word(chan + CHAN_REFS_OFFSET as u64, refs)?;
+        if refs > 0 {
+            return Ok(());
+        }
+        let class = self.memory.read_word(chan + CHAN_CLASS_OFFSET as u64)? as i64;
+        let channel = self
+            .channels
+            .remove(&chan)
+            .ok_or_else(|| fault(format!("{chan:#x} is not a live channel")))?;
+        for value in channel.values {
+            if class == CHAN_CLASS_ENV {
+                self.release_closure(value)?;
+            } else {
+                self.release_array_elem(value, class)?;
+            }
+        }
+        self.memory.unmap(chan, CHAN_SIZE as u64)
+    }
+
+    /// Adds a reference to a channel. Copies share its queues.
+    fn copy_chan(&mut self, chan: u64) -> Exec<u64> {
+        let refs = self.memory.read_word(chan + CHAN_REFS_OFFSET as u64)?;
+        self.memory
+            .write_word(chan + CHAN_REFS_OFFSET as u64, refs + 1)?;
+        Ok(chan)
+    }
+
+    fn arg(&self, frame: &Frame<'_>, arg: &AirArg) -> Exec<u64> {
+        match &arg.literal {
+            Some(Lit::Int(value)) => Ok(*value as u64),
+            Some(Lit::F64(value)) => Ok(value.to_bits()),
+            Some(Lit::Str(_)) => self
+                .literals
+                .get(&arg.name)
+                .copied()
+                .ok_or_else(|| fault(format!("no string literal `{}`", arg.name))),
+            None => frame.get(&arg.name),
+        }
+    }
+
+    fn args(&self, frame: &Frame<'_>, args: &[AirArg]) -> Exec<Vec<u64>> {
+        args.iter().map(|arg| self.arg(frame, arg)).collect()
+    }
+
+    /// Reads a float operand; integer literals are converted like `cvtsi2sd`.
+    fn float_arg(&self, frame: &Frame<'_>, arg: &AirArg) -> Exec<f64> {
+        match &arg.literal {
+            Some(Lit::Int(value)) => Ok(*value as f64),
+            Some(Lit::Str(_)) => Err(fault(format!(
+                "cannot use string literal '{}' in float operation",
+                arg.name
+            ))),
+            _ => Ok(f64::from_bits(self.arg(frame, arg)?)),
+        }
+    }
+
+    fn float_pair(&self, frame: &Frame<'_>, jump: &AirJumpCmpF64) -> Exec<(f64, f64)> {
+        Ok((
+            self.float_arg(frame, &jump.input_a)?,
+            self.float_arg(frame, &jump.input_b)?,
+        ))
+    }
+
+    fn pair(&self, frame: &Frame<'_>, args: &[AirArg], builtin: &str) -> Exec<(u64, u64)> {
+        match args {
+            [left, right, ..] => Ok((self.arg(frame, left)?, self.arg(frame, right)?)),
+            _ => Err(fault(format!("{builtin} builtin requires two arguments"))),
+        }
+    }
+
+    fn format(&self, frame: &Frame<'_>, args: &[AirArg], builtin: &str) -> Exec<Vec<u8>> {
+        let Some((fmt, rest)) = args.split_first() else {
+            return Err(fault(format!(
+                "{builtin} requires a format string before the continuation"
+            )));
+        };
+        let fmt = self.memory.read_cstr(self.arg(frame, fmt)?)?;
+        let values = self.args(frame, rest)?;
+        format_c(&fmt, &values, &self.memory)
+    }
+}
+
+/// Where a closure environment came from, for memory reports.
+#[derive(Clone)]
+struct EnvOrigin {
+    closure: String,
+    made_in: String,
+    copy: bool,
+}
+
+impl fmt::Display for EnvOrigin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let what = if self.copy { "a copy of the" } else { "the" };
+        write!(
+            f,
+            "{what} `{}` closure made in `{}`",
+            self.closure, self.made_in
+        )
+    }
+}
+
+/// What a channel holds besides its block: the values sent but not received
+/// yet, and the continuations of the coroutines waiting for one.
+#[derive(Default)]
+struct Channel {
+    values: VecDeque<u64>,
+    receivers: VecDeque<u64>,
+}
+
+struct Frame<'a> {
+    function: &'a str,
+    bindings: HashMap<String, u64>,
+}
+
+impl Frame<'_> {
+    fn get(&self, name: &str) -> Exec<u64> {
+        self.bindings
+            .get(name)
+            .copied()
+            .ok_or_else(|| fault(format!("`{}` uses unbound `{name}`", self.function)))
+    }
+
+    fn value(&self, value: &AirValue) -> Exec<u64> {
+        match value {
+            AirValue::Binding(name) => self.get(name),
+            AirValue::Literal(value) => Ok(*value as u64),
+        }
+    }
+}
+
+fn field_address(env_end: u64, offset: isize) -> u64 {
+    env_end.wrapping_add((offset as i64 * WORD_SIZE as i64) as u64)
+}
+
+/// The program's stdout: `printf` output is buffered like stdio, `write`
+/// output is not.
+/// Opens `path` the way `open(2)` would with `flags` and `mode`.
+fn open_file(path: &[u8], flags: i64, mode: i64) -> Result<File, i64> {
+    let mut options = OpenOptions::new();
+    match flags & 0o3 {
+        0 => options.read(true),
+        1 => options.write(true),
+        _ => options.read(true).write(true),
+    };
+    options
+        .custom_flags(flags as i32)
+        .mode(mode as u32)
+        .open(std::ffi::OsStr::from_bytes(path))
+        .map_err(errno)
+}
+
+fn errno(err: std::io::Error) -> i64 {
+    err.raw_os_error().map_or(EINVAL, i64::from)
+}
+
+struct Stdout<'a, W: Write> {
+    out: &'a mut W,
+    buffer: Vec<u8>,
+    line_buffered: bool,
+}
+
+impl<W: Write> Stdout<'_, W> {
+    fn print(&mut self, bytes: &[u8]) -> std::io::Result<()> {
+        self.buffer.extend_from_slice(bytes);
+        if self.line_buffered {
+            if let Some(end) = self.buffer.iter().rposition(|&byte| byte == b'\n') {
+                self.out.write_all(&self.buffer[..=end])?;
+                self.buffer.drain(..=end);
+                self.out.flush()?;
+            }
+        } else if self.buffer.len() >= STDIO_BUFFER_SIZE {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    fn write(&mut self, bytes: &[u8]) -> std::io::Result<()> {
+        self.out.write_all(bytes)?;
+        self.out.flush()
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.out.write_all(&self.buffer)?;
+        self.buffer.clear();
+        self.out.flush()
+    }
+}
+
+/// The simulated address space: every `mmap` and string literal gets its own
+/// block, and blocks are never reused.
+struct Memory {
+    blocks: BTreeMap<u64, Vec<u8>>,
+    released: BTreeMap<u64, u64>,
+    next_data: u64,
+    next_heap: u64,
+}
+
+impl Memory {
+    fn new() -> Self {
+        Self {
+            blocks: BTreeMap::new(),
+            released: BTreeMap::new(),
+            next_data: DATA_BASE,
+            next_heap: HEAP_BASE,
+        }
+    }
+
+    fn map_data(&mut self, bytes: Vec<u8>) -> u64 {
+        let addr = self.next_data;
+        self.next_data += (bytes.len() as u64).next_multiple_of(WORD_SIZE);
+        self.blocks.insert(addr, bytes);
+        addr
+    }
+
+    fn map(&mut self, len: u64) -> u64 {
+        let addr = self.next_heap;
+        // Leave an unmapped page after every block so overruns fault.
+        self.next_heap += len.max(1).next_multiple_of(PAGE_SIZE) + PAGE_SIZE;
+        self.blocks.insert(addr, vec![0; len as usize]);
+        addr
+    }
+
+    fn unmap(&mut self, addr: u64, len: u64) -> Exec<()> {
+        match self.blocks.get(&addr) {
+            Some(block) if block.len() as u64 == len && addr >= HEAP_BASE => {
+                self.blocks.remove(&addr);
+                self.released.insert(addr, len);
+                Ok(())
+            }
+            Some(block) => Err(fault(format!(
+                "release of {len} bytes at {addr:#x}, but the block there holds {}",
+                block.len()
+            ))),
+            None if self.released.contains_key(&addr) => Err(fault(format!(
+                "double release of the closure environment at {addr:#x}"
+            ))),
+            None => Err(fault(format!(
+                "release of {addr:#x}, which is not a closure environment"
+            ))),
+        }
+    }
+
+    fn block(&self, addr: u64, len: u64) -> Exec<&[u8]> {
+        self.blocks
+            .range(..=addr)
+            .next_back()
+            .and_then(|(&base, block)| {
+                let start = (addr - base) as usize;
+                let end = start.checked_add(len as usize)?;
+                block.get(start..end)
+            })
+            .ok_or_else(|| self.bad_access(addr, len))
+    }
+
+    fn block_mut(&mut self, addr: u64, len: u64) -> Exec<&mut [u8]> {
+        let err = self.bad_access(addr, len);
+        self.blocks
+            .range_mut(..=addr)
+            .next_back()
+            .filter(|(&base, _)| base >= HEAP_BASE)
+            .and_then(|(&base, block)| {
+                let start = (addr - base) as usize;
+                let end = start.checked_add(len as usize)?;
+                block.get_mut(start..end)
+            })
+            .ok_or(err)
+    }
+
+    fn bad_access(&self, addr: u64, len: u64) -> Stop {
+        let released = self
+            .released
+            .range(..=addr)
+            .next_back()
+            .is_some_and(|(&base, &size)| addr < base + size);
+        if released {
+            fault(format!(
+                "access of {len} bytes at {addr:#x}, in a released closure environment"
+            ))
+        } else {
+            fault(format!(
+                "access of {len} bytes at {addr:#x}, which is not mapped"
+            ))
+        }
+    }
+
+    fn read_word(&self, addr: u64) -> Exec<u64> {
+        let bytes = self.block(addr, WORD_SIZE)?;
+        Ok(u64::from_le_bytes(bytes.try_into().expect("word sized")))
+    }
+
+    fn write_word(&mut self, addr: u64, value: u64) -> Exec<()> {
+        self.write_bytes(addr, &value.to_le_bytes())
+    }
+
+    fn read_bytes(&self, addr: u64, len: u64) -> Exec<Vec<u8>> {
+        Ok(self.block(addr, len)?.to_vec())
+    }
+
+    fn write_bytes(&mut self, addr: u64, bytes: &[u8]) -> Exec<()> {
+        self.block_mut(addr, bytes.len() as u64)?
+            .copy_from_slice(bytes);
+        Ok(())
+    }
+
+    /// Reads the NUL-terminated string at `addr`, without the terminator.
+    fn read_cstr(&self, addr: u64) -> Exec<Vec<u8>> {
+        let (&base, block) = self
+            .blocks
+            .range(..=addr)
+            .next_back()
+            .filter(|(&base, block)| addr - base < block.len() as u64)
+            .ok_or_else(|| self.bad_access(addr, 1))?;
+        let tail = &block[(addr - base) as usize..];
+        match tail.iter().position(|&byte| byte == 0) {
+            Some(len) => Ok(tail[..len].to_vec()),
+            None => Err(fault(format!(
+                "string at {addr:#x} runs past the end of its block"
+            ))),
+        }
+    }
+}
+
+/// Formats `args` the way glibc's printf does for the conversions Rgo
+/// programs use. Arguments are the raw 64-bit words the program passes;
+/// floats are their bit patterns.
+fn format_c(fmt: &[u8], args: &[u64], memory: &Memory) -> Exec<Vec<u8>> {
+    let mut out = Vec::new();
+    let mut args = args.iter().copied();
+    let mut bytes = fmt.iter().copied().peekable();
+    while let Some(byte) = bytes.next() {
+        if byte != b'%' {
+            out.push(byte);
+            continue;
+        }
+        let mut spec = Spec::default();
+        while let Some(&flag) = bytes.peek() {
+            match flag {
+                b'-' => spec.left = true,
+                b'+' => spec.plus = true,
+                b' ' => spec.space = true,
+                b'#' => spec.alternate = true,
+                b'0' => spec.zero = true,
+                _ => break,
+            }
+            bytes.next();
+        }
+        let mut next_arg = || {
+            args.next()
+                .ok_or_else(|| fault("printf format needs more arguments than were passed"))
+        };
+        if bytes.peek() == Some(&b'*') {
+            bytes.next();
+            let width = next_arg()? as i32;
+            spec.left |= width < 0;
+            spec.width = width.unsigned_abs() as usize;
+        } else {
+            spec.width = parse_number(&mut bytes);
+        }
+        if bytes.peek() == Some(&b'.') {
+            bytes.next();
+            if bytes.peek() == Some(&b'*') {
+                bytes.next();
+                let precision = next_arg()? as i32;
+                spec.precision = (precision >= 0).then_some(precision as usize);
+            } else {
+                spec.precision = Some(parse_number(&mut bytes));
+            }
+        }
+        let mut length = Length::Int;
+        while let Some(&modifier) = bytes.peek() {
+            length = match (modifier, length) {
+                (b'h', Length::Short) => Length::Char,
+                (b'h', _) => Length::Short,
+                (b'l' | b'q' | b'j' | b'z' | b't' | b'L', _) => Length::Long,
+                _ => break,
+            };
+            bytes.next();
+        }
+        let Some(conversion) = bytes.next() else {
+            out.push(b'%');
+            break;
+        };
+        let text = match conversion {
+            b'%' => {
+                out.push(b'%');
+                continue;
+            }
+            b'd' | b'i' => {
+                let value = length.signed(next_arg()?);
+                let sign = if value < 0 {
+                    "-"
+                } else if spec.plus {
+                    "+"
+                } else if spec.space {
+                    " "
+                } else {
+                    ""
+                };
+                spec.integer(sign, "", value.unsigned_abs().to_string())
+            }
+            b'u' => spec.integer("", "", length.unsigned(next_arg()?).to_string()),
+            b'x' | b'X' => {
+                let value = length.unsigned(next_arg()?);
+                let mut digits = format!("{value:x}");
+                let mut prefix = if spec.alternate && value != 0 {
+                    "0x"
+                } else {
+                    ""
+                };
+                if conversion == b'X' {
+                    digits = digits.to_uppercase();
+                    prefix = if prefix.is_empty() { "" } else { "0X" };
+                }
+                spec.integer("", prefix, digits)
+            }
+            b'o' => {
+                let value = length.unsigned(next_arg()?);
+                let mut digits = format!("{value:o}");
+                if spec.alternate && !digits.starts_with('0') {
+                    digits.insert(0, '0');
+                }
+                spec.integer("", "", digits)
+            }
+            b'p' => match next_arg()? {
+                0 => spec.pad(b"(nil)".to_vec()),
+                addr => spec.integer("", "0x", format!("{addr:x}")),
+            },
+            b'c' => spec.pad(vec![next_arg()? as u8]),
+            b's' => {
+                let mut text = match next_arg()? {
+                    0 => b"(null)".to_vec(),
+                    addr => memory.read_cstr(addr)?,
+                };
+                if let Some(precision) = spec.precision {
+                    text.truncate(precision);
+                }
+                spec.pad(text)
+            }
+            b'f' | b'F' | b'e' | b'E' | b'g' | b'G' => {
+                spec.float(f64::from_bits(next_arg()?), conversion)
+            }
+            other => {
+                return Err(fault(format!(
+                    "printf conversion `%{}` is not supported",
+                    other as char
+                )))
+            }
+        };
+        out.extend_from_slice(&text);
+    }
+    Ok(out)
+}
+
+fn parse_number(bytes: &mut std::iter::Peekable<impl Iterator<Item = u8>>) -> usize {
+    let mut value = 0usize;
+    while let Some(digit) = bytes.peek().filter(|byte| byte.is_ascii_digit()) {
+        value = value
+            .saturating_mul(10)
+            .saturating_add((digit - b'0') as usize);
+        bytes.next();
+    }
+    value
+}
+
+#[derive(Clone, Copy)]
+enum Length {
+    Char,
+    Short,
+    Int,
+    Long,
+}
+
+impl Length {
+    fn signed(self, word: u64) -> i64 {
+        match self {
+            Length::Char => word as i8 as i64,
+            Length::Short => word as i16 as i64,
+            Length::Int => word as i32 as i64,
+            Length::Long => word as i64,
+        }
+    }
+
+    fn unsigned(self, word: u64) -> u64 {
+        match self {
+            Length::Char => word as u8 as u64,
+            Length::Short => word as u16 as u64,
+            Length::Int => word as u32 as u64,
+            Length::Long => word,
+        }
+    }
+}
+
+#[derive(Default)]
+struct Spec {
+    left: bool,
+    plus: bool,
+    space: bool,
+    alternate: bool,
+    zero: bool,
+    width: usize,
+    precision: Option<usize>,
+}
+
+impl Spec {
+    fn integer(&self, sign: &str, prefix: &str, mut digits: String) -> Vec<u8> {
+        if let Some(precision) = self.precision {
+            if precision == 0 && digits == "0" {
+                digits.clear();
+            }
+            if digits.len() < precision {
+                digits.insert_str(0, &"0".repeat(precision - digits.len()));
+            }
+        }
+        let zero_pad = self.zero && self.precision.is_none();
+        self.pad_number(sign, prefix, digits, zero_pad)
+    }
+
+    fn float(&self, value: f64, conversion: u8) -> Vec<u8> {
+        // glibc prints the sign of a NaN too, and x86 makes negative ones.
+        let sign = if value.is_sign_negative() {
+            "-"
+        } else if self.plus {
+            "+"
+        } else if self.space {
+            " "
+        } else {
+            ""
+        };
+        let upper = conversion.is_ascii_uppercase();
+        let value = value.abs();
+        if !value.is_finite() {
+            let text = if value.is_nan() { "nan" } else { "inf" };
+            let text = if upper {
+                text.to_uppercase()
+            } else {
+                text.into()
+            };
+            return self.pad_number(sign, "", text, false);
+        }
+        let precision = self.precision.unwrap_or(6);
+        let text = match conversion.to_ascii_lowercase() {
+            b'f' => format!("{value:.precision$}"),
+            b'e' => format_exponent(value, precision),
+            _ => {
+                let precision = precision.max(1);
+                let exponent = if value == 0.0 {
+                    0
+                } else {
+                    let text = format_exponent(value, precision - 1);
+                    text[text.find('e').expect("exponent") + 1..]
+                        .parse::<i32>()
+                        .expect("exponent digits")
+                };
+                let mut text = if exponent < -4 || exponent >= precision as i32 {
+                    format_exponent(value, precision - 1)
+                } else {
+                    format!("{value:.*}", (precision as i32 - 1 - exponent) as usize)
+                };
+                if !self.alternate {
+                    let (mantissa, exponent) = match text.find('e') {
+                        Some(at) => text.split_at(at),
+                        None => (text.as_str(), ""),
+                    };
+                    let mantissa = if mantissa.contains('.') {
+                        mantissa.trim_end_matches('0').trim_end_matches('.')
+                    } else {
+                        mantissa
+                    };
+                    text = format!("{mantissa}{exponent}");
+                }
+                text
+            }
+        };
+        let text = if upper { text.to_uppercase() } else { text };
+        self.pad_number(sign, "", text, self.zero)
+    }
+
+    fn pad_number(&self, sign: &str, prefix: &str, digits: String, zero_pad: bool) -> Vec<u8> {
+        let len = sign.len() + prefix.len() + digits.len();
+        if zero_pad && !self.left && len < self.width {
+            let zeros = "0".repeat(self.width - len);
+            return format!("{sign}{prefix}{zeros}{digits}").into_bytes();
+        }
+        self.pad(format!("{sign}{prefix}{digits}").into_bytes())
+    }
+
+    fn pad(&self, mut text: Vec<u8>) -> Vec<u8> {
+        if text.len() >= self.width {
+            return text;
+        }
+        let fill = vec![b' '; self.width - text.len()];
+        if self.left {
+            text.extend(fill);
+            text
+        } else {
+            [fill, text].concat()
+        }
+    }
+}
+
+/// `%e` formatting: one digit before the point and a signed exponent of at
+/// least two digits.
+fn format_exponent(value: f64, precision: usize) -> String {
+    let text = format!("{value:.precision$e}");
+    let (mantissa, exponent) = text.split_once('e').expect("exponent");
+    let exponent: i32 = exponent.parse().expect("exponent digits");
+    let sign = if exponent < 0 { '-' } else { '+' };
+    format!("{mantissa}e{sign}{:02}", exponent.abs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compiler::lower_modules;
+    use crate::compiler::module::ModuleReader;
+    use crate::compiler::source_map::SourceMap;
+    use std::io::Cursor;
+
+    fn interpret(source: &str) -> (Result<i32, Error>, String) {
+        let mut sources = SourceMap::new();
+        let file = sources.add("<test>", source);
+        let reader = ModuleReader::new(Cursor::new(source.as_bytes().to_vec()), file, Vec::new());
+        let functions = lower_modules(reader, &mut sources, "main").expect("program compiles");
+        let mut out = Vec::new();
+        let status = run(&functions, &mut out);
+        (status, String::from_utf8(out).expect("utf-8 output"))
+    }
+
+    fn format(fmt: &str, args: &[u64]) -> String {
+        let memory = Memory::new();
+        String::from_utf8(
+            format_c(fmt.as_bytes(), args, &memory)
+                .ok()
+                .expect("formats"),
+        )
+        .expect("utf-8")
+    }
+
+    #[test]
+    fn formats_like_printf() {
+        assert_eq!(
+            format("%d|%5d|%-5d|%05d", &[42, (-7i64) as u64, 3, 9]),
+            "42|   -7|3    |00009"
+        );
+        assert_eq!(
+            format("%x %#X %o %u", &[255, 255, 8, u32::MAX as u64]),
+            "ff 0XFF 10 4294967295"
+        );
+        assert_eq!(
+            format("%d %ld", &[5_000_000_000, 5_000_000_000]),
+            "705032704 5000000000"
+        );
+        assert_eq!(format("%c%c %%", &[b'o' as u64, b'k' as u64]), "ok %");
+        assert_eq!(
+            format("%f %.2f", &[1.5f64.to_bits(), (2.0f64 / 3.0).to_bits()]),
+            "1.500000 0.67"
+        );
+        assert_eq!(format("%e", &[1234.5f64.to_bits()]), "1.234500e+03");
+        assert_eq!(
+            format(
+                "%g %g %g",
+                &[0.0001f64.to_bits(), 1e10f64.to_bits(), 2.5f64.to_bits()]
+            ),
+            "0.0001 1e+10 2.5"
+        );
+        assert_eq!(
+            format(
+                "%f %f %F",
+                &[
+                    (-f64::NAN).to_bits(),
+                    f64::NAN.to_bits(),
+                    f64::NEG_INFINITY.to_bits()
+                ]
+            ),
+            "-nan nan -INF"
+        );
+    }
+
+    #[test]
+    fn runs_closures_and_builtins() {
+        let (status, out) = interpret(
+            r#"
+sprintf: @sprintf
+write: @write
+exit: @exit
+div: @div
+
+fail: () {
+    exit(1)
+}
+
+show: (value: @int, ok: ()) {
+    sprintf("%d\n", value, (text: @str) {
+        write(text, ok)
+    })
+}
+
+main: () {
+    div(84, 2, fail, (half: @int) {
+        show(half, exit(3))
+    })
+}
+"#,
+        );
+        assert_eq!(out, "42\n");
+        assert_eq!(status.ok(), Some(3));
+    }
+
+    #[test]
+    fn exits_with_one_on_deadlock() {
+        let (status, out) = interpret(
+            r#"
+write: @write
+chan: @chan
+recv: @recv
+spawn: @spawn
+done: @done
+
+main: () {
+    chan((c: @chan<@int>) {
+        spawn(() {
+            write("waiting\n", () {
+                recv(c, (value: @int, c: @chan<@int>) {
+                    done()
+                })
+            })
+        }, done)
+    })
+}
+"#,
+        );
+        assert_eq!(out, "waiting\n");
+        assert_eq!(status.ok(), Some(1));
+    }
+
+    #[test]
+    fn reports_use_after_release() {
+        let mut memory = Memory::new();
+        let block = memory.map(64);
+        memory.unmap(block, 64).ok().expect("unmaps");
+        let Err(Stop::Fault(err)) = memory.read_word(block + 8) else {
+            panic!("read of released memory should fault");
+        };
+        assert!(
+            err.message.contains("released closure environment"),
+            "{}",
+            err.message
+        );
+        let Err(Stop::Fault(err)) = memory.unmap(block, 64) else {
+            panic!("double release should fault");
+        };
+        assert!(err.message.contains("double release"), "{}", err.message);
+    }
+
+    #[test]
+    fn reports_closures_live_at_exit() {
+        let source = r#"
+write: @write
+exit: @exit
+
+say: (text: @str, ok: ()) {
+    write(text, () {
+        write("!\n", ok)
+    })
+}
+
+main: () {
+    say("hi", exit(0))
+}
+"#;
+        let mut sources = SourceMap::new();
+        let file = sources.add("<test>", source);
+        let reader = ModuleReader::new(Cursor::new(source.as_bytes().to_vec()), file, Vec::new());
+        let mut functions = lower_modules(reader, &mut sources, "main").expect("program compiles");
+        // Make the continuation `exit(0)` leak by no longer releasing its
+        // environment when it runs.
+        let unwrapper = functions
+            .iter_mut()
+            .find(|function| function.sig.name == "_6_main_unwrapper")
+            .expect("main's continuation has an unwrapper");
+        unwrapper.items.retain(
+            |item| !matches!(item, AirStmt::Op(op) if matches!(**op, AirOp::ReleaseHeap(_))),
+        );
+        let options = Options {
+            check_memory: true,
+            ..Options::default()
+        };
+        let mut out = Vec::new();
+        let err = run_with(&functions, options, &mut out).expect_err("`ok` leaks");
+        assert_eq!(out, b"hi!\n");
+        assert_eq!(err.message, "1 closure environment is still live at exit");
+        assert_eq!(err.notes, ["1 x the `_6_main` closure made in `main`"]);
+    }
+}
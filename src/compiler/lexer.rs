@@ -1,31 +1,57 @@
 use std::io::{self, BufRead};
 
 use crate::compiler::error::{Code, Error};
-use crate::compiler::span::Span;
+use crate::compiler::span::{FileId, Span};
 use crate::compiler::token::{Token, TokenKind};
 
 pub struct Lexer<R: BufRead> {
     reader: R,
     pending_byte: Option<(u8, usize)>,
     pending_char: Option<(char, Span)>,
+    file: FileId,
     line: usize,
     column: usize,
     offset: usize,
+    consumed_end: usize,
 }
 
 impl<R: BufRead> Lexer<R> {
     pub fn new(reader: R) -> Self {
+        Self::with_file(reader, FileId::default())
+    }
+
+    pub fn with_file(reader: R, file: FileId) -> Self {
         Self {
             reader,
             pending_byte: None,
             pending_char: None,
+            file,
             line: 1,
             column: 1,
             offset: 0,
+            consumed_end: 0,
         }
     }
 
+    /// Lexes the next token; its span runs from its first byte to just past its last.
     pub fn next_token(&mut self) -> Result<Token, Error> {
+        match self.lex_token() {
+            Ok(mut token) => {
+                if !matches!(token.kind, TokenKind::Newline | TokenKind::Eof) {
+                    token.span.end = self.consumed_end.max(token.span.offset);
+                }
+                Ok(token)
+            }
+            Err(mut err) => {
+                if err.code == Code::Lex {
+                    err.span.end = self.consumed_end.max(err.span.offset);
+                }
+                Err(err)
+            }
+        }
+    }
+
+    fn lex_token(&mut self) -> Result<Token, Error> {
         let has_newline = self
             .skip_whitespace_and_comments()
             .map_err(|err| self.io_error(err))?;
@@ -42,6 +68,18 @@ impl<R: BufRead> Lexer<R> {
 
         let token = match ch {
             '@' => {
+                if let Some(('"', _)) = self.peek_char().map_err(|err| self.io_error(err))? {
+                    self.next_char().map_err(|err| self.io_error(err))?; // consume '"'
+                    let path = match self.string_token(span, '"')?.kind {
+                        TokenKind::StringLiteral(path) => path,
+                        _ => unreachable!("string_token always yields a string literal"),
+                    };
+                    if path.is_empty() {
+                        return Err(Error::new(Code::Lex, "import path cannot be empty", span));
+                    }
+                    return Ok(Token::new(TokenKind::ImportPath(path), span));
+                }
+
                 if let Some(('/', _)) = self.peek_char().map_err(|err| self.io_error(err))? {
                     return Err(Error::new(
                         Code::Lex,
@@ -341,10 +379,14 @@ impl<R: BufRead> Lexer<R> {
     }
 
     fn next_char(&mut self) -> io::Result<Option<(char, Span)>> {
-        if let Some(ch) = self.pending_char.take() {
-            return Ok(Some(ch));
+        let next = match self.pending_char.take() {
+            Some(ch) => Some(ch),
+            None => self.read_char_raw()?,
+        };
+        if let Some((_, span)) = next {
+            self.consumed_end = span.offset + 1;
         }
-        self.read_char_raw()
+        Ok(next)
     }
 
     fn read_char_raw(&mut self) -> io::Result<Option<(char, Span)>> {
@@ -352,7 +394,7 @@ impl<R: BufRead> Lexer<R> {
             Some(pair) => pair,
             None => return Ok(None),
         };
-        let span = Span::new(self.line, self.column, offset);
+        let span = Span::new(self.line, self.column, offset).in_file(self.file);
         match byte {
             b'\n' => {
                 self.line += 1;
@@ -370,7 +412,11 @@ impl<R: BufRead> Lexer<R> {
                 Ok(Some(('\n', span)))
             }
             _ => {
-                self.column += 1;
+                // Columns count chars, so a UTF-8 continuation byte stays in
+                // the column its leading byte started.
+                if byte & 0xC0 != 0x80 {
+                    self.column += 1;
+                }
                 Ok(Some((byte as char, span)))
             }
         }
@@ -407,7 +453,7 @@ impl<R: BufRead> Lexer<R> {
     }
 
     fn current_span(&self) -> Span {
-        Span::new(self.line, self.column, self.offset)
+        Span::new(self.line, self.column, self.offset).in_file(self.file)
     }
 
     fn io_error(&self, err: io::Error) -> Error {
@@ -1,6 +1,7 @@
 use std::io::Cursor;
 
 use super::lexer::Lexer;
+use super::span::FileId;
 use super::token::TokenKind;
 
 #[test]
@@ -204,3 +205,43 @@ fn ellipsis_is_single_token() {
     let eof = lexer.next_token().expect("should reach EOF");
     assert!(matches!(eof.kind, TokenKind::Eof));
 }
+
+#[test]
+fn columns_count_chars_not_bytes() {
+    let cursor = Cursor::new("\"héllo\" bar".as_bytes());
+    let mut lexer = Lexer::new(cursor);
+    let string = lexer.next_token().expect("lexer should accept a string");
+    assert_eq!((string.span.column, string.span.offset), (1, 0));
+    let ident = lexer
+        .next_token()
+        .expect("lexer should accept an identifier");
+    assert_eq!(ident.kind, TokenKind::Ident("bar".to_string()));
+    assert_eq!((ident.span.column, ident.span.offset), (9, 9));
+}
+
+#[test]
+fn tokens_span_their_full_range_in_their_file() {
+    let cursor = Cursor::new(b"foo: \"hi\"\n  @write");
+    let mut lexer = Lexer::with_file(cursor, FileId(3));
+    let mut ranges = Vec::new();
+    loop {
+        let token = lexer.next_token().expect("lexer should accept input");
+        match token.kind {
+            TokenKind::Eof => break,
+            TokenKind::Newline => continue,
+            _ => {}
+        }
+        let span = token.span;
+        assert_eq!(span.file, FileId(3));
+        ranges.push((span.line, span.column, span.offset, span.end));
+    }
+    assert_eq!(
+        ranges,
+        vec![
+            (1, 1, 0, 3),   // foo
+            (1, 4, 3, 4),   // :
+            (1, 6, 5, 9),   // "hi"
+            (2, 3, 12, 18), // @write
+        ]
+    );
+}
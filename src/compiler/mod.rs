@@ -1,24 +1,37 @@
 use std::collections::HashMap;
 use std::io::{BufRead, Write};
+use std::path::{Path, PathBuf};
 
+pub mod aarch64;
 pub mod air;
 pub mod air_ast;
+pub mod assembler;
 pub mod ast;
 pub mod builtins;
 pub mod codegen;
+pub mod diagnostic;
+pub mod elf;
 pub mod error;
 pub mod format_air;
 pub mod format_hir;
 pub mod hir;
 pub mod hir_ast;
 pub mod hir_context;
+pub mod interpreter;
 pub mod lexer;
+pub mod module;
+pub mod ownership;
 pub mod parser;
+pub mod refinement;
 pub mod runtime;
 pub mod signature;
+pub mod source_map;
 pub mod span;
+pub mod stdlib;
 pub mod symbol;
+pub mod target;
 pub mod token;
+pub mod toolchain;
 
 #[cfg(test)]
 mod codegen_test;
@@ -27,44 +40,142 @@ mod lexer_test;
 #[cfg(test)]
 mod parser_test;
 
+use diagnostic::Diagnostics;
 use error::Error;
 use error::{Code, Error as CompilerError};
 use hir::Lowerer;
-use lexer::Lexer;
-use parser::Parser;
+use module::ModuleReader;
+use source_map::SourceMap;
 use span::Span;
 use symbol::SymbolRegistry;
+use target::Arch;
 
-pub fn compile<R: BufRead, W: Write>(input: R, target: &str, out: &mut W) -> Result<(), Error> {
-    let lexer = Lexer::new(input);
-    let mut parser = Parser::new(lexer);
+/// Compiles `input` to NASM, reporting every independent error it finds.
+///
+/// Parsing resumes at the next top-level item after a syntax error, and each
+/// top-level item is lowered on its own, so one run reports one error per broken
+/// item. Lowering stops after the first syntax error because anything defined by
+/// a broken item would show up again as a cascade of undefined names.
+///
+/// Source imports in `input` are resolved against the working directory. The
+/// output targets x86-64; `compile_modules` takes any `Arch`.
+pub fn compile<R: BufRead, W: Write>(
+    input: R,
+    target: &str,
+    out: &mut W,
+) -> Result<(), Diagnostics> {
+    let mut sources = SourceMap::new();
+    let file = sources.add("<input>", "");
+    let reader = ModuleReader::new(input, file, Vec::new());
+    compile_modules(reader, &mut sources, target, Arch::X86_64, out)
+}
+
+/// Compiles the program rooted at `path`. Source imports are looked up next to
+/// the importing file, then in each of `search_paths`; every file read is added
+/// to `sources` so diagnostics can quote it.
+pub fn compile_file<W: Write>(
+    path: &Path,
+    search_paths: &[PathBuf],
+    sources: &mut SourceMap,
+    target: &str,
+    out: &mut W,
+) -> Result<(), Diagnostics> {
+    let reader = ModuleReader::open(path, search_paths.to_vec(), sources)?;
+    compile_modules(reader, sources, target, Arch::X86_64, out)
+}
+
+/// Compiles every item `reader` yields to assembly for `arch`; see `compile`.
+pub fn compile_modules<W: Write>(
+    reader: ModuleReader<'_>,
+    sources: &mut SourceMap,
+    target: &str,
+    arch: Arch,
+    out: &mut W,
+) -> Result<(), Diagnostics> {
+    let air_functions = lower_modules(reader, sources, target)?;
+    generate(air_functions, arch, out)
+}
+
+/// Writes the assembly for `arch` of the lowered `air_functions`.
+pub fn generate<W: Write>(
+    air_functions: Vec<air::AirFunction>,
+    arch: Arch,
+    out: &mut W,
+) -> Result<(), Diagnostics> {
+    let mut artifacts = codegen::Artifacts::collect(&air_functions);
+    arch.write_preamble(out)?;
+    for func in air_functions {
+        arch.function(func, &mut artifacts, out)?;
+    }
+    arch.emit_externs(&artifacts.externs, out)?;
+    arch.emit_data(artifacts.string_literals(), out)?;
+    Ok(())
+}
+
+/// Lowers every item `reader` yields to the AIR functions of a program that
+/// starts in `target`. Errors are collected the same way `compile` collects
+/// them.
+pub fn lower_modules(
+    mut reader: ModuleReader<'_>,
+    sources: &mut SourceMap,
+    target: &str,
+) -> Result<Vec<air::AirFunction>, Diagnostics> {
     let mut symbols = SymbolRegistry::new();
     let mut hir_ctx = hir::Context::new();
     let mut air_functions: Vec<air::AirFunction> = Vec::new();
     let mut hir_functions: HashMap<String, hir::Function> = HashMap::new();
-
-    // Emit preamble (globals, default labels, etc.).
-    codegen::write_preamble(out)?;
+    let mut diagnostics = Diagnostics::new();
+    let mut syntax_ok = true;
 
     let mut lowerer = Lowerer::new();
     let mut entry_items: Vec<hir::BlockItem> = Vec::new();
 
-    while let Some(item) = parser.next_block_item()? {
-        reject_root_execution(&item)?;
-        lowerer.consume(&mut hir_ctx, item)?; // consume one function/item
+    loop {
+        let item = match reader.next_item(sources) {
+            Ok(Some(item)) => item,
+            Ok(None) => break,
+            Err(err) => {
+                diagnostics.push(err);
+                if diagnostics.has_fatal() {
+                    return Err(diagnostics);
+                }
+                syntax_ok = false;
+                reader.synchronize();
+                continue;
+            }
+        };
+        if !syntax_ok {
+            continue;
+        }
+        if let Err(err) = reject_root_execution(&item) {
+            diagnostics.push(err);
+            continue;
+        }
+        // consume one function/item
+        if let Err(err) = lowerer.consume(&mut hir_ctx, item) {
+            diagnostics.push(err);
+        }
 
         // produce many functions/types etc (hoisted)
         while let Some(lowered) = lowerer.produce() {
             match lowered {
                 hir::BlockItem::Import { label, path } => {
-                    symbol::register_builtin_import(&label, &path, &mut symbols)?;
+                    if let Err(err) = symbol::register_builtin_import(&label, &path, &mut symbols) {
+                        diagnostics.push(err);
+                    }
                 }
                 hir::BlockItem::SigDef { name, sig } => {
-                    symbols.install_type(name.to_string(), air::SigKind::Sig(sig.clone()))?;
+                    if let Err(err) =
+                        symbols.install_type(name.to_string(), air::SigKind::Sig(sig.clone()))
+                    {
+                        diagnostics.push(err);
+                    }
                 }
                 hir::BlockItem::FunctionDef(function) => {
                     let sig = air::function_sig_from_hir(&function);
-                    symbols.declare_function(sig)?;
+                    if let Err(err) = symbols.declare_function(sig) {
+                        diagnostics.push(err);
+                    }
                     hir_functions.insert(function.name.clone(), function);
                 }
                 other => entry_items.push(other),
@@ -72,22 +183,35 @@ pub fn compile<R: BufRead, W: Write>(input: R, target: &str, out: &mut W) -> Res
         }
     }
 
-    let target_exec = ast::BlockItem::Ident(ast::Ident {
-        name: target.to_string(),
-        args: Vec::new(),
-        span: Span::unknown(),
-    });
-    lowerer.consume(&mut hir_ctx, target_exec)?;
+    if let Err(err) = lowerer.finish(&hir_ctx) {
+        diagnostics.push(err);
+    }
+    // A broken target would only be reported again as unresolved.
+    if !diagnostics.is_empty() {
+        return Err(diagnostics);
+    }
+
+    for item in entry_target_items(target, hir_functions.get(target), &hir_ctx)? {
+        lowerer.consume(&mut hir_ctx, item)?;
+    }
     while let Some(lowered) = lowerer.produce() {
         match lowered {
-            hir::BlockItem::Import { .. }
-            | hir::BlockItem::SigDef { .. }
-            | hir::BlockItem::FunctionDef(_) => {
+            // Passing arguments to the target goes through a function of
+            // its own, with the builtins it imports.
+            hir::BlockItem::Import { label, path } => {
+                symbol::register_builtin_import(&label, &path, &mut symbols)?;
+            }
+            hir::BlockItem::FunctionDef(function) => {
+                symbols.declare_function(air::function_sig_from_hir(&function))?;
+                hir_functions.insert(function.name.clone(), function);
+            }
+            hir::BlockItem::SigDef { .. } => {
                 return Err(CompilerError::new(
                     Code::Internal,
                     "entry target lowering produced a declaration",
                     Span::unknown(),
-                ));
+                )
+                .into());
             }
             other => entry_items.push(other),
         }
@@ -98,25 +222,248 @@ pub fn compile<R: BufRead, W: Write>(input: R, target: &str, out: &mut W) -> Res
     let mut generated = function_lowerer.take_generated_functions();
     generated.extend(entry_funcs);
     air_functions.extend(generated);
+    Ok(air_functions)
+}
 
-    let mut artifacts = codegen::Artifacts::collect(&air_functions);
-    for func in air_functions {
-        codegen::function(func, &mut artifacts, out)?;
+/// The items that start the program at `target`, given its lowered
+/// `function` when it is one.
+pub fn entry_target_items(
+    target: &str,
+    function: Option<&hir::Function>,
+    hir_ctx: &hir::Context,
+) -> Result<Vec<ast::BlockItem>, Error> {
+    let takes_args = match function {
+        Some(function) => entry_takes_args(function, hir_ctx)?,
+        None => false,
+    };
+    Ok(entry_items_for(target, takes_args))
+}
+
+/// Whether the entry `target` asks for the command line, which it can only do
+/// with the parameters `entry_items_for` passes.
+fn entry_takes_args(target: &hir::Function, hir_ctx: &hir::Context) -> Result<bool, Error> {
+    // Captured names come first and are passed implicitly.
+    let captures = hir_ctx
+        .get(&target.name)
+        .map_or(0, |entry| entry.captures.len());
+    let params = &target.sig.items[captures..];
+    if params.is_empty() {
+        return Ok(false);
+    }
+    let nth = hir::Signature::from_kinds([
+        hir::SigKind::Int,
+        hir::SigKind::Sig(hir::Signature::from_kinds([hir::SigKind::Str])),
+        hir::SigKind::Sig(hir::Signature::from_kinds([])),
+    ]);
+    let expected = hir::Signature::from_kinds([hir::SigKind::Int, hir::SigKind::Sig(nth)]);
+    if params != expected.items.as_slice() {
+        return Err(CompilerError::new(
+            Code::HIR,
+            format!(
+                "entry target `{}` takes unsupported parameters",
+                target.name
+            ),
+            target.span,
+        )
+        .with_help(
+            "take no parameters, or `(argc: int, nth: (index: int, one: (str), none: ()))` \
+             for the command line",
+        ));
+    }
+    Ok(true)
+}
+
+/// Root function that hands a target with parameters its arguments.
+const ENTRY_ARGS_FUNCTION: &str = "_start_args";
+
+/// The items that start the program at `target`. A target with parameters is
+/// handed the argument count and an accessor for the arguments, as in
+/// `main: (argc: int, nth: (index: int, one: (str), none: ()))`, from a root
+/// function so that the lambdas involved call `target` instead of
+/// capturing it.
+fn entry_items_for(target: &str, takes_args: bool) -> Vec<ast::BlockItem> {
+    if !takes_args {
+        return vec![ast::BlockItem::Ident(entry_call(target, Vec::new()))];
+    }
+    let continuation = |kinds: Vec<ast::SigKind>| {
+        ast::SigKind::Sig(ast::Signature::from_kinds(kinds, Span::unknown()))
+    };
+    let nth = entry_lambda(
+        &[
+            ("index", ast::SigKind::Int),
+            ("one", continuation(vec![ast::SigKind::Str])),
+            ("none", continuation(Vec::new())),
+        ],
+        entry_call(
+            "@arg",
+            vec![entry_name("index"), entry_name("one"), entry_name("none")],
+        ),
+    );
+    let start = entry_lambda(
+        &[("argc", ast::SigKind::Int)],
+        entry_call(target, vec![entry_name("argc"), nth]),
+    );
+    let ast::Term::Lambda(lambda) = entry_lambda(&[], entry_call("@argc", vec![start])) else {
+        unreachable!("entry_lambda makes lambdas");
+    };
+    vec![
+        ast::BlockItem::FunctionDef {
+            name: ENTRY_ARGS_FUNCTION.to_string(),
+            lambda,
+            span: Span::unknown(),
+        },
+        ast::BlockItem::Ident(entry_call(ENTRY_ARGS_FUNCTION, Vec::new())),
+    ]
+}
+
+fn entry_call(name: &str, args: Vec<ast::Term>) -> ast::Ident {
+    ast::Ident {
+        name: name.to_string(),
+        args: args
+            .into_iter()
+            .map(|term| ast::Arg {
+                name: None,
+                term,
+                span: Span::unknown(),
+            })
+            .collect(),
+        span: Span::unknown(),
+    }
+}
+
+fn entry_name(name: &str) -> ast::Term {
+    ast::Term::Ident(entry_call(name, Vec::new()))
+}
+
+fn entry_lambda(params: &[(&str, ast::SigKind)], body: ast::Ident) -> ast::Term {
+    let mut params_sig =
+        ast::Signature::from_kinds(params.iter().map(|(_, kind)| kind.clone()), Span::unknown());
+    for (item, (name, _)) in params_sig.items.iter_mut().zip(params) {
+        item.name = name.to_string();
+    }
+    ast::Term::Lambda(ast::Lambda {
+        params: params_sig,
+        body: ast::Block {
+            items: vec![ast::BlockItem::Ident(body)],
+            span: Span::unknown(),
+        },
+        args: Vec::new(),
+        span: Span::unknown(),
+    })
+}
+
+/// A pipeline stage whose output `emit` can write on its own.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stage {
+    Ast,
+    Hir,
+    Air,
+    Asm,
+}
+
+impl Stage {
+    pub const NAMES: &'static str = "ast|hir|air|asm";
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "ast" => Some(Stage::Ast),
+            "hir" => Some(Stage::Hir),
+            "air" => Some(Stage::Air),
+            "asm" => Some(Stage::Asm),
+            _ => None,
+        }
+    }
+}
+
+/// Writes the program as it stands after `stage`: the parsed items, the
+/// normalized HIR, the AIR functions or the final assembly for `arch`. Errors
+/// are collected the same way `compile` collects them.
+pub fn emit<W: Write>(
+    mut reader: ModuleReader<'_>,
+    sources: &mut SourceMap,
+    target: &str,
+    arch: Arch,
+    stage: Stage,
+    out: &mut W,
+) -> Result<(), Diagnostics> {
+    match stage {
+        Stage::Asm => return compile_modules(reader, sources, target, arch, out),
+        Stage::Air => {
+            let functions = lower_modules(reader, sources, target)?;
+            out.write_all(format_air::render_air_functions(&functions).as_bytes())?;
+            return Ok(());
+        }
+        Stage::Ast | Stage::Hir => {}
+    }
+
+    let mut diagnostics = Diagnostics::new();
+    let mut items = Vec::new();
+    loop {
+        match reader.next_item(sources) {
+            Ok(Some(item)) => items.push(item),
+            Ok(None) => break,
+            Err(err) => {
+                diagnostics.push(err);
+                if diagnostics.has_fatal() {
+                    return Err(diagnostics);
+                }
+                reader.synchronize();
+            }
+        }
+    }
+    if !diagnostics.is_empty() {
+        return Err(diagnostics);
+    }
+    if stage == Stage::Ast {
+        writeln!(out, "{items:#?}")?;
+        return Ok(());
+    }
+
+    let mut hir_ctx = hir::Context::new();
+    let mut lowerer = Lowerer::new();
+    let mut hir_items = Vec::new();
+    for item in items {
+        let lowered =
+            reject_root_execution(&item).and_then(|_| lowerer.consume(&mut hir_ctx, item));
+        if let Err(err) = lowered {
+            diagnostics.push(err);
+        }
+        hir_items.extend(std::iter::from_fn(|| lowerer.produce()));
+    }
+    if let Err(err) = lowerer.finish(&hir_ctx) {
+        diagnostics.push(err);
+    }
+    if !diagnostics.is_empty() {
+        return Err(diagnostics);
+    }
+    let target_function = hir_items.iter().find_map(|item| match item {
+        hir::BlockItem::FunctionDef(function) if function.name == target => Some(function),
+        _ => None,
+    });
+    for item in entry_target_items(target, target_function, &hir_ctx)? {
+        lowerer.consume(&mut hir_ctx, item)?;
+    }
+    hir_items.extend(std::iter::from_fn(|| lowerer.produce()));
+
+    match stage {
+        Stage::Hir => out.write_all(format_hir::render_normalized_rgo(&hir_items).as_bytes())?,
+        Stage::Ast | Stage::Air | Stage::Asm => unreachable!("emitted before lowering"),
     }
-    codegen::emit_externs(&artifacts.externs, out)?;
-    codegen::emit_data(artifacts.string_literals(), out)?;
     Ok(())
 }
 
-fn reject_root_execution(item: &ast::BlockItem) -> Result<(), Error> {
+/// Rejects a top-level item that would run code rather than declare something.
+pub fn reject_root_execution(item: &ast::BlockItem) -> Result<(), Error> {
     match item {
         ast::BlockItem::Ident(_)
         | ast::BlockItem::Lambda(_)
+        | ast::BlockItem::Case(_)
         | ast::BlockItem::ScopeCapture { .. } => Err(CompilerError::new(
             Code::Parse,
             "root-level invocation is not supported; choose a target function",
             item.span(),
-        )),
+        )
+        .with_help("move the call into a function such as `main: () { ... }`")),
         _ => Ok(()),
     }
 }
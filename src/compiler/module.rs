@@ -0,0 +1,261 @@
+use std::collections::HashSet;
+use std::fs;
+use std::io::{BufRead, Cursor};
+use std::path::{Path, PathBuf};
+
+use crate::compiler::ast;
+use crate::compiler::error::{Code, Error};
+use crate::compiler::lexer::Lexer;
+use crate::compiler::parser::Parser;
+use crate::compiler::source_map::SourceMap;
+use crate::compiler::span::{FileId, Span};
+use crate::compiler::stdlib;
+
+/// Streams the top-level items of a program whose files pull each other in with
+/// `@"path.rgo"`.
+///
+/// An imported file's items are yielded in place of its import, so a name must
+/// still be defined before it is used, whichever file it lives in. Each file is
+/// read once no matter how many files import it; importing a file that is still
+/// being read is a cycle. Paths under `std/` name the bundled standard library.
+pub struct ModuleReader<'a> {
+    search_paths: Vec<PathBuf>,
+    stack: Vec<Module<'a>>,
+    loaded: HashSet<PathBuf>,
+}
+
+struct Module<'a> {
+    parser: Parser<Box<dyn BufRead + 'a>>,
+    // Canonical path, the `std/` path of a bundled file, or None for a root read
+    // from memory.
+    path: Option<PathBuf>,
+    // Relative imports are resolved against this directory first.
+    dir: PathBuf,
+}
+
+impl<'a> ModuleReader<'a> {
+    /// Reads the root from `input`, which is registered as `file`. Its imports are
+    /// resolved against the working directory.
+    pub fn new(input: impl BufRead + 'a, file: FileId, search_paths: Vec<PathBuf>) -> Self {
+        let input: Box<dyn BufRead + 'a> = Box::new(input);
+        Self {
+            search_paths,
+            stack: vec![Module {
+                parser: Parser::new(Lexer::with_file(input, file)),
+                path: None,
+                dir: PathBuf::new(),
+            }],
+            loaded: HashSet::new(),
+        }
+    }
+
+    /// Reads the root from the file at `path`, adding it and every file it
+    /// imports to `sources`.
+    pub fn open(
+        path: &Path,
+        search_paths: Vec<PathBuf>,
+        sources: &mut SourceMap,
+    ) -> Result<Self, Error> {
+        let mut reader = Self {
+            search_paths,
+            stack: Vec::new(),
+            loaded: HashSet::new(),
+        };
+        reader.enter(path, sources, Span::unknown())?;
+        Ok(reader)
+    }
+
+    pub fn next_item(&mut self, sources: &mut SourceMap) -> Result<Option<ast::BlockItem>, Error> {
+        loop {
+            let Some(module) = self.stack.last_mut() else {
+                return Ok(None);
+            };
+            match module.parser.next_block_item()? {
+                Some(ast::BlockItem::SourceImport { path, span }) => {
+                    if path.starts_with(stdlib::PREFIX) {
+                        self.enter_std(&path, sources, span)?;
+                    } else {
+                        let resolved = self.resolve(&path, span)?;
+                        self.enter(&resolved, sources, span)?;
+                    }
+                }
+                Some(item) => return Ok(Some(item)),
+                None => {
+                    self.stack.pop();
+                }
+            }
+        }
+    }
+
+    /// Skips to the next top-level item of the file that reported a syntax error.
+    pub fn synchronize(&mut self) {
+        if let Some(module) = self.stack.last_mut() {
+            module.parser.synchronize();
+        }
+    }
+
+    fn resolve(&self, path: &str, span: Span) -> Result<PathBuf, Error> {
+        let dir = self.stack.last().map(|module| module.dir.as_path());
+        let candidates: Vec<PathBuf> = dir
+            .into_iter()
+            .chain(self.search_paths.iter().map(PathBuf::as_path))
+            .map(|dir| dir.join(path))
+            .collect();
+        if let Some(found) = candidates.iter().find(|candidate| candidate.is_file()) {
+            return Ok(found.clone());
+        }
+
+        let mut err = Error::new(Code::Resolve, format!("cannot find `{path}`"), span);
+        for candidate in &candidates {
+            err = err.with_note(format!("looked for {}", candidate.display()));
+        }
+        Err(err.with_help("add the directory that holds it with `-I <dir>`"))
+    }
+
+    fn enter(&mut self, path: &Path, sources: &mut SourceMap, span: Span) -> Result<(), Error> {
+        let io_error =
+            |err: std::io::Error| Error::new(Code::Io, format!("{}: {err}", path.display()), span);
+        let canonical = fs::canonicalize(path).map_err(io_error)?;
+        if !self.first_visit(&canonical, span)? {
+            return Ok(());
+        }
+
+        let source = fs::read_to_string(&canonical).map_err(io_error)?;
+        let dir = path.parent().map(Path::to_path_buf).unwrap_or_default();
+        self.push(path.display().to_string(), source, canonical, dir, sources);
+        Ok(())
+    }
+
+    fn enter_std(&mut self, path: &str, sources: &mut SourceMap, span: Span) -> Result<(), Error> {
+        let Some(source) = stdlib::source(path) else {
+            let available: Vec<&str> = stdlib::paths().collect();
+            return Err(Error::new(
+                Code::Resolve,
+                format!("`{path}` is not part of the standard library"),
+                span,
+            )
+            .with_note(format!("available: {}", available.join(", "))));
+        };
+        let key = PathBuf::from(path);
+        if !self.first_visit(&key, span)? {
+            return Ok(());
+        }
+        self.push(
+            path.to_string(),
+            source.to_string(),
+            key,
+            PathBuf::new(),
+            sources,
+        );
+        Ok(())
+    }
+
+    // Errors when `path` is still being read, returns false when it was read already.
+    fn first_visit(&mut self, path: &Path, span: Span) -> Result<bool, Error> {
+        if let Some(start) = self
+            .stack
+            .iter()
+            .position(|module| module.path.as_deref() == Some(path))
+        {
+            let chain: Vec<String> = self.stack[start..]
+                .iter()
+                .filter_map(|module| module.path.as_deref())
+                .chain([path])
+                .map(display_name)
+                .collect();
+            return Err(Error::new(
+                Code::Resolve,
+                format!("import cycle: {}", chain.join(" -> ")),
+                span,
+            ));
+        }
+        Ok(self.loaded.insert(path.to_path_buf()))
+    }
+
+    fn push(
+        &mut self,
+        name: String,
+        source: String,
+        path: PathBuf,
+        dir: PathBuf,
+        sources: &mut SourceMap,
+    ) {
+        let input: Box<dyn BufRead + 'a> = Box::new(Cursor::new(source.clone().into_bytes()));
+        let file = sources.add(name, source);
+        self.stack.push(Module {
+            parser: Parser::new(Lexer::with_file(input, file)),
+            path: Some(path),
+            dir,
+        });
+    }
+}
+
+fn display_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| path.display().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_files(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("rgo-module-{name}-{}", std::process::id()));
+        fs::create_dir_all(&dir).expect("create temp dir");
+        for (file, source) in files {
+            fs::write(dir.join(file), source).expect("write source");
+        }
+        dir
+    }
+
+    fn item_names(
+        reader: &mut ModuleReader,
+        sources: &mut SourceMap,
+    ) -> Result<Vec<String>, Error> {
+        let mut names = Vec::new();
+        while let Some(item) = reader.next_item(sources)? {
+            if let ast::BlockItem::FunctionDef { name, .. } = item {
+                names.push(name);
+            }
+        }
+        Ok(names)
+    }
+
+    #[test]
+    fn imports_are_inlined_once_in_order() {
+        let dir = write_files(
+            "diamond",
+            &[
+                (
+                    "main.rgo",
+                    "@\"a.rgo\"\n@\"b.rgo\"\nmain: (ok: ()) {\n    ok()\n}\n",
+                ),
+                ("a.rgo", "@\"base.rgo\"\na: (ok: ()) {\n    ok()\n}\n"),
+                ("b.rgo", "@\"base.rgo\"\nb: (ok: ()) {\n    ok()\n}\n"),
+                ("base.rgo", "base: (ok: ()) {\n    ok()\n}\n"),
+            ],
+        );
+        let mut sources = SourceMap::new();
+        let mut reader =
+            ModuleReader::open(&dir.join("main.rgo"), Vec::new(), &mut sources).expect("open");
+        let names = item_names(&mut reader, &mut sources).expect("items");
+        assert_eq!(names, ["base", "a", "b", "main"]);
+    }
+
+    #[test]
+    fn import_cycle_reports_the_chain() {
+        let dir = write_files(
+            "cycle",
+            &[
+                ("main.rgo", "@\"a.rgo\"\nmain: (ok: ()) {\n    ok()\n}\n"),
+                ("a.rgo", "@\"main.rgo\"\na: (ok: ()) {\n    ok()\n}\n"),
+            ],
+        );
+        let mut sources = SourceMap::new();
+        let mut reader =
+            ModuleReader::open(&dir.join("main.rgo"), Vec::new(), &mut sources).expect("open");
+        let err = item_names(&mut reader, &mut sources).expect_err("cycle");
+        assert_eq!(err.message, "import cycle: main.rgo -> a.rgo -> main.rgo");
+    }
+}
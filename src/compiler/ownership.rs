@@ -0,0 +1,793 @@
+//! Checks that every closure a function owns is released or handed on exactly
+//! once along every path through the function.
+//!
+//! A function owns its closure parameters, the closures it makes with
+//! `@newclosure` and `@cloneclosure`, and the closures it loads out of an environment
+//! with `@field`. Releasing a closure with `@release` or `@callptr` consumes
+//! it, and so does handing it on: jumping to it, passing it to a function or
+//! closure, using it as the continuation of a builtin, or storing it into an
+//! environment, which includes capturing it with `@newclosure`.
+//!
+//! Each path is followed separately, so a closure that is consumed on one branch
+//! and not the other is reported as leaking on the path that missed it.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use crate::compiler::air::{AirArg, AirCallPtrTarget, AirFunction, AirOp, AirStmt, AirValue};
+use crate::compiler::air_ast::SigKind;
+use crate::compiler::diagnostic::Diagnostics;
+use crate::compiler::error::{Code, Error};
+
+/// Reports every closure in `functions` that leaks or is consumed twice.
+pub fn check(functions: &[AirFunction]) -> Result<(), Diagnostics> {
+    let mut diagnostics = Diagnostics::new();
+    for function in functions {
+        for err in check_function(function) {
+            diagnostics.push(err);
+        }
+    }
+    if diagnostics.is_empty() {
+        Ok(())
+    } else {
+        Err(diagnostics)
+    }
+}
+
+/// Whether a closure is still owned at a point, or how it was given up.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum Owned {
+    Live,
+    Released(&'static str),
+    Moved(String),
+}
+
+type State = BTreeMap<String, Owned>;
+
+struct Path {
+    pc: usize,
+    state: State,
+    /// Labels taken by branches on the way here, for notes.
+    labels: Vec<String>,
+}
+
+struct Checker<'a> {
+    function: &'a AirFunction,
+    labels: HashMap<&'a str, usize>,
+    errors: Vec<Error>,
+    reported: HashSet<(String, &'static str)>,
+}
+
+fn check_function(function: &AirFunction) -> Vec<Error> {
+    let labels = function
+        .items
+        .iter()
+        .enumerate()
+        .filter_map(|(pc, stmt)| match stmt {
+            AirStmt::Label(label) => Some((label.name.as_str(), pc)),
+            AirStmt::Op(_) => None,
+        })
+        .collect();
+    let mut checker = Checker {
+        function,
+        labels,
+        errors: Vec::new(),
+        reported: HashSet::new(),
+    };
+    let state = function
+        .sig
+        .params
+        .iter()
+        .filter(|param| is_closure(&param.kind))
+        .map(|param| (param.name.clone(), Owned::Live))
+        .collect();
+    checker.walk(Path {
+        pc: 0,
+        state,
+        labels: Vec::new(),
+    });
+    checker.errors
+}
+
+fn is_closure(kind: &SigKind) -> bool {
+    matches!(kind, SigKind::Sig(_))
+}
+
+impl<'a> Checker<'a> {
+    fn walk(&mut self, start: Path) {
+        let mut pending = vec![start];
+        let mut visited = HashSet::new();
+        while let Some(mut path) = pending.pop() {
+            loop {
+                if !visited.insert((path.pc, path.state.clone())) {
+                    break;
+                }
+                let op = match self.function.items.get(path.pc) {
+                    Some(AirStmt::Op(op)) => op.as_ref(),
+                    Some(AirStmt::Label(_)) => {
+                        path.pc += 1;
+                        continue;
+                    }
+                    None => {
+                        self.leaks(&path, "falls off its end");
+                        break;
+                    }
+                };
+                match self.step(op, &mut path) {
+                    Step::Next => path.pc += 1,
+                    Step::Branch(branches) => {
+                        for label in branches {
+                            let Some(target) = self.label(label) else {
+                                continue;
+                            };
+                            let mut labels = path.labels.clone();
+                            labels.push(label.to_string());
+                            pending.push(Path {
+                                pc: target,
+                                state: path.state.clone(),
+                                labels,
+                            });
+                        }
+                        path.pc += 1;
+                    }
+                    Step::Switch(targets) => {
+                        for label in targets {
+                            let Some(target) = self.label(label) else {
+                                continue;
+                            };
+                            let mut labels = path.labels.clone();
+                            labels.push(label.to_string());
+                            pending.push(Path {
+                                pc: target,
+                                state: path.state.clone(),
+                                labels,
+                            });
+                        }
+                        break;
+                    }
+                    Step::Goto(label) => {
+                        let Some(target) = self.label(label) else {
+                            break;
+                        };
+                        path.labels.push(label.to_string());
+                        path.pc = target;
+                    }
+                    Step::Exit(how) => {
+                        self.leaks(&path, &how);
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    fn step<'op>(&mut self, op: &'op AirOp, path: &mut Path) -> Step<'op> {
+        let state = &mut path.state;
+        match op {
+            AirOp::Return(ret) => {
+                if let Some(value) = &ret.value {
+                    self.use_name(state, value);
+                }
+                Step::Exit("returns".to_string())
+            }
+            AirOp::Jump(jump) => Step::Goto(&jump.target),
+            AirOp::JumpEqInt(jump) | AirOp::JumpEqStr(jump) => {
+                self.use_args(state, &jump.args);
+                Step::Branch(vec![&jump.target])
+            }
+            AirOp::JumpCmpStr(jump) => {
+                self.use_args(state, &jump.args);
+                Step::Branch(vec![&jump.lt_target, &jump.gt_target])
+            }
+            AirOp::JumpLt(jump) => {
+                self.use_values(state, [&jump.left, &jump.right]);
+                Step::Branch(vec![&jump.target])
+            }
+            AirOp::JumpGt(jump) => {
+                self.use_values(state, [&jump.left, &jump.right]);
+                Step::Branch(vec![&jump.target])
+            }
+            AirOp::JumpEqF64(jump) | AirOp::JumpLtF64(jump) | AirOp::JumpGtF64(jump) => {
+                self.use_args(state, [&jump.input_a, &jump.input_b]);
+                Step::Branch(vec![&jump.target])
+            }
+            AirOp::JumpTable(jump) => {
+                self.use_values(state, [&jump.index]);
+                Step::Switch(jump.targets.iter().map(String::as_str).collect())
+            }
+            AirOp::JumpArgs(jump) => {
+                let how = format!("passed to `{}`", jump.target.name);
+                self.move_args(state, &jump.args, &how);
+                Step::Exit(format!("jumps to `{}`", jump.target.name))
+            }
+            AirOp::JumpClosure(jump) => {
+                let how = format!("passed to `${}`", jump.env_end);
+                self.move_args(state, &jump.args, &how);
+                self.consume(state, &jump.env_end, Owned::Moved("jumped to".to_string()));
+                Step::Exit(format!("jumps to `${}`", jump.env_end))
+            }
+            AirOp::Add(op) => {
+                self.value_jump(state, [&op.input_a, &op.input_b], &op.target, "@add")
+            }
+            AirOp::Sub(op) => {
+                self.value_jump(state, [&op.input_a, &op.input_b], &op.target, "@sub")
+            }
+            AirOp::Mul(op) => {
+                self.value_jump(state, [&op.input_a, &op.input_b], &op.target, "@mul")
+            }
+            AirOp::AddF64(op) => {
+                self.value_jump(state, [&op.input_a, &op.input_b], &op.target, "@addf64")
+            }
+            AirOp::SubF64(op) => {
+                self.value_jump(state, [&op.input_a, &op.input_b], &op.target, "@subf64")
+            }
+            AirOp::MulF64(op) => {
+                self.value_jump(state, [&op.input_a, &op.input_b], &op.target, "@mulf64")
+            }
+            AirOp::DivF64(op) => {
+                self.value_jump(state, [&op.input_a, &op.input_b], &op.target, "@divf64")
+            }
+            AirOp::NegF64(op) => self.value_jump(state, [&op.input], &op.target, "@negf64"),
+            AirOp::SqrtF64(op) => self.value_jump(state, [&op.input], &op.target, "@sqrtf64"),
+            AirOp::IntToF64(op) => self.value_jump(state, [&op.input], &op.target, "@itof"),
+            AirOp::F64ToInt(op) => {
+                self.use_args(state, [&op.input]);
+                self.branch_jump(state, &[&op.err_target, &op.ok_target], "@ftoi")
+            }
+            AirOp::DivInt(op) => {
+                self.use_args(state, [&op.input_a, &op.input_b]);
+                self.branch_jump(state, &[&op.err_target, &op.ok_target], "@div")
+            }
+            AirOp::RemInt(op) => {
+                self.use_args(state, [&op.input_a, &op.input_b]);
+                self.branch_jump(state, &[&op.err_target, &op.ok_target], "@rem")
+            }
+            AirOp::And(op) => {
+                self.value_jump(state, [&op.input_a, &op.input_b], &op.target, "@and")
+            }
+            AirOp::Or(op) => self.value_jump(state, [&op.input_a, &op.input_b], &op.target, "@or"),
+            AirOp::Xor(op) => {
+                self.value_jump(state, [&op.input_a, &op.input_b], &op.target, "@xor")
+            }
+            AirOp::Shl(op) => {
+                self.value_jump(state, [&op.input_a, &op.input_b], &op.target, "@shl")
+            }
+            AirOp::Shr(op) => {
+                self.value_jump(state, [&op.input_a, &op.input_b], &op.target, "@shr")
+            }
+            AirOp::Neg(op) => self.value_jump(state, [&op.input], &op.target, "@neg"),
+            AirOp::AddChecked(checked)
+            | AirOp::SubChecked(checked)
+            | AirOp::MulChecked(checked) => {
+                let builtin = match op {
+                    AirOp::AddChecked(_) => "@addc",
+                    AirOp::SubChecked(_) => "@subc",
+                    _ => "@mulc",
+                };
+                self.use_args(state, [&checked.input_a, &checked.input_b]);
+                let targets = [&checked.overflow_target, &checked.ok_target];
+                self.branch_jump(state, &targets.map(String::as_str), builtin)
+            }
+            AirOp::StrLen(op) => self.value_jump(state, [&op.input], &op.target, "@strlen"),
+            AirOp::Concat(op) => {
+                self.value_jump(state, [&op.input_a, &op.input_b], &op.target, "@concat")
+            }
+            AirOp::Slice(op) => {
+                self.use_args(state, [&op.input, &op.start, &op.end]);
+                self.branch_jump(state, &[&op.err_target, &op.ok_target], "@slice")
+            }
+            AirOp::ByteAt(op) => {
+                self.use_args(state, [&op.input, &op.index]);
+                self.branch_jump(state, &[&op.err_target, &op.ok_target], "@byteat")
+            }
+            AirOp::ArrayLen(op) => self.value_jump(state, [&op.input], &op.target, "@arrlen"),
+            AirOp::ArrayGet(op) => {
+                self.use_args(state, [&op.input, &op.index]);
+                self.branch_jump(state, &[&op.one_target, &op.none_target], "@get")
+            }
+            AirOp::ArraySet(op) => {
+                self.use_args(state, [&op.input, &op.index, &op.value]);
+                self.branch_jump(state, &[&op.err_target, &op.ok_target], "@set")
+            }
+            AirOp::MapInsert(op) => self.value_jump(
+                state,
+                [&op.input, &op.key, &op.value],
+                &op.target,
+                "@insert",
+            ),
+            AirOp::MapLookup(op) => {
+                self.use_args(state, [&op.input, &op.key]);
+                let targets = [&op.found_target, &op.missing_target];
+                self.branch_jump(state, &targets.map(String::as_str), "@lookup")
+            }
+            AirOp::MapRemove(op) => {
+                self.value_jump(state, [&op.input, &op.key], &op.target, "@remove")
+            }
+            AirOp::MapLen(op) => self.value_jump(state, [&op.input], &op.target, "@maplen"),
+            AirOp::MapNext(op) => {
+                self.use_args(state, [&op.input, &op.cursor]);
+                let targets = [&op.entry_target, &op.done_target];
+                self.branch_jump(state, &targets.map(String::as_str), "@mapnext")
+            }
+            AirOp::NewChan(op) => self.value_jump(state, [], &op.target, "@chan"),
+            AirOp::ChanSend(op) => {
+                self.use_args(state, [&op.channel]);
+                self.move_args(
+                    state,
+                    std::slice::from_ref(&op.value),
+                    "sent over a channel",
+                );
+                self.value_jump(state, [], &op.target, "@send")
+            }
+            AirOp::ChanRecv(op) => self.value_jump(state, [&op.channel], &op.target, "@recv"),
+            AirOp::Spawn(op) => {
+                let how = Owned::Moved("spawned as a coroutine".to_string());
+                self.consume(state, &op.task, how);
+                self.value_jump(state, [], &op.target, "@spawn")
+            }
+            AirOp::Thread(op) => {
+                let how = Owned::Moved("started on a thread".to_string());
+                self.consume(state, &op.task, how);
+                self.value_jump(state, [], &op.target, "@thread")
+            }
+            AirOp::Yield(op) => self.value_jump(state, [], &op.target, "@yield"),
+            AirOp::Done(_) => Step::Exit("ends in @done".to_string()),
+            AirOp::SysExit(exit) => {
+                self.use_args(state, &exit.args);
+                Step::Exit("exits".to_string())
+            }
+            AirOp::Printf(call) => self.value_jump(state, &call.args, &call.target, "@printf"),
+            AirOp::Sprintf(call) => self.value_jump(state, &call.args, &call.target, "@sprintf"),
+            AirOp::Write(call) => self.value_jump(state, &call.args, &call.target, "@write"),
+            AirOp::WriteFd(call) => self.value_jump(state, &call.args, &call.target, "@writefd"),
+            AirOp::Read(op) => {
+                self.use_args(state, [&op.fd, &op.max]);
+                let targets = [&op.ok_target, &op.eof_target, &op.err_target];
+                self.branch_jump(state, &targets.map(String::as_str), "@read")
+            }
+            AirOp::Open(op) => {
+                self.use_args(state, [&op.path, &op.flags, &op.mode]);
+                self.branch_jump(state, &[&op.err_target, &op.ok_target], "@open")
+            }
+            AirOp::Close(op) => {
+                self.use_args(state, [&op.fd]);
+                self.branch_jump(state, &[&op.err_target, &op.ok_target], "@close")
+            }
+            AirOp::ArgCount(op) => self.value_jump(state, [], &op.target, "@argc"),
+            AirOp::ArgAt(op) => {
+                self.use_args(state, [&op.index]);
+                self.branch_jump(state, &[&op.one_target, &op.none_target], "@arg")
+            }
+            AirOp::Getenv(op) => {
+                self.use_args(state, [&op.name]);
+                let targets = [&op.found_target, &op.missing_target];
+                self.branch_jump(state, &targets.map(String::as_str), "@getenv")
+            }
+            AirOp::CallPtr(call) => {
+                let AirCallPtrTarget::Binding(name) = &call.target;
+                self.consume(state, name, Owned::Released("@callptr"));
+                Step::Next
+            }
+            AirOp::ReleaseHeap(release) => {
+                self.consume(state, &release.name, Owned::Released("@release"));
+                Step::Next
+            }
+            // Only closures are tracked; strings, arrays, maps and channels are plain values here.
+            AirOp::ReleaseStr(release) => {
+                self.use_name(state, &release.name);
+                Step::Next
+            }
+            AirOp::ReleaseArray(release) => {
+                self.use_name(state, &release.name);
+                Step::Next
+            }
+            AirOp::CopyStr(copy) => {
+                self.use_name(state, &copy.src);
+                Step::Next
+            }
+            AirOp::CopyArray(copy) => {
+                self.use_name(state, &copy.src);
+                Step::Next
+            }
+            AirOp::NewArray(array) => {
+                self.use_args(state, &array.items);
+                Step::Next
+            }
+            AirOp::ReleaseMap(release) => {
+                self.use_name(state, &release.name);
+                Step::Next
+            }
+            AirOp::CopyMap(copy) => {
+                self.use_name(state, &copy.src);
+                Step::Next
+            }
+            AirOp::ReleaseChan(release) => {
+                self.use_name(state, &release.name);
+                Step::Next
+            }
+            AirOp::CopyChan(copy) => {
+                self.use_name(state, &copy.src);
+                Step::Next
+            }
+            AirOp::ReleaseSum(release) => {
+                self.use_name(state, &release.name);
+                Step::Next
+            }
+            AirOp::NewMap(map) => {
+                let entries = map.entries.iter().flat_map(|(key, value)| [key, value]);
+                self.use_args(state, entries);
+                Step::Next
+            }
+            AirOp::NewClosure(closure) => {
+                let how = format!("captured by `${}`", closure.name);
+                self.move_args(state, &closure.args, &how);
+                self.bind(state, &closure.name);
+                Step::Next
+            }
+            AirOp::CloneClosure(clone) => {
+                self.use_name(state, &clone.src);
+                self.bind(state, &clone.dst);
+                Step::Next
+            }
+            AirOp::Pin(pin) => {
+                self.use_values(state, [&pin.value]);
+                Step::Next
+            }
+            AirOp::Field(field) => {
+                self.use_name(state, &field.ptr);
+                if is_closure(&field.kind) {
+                    self.bind(state, &field.result);
+                }
+                Step::Next
+            }
+            AirOp::CopyField(field) => {
+                self.use_name(state, &field.ptr);
+                Step::Next
+            }
+            AirOp::SetField(set) => {
+                self.use_name(state, &set.env_end);
+                if is_closure(&set.value.kind) && set.value.literal.is_none() {
+                    let how = Owned::Moved(format!("stored into `${}`", set.env_end));
+                    self.consume(state, &set.value.name, how);
+                } else {
+                    self.use_args(state, [&set.value]);
+                }
+                Step::Next
+            }
+        }
+    }
+
+    /// Uses `inputs`, then hands the result to the closure `target` and jumps
+    /// to it.
+    fn value_jump<'op, 'i>(
+        &mut self,
+        state: &mut State,
+        inputs: impl IntoIterator<Item = &'i AirArg>,
+        target: &str,
+        builtin: &str,
+    ) -> Step<'op> {
+        self.use_args(state, inputs);
+        let how = Owned::Moved(format!("used as the continuation of {builtin}"));
+        self.consume(state, target, how);
+        Step::Exit(format!("ends in {builtin}"))
+    }
+
+    /// Jumps to one of `targets` and releases the others.
+    fn branch_jump<'op>(
+        &mut self,
+        state: &mut State,
+        targets: &[&str],
+        builtin: &str,
+    ) -> Step<'op> {
+        for target in targets {
+            let how = Owned::Moved(format!("used as a continuation of {builtin}"));
+            self.consume(state, target, how);
+        }
+        Step::Exit(format!("ends in {builtin}"))
+    }
+
+    fn label(&mut self, label: &str) -> Option<usize> {
+        let target = self.labels.get(label).copied();
+        if target.is_none() {
+            let message = format!(
+                "`{}` jumps to label `{label}`, which it does not define",
+                self.function.sig.name
+            );
+            self.report(
+                label,
+                "label",
+                Error::new(Code::Internal, message, self.function.span),
+            );
+        }
+        target
+    }
+
+    fn bind(&mut self, state: &mut State, name: &str) {
+        if state.get(name) == Some(&Owned::Live) {
+            let message = format!(
+                "closure `${name}` in `{}` is overwritten while it is still owned",
+                self.function.sig.name
+            );
+            self.report(name, "leak", self.error(message));
+        }
+        state.insert(name.to_string(), Owned::Live);
+    }
+
+    fn use_name(&mut self, state: &State, name: &str) {
+        if let Some(how) = state.get(name).and_then(Owned::given_up) {
+            let message = format!(
+                "closure `${name}` in `{}` is used after it was given up",
+                self.function.sig.name
+            );
+            let err = self.error(message).with_note(format!("it was {how}"));
+            self.report(name, "use", err);
+        }
+    }
+
+    fn use_args<'i>(&mut self, state: &State, args: impl IntoIterator<Item = &'i AirArg>) {
+        for arg in args {
+            if arg.literal.is_none() {
+                self.use_name(state, &arg.name);
+            }
+        }
+    }
+
+    fn use_values<'i>(&mut self, state: &State, values: impl IntoIterator<Item = &'i AirValue>) {
+        for value in values {
+            if let AirValue::Binding(name) = value {
+                self.use_name(state, name);
+            }
+        }
+    }
+
+    fn move_args(&mut self, state: &mut State, args: &[AirArg], how: &str) {
+        for arg in args {
+            if arg.literal.is_none() {
+                self.consume(state, &arg.name, Owned::Moved(how.to_string()));
+            }
+        }
+    }
+
+    /// Gives up `name` if it is a closure this function owns.
+    fn consume(&mut self, state: &mut State, name: &str, how: Owned) {
+        let Some(owned) = state.get_mut(name) else {
+            return;
+        };
+        match (&*owned, &how) {
+            (Owned::Live, _) => *owned = how,
+            (Owned::Released(first), Owned::Released(second)) => {
+                let message = format!(
+                    "closure `${name}` in `{}` is released twice",
+                    self.function.sig.name
+                );
+                let err = self
+                    .error(message)
+                    .with_note(format!("it is released by {first}, then by {second}"));
+                self.report(name, "double", err);
+            }
+            (first, then) => {
+                let (first, then) = (
+                    first.given_up().unwrap_or_default(),
+                    then.given_up().unwrap_or_default(),
+                );
+                let message = format!(
+                    "closure `${name}` in `{}` is given up twice",
+                    self.function.sig.name
+                );
+                let err = self
+                    .error(message)
+                    .with_note(format!("it is {first}, then {then}"));
+                self.report(name, "double", err);
+            }
+        }
+    }
+
+    /// Reports every closure still owned where `path` leaves the function.
+    fn leaks(&mut self, path: &Path, how: &str) {
+        let live: Vec<&String> = path
+            .state
+            .iter()
+            .filter(|(_, owned)| **owned == Owned::Live)
+            .map(|(name, _)| name)
+            .collect();
+        for name in live {
+            let message = format!(
+                "closure `${name}` in `{}` is never released",
+                self.function.sig.name
+            );
+            let mut err = self.error(message).with_note(format!(
+                "it is still owned when `{}` {how}",
+                self.function.sig.name
+            ));
+            if !path.labels.is_empty() {
+                let labels: Vec<String> = path
+                    .labels
+                    .iter()
+                    .map(|label| format!("`{label}`"))
+                    .collect();
+                err = err.with_note(format!("on the path through {}", labels.join(", ")));
+            }
+            self.report(name, "leak", err);
+        }
+    }
+
+    fn error(&self, message: String) -> Error {
+        Error::new(Code::Ownership, message, self.function.span)
+    }
+
+    /// Records `err` unless the same problem with `name` was already reported
+    /// on another path.
+    fn report(&mut self, name: &str, problem: &'static str, err: Error) {
+        if self.reported.insert((name.to_string(), problem)) {
+            self.errors.push(err);
+        }
+    }
+}
+
+/// Where a path goes after an op.
+enum Step<'op> {
+    Next,
+    Branch(Vec<&'op str>),
+    Goto(&'op str),
+    /// Jumps to one of the labels, never falling through.
+    Switch(Vec<&'op str>),
+    Exit(String),
+}
+
+impl Owned {
+    /// How the closure was given up, or `None` while it is still owned.
+    fn given_up(&self) -> Option<String> {
+        match self {
+            Owned::Live => None,
+            Owned::Released(by) => Some(format!("released by {by}")),
+            Owned::Moved(how) => Some(how.clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compiler::air_ast::{
+        AirCallPtr, AirJumpArgs, AirJumpGt, AirLabel, AirNewClosure, AirReleaseHeap, AirSysExit,
+        FunctionSig, SigItem,
+    };
+    use crate::compiler::hir_ast::Signature;
+    use crate::compiler::span::Span;
+    use std::collections::BTreeSet;
+
+    fn sig(name: &str, params: Vec<SigItem>) -> FunctionSig {
+        FunctionSig {
+            name: name.to_string(),
+            params,
+            generics: BTreeSet::new(),
+            builtin: None,
+        }
+    }
+
+    fn closure_param(name: &str) -> SigItem {
+        SigItem {
+            name: name.to_string(),
+            kind: SigKind::Sig(Signature::from_tuple(Vec::new())),
+            has_bang: false,
+        }
+    }
+
+    fn function(params: Vec<SigItem>, items: Vec<AirStmt>) -> AirFunction {
+        AirFunction {
+            sig: sig("f", params),
+            items,
+            span: Span::unknown(),
+        }
+    }
+
+    fn release(name: &str) -> AirStmt {
+        AirStmt::op(AirOp::ReleaseHeap(AirReleaseHeap {
+            name: name.to_string(),
+        }))
+    }
+
+    fn exit() -> AirStmt {
+        AirStmt::op(AirOp::SysExit(AirSysExit { args: Vec::new() }))
+    }
+
+    fn messages(function: &AirFunction) -> Vec<String> {
+        check_function(function)
+            .into_iter()
+            .map(|err| err.message)
+            .collect()
+    }
+
+    #[test]
+    fn accepts_closures_consumed_once() {
+        let k = AirArg {
+            name: "k".to_string(),
+            kind: SigKind::Sig(Signature::from_tuple(Vec::new())),
+            literal: None,
+        };
+        let function = function(
+            vec![closure_param("k")],
+            vec![
+                AirStmt::op(AirOp::NewClosure(AirNewClosure {
+                    name: "c".to_string(),
+                    target: sig("g", vec![closure_param("ok")]),
+                    args: vec![k.clone()],
+                })),
+                AirStmt::op(AirOp::JumpArgs(AirJumpArgs {
+                    target: sig("h", Vec::new()),
+                    args: vec![AirArg {
+                        name: "c".to_string(),
+                        ..k
+                    }],
+                })),
+            ],
+        );
+        assert_eq!(messages(&function), Vec::<String>::new());
+    }
+
+    #[test]
+    fn reports_double_releases_and_leaks() {
+        let double = function(
+            vec![closure_param("k")],
+            vec![
+                release("k"),
+                AirStmt::op(AirOp::CallPtr(AirCallPtr {
+                    target: AirCallPtrTarget::Binding("k".to_string()),
+                })),
+                exit(),
+            ],
+        );
+        assert_eq!(messages(&double), ["closure `$k` in `f` is released twice"]);
+
+        let leak = function(vec![closure_param("k")], vec![exit()]);
+        assert_eq!(messages(&leak), ["closure `$k` in `f` is never released"]);
+    }
+
+    #[test]
+    fn captures_move_closures() {
+        let k = AirArg {
+            name: "k".to_string(),
+            kind: SigKind::Sig(Signature::from_tuple(Vec::new())),
+            literal: None,
+        };
+        let function = function(
+            vec![closure_param("k")],
+            vec![
+                AirStmt::op(AirOp::NewClosure(AirNewClosure {
+                    name: "c".to_string(),
+                    target: sig("g", vec![closure_param("ok")]),
+                    args: vec![k],
+                })),
+                release("k"),
+                release("c"),
+                exit(),
+            ],
+        );
+        assert_eq!(
+            messages(&function),
+            ["closure `$k` in `f` is given up twice"]
+        );
+    }
+
+    #[test]
+    fn follows_each_branch() {
+        let function = function(
+            vec![closure_param("k")],
+            vec![
+                AirStmt::op(AirOp::JumpGt(AirJumpGt {
+                    left: AirValue::Literal(1),
+                    right: AirValue::Literal(0),
+                    target: "skip".to_string(),
+                })),
+                release("k"),
+                AirStmt::Label(AirLabel {
+                    name: "skip".to_string(),
+                }),
+                exit(),
+            ],
+        );
+        let errors = check_function(&function);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].message, "closure `$k` in `f` is never released");
+        assert_eq!(errors[0].notes[1], "on the path through `skip`");
+    }
+}
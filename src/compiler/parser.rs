@@ -3,11 +3,13 @@ use std::io::BufRead;
 
 use crate::compiler::ast;
 use crate::compiler::ast::{
-    Block, BlockItem, Ident, Lambda, Literal, SigIdent, SigItem, SigKind, Signature, Term,
+    ArrayLit, Block, BlockItem, Case, CaseArm, FieldAccess, Ident, Lambda, Literal, MapLit,
+    SigIdent, SigItem, SigKind, Signature, Term,
 };
 use crate::compiler::builtins;
 use crate::compiler::error::{Code, Error};
 use crate::compiler::lexer::Lexer;
+use crate::compiler::refinement::{Bound, BoundValue, Comparison};
 use crate::compiler::span::Span;
 use crate::compiler::token::{Token, TokenKind};
 
@@ -16,6 +18,7 @@ pub struct Parser<R: BufRead> {
     peeked: VecDeque<Token>,
     allow_top_imports: bool,
     generic_param_stack: Vec<BTreeSet<String>>,
+    prev_span: Span,
 }
 
 #[derive(Copy, Clone)]
@@ -31,6 +34,7 @@ impl<R: BufRead> Parser<R> {
             peeked: VecDeque::new(),
             allow_top_imports: true,
             generic_param_stack: Vec::new(),
+            prev_span: Span::unknown(),
         }
     }
 
@@ -51,6 +55,21 @@ impl<R: BufRead> Parser<R> {
         let token = self.peek_token()?.clone();
         match token.kind {
             TokenKind::Eof => Ok(None),
+            TokenKind::ImportPath(path) => {
+                self.bump()?;
+                if !self.allow_top_imports {
+                    return Err(Error::new(
+                        Code::Parse,
+                        "source imports must come before any other item in the file",
+                        token.span,
+                    ));
+                }
+                self.consume_block_item_separators()?;
+                Ok(Some(BlockItem::SourceImport {
+                    path,
+                    span: token.span,
+                }))
+            }
             _ => {
                 let item = self.parse_block_item()?;
                 if !matches!(&item, BlockItem::Import { .. }) {
@@ -62,6 +81,43 @@ impl<R: BufRead> Parser<R> {
         }
     }
 
+    /// Skips the rest of a broken top-level item so parsing can resume after an error.
+    /// Top-level items start in the first column, so the next line that does is taken
+    /// as the resume point. Lexer errors met while skipping belong to the broken item.
+    pub fn synchronize(&mut self) {
+        loop {
+            let token = match self.bump() {
+                Ok(token) => token,
+                Err(err) if err.code == Code::Io => return,
+                Err(_) => continue,
+            };
+            match token.kind {
+                TokenKind::Eof => {
+                    self.peeked.push_front(token);
+                    return;
+                }
+                TokenKind::Newline => match self.peek_token() {
+                    Ok(next) => {
+                        let resumes = next.span.column == 1
+                            && matches!(
+                                next.kind,
+                                TokenKind::Ident(_)
+                                    | TokenKind::Import(_)
+                                    | TokenKind::ImportPath(_)
+                                    | TokenKind::Eof
+                            );
+                        if resumes {
+                            return;
+                        }
+                    }
+                    Err(err) if err.code == Code::Io => return,
+                    Err(_) => {}
+                },
+                _ => {}
+            }
+        }
+    }
+
     fn skip_newlines(&mut self) -> Result<(), Error> {
         while self
             .consume_if(|k| matches!(k, TokenKind::Newline))?
@@ -107,10 +163,22 @@ impl<R: BufRead> Parser<R> {
                 return self.parse_lambda_or_scope_capture();
             }
             TokenKind::Newline => {}
+            TokenKind::ImportPath(_) => {
+                return Err(Error::new(
+                    Code::Parse,
+                    "source imports are only allowed at the top of a file",
+                    span,
+                ))
+            }
             _ => return Err(Error::new(Code::Parse, "expected a top-level item", span)),
         }
 
         let term = self.parse_term()?;
+        let is_case_value = matches!(&term, Term::Ident(ident) if ident.args.is_empty())
+            || matches!(term, Term::Field(_));
+        if is_case_value && matches!(self.peek_token()?.kind, TokenKind::LBrace) {
+            return self.parse_case(term);
+        }
         match term {
             Term::Lit(literal) => Err(Error::new(
                 Code::Parse,
@@ -119,6 +187,21 @@ impl<R: BufRead> Parser<R> {
             )),
             Term::Ident(ident) => Ok(BlockItem::Ident(ident)),
             Term::Lambda(lambda) => Ok(BlockItem::Lambda(lambda)),
+            Term::Array(array) => Err(Error::new(
+                Code::Parse,
+                "array literals cannot be called",
+                array.span,
+            )),
+            Term::Map(map) => Err(Error::new(
+                Code::Parse,
+                "map literals cannot be called",
+                map.span,
+            )),
+            Term::Field(field) => Err(Error::new(
+                Code::Parse,
+                "record fields cannot be called",
+                field.span,
+            )),
         }
     }
 
@@ -129,6 +212,24 @@ impl<R: BufRead> Parser<R> {
         let has_head = matches!(next_token.kind, TokenKind::LParen);
         let has_brace = matches!(next_token.kind, TokenKind::LBrace);
 
+        if has_brace && self.starts_record_fields()? {
+            let fields = self.parse_record_fields()?;
+            return Ok(BlockItem::RecordDef {
+                name,
+                fields,
+                span: name_span,
+            });
+        }
+
+        if has_brace && self.starts_sum_variants()? {
+            let variants = self.parse_sum_variants()?;
+            return Ok(BlockItem::SumDef {
+                name,
+                variants,
+                span: name_span,
+            });
+        }
+
         if has_brace {
             return Err(Error::new(
                 Code::Parse,
@@ -153,7 +254,7 @@ impl<R: BufRead> Parser<R> {
                     params,
                     body,
                     args: Vec::new(),
-                    span: name_span,
+                    span: self.span_from(name_span),
                 };
 
                 return Ok(BlockItem::FunctionDef {
@@ -163,6 +264,18 @@ impl<R: BufRead> Parser<R> {
                 });
             }
 
+            if self
+                .consume_if(|kind| matches!(kind, TokenKind::Ellipsis))?
+                .is_some()
+            {
+                params.generics = generics.clone();
+                return Ok(BlockItem::FunctionDecl {
+                    name,
+                    params,
+                    span: name_span,
+                });
+            }
+
             let param_types = Self::collect_param_kinds(&params.items)?;
             let mut target = Signature::from_kinds(param_types, params_span);
             target.generics = generics.clone();
@@ -195,6 +308,187 @@ impl<R: BufRead> Parser<R> {
         }
     }
 
+    // `{` opens a record type when the first thing inside is `name:`; a bare
+    // body block is rejected by the caller.
+    fn starts_record_fields(&mut self) -> Result<bool, Error> {
+        let mut n = 1;
+        while matches!(self.peek_nth(n)?.kind, TokenKind::Newline) {
+            n += 1;
+        }
+        Ok(matches!(self.peek_nth(n)?.kind, TokenKind::Ident(_))
+            && matches!(self.peek_nth(n + 1)?.kind, TokenKind::Colon))
+    }
+
+    fn parse_record_fields(&mut self) -> Result<Signature, Error> {
+        let lbrace = self.expect_token("{", |kind| matches!(kind, TokenKind::LBrace))?;
+        let mut fields: Vec<SigItem> = Vec::new();
+        loop {
+            self.skip_newlines()?;
+            if matches!(self.peek_token()?.kind, TokenKind::RBrace) {
+                break;
+            }
+            let (name, name_span) = self.parse_identifier("field name")?;
+            if fields.iter().any(|field| field.name == name) {
+                return Err(Error::new(
+                    Code::Parse,
+                    format!("field `{name}` is declared twice"),
+                    name_span,
+                ));
+            }
+            self.expect_token(":", |kind| matches!(kind, TokenKind::Colon))?;
+            let kind = self.parse_type_kind()?;
+            fields.push(SigItem {
+                name,
+                kind,
+                has_bang: false,
+                span: self.span_from(name_span),
+            });
+            self.skip_newlines()?;
+            if self
+                .consume_if(|kind| matches!(kind, TokenKind::Comma))?
+                .is_none()
+            {
+                break;
+            }
+        }
+        self.skip_newlines()?;
+        self.expect_token("}", |kind| matches!(kind, TokenKind::RBrace))?;
+        Ok(Signature {
+            items: fields,
+            span: self.span_from(lbrace.span),
+            generics: BTreeSet::new(),
+        })
+    }
+
+    // `{` opens a sum type when it lists at least two variants: a name with an
+    // optional payload, then a comma or another name on the next line. Anything
+    // else is left to be reported as a function body missing its parameters.
+    fn starts_sum_variants(&mut self) -> Result<bool, Error> {
+        let mut n = 1;
+        while matches!(self.peek_nth(n)?.kind, TokenKind::Newline) {
+            n += 1;
+        }
+        if !matches!(self.peek_nth(n)?.kind, TokenKind::Ident(_)) {
+            return Ok(false);
+        }
+        n += 1;
+        if matches!(self.peek_nth(n)?.kind, TokenKind::LParen) {
+            let mut depth = 0;
+            loop {
+                match self.peek_nth(n)?.kind {
+                    TokenKind::LParen => depth += 1,
+                    TokenKind::RParen => depth -= 1,
+                    TokenKind::Eof => return Ok(false),
+                    _ => {}
+                }
+                n += 1;
+                if depth == 0 {
+                    break;
+                }
+            }
+        }
+        match self.peek_nth(n)?.kind {
+            TokenKind::Comma => Ok(true),
+            TokenKind::Newline => {
+                while matches!(self.peek_nth(n)?.kind, TokenKind::Newline) {
+                    n += 1;
+                }
+                Ok(matches!(self.peek_nth(n)?.kind, TokenKind::Ident(_)))
+            }
+            _ => Ok(false),
+        }
+    }
+
+    fn parse_sum_variants(&mut self) -> Result<Signature, Error> {
+        let lbrace = self.expect_token("{", |kind| matches!(kind, TokenKind::LBrace))?;
+        let mut variants: Vec<SigItem> = Vec::new();
+        loop {
+            self.skip_newlines()?;
+            if matches!(self.peek_token()?.kind, TokenKind::RBrace) {
+                break;
+            }
+            let (name, name_span) = self.parse_identifier("variant name")?;
+            if variants.iter().any(|variant| variant.name == name) {
+                return Err(Error::new(
+                    Code::Parse,
+                    format!("variant `{name}` is declared twice"),
+                    name_span,
+                ));
+            }
+            let payload = if matches!(self.peek_token()?.kind, TokenKind::LParen) {
+                self.parse_params(ParamContext::Params)?
+            } else {
+                Signature {
+                    items: Vec::new(),
+                    span: name_span,
+                    generics: BTreeSet::new(),
+                }
+            };
+            variants.push(SigItem {
+                name,
+                kind: SigKind::Sig(payload),
+                has_bang: false,
+                span: self.span_from(name_span),
+            });
+            // Variants are separated by a comma, a line break or both.
+            let ends_line = matches!(self.peek_token()?.kind, TokenKind::Newline);
+            self.skip_newlines()?;
+            let has_comma = self
+                .consume_if(|kind| matches!(kind, TokenKind::Comma))?
+                .is_some();
+            if !ends_line && !has_comma {
+                break;
+            }
+        }
+        self.skip_newlines()?;
+        self.expect_token("}", |kind| matches!(kind, TokenKind::RBrace))?;
+        Ok(Signature {
+            items: variants,
+            span: self.span_from(lbrace.span),
+            generics: BTreeSet::new(),
+        })
+    }
+
+    // Each arm names a variant, binds its payload like lambda parameters and
+    // runs its body; `empty { ... }` stands for `empty() { ... }`.
+    fn parse_case(&mut self, value: Term) -> Result<BlockItem, Error> {
+        self.expect_token("{", |kind| matches!(kind, TokenKind::LBrace))?;
+        let mut arms = Vec::new();
+        loop {
+            self.skip_newlines()?;
+            if matches!(self.peek_token()?.kind, TokenKind::RBrace) {
+                break;
+            }
+            let (variant, variant_span) = self.parse_identifier("variant name")?;
+            let params = if matches!(self.peek_token()?.kind, TokenKind::LParen) {
+                self.parse_params(ParamContext::Lambda)?
+            } else {
+                Signature {
+                    items: Vec::new(),
+                    span: variant_span,
+                    generics: BTreeSet::new(),
+                }
+            };
+            let brace = self.expect_token("{", |kind| matches!(kind, TokenKind::LBrace))?;
+            let body = self.parse_body(brace.span)?;
+            self.expect_token("}", |kind| matches!(kind, TokenKind::RBrace))?;
+            let span = self.span_from(variant_span);
+            arms.push(CaseArm {
+                variant,
+                lambda: Lambda {
+                    params,
+                    body,
+                    args: Vec::new(),
+                    span,
+                },
+                span,
+            });
+        }
+        self.expect_token("}", |kind| matches!(kind, TokenKind::RBrace))?;
+        let span = self.span_from(value.span());
+        Ok(BlockItem::Case(Case { value, arms, span }))
+    }
+
     fn parse_lambda_or_scope_capture(&mut self) -> Result<BlockItem, Error> {
         // 1. Parse params ALWAYS
         let params = self.parse_params(ParamContext::Lambda)?;
@@ -209,7 +503,7 @@ impl<R: BufRead> Parser<R> {
                     params: params.clone(),
                     continuation,
                     term,
-                    span: params.span,
+                    span: self.span_from(params.span),
                 })
             }
             TokenKind::LBrace => {
@@ -225,7 +519,7 @@ impl<R: BufRead> Parser<R> {
                     params,
                     body,
                     args,
-                    span: brace.span,
+                    span: self.span_from(brace.span),
                 }))
             }
 
@@ -240,16 +534,41 @@ impl<R: BufRead> Parser<R> {
     fn parse_term(&mut self) -> Result<Term, Error> {
         let mut term = self.parse_head()?;
 
-        while matches!(self.peek_token()?.kind, TokenKind::LParen) {
+        loop {
+            if matches!(self.peek_token()?.kind, TokenKind::Dot) {
+                let dot = self.bump()?; // consume '.'
+                if !matches!(&term, Term::Ident(ident) if ident.args.is_empty())
+                    && !matches!(term, Term::Field(_))
+                {
+                    return Err(Error::new(
+                        Code::Parse,
+                        "expected a record name before `.`",
+                        dot.span,
+                    ));
+                }
+                let (field, _) = self.parse_identifier("field name after `.`")?;
+                let start = term.span();
+                term = Term::Field(FieldAccess {
+                    record: Box::new(term),
+                    field,
+                    span: self.span_from(start),
+                });
+                continue;
+            }
+            if !matches!(self.peek_token()?.kind, TokenKind::LParen) {
+                break;
+            }
             let lparen = self.bump()?; // consume '('
             let args = self.parse_argument_list()?;
 
             match &mut term {
                 Term::Ident(ident) => {
                     ident.args.extend(args);
+                    ident.span = self.span_from(ident.span);
                 }
                 Term::Lambda(lambda) => {
                     lambda.args.extend(args);
+                    lambda.span = self.span_from(lambda.span);
                 }
                 _ => {
                     return Err(Error::new(
@@ -302,7 +621,47 @@ impl<R: BufRead> Parser<R> {
                     params,
                     body,
                     args: Vec::new(),
-                    span: token.span,
+                    span: self.span_from(token.span),
+                }))
+            }
+            TokenKind::LBracket => {
+                // [ a, b, ... ] → array literal, [ k: v, ... ] or [:] → map literal
+                self.skip_newlines()?;
+                if self
+                    .consume_if(|kind| matches!(kind, TokenKind::Colon))?
+                    .is_some()
+                {
+                    self.skip_newlines()?;
+                    self.expect_token("]", |kind| matches!(kind, TokenKind::RBracket))?;
+                    return Ok(Term::Map(MapLit {
+                        entries: Vec::new(),
+                        span: self.span_from(token.span),
+                    }));
+                }
+                let mut items = Vec::new();
+                loop {
+                    self.skip_newlines()?;
+                    if matches!(self.peek_token()?.kind, TokenKind::RBracket) {
+                        break;
+                    }
+                    items.push(self.parse_term()?);
+                    self.skip_newlines()?;
+                    if items.len() == 1 && matches!(self.peek_token()?.kind, TokenKind::Colon) {
+                        let key = items.pop().expect("first map key");
+                        return self.parse_map_entries(token.span, key);
+                    }
+                    if self
+                        .consume_if(|kind| matches!(kind, TokenKind::Comma))?
+                        .is_none()
+                    {
+                        break;
+                    }
+                }
+                self.skip_newlines()?;
+                self.expect_token("]", |kind| matches!(kind, TokenKind::RBracket))?;
+                Ok(Term::Array(ArrayLit {
+                    items,
+                    span: self.span_from(token.span),
                 }))
             }
             _ => Err(Error::new(
@@ -313,6 +672,36 @@ impl<R: BufRead> Parser<R> {
         }
     }
 
+    fn parse_map_entries(&mut self, start: Span, first_key: Term) -> Result<Term, Error> {
+        let mut entries = Vec::new();
+        let mut key = first_key;
+        loop {
+            self.expect_token(":", |kind| matches!(kind, TokenKind::Colon))?;
+            self.skip_newlines()?;
+            let value = self.parse_term()?;
+            entries.push((key, value));
+            self.skip_newlines()?;
+            if self
+                .consume_if(|kind| matches!(kind, TokenKind::Comma))?
+                .is_none()
+            {
+                break;
+            }
+            self.skip_newlines()?;
+            if matches!(self.peek_token()?.kind, TokenKind::RBracket) {
+                break;
+            }
+            key = self.parse_term()?;
+            self.skip_newlines()?;
+        }
+        self.skip_newlines()?;
+        self.expect_token("]", |kind| matches!(kind, TokenKind::RBracket))?;
+        Ok(Term::Map(MapLit {
+            entries,
+            span: self.span_from(start),
+        }))
+    }
+
     fn parse_argument_list(&mut self) -> Result<Vec<ast::Arg>, Error> {
         let mut args = Vec::new();
         self.skip_newlines()?;
@@ -348,7 +737,7 @@ impl<R: BufRead> Parser<R> {
                 return Ok(ast::Arg {
                     name: Some(name),
                     term,
-                    span,
+                    span: self.span_from(span),
                 });
             }
         }
@@ -391,6 +780,11 @@ impl<R: BufRead> Parser<R> {
             // Pure type-only parameter: `int`, `str`, `(a:int)`
             (None, self.parse_type_kind()?)
         };
+        let ty = if matches!(self.peek_token()?.kind, TokenKind::LParen) {
+            SigKind::Refined(Box::new(ty), self.parse_bounds()?)
+        } else {
+            ty
+        };
 
         let has_bang = self
             .consume_if(|kind| matches!(kind, TokenKind::Bang))?
@@ -400,10 +794,59 @@ impl<R: BufRead> Parser<R> {
             name: name.unwrap_or_default(),
             kind: ty,
             has_bang,
-            span: item_span,
+            span: self.span_from(item_span),
         })
     }
 
+    // `(ne: 0, lt: 10)` after a parameter's type: the comparisons that refine it.
+    fn parse_bounds(&mut self) -> Result<Vec<Bound>, Error> {
+        let lparen = self.expect_token("(", |kind| matches!(kind, TokenKind::LParen))?;
+        let mut bounds = Vec::new();
+        while !matches!(self.peek_token()?.kind, TokenKind::RParen) {
+            let (name, name_span) = self.parse_identifier("comparison")?;
+            let comparison = Comparison::from_name(&name).ok_or_else(|| {
+                Error::new(
+                    Code::Parse,
+                    format!("`{name}` is not a comparison; use eq, ne, lt, le, gt or ge"),
+                    name_span,
+                )
+            })?;
+            self.expect_token(":", |kind| matches!(kind, TokenKind::Colon))?;
+            let negative = self
+                .consume_if(|kind| matches!(kind, TokenKind::Minus))?
+                .is_some();
+            let token = self.bump()?;
+            let value = match token.kind {
+                TokenKind::IntLiteral(value) if negative => BoundValue::Int(-value),
+                TokenKind::IntLiteral(value) => BoundValue::Int(value),
+                TokenKind::StringLiteral(value) if !negative => BoundValue::Str(value),
+                _ => {
+                    return Err(Error::new(
+                        Code::Parse,
+                        "refinements compare with an int or str literal",
+                        token.span,
+                    ))
+                }
+            };
+            bounds.push(Bound { comparison, value });
+            if self
+                .consume_if(|kind| matches!(kind, TokenKind::Comma))?
+                .is_none()
+            {
+                break;
+            }
+        }
+        self.expect_token(")", |kind| matches!(kind, TokenKind::RParen))?;
+        if bounds.is_empty() {
+            return Err(Error::new(
+                Code::Parse,
+                "a refinement needs at least one comparison",
+                self.span_from(lparen.span),
+            ));
+        }
+        Ok(bounds)
+    }
+
     fn parse_generic_params(&mut self) -> Result<BTreeSet<String>, Error> {
         if !matches!(self.peek_token()?.kind, TokenKind::AngleOpen) {
             return Ok(BTreeSet::new());
@@ -496,7 +939,7 @@ impl<R: BufRead> Parser<R> {
                 self.expect_token(")", |kind| matches!(kind, TokenKind::RParen))?;
                 let kind = SigKind::Sig(Signature {
                     items: args,
-                    span: lparen.span,
+                    span: self.span_from(lparen.span),
                     generics: BTreeSet::new(),
                 });
                 Ok(kind)
@@ -533,7 +976,7 @@ impl<R: BufRead> Parser<R> {
                     //         CompileError::new(CompileErrorCode::Parse, format!("unknown type '{}'", name), span).into()
                     //     );
                     // }
-                    return Ok(SigKind::GenericInst { name, args });
+                    return Ok(SigKind::GenericInst { name, args, span });
                 }
 
                 // TODO: Not parsers job
@@ -556,6 +999,23 @@ impl<R: BufRead> Parser<R> {
                 // return Err(CompileError::new(CompileErrorCode::Parse, format!("unknown type '{}'", name), span).into());
                 Ok(SigKind::Ident(SigIdent { name, span }))
             }
+            // `@chan` names both the builtin that makes a channel and, with
+            // its element kind, the type of one.
+            TokenKind::Import(name) if name == "chan" => {
+                let args = if matches!(self.peek_token()?.kind, TokenKind::AngleOpen) {
+                    self.parse_type_arguments()?
+                } else {
+                    Vec::new()
+                };
+                let Ok([elem]) = <[SigKind; 1]>::try_from(args) else {
+                    return Err(Error::new(
+                        Code::Parse,
+                        "`@chan` takes one type argument, as in `@chan<int>`",
+                        self.span_from(span),
+                    ));
+                };
+                Ok(SigKind::Chan(Box::new(elem)))
+            }
             TokenKind::Import(name) => match builtins::get_spec(&name) {
                 Some(builtins::BuiltinSpec::Type(_)) => Ok(SigKind::Ident(SigIdent {
                     name: format!("@{name}"),
@@ -572,6 +1032,19 @@ impl<R: BufRead> Parser<R> {
                     span,
                 )),
             },
+            TokenKind::LBracket => {
+                let elem = self.parse_type_kind()?;
+                if self
+                    .consume_if(|kind| matches!(kind, TokenKind::Colon))?
+                    .is_some()
+                {
+                    let value = self.parse_type_kind()?;
+                    self.expect_token("]", |kind| matches!(kind, TokenKind::RBracket))?;
+                    return Ok(SigKind::Map(Box::new(elem), Box::new(value)));
+                }
+                self.expect_token("]", |kind| matches!(kind, TokenKind::RBracket))?;
+                Ok(SigKind::Array(Box::new(elem)))
+            }
             TokenKind::Ellipsis => Ok(SigKind::Variadic),
             _ => Err(Error::new(Code::Parse, "expected a type", span)),
         }
@@ -599,7 +1072,7 @@ impl<R: BufRead> Parser<R> {
         self.expect_token(")", |k| matches!(k, TokenKind::RParen))?;
         Ok(Signature {
             items: params,
-            span: lparen.span,
+            span: self.span_from(lparen.span),
             generics: BTreeSet::new(),
         })
     }
@@ -644,10 +1117,19 @@ impl<R: BufRead> Parser<R> {
     }
 
     fn bump(&mut self) -> Result<Token, Error> {
-        if let Some(token) = self.peeked.pop_front() {
-            return Ok(token);
+        let token = match self.peeked.pop_front() {
+            Some(token) => token,
+            None => self.lexer.next_token()?,
+        };
+        if !matches!(token.kind, TokenKind::Newline | TokenKind::Eof) {
+            self.prev_span = token.span;
         }
-        self.lexer.next_token()
+        Ok(token)
+    }
+
+    // Span from `start` through the last token consumed.
+    fn span_from(&self, start: Span) -> Span {
+        start.to(self.prev_span)
     }
 
     fn peek_token(&mut self) -> Result<&Token, Error> {
@@ -691,7 +1173,7 @@ impl<R: BufRead> Parser<R> {
 
         Ok(Block {
             items,
-            span: start_span,
+            span: self.span_from(start_span),
         })
     }
 
@@ -753,4 +1235,275 @@ mod tests {
             "unexpected error: {err}"
         );
     }
+
+    #[test]
+    fn synchronize_resumes_at_next_top_level_item() {
+        let source = "foo: (a int) {\n    bar(a)\n}\nbaz: (b: int) {\n    bar(b)\n}\n";
+        let mut parser = Parser::new(Lexer::new(Cursor::new(source)));
+        parser
+            .next_block_item()
+            .expect_err("missing colon must fail");
+        parser.synchronize();
+        let item = parser
+            .next_block_item()
+            .expect("parser should resume after the broken item")
+            .expect("second item should be parsed");
+        assert!(
+            matches!(&item, BlockItem::FunctionDef { name, .. } if name == "baz"),
+            "unexpected item: {item:?}"
+        );
+        assert!(parser.next_block_item().expect("eof").is_none());
+    }
+
+    #[test]
+    fn source_imports_must_lead_the_file() {
+        let source = "@\"io.rgo\"\nexit: @exit\n@\"late.rgo\"\n";
+        let mut parser = Parser::new(Lexer::new(Cursor::new(source)));
+        let item = parser.next_block_item().expect("import").expect("item");
+        assert!(
+            matches!(&item, BlockItem::SourceImport { path, .. } if path == "io.rgo"),
+            "unexpected item: {item:?}"
+        );
+        parser.next_block_item().expect("alias").expect("item");
+        let err = parser
+            .next_block_item()
+            .expect_err("late source import must fail");
+        assert_eq!(
+            err.message,
+            "source imports must come before any other item in the file"
+        );
+    }
+
+    #[test]
+    fn parse_array_types_and_literals() {
+        let source =
+            "foo: (xs: [[int]], ok: ([str])) {\n    ok([\"a\",\n \"b\"], [], [[1], [2, 3]])\n}\n";
+        let mut parser = Parser::new(Lexer::new(Cursor::new(source)));
+        let item = parser.next_block_item().expect("function").expect("item");
+        let BlockItem::FunctionDef { lambda, .. } = item else {
+            panic!("unexpected item: {item:?}");
+        };
+        let SigKind::Array(outer) = &lambda.params.items[0].kind else {
+            panic!("unexpected kind: {:?}", lambda.params.items[0].kind);
+        };
+        assert!(
+            matches!(outer.as_ref(), SigKind::Array(inner) if matches!(inner.as_ref(), SigKind::Ident(ident) if ident.name == "int")),
+            "unexpected kind: {outer:?}"
+        );
+        let BlockItem::Ident(call) = &lambda.body.items[0] else {
+            panic!("unexpected body: {:?}", lambda.body.items);
+        };
+        let lens: Vec<usize> = call
+            .args
+            .iter()
+            .map(|arg| match &arg.term {
+                Term::Array(array) => array.items.len(),
+                term => panic!("unexpected term: {term:?}"),
+            })
+            .collect();
+        assert_eq!(lens, vec![2, 0, 2]);
+    }
+
+    #[test]
+    fn parse_map_types_and_literals() {
+        let source = "foo: (ages: [str: int], ok: ([int: str])) {\n    ok([\"a\": 1,\n \"b\": 2,], [:], [1: \"x\"])\n}\n";
+        let mut parser = Parser::new(Lexer::new(Cursor::new(source)));
+        let item = parser.next_block_item().expect("function").expect("item");
+        let BlockItem::FunctionDef { lambda, .. } = item else {
+            panic!("unexpected item: {item:?}");
+        };
+        let SigKind::Map(key, value) = &lambda.params.items[0].kind else {
+            panic!("unexpected kind: {:?}", lambda.params.items[0].kind);
+        };
+        assert!(
+            matches!(key.as_ref(), SigKind::Ident(ident) if ident.name == "str")
+                && matches!(value.as_ref(), SigKind::Ident(ident) if ident.name == "int"),
+            "unexpected kinds: {key:?} {value:?}"
+        );
+        let BlockItem::Ident(call) = &lambda.body.items[0] else {
+            panic!("unexpected body: {:?}", lambda.body.items);
+        };
+        let lens: Vec<usize> = call
+            .args
+            .iter()
+            .map(|arg| match &arg.term {
+                Term::Map(map) => map.entries.len(),
+                term => panic!("unexpected term: {term:?}"),
+            })
+            .collect();
+        assert_eq!(lens, vec![2, 0, 1]);
+    }
+
+    #[test]
+    fn parse_record_definitions_and_field_access() {
+        let source =
+            "point: {\n    x: int,\n    y: int\n}\nfoo: (p: point) {\n    exit(p.x, p.home.y)\n}\n";
+        let mut parser = Parser::new(Lexer::new(Cursor::new(source)));
+        let item = parser.next_block_item().expect("record").expect("item");
+        let BlockItem::RecordDef { name, fields, .. } = item else {
+            panic!("unexpected item: {item:?}");
+        };
+        assert_eq!(name, "point");
+        let names: Vec<&str> = fields.items.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, vec!["x", "y"]);
+
+        let item = parser.next_block_item().expect("function").expect("item");
+        let BlockItem::FunctionDef { lambda, .. } = item else {
+            panic!("unexpected item: {item:?}");
+        };
+        let BlockItem::Ident(call) = &lambda.body.items[0] else {
+            panic!("unexpected body: {:?}", lambda.body.items);
+        };
+        let Term::Field(nested) = &call.args[1].term else {
+            panic!("unexpected term: {:?}", call.args[1].term);
+        };
+        assert_eq!(nested.field, "y");
+        assert!(
+            matches!(nested.record.as_ref(), Term::Field(inner) if inner.field == "home"),
+            "unexpected record: {:?}",
+            nested.record
+        );
+    }
+
+    #[test]
+    fn parse_rejects_duplicate_record_fields() {
+        let mut parser = Parser::new(Lexer::new(Cursor::new("point: {x: int, x: int}")));
+        let err = parser
+            .next_block_item()
+            .expect_err("duplicate fields must fail");
+        assert_eq!(err.message, "field `x` is declared twice");
+    }
+
+    #[test]
+    fn parse_sum_definitions_and_cases() {
+        let source = "shape: {circle(r: int), rect(w: int, h: int)\n    empty}\nfoo: (s: shape) {\n    s {\n        circle(r: int) { exit(r) }\n        empty { exit(0) }\n    }\n}\n";
+        let mut parser = Parser::new(Lexer::new(Cursor::new(source)));
+        let item = parser.next_block_item().expect("sum").expect("item");
+        let BlockItem::SumDef { name, variants, .. } = item else {
+            panic!("unexpected item: {item:?}");
+        };
+        assert_eq!(name, "shape");
+        let payloads: Vec<(&str, usize)> = variants
+            .items
+            .iter()
+            .map(|variant| match &variant.kind {
+                SigKind::Sig(payload) => (variant.name.as_str(), payload.items.len()),
+                kind => panic!("unexpected kind: {kind:?}"),
+            })
+            .collect();
+        assert_eq!(payloads, vec![("circle", 1), ("rect", 2), ("empty", 0)]);
+
+        let item = parser.next_block_item().expect("function").expect("item");
+        let BlockItem::FunctionDef { lambda, .. } = item else {
+            panic!("unexpected item: {item:?}");
+        };
+        let BlockItem::Case(case) = &lambda.body.items[0] else {
+            panic!("unexpected body: {:?}", lambda.body.items);
+        };
+        assert!(matches!(&case.value, Term::Ident(ident) if ident.name == "s"));
+        let arms: Vec<(&str, usize)> = case
+            .arms
+            .iter()
+            .map(|arm| (arm.variant.as_str(), arm.lambda.params.items.len()))
+            .collect();
+        assert_eq!(arms, vec![("circle", 1), ("empty", 0)]);
+    }
+
+    #[test]
+    fn parse_rejects_duplicate_variants() {
+        let mut parser = Parser::new(Lexer::new(Cursor::new("shape: {empty, circle, empty}")));
+        let err = parser
+            .next_block_item()
+            .expect_err("duplicate variants must fail");
+        assert_eq!(err.message, "variant `empty` is declared twice");
+    }
+
+    #[test]
+    fn parse_function_declarations() {
+        let source = "is_odd: <T>(n: T, yes: (), no: ()) ...\nodd: (n: int)\n";
+        let mut parser = Parser::new(Lexer::new(Cursor::new(source)));
+        let item = parser.next_block_item().expect("decl").expect("item");
+        let BlockItem::FunctionDecl { name, params, .. } = item else {
+            panic!("unexpected item: {item:?}");
+        };
+        assert_eq!(name, "is_odd");
+        let names: Vec<&str> = params.items.iter().map(|item| item.name.as_str()).collect();
+        assert_eq!(names, vec!["n", "yes", "no"]);
+        assert!(params.generics.contains("T"));
+
+        let item = parser.next_block_item().expect("sig").expect("item");
+        assert!(
+            matches!(item, BlockItem::SigDef { .. }),
+            "unexpected item: {item:?}"
+        );
+    }
+
+    #[test]
+    fn parse_refined_parameters() {
+        let source = "digit: (d: int(ge: 0, lt: 10), name: str(ne: \"\"), ok: ())\n";
+        let mut parser = Parser::new(Lexer::new(Cursor::new(source)));
+        let item = parser.next_block_item().expect("sig").expect("item");
+        let BlockItem::SigDef { sig, .. } = item else {
+            panic!("unexpected item: {item:?}");
+        };
+        let SigKind::Refined(base, bounds) = &sig.items[0].kind else {
+            panic!("unexpected kind: {:?}", sig.items[0].kind);
+        };
+        assert!(matches!(base.as_ref(), SigKind::Ident(ident) if ident.name == "int"));
+        assert_eq!(
+            bounds,
+            &vec![
+                Bound {
+                    comparison: Comparison::Ge,
+                    value: BoundValue::Int(0),
+                },
+                Bound {
+                    comparison: Comparison::Lt,
+                    value: BoundValue::Int(10),
+                },
+            ]
+        );
+        assert!(
+            matches!(&sig.items[1].kind, SigKind::Refined(base, _) if matches!(base.as_ref(), SigKind::Ident(ident) if ident.name == "str"))
+        );
+
+        let mut parser = Parser::new(Lexer::new(Cursor::new("f: (x: int(nz: 0))")));
+        let err = parser
+            .next_block_item()
+            .expect_err("unknown comparison must fail");
+        assert_eq!(
+            err.message,
+            "`nz` is not a comparison; use eq, ne, lt, le, gt or ge"
+        );
+    }
+
+    #[test]
+    fn parse_channel_types() {
+        let source = "pipe: (jobs: @chan<int>, ok: (@chan<[str]>))
+";
+        let mut parser = Parser::new(Lexer::new(Cursor::new(source)));
+        let item = parser.next_block_item().expect("sig").expect("item");
+        let BlockItem::SigDef { sig, .. } = item else {
+            panic!("unexpected item: {item:?}");
+        };
+        let SigKind::Chan(elem) = &sig.items[0].kind else {
+            panic!("unexpected kind: {:?}", sig.items[0].kind);
+        };
+        assert!(matches!(elem.as_ref(), SigKind::Ident(ident) if ident.name == "int"));
+        let SigKind::Sig(ok) = &sig.items[1].kind else {
+            panic!("unexpected kind: {:?}", sig.items[1].kind);
+        };
+        assert!(
+            matches!(&ok.items[0].kind, SigKind::Chan(elem) if matches!(elem.as_ref(), SigKind::Array(_)))
+        );
+
+        let mut parser = Parser::new(Lexer::new(Cursor::new("f: (c: @chan)")));
+        let err = parser
+            .next_block_item()
+            .expect_err("a channel without an element kind must fail");
+        assert_eq!(
+            err.message,
+            "`@chan` takes one type argument, as in `@chan<int>`"
+        );
+    }
 }
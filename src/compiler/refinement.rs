@@ -0,0 +1,330 @@
+//! Refinements narrow an `int` or `str` parameter to the values that pass a
+//! list of comparisons, as in `y: int(ne: 0)` or `name: str(ne: "")`. They
+//! only exist while HIR is checked; `erase_item` drops them before AIR.
+
+use crate::compiler::builtins::Builtin;
+use crate::compiler::hir::{BlockItem, SigItem, SigKind, Signature};
+use std::collections::BTreeSet;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Comparison {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl Comparison {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "eq" => Some(Comparison::Eq),
+            "ne" => Some(Comparison::Ne),
+            "lt" => Some(Comparison::Lt),
+            "le" => Some(Comparison::Le),
+            "gt" => Some(Comparison::Gt),
+            "ge" => Some(Comparison::Ge),
+            _ => None,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Comparison::Eq => "eq",
+            Comparison::Ne => "ne",
+            Comparison::Lt => "lt",
+            Comparison::Le => "le",
+            Comparison::Gt => "gt",
+            Comparison::Ge => "ge",
+        }
+    }
+
+    /// The comparison that holds exactly when this one does not.
+    pub fn negate(self) -> Self {
+        match self {
+            Comparison::Eq => Comparison::Ne,
+            Comparison::Ne => Comparison::Eq,
+            Comparison::Lt => Comparison::Ge,
+            Comparison::Le => Comparison::Gt,
+            Comparison::Gt => Comparison::Le,
+            Comparison::Ge => Comparison::Lt,
+        }
+    }
+
+    /// The comparison with its operands swapped: `1 < x` is `x > 1`.
+    pub fn flip(self) -> Self {
+        match self {
+            Comparison::Lt => Comparison::Gt,
+            Comparison::Le => Comparison::Ge,
+            Comparison::Gt => Comparison::Lt,
+            Comparison::Ge => Comparison::Le,
+            other => other,
+        }
+    }
+
+    /// The comparison a builtin takes its first continuation for.
+    pub fn of_builtin(builtin: Builtin) -> Option<Self> {
+        match builtin {
+            Builtin::Eq | Builtin::Eqi | Builtin::Eqs => Some(Comparison::Eq),
+            Builtin::Ne => Some(Comparison::Ne),
+            Builtin::Lt => Some(Comparison::Lt),
+            Builtin::Le => Some(Comparison::Le),
+            Builtin::Gt => Some(Comparison::Gt),
+            Builtin::Ge => Some(Comparison::Ge),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum BoundValue {
+    Int(i64),
+    Str(String),
+}
+
+impl fmt::Display for BoundValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BoundValue::Int(value) => write!(f, "{value}"),
+            BoundValue::Str(value) => write!(f, "{value:?}"),
+        }
+    }
+}
+
+/// One comparison a refined value passes, with the value on its right.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Bound {
+    pub comparison: Comparison,
+    pub value: BoundValue,
+}
+
+impl fmt::Display for Bound {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.comparison.name(), self.value)
+    }
+}
+
+pub fn format_bounds(bounds: &[Bound]) -> String {
+    bounds
+        .iter()
+        .map(Bound::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Why `bounds` cannot refine a value of kind `base`, if they cannot. A str
+/// is only compared with `""`, which is all its comparisons can prove.
+pub fn check_bounds(base: &SigKind, bounds: &[Bound]) -> Result<(), String> {
+    let is_str = match base {
+        SigKind::Int => false,
+        SigKind::Str => true,
+        _ => return Err("only int and str parameters can be refined".to_string()),
+    };
+    for bound in bounds {
+        match (&bound.value, is_str) {
+            (BoundValue::Int(_), false) => {}
+            (BoundValue::Str(value), true) if value.is_empty() => {}
+            (BoundValue::Str(_), true) => {
+                return Err(format!(
+                    "`{bound}` compares a str with something other than \"\""
+                ));
+            }
+            (BoundValue::Int(_), true) => {
+                return Err(format!("`{bound}` compares a str with an int"));
+            }
+            (BoundValue::Str(_), false) => {
+                return Err(format!("`{bound}` compares an int with a str"));
+            }
+        }
+    }
+    if Interval::of(bounds, is_str).is_empty() {
+        return Err(format!("`{}` allows no value", format_bounds(bounds)));
+    }
+    Ok(())
+}
+
+/// Whether every value that passes `actual` also passes `expected`.
+pub fn implies(actual: &[Bound], expected: &[Bound], is_str: bool) -> bool {
+    Interval::of(actual, is_str).within(&Interval::of(expected, is_str))
+}
+
+/// Whether the literal `value` passes `bounds`.
+pub fn allows(bounds: &[Bound], value: &BoundValue) -> bool {
+    let (point, is_str) = match value {
+        BoundValue::Int(value) => (*value as i128, false),
+        BoundValue::Str(value) => (value.len() as i128, true),
+    };
+    Interval::of(bounds, is_str).contains(point)
+}
+
+/// The values a list of bounds allows: those between `min` and `max` other
+/// than the ones in `excluded`. A str stands for its length, so `ne: ""`
+/// becomes a length of at least one.
+struct Interval {
+    min: i128,
+    max: i128,
+    excluded: BTreeSet<i128>,
+}
+
+impl Interval {
+    fn of(bounds: &[Bound], is_str: bool) -> Interval {
+        let mut interval = Interval {
+            min: if is_str { 0 } else { i64::MIN as i128 },
+            max: i64::MAX as i128,
+            excluded: BTreeSet::new(),
+        };
+        for bound in bounds {
+            let value = match &bound.value {
+                BoundValue::Int(value) => *value as i128,
+                BoundValue::Str(value) => value.len() as i128,
+            };
+            match bound.comparison {
+                Comparison::Eq => {
+                    interval.min = interval.min.max(value);
+                    interval.max = interval.max.min(value);
+                }
+                Comparison::Ne => {
+                    interval.excluded.insert(value);
+                }
+                Comparison::Lt => interval.max = interval.max.min(value - 1),
+                Comparison::Le => interval.max = interval.max.min(value),
+                Comparison::Gt => interval.min = interval.min.max(value + 1),
+                Comparison::Ge => interval.min = interval.min.max(value),
+            }
+        }
+        while interval.min <= interval.max && interval.excluded.contains(&interval.min) {
+            interval.min += 1;
+        }
+        while interval.min <= interval.max && interval.excluded.contains(&interval.max) {
+            interval.max -= 1;
+        }
+        let (min, max) = (interval.min, interval.max);
+        interval
+            .excluded
+            .retain(|value| (min..=max).contains(value));
+        interval
+    }
+
+    fn is_empty(&self) -> bool {
+        self.min > self.max
+    }
+
+    fn contains(&self, value: i128) -> bool {
+        (self.min..=self.max).contains(&value) && !self.excluded.contains(&value)
+    }
+
+    fn within(&self, other: &Interval) -> bool {
+        if self.is_empty() {
+            return true;
+        }
+        self.min >= other.min
+            && self.max <= other.max
+            && other.excluded.iter().all(|value| !self.contains(*value))
+    }
+}
+
+/// `kind` with every refinement replaced by the kind it refines.
+pub fn erase_kind(kind: &SigKind) -> SigKind {
+    match kind {
+        SigKind::Refined(base, _) => erase_kind(base),
+        SigKind::Sig(signature) => SigKind::Sig(erase_signature(signature)),
+        SigKind::Array(elem) => SigKind::Array(Box::new(erase_kind(elem))),
+        SigKind::Map(key, value) => {
+            SigKind::Map(Box::new(erase_kind(key)), Box::new(erase_kind(value)))
+        }
+        SigKind::Chan(elem) => SigKind::Chan(Box::new(erase_kind(elem))),
+        SigKind::GenericInst { name, args, span } => SigKind::GenericInst {
+            name: name.clone(),
+            span: *span,
+            args: args.iter().map(erase_kind).collect(),
+        },
+        other => other.clone(),
+    }
+}
+
+pub fn erase_signature(signature: &Signature) -> Signature {
+    Signature {
+        items: signature
+            .items
+            .iter()
+            .map(|item| SigItem {
+                name: item.name.clone(),
+                kind: erase_kind(&item.kind),
+                has_bang: item.has_bang,
+            })
+            .collect(),
+        generics: signature.generics.clone(),
+    }
+}
+
+/// `item` as AIR sees it, with its refinements erased.
+pub fn erase_item(item: BlockItem) -> BlockItem {
+    match item {
+        BlockItem::FunctionDef(mut function) => {
+            function.sig = erase_signature(&function.sig);
+            function.body.items = function.body.items.into_iter().map(erase_item).collect();
+            BlockItem::FunctionDef(function)
+        }
+        BlockItem::SigDef { name, sig } => BlockItem::SigDef {
+            name,
+            sig: erase_signature(&sig),
+        },
+        BlockItem::ArrayDef(mut array) => {
+            array.elem = erase_kind(&array.elem);
+            BlockItem::ArrayDef(array)
+        }
+        BlockItem::MapDef(mut map) => {
+            map.key = erase_kind(&map.key);
+            map.value = erase_kind(&map.value);
+            BlockItem::MapDef(map)
+        }
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bound(comparison: Comparison, value: i64) -> Bound {
+        Bound {
+            comparison,
+            value: BoundValue::Int(value),
+        }
+    }
+
+    #[test]
+    fn narrower_bounds_imply_wider_ones() {
+        let digit = [bound(Comparison::Ge, 0), bound(Comparison::Lt, 10)];
+        let non_negative = [bound(Comparison::Ge, 0)];
+        let non_zero = [bound(Comparison::Ne, 0)];
+        assert!(implies(&digit, &non_negative, false));
+        assert!(!implies(&non_negative, &digit, false));
+        assert!(!implies(&digit, &non_zero, false));
+        assert!(implies(&[bound(Comparison::Gt, 0)], &non_zero, false));
+        assert!(implies(&[bound(Comparison::Lt, 0)], &non_zero, false));
+    }
+
+    #[test]
+    fn empty_str_bound_is_a_length() {
+        let non_empty = [Bound {
+            comparison: Comparison::Ne,
+            value: BoundValue::Str(String::new()),
+        }];
+        assert!(allows(&non_empty, &BoundValue::Str("a".to_string())));
+        assert!(!allows(&non_empty, &BoundValue::Str(String::new())));
+        assert!(check_bounds(&SigKind::Str, &non_empty).is_ok());
+        assert!(check_bounds(&SigKind::Int, &non_empty).is_err());
+    }
+
+    #[test]
+    fn contradicting_bounds_allow_no_value() {
+        let bounds = [bound(Comparison::Gt, 0), bound(Comparison::Lt, 1)];
+        assert_eq!(
+            check_bounds(&SigKind::Int, &bounds),
+            Err("`gt: 0, lt: 1` allows no value".to_string())
+        );
+    }
+}
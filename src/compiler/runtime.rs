@@ -2,8 +2,19 @@ use std::io::Write;
 
 use crate::compiler::air;
 use crate::compiler::codegen::{
+    map_block_size, map_index_offset, ARRAY_CLASS_ARRAY, ARRAY_CLASS_OFFSET, ARRAY_CLASS_STR,
+    ARRAY_HEADER_SIZE, ARRAY_LEN_OFFSET, CHAN_CLASS_ENV, CHAN_CLASS_OFFSET, CHAN_RECEIVERS_OFFSET,
+    CHAN_REFS_OFFSET, CHAN_SIZE, CHAN_VALUES_OFFSET, DEADLOCK_MESSAGE,
     ENV_METADATA_DEEP_COPY_OFFSET, ENV_METADATA_ENV_SIZE_OFFSET, ENV_METADATA_HEAP_SIZE_OFFSET,
-    MAP_ANONYMOUS, MAP_PRIVATE, PROT_READ, PROT_WRITE, SYSCALL_MMAP, SYSCALL_MUNMAP,
+    ENV_METADATA_RELEASE_OFFSET, ENV_METADATA_UNWRAPPER_OFFSET, FNV_OFFSET_BASIS, FNV_PRIME,
+    FUTEX_WAIT_PRIVATE, FUTEX_WAKE_PRIVATE, HEAP_ARENA_LABEL, HEAP_ARENA_SIZE,
+    HEAP_FREE_LISTS_LABEL, HEAP_LOCK_LABEL, HEAP_MAX_SMALL_SIZE, LOCK_SPINS, MAP_ANONYMOUS,
+    MAP_CAPACITY_OFFSET, MAP_COUNT_OFFSET, MAP_ENTRY_SIZE, MAP_HEADER_SIZE, MAP_INITIAL_CAPACITY,
+    MAP_INT_HASH_MULTIPLIER, MAP_KEY_CLASS_OFFSET, MAP_PRIVATE, MAP_VALUE_CLASS_OFFSET,
+    PROCESS_ARGS_LABEL, PROT_READ, PROT_WRITE, QUEUE_NODE_SIZE, SCHED_IDLE_OFFSET,
+    SCHED_LOCK_LABEL, SCHED_STATE_LABEL, SCHED_WAITING_OFFSET, SCHED_WAKEUPS_OFFSET,
+    SCHED_WORKERS_OFFSET, STR_ALLOC_SIZE_OFFSET, STR_HEADER_SIZE, STR_LEN_OFFSET, SYSCALL_FUTEX,
+    SYSCALL_MMAP, SYSCALL_MUNMAP, SYSCALL_SCHED_YIELD, SYSCALL_WRITE, WORD_SIZE,
 };
 use crate::compiler::error;
 
@@ -50,11 +61,1620 @@ pub fn emit_release_heap_ptr<W: Write>(out: &mut W) -> Result<(), error::Error>
     )?;
     writeln!(out, "    mov rdi, rbx")?;
     writeln!(out, "    sub rdi, rcx ; compute env base pointer")?;
-    writeln!(out, "    mov rsi, rdx ; heap size for munmap")?;
+    writeln!(out, "    mov rsi, rdx ; heap size to free")?;
+    writeln!(out, "    call free_heap_ptr ; return env to the allocator")?;
+    writeln!(out, "    pop rbx")?;
+    writeln!(out, "    pop rbp")?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+/// Emits `alloc_heap_ptr`, which returns in rax a block of at least rdi bytes,
+/// or 0 when the kernel will not map one, and the allocator state it shares
+/// with `free_heap_ptr`. Only rax, rcx, rdx, rsi, rdi and r8 to r11 are
+/// clobbered. A `threaded` allocator lets one
+/// thread in at a time.
+pub fn emit_alloc_heap_ptr<W: Write>(out: &mut W, threaded: bool) -> Result<(), error::Error> {
+    writeln!(out, "global alloc_heap_ptr")?;
+    writeln!(out, "alloc_heap_ptr:")?;
+    if threaded {
+        emit_locked_call(out, HEAP_LOCK_LABEL, "alloc_heap_ptr")?;
+    }
+    writeln!(out, "    add rdi, 7 ; round size up to whole words")?;
+    writeln!(out, "    and rdi, -8")?;
+    writeln!(out, "    cmp rdi, {} ; small block?", HEAP_MAX_SMALL_SIZE)?;
+    writeln!(out, "    ja alloc_heap_ptr_large")?;
+    writeln!(
+        out,
+        "    lea rcx, [{}] ; free list heads by size",
+        HEAP_FREE_LISTS_LABEL
+    )?;
+    writeln!(
+        out,
+        "    mov rax, [rcx+rdi] ; head of this size's free list"
+    )?;
+    writeln!(out, "    test rax, rax")?;
+    writeln!(out, "    jz alloc_heap_ptr_bump")?;
+    writeln!(out, "    mov rdx, [rax] ; next free block")?;
+    writeln!(out, "    mov [rcx+rdi], rdx ; pop the head")?;
+    writeln!(out, "    ret")?;
+    writeln!(out, "alloc_heap_ptr_bump:")?;
+    writeln!(
+        out,
+        "    lea rcx, [{}] ; arena next and end pointers",
+        HEAP_ARENA_LABEL
+    )?;
+    writeln!(out, "    mov rax, [rcx] ; next free byte of the arena")?;
+    writeln!(out, "    mov rdx, rax")?;
+    writeln!(out, "    add rdx, rdi ; end of the new block")?;
+    writeln!(out, "    mov r8, [rcx+8] ; end of the arena")?;
+    writeln!(out, "    cmp rdx, r8")?;
+    writeln!(out, "    ja alloc_heap_ptr_refill")?;
+    writeln!(out, "    mov [rcx], rdx ; bump past the block")?;
+    writeln!(out, "    ret")?;
+    writeln!(out, "alloc_heap_ptr_refill:")?;
+    writeln!(out, "    push rdi ; keep block size across the syscall")?;
+    writeln!(out, "    mov rsi, {} ; arena size", HEAP_ARENA_SIZE)?;
+    emit_anonymous_mmap(out, "map a new arena")?;
+    writeln!(out, "    pop rdi")?;
+    writeln!(out, "    cmp rax, -4095 ; mmap failed?")?;
+    writeln!(out, "    jae alloc_heap_ptr_failed")?;
+    writeln!(
+        out,
+        "    lea rcx, [{}] ; arena next and end pointers",
+        HEAP_ARENA_LABEL
+    )?;
+    writeln!(
+        out,
+        "    lea rdx, [rax+rdi] ; block is the start of the arena"
+    )?;
+    writeln!(out, "    mov [rcx], rdx")?;
+    writeln!(out, "    mov rdx, rax")?;
+    writeln!(out, "    add rdx, {} ; end of the arena", HEAP_ARENA_SIZE)?;
+    writeln!(out, "    mov [rcx+8], rdx")?;
+    writeln!(out, "    ret")?;
+    writeln!(out, "alloc_heap_ptr_large:")?;
+    writeln!(out, "    mov rsi, rdi ; length = block size")?;
+    emit_anonymous_mmap(out, "map a large block on its own")?;
+    writeln!(out, "    cmp rax, -4095 ; mmap failed?")?;
+    writeln!(out, "    jae alloc_heap_ptr_failed")?;
+    writeln!(out, "    ret")?;
+    writeln!(out, "alloc_heap_ptr_failed:")?;
+    writeln!(out, "    xor eax, eax ; no memory left")?;
+    writeln!(out, "    ret")?;
+    writeln!(out, "section .data")?;
+    writeln!(out, "{}:", HEAP_FREE_LISTS_LABEL)?;
+    writeln!(out, "    times {} dq 0", HEAP_MAX_SMALL_SIZE / 8 + 1)?;
+    writeln!(out, "{}:", HEAP_ARENA_LABEL)?;
+    writeln!(out, "    dq 0, 0")?;
+    if threaded {
+        writeln!(out, "{}:", HEAP_LOCK_LABEL)?;
+        writeln!(out, "    dq 0")?;
+    }
+    writeln!(out, "section .text")?;
+    Ok(())
+}
+
+/// Emits the entry of the helper `name`, which takes `lock`, calls the body
+/// at `name_locked` that follows and lets go of the lock again. rax, rcx and
+/// r11 are clobbered before the body runs.
+fn emit_locked_call<W: Write>(out: &mut W, lock: &str, name: &str) -> Result<(), error::Error> {
+    emit_lock(out, lock, &format!("{}_lock", name))?;
+    writeln!(out, "    call {}_locked", name)?;
+    emit_unlock(out, lock)?;
+    writeln!(out, "    ret")?;
+    writeln!(out, "{}_locked:", name)?;
+    Ok(())
+}
+
+/// Spins at the label `spin` until this thread holds the lock word at
+/// `lock`, yielding the CPU now and then so a holder that lost it can finish.
+/// Only rax, rcx and r11 are clobbered.
+fn emit_lock<W: Write>(out: &mut W, lock: &str, spin: &str) -> Result<(), error::Error> {
+    writeln!(out, "{}:", spin)?;
+    writeln!(out, "    mov rax, 1")?;
+    writeln!(out, "    xchg rax, [{}] ; try to take the lock", lock)?;
+    writeln!(out, "    test rax, rax")?;
+    writeln!(out, "    jz {}_held", spin)?;
+    writeln!(out, "{}_busy:", spin)?;
+    writeln!(out, "    mov rax, {} ; checks before yielding", LOCK_SPINS)?;
+    writeln!(out, "{}_wait:", spin)?;
+    writeln!(out, "    pause ; another thread holds it")?;
+    writeln!(out, "    cmp qword [{}], 0", lock)?;
+    writeln!(out, "    je {}", spin)?;
+    writeln!(out, "    dec rax")?;
+    writeln!(out, "    jnz {}_wait", spin)?;
+    writeln!(
+        out,
+        "    mov rax, {} ; sched_yield syscall",
+        SYSCALL_SCHED_YIELD
+    )?;
+    writeln!(out, "    syscall ; let the holder run")?;
+    writeln!(out, "    jmp {}_busy", spin)?;
+    writeln!(out, "{}_held:", spin)?;
+    Ok(())
+}
+
+fn emit_unlock<W: Write>(out: &mut W, lock: &str) -> Result<(), error::Error> {
+    writeln!(out, "    mov qword [{}], 0 ; let other threads in", lock)?;
+    Ok(())
+}
+
+/// Maps rsi bytes of anonymous read/write memory into rax.
+fn emit_anonymous_mmap<W: Write>(out: &mut W, comment: &str) -> Result<(), error::Error> {
+    writeln!(
+        out,
+        "    xor rdi, rdi ; addr hint so kernel picks mmap base"
+    )?;
+    writeln!(
+        out,
+        "    mov rdx, {} ; prot = read/write",
+        PROT_READ | PROT_WRITE
+    )?;
+    writeln!(
+        out,
+        "    mov r10, {} ; flags = private & anonymous",
+        MAP_PRIVATE | MAP_ANONYMOUS
+    )?;
+    writeln!(out, "    mov r8, -1 ; fd = -1")?;
+    writeln!(out, "    xor r9, r9 ; offset = 0")?;
+    writeln!(out, "    mov rax, {} ; mmap syscall", SYSCALL_MMAP)?;
+    writeln!(out, "    syscall ; {}", comment)?;
+    Ok(())
+}
+
+/// Emits `free_heap_ptr`, which takes back the block at rdi that was
+/// allocated with rsi bytes.
+pub fn emit_free_heap_ptr<W: Write>(out: &mut W, threaded: bool) -> Result<(), error::Error> {
+    writeln!(out, "global free_heap_ptr")?;
+    writeln!(out, "free_heap_ptr:")?;
+    if threaded {
+        emit_locked_call(out, HEAP_LOCK_LABEL, "free_heap_ptr")?;
+    }
+    writeln!(out, "    add rsi, 7 ; round size up to whole words")?;
+    writeln!(out, "    and rsi, -8")?;
+    writeln!(out, "    cmp rsi, {} ; small block?", HEAP_MAX_SMALL_SIZE)?;
+    writeln!(out, "    ja free_heap_ptr_large")?;
+    writeln!(
+        out,
+        "    lea rcx, [{}] ; free list heads by size",
+        HEAP_FREE_LISTS_LABEL
+    )?;
+    writeln!(out, "    mov rax, [rcx+rsi] ; current head")?;
+    writeln!(out, "    mov [rdi], rax ; link it behind the block")?;
+    writeln!(out, "    mov [rcx+rsi], rdi ; block is the new head")?;
+    writeln!(out, "    ret")?;
+    writeln!(out, "free_heap_ptr_large:")?;
     writeln!(out, "    mov rax, {} ; munmap syscall", SYSCALL_MUNMAP)?;
     writeln!(out, "    syscall")?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+/// Emits `release_str_ptr`, which frees the string at rdi. Its header holds
+/// the allocation size, or 0 for literals, which are left alone.
+pub fn emit_release_str_ptr<W: Write>(out: &mut W) -> Result<(), error::Error> {
+    writeln!(out, "global release_str_ptr")?;
+    writeln!(out, "release_str_ptr:")?;
+    writeln!(out, "    test rdi, rdi ; nothing to free")?;
+    writeln!(out, "    jz release_str_ptr_done")?;
+    writeln!(
+        out,
+        "    mov rsi, [rdi-{}] ; allocation size header",
+        STR_ALLOC_SIZE_OFFSET
+    )?;
+    writeln!(out, "    test rsi, rsi ; literals have no allocation")?;
+    writeln!(out, "    jz release_str_ptr_done")?;
+    writeln!(
+        out,
+        "    sub rdi, {} ; block starts at the header",
+        STR_HEADER_SIZE
+    )?;
+    writeln!(
+        out,
+        "    jmp free_heap_ptr ; return string to the allocator"
+    )?;
+    writeln!(out, "release_str_ptr_done:")?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+/// Emits `copy_str_ptr`, which returns in rax a string owned separately from
+/// the one at rdi. Literals are shared rather than copied.
+pub fn emit_copy_str_ptr<W: Write>(out: &mut W) -> Result<(), error::Error> {
+    writeln!(out, "global copy_str_ptr")?;
+    writeln!(out, "copy_str_ptr:")?;
+    writeln!(
+        out,
+        "    mov rax, rdi ; share the pointer unless it owns memory"
+    )?;
+    writeln!(out, "    test rdi, rdi")?;
+    writeln!(out, "    jz copy_str_ptr_done")?;
+    writeln!(
+        out,
+        "    mov rsi, [rdi-{}] ; allocation size header",
+        STR_ALLOC_SIZE_OFFSET
+    )?;
+    writeln!(out, "    test rsi, rsi ; literals are never freed")?;
+    writeln!(out, "    jz copy_str_ptr_done")?;
+    writeln!(out, "    push rbx ; preserve callee-saved registers")?;
+    writeln!(out, "    push r13")?;
+    writeln!(out, "    push r14")?;
+    writeln!(out, "    mov rbx, rdi ; source string")?;
+    writeln!(out, "    mov r13, rsi ; allocation size")?;
+    writeln!(out, "    mov rdi, rsi")?;
+    writeln!(out, "    call alloc_heap_ptr ; allocate the copy")?;
+    writeln!(out, "    mov r14, rax ; copy block")?;
+    writeln!(out, "    mov rdi, rax ; destination block")?;
+    writeln!(
+        out,
+        "    lea rsi, [rbx-{}] ; source block, header included",
+        STR_HEADER_SIZE
+    )?;
+    writeln!(out, "    mov rcx, r13 ; bytes to copy")?;
+    writeln!(out, "    cld ; ensure forward copy")?;
+    writeln!(out, "    rep movsb ; duplicate header and bytes")?;
+    writeln!(
+        out,
+        "    lea rax, [r14+{}] ; copied string starts after its header",
+        STR_HEADER_SIZE
+    )?;
+    writeln!(out, "    pop r14")?;
+    writeln!(out, "    pop r13")?;
+    writeln!(out, "    pop rbx")?;
+    writeln!(out, "copy_str_ptr_done:")?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+/// Emits `release_array_ptr`, which frees the array at rdi along with the
+/// strings and arrays it holds.
+pub fn emit_release_array_ptr<W: Write>(out: &mut W) -> Result<(), error::Error> {
+    writeln!(out, "global release_array_ptr")?;
+    writeln!(out, "release_array_ptr:")?;
+    writeln!(out, "    test rdi, rdi ; nothing to free")?;
+    writeln!(out, "    jz release_array_ptr_done")?;
+    writeln!(out, "    push rbx ; preserve callee-saved registers")?;
+    writeln!(out, "    push r13")?;
+    writeln!(out, "    push r14")?;
+    writeln!(out, "    mov rbx, rdi ; array")?;
+    writeln!(
+        out,
+        "    mov r13, [rbx-{}] ; elements left to release",
+        ARRAY_LEN_OFFSET
+    )?;
+    writeln!(
+        out,
+        "    mov r14, [rbx-{}] ; element class",
+        ARRAY_CLASS_OFFSET
+    )?;
+    writeln!(out, "    test r14, r14 ; plain elements own nothing")?;
+    writeln!(out, "    jz release_array_ptr_free")?;
+    writeln!(out, "release_array_ptr_loop:")?;
+    writeln!(out, "    test r13, r13")?;
+    writeln!(out, "    jz release_array_ptr_free")?;
+    writeln!(out, "    dec r13")?;
+    writeln!(out, "    mov rcx, r13")?;
+    writeln!(out, "    shl rcx, 3 ; element offset")?;
+    writeln!(out, "    mov rdi, [rbx+rcx]")?;
+    writeln!(out, "    mov rsi, r14")?;
+    writeln!(out, "    call release_array_elem_ptr")?;
+    writeln!(out, "    jmp release_array_ptr_loop")?;
+    writeln!(out, "release_array_ptr_free:")?;
+    writeln!(out, "    mov rsi, [rbx-{}] ; length", ARRAY_LEN_OFFSET)?;
+    writeln!(out, "    shl rsi, 3")?;
+    writeln!(
+        out,
+        "    add rsi, {} ; block size, header included",
+        ARRAY_HEADER_SIZE
+    )?;
+    writeln!(
+        out,
+        "    lea rdi, [rbx-{}] ; block starts at the header",
+        ARRAY_HEADER_SIZE
+    )?;
+    writeln!(out, "    pop r14")?;
+    writeln!(out, "    pop r13")?;
+    writeln!(out, "    pop rbx")?;
+    writeln!(
+        out,
+        "    jmp free_heap_ptr ; return the array to the allocator"
+    )?;
+    writeln!(out, "release_array_ptr_done:")?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+/// Emits `release_array_elem_ptr`, which releases the element at rdi of an
+/// array whose element class is in rsi.
+pub fn emit_release_array_elem_ptr<W: Write>(out: &mut W) -> Result<(), error::Error> {
+    writeln!(out, "global release_array_elem_ptr")?;
+    writeln!(out, "release_array_elem_ptr:")?;
+    writeln!(out, "    cmp rsi, {} ; string element?", ARRAY_CLASS_STR)?;
+    writeln!(out, "    je release_str_ptr")?;
+    writeln!(out, "    cmp rsi, {} ; array element?", ARRAY_CLASS_ARRAY)?;
+    writeln!(out, "    je release_array_ptr")?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+/// Emits `copy_array_ptr`, which returns in rax a deep copy of the array at
+/// rdi, so the copy owns its strings and arrays separately.
+pub fn emit_copy_array_ptr<W: Write>(out: &mut W) -> Result<(), error::Error> {
+    writeln!(out, "global copy_array_ptr")?;
+    writeln!(out, "copy_array_ptr:")?;
+    writeln!(out, "    mov rax, rdi")?;
+    writeln!(out, "    test rdi, rdi ; nothing to copy")?;
+    writeln!(out, "    jz copy_array_ptr_done")?;
+    writeln!(out, "    push rbx ; preserve callee-saved registers")?;
+    writeln!(out, "    push r13")?;
+    writeln!(out, "    push r14")?;
+    writeln!(out, "    push r15")?;
+    writeln!(out, "    mov rbx, rdi ; source array")?;
+    writeln!(out, "    mov r13, [rbx-{}] ; length", ARRAY_LEN_OFFSET)?;
+    writeln!(out, "    shl r13, 3")?;
+    writeln!(
+        out,
+        "    add r13, {} ; block size, header included",
+        ARRAY_HEADER_SIZE
+    )?;
+    writeln!(out, "    mov rdi, r13")?;
+    writeln!(out, "    call alloc_heap_ptr ; allocate the copy")?;
+    writeln!(out, "    mov r14, rax ; copy block")?;
+    writeln!(out, "    mov rdi, rax ; destination block")?;
+    writeln!(
+        out,
+        "    lea rsi, [rbx-{}] ; source block, header included",
+        ARRAY_HEADER_SIZE
+    )?;
+    writeln!(out, "    mov rcx, r13 ; bytes to copy")?;
+    writeln!(out, "    cld ; ensure forward copy")?;
+    writeln!(out, "    rep movsb ; duplicate header and elements")?;
+    writeln!(
+        out,
+        "    add r14, {} ; copied array starts after its header",
+        ARRAY_HEADER_SIZE
+    )?;
+    writeln!(
+        out,
+        "    mov r15, [r14-{}] ; elements left to copy",
+        ARRAY_LEN_OFFSET
+    )?;
+    writeln!(
+        out,
+        "    mov rcx, [r14-{}] ; element class",
+        ARRAY_CLASS_OFFSET
+    )?;
+    writeln!(out, "    test rcx, rcx ; plain elements are copied already")?;
+    writeln!(out, "    jz copy_array_ptr_copied")?;
+    writeln!(out, "copy_array_ptr_loop:")?;
+    writeln!(out, "    test r15, r15")?;
+    writeln!(out, "    jz copy_array_ptr_copied")?;
+    writeln!(out, "    dec r15")?;
+    writeln!(out, "    mov rcx, r15")?;
+    writeln!(out, "    shl rcx, 3 ; element offset")?;
+    writeln!(
+        out,
+        "    mov rdi, [r14+rcx] ; element shared with the source"
+    )?;
+    writeln!(
+        out,
+        "    mov rcx, [r14-{}] ; element class",
+        ARRAY_CLASS_OFFSET
+    )?;
+    writeln!(out, "    cmp rcx, {} ; string element?", ARRAY_CLASS_STR)?;
+    writeln!(out, "    je copy_array_ptr_str")?;
+    writeln!(out, "    call copy_array_ptr")?;
+    writeln!(out, "    jmp copy_array_ptr_store")?;
+    writeln!(out, "copy_array_ptr_str:")?;
+    writeln!(out, "    call copy_str_ptr")?;
+    writeln!(out, "copy_array_ptr_store:")?;
+    writeln!(out, "    mov rcx, r15")?;
+    writeln!(out, "    shl rcx, 3")?;
+    writeln!(out, "    mov [r14+rcx], rax ; element owned by the copy")?;
+    writeln!(out, "    jmp copy_array_ptr_loop")?;
+    writeln!(out, "copy_array_ptr_copied:")?;
+    writeln!(out, "    mov rax, r14")?;
+    writeln!(out, "    pop r15")?;
+    writeln!(out, "    pop r14")?;
+    writeln!(out, "    pop r13")?;
+    writeln!(out, "    pop rbx")?;
+    writeln!(out, "copy_array_ptr_done:")?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+/// Emits `copy_array_elem_ptr`, which returns in rax a copy of the element at
+/// rdi of an array whose element class is in rsi.
+pub fn emit_copy_array_elem_ptr<W: Write>(out: &mut W) -> Result<(), error::Error> {
+    writeln!(out, "global copy_array_elem_ptr")?;
+    writeln!(out, "copy_array_elem_ptr:")?;
+    writeln!(out, "    cmp rsi, {} ; string element?", ARRAY_CLASS_STR)?;
+    writeln!(out, "    je copy_str_ptr")?;
+    writeln!(out, "    cmp rsi, {} ; array element?", ARRAY_CLASS_ARRAY)?;
+    writeln!(out, "    je copy_array_ptr")?;
+    writeln!(out, "    mov rax, rdi ; plain elements are copied by value")?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+/// Emits `map_new_ptr`, which returns in rax an empty map whose keys have the
+/// element class in rdi and whose values have the one in rsi.
+pub fn emit_map_new_ptr<W: Write>(out: &mut W) -> Result<(), error::Error> {
+    writeln!(out, "global map_new_ptr")?;
+    writeln!(out, "map_new_ptr:")?;
+    writeln!(out, "    push rbx ; preserve callee-saved registers")?;
+    writeln!(out, "    push r13")?;
+    writeln!(out, "    mov rbx, rdi ; key class")?;
+    writeln!(out, "    mov r13, rsi ; value class")?;
+    writeln!(
+        out,
+        "    mov rdi, {} ; header, entries and index",
+        map_block_size(MAP_INITIAL_CAPACITY)
+    )?;
+    writeln!(out, "    call alloc_heap_ptr")?;
+    writeln!(
+        out,
+        "    add rax, {} ; map starts after its header",
+        MAP_HEADER_SIZE
+    )?;
+    writeln!(
+        out,
+        "    mov [rax-{}], rbx ; key class header",
+        MAP_KEY_CLASS_OFFSET
+    )?;
+    writeln!(
+        out,
+        "    mov [rax-{}], r13 ; value class header",
+        MAP_VALUE_CLASS_OFFSET
+    )?;
+    writeln!(
+        out,
+        "    mov qword [rax-{}], {} ; capacity header",
+        MAP_CAPACITY_OFFSET, MAP_INITIAL_CAPACITY
+    )?;
+    writeln!(
+        out,
+        "    mov qword [rax-{}], 0 ; no entries yet",
+        MAP_COUNT_OFFSET
+    )?;
+    writeln!(out, "    mov rbx, rax")?;
+    writeln!(
+        out,
+        "    lea rdi, [rax+{}] ; index follows the entries",
+        map_index_offset(MAP_INITIAL_CAPACITY)
+    )?;
+    writeln!(
+        out,
+        "    mov rcx, {} ; index bytes",
+        MAP_INITIAL_CAPACITY * WORD_SIZE
+    )?;
+    writeln!(out, "    xor rax, rax")?;
+    writeln!(out, "    cld")?;
+    writeln!(out, "    rep stosb ; every slot starts empty")?;
+    writeln!(out, "    mov rax, rbx")?;
+    writeln!(out, "    pop r13")?;
+    writeln!(out, "    pop rbx")?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+/// Emits `map_hash_ptr`, which returns in rax the hash of the key at rdi
+/// whose element class is in rsi: FNV-1a over the bytes of a string, and a
+/// multiplicative mix of anything else. Clobbers rcx, rdx, rsi and rdi.
+pub fn emit_map_hash_ptr<W: Write>(out: &mut W) -> Result<(), error::Error> {
+    writeln!(out, "global map_hash_ptr")?;
+    writeln!(out, "map_hash_ptr:")?;
+    writeln!(out, "    cmp rsi, {} ; string key?", ARRAY_CLASS_STR)?;
+    writeln!(out, "    je map_hash_ptr_str")?;
+    writeln!(
+        out,
+        "    mov rax, {:#x} ; golden ratio multiplier",
+        MAP_INT_HASH_MULTIPLIER
+    )?;
+    writeln!(out, "    imul rax, rdi")?;
+    writeln!(out, "    mov rdx, rax")?;
+    writeln!(out, "    shr rdx, 32 ; fold the well mixed high bits down")?;
+    writeln!(out, "    xor rax, rdx")?;
+    writeln!(out, "    ret")?;
+    writeln!(out, "map_hash_ptr_str:")?;
+    writeln!(out, "    mov rcx, [rdi-{}] ; bytes to hash", STR_LEN_OFFSET)?;
+    writeln!(
+        out,
+        "    mov rax, {:#x} ; FNV offset basis",
+        FNV_OFFSET_BASIS
+    )?;
+    writeln!(out, "    mov rsi, {:#x} ; FNV prime", FNV_PRIME)?;
+    writeln!(out, "map_hash_ptr_loop:")?;
+    writeln!(out, "    test rcx, rcx")?;
+    writeln!(out, "    jz map_hash_ptr_done")?;
+    writeln!(out, "    movzx rdx, byte [rdi]")?;
+    writeln!(out, "    xor rax, rdx")?;
+    writeln!(out, "    imul rax, rsi")?;
+    writeln!(out, "    inc rdi")?;
+    writeln!(out, "    dec rcx")?;
+    writeln!(out, "    jmp map_hash_ptr_loop")?;
+    writeln!(out, "map_hash_ptr_done:")?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+/// Emits `map_find_ptr`, which looks up the key at rsi in the map at rdi. It
+/// returns in rax the address of the entry holding it, or 0, and in rdx the
+/// address of the index slot that refers to that entry or would.
+pub fn emit_map_find_ptr<W: Write>(out: &mut W) -> Result<(), error::Error> {
+    writeln!(out, "global map_find_ptr")?;
+    writeln!(out, "map_find_ptr:")?;
+    writeln!(out, "    push rbx ; preserve callee-saved registers")?;
+    writeln!(out, "    push r12")?;
+    writeln!(out, "    push r13")?;
+    writeln!(out, "    push r14")?;
+    writeln!(out, "    push r15")?;
+    writeln!(out, "    mov rbx, rdi ; map")?;
+    writeln!(out, "    mov r12, rsi ; key")?;
+    writeln!(out, "    mov rdi, rsi")?;
+    writeln!(
+        out,
+        "    mov rsi, [rbx-{}] ; key class",
+        MAP_KEY_CLASS_OFFSET
+    )?;
+    writeln!(out, "    call map_hash_ptr")?;
+    writeln!(out, "    mov r13, [rbx-{}] ; capacity", MAP_CAPACITY_OFFSET)?;
+    emit_map_index_start(out, "r15", "r13", "rbx")?;
+    writeln!(out, "    dec r13 ; slot mask")?;
+    writeln!(out, "    and rax, r13")?;
+    writeln!(out, "    mov r14, rax ; first slot to probe")?;
+    writeln!(out, "map_find_ptr_probe:")?;
+    writeln!(out, "    mov rdx, r14")?;
+    writeln!(out, "    shl rdx, 3")?;
+    writeln!(out, "    add rdx, r15 ; slot address")?;
+    writeln!(out, "    mov rax, [rdx] ; entry position plus one")?;
+    writeln!(out, "    test rax, rax")?;
+    writeln!(out, "    jz map_find_ptr_absent")?;
+    emit_map_entry_from_slot(out)?;
+    writeln!(out, "    mov rdi, [rax] ; key of the entry")?;
+    writeln!(
+        out,
+        "    mov rcx, [rbx-{}] ; key class",
+        MAP_KEY_CLASS_OFFSET
+    )?;
+    writeln!(out, "    cmp rcx, {} ; string keys?", ARRAY_CLASS_STR)?;
+    writeln!(out, "    je map_find_ptr_str")?;
+    writeln!(out, "    cmp rdi, r12")?;
+    writeln!(out, "    je map_find_ptr_done")?;
+    writeln!(out, "    jmp map_find_ptr_next")?;
+    writeln!(out, "map_find_ptr_str:")?;
+    writeln!(out, "    mov rsi, r12")?;
+    writeln!(out, "    call cmp_str_ptr")?;
+    writeln!(out, "    test rax, rax ; same bytes?")?;
+    writeln!(out, "    jz map_find_ptr_found_str")?;
+    writeln!(out, "map_find_ptr_next:")?;
+    writeln!(out, "    inc r14")?;
+    writeln!(out, "    and r14, r13 ; wrap around the index")?;
+    writeln!(out, "    jmp map_find_ptr_probe")?;
+    writeln!(out, "map_find_ptr_found_str:")?;
+    writeln!(out, "    mov rdx, r14")?;
+    writeln!(out, "    shl rdx, 3")?;
+    writeln!(out, "    add rdx, r15 ; slot address")?;
+    writeln!(out, "    mov rax, [rdx]")?;
+    emit_map_entry_from_slot(out)?;
+    writeln!(out, "    jmp map_find_ptr_done")?;
+    writeln!(out, "map_find_ptr_absent:")?;
+    writeln!(out, "    xor rax, rax ; no entry; rdx is the empty slot")?;
+    writeln!(out, "map_find_ptr_done:")?;
+    writeln!(out, "    pop r15")?;
+    writeln!(out, "    pop r14")?;
+    writeln!(out, "    pop r13")?;
+    writeln!(out, "    pop r12")?;
+    writeln!(out, "    pop rbx")?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+/// Turns the position plus one in rax, read from an index slot of the map in
+/// rbx, into the address of its entry.
+fn emit_map_entry_from_slot<W: Write>(out: &mut W) -> Result<(), error::Error> {
+    writeln!(out, "    dec rax")?;
+    writeln!(out, "    shl rax, 4 ; entry offset")?;
+    writeln!(out, "    add rax, rbx ; entry address")?;
+    Ok(())
+}
+
+/// Leaves in `dst` the start of the index of the map in `map`, whose capacity
+/// is in `capacity`.
+fn emit_map_index_start<W: Write>(
+    out: &mut W,
+    dst: &str,
+    capacity: &str,
+    map: &str,
+) -> Result<(), error::Error> {
+    writeln!(out, "    mov {dst}, {capacity}")?;
+    writeln!(out, "    shl {dst}, 3 ; entries take a word per slot")?;
+    writeln!(out, "    add {dst}, {map} ; index start")?;
+    Ok(())
+}
+
+/// Emits `map_insert_ptr`, which stores the value at rdx under the key at rsi
+/// in the map at rdi, taking both, and returns the map in rax. A value
+/// already under the key is released along with the new key; otherwise the
+/// entry is appended, growing the map into a new block when it is full.
+pub fn emit_map_insert_ptr<W: Write>(out: &mut W) -> Result<(), error::Error> {
+    writeln!(out, "global map_insert_ptr")?;
+    writeln!(out, "map_insert_ptr:")?;
+    writeln!(out, "    push rbx ; preserve callee-saved registers")?;
+    writeln!(out, "    push r12")?;
+    writeln!(out, "    push r13")?;
+    writeln!(out, "    mov rbx, rdi ; map")?;
+    writeln!(out, "    mov r12, rsi ; key")?;
+    writeln!(out, "    mov r13, rdx ; value")?;
+    writeln!(out, "    call map_find_ptr")?;
+    writeln!(out, "    test rax, rax")?;
+    writeln!(out, "    jz map_insert_ptr_absent")?;
+    writeln!(out, "    mov rdi, [rax+{}] ; old value", WORD_SIZE)?;
+    writeln!(out, "    mov [rax+{}], r13 ; store the new one", WORD_SIZE)?;
+    writeln!(
+        out,
+        "    mov rsi, [rbx-{}] ; value class",
+        MAP_VALUE_CLASS_OFFSET
+    )?;
+    writeln!(out, "    call release_array_elem_ptr")?;
+    writeln!(out, "    mov rdi, r12")?;
+    writeln!(
+        out,
+        "    mov rsi, [rbx-{}] ; key class",
+        MAP_KEY_CLASS_OFFSET
+    )?;
+    writeln!(
+        out,
+        "    call release_array_elem_ptr ; the entry keeps its own key"
+    )?;
+    writeln!(out, "    jmp map_insert_ptr_done")?;
+    writeln!(out, "map_insert_ptr_absent:")?;
+    writeln!(out, "    mov rcx, [rbx-{}] ; entries", MAP_COUNT_OFFSET)?;
+    writeln!(out, "    shl rcx, 1")?;
+    writeln!(
+        out,
+        "    cmp rcx, [rbx-{}] ; room for another entry?",
+        MAP_CAPACITY_OFFSET
+    )?;
+    writeln!(out, "    jl map_insert_ptr_room")?;
+    writeln!(out, "    mov rdi, rbx")?;
+    writeln!(out, "    call map_grow_ptr")?;
+    writeln!(out, "    mov rbx, rax")?;
+    writeln!(out, "    mov rdi, rbx")?;
+    writeln!(out, "    mov rsi, r12")?;
+    writeln!(out, "    call map_find_ptr ; empty slot in the new index")?;
+    writeln!(out, "map_insert_ptr_room:")?;
+    writeln!(out, "    mov rcx, [rbx-{}] ; entries", MAP_COUNT_OFFSET)?;
+    writeln!(out, "    mov rax, rcx")?;
+    writeln!(out, "    shl rax, 4 ; entry offset")?;
+    writeln!(out, "    add rax, rbx ; first free entry")?;
+    writeln!(out, "    mov [rax], r12")?;
+    writeln!(out, "    mov [rax+{}], r13", WORD_SIZE)?;
+    writeln!(out, "    inc rcx")?;
+    writeln!(out, "    mov [rbx-{}], rcx", MAP_COUNT_OFFSET)?;
+    writeln!(out, "    mov [rdx], rcx ; slot refers to the entry")?;
+    writeln!(out, "map_insert_ptr_done:")?;
+    writeln!(out, "    mov rax, rbx")?;
+    writeln!(out, "    pop r13")?;
+    writeln!(out, "    pop r12")?;
+    writeln!(out, "    pop rbx")?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+/// Emits `map_grow_ptr`, which moves the map at rdi into a block with twice
+/// the capacity, frees the old block and returns the new map in rax.
+pub fn emit_map_grow_ptr<W: Write>(out: &mut W) -> Result<(), error::Error> {
+    writeln!(out, "global map_grow_ptr")?;
+    writeln!(out, "map_grow_ptr:")?;
+    writeln!(out, "    push rbx ; preserve callee-saved registers")?;
+    writeln!(out, "    push r12")?;
+    writeln!(out, "    push r13")?;
+    writeln!(out, "    mov rbx, rdi ; old map")?;
+    writeln!(
+        out,
+        "    mov r12, [rbx-{}] ; old capacity",
+        MAP_CAPACITY_OFFSET
+    )?;
+    writeln!(out, "    mov rdi, r12")?;
+    writeln!(out, "    shl rdi, 5 ; entries and index of twice the slots")?;
+    writeln!(out, "    add rdi, {} ; header included", MAP_HEADER_SIZE)?;
+    writeln!(out, "    call alloc_heap_ptr")?;
+    writeln!(
+        out,
+        "    lea r13, [rax+{}] ; new map starts after its header",
+        MAP_HEADER_SIZE
+    )?;
+    for (offset, what) in [
+        (MAP_KEY_CLASS_OFFSET, "key class"),
+        (MAP_VALUE_CLASS_OFFSET, "value class"),
+    ] {
+        writeln!(out, "    mov rcx, [rbx-{}] ; {}", offset, what)?;
+        writeln!(out, "    mov [r13-{}], rcx", offset)?;
+    }
+    writeln!(out, "    mov rcx, r12")?;
+    writeln!(out, "    shl rcx, 1")?;
+    writeln!(
+        out,
+        "    mov [r13-{}], rcx ; doubled capacity",
+        MAP_CAPACITY_OFFSET
+    )?;
+    writeln!(out, "    mov rcx, [rbx-{}] ; entries", MAP_COUNT_OFFSET)?;
+    writeln!(out, "    mov [r13-{}], rcx", MAP_COUNT_OFFSET)?;
+    writeln!(out, "    shl rcx, 4 ; entry bytes")?;
+    writeln!(out, "    mov rdi, r13")?;
+    writeln!(out, "    mov rsi, rbx")?;
+    writeln!(out, "    cld ; ensure forward copy")?;
+    writeln!(out, "    rep movsb ; entries keep their order")?;
+    writeln!(
+        out,
+        "    lea rdi, [rbx-{}] ; old block starts at the header",
+        MAP_HEADER_SIZE
+    )?;
+    writeln!(out, "    mov rsi, r12")?;
+    writeln!(out, "    shl rsi, 4")?;
+    writeln!(out, "    add rsi, {} ; old block size", MAP_HEADER_SIZE)?;
+    writeln!(out, "    call free_heap_ptr")?;
+    writeln!(out, "    mov rdi, r13")?;
+    writeln!(out, "    call map_reindex_ptr")?;
+    writeln!(out, "    mov rax, r13")?;
+    writeln!(out, "    pop r13")?;
+    writeln!(out, "    pop r12")?;
+    writeln!(out, "    pop rbx")?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+/// Emits `map_reindex_ptr`, which empties the index of the map at rdi and
+/// points a slot at every entry again.
+pub fn emit_map_reindex_ptr<W: Write>(out: &mut W) -> Result<(), error::Error> {
+    writeln!(out, "global map_reindex_ptr")?;
+    writeln!(out, "map_reindex_ptr:")?;
+    writeln!(out, "    push rbx ; preserve callee-saved registers")?;
+    writeln!(out, "    push r12")?;
+    writeln!(out, "    push r13")?;
+    writeln!(out, "    push r14")?;
+    writeln!(out, "    mov rbx, rdi ; map")?;
+    writeln!(out, "    mov r12, [rbx-{}] ; capacity", MAP_CAPACITY_OFFSET)?;
+    emit_map_index_start(out, "r14", "r12", "rbx")?;
+    writeln!(out, "    mov rdi, r14")?;
+    writeln!(out, "    mov rcx, r12")?;
+    writeln!(out, "    shl rcx, 3 ; index bytes")?;
+    writeln!(out, "    xor rax, rax")?;
+    writeln!(out, "    cld")?;
+    writeln!(out, "    rep stosb ; empty every slot")?;
+    writeln!(out, "    xor r13, r13 ; entry position")?;
+    writeln!(out, "map_reindex_ptr_loop:")?;
+    writeln!(
+        out,
+        "    cmp r13, [rbx-{}] ; past the last entry?",
+        MAP_COUNT_OFFSET
+    )?;
+    writeln!(out, "    jge map_reindex_ptr_done")?;
+    writeln!(out, "    mov rax, r13")?;
+    writeln!(out, "    shl rax, 4 ; entry offset")?;
+    writeln!(out, "    add rax, rbx")?;
+    writeln!(out, "    mov rdi, [rax] ; key of the entry")?;
+    writeln!(
+        out,
+        "    mov rsi, [rbx-{}] ; key class",
+        MAP_KEY_CLASS_OFFSET
+    )?;
+    writeln!(out, "    call map_hash_ptr")?;
+    writeln!(out, "    mov rcx, r12")?;
+    writeln!(out, "    dec rcx ; slot mask")?;
+    writeln!(out, "map_reindex_ptr_probe:")?;
+    writeln!(out, "    and rax, rcx ; wrap around the index")?;
+    writeln!(out, "    mov rdx, rax")?;
+    writeln!(out, "    shl rdx, 3")?;
+    writeln!(out, "    add rdx, r14 ; slot address")?;
+    writeln!(out, "    mov r8, [rdx]")?;
+    writeln!(out, "    test r8, r8 ; empty slot?")?;
+    writeln!(out, "    jz map_reindex_ptr_place")?;
+    writeln!(out, "    inc rax")?;
+    writeln!(out, "    jmp map_reindex_ptr_probe")?;
+    writeln!(out, "map_reindex_ptr_place:")?;
+    writeln!(out, "    inc r13")?;
+    writeln!(out, "    mov [rdx], r13 ; entry position plus one")?;
+    writeln!(out, "    jmp map_reindex_ptr_loop")?;
+    writeln!(out, "map_reindex_ptr_done:")?;
+    writeln!(out, "    pop r14")?;
+    writeln!(out, "    pop r13")?;
+    writeln!(out, "    pop r12")?;
+    writeln!(out, "    pop rbx")?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+/// Emits `map_remove_ptr`, which drops the entry under the key at rsi from
+/// the map at rdi, if there is one, and returns the map in rax. The key is
+/// taken, and later entries move down so the rest keep their order.
+pub fn emit_map_remove_ptr<W: Write>(out: &mut W) -> Result<(), error::Error> {
+    writeln!(out, "global map_remove_ptr")?;
+    writeln!(out, "map_remove_ptr:")?;
+    writeln!(out, "    push rbx ; preserve callee-saved registers")?;
+    writeln!(out, "    push r12")?;
+    writeln!(out, "    push r13")?;
+    writeln!(out, "    mov rbx, rdi ; map")?;
+    writeln!(out, "    mov r12, rsi ; key")?;
+    writeln!(out, "    call map_find_ptr")?;
+    writeln!(out, "    mov r13, rax ; entry, if any")?;
+    writeln!(out, "    mov rdi, r12")?;
+    writeln!(
+        out,
+        "    mov rsi, [rbx-{}] ; key class",
+        MAP_KEY_CLASS_OFFSET
+    )?;
+    writeln!(out, "    call release_array_elem_ptr ; release the key")?;
+    writeln!(out, "    test r13, r13")?;
+    writeln!(out, "    jz map_remove_ptr_done")?;
+    writeln!(out, "    mov rdi, [r13] ; key of the entry")?;
+    writeln!(
+        out,
+        "    mov rsi, [rbx-{}] ; key class",
+        MAP_KEY_CLASS_OFFSET
+    )?;
+    writeln!(out, "    call release_array_elem_ptr")?;
+    writeln!(out, "    mov rdi, [r13+{}] ; value of the entry", WORD_SIZE)?;
+    writeln!(
+        out,
+        "    mov rsi, [rbx-{}] ; value class",
+        MAP_VALUE_CLASS_OFFSET
+    )?;
+    writeln!(out, "    call release_array_elem_ptr")?;
+    writeln!(out, "    mov rcx, [rbx-{}] ; entries", MAP_COUNT_OFFSET)?;
+    writeln!(out, "    dec rcx")?;
+    writeln!(out, "    mov [rbx-{}], rcx", MAP_COUNT_OFFSET)?;
+    writeln!(out, "    shl rcx, 4")?;
+    writeln!(out, "    add rcx, rbx ; start of the old last entry")?;
+    writeln!(out, "    sub rcx, r13 ; bytes after the dropped entry")?;
+    writeln!(out, "    mov rdi, r13")?;
+    writeln!(out, "    lea rsi, [r13+{}] ; next entry", MAP_ENTRY_SIZE)?;
+    writeln!(out, "    cld ; forward copy moves entries down safely")?;
+    writeln!(out, "    rep movsb")?;
+    writeln!(out, "    mov rdi, rbx")?;
+    writeln!(
+        out,
+        "    call map_reindex_ptr ; positions after the entry moved"
+    )?;
+    writeln!(out, "map_remove_ptr_done:")?;
+    writeln!(out, "    mov rax, rbx")?;
+    writeln!(out, "    pop r13")?;
+    writeln!(out, "    pop r12")?;
+    writeln!(out, "    pop rbx")?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+/// Emits `map_release_ptr`, which frees the map at rdi along with the keys
+/// and values it holds.
+pub fn emit_map_release_ptr<W: Write>(out: &mut W) -> Result<(), error::Error> {
+    writeln!(out, "global map_release_ptr")?;
+    writeln!(out, "map_release_ptr:")?;
+    writeln!(out, "    test rdi, rdi ; nothing to free")?;
+    writeln!(out, "    jz map_release_ptr_done")?;
+    writeln!(out, "    push rbx ; preserve callee-saved registers")?;
+    writeln!(out, "    push r13")?;
+    writeln!(out, "    mov rbx, rdi ; map")?;
+    writeln!(
+        out,
+        "    mov r13, [rbx-{}] ; entries left to release",
+        MAP_COUNT_OFFSET
+    )?;
+    writeln!(out, "map_release_ptr_loop:")?;
+    writeln!(out, "    test r13, r13")?;
+    writeln!(out, "    jz map_release_ptr_free")?;
+    writeln!(out, "    dec r13")?;
+    for (offset, class_offset) in [
+        (0, MAP_KEY_CLASS_OFFSET),
+        (WORD_SIZE, MAP_VALUE_CLASS_OFFSET),
+    ] {
+        writeln!(out, "    mov rax, r13")?;
+        writeln!(out, "    shl rax, 4 ; entry offset")?;
+        writeln!(out, "    add rax, rbx")?;
+        writeln!(out, "    mov rdi, [rax+{}]", offset)?;
+        writeln!(out, "    mov rsi, [rbx-{}]", class_offset)?;
+        writeln!(out, "    call release_array_elem_ptr")?;
+    }
+    writeln!(out, "    jmp map_release_ptr_loop")?;
+    writeln!(out, "map_release_ptr_free:")?;
+    writeln!(out, "    mov rsi, [rbx-{}] ; capacity", MAP_CAPACITY_OFFSET)?;
+    writeln!(out, "    shl rsi, 4")?;
+    writeln!(
+        out,
+        "    add rsi, {} ; block size, header included",
+        MAP_HEADER_SIZE
+    )?;
+    writeln!(
+        out,
+        "    lea rdi, [rbx-{}] ; block starts at the header",
+        MAP_HEADER_SIZE
+    )?;
+    writeln!(out, "    pop r13")?;
+    writeln!(out, "    pop rbx")?;
+    writeln!(
+        out,
+        "    jmp free_heap_ptr ; return the map to the allocator"
+    )?;
+    writeln!(out, "map_release_ptr_done:")?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+/// Emits `map_copy_ptr`, which returns in rax a deep copy of the map at rdi,
+/// so the copy owns its keys and values separately.
+pub fn emit_map_copy_ptr<W: Write>(out: &mut W) -> Result<(), error::Error> {
+    writeln!(out, "global map_copy_ptr")?;
+    writeln!(out, "map_copy_ptr:")?;
+    writeln!(out, "    mov rax, rdi")?;
+    writeln!(out, "    test rdi, rdi ; nothing to copy")?;
+    writeln!(out, "    jz map_copy_ptr_done")?;
+    writeln!(out, "    push rbx ; preserve callee-saved registers")?;
+    writeln!(out, "    push r13")?;
+    writeln!(out, "    push r14")?;
+    writeln!(out, "    push r15")?;
+    writeln!(out, "    mov rbx, rdi ; source map")?;
+    writeln!(out, "    mov r13, [rbx-{}] ; capacity", MAP_CAPACITY_OFFSET)?;
+    writeln!(out, "    shl r13, 4")?;
+    writeln!(
+        out,
+        "    add r13, {} ; block size, header included",
+        MAP_HEADER_SIZE
+    )?;
+    writeln!(out, "    mov rdi, r13")?;
+    writeln!(out, "    call alloc_heap_ptr ; allocate the copy")?;
+    writeln!(out, "    mov r14, rax ; copy block")?;
+    writeln!(out, "    mov rdi, rax ; destination block")?;
+    writeln!(
+        out,
+        "    lea rsi, [rbx-{}] ; source block, header included",
+        MAP_HEADER_SIZE
+    )?;
+    writeln!(out, "    mov rcx, r13 ; bytes to copy")?;
+    writeln!(out, "    cld ; ensure forward copy")?;
+    writeln!(out, "    rep movsb ; duplicate header, entries and index")?;
+    writeln!(
+        out,
+        "    add r14, {} ; copied map starts after its header",
+        MAP_HEADER_SIZE
+    )?;
+    writeln!(
+        out,
+        "    mov r15, [r14-{}] ; entries left to copy",
+        MAP_COUNT_OFFSET
+    )?;
+    writeln!(out, "map_copy_ptr_loop:")?;
+    writeln!(out, "    test r15, r15")?;
+    writeln!(out, "    jz map_copy_ptr_copied")?;
+    writeln!(out, "    dec r15")?;
+    for (offset, class_offset) in [
+        (0, MAP_KEY_CLASS_OFFSET),
+        (WORD_SIZE, MAP_VALUE_CLASS_OFFSET),
+    ] {
+        writeln!(out, "    mov rcx, r15")?;
+        writeln!(out, "    shl rcx, 4 ; entry offset")?;
+        writeln!(out, "    add rcx, r14")?;
+        writeln!(
+            out,
+            "    mov rdi, [rcx+{}] ; shared with the source",
+            offset
+        )?;
+        writeln!(out, "    mov rsi, [r14-{}]", class_offset)?;
+        writeln!(out, "    call copy_array_elem_ptr")?;
+        writeln!(out, "    mov rcx, r15")?;
+        writeln!(out, "    shl rcx, 4")?;
+        writeln!(out, "    add rcx, r14")?;
+        writeln!(out, "    mov [rcx+{}], rax ; owned by the copy", offset)?;
+    }
+    writeln!(out, "    jmp map_copy_ptr_loop")?;
+    writeln!(out, "map_copy_ptr_copied:")?;
+    writeln!(out, "    mov rax, r14")?;
+    writeln!(out, "    pop r15")?;
+    writeln!(out, "    pop r14")?;
+    writeln!(out, "    pop r13")?;
+    writeln!(out, "    pop rbx")?;
+    writeln!(out, "map_copy_ptr_done:")?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+/// Emits `queue_push_ptr`, which appends the word in rsi to the queue whose
+/// head and tail are at rdi.
+pub fn emit_queue_push_ptr<W: Write>(out: &mut W) -> Result<(), error::Error> {
+    writeln!(out, "global queue_push_ptr")?;
+    writeln!(out, "queue_push_ptr:")?;
+    writeln!(out, "    push rbx ; preserve callee-saved registers")?;
+    writeln!(out, "    push r13")?;
+    writeln!(out, "    mov rbx, rdi ; queue")?;
+    writeln!(out, "    mov r13, rsi ; word to queue")?;
+    writeln!(out, "    mov rdi, {} ; node size", QUEUE_NODE_SIZE)?;
+    writeln!(out, "    call alloc_heap_ptr")?;
+    writeln!(out, "    mov qword [rax], 0 ; last node")?;
+    writeln!(out, "    mov [rax+{}], r13", WORD_SIZE)?;
+    writeln!(out, "    mov rcx, [rbx+{}] ; tail", WORD_SIZE)?;
+    writeln!(out, "    test rcx, rcx")?;
+    writeln!(out, "    jz queue_push_ptr_empty")?;
+    writeln!(out, "    mov [rcx], rax ; link behind the tail")?;
+    writeln!(out, "    jmp queue_push_ptr_linked")?;
+    writeln!(out, "queue_push_ptr_empty:")?;
+    writeln!(out, "    mov [rbx], rax ; node is the head too")?;
+    writeln!(out, "queue_push_ptr_linked:")?;
+    writeln!(
+        out,
+        "    mov [rbx+{}], rax ; node is the new tail",
+        WORD_SIZE
+    )?;
+    writeln!(out, "    pop r13")?;
+    writeln!(out, "    pop rbx")?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+/// Emits `queue_pop_ptr`, which unlinks the head of the non-empty queue at
+/// rdi and returns in rax the word it held.
+pub fn emit_queue_pop_ptr<W: Write>(out: &mut W) -> Result<(), error::Error> {
+    writeln!(out, "global queue_pop_ptr")?;
+    writeln!(out, "queue_pop_ptr:")?;
+    writeln!(out, "    push rbx ; preserve callee-saved registers")?;
+    writeln!(out, "    mov rdx, rdi ; queue")?;
+    writeln!(out, "    mov rdi, [rdx] ; head node")?;
+    writeln!(out, "    mov rcx, [rdi] ; next node")?;
+    writeln!(out, "    mov [rdx], rcx ; next node is the new head")?;
+    writeln!(out, "    test rcx, rcx")?;
+    writeln!(out, "    jnz queue_pop_ptr_unlinked")?;
+    writeln!(out, "    mov qword [rdx+{}], 0 ; queue is empty", WORD_SIZE)?;
+    writeln!(out, "queue_pop_ptr_unlinked:")?;
+    writeln!(out, "    mov rbx, [rdi+{}] ; word the node held", WORD_SIZE)?;
+    writeln!(out, "    mov rsi, {} ; node size", QUEUE_NODE_SIZE)?;
+    writeln!(out, "    call free_heap_ptr")?;
+    writeln!(out, "    mov rax, rbx")?;
+    writeln!(out, "    pop rbx")?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+/// Emits `sched_next_ptr`, which generated code jumps to once its frame is
+/// gone. It enters the next closure in the run queue. With nothing left to
+/// run the program exits, with status 0 when no coroutine is still parked on
+/// a channel and with a deadlock message and status 1 otherwise. A `threaded`
+/// scheduler only exits once every thread has nothing to run; until then an
+/// idle thread sleeps until a coroutine becomes runnable.
+pub fn emit_sched_next_ptr<W: Write>(out: &mut W, threaded: bool) -> Result<(), error::Error> {
+    writeln!(out, "section .data")?;
+    writeln!(out, "{}:", SCHED_STATE_LABEL)?;
+    writeln!(out, "    dq 0, 0, 0, 1, 0, 0")?;
+    if threaded {
+        writeln!(out, "{}:", SCHED_LOCK_LABEL)?;
+        writeln!(out, "    dq 0")?;
+    }
+    writeln!(out, "section .rodata")?;
+    writeln!(out, "sched_deadlock_message:")?;
+    writeln!(
+        out,
+        "    db {}",
+        crate::escape_literal_for_rodata(DEADLOCK_MESSAGE)
+    )?;
+    writeln!(out, "section .text")?;
+    writeln!(out, "global sched_next_ptr")?;
+    writeln!(out, "sched_next_ptr:")?;
+    if threaded {
+        emit_lock(out, SCHED_LOCK_LABEL, "sched_next_ptr_lock")?;
+    }
+    writeln!(out, "sched_next_ptr_pick:")?;
+    writeln!(out, "    lea rdi, [{}] ; run queue", SCHED_STATE_LABEL)?;
+    writeln!(out, "    cmp qword [rdi], 0 ; anything to run?")?;
+    writeln!(out, "    je sched_next_ptr_idle")?;
+    writeln!(out, "    call queue_pop_ptr")?;
+    writeln!(out, "    mov rdi, rax ; env_end of the next coroutine")?;
+    if threaded {
+        emit_unlock(out, SCHED_LOCK_LABEL)?;
+    }
+    writeln!(
+        out,
+        "    mov rax, [rdi+{}] ; load its entry point",
+        ENV_METADATA_UNWRAPPER_OFFSET
+    )?;
+    writeln!(out, "    jmp rax")?;
+    writeln!(out, "sched_next_ptr_idle:")?;
+    if threaded {
+        writeln!(out, "    mov rax, [rdi+{}]", SCHED_IDLE_OFFSET)?;
+        writeln!(out, "    inc rax ; this thread has nothing to run")?;
+        writeln!(
+            out,
+            "    cmp rax, [rdi+{}] ; neither has any other?",
+            SCHED_WORKERS_OFFSET
+        )?;
+        writeln!(out, "    je sched_next_ptr_stop")?;
+        writeln!(out, "    mov [rdi+{}], rax", SCHED_IDLE_OFFSET)?;
+        writeln!(
+            out,
+            "    mov edx, dword [rdi+{}] ; wakeups so far",
+            SCHED_WAKEUPS_OFFSET
+        )?;
+        emit_unlock(out, SCHED_LOCK_LABEL)?;
+        writeln!(out, "    add rdi, {}", SCHED_WAKEUPS_OFFSET)?;
+        writeln!(out, "    mov rsi, {} ; wait", FUTEX_WAIT_PRIVATE)?;
+        writeln!(out, "    xor r10, r10 ; no timeout")?;
+        writeln!(out, "    mov rax, {} ; futex syscall", SYSCALL_FUTEX)?;
+        writeln!(
+            out,
+            "    syscall ; sleep unless a coroutine became runnable since"
+        )?;
+        emit_lock(out, SCHED_LOCK_LABEL, "sched_next_ptr_relock")?;
+        writeln!(out, "    lea rdi, [{}]", SCHED_STATE_LABEL)?;
+        writeln!(out, "    dec qword [rdi+{}]", SCHED_IDLE_OFFSET)?;
+        writeln!(out, "    jmp sched_next_ptr_pick")?;
+        writeln!(out, "sched_next_ptr_stop:")?;
+    }
+    writeln!(out, "    and rsp, -16 ; align the stack for libc")?;
+    writeln!(
+        out,
+        "    cmp qword [rdi+{}], 0 ; anyone parked?",
+        SCHED_WAITING_OFFSET
+    )?;
+    writeln!(out, "    jne sched_next_ptr_deadlock")?;
+    writeln!(out, "    mov rdi, 0 ; exit code")?;
+    writeln!(out, "    call exit ; call libc exit to flush buffers")?;
+    writeln!(out, "sched_next_ptr_deadlock:")?;
+    writeln!(out, "    mov rax, {} ; write syscall", SYSCALL_WRITE)?;
+    writeln!(out, "    mov rdi, 2 ; stderr")?;
+    writeln!(out, "    lea rsi, [sched_deadlock_message]")?;
+    writeln!(
+        out,
+        "    mov rdx, {} ; message length",
+        DEADLOCK_MESSAGE.len()
+    )?;
+    writeln!(out, "    syscall")?;
+    writeln!(out, "    mov rdi, 1 ; exit code")?;
+    writeln!(out, "    call exit")?;
+    Ok(())
+}
+
+/// Emits `sched_ready_ptr`, which puts the closure at rdi at the back of the
+/// run queue. A `threaded` scheduler also wakes a thread that has nothing to
+/// run, and `sched_ready_ptr_locked` does the same for callers that already
+/// hold the scheduler's lock.
+pub fn emit_sched_ready_ptr<W: Write>(out: &mut W, threaded: bool) -> Result<(), error::Error> {
+    writeln!(out, "global sched_ready_ptr")?;
+    writeln!(out, "sched_ready_ptr:")?;
+    if !threaded {
+        writeln!(out, "    mov rsi, rdi")?;
+        writeln!(out, "    lea rdi, [{}] ; run queue", SCHED_STATE_LABEL)?;
+        writeln!(out, "    jmp queue_push_ptr")?;
+        return Ok(());
+    }
+    emit_locked_call(out, SCHED_LOCK_LABEL, "sched_ready_ptr")?;
+    writeln!(out, "    mov rsi, rdi")?;
+    writeln!(out, "    lea rdi, [{}] ; run queue", SCHED_STATE_LABEL)?;
+    writeln!(out, "    call queue_push_ptr")?;
+    writeln!(out, "    lea rdi, [{}]", SCHED_STATE_LABEL)?;
+    writeln!(
+        out,
+        "    inc dword [rdi+{}] ; sleepers see a new count",
+        SCHED_WAKEUPS_OFFSET
+    )?;
+    writeln!(
+        out,
+        "    cmp qword [rdi+{}], 0 ; anyone asleep?",
+        SCHED_IDLE_OFFSET
+    )?;
+    writeln!(out, "    je sched_ready_ptr_done")?;
+    writeln!(out, "    add rdi, {}", SCHED_WAKEUPS_OFFSET)?;
+    writeln!(out, "    mov rsi, {} ; wake", FUTEX_WAKE_PRIVATE)?;
+    writeln!(
+        out,
+        "    mov rdx, 1 ; one thread is enough for one coroutine"
+    )?;
+    writeln!(out, "    mov rax, {} ; futex syscall", SYSCALL_FUTEX)?;
+    writeln!(out, "    syscall")?;
+    writeln!(out, "sched_ready_ptr_done:")?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+/// Emits `thread_new_ptr`, which starts a thread that enters the closure at
+/// rdi and then takes its turn running coroutines. When no thread can be
+/// started the closure is queued as a coroutine instead.
+pub fn emit_thread_new_ptr<W: Write>(out: &mut W) -> Result<(), error::Error> {
+    writeln!(out, "global thread_new_ptr")?;
+    writeln!(out, "thread_new_ptr:")?;
+    writeln!(out, "    push rbp ; save caller frame")?;
+    writeln!(out, "    mov rbp, rsp ; establish frame")?;
+    writeln!(out, "    push rdi ; task")?;
+    emit_lock(out, SCHED_LOCK_LABEL, "thread_new_ptr_lock")?;
+    writeln!(out, "    lea rcx, [{}]", SCHED_STATE_LABEL)?;
+    writeln!(
+        out,
+        "    inc qword [rcx+{}] ; busy until it runs out of work",
+        SCHED_WORKERS_OFFSET
+    )?;
+    emit_unlock(out, SCHED_LOCK_LABEL)?;
+    writeln!(out, "    sub rsp, {} ; room for the thread id", WORD_SIZE)?;
+    writeln!(out, "    and rsp, -16 ; align the stack for libc")?;
+    writeln!(out, "    mov rdi, rsp")?;
+    writeln!(out, "    xor rsi, rsi ; default attributes")?;
+    writeln!(out, "    lea rdx, [thread_start_ptr]")?;
+    writeln!(out, "    mov rcx, [rbp-{}] ; task", WORD_SIZE)?;
+    writeln!(out, "    call pthread_create")?;
+    writeln!(out, "    test eax, eax")?;
+    writeln!(out, "    jz thread_new_ptr_done")?;
+    emit_lock(out, SCHED_LOCK_LABEL, "thread_new_ptr_undo")?;
+    writeln!(out, "    lea rcx, [{}]", SCHED_STATE_LABEL)?;
+    writeln!(out, "    dec qword [rcx+{}]", SCHED_WORKERS_OFFSET)?;
+    emit_unlock(out, SCHED_LOCK_LABEL)?;
+    writeln!(out, "    mov rdi, [rbp-{}]", WORD_SIZE)?;
+    writeln!(
+        out,
+        "    call sched_ready_ptr ; run it on the threads there are"
+    )?;
+    writeln!(out, "thread_new_ptr_done:")?;
+    writeln!(out, "    leave")?;
+    writeln!(out, "    ret")?;
+    writeln!(out, "thread_start_ptr:")?;
+    writeln!(
+        out,
+        "    mov rax, [rdi+{}] ; enter the task",
+        ENV_METADATA_UNWRAPPER_OFFSET
+    )?;
+    writeln!(out, "    jmp rax")?;
+    Ok(())
+}
+
+/// Emits `chan_new_ptr`, which returns in rax an empty channel for values of
+/// the class in rdi, with one reference.
+pub fn emit_chan_new_ptr<W: Write>(out: &mut W) -> Result<(), error::Error> {
+    writeln!(out, "global chan_new_ptr")?;
+    writeln!(out, "chan_new_ptr:")?;
+    writeln!(out, "    push rbx ; preserve callee-saved registers")?;
+    writeln!(out, "    mov rbx, rdi ; value class")?;
+    writeln!(out, "    mov rdi, {} ; channel size", CHAN_SIZE)?;
+    writeln!(out, "    call alloc_heap_ptr")?;
+    writeln!(
+        out,
+        "    mov qword [rax+{}], 1 ; one reference",
+        CHAN_REFS_OFFSET
+    )?;
+    writeln!(out, "    mov [rax+{}], rbx", CHAN_CLASS_OFFSET)?;
+    for offset in [CHAN_VALUES_OFFSET, CHAN_RECEIVERS_OFFSET] {
+        writeln!(out, "    mov qword [rax+{}], 0 ; empty queue", offset)?;
+        writeln!(out, "    mov qword [rax+{}], 0", offset + WORD_SIZE)?;
+    }
     writeln!(out, "    pop rbx")?;
-    writeln!(out, "    pop rbp")?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+/// Emits `chan_send_ptr`, which hands the value in rsi to the first receiver
+/// parked on the channel at rdi and makes it runnable, or queues the value
+/// when nobody is waiting.
+pub fn emit_chan_send_ptr<W: Write>(out: &mut W, threaded: bool) -> Result<(), error::Error> {
+    writeln!(out, "global chan_send_ptr")?;
+    writeln!(out, "chan_send_ptr:")?;
+    let ready = if threaded {
+        emit_locked_call(out, SCHED_LOCK_LABEL, "chan_send_ptr")?;
+        "sched_ready_ptr_locked"
+    } else {
+        "sched_ready_ptr"
+    };
+    writeln!(out, "    push rbx ; preserve callee-saved registers")?;
+    writeln!(out, "    push r13")?;
+    writeln!(out, "    mov rbx, rdi ; channel")?;
+    writeln!(out, "    mov r13, rsi ; value")?;
+    writeln!(
+        out,
+        "    cmp qword [rbx+{}], 0 ; anyone receiving?",
+        CHAN_RECEIVERS_OFFSET
+    )?;
+    writeln!(out, "    je chan_send_ptr_queue")?;
+    writeln!(out, "    lea rdi, [rbx+{}]", CHAN_RECEIVERS_OFFSET)?;
+    writeln!(out, "    call queue_pop_ptr ; the receiver's continuation")?;
+    writeln!(out, "    mov [rax-{}], r13 ; pass the value", WORD_SIZE * 2)?;
+    writeln!(out, "    mov [rax-{}], rbx ; and the channel", WORD_SIZE)?;
+    writeln!(out, "    mov rdi, rax")?;
+    writeln!(out, "    call {} ; the receiver can run again", ready)?;
+    writeln!(out, "    lea rcx, [{}]", SCHED_STATE_LABEL)?;
+    writeln!(
+        out,
+        "    dec qword [rcx+{}] ; one coroutine fewer parked",
+        SCHED_WAITING_OFFSET
+    )?;
+    writeln!(out, "    jmp chan_send_ptr_done")?;
+    writeln!(out, "chan_send_ptr_queue:")?;
+    writeln!(out, "    lea rdi, [rbx+{}]", CHAN_VALUES_OFFSET)?;
+    writeln!(out, "    mov rsi, r13")?;
+    writeln!(
+        out,
+        "    call queue_push_ptr ; keep it for the next receiver"
+    )?;
+    writeln!(out, "chan_send_ptr_done:")?;
+    writeln!(out, "    pop r13")?;
+    writeln!(out, "    pop rbx")?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+/// Emits `chan_recv_ptr`, which takes the oldest value queued on the channel
+/// at rdi into rax and sets rdx to 1. When none is queued it parks the
+/// continuation in rsi on the channel instead and sets rdx to 0.
+pub fn emit_chan_recv_ptr<W: Write>(out: &mut W, threaded: bool) -> Result<(), error::Error> {
+    writeln!(out, "global chan_recv_ptr")?;
+    writeln!(out, "chan_recv_ptr:")?;
+    if threaded {
+        emit_locked_call(out, SCHED_LOCK_LABEL, "chan_recv_ptr")?;
+    }
+    writeln!(
+        out,
+        "    cmp qword [rdi+{}], 0 ; any value queued?",
+        CHAN_VALUES_OFFSET
+    )?;
+    writeln!(out, "    je chan_recv_ptr_park")?;
+    writeln!(out, "    add rdi, {}", CHAN_VALUES_OFFSET)?;
+    writeln!(out, "    call queue_pop_ptr")?;
+    writeln!(out, "    mov rdx, 1 ; got a value")?;
+    writeln!(out, "    ret")?;
+    writeln!(out, "chan_recv_ptr_park:")?;
+    writeln!(out, "    add rdi, {}", CHAN_RECEIVERS_OFFSET)?;
+    writeln!(out, "    call queue_push_ptr ; wait for a sender")?;
+    writeln!(out, "    lea rcx, [{}]", SCHED_STATE_LABEL)?;
+    writeln!(
+        out,
+        "    inc qword [rcx+{}] ; one more coroutine parked",
+        SCHED_WAITING_OFFSET
+    )?;
+    writeln!(out, "    mov rdx, 0 ; parked")?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+/// Emits `chan_release_ptr`, which drops a reference to the channel at rdi.
+/// The last reference releases the values still queued on it, by class, and
+/// frees the channel.
+pub fn emit_chan_release_ptr<W: Write>(out: &mut W, threaded: bool) -> Result<(), error::Error> {
+    writeln!(out, "global chan_release_ptr")?;
+    writeln!(out, "chan_release_ptr:")?;
+    // Once the count reaches zero no other thread can reach the channel, so
+    // only the count itself has to change atomically.
+    writeln!(
+        out,
+        "    {}dec qword [rdi+{}] ; drop a reference",
+        atomic_prefix(threaded),
+        CHAN_REFS_OFFSET
+    )?;
+    writeln!(out, "    jnz chan_release_ptr_done")?;
+    // Environments release themselves through generated code, which keeps
+    // nothing in registers, so the channel stays on the stack.
+    writeln!(out, "    push rdi ; channel")?;
+    writeln!(out, "chan_release_ptr_loop:")?;
+    writeln!(out, "    mov rdi, [rsp]")?;
+    writeln!(
+        out,
+        "    cmp qword [rdi+{}], 0 ; values left?",
+        CHAN_VALUES_OFFSET
+    )?;
+    writeln!(out, "    je chan_release_ptr_free")?;
+    writeln!(out, "    add rdi, {}", CHAN_VALUES_OFFSET)?;
+    writeln!(out, "    call queue_pop_ptr")?;
+    writeln!(out, "    mov rdi, rax ; value")?;
+    writeln!(out, "    mov rcx, [rsp]")?;
+    writeln!(
+        out,
+        "    mov rsi, [rcx+{}] ; value class",
+        CHAN_CLASS_OFFSET
+    )?;
+    writeln!(out, "    cmp rsi, {} ; environment?", CHAN_CLASS_ENV)?;
+    writeln!(out, "    je chan_release_ptr_env")?;
+    writeln!(out, "    call release_array_elem_ptr")?;
+    writeln!(out, "    jmp chan_release_ptr_loop")?;
+    writeln!(out, "chan_release_ptr_env:")?;
+    writeln!(
+        out,
+        "    mov rax, [rdi+{}] ; its release helper",
+        ENV_METADATA_RELEASE_OFFSET
+    )?;
+    writeln!(out, "    call rax")?;
+    writeln!(out, "    jmp chan_release_ptr_loop")?;
+    writeln!(out, "chan_release_ptr_free:")?;
+    writeln!(out, "    pop rdi")?;
+    writeln!(out, "    mov rsi, {} ; channel size", CHAN_SIZE)?;
+    writeln!(
+        out,
+        "    jmp free_heap_ptr ; return the channel to the allocator"
+    )?;
+    writeln!(out, "chan_release_ptr_done:")?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+/// Emits `chan_copy_ptr`, which adds a reference to the channel at rdi and
+/// returns it in rax. Copies share the one channel.
+pub fn emit_chan_copy_ptr<W: Write>(out: &mut W, threaded: bool) -> Result<(), error::Error> {
+    writeln!(out, "global chan_copy_ptr")?;
+    writeln!(out, "chan_copy_ptr:")?;
+    writeln!(
+        out,
+        "    {}inc qword [rdi+{}] ; one more reference",
+        atomic_prefix(threaded),
+        CHAN_REFS_OFFSET
+    )?;
+    writeln!(out, "    mov rax, rdi")?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+/// The prefix that makes a read-modify-write of memory atomic when other
+/// threads may touch the same word.
+fn atomic_prefix(threaded: bool) -> &'static str {
+    if threaded {
+        "lock "
+    } else {
+        ""
+    }
+}
+
+/// Emits `alloc_str_ptr`, which returns in rax a new string of rdi bytes with
+/// its header and terminator in place, leaving the bytes for the caller, or 0
+/// when there is no memory for it.
+pub fn emit_alloc_str_ptr<W: Write>(out: &mut W) -> Result<(), error::Error> {
+    writeln!(out, "global alloc_str_ptr")?;
+    writeln!(out, "alloc_str_ptr:")?;
+    writeln!(out, "    push rbx ; preserve callee-saved register")?;
+    writeln!(out, "    mov rbx, rdi ; string length")?;
+    writeln!(
+        out,
+        "    lea rdi, [rbx+{}] ; header, bytes and terminator",
+        STR_HEADER_SIZE + 1
+    )?;
+    writeln!(out, "    call alloc_heap_ptr")?;
+    writeln!(out, "    test rax, rax ; out of memory?")?;
+    writeln!(out, "    jz alloc_str_ptr_done")?;
+    writeln!(out, "    lea rcx, [rbx+{}]", STR_HEADER_SIZE + 1)?;
+    writeln!(out, "    mov [rax], rcx ; allocation size header")?;
+    writeln!(
+        out,
+        "    mov [rax+{}], rbx ; length header",
+        STR_HEADER_SIZE - STR_LEN_OFFSET
+    )?;
+    writeln!(
+        out,
+        "    add rax, {} ; string bytes follow the header",
+        STR_HEADER_SIZE
+    )?;
+    writeln!(out, "    mov byte [rax+rbx], 0 ; terminator")?;
+    writeln!(out, "alloc_str_ptr_done:")?;
+    writeln!(out, "    pop rbx")?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+/// Emits `concat_str_ptr`, which returns in rax a new string holding the
+/// bytes of the string at rdi followed by those of the one at rsi.
+pub fn emit_concat_str_ptr<W: Write>(out: &mut W) -> Result<(), error::Error> {
+    writeln!(out, "global concat_str_ptr")?;
+    writeln!(out, "concat_str_ptr:")?;
+    writeln!(out, "    push rbx ; preserve callee-saved registers")?;
+    writeln!(out, "    push r13")?;
+    writeln!(out, "    push r14")?;
+    writeln!(out, "    mov rbx, rdi ; left string")?;
+    writeln!(out, "    mov r13, rsi ; right string")?;
+    writeln!(out, "    mov rdi, [rbx-{}] ; left length", STR_LEN_OFFSET)?;
+    writeln!(out, "    mov rcx, [r13-{}] ; right length", STR_LEN_OFFSET)?;
+    writeln!(out, "    add rdi, rcx ; combined length")?;
+    writeln!(out, "    call alloc_str_ptr")?;
+    writeln!(out, "    mov r14, rax ; combined string")?;
+    writeln!(out, "    mov rdi, rax")?;
+    writeln!(out, "    mov rsi, rbx")?;
+    writeln!(out, "    mov rcx, [rbx-{}]", STR_LEN_OFFSET)?;
+    writeln!(out, "    cld ; ensure forward copy")?;
+    writeln!(out, "    rep movsb ; copy the left bytes")?;
+    writeln!(out, "    mov rsi, r13")?;
+    writeln!(out, "    mov rcx, [r13-{}]", STR_LEN_OFFSET)?;
+    writeln!(out, "    rep movsb ; append the right bytes")?;
+    writeln!(out, "    mov rax, r14")?;
+    writeln!(out, "    pop r14")?;
+    writeln!(out, "    pop r13")?;
+    writeln!(out, "    pop rbx")?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+/// Emits `slice_str_ptr`, which returns in rax a new string holding the
+/// bytes of the string at rdi from rsi up to rdx. The range is checked by
+/// the caller.
+pub fn emit_slice_str_ptr<W: Write>(out: &mut W) -> Result<(), error::Error> {
+    writeln!(out, "global slice_str_ptr")?;
+    writeln!(out, "slice_str_ptr:")?;
+    writeln!(out, "    push rbx ; preserve callee-saved registers")?;
+    writeln!(out, "    push r13")?;
+    writeln!(out, "    lea rbx, [rdi+rsi] ; first byte of the slice")?;
+    writeln!(out, "    mov r13, rdx")?;
+    writeln!(out, "    sub r13, rsi ; slice length")?;
+    writeln!(out, "    mov rdi, r13")?;
+    writeln!(out, "    call alloc_str_ptr")?;
+    writeln!(out, "    mov rdi, rax")?;
+    writeln!(out, "    mov rsi, rbx")?;
+    writeln!(out, "    mov rcx, r13")?;
+    writeln!(out, "    cld ; ensure forward copy")?;
+    writeln!(out, "    rep movsb ; copy the slice")?;
+    writeln!(out, "    pop r13")?;
+    writeln!(out, "    pop rbx")?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+/// Emits `cstr_to_str_ptr`, which returns in rax a new string holding the
+/// bytes of the NUL-terminated C string at rdi.
+pub fn emit_cstr_to_str_ptr<W: Write>(out: &mut W) -> Result<(), error::Error> {
+    writeln!(out, "global cstr_to_str_ptr")?;
+    writeln!(out, "cstr_to_str_ptr:")?;
+    writeln!(out, "    push rbx ; preserve callee-saved registers")?;
+    writeln!(out, "    push r13")?;
+    writeln!(out, "    mov rbx, rdi ; C string")?;
+    writeln!(out, "    xor r13, r13 ; length so far")?;
+    writeln!(out, "cstr_to_str_ptr_len:")?;
+    writeln!(out, "    movzx rax, byte [rbx+r13]")?;
+    writeln!(out, "    test rax, rax ; terminator?")?;
+    writeln!(out, "    jz cstr_to_str_ptr_copy")?;
+    writeln!(out, "    inc r13")?;
+    writeln!(out, "    jmp cstr_to_str_ptr_len")?;
+    writeln!(out, "cstr_to_str_ptr_copy:")?;
+    writeln!(out, "    mov rdi, r13")?;
+    writeln!(out, "    call alloc_str_ptr")?;
+    writeln!(out, "    mov rdi, rax")?;
+    writeln!(out, "    mov rsi, rbx")?;
+    writeln!(out, "    mov rcx, r13")?;
+    writeln!(out, "    cld ; ensure forward copy")?;
+    writeln!(out, "    rep movsb ; copy the bytes")?;
+    writeln!(out, "    pop r13")?;
+    writeln!(out, "    pop rbx")?;
+    writeln!(out, "    ret")?;
+    Ok(())
+}
+
+/// Emits the words `_start` saves argc and argv into.
+pub fn emit_process_args<W: Write>(out: &mut W) -> Result<(), error::Error> {
+    writeln!(out, "section .data")?;
+    writeln!(out, "{}:", PROCESS_ARGS_LABEL)?;
+    writeln!(out, "    dq 0, 0")?;
+    writeln!(out, "section .text")?;
+    Ok(())
+}
+
+/// Emits `cmp_str_ptr`, which sets rax to -1, 0 or 1 as the string at rdi
+/// sorts before, equal to or after the one at rsi, byte by byte and then by
+/// length.
+pub fn emit_cmp_str_ptr<W: Write>(out: &mut W) -> Result<(), error::Error> {
+    writeln!(out, "global cmp_str_ptr")?;
+    writeln!(out, "cmp_str_ptr:")?;
+    writeln!(out, "    mov rcx, [rdi-{}] ; left length", STR_LEN_OFFSET)?;
+    writeln!(out, "    mov rdx, [rsi-{}] ; right length", STR_LEN_OFFSET)?;
+    writeln!(out, "    mov r8, rcx ; bytes both strings have")?;
+    writeln!(out, "    cmp rcx, rdx")?;
+    writeln!(out, "    jbe cmp_str_ptr_loop")?;
+    writeln!(out, "    mov r8, rdx")?;
+    writeln!(out, "cmp_str_ptr_loop:")?;
+    writeln!(out, "    test r8, r8")?;
+    writeln!(out, "    jz cmp_str_ptr_lengths")?;
+    writeln!(out, "    movzx rax, byte [rdi]")?;
+    writeln!(out, "    movzx r9, byte [rsi]")?;
+    writeln!(out, "    cmp rax, r9")?;
+    writeln!(out, "    jb cmp_str_ptr_less")?;
+    writeln!(out, "    ja cmp_str_ptr_greater")?;
+    writeln!(out, "    inc rdi")?;
+    writeln!(out, "    inc rsi")?;
+    writeln!(out, "    dec r8")?;
+    writeln!(out, "    jmp cmp_str_ptr_loop")?;
+    writeln!(out, "cmp_str_ptr_lengths:")?;
+    writeln!(out, "    cmp rcx, rdx ; a prefix sorts first")?;
+    writeln!(out, "    jb cmp_str_ptr_less")?;
+    writeln!(out, "    ja cmp_str_ptr_greater")?;
+    writeln!(out, "    xor rax, rax")?;
+    writeln!(out, "    ret")?;
+    writeln!(out, "cmp_str_ptr_less:")?;
+    writeln!(out, "    mov rax, -1")?;
+    writeln!(out, "    ret")?;
+    writeln!(out, "cmp_str_ptr_greater:")?;
+    writeln!(out, "    mov rax, 1")?;
     writeln!(out, "    ret")?;
     Ok(())
 }
@@ -82,22 +1702,8 @@ pub fn emit_deepcopy_heap_ptr<W: Write>(out: &mut W) -> Result<(), error::Error>
     )?;
     writeln!(out, "    mov rbx, r12 ; keep env_end pointer")?;
     writeln!(out, "    sub rbx, r14 ; compute env base pointer")?;
-    writeln!(out, "    mov rdi, 0 ; addr hint so kernel picks mmap base")?;
-    writeln!(out, "    mov rsi, r15 ; length = heap size")?;
-    writeln!(
-        out,
-        "    mov rdx, {} ; prot = read/write",
-        PROT_READ | PROT_WRITE
-    )?;
-    writeln!(
-        out,
-        "    mov r10, {} ; flags = private & anonymous",
-        MAP_PRIVATE | MAP_ANONYMOUS
-    )?;
-    writeln!(out, "    mov r8, -1 ; fd = -1")?;
-    writeln!(out, "    xor r9, r9 ; offset = 0")?;
-    writeln!(out, "    mov rax, {} ; mmap syscall", SYSCALL_MMAP)?;
-    writeln!(out, "    syscall ; allocate new closure env")?;
+    writeln!(out, "    mov rdi, r15 ; length = heap size")?;
+    writeln!(out, "    call alloc_heap_ptr ; allocate new closure env")?;
     writeln!(out, "    mov r13, rax ; new env base pointer")?;
     writeln!(out, "    mov rdi, r13 ; memcpy dest")?;
     writeln!(out, "    mov rsi, rbx ; memcpy src")?;
@@ -107,6 +107,20 @@ fn normalize_sig_kind_inner(
                 generics: signature.generics.clone(),
             })
         }
+        hir::SigKind::Array(elem) => {
+            hir::SigKind::Array(Box::new(normalize_sig_kind_inner(elem, ctx, seen)))
+        }
+        hir::SigKind::Map(key, value) => hir::SigKind::Map(
+            Box::new(normalize_sig_kind_inner(key, ctx, seen)),
+            Box::new(normalize_sig_kind_inner(value, ctx, seen)),
+        ),
+        hir::SigKind::Chan(elem) => {
+            hir::SigKind::Chan(Box::new(normalize_sig_kind_inner(elem, ctx, seen)))
+        }
+        hir::SigKind::Refined(base, bounds) => hir::SigKind::Refined(
+            Box::new(normalize_sig_kind_inner(base, ctx, seen)),
+            bounds.clone(),
+        ),
         other => other.clone(),
     }
 }
@@ -213,13 +227,26 @@ fn ast_sig_kind_to_hir(kind: ast::SigKind) -> hir::SigKind {
         ast::SigKind::Variadic => hir::SigKind::Variadic,
         ast::SigKind::CompileTimeInt => hir::SigKind::CompileTimeInt,
         ast::SigKind::CompileTimeStr => hir::SigKind::CompileTimeStr,
-        ast::SigKind::Ident(ident) => hir::SigKind::Ident(hir::SigIdent { name: ident.name }),
+        ast::SigKind::Ident(ident) => hir::SigKind::Ident(hir::SigIdent {
+            name: ident.name,
+            span: ident.span,
+        }),
         ast::SigKind::Sig(signature) => hir::SigKind::Sig(ast_signature_to_hir(signature)),
-        ast::SigKind::GenericInst { name, args } => hir::SigKind::GenericInst {
+        ast::SigKind::Array(elem) => hir::SigKind::Array(Box::new(ast_sig_kind_to_hir(*elem))),
+        ast::SigKind::Map(key, value) => hir::SigKind::Map(
+            Box::new(ast_sig_kind_to_hir(*key)),
+            Box::new(ast_sig_kind_to_hir(*value)),
+        ),
+        ast::SigKind::Chan(elem) => hir::SigKind::Chan(Box::new(ast_sig_kind_to_hir(*elem))),
+        ast::SigKind::GenericInst { name, args, span } => hir::SigKind::GenericInst {
             name,
+            span,
             args: args.into_iter().map(ast_sig_kind_to_hir).collect(),
         },
         ast::SigKind::Generic(name) => hir::SigKind::Generic(name),
+        ast::SigKind::Refined(base, bounds) => {
+            hir::SigKind::Refined(Box::new(ast_sig_kind_to_hir(*base)), bounds)
+        }
     }
 }
 
@@ -243,14 +270,30 @@ fn hir_sig_kind_to_ast(kind: hir::SigKind) -> ast::SigKind {
         hir::SigKind::CompileTimeStr => ast::SigKind::CompileTimeStr,
         hir::SigKind::Ident(ident) => ast::SigKind::Ident(ast::SigIdent {
             name: ident.name,
-            span: Span::unknown(),
+            span: ident.span,
         }),
         hir::SigKind::Sig(signature) => ast::SigKind::Sig(hir_signature_to_ast(signature)),
-        hir::SigKind::GenericInst { name, args } => ast::SigKind::GenericInst {
+        hir::SigKind::Array(elem) => ast::SigKind::Array(Box::new(hir_sig_kind_to_ast(*elem))),
+        hir::SigKind::Map(key, value) => ast::SigKind::Map(
+            Box::new(hir_sig_kind_to_ast(*key)),
+            Box::new(hir_sig_kind_to_ast(*value)),
+        ),
+        hir::SigKind::Chan(elem) => ast::SigKind::Chan(Box::new(hir_sig_kind_to_ast(*elem))),
+        hir::SigKind::Record { name, .. } | hir::SigKind::Sum { name, .. } => {
+            ast::SigKind::Ident(ast::SigIdent {
+                name,
+                span: Span::unknown(),
+            })
+        }
+        hir::SigKind::GenericInst { name, args, span } => ast::SigKind::GenericInst {
             name,
+            span,
             args: args.into_iter().map(hir_sig_kind_to_ast).collect(),
         },
         hir::SigKind::Generic(name) => ast::SigKind::Generic(name),
+        hir::SigKind::Refined(base, bounds) => {
+            ast::SigKind::Refined(Box::new(hir_sig_kind_to_ast(*base)), bounds)
+        }
     }
 }
 
@@ -271,16 +314,32 @@ fn lower_sig_kind(kind: &hir::SigKind, ctx: &mut ctx::Context, has_bang: bool) -
             }
         }
         hir::SigKind::Sig(signature) => hir::SigKind::Sig(resolve_signature(signature, ctx)),
-        hir::SigKind::GenericInst { name, args } => {
+        hir::SigKind::Array(elem) => {
+            hir::SigKind::Array(Box::new(lower_sig_kind(elem, ctx, false)))
+        }
+        hir::SigKind::Map(key, value) => hir::SigKind::Map(
+            Box::new(lower_sig_kind(key, ctx, false)),
+            Box::new(lower_sig_kind(value, ctx, false)),
+        ),
+        hir::SigKind::Chan(elem) => hir::SigKind::Chan(Box::new(lower_sig_kind(elem, ctx, false))),
+        hir::SigKind::GenericInst { name, args, span } => {
             let resolved_args = args
                 .iter()
                 .map(|arg| lower_sig_kind(arg, ctx, false))
                 .collect::<Vec<_>>();
 
-            instantiate_generic_inst(name, &resolved_args, ctx)
-                .unwrap_or_else(|| hir::SigKind::Ident(hir::SigIdent { name: name.clone() }))
+            instantiate_generic_inst(name, &resolved_args, ctx).unwrap_or_else(|| {
+                hir::SigKind::Ident(hir::SigIdent {
+                    name: name.clone(),
+                    span: *span,
+                })
+            })
         }
         hir::SigKind::Generic(name) => hir::SigKind::Generic(name.clone()),
+        hir::SigKind::Refined(base, bounds) => hir::SigKind::Refined(
+            Box::new(lower_sig_kind(base, ctx, has_bang)),
+            bounds.clone(),
+        ),
         hir::SigKind::Int => {
             if has_bang {
                 hir::SigKind::CompileTimeInt
@@ -295,7 +354,10 @@ fn lower_sig_kind(kind: &hir::SigKind, ctx: &mut ctx::Context, has_bang: bool) -
                 hir::SigKind::Str
             }
         }
-        hir::SigKind::Byte | hir::SigKind::F64 => kind.clone(),
+        hir::SigKind::Byte
+        | hir::SigKind::F64
+        | hir::SigKind::Record { .. }
+        | hir::SigKind::Sum { .. } => kind.clone(),
         hir::SigKind::Variadic => hir::SigKind::Variadic,
         hir::SigKind::CompileTimeInt => hir::SigKind::CompileTimeInt,
         hir::SigKind::CompileTimeStr => hir::SigKind::CompileTimeStr,
@@ -352,6 +414,12 @@ fn substitute_signature(
 fn substitute_kind(kind: &hir::SigKind, mapping: &HashMap<String, hir::SigKind>) -> hir::SigKind {
     match kind {
         hir::SigKind::Sig(signature) => hir::SigKind::Sig(substitute_signature(signature, mapping)),
+        hir::SigKind::Array(elem) => hir::SigKind::Array(Box::new(substitute_kind(elem, mapping))),
+        hir::SigKind::Map(key, value) => hir::SigKind::Map(
+            Box::new(substitute_kind(key, mapping)),
+            Box::new(substitute_kind(value, mapping)),
+        ),
+        hir::SigKind::Chan(elem) => hir::SigKind::Chan(Box::new(substitute_kind(elem, mapping))),
         hir::SigKind::Ident(ident) => {
             if let Some(mapped) = mapping.get(&ident.name) {
                 mapped.clone()
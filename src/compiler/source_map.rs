@@ -0,0 +1,91 @@
+use crate::compiler::span::{FileId, Span};
+
+pub struct SourceFile {
+    pub name: String,
+    pub source: String,
+    line_starts: Vec<usize>,
+}
+
+impl SourceFile {
+    fn new(name: String, source: String) -> Self {
+        let mut line_starts = vec![0];
+        for (idx, byte) in source.bytes().enumerate() {
+            if byte == b'\n' {
+                line_starts.push(idx + 1);
+            }
+        }
+        Self {
+            name,
+            source,
+            line_starts,
+        }
+    }
+
+    /// Returns the text of a 1-based line without its line terminator.
+    pub fn line(&self, line: usize) -> Option<&str> {
+        let start = *self.line_starts.get(line.checked_sub(1)?)?;
+        let end = self
+            .line_starts
+            .get(line)
+            .copied()
+            .unwrap_or(self.source.len());
+        let text = self.source.get(start..end)?;
+        Some(text.trim_end_matches(['\n', '\r']))
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    pub fn snippet(&self, span: Span) -> Option<&str> {
+        self.source.get(span.offset..span.end)
+    }
+}
+
+/// Owns the text of every file in a compilation so spans can be resolved back to source.
+#[derive(Default)]
+pub struct SourceMap {
+    files: Vec<SourceFile>,
+}
+
+impl SourceMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, name: impl Into<String>, source: impl Into<String>) -> FileId {
+        let id = FileId(self.files.len() as u32);
+        self.files.push(SourceFile::new(name.into(), source.into()));
+        id
+    }
+
+    pub fn get(&self, file: FileId) -> Option<&SourceFile> {
+        self.files.get(file.0 as usize)
+    }
+
+    pub fn name(&self, file: FileId) -> &str {
+        self.get(file)
+            .map(|file| file.name.as_str())
+            .unwrap_or("<unknown>")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lines_and_snippets_resolve_through_file_id() {
+        let mut map = SourceMap::new();
+        map.add("lib.rgo", "x: 1\n");
+        let main = map.add("main.rgo", "main: () {\r\n    foo()\n}");
+        let file = map.get(main).expect("file registered");
+        assert_eq!(map.name(main), "main.rgo");
+        assert_eq!(file.line(1), Some("main: () {"));
+        assert_eq!(file.line(3), Some("}"));
+        assert_eq!(file.line(4), None);
+
+        let span = Span::new(2, 5, 16).to(Span::new(2, 8, 19)).in_file(main);
+        assert_eq!(file.snippet(span), Some("foo"));
+    }
+}
@@ -1,25 +1,71 @@
+/// Identifies a source file registered in a `SourceMap`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FileId(pub u32);
+
+/// A half-open byte range of source text. `line`, `column` and `offset` mark where
+/// the range starts and `end` is the offset just past its last byte.
 #[derive(Clone, Copy, Default)]
 pub struct Span {
+    pub file: FileId,
     pub line: usize,
     pub column: usize,
     pub offset: usize,
+    pub end: usize,
 }
 
 impl Span {
+    /// An empty span at the given position.
     pub const fn new(line: usize, column: usize, offset: usize) -> Self {
         Self {
+            file: FileId(0),
             line,
             column,
             offset,
+            end: offset,
         }
     }
 
     pub const fn unknown() -> Self {
-        Self {
-            line: 0,
-            column: 0,
-            offset: 0,
+        Self::new(0, 0, 0)
+    }
+
+    pub const fn in_file(mut self, file: FileId) -> Self {
+        self.file = file;
+        self
+    }
+
+    pub const fn is_unknown(&self) -> bool {
+        self.line == 0
+    }
+
+    /// Extends this span so it ends where `end` ends. Unknown spans are left alone.
+    pub const fn to(mut self, end: Span) -> Self {
+        if self.is_unknown() || end.is_unknown() || end.end < self.offset {
+            return self;
         }
+        self.end = end.end;
+        self
+    }
+
+    /// The first `len` bytes of this span.
+    pub const fn head(mut self, len: usize) -> Self {
+        if len < self.len() {
+            self.end = self.offset + len;
+        }
+        self
+    }
+
+    pub const fn len(&self) -> usize {
+        self.end - self.offset
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.end == self.offset
+    }
+
+    /// Compares file and range, unlike `==` which treats every span as equal.
+    pub fn same_range(&self, other: &Span) -> bool {
+        self.file == other.file && self.offset == other.offset && self.end == other.end
     }
 }
 
@@ -0,0 +1,56 @@
+//! The standard library: Rgo source bundled into the compiler binary.
+//!
+//! A program imports it like any other file, e.g. `@"std/io.rgo"`. Paths under
+//! `std/` always name the bundled files, so a program cannot shadow them.
+
+const FILES: &[(&str, &str)] = &[
+    ("std/core.rgo", include_str!("../../std/core.rgo")),
+    ("std/io.rgo", include_str!("../../std/io.rgo")),
+    ("std/bool.rgo", include_str!("../../std/bool.rgo")),
+    ("std/int.rgo", include_str!("../../std/int.rgo")),
+    ("std/fmt.rgo", include_str!("../../std/fmt.rgo")),
+    ("std/iter.rgo", include_str!("../../std/iter.rgo")),
+    ("std/list.rgo", include_str!("../../std/list.rgo")),
+    ("std/option.rgo", include_str!("../../std/option.rgo")),
+    ("std/either.rgo", include_str!("../../std/either.rgo")),
+    ("std/prelude.rgo", include_str!("../../std/prelude.rgo")),
+];
+
+pub const PREFIX: &str = "std/";
+
+/// Returns the bundled source for an import path such as `std/io.rgo`.
+pub fn source(path: &str) -> Option<&'static str> {
+    FILES
+        .iter()
+        .find(|(name, _)| *name == path)
+        .map(|(_, source)| *source)
+}
+
+pub fn paths() -> impl Iterator<Item = &'static str> {
+    FILES.iter().map(|(name, _)| *name)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::compiler::compile;
+
+    #[test]
+    fn prelude_covers_and_compiles_every_file() {
+        let prelude = source("std/prelude.rgo").expect("prelude is bundled");
+        for path in paths().filter(|path| *path != "std/prelude.rgo") {
+            assert!(
+                prelude.contains(&format!("@\"{path}\"")),
+                "prelude does not import {path}"
+            );
+        }
+
+        let program = "@\"std/prelude.rgo\"\n\nmain: () {\n    exit(0)\n}\n";
+        let mut out = Vec::new();
+        if let Err(err) = compile(Cursor::new(program), "main", &mut out) {
+            panic!("prelude should compile: {err}");
+        }
+    }
+}
@@ -0,0 +1,478 @@
+//! The machines Rgo compiles for, and the backend each one lowers AIR with.
+
+use std::collections::HashSet;
+use std::fmt;
+use std::io::Write;
+
+use crate::compiler::aarch64;
+use crate::compiler::air::{
+    AirCopyArray, AirCopyChan, AirCopyMap, AirCopyStr, AirFunction, AirOp, SigKind,
+};
+use crate::compiler::builtins::AirRuntimeHelper;
+use crate::compiler::codegen::{self, is_chan_op, is_map_op, Artifacts, CHAN_HELPERS, MAP_HELPERS};
+use crate::compiler::error::Error;
+
+/// An instruction set Rgo can generate code for. Every target runs Linux and
+/// links against glibc.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Arch {
+    X86_64,
+    Aarch64,
+}
+
+impl Arch {
+    pub const TRIPLES: &'static str = "x86_64-linux-gnu|aarch64-linux-gnu";
+
+    /// Parses a triple such as `aarch64-unknown-linux-gnu`. The vendor may be
+    /// left out.
+    pub fn from_triple(triple: &str) -> Option<Self> {
+        let mut parts = triple.split('-');
+        let arch = parts.next()?;
+        let rest: Vec<&str> = parts.collect();
+        let env = match rest.as_slice() {
+            ["linux", env @ ..] | [_, "linux", env @ ..] => env,
+            _ => return None,
+        };
+        if !matches!(env, [] | ["gnu"]) {
+            return None;
+        }
+        match arch {
+            "x86_64" | "amd64" => Some(Arch::X86_64),
+            "aarch64" | "arm64" => Some(Arch::Aarch64),
+            _ => None,
+        }
+    }
+
+    /// The machine the compiler itself runs on, or x86-64 on anything else.
+    pub fn host() -> Self {
+        if cfg!(target_arch = "aarch64") {
+            Arch::Aarch64
+        } else {
+            Arch::X86_64
+        }
+    }
+
+    pub fn triple(self) -> &'static str {
+        match self {
+            Arch::X86_64 => "x86_64-linux-gnu",
+            Arch::Aarch64 => "aarch64-linux-gnu",
+        }
+    }
+
+    /// Whether the built-in assembler and ELF writer handle this target; the
+    /// others go through the system assembler and linker.
+    pub fn has_builtin_assembler(self) -> bool {
+        self == Arch::X86_64
+    }
+
+    pub fn write_preamble<W: Write>(self, out: &mut W) -> Result<(), Error> {
+        match self {
+            Arch::X86_64 => codegen::write_preamble(out),
+            Arch::Aarch64 => aarch64::write_preamble(out),
+        }
+    }
+
+    pub fn function<W: Write>(
+        self,
+        air: AirFunction,
+        artifacts: &mut Artifacts,
+        out: &mut W,
+    ) -> Result<(), Error> {
+        match self {
+            Arch::X86_64 => codegen::function(air, artifacts, out),
+            Arch::Aarch64 => aarch64::function(air, artifacts, out),
+        }
+    }
+
+    pub fn emit_externs<W: Write>(
+        self,
+        externs: &HashSet<String>,
+        out: &mut W,
+    ) -> Result<(), Error> {
+        match self {
+            Arch::X86_64 => codegen::emit_externs(externs, out),
+            // GNU as treats every undefined symbol as external.
+            Arch::Aarch64 => Ok(()),
+        }
+    }
+
+    pub fn emit_data<W: Write>(
+        self,
+        string_literals: &[(String, String)],
+        out: &mut W,
+    ) -> Result<(), Error> {
+        match self {
+            Arch::X86_64 => codegen::emit_data(string_literals, out),
+            Arch::Aarch64 => aarch64::emit_data(string_literals, out),
+        }
+    }
+}
+
+/// How a backend writes out the runtime helpers its generated code calls.
+pub trait Runtime {
+    /// Writes `helper`; `threaded` picks the variants that take locks.
+    fn emit_helper<W: Write>(
+        helper: AirRuntimeHelper,
+        threaded: bool,
+        out: &mut W,
+    ) -> Result<(), Error>;
+
+    /// Helpers this backend's version of `helper` calls on top of the ones
+    /// [`runtime_helpers`] already pulls in alongside it.
+    fn helper_calls(_helper: AirRuntimeHelper) -> &'static [AirRuntimeHelper] {
+        &[]
+    }
+}
+
+/// Writes every runtime helper `air` needs that the program does not have yet.
+pub fn emit_runtime_helpers<R: Runtime, W: Write>(
+    air: &AirFunction,
+    artifacts: &mut Artifacts,
+    out: &mut W,
+) -> Result<(), Error> {
+    for helper in runtime_helpers(air) {
+        emit_runtime_helper_once::<R, W>(helper, artifacts, out)?;
+    }
+    Ok(())
+}
+
+/// Writes `helper` unless an earlier function already brought it in.
+pub fn emit_runtime_helper_once<R: Runtime, W: Write>(
+    helper: AirRuntimeHelper,
+    artifacts: &mut Artifacts,
+    out: &mut W,
+) -> Result<(), Error> {
+    if !artifacts.builtins_used.insert(helper.name().to_string()) {
+        return Ok(());
+    }
+    artifacts.externs.remove(helper.name());
+    R::emit_helper(helper, artifacts.uses_threads, out)?;
+    for &callee in R::helper_calls(helper) {
+        emit_runtime_helper_once::<R, W>(callee, artifacts, out)?;
+    }
+    Ok(())
+}
+
+/// The helper that duplicates a captured value of `kind`.
+pub fn copy_helper(kind: &SigKind) -> AirRuntimeHelper {
+    match kind {
+        SigKind::Str => AirRuntimeHelper::CopyStrPtr,
+        SigKind::Array(_) => AirRuntimeHelper::CopyArrayPtr,
+        SigKind::Map(_, _) => AirRuntimeHelper::MapCopyPtr,
+        SigKind::Chan(_) => AirRuntimeHelper::ChanCopyPtr,
+        _ => AirRuntimeHelper::DeepCopyHeapPtr,
+    }
+}
+
+/// The runtime helpers the code for `air` calls, in the order they are
+/// emitted.
+pub fn runtime_helpers(air: &AirFunction) -> Vec<AirRuntimeHelper> {
+    let mut needs_alloc = false;
+    let mut needs_release = false;
+    let mut needs_deepcopy = false;
+    let mut needs_release_str = false;
+    let mut needs_copy_str = false;
+    let mut needs_alloc_str = false;
+    let mut needs_concat_str = false;
+    let mut needs_slice_str = false;
+    let mut needs_cmp_str = false;
+    let mut needs_cstr_to_str = false;
+    let mut needs_process_args = false;
+    let mut needs_release_array = false;
+    let mut needs_copy_array = false;
+    let mut needs_map = false;
+    let mut needs_chan = false;
+    let mut needs_thread = false;
+    for stmt in &air.items {
+        let Some(op) = stmt.as_op() else {
+            continue;
+        };
+        needs_map |= is_map_op(op);
+        needs_chan |= is_chan_op(op);
+        match op {
+            AirOp::ReleaseHeap(_)
+            | AirOp::CallPtr(_)
+            | AirOp::DivInt(_)
+            | AirOp::RemInt(_)
+            | AirOp::F64ToInt(_)
+            | AirOp::AddChecked(_)
+            | AirOp::SubChecked(_)
+            | AirOp::MulChecked(_)
+            | AirOp::Close(_)
+            | AirOp::MapLookup(_)
+            | AirOp::MapNext(_) => needs_release = true,
+            AirOp::ReleaseStr(_) => needs_release_str = true,
+            AirOp::CopyStr(_) => needs_copy_str = true,
+            // Maps and channels bring their copy helpers in with the rest
+            // of their runtime.
+            AirOp::CopyField(field) => match copy_helper(&field.kind) {
+                AirRuntimeHelper::CopyStrPtr => needs_copy_str = true,
+                AirRuntimeHelper::CopyArrayPtr => needs_copy_array = true,
+                AirRuntimeHelper::DeepCopyHeapPtr => needs_deepcopy = true,
+                _ => {}
+            },
+            AirOp::Printf(call) => needs_release_str |= !call.release.is_empty(),
+            AirOp::Write(call) | AirOp::WriteFd(call) => {
+                needs_release_str |= !call.release.is_empty()
+            }
+            AirOp::Read(_) => {
+                needs_alloc_str = true;
+                needs_release = true;
+                needs_release_str = true;
+            }
+            AirOp::Open(op) => {
+                needs_release = true;
+                needs_release_str |= !op.release.is_empty();
+            }
+            AirOp::ArgCount(_) => needs_process_args = true,
+            AirOp::ArgAt(_) => {
+                needs_alloc_str = true;
+                needs_cstr_to_str = true;
+                needs_process_args = true;
+                needs_release = true;
+            }
+            AirOp::Getenv(op) => {
+                needs_alloc_str = true;
+                needs_cstr_to_str = true;
+                needs_release = true;
+                needs_release_str |= !op.release.is_empty();
+            }
+            AirOp::Sprintf(call) => {
+                needs_alloc = true;
+                needs_release_str |= !call.release.is_empty();
+            }
+            AirOp::NewClosure(_) | AirOp::CloneClosure(_) | AirOp::NewArray(_) => {
+                needs_alloc = true
+            }
+            AirOp::StrLen(op) => needs_release_str |= !op.release.is_empty(),
+            AirOp::Concat(op) => {
+                needs_alloc_str = true;
+                needs_concat_str = true;
+                needs_release_str |= !op.release.is_empty();
+            }
+            AirOp::Slice(op) => {
+                needs_alloc_str = true;
+                needs_slice_str = true;
+                needs_release = true;
+                needs_release_str |= !op.release.is_empty();
+            }
+            AirOp::ByteAt(op) => {
+                needs_release = true;
+                needs_release_str |= !op.release.is_empty();
+            }
+            AirOp::JumpCmpStr(_) => needs_cmp_str = true,
+            AirOp::ReleaseArray(_) | AirOp::ArrayLen(_) => needs_release_array = true,
+            AirOp::ArrayGet(_) | AirOp::ArraySet(_) => {
+                needs_release_array = true;
+                needs_release = true;
+            }
+            AirOp::CopyArray(_) => needs_copy_array = true,
+            AirOp::Thread(_) => needs_thread = true,
+            _ => {}
+        }
+    }
+    // Maps hold keys and values the way arrays hold elements, and string keys
+    // are told apart by comparing them.
+    needs_release_array |= needs_map;
+    needs_copy_array |= needs_map;
+    needs_cmp_str |= needs_map;
+    needs_alloc |= needs_map;
+    // Channels release the values still queued on them when they go, and
+    // queue nodes come from the allocator.
+    needs_release_array |= needs_chan;
+    needs_alloc |= needs_chan;
+    // Arrays release and copy the strings they hold along with themselves.
+    needs_release_str |= needs_release_array;
+    needs_copy_str |= needs_copy_array;
+
+    let mut helpers = Vec::new();
+    // Releasing and copying go through the allocator too, so it comes along
+    // with any of them.
+    if needs_alloc
+        || needs_release
+        || needs_deepcopy
+        || needs_release_str
+        || needs_copy_str
+        || needs_alloc_str
+    {
+        helpers.push(AirRuntimeHelper::AllocHeapPtr);
+        helpers.push(AirRuntimeHelper::FreeHeapPtr);
+    }
+    if needs_release {
+        helpers.push(AirRuntimeHelper::ReleaseHeapPtr);
+    }
+    if needs_deepcopy {
+        helpers.push(AirRuntimeHelper::DeepCopyHeapPtr);
+        helpers.push(AirRuntimeHelper::MemcpyHelper);
+    }
+    if needs_release_str {
+        helpers.push(AirRuntimeHelper::ReleaseStrPtr);
+    }
+    if needs_copy_str {
+        helpers.push(AirRuntimeHelper::CopyStrPtr);
+    }
+    if needs_alloc_str {
+        helpers.push(AirRuntimeHelper::AllocStrPtr);
+    }
+    if needs_concat_str {
+        helpers.push(AirRuntimeHelper::ConcatStrPtr);
+    }
+    if needs_slice_str {
+        helpers.push(AirRuntimeHelper::SliceStrPtr);
+    }
+    if needs_cmp_str {
+        helpers.push(AirRuntimeHelper::CmpStrPtr);
+    }
+    if needs_cstr_to_str {
+        helpers.push(AirRuntimeHelper::CStrToStrPtr);
+    }
+    if needs_process_args {
+        helpers.push(AirRuntimeHelper::ProcessArgs);
+    }
+    if needs_release_array {
+        helpers.push(AirRuntimeHelper::ReleaseArrayPtr);
+        helpers.push(AirRuntimeHelper::ReleaseArrayElemPtr);
+    }
+    if needs_copy_array {
+        helpers.push(AirRuntimeHelper::CopyArrayPtr);
+    }
+    if needs_map {
+        helpers.extend(MAP_HELPERS);
+    }
+    if needs_chan {
+        helpers.extend(CHAN_HELPERS);
+    }
+    if needs_thread {
+        helpers.push(AirRuntimeHelper::ThreadNewPtr);
+    }
+    helpers
+}
+
+/// The instructions a backend's function emitter supplies so releasing and
+/// copying values can be written once for every target.
+pub trait ReleaseCopy {
+    /// Calls `helper` with the value bound to `name` as its only argument;
+    /// `note` becomes the comment on the call.
+    fn call_helper_on(
+        &mut self,
+        helper: AirRuntimeHelper,
+        name: &str,
+        note: &str,
+    ) -> Result<(), Error>;
+
+    /// Stores what the last helper call returned into `dst`.
+    fn store_helper_result(&mut self, dst: &str) -> Result<(), Error>;
+
+    /// Moves the pending result somewhere the helpers leave alone.
+    fn keep_result(&mut self) -> Result<(), Error>;
+
+    /// Moves the result put aside by [`ReleaseCopy::keep_result`] back.
+    fn restore_result(&mut self) -> Result<(), Error>;
+
+    /// Releases the closure `name` together with everything it holds, through
+    /// the release helper its metadata names. The helper pins its own
+    /// environment, so the pinned one and a kept result survive the call.
+    fn emit_release_closure(&mut self, name: &str) -> Result<(), Error>;
+
+    /// Releases the strings in `release` and the closures in `untaken`
+    /// after an operation, keeping its result when it has one.
+    fn emit_releases(
+        &mut self,
+        release: &[String],
+        untaken: &[&str],
+        has_result: bool,
+    ) -> Result<(), Error> {
+        if release.is_empty() && untaken.is_empty() {
+            return Ok(());
+        }
+        if has_result {
+            self.keep_result()?;
+        }
+        for name in release {
+            self.emit_release_str_ptr(name)?;
+        }
+        for name in untaken {
+            self.emit_release_closure(name)?;
+        }
+        if has_result {
+            self.restore_result()?;
+        }
+        Ok(())
+    }
+
+    fn emit_release_str_ptr(&mut self, name: &str) -> Result<(), Error> {
+        let note = format!("release {} string", name);
+        self.call_helper_on(AirRuntimeHelper::ReleaseStrPtr, name, &note)
+    }
+
+    fn emit_release_array_ptr(&mut self, name: &str) -> Result<(), Error> {
+        let note = format!("release {} array", name);
+        self.call_helper_on(AirRuntimeHelper::ReleaseArrayPtr, name, &note)
+    }
+
+    fn emit_release_map_ptr(&mut self, name: &str) -> Result<(), Error> {
+        let note = format!("release {} map", name);
+        self.call_helper_on(AirRuntimeHelper::MapReleasePtr, name, &note)
+    }
+
+    fn emit_release_chan_ptr(&mut self, name: &str) -> Result<(), Error> {
+        let note = format!("drop a reference to {} channel", name);
+        self.call_helper_on(AirRuntimeHelper::ChanReleasePtr, name, &note)
+    }
+
+    fn emit_copy_str(&mut self, copy: &AirCopyStr) -> Result<(), Error> {
+        let note = format!("copy {} string", copy.src);
+        self.call_helper_on(AirRuntimeHelper::CopyStrPtr, &copy.src, &note)?;
+        self.store_helper_result(&copy.dst)
+    }
+
+    fn emit_copy_array(&mut self, copy: &AirCopyArray) -> Result<(), Error> {
+        let note = format!("copy {} array", copy.src);
+        self.call_helper_on(AirRuntimeHelper::CopyArrayPtr, &copy.src, &note)?;
+        self.store_helper_result(&copy.dst)
+    }
+
+    fn emit_copy_map(&mut self, copy: &AirCopyMap) -> Result<(), Error> {
+        let note = format!("copy {} map", copy.src);
+        self.call_helper_on(AirRuntimeHelper::MapCopyPtr, &copy.src, &note)?;
+        self.store_helper_result(&copy.dst)
+    }
+
+    fn emit_copy_chan(&mut self, copy: &AirCopyChan) -> Result<(), Error> {
+        let note = format!("another reference to {} channel", copy.src);
+        self.call_helper_on(AirRuntimeHelper::ChanCopyPtr, &copy.src, &note)?;
+        self.store_helper_result(&copy.dst)
+    }
+}
+
+impl fmt::Display for Arch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.triple())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_linux_triples() {
+        for (triple, arch) in [
+            ("x86_64-linux-gnu", Some(Arch::X86_64)),
+            ("x86_64-unknown-linux-gnu", Some(Arch::X86_64)),
+            ("amd64-linux", Some(Arch::X86_64)),
+            ("aarch64-linux-gnu", Some(Arch::Aarch64)),
+            ("arm64-unknown-linux", Some(Arch::Aarch64)),
+            ("aarch64-apple-darwin", None),
+            ("x86_64-linux-musl", None),
+            ("riscv64-linux-gnu", None),
+            ("aarch64", None),
+        ] {
+            assert_eq!(Arch::from_triple(triple), arch, "for `{triple}`");
+        }
+        assert_eq!(
+            Arch::from_triple(Arch::Aarch64.triple()),
+            Some(Arch::Aarch64)
+        );
+    }
+}
@@ -17,6 +17,7 @@ pub enum TokenKind {
     Eof,
     Ident(String),
     Import(String),
+    ImportPath(String),
     IntLiteral(i64),
     FloatLiteral(f64),
     StringLiteral(String),
@@ -0,0 +1,126 @@
+//! Turns emitted assembly into an object file or executable, either with the
+//! built-in assembler and ELF writer or with the system `nasm` or GNU binutils.
+
+use std::io;
+use std::path::Path;
+use std::process::Command;
+
+use crate::compiler::air::ENTRY_FUNCTION_NAME;
+use crate::compiler::error::{Code, Error};
+use crate::compiler::span::Span;
+use crate::compiler::target::Arch;
+use crate::compiler::{assembler, elf};
+
+const NASM_HELP: &str = "drop `--nasm` to use the built-in assembler";
+
+/// Assembles `asm` into the bytes of a relocatable ELF object.
+pub fn object(asm: &str) -> Result<Vec<u8>, Error> {
+    Ok(elf::write_relocatable(&assembler::assemble(asm)?))
+}
+
+/// Assembles `asm` into the bytes of an executable that is linked against libc
+/// when it calls into it.
+pub fn executable(asm: &str) -> Result<Vec<u8>, Error> {
+    elf::write_executable(&assembler::assemble(asm)?, ENTRY_FUNCTION_NAME)
+}
+
+/// Assembles the file `asm` into `object` with `nasm`.
+pub fn nasm(asm: &Path, object: &Path) -> Result<(), Error> {
+    run(
+        Command::new("nasm")
+            .arg("-felf64")
+            .arg(asm)
+            .arg("-o")
+            .arg(object),
+        NASM_HELP,
+    )
+}
+
+/// Assembles `asm` into `object` with `nasm`, then links `object` against libc into `exe`.
+pub fn assemble_and_link(asm: &Path, object: &Path, exe: &Path) -> Result<(), Error> {
+    nasm(asm, object)?;
+    link(Arch::X86_64, object, exe, NASM_HELP)
+}
+
+/// Assembles the GNU as file `asm` for `arch` into `object`. Targets other
+/// than the host use the `<triple>-as` cross assembler.
+pub fn gnu_as(arch: Arch, asm: &Path, object: &Path) -> Result<(), Error> {
+    run(
+        Command::new(binutil(arch, "as"))
+            .arg(asm)
+            .arg("-o")
+            .arg(object),
+        &binutils_help(arch),
+    )
+}
+
+/// Assembles `asm` for `arch` with GNU as, then links `object` against libc
+/// into `exe`.
+pub fn gnu_assemble_and_link(
+    arch: Arch,
+    asm: &Path,
+    object: &Path,
+    exe: &Path,
+) -> Result<(), Error> {
+    gnu_as(arch, asm, object)?;
+    link(arch, object, exe, &binutils_help(arch))
+}
+
+fn link(arch: Arch, object: &Path, exe: &Path, help: &str) -> Result<(), Error> {
+    let dynamic_linker = match arch {
+        Arch::X86_64 => "/lib64/ld-linux-x86-64.so.2",
+        Arch::Aarch64 => "/lib/ld-linux-aarch64.so.1",
+    };
+    run(
+        Command::new(binutil(arch, "ld"))
+            .arg("-dynamic-linker")
+            .arg(dynamic_linker)
+            .arg("-lc")
+            .arg(object)
+            .arg("-o")
+            .arg(exe),
+        help,
+    )
+}
+
+fn binutil(arch: Arch, tool: &str) -> String {
+    if arch == Arch::host() {
+        tool.to_string()
+    } else {
+        format!("{}-{tool}", arch.triple())
+    }
+}
+
+fn binutils_help(arch: Arch) -> String {
+    format!("building for {arch} needs the GNU binutils for it")
+}
+
+fn run(cmd: &mut Command, help: &str) -> Result<(), Error> {
+    let program = cmd.get_program().to_string_lossy().into_owned();
+    let output = cmd.output().map_err(|err| {
+        let not_found = err.kind() == io::ErrorKind::NotFound;
+        let err = Error::new(
+            Code::Toolchain,
+            format!("failed to start `{program}`: {err}"),
+            Span::unknown(),
+        );
+        if not_found {
+            err.with_help(format!("install `{program}`; {help}"))
+        } else {
+            err
+        }
+    })?;
+    if output.status.success() {
+        return Ok(());
+    }
+
+    let mut err = Error::new(
+        Code::Toolchain,
+        format!("`{program}` failed ({})", output.status),
+        Span::unknown(),
+    );
+    for line in String::from_utf8_lossy(&output.stderr).lines() {
+        err = err.with_note(line.to_string());
+    }
+    Err(err)
+}
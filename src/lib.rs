@@ -1,5 +1,4 @@
 pub mod compiler;
-pub mod debug_tools;
 
 pub use compiler::compile;
 pub use compiler::error::{Code, Error};
@@ -77,6 +76,7 @@ pub fn sanitize_function_name(name: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::compile;
+    use crate::compiler::span::Span;
     use std::io::Cursor;
 
     #[test]
@@ -128,7 +128,7 @@ main: () {
             .expect("compiler produced asm");
         let asm = String::from_utf8(output).expect("valid utf8");
         assert!(asm.contains("global _start"));
-        assert!(asm.contains("extern sprintf"));
+        assert!(asm.contains("extern snprintf"));
         assert!(asm.contains("extern write"));
     }
 
@@ -172,4 +172,65 @@ main: () {
             .expect_err("puts should no longer be a builtin import");
         assert!(err.to_string().contains("@puts"));
     }
+
+    #[test]
+    fn report_independent_errors_in_one_run() {
+        let source = r#"
+exit: @exit
+foo: () {
+    printf("hello", exit(0))
+}
+bar: () {
+    puts("hello", exit(0))
+}
+main: () {
+    foo()
+}
+        "#;
+        let mut output = Vec::new();
+        let diagnostics = compile(Cursor::new(source.as_bytes()), "main", &mut output)
+            .expect_err("both undefined names should be reported");
+        assert_eq!(diagnostics.len(), 2, "unexpected errors: {diagnostics}");
+        let mut sources = crate::compiler::source_map::SourceMap::new();
+        sources.add("main.rgo", source);
+        let rendered = diagnostics.render(&sources);
+        assert!(rendered.contains("`printf` is not defined"));
+        assert!(rendered.contains("import it with `printf: @printf`"));
+        assert!(rendered.contains("`puts` is not defined"));
+    }
+
+    #[test]
+    fn report_undefined_type_at_its_reference() {
+        let source = "exit: @exit\n\nuse: (p: point, ok: ()) {\n    ok()\n}\n\nmain: () {\n    use(1, exit(0))\n}\n";
+        let mut output = Vec::new();
+        let diagnostics = compile(Cursor::new(source.as_bytes()), "main", &mut output)
+            .expect_err("`point` should be reported");
+        let [err] = diagnostics.errors() else {
+            panic!("unexpected errors: {diagnostics}");
+        };
+        assert!(err.message.contains("type `point` is not defined"));
+        let point = Span::new(3, 10, 22).to(Span::new(3, 15, 27));
+        assert!(
+            err.span.same_range(&point),
+            "`point` was reported at {:?}",
+            err.span
+        );
+    }
+
+    #[test]
+    fn underline_errors_after_non_ascii_text() {
+        let source = "write: @write\nmain: () {\n    write(\"héllo\", bar(1))\n}\n";
+        let mut output = Vec::new();
+        let diagnostics = compile(Cursor::new(source.as_bytes()), "main", &mut output)
+            .expect_err("`bar` should be reported");
+        let mut sources = crate::compiler::source_map::SourceMap::new();
+        sources.add("main.rgo", source);
+        let rendered = diagnostics.render(&sources);
+        assert!(
+            rendered.contains(
+                "--> main.rgo:3:20\n  |\n3 |     write(\"héllo\", bar(1))\n  |                    ^^^\n"
+            ),
+            "unexpected rendering: {rendered}"
+        );
+    }
 }
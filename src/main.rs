@@ -1,26 +1,544 @@
-use compiler::compile;
+use compiler::compiler::diagnostic::Diagnostics;
+use compiler::compiler::error::Code;
+use compiler::compiler::module::ModuleReader;
+use compiler::compiler::source_map::SourceMap;
+use compiler::compiler::target::Arch;
+use compiler::compiler::{emit, generate, interpreter, lower_modules, ownership, toolchain, Stage};
 use std::env;
-use std::error::Error;
-use std::fs::File;
-use std::io::{BufReader, BufWriter};
-
-fn main() -> Result<(), Box<dyn Error>> {
-    let mut args = env::args().skip(1);
-    let input = args.next();
-    let target = args.next();
-    let output = args.next();
-    if args.next().is_some() {
-        return Err("expected exactly three arguments: <input> <target> <output>".into());
-    }
-
-    let (input_path, target, output_path) = match (input, target, output) {
-        (Some(input), Some(target), Some(output)) => (input, target, output),
-        _ => return Err("compiler requires <input> <target> <output>".into()),
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Cursor, IsTerminal, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{self, Command};
+
+const USAGE: &str = "\
+usage: rgo <command> [options] <input.rgo | ->
+
+commands:
+    build   compile to an executable
+    run     build to a temporary executable and run it; arguments after `--` are passed on
+    check   report errors without writing anything
+    emit    write the program after one stage (default: asm)
+
+options:
+    -o <path>                 output path (build: the input without .rgo, or a.out for stdin,
+                              with .o under -c; emit: stdout)
+    -c                        build: write a relocatable object instead of an executable
+    --nasm                    build, run: assemble and link with the system nasm and ld
+    --interp                  run: interpret the program's AIR instead of building it
+    --check-memory            check: also prove every closure is released exactly once;
+                              run --interp: also report closures still live at exit
+    --triple <triple>         machine to compile for: x86_64-linux-gnu or aarch64-linux-gnu
+                              (default: the host); other machines need GNU binutils
+                              for the triple, and `run` uses qemu-user for them
+    -t, --target <name>       function the program starts in (default: main)
+    -I, --search-path <dir>   also look for source imports in <dir>
+    --stage <ast|hir|air|asm> stage to emit
+    -h, --help                print this help
+
+An input of `-` reads the program from stdin.
+
+exit status: 0 on success, 1 on compile errors, 2 on usage errors, 3 when reading input,
+writing output, assembling or linking fails. `run` exits with the program's status, or 1
+when `--interp` stops it on a runtime error.";
+
+const EXIT_COMPILE: i32 = 1;
+const EXIT_USAGE: i32 = 2;
+const EXIT_TOOLCHAIN: i32 = 3;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Subcommand {
+    Build,
+    Run,
+    Check,
+    Emit,
+}
+
+enum Input {
+    File(PathBuf),
+    Stdin,
+}
+
+struct Options {
+    command: Subcommand,
+    input: Input,
+    output: Option<PathBuf>,
+    target: String,
+    arch: Arch,
+    search_paths: Vec<PathBuf>,
+    stage: Stage,
+    object: bool,
+    nasm: bool,
+    interp: bool,
+    check_memory: bool,
+    run_args: Vec<String>,
+}
+
+fn main() {
+    let options = match parse_args(env::args().skip(1)) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            println!("{USAGE}");
+            return;
+        }
+        Err(message) => {
+            eprintln!("rgo: {message}");
+            eprintln!("run `rgo --help` for usage");
+            process::exit(EXIT_USAGE);
+        }
     };
 
-    let input = File::open(input_path)?;
-    let mut output = BufWriter::new(File::create(output_path)?);
-    compile(BufReader::new(input), &target, &mut output)?;
+    // Every file read is kept so diagnostics can quote the offending lines.
+    let mut sources = SourceMap::new();
+    match execute(&options, &mut sources) {
+        Ok(status) => process::exit(status),
+        Err(diagnostics) => {
+            eprint!("{}", diagnostics.render(&sources));
+            let toolchain_only = diagnostics
+                .errors()
+                .iter()
+                .all(|err| matches!(err.code, Code::Io | Code::Toolchain));
+            process::exit(if toolchain_only {
+                EXIT_TOOLCHAIN
+            } else {
+                EXIT_COMPILE
+            });
+        }
+    }
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Option<Options>, String> {
+    let command = match args.next().as_deref() {
+        Some("build") => Subcommand::Build,
+        Some("run") => Subcommand::Run,
+        Some("check") => Subcommand::Check,
+        Some("emit") => Subcommand::Emit,
+        Some("-h" | "--help") => return Ok(None),
+        Some(other) => return Err(format!("unknown command `{other}`")),
+        None => return Err("missing command".to_string()),
+    };
 
+    let mut input = None;
+    let mut output = None;
+    let mut target = "main".to_string();
+    let mut arch = Arch::host();
+    let mut search_paths = Vec::new();
+    let mut stage = None;
+    let mut object = false;
+    let mut nasm = false;
+    let mut interp = false;
+    let mut check_memory = false;
+    let mut run_args = Vec::new();
+    while let Some(arg) = args.next() {
+        let (flag, inline_value) = match arg.split_once('=') {
+            Some((flag, value)) if flag.starts_with("--") => (flag, Some(value.to_string())),
+            _ => (arg.as_str(), None),
+        };
+        let mut value = || {
+            inline_value
+                .clone()
+                .or_else(|| args.next())
+                .ok_or_else(|| format!("{flag} expects a value"))
+        };
+        match flag {
+            "-h" | "--help" => return Ok(None),
+            "-o" | "--output" => output = Some(PathBuf::from(value()?)),
+            "-t" | "--target" => target = value()?,
+            "--triple" => {
+                let triple = value()?;
+                arch = Arch::from_triple(&triple).ok_or_else(|| {
+                    format!(
+                        "unknown triple `{triple}`; expected one of {}",
+                        Arch::TRIPLES
+                    )
+                })?;
+            }
+            "-I" | "--search-path" => search_paths.push(PathBuf::from(value()?)),
+            "--stage" => {
+                let name = value()?;
+                let parsed = Stage::from_name(&name).ok_or_else(|| {
+                    format!("unknown stage `{name}`; expected one of {}", Stage::NAMES)
+                })?;
+                stage = Some(parsed);
+            }
+            "-c" => object = true,
+            "--nasm" => nasm = true,
+            "--interp" => interp = true,
+            "--check-memory" => check_memory = true,
+            "--" if command == Subcommand::Run => {
+                run_args.extend(args.by_ref());
+            }
+            "-" => input = set_input(input, Input::Stdin)?,
+            flag if flag.starts_with('-') => return Err(format!("unknown option `{flag}`")),
+            _ => input = set_input(input, Input::File(PathBuf::from(arg)))?,
+        }
+    }
+
+    if stage.is_some() && command != Subcommand::Emit {
+        return Err("--stage only applies to `emit`".to_string());
+    }
+    if object && command != Subcommand::Build {
+        return Err("-c only applies to `build`".to_string());
+    }
+    if nasm && !matches!(command, Subcommand::Build | Subcommand::Run) {
+        return Err("--nasm only applies to `build` and `run`".to_string());
+    }
+    if interp && command != Subcommand::Run {
+        return Err("--interp only applies to `run`".to_string());
+    }
+    if interp && nasm {
+        return Err("--interp does not assemble, so it cannot take --nasm".to_string());
+    }
+    if check_memory && !(command == Subcommand::Check || command == Subcommand::Run && interp) {
+        return Err("--check-memory only applies to `check` and `run --interp`".to_string());
+    }
+    if nasm && arch != Arch::X86_64 {
+        return Err(format!("--nasm only assembles for {}", Arch::X86_64));
+    }
+    if output.is_some() && matches!(command, Subcommand::Run | Subcommand::Check) {
+        return Err("-o does not apply to `run` or `check`".to_string());
+    }
+    let options = Options {
+        command,
+        input: input.ok_or("missing input file; use `-` to read stdin")?,
+        output,
+        target,
+        arch,
+        search_paths,
+        stage: stage.unwrap_or(Stage::Asm),
+        object,
+        nasm,
+        interp,
+        check_memory,
+        run_args,
+    };
+    if let (Subcommand::Build, Input::File(input)) = (options.command, &options.input) {
+        let output = build_output(&options);
+        if same_file(input, &output) {
+            return Err(format!(
+                "building `{}` would overwrite it; name the output with -o",
+                input.display()
+            ));
+        }
+    }
+    Ok(Some(options))
+}
+
+/// Where `build` writes: the `-o` path, or else the input with its extension
+/// swapped for the output's.
+fn build_output(options: &Options) -> PathBuf {
+    let extension = if options.object { "o" } else { "" };
+    options
+        .output
+        .clone()
+        .unwrap_or_else(|| match &options.input {
+            Input::File(path) => path.with_extension(extension),
+            Input::Stdin if options.object => PathBuf::from("a.o"),
+            Input::Stdin => PathBuf::from("a.out"),
+        })
+}
+
+fn same_file(a: &Path, b: &Path) -> bool {
+    a == b
+        || matches!(
+            (fs::canonicalize(a), fs::canonicalize(b)),
+            (Ok(a), Ok(b)) if a == b
+        )
+}
+
+fn set_input(current: Option<Input>, input: Input) -> Result<Option<Input>, String> {
+    match current {
+        Some(_) => Err("expected a single input file".to_string()),
+        None => Ok(Some(input)),
+    }
+}
+
+fn execute(options: &Options, sources: &mut SourceMap) -> Result<i32, Diagnostics> {
+    let reader = open_input(options, sources)?;
+    match options.command {
+        Subcommand::Check => {
+            let functions = lower_modules(reader, sources, &options.target)?;
+            if options.check_memory {
+                ownership::check(&functions)?;
+            }
+            generate(functions, options.arch, &mut io::sink())?;
+            Ok(0)
+        }
+        Subcommand::Emit => {
+            let mut out: Box<dyn Write> = match &options.output {
+                Some(path) => Box::new(BufWriter::new(File::create(path)?)),
+                None => Box::new(BufWriter::new(io::stdout().lock())),
+            };
+            emit(
+                reader,
+                sources,
+                &options.target,
+                options.arch,
+                options.stage,
+                &mut out,
+            )?;
+            out.flush()?;
+            Ok(0)
+        }
+        Subcommand::Build => {
+            build(reader, sources, options, &build_output(options))?;
+            Ok(0)
+        }
+        Subcommand::Run if options.interp => {
+            let functions = lower_modules(reader, sources, &options.target)?;
+            let mut stdout = io::stdout().lock();
+            let interp_options = interpreter::Options {
+                line_buffered: stdout.is_terminal(),
+                check_memory: options.check_memory,
+                args: interpreted_args(options),
+            };
+            Ok(interpreter::run_with(
+                &functions,
+                interp_options,
+                &mut stdout,
+            )?)
+        }
+        Subcommand::Run => {
+            let work_dir = WorkDir::create()?;
+            let exe = work_dir.path.join("main");
+            build(reader, sources, options, &exe)?;
+            let status = run_command(options.arch, &exe)
+                .args(&options.run_args)
+                .status()?;
+            Ok(exit_code(status))
+        }
+    }
+}
+
+/// The command line an interpreted program sees: its source path in place
+/// of an executable, then the arguments after `--`.
+fn interpreted_args(options: &Options) -> Vec<String> {
+    let program = match &options.input {
+        Input::File(path) => path.display().to_string(),
+        Input::Stdin => "-".to_string(),
+    };
+    std::iter::once(program)
+        .chain(options.run_args.iter().cloned())
+        .collect()
+}
+
+fn open_input<'a>(
+    options: &Options,
+    sources: &mut SourceMap,
+) -> Result<ModuleReader<'a>, Diagnostics> {
+    let search_paths = options.search_paths.clone();
+    match &options.input {
+        Input::File(path) => Ok(ModuleReader::open(path, search_paths, sources)?),
+        Input::Stdin => {
+            let mut source = String::new();
+            io::stdin().read_to_string(&mut source)?;
+            let file = sources.add("<stdin>", source.clone());
+            Ok(ModuleReader::new(
+                Cursor::new(source.into_bytes()),
+                file,
+                search_paths,
+            ))
+        }
+    }
+}
+
+/// Writes the executable, or the object under `-c`, to `path`.
+fn build(
+    reader: ModuleReader<'_>,
+    sources: &mut SourceMap,
+    options: &Options,
+    path: &Path,
+) -> Result<(), Diagnostics> {
+    let mut asm = Vec::new();
+    emit(
+        reader,
+        sources,
+        &options.target,
+        options.arch,
+        Stage::Asm,
+        &mut asm,
+    )?;
+    if !options.arch.has_builtin_assembler() {
+        let work_dir = WorkDir::create_for("as")?;
+        let asm_path = work_dir.path.join("main.s");
+        fs::write(&asm_path, asm)?;
+        if options.object {
+            toolchain::gnu_as(options.arch, &asm_path, path)?;
+        } else {
+            let object = work_dir.path.join("main.o");
+            toolchain::gnu_assemble_and_link(options.arch, &asm_path, &object, path)?;
+        }
+        return Ok(());
+    }
+    if options.nasm {
+        let work_dir = WorkDir::create_for("nasm")?;
+        let asm_path = work_dir.path.join("main.asm");
+        fs::write(&asm_path, asm)?;
+        if options.object {
+            toolchain::nasm(&asm_path, path)?;
+        } else {
+            toolchain::assemble_and_link(&asm_path, &work_dir.path.join("main.o"), path)?;
+        }
+        return Ok(());
+    }
+
+    let asm = String::from_utf8_lossy(&asm);
+    if options.object {
+        fs::write(path, toolchain::object(&asm)?)?;
+    } else {
+        fs::write(path, toolchain::executable(&asm)?)?;
+        make_executable(path)?;
+    }
     Ok(())
 }
+
+/// Runs `exe` directly on its own machine, or under qemu-user with the
+/// cross libc from the usual Debian location otherwise.
+fn run_command(arch: Arch, exe: &Path) -> Command {
+    if arch == Arch::host() {
+        return Command::new(exe);
+    }
+    let qemu = match arch {
+        Arch::X86_64 => "qemu-x86_64",
+        Arch::Aarch64 => "qemu-aarch64",
+    };
+    let mut cmd = Command::new(qemu);
+    cmd.arg("-L")
+        .arg(format!("/usr/{}", arch.triple()))
+        .arg(exe);
+    cmd
+}
+
+#[cfg(unix)]
+fn make_executable(path: &Path) -> io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    fs::set_permissions(path, fs::Permissions::from_mode(0o755))
+}
+
+#[cfg(not(unix))]
+fn make_executable(_path: &Path) -> io::Result<()> {
+    Ok(())
+}
+
+#[cfg(unix)]
+fn exit_code(status: process::ExitStatus) -> i32 {
+    use std::os::unix::process::ExitStatusExt;
+    status
+        .code()
+        .or_else(|| status.signal().map(|signal| 128 + signal))
+        .unwrap_or(1)
+}
+
+#[cfg(not(unix))]
+fn exit_code(status: process::ExitStatus) -> i32 {
+    status.code().unwrap_or(1)
+}
+
+/// Scratch directory for intermediate files, removed when dropped.
+struct WorkDir {
+    path: PathBuf,
+}
+
+impl WorkDir {
+    fn create() -> io::Result<Self> {
+        Self::create_for("run")
+    }
+
+    fn create_for(purpose: &str) -> io::Result<Self> {
+        let path = env::temp_dir().join(format!("rgo-{purpose}-{}", process::id()));
+        fs::create_dir_all(&path)?;
+        Ok(Self { path })
+    }
+}
+
+impl Drop for WorkDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &str) -> Result<Option<Options>, String> {
+        parse_args(args.split_whitespace().map(str::to_string))
+    }
+
+    #[test]
+    fn parses_subcommands_and_options() {
+        let options = parse("emit --stage=hir -I lib -t start -o out.hir main.rgo")
+            .expect("valid arguments")
+            .expect("not a help request");
+        assert!(options.command == Subcommand::Emit);
+        assert!(matches!(&options.input, Input::File(path) if path == Path::new("main.rgo")));
+        assert_eq!(options.stage, Stage::Hir);
+        assert_eq!(options.target, "start");
+        assert_eq!(options.search_paths, [PathBuf::from("lib")]);
+        assert_eq!(options.output, Some(PathBuf::from("out.hir")));
+        assert_eq!(options.arch, Arch::host());
+
+        let options = parse("build --triple aarch64-unknown-linux-gnu main.rgo")
+            .expect("valid")
+            .expect("options");
+        assert_eq!(options.arch, Arch::Aarch64);
+
+        let options = parse("run --interp --check-memory main.rgo")
+            .expect("valid")
+            .expect("options");
+        assert!(options.interp && options.check_memory);
+
+        let options = parse("run - -- a -o b").expect("valid").expect("options");
+        assert!(matches!(options.input, Input::Stdin));
+        assert_eq!(options.run_args, ["a", "-o", "b"]);
+    }
+
+    #[test]
+    fn rejects_misused_options() {
+        for (args, message) in [
+            ("", "missing command"),
+            ("build", "missing input file; use `-` to read stdin"),
+            ("build a.rgo b.rgo", "expected a single input file"),
+            ("check --stage asm a.rgo", "--stage only applies to `emit`"),
+            (
+                "emit --stage=mir a.rgo",
+                "unknown stage `mir`; expected one of ast|hir|air|asm",
+            ),
+            ("build -o", "-o expects a value"),
+            ("run -c a.rgo", "-c only applies to `build`"),
+            (
+                "emit --nasm a.rgo",
+                "--nasm only applies to `build` and `run`",
+            ),
+            ("build --interp a.rgo", "--interp only applies to `run`"),
+            (
+                "run --check-memory a.rgo",
+                "--check-memory only applies to `check` and `run --interp`",
+            ),
+            (
+                "run --interp --nasm a.rgo",
+                "--interp does not assemble, so it cannot take --nasm",
+            ),
+            (
+                "build --triple riscv64-linux-gnu a.rgo",
+                "unknown triple `riscv64-linux-gnu`; expected one of x86_64-linux-gnu|aarch64-linux-gnu",
+            ),
+            (
+                "run --nasm --triple=aarch64-linux-gnu a.rgo",
+                "--nasm only assembles for x86_64-linux-gnu",
+            ),
+            (
+                "build prog",
+                "building `prog` would overwrite it; name the output with -o",
+            ),
+            (
+                "build -c -o a.rgo a.rgo",
+                "building `a.rgo` would overwrite it; name the output with -o",
+            ),
+        ] {
+            match parse(args) {
+                Err(err) => assert_eq!(err, message, "for `{args}`"),
+                Ok(_) => panic!("`{args}` should be rejected"),
+            }
+        }
+    }
+}
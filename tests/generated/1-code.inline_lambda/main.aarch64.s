@@ -0,0 +1,461 @@
+.text
+.p2align 2
+.global _10_main
+_10_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    // load exit code
+    mov x0, #0 // operand literal
+    bl exit // call libc exit to flush buffers
+.global alloc_heap_ptr
+alloc_heap_ptr:
+    add x0, x0, #7 // round size up to whole words
+    and x0, x0, #-8
+    cmp x0, #1024 // small block?
+    b.hi alloc_heap_ptr_large
+    adrp x9, heap_free_lists
+    add x9, x9, :lo12:heap_free_lists
+    ldr x10, [x9, x0] // head of this size's free list
+    cbz x10, alloc_heap_ptr_bump
+    ldr x11, [x10] // next free block
+    str x11, [x9, x0] // pop the head
+    mov x0, x10
+    ret
+alloc_heap_ptr_bump:
+    adrp x9, heap_arena
+    add x9, x9, :lo12:heap_arena
+    ldp x10, x11, [x9] // arena next and end
+    add x12, x10, x0 // end of the new block
+    cmp x12, x11
+    b.hi alloc_heap_ptr_refill
+    str x12, [x9] // bump past the block
+    mov x0, x10
+    ret
+alloc_heap_ptr_refill:
+    mov x13, x0 // keep block size across the syscall
+    mov x1, #1048576 // arena size
+    mov x0, #0 // addr hint so kernel picks mmap base
+    mov x2, #3 // prot = read/write
+    mov x3, #34 // flags = private & anonymous
+    mov x4, #-1 // fd = -1
+    mov x5, #0 // offset = 0
+    mov x8, #222 // mmap syscall
+    svc #0 // map a new arena
+    cmn x0, #4095 // mmap failed?
+    b.hs alloc_heap_ptr_failed
+    add x12, x0, x13 // block is the start of the arena
+    mov x11, #1048576
+    add x11, x0, x11 // end of the arena
+    stp x12, x11, [x9]
+    ret
+alloc_heap_ptr_large:
+    mov x1, x0 // length = block size
+    mov x0, #0 // addr hint so kernel picks mmap base
+    mov x2, #3 // prot = read/write
+    mov x3, #34 // flags = private & anonymous
+    mov x4, #-1 // fd = -1
+    mov x5, #0 // offset = 0
+    mov x8, #222 // mmap syscall
+    svc #0 // map a large block on its own
+    cmn x0, #4095 // mmap failed?
+    b.hs alloc_heap_ptr_failed
+    ret
+alloc_heap_ptr_failed:
+    mov x0, #0 // no memory left
+    ret
+.data
+.p2align 3
+heap_free_lists:
+    .zero 1032
+heap_arena:
+    .quad 0, 0
+.text
+.global free_heap_ptr
+free_heap_ptr:
+    add x1, x1, #7 // round size up to whole words
+    and x1, x1, #-8
+    cmp x1, #1024 // small block?
+    b.hi free_heap_ptr_large
+    adrp x9, heap_free_lists
+    add x9, x9, :lo12:heap_free_lists
+    ldr x10, [x9, x1] // current head
+    str x10, [x0] // link it behind the block
+    str x0, [x9, x1] // block is the new head
+    ret
+free_heap_ptr_large:
+    mov x8, #215 // munmap syscall
+    svc #0
+    ret
+.global release_heap_ptr
+release_heap_ptr:
+    stp x29, x30, [sp, #-16]! // save caller frame
+    mov x29, sp // establish frame
+    ldr x11, [x0, #24] // load env size metadata
+    ldr x12, [x0, #32] // load heap size metadata
+    sub x0, x0, x11 // compute env base pointer
+    mov x1, x12 // heap size to free
+    bl free_heap_ptr // return env to the allocator
+    ldp x29, x30, [sp], #16
+    ret
+.global _10_main_unwrapper
+_10_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _10_main
+.global _10_main_deep_release
+_10_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _10_main_deepcopy
+_10_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global release_str_ptr
+release_str_ptr:
+    cbz x0, release_str_ptr_done // nothing to free
+    ldr x1, [x0, #-16] // allocation size header
+    cbz x1, release_str_ptr_done // literals have no allocation
+    sub x0, x0, #16 // block starts at the header
+    b free_heap_ptr // return string to the allocator
+release_str_ptr_done:
+    ret
+.global _8_main
+_8_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store s arg in frame
+    mov x0, #48 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x9, #0 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #48 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _10_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_10_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _10_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_10_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _10_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_10_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _10_main closure env_end to x9
+    stur x9, [x29, #-16] // store value
+    ldur x13, [x29, #-8] // load operand
+    ldr x2, [x13, #-8] // length to write
+    mov x1, x13 // buffer start
+    mov x0, #1 // stdout fd
+    bl write // invoke libc write
+    ldur x0, [x29, #-8] // load operand
+    bl release_str_ptr // release s string
+    ldur x19, [x29, #-16] // load continuation env_end pointer
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _8_main_unwrapper
+_8_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-8] // load s env field
+    stur x9, [x29, #-16] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _8_main
+.global _8_main_deep_release
+_8_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _8_main_release_skip_0
+    ldur x9, [x19, #-8] // load _8_main_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl release_str_ptr // release _8_main_release_field_0 string
+_8_main_release_skip_0:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global copy_str_ptr
+copy_str_ptr:
+    cbz x0, copy_str_ptr_done
+    ldr x1, [x0, #-16] // allocation size header
+    cbz x1, copy_str_ptr_done // literals are never freed
+    stp x29, x30, [sp, #-16]! // save caller frame
+    mov x29, sp // establish frame
+    stp x20, x21, [sp, #-16]! // preserve callee-saved registers
+    sub x20, x0, #16 // source block, header included
+    mov x21, x1 // allocation size
+    mov x0, x1
+    bl alloc_heap_ptr // allocate the copy
+    mov x1, x20 // memcpy src
+    mov x2, x21 // memcpy length
+    mov x20, x0 // copy block
+    bl memcpy_helper // duplicate header and bytes
+    add x0, x20, #16 // copied string starts after its header
+    ldp x20, x21, [sp], #16
+    ldp x29, x30, [sp], #16
+copy_str_ptr_done:
+    ret
+.global memcpy_helper
+memcpy_helper:
+    mov x11, #0 // counter = 0
+internal_memcpy_loop:
+    cmp x11, x2 // counter < count?
+    b.ge internal_memcpy_done
+    ldr x9, [x1, x11] // load 8 bytes from source
+    str x9, [x0, x11] // store 8 bytes to destination
+    add x11, x11, #8 // advance counter by 8
+    b internal_memcpy_loop
+internal_memcpy_done:
+    ret
+.global _8_main_deepcopy
+_8_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _8_main_deepcopy_skip_0
+    ldur x0, [x19, #-8] // load field pointer
+    bl copy_str_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_8_main_deepcopy_skip_0:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _5_main
+_5_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store x arg in frame
+    mov x0, #56 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #8 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #8 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #56 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _8_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_8_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _8_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_8_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _8_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_8_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _8_main closure env_end to x9
+    stur x9, [x29, #-16] // store value
+    adrp x2, _6 // point to string literal
+    add x2, x2, :lo12:_6
+    ldur x3, [x29, #-8] // load operand
+    mov x0, #0 // measure only
+    mov x1, #0
+    bl snprintf // invoke libc snprintf
+    mov x20, x0 // formatted length
+    add x0, x20, #17 // header, bytes and terminator
+    bl alloc_heap_ptr // allocate the string
+    mov x21, x0 // string block
+    add x9, x20, #17
+    str x9, [x21] // allocation size header
+    str x20, [x21, #8] // length header
+    adrp x2, _6 // point to string literal
+    add x2, x2, :lo12:_6
+    ldur x3, [x29, #-8] // load operand
+    add x0, x21, #16 // string bytes follow the header
+    add x1, x20, #1 // room for the terminator
+    bl snprintf // invoke libc snprintf
+    add x9, x21, #16 // return formatted string pointer
+    ldur x19, [x29, #-16] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _5_main_unwrapper
+_5_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-8] // load x env field
+    stur x9, [x29, #-16] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _5_main
+.global _5_main_deep_release
+_5_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _5_main_deepcopy
+_5_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global main
+main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    mov x0, #56 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x9, #42 // operand literal
+    str x9, [x20, #0] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #8 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #8 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #56 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _5_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_5_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _5_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_5_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _5_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_5_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _15_main closure env_end to x9
+    stur x9, [x29, #-8] // store value
+    ldur x20, [x29, #-8] // load _15_main closure env_end pointer
+    mov x0, x20 // pass env_end pointer to closure
+    ldr x9, [x0, #0] // load closure unwrapper entry point
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9 // tail call into closure
+.global main_unwrapper
+main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b main
+.global main_deep_release
+main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global main_deepcopy
+main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _start
+_start:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b main
+.section .rodata
+.p2align 3
+    .quad 0, 17 // static strings have no allocation to free
+_6:
+    .asciz "the answer is: %d"
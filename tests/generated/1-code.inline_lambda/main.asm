@@ -6,8 +6,87 @@ _10_main:
     push rbp ; save executor frame pointer
     mov rbp, rsp ; establish new frame base
     ; load exit code
-    mov rdi, 0 ; exit code
+    mov rdi, 0 ; operand literal
     call exit ; call libc exit to flush buffers
+global alloc_heap_ptr
+alloc_heap_ptr:
+    add rdi, 7 ; round size up to whole words
+    and rdi, -8
+    cmp rdi, 1024 ; small block?
+    ja alloc_heap_ptr_large
+    lea rcx, [heap_free_lists] ; free list heads by size
+    mov rax, [rcx+rdi] ; head of this size's free list
+    test rax, rax
+    jz alloc_heap_ptr_bump
+    mov rdx, [rax] ; next free block
+    mov [rcx+rdi], rdx ; pop the head
+    ret
+alloc_heap_ptr_bump:
+    lea rcx, [heap_arena] ; arena next and end pointers
+    mov rax, [rcx] ; next free byte of the arena
+    mov rdx, rax
+    add rdx, rdi ; end of the new block
+    mov r8, [rcx+8] ; end of the arena
+    cmp rdx, r8
+    ja alloc_heap_ptr_refill
+    mov [rcx], rdx ; bump past the block
+    ret
+alloc_heap_ptr_refill:
+    push rdi ; keep block size across the syscall
+    mov rsi, 1048576 ; arena size
+    xor rdi, rdi ; addr hint so kernel picks mmap base
+    mov rdx, 3 ; prot = read/write
+    mov r10, 34 ; flags = private & anonymous
+    mov r8, -1 ; fd = -1
+    xor r9, r9 ; offset = 0
+    mov rax, 9 ; mmap syscall
+    syscall ; map a new arena
+    pop rdi
+    cmp rax, -4095 ; mmap failed?
+    jae alloc_heap_ptr_failed
+    lea rcx, [heap_arena] ; arena next and end pointers
+    lea rdx, [rax+rdi] ; block is the start of the arena
+    mov [rcx], rdx
+    mov rdx, rax
+    add rdx, 1048576 ; end of the arena
+    mov [rcx+8], rdx
+    ret
+alloc_heap_ptr_large:
+    mov rsi, rdi ; length = block size
+    xor rdi, rdi ; addr hint so kernel picks mmap base
+    mov rdx, 3 ; prot = read/write
+    mov r10, 34 ; flags = private & anonymous
+    mov r8, -1 ; fd = -1
+    xor r9, r9 ; offset = 0
+    mov rax, 9 ; mmap syscall
+    syscall ; map a large block on its own
+    cmp rax, -4095 ; mmap failed?
+    jae alloc_heap_ptr_failed
+    ret
+alloc_heap_ptr_failed:
+    xor eax, eax ; no memory left
+    ret
+section .data
+heap_free_lists:
+    times 129 dq 0
+heap_arena:
+    dq 0, 0
+section .text
+global free_heap_ptr
+free_heap_ptr:
+    add rsi, 7 ; round size up to whole words
+    and rsi, -8
+    cmp rsi, 1024 ; small block?
+    ja free_heap_ptr_large
+    lea rcx, [heap_free_lists] ; free list heads by size
+    mov rax, [rcx+rsi] ; current head
+    mov [rdi], rax ; link it behind the block
+    mov [rcx+rsi], rdi ; block is the new head
+    ret
+free_heap_ptr_large:
+    mov rax, 11 ; munmap syscall
+    syscall
+    ret
 global release_heap_ptr
 release_heap_ptr:
     push rbp ; save caller frame
@@ -18,9 +97,8 @@ release_heap_ptr:
     mov rdx, [rbx+32] ; load heap size metadata
     mov rdi, rbx
     sub rdi, rcx ; compute env base pointer
-    mov rsi, rdx ; heap size for munmap
-    mov rax, 11 ; munmap syscall
-    syscall
+    mov rsi, rdx ; heap size to free
+    call free_heap_ptr ; return env to the allocator
     pop rbx
     pop rbp
     ret
@@ -57,20 +135,25 @@ _10_main_deepcopy:
     leave
     ret
 
+global release_str_ptr
+release_str_ptr:
+    test rdi, rdi ; nothing to free
+    jz release_str_ptr_done
+    mov rsi, [rdi-16] ; allocation size header
+    test rsi, rsi ; literals have no allocation
+    jz release_str_ptr_done
+    sub rdi, 16 ; block starts at the header
+    jmp free_heap_ptr ; return string to the allocator
+release_str_ptr_done:
+    ret
 global _8_main
 _8_main:
     push rbp ; save executor frame pointer
     mov rbp, rsp ; establish new frame base
     sub rsp, 16 ; reserve stack space for locals
     mov [rbp-8], rdi ; store s arg in frame
-    mov rax, 9 ; mmap syscall
-    xor rdi, rdi ; addr hint for kernel base selection
-    mov rsi, 48 ; length for allocation
-    mov rdx, 3 ; prot = read/write
-    mov r10, 34 ; flags: private & anonymous
-    mov r8, -1 ; fd = -1
-    xor r9, r9 ; offset = 0
-    syscall ; allocate env pages
+    mov rdi, 48 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
     mov rbx, rax ; closure env base pointer
     mov r12, rbx ; env_end pointer before metadata
     mov rax, 0 ; store env size metadata
@@ -89,19 +172,12 @@ _8_main:
     mov rax, [rbp-8] ; load operand
     push rax ; stack arg
     pop rdi ; restore arg into register
-    mov r8, rdi ; keep string pointer
-    xor rcx, rcx ; reset length counter
-_8_main_write_strlen_loop_0:
-    mov dl, byte [r8+rcx] ; load current character
-    cmp dl, 0 ; stop at terminator
-    je _8_main_write_strlen_done_0
-    inc rcx ; advance char counter
-    jmp _8_main_write_strlen_loop_0
-_8_main_write_strlen_done_0:
-    mov rdx, rcx ; length to write
-    mov rsi, r8 ; buffer start
+    mov rdx, [rdi-8] ; length to write
+    mov rsi, rdi ; buffer start
     mov rdi, 1 ; stdout fd
     call write ; invoke libc write
+    mov rdi, [rbp-8] ; load operand
+    call release_str_ptr ; release s string
     mov r12, [rbp-16] ; load continuation env_end pointer
     mov rax, [r12+0] ; load continuation entry point
     mov rdi, r12 ; pass env_end pointer to continuation
@@ -127,21 +203,71 @@ global _8_main_deep_release
 _8_main_deep_release:
     push rbp ; save executor frame pointer
     mov rbp, rsp ; establish new frame base
-    sub rsp, 16 ; reserve stack space for locals
+    sub rsp, 32 ; reserve stack space for locals
     mov [rbp-8], rdi ; store env_end arg in frame
     mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load __num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _8_main_release_skip_0
+    mov rax, [r12-8] ; load _8_main_release_field_0 env field
+    mov [rbp-24], rax ; store value
+    mov rdi, [rbp-24] ; load operand
+    call release_str_ptr ; release _8_main_release_field_0 string
+_8_main_release_skip_0:
     mov rdi, r12 ; use pinned __env_end env_end pointer
     call release_heap_ptr ; release __env_end closure environment
     leave
     ret
 
+global copy_str_ptr
+copy_str_ptr:
+    mov rax, rdi ; share the pointer unless it owns memory
+    test rdi, rdi
+    jz copy_str_ptr_done
+    mov rsi, [rdi-16] ; allocation size header
+    test rsi, rsi ; literals are never freed
+    jz copy_str_ptr_done
+    push rbx ; preserve callee-saved registers
+    push r13
+    push r14
+    mov rbx, rdi ; source string
+    mov r13, rsi ; allocation size
+    mov rdi, rsi
+    call alloc_heap_ptr ; allocate the copy
+    mov r14, rax ; copy block
+    mov rdi, rax ; destination block
+    lea rsi, [rbx-16] ; source block, header included
+    mov rcx, r13 ; bytes to copy
+    cld ; ensure forward copy
+    rep movsb ; duplicate header and bytes
+    lea rax, [r14+16] ; copied string starts after its header
+    pop r14
+    pop r13
+    pop rbx
+copy_str_ptr_done:
+    ret
 global _8_main_deepcopy
 _8_main_deepcopy:
     push rbp ; save executor frame pointer
     mov rbp, rsp ; establish new frame base
-    sub rsp, 16 ; reserve stack space for locals
+    sub rsp, 32 ; reserve stack space for locals
     mov [rbp-8], rdi ; store env_end arg in frame
     mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _8_main_deepcopy_skip_0
+    mov rcx, [r12-8] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call copy_str_ptr ; duplicate heap pointer
+    mov [r12-8], rax ; store duplicated pointer
+    mov [rbp-24], rax ; store value
+_8_main_deepcopy_skip_0:
     leave
     ret
 
@@ -151,14 +277,8 @@ _5_main:
     mov rbp, rsp ; establish new frame base
     sub rsp, 16 ; reserve stack space for locals
     mov [rbp-8], rdi ; store x arg in frame
-    mov rax, 9 ; mmap syscall
-    xor rdi, rdi ; addr hint for kernel base selection
-    mov rsi, 56 ; length for allocation
-    mov rdx, 3 ; prot = read/write
-    mov r10, 34 ; flags: private & anonymous
-    mov r8, -1 ; fd = -1
-    xor r9, r9 ; offset = 0
-    syscall ; allocate env pages
+    mov rdi, 56 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
     mov rbx, rax ; closure env base pointer
     mov r12, rbx ; env_end pointer before metadata
     add r12, 8 ; move pointer past env payload
@@ -179,32 +299,34 @@ _5_main:
     push rax ; stack arg
     lea rax, [rel _6] ; point to string literal
     push rax ; stack arg
-    mov rax, 9 ; mmap syscall
-    xor rdi, rdi ; addr hint for kernel base selection
-    mov rsi, 1024 ; length for allocation
-    mov rdx, 3 ; prot = read/write
-    mov r10, 34 ; flags: private & anonymous
-    mov r8, -1 ; fd = -1
-    xor r9, r9 ; offset = 0
-    syscall ; allocate env pages
-    mov rbx, rax ; keep sprintf buffer pointer
-    pop rdi ; restore arg into register
-    pop rsi ; restore arg into register
-    mov rdx, rsi ; shift sprintf args for buffer insertion
-    mov rsi, rdi ; shift sprintf args for buffer insertion
-    mov rdi, rbx ; destination buffer for sprintf
-    push rbp ; helper prologue
-    mov rbp, rsp
-    push r12
-    mov rax, rsp ; align stack for variadic sprintf call
-    and rax, 15
-    mov r12, rax
-    sub rsp, r12
-    call sprintf ; invoke libc sprintf
-    add rsp, r12
-    pop r12
-    pop rbp
-    mov rax, rbx ; return formatted string pointer
+    mov r13, rsp ; sprintf args in order
+    xor rdi, rdi ; measure only
+    xor rsi, rsi
+    mov r15, rsp ; restore point after snprintf
+    and rsp, -16 ; align stack for variadic call
+    mov rdx, [r13+0] ; snprintf arg 0
+    mov rcx, [r13+8] ; snprintf arg 1
+    mov eax, 0 ; vector registers holding args
+    call snprintf ; invoke libc snprintf
+    mov rsp, r15
+    mov rbx, rax ; formatted length
+    lea rdi, [rbx+17] ; header, bytes and terminator
+    call alloc_heap_ptr ; allocate the string
+    mov r14, rax ; string block
+    lea rax, [rbx+17]
+    mov [r14], rax ; allocation size header
+    mov [r14+8], rbx ; length header
+    lea rdi, [r14+16] ; string bytes follow the header
+    lea rsi, [rbx+1] ; room for the terminator
+    mov r15, rsp ; restore point after snprintf
+    and rsp, -16 ; align stack for variadic call
+    mov rdx, [r13+0] ; snprintf arg 0
+    mov rcx, [r13+8] ; snprintf arg 1
+    mov eax, 0 ; vector registers holding args
+    call snprintf ; invoke libc snprintf
+    mov rsp, r15
+    lea rax, [r14+16] ; return formatted string pointer
+    add rsp, 16 ; pop stack args after libc call
     mov r12, [rbp-16] ; load continuation env_end pointer
     mov [r12-8], rax ; store env field
     mov rax, [r12+0] ; load continuation entry point
@@ -254,14 +376,8 @@ main:
     push rbp ; save executor frame pointer
     mov rbp, rsp ; establish new frame base
     sub rsp, 16 ; reserve stack space for locals
-    mov rax, 9 ; mmap syscall
-    xor rdi, rdi ; addr hint for kernel base selection
-    mov rsi, 56 ; length for allocation
-    mov rdx, 3 ; prot = read/write
-    mov r10, 34 ; flags: private & anonymous
-    mov r8, -1 ; fd = -1
-    xor r9, r9 ; offset = 0
-    syscall ; allocate env pages
+    mov rdi, 56 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
     mov rbx, rax ; closure env base pointer
     mov rax, 42 ; operand literal
     mov [rbx+0], rax ; capture arg into env
@@ -325,8 +441,9 @@ _start:
     leave ; unwind before named jump
     jmp main
 extern exit
-extern sprintf
+extern snprintf
 extern write
 section .rodata
+    dq 0, 17 ; static strings have no allocation to free
 _6:
     db "the answer is: %d", 0
@@ -6,8 +6,87 @@ _4_foo:
     push rbp ; save executor frame pointer
     mov rbp, rsp ; establish new frame base
     ; load exit code
-    mov rdi, 0 ; exit code
+    mov rdi, 0 ; operand literal
     call exit ; call libc exit to flush buffers
+global alloc_heap_ptr
+alloc_heap_ptr:
+    add rdi, 7 ; round size up to whole words
+    and rdi, -8
+    cmp rdi, 1024 ; small block?
+    ja alloc_heap_ptr_large
+    lea rcx, [heap_free_lists] ; free list heads by size
+    mov rax, [rcx+rdi] ; head of this size's free list
+    test rax, rax
+    jz alloc_heap_ptr_bump
+    mov rdx, [rax] ; next free block
+    mov [rcx+rdi], rdx ; pop the head
+    ret
+alloc_heap_ptr_bump:
+    lea rcx, [heap_arena] ; arena next and end pointers
+    mov rax, [rcx] ; next free byte of the arena
+    mov rdx, rax
+    add rdx, rdi ; end of the new block
+    mov r8, [rcx+8] ; end of the arena
+    cmp rdx, r8
+    ja alloc_heap_ptr_refill
+    mov [rcx], rdx ; bump past the block
+    ret
+alloc_heap_ptr_refill:
+    push rdi ; keep block size across the syscall
+    mov rsi, 1048576 ; arena size
+    xor rdi, rdi ; addr hint so kernel picks mmap base
+    mov rdx, 3 ; prot = read/write
+    mov r10, 34 ; flags = private & anonymous
+    mov r8, -1 ; fd = -1
+    xor r9, r9 ; offset = 0
+    mov rax, 9 ; mmap syscall
+    syscall ; map a new arena
+    pop rdi
+    cmp rax, -4095 ; mmap failed?
+    jae alloc_heap_ptr_failed
+    lea rcx, [heap_arena] ; arena next and end pointers
+    lea rdx, [rax+rdi] ; block is the start of the arena
+    mov [rcx], rdx
+    mov rdx, rax
+    add rdx, 1048576 ; end of the arena
+    mov [rcx+8], rdx
+    ret
+alloc_heap_ptr_large:
+    mov rsi, rdi ; length = block size
+    xor rdi, rdi ; addr hint so kernel picks mmap base
+    mov rdx, 3 ; prot = read/write
+    mov r10, 34 ; flags = private & anonymous
+    mov r8, -1 ; fd = -1
+    xor r9, r9 ; offset = 0
+    mov rax, 9 ; mmap syscall
+    syscall ; map a large block on its own
+    cmp rax, -4095 ; mmap failed?
+    jae alloc_heap_ptr_failed
+    ret
+alloc_heap_ptr_failed:
+    xor eax, eax ; no memory left
+    ret
+section .data
+heap_free_lists:
+    times 129 dq 0
+heap_arena:
+    dq 0, 0
+section .text
+global free_heap_ptr
+free_heap_ptr:
+    add rsi, 7 ; round size up to whole words
+    and rsi, -8
+    cmp rsi, 1024 ; small block?
+    ja free_heap_ptr_large
+    lea rcx, [heap_free_lists] ; free list heads by size
+    mov rax, [rcx+rsi] ; current head
+    mov [rdi], rax ; link it behind the block
+    mov [rcx+rsi], rdi ; block is the new head
+    ret
+free_heap_ptr_large:
+    mov rax, 11 ; munmap syscall
+    syscall
+    ret
 global release_heap_ptr
 release_heap_ptr:
     push rbp ; save caller frame
@@ -18,9 +97,8 @@ release_heap_ptr:
     mov rdx, [rbx+32] ; load heap size metadata
     mov rdi, rbx
     sub rdi, rcx ; compute env base pointer
-    mov rsi, rdx ; heap size for munmap
-    mov rax, 11 ; munmap syscall
-    syscall
+    mov rsi, rdx ; heap size to free
+    call free_heap_ptr ; return env to the allocator
     pop rbx
     pop rbp
     ret
@@ -62,14 +140,8 @@ _1_foo:
     push rbp ; save executor frame pointer
     mov rbp, rsp ; establish new frame base
     sub rsp, 16 ; reserve stack space for locals
-    mov rax, 9 ; mmap syscall
-    xor rdi, rdi ; addr hint for kernel base selection
-    mov rsi, 48 ; length for allocation
-    mov rdx, 3 ; prot = read/write
-    mov r10, 34 ; flags: private & anonymous
-    mov r8, -1 ; fd = -1
-    xor r9, r9 ; offset = 0
-    syscall ; allocate env pages
+    mov rdi, 48 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
     mov rbx, rax ; closure env base pointer
     mov r12, rbx ; env_end pointer before metadata
     mov rax, 0 ; store env size metadata
@@ -88,17 +160,8 @@ _1_foo:
     lea rax, [rel _2] ; point to string literal
     push rax ; stack arg
     pop rdi ; restore arg into register
-    mov r8, rdi ; keep string pointer
-    xor rcx, rcx ; reset length counter
-_1_foo_write_strlen_loop_0:
-    mov dl, byte [r8+rcx] ; load current character
-    cmp dl, 0 ; stop at terminator
-    je _1_foo_write_strlen_done_0
-    inc rcx ; advance char counter
-    jmp _1_foo_write_strlen_loop_0
-_1_foo_write_strlen_done_0:
-    mov rdx, rcx ; length to write
-    mov rsi, r8 ; buffer start
+    mov rdx, [rdi-8] ; length to write
+    mov rsi, rdi ; buffer start
     mov rdi, 1 ; stdout fd
     call write ; invoke libc write
     mov r12, [rbp-8] ; load continuation env_end pointer
@@ -226,5 +289,6 @@ _start:
 extern exit
 extern write
 section .rodata
+    dq 0, 4 ; static strings have no allocation to free
 _2:
     db "foo,", 0
@@ -0,0 +1,457 @@
+.text
+.p2align 2
+.global _6_main
+_6_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    // load exit code
+    mov x0, #0 // operand literal
+    bl exit // call libc exit to flush buffers
+.global alloc_heap_ptr
+alloc_heap_ptr:
+    add x0, x0, #7 // round size up to whole words
+    and x0, x0, #-8
+    cmp x0, #1024 // small block?
+    b.hi alloc_heap_ptr_large
+    adrp x9, heap_free_lists
+    add x9, x9, :lo12:heap_free_lists
+    ldr x10, [x9, x0] // head of this size's free list
+    cbz x10, alloc_heap_ptr_bump
+    ldr x11, [x10] // next free block
+    str x11, [x9, x0] // pop the head
+    mov x0, x10
+    ret
+alloc_heap_ptr_bump:
+    adrp x9, heap_arena
+    add x9, x9, :lo12:heap_arena
+    ldp x10, x11, [x9] // arena next and end
+    add x12, x10, x0 // end of the new block
+    cmp x12, x11
+    b.hi alloc_heap_ptr_refill
+    str x12, [x9] // bump past the block
+    mov x0, x10
+    ret
+alloc_heap_ptr_refill:
+    mov x13, x0 // keep block size across the syscall
+    mov x1, #1048576 // arena size
+    mov x0, #0 // addr hint so kernel picks mmap base
+    mov x2, #3 // prot = read/write
+    mov x3, #34 // flags = private & anonymous
+    mov x4, #-1 // fd = -1
+    mov x5, #0 // offset = 0
+    mov x8, #222 // mmap syscall
+    svc #0 // map a new arena
+    cmn x0, #4095 // mmap failed?
+    b.hs alloc_heap_ptr_failed
+    add x12, x0, x13 // block is the start of the arena
+    mov x11, #1048576
+    add x11, x0, x11 // end of the arena
+    stp x12, x11, [x9]
+    ret
+alloc_heap_ptr_large:
+    mov x1, x0 // length = block size
+    mov x0, #0 // addr hint so kernel picks mmap base
+    mov x2, #3 // prot = read/write
+    mov x3, #34 // flags = private & anonymous
+    mov x4, #-1 // fd = -1
+    mov x5, #0 // offset = 0
+    mov x8, #222 // mmap syscall
+    svc #0 // map a large block on its own
+    cmn x0, #4095 // mmap failed?
+    b.hs alloc_heap_ptr_failed
+    ret
+alloc_heap_ptr_failed:
+    mov x0, #0 // no memory left
+    ret
+.data
+.p2align 3
+heap_free_lists:
+    .zero 1032
+heap_arena:
+    .quad 0, 0
+.text
+.global free_heap_ptr
+free_heap_ptr:
+    add x1, x1, #7 // round size up to whole words
+    and x1, x1, #-8
+    cmp x1, #1024 // small block?
+    b.hi free_heap_ptr_large
+    adrp x9, heap_free_lists
+    add x9, x9, :lo12:heap_free_lists
+    ldr x10, [x9, x1] // current head
+    str x10, [x0] // link it behind the block
+    str x0, [x9, x1] // block is the new head
+    ret
+free_heap_ptr_large:
+    mov x8, #215 // munmap syscall
+    svc #0
+    ret
+.global release_heap_ptr
+release_heap_ptr:
+    stp x29, x30, [sp, #-16]! // save caller frame
+    mov x29, sp // establish frame
+    ldr x11, [x0, #24] // load env size metadata
+    ldr x12, [x0, #32] // load heap size metadata
+    sub x0, x0, x11 // compute env base pointer
+    mov x1, x12 // heap size to free
+    bl free_heap_ptr // return env to the allocator
+    ldp x29, x30, [sp], #16
+    ret
+.global _6_main_unwrapper
+_6_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _6_main
+.global _6_main_deep_release
+_6_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _6_main_deepcopy
+_6_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _3_main
+_3_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    mov x0, #48 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x9, #0 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #48 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _6_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_6_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _6_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_6_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _6_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_6_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _6_main closure env_end to x9
+    stur x9, [x29, #-8] // store value
+    adrp x13, _4 // point to string literal
+    add x13, x13, :lo12:_4
+    ldr x2, [x13, #-8] // length to write
+    mov x1, x13 // buffer start
+    mov x0, #1 // stdout fd
+    bl write // invoke libc write
+    ldur x19, [x29, #-8] // load continuation env_end pointer
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _3_main_unwrapper
+_3_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _3_main
+.global _3_main_deep_release
+_3_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _3_main_deepcopy
+_3_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _14_main
+_14_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    // load exit code
+    mov x0, #0 // operand literal
+    bl exit // call libc exit to flush buffers
+.global _14_main_unwrapper
+_14_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _14_main
+.global _14_main_deep_release
+_14_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _14_main_deepcopy
+_14_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _11_main
+_11_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    mov x0, #48 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x9, #0 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #48 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _14_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_14_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _14_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_14_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _14_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_14_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _14_main closure env_end to x9
+    stur x9, [x29, #-8] // store value
+    adrp x13, _12 // point to string literal
+    add x13, x13, :lo12:_12
+    ldr x2, [x13, #-8] // length to write
+    mov x1, x13 // buffer start
+    mov x0, #1 // stdout fd
+    bl write // invoke libc write
+    ldur x19, [x29, #-8] // load continuation env_end pointer
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _11_main_unwrapper
+_11_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _11_main
+.global _11_main_deep_release
+_11_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _11_main_deepcopy
+_11_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global main
+main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    mov x0, #48 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x9, #0 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #48 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _3_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_3_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _3_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_3_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _3_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_3_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _3_main closure env_end to x9
+    stur x9, [x29, #-8] // store value
+    mov x0, #48 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x9, #0 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #48 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _11_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_11_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _11_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_11_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _11_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_11_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _11_main closure env_end to x9
+    stur x9, [x29, #-16] // store value
+    mov x9, #1 // operand literal
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.lt lt__3_main_true_0_0
+lt__11_main_false_0_0:
+    ldur x0, [x29, #-8] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _3_main release helper
+    blr x10 // release _3_main and what it holds
+    ldp x19, x22, [sp], #16
+    ldur x20, [x29, #-16] // load _11_main closure env_end pointer
+    mov x0, x20 // pass env_end pointer to closure
+    ldr x9, [x0, #0] // load closure unwrapper entry point
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9 // tail call into closure
+lt__3_main_true_0_0:
+    ldur x0, [x29, #-16] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _11_main release helper
+    blr x10 // release _11_main and what it holds
+    ldp x19, x22, [sp], #16
+    ldur x20, [x29, #-8] // load _3_main closure env_end pointer
+    mov x0, x20 // pass env_end pointer to closure
+    ldr x9, [x0, #0] // load closure unwrapper entry point
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9 // tail call into closure
+.global main_unwrapper
+main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b main
+.global main_deep_release
+main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global main_deepcopy
+main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _start
+_start:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b main
+.section .rodata
+.p2align 3
+    .quad 0, 8 // static strings have no allocation to free
+_4:
+    .asciz "lt: true"
+.p2align 3
+    .quad 0, 9 // static strings have no allocation to free
+_12:
+    .asciz "lt: false"
@@ -0,0 +1,5628 @@
+.text
+.p2align 2
+.global alloc_heap_ptr
+alloc_heap_ptr:
+    add x0, x0, #7 // round size up to whole words
+    and x0, x0, #-8
+    cmp x0, #1024 // small block?
+    b.hi alloc_heap_ptr_large
+    adrp x9, heap_free_lists
+    add x9, x9, :lo12:heap_free_lists
+    ldr x10, [x9, x0] // head of this size's free list
+    cbz x10, alloc_heap_ptr_bump
+    ldr x11, [x10] // next free block
+    str x11, [x9, x0] // pop the head
+    mov x0, x10
+    ret
+alloc_heap_ptr_bump:
+    adrp x9, heap_arena
+    add x9, x9, :lo12:heap_arena
+    ldp x10, x11, [x9] // arena next and end
+    add x12, x10, x0 // end of the new block
+    cmp x12, x11
+    b.hi alloc_heap_ptr_refill
+    str x12, [x9] // bump past the block
+    mov x0, x10
+    ret
+alloc_heap_ptr_refill:
+    mov x13, x0 // keep block size across the syscall
+    mov x1, #1048576 // arena size
+    mov x0, #0 // addr hint so kernel picks mmap base
+    mov x2, #3 // prot = read/write
+    mov x3, #34 // flags = private & anonymous
+    mov x4, #-1 // fd = -1
+    mov x5, #0 // offset = 0
+    mov x8, #222 // mmap syscall
+    svc #0 // map a new arena
+    cmn x0, #4095 // mmap failed?
+    b.hs alloc_heap_ptr_failed
+    add x12, x0, x13 // block is the start of the arena
+    mov x11, #1048576
+    add x11, x0, x11 // end of the arena
+    stp x12, x11, [x9]
+    ret
+alloc_heap_ptr_large:
+    mov x1, x0 // length = block size
+    mov x0, #0 // addr hint so kernel picks mmap base
+    mov x2, #3 // prot = read/write
+    mov x3, #34 // flags = private & anonymous
+    mov x4, #-1 // fd = -1
+    mov x5, #0 // offset = 0
+    mov x8, #222 // mmap syscall
+    svc #0 // map a large block on its own
+    cmn x0, #4095 // mmap failed?
+    b.hs alloc_heap_ptr_failed
+    ret
+alloc_heap_ptr_failed:
+    mov x0, #0 // no memory left
+    ret
+.data
+.p2align 3
+heap_free_lists:
+    .zero 1032
+heap_arena:
+    .quad 0, 0
+.text
+.global free_heap_ptr
+free_heap_ptr:
+    add x1, x1, #7 // round size up to whole words
+    and x1, x1, #-8
+    cmp x1, #1024 // small block?
+    b.hi free_heap_ptr_large
+    adrp x9, heap_free_lists
+    add x9, x9, :lo12:heap_free_lists
+    ldr x10, [x9, x1] // current head
+    str x10, [x0] // link it behind the block
+    str x0, [x9, x1] // block is the new head
+    ret
+free_heap_ptr_large:
+    mov x8, #215 // munmap syscall
+    svc #0
+    ret
+.global release_str_ptr
+release_str_ptr:
+    cbz x0, release_str_ptr_done // nothing to free
+    ldr x1, [x0, #-16] // allocation size header
+    cbz x1, release_str_ptr_done // literals have no allocation
+    sub x0, x0, #16 // block starts at the header
+    b free_heap_ptr // return string to the allocator
+release_str_ptr_done:
+    ret
+.global release_array_ptr
+release_array_ptr:
+    cbz x0, release_array_ptr_done // nothing to free
+    stp x29, x30, [sp, #-16]! // save caller frame
+    mov x29, sp // establish frame
+    stp x20, x21, [sp, #-16]! // preserve callee-saved registers
+    stp x22, x23, [sp, #-16]!
+    mov x20, x0 // array
+    ldr x21, [x20, #-8] // elements left to release
+    ldr x22, [x20, #-16] // element class
+    cbz x22, release_array_ptr_free // plain elements own nothing
+release_array_ptr_loop:
+    cbz x21, release_array_ptr_free
+    sub x21, x21, #1
+    ldr x0, [x20, x21, lsl #3]
+    mov x1, x22
+    bl release_array_elem_ptr
+    b release_array_ptr_loop
+release_array_ptr_free:
+    ldr x1, [x20, #-8] // length
+    lsl x1, x1, #3
+    add x1, x1, #16 // block size, header included
+    sub x0, x20, #16 // block starts at the header
+    ldp x22, x23, [sp], #16
+    ldp x20, x21, [sp], #16
+    ldp x29, x30, [sp], #16
+    b free_heap_ptr // return the array to the allocator
+release_array_ptr_done:
+    ret
+.global release_array_elem_ptr
+release_array_elem_ptr:
+    cmp x1, #1 // string element?
+    b.eq release_str_ptr
+    cmp x1, #2 // array element?
+    b.eq release_array_ptr
+    ret
+.global queue_push_ptr
+queue_push_ptr:
+    stp x29, x30, [sp, #-16]! // save caller frame
+    mov x29, sp // establish frame
+    stp x20, x21, [sp, #-16]! // preserve callee-saved registers
+    mov x20, x0 // queue
+    mov x21, x1 // word to queue
+    mov x0, #16 // node size
+    bl alloc_heap_ptr
+    str xzr, [x0] // last node
+    str x21, [x0, #8]
+    ldr x11, [x20, #8] // tail
+    cbz x11, queue_push_ptr_empty
+    str x0, [x11] // link behind the tail
+    b queue_push_ptr_linked
+queue_push_ptr_empty:
+    str x0, [x20] // node is the head too
+queue_push_ptr_linked:
+    str x0, [x20, #8] // node is the new tail
+    ldp x20, x21, [sp], #16
+    ldp x29, x30, [sp], #16
+    ret
+.global queue_pop_ptr
+queue_pop_ptr:
+    stp x29, x30, [sp, #-16]! // save caller frame
+    mov x29, sp // establish frame
+    stp x20, x21, [sp, #-16]! // preserve callee-saved registers
+    mov x12, x0 // queue
+    ldr x0, [x12] // head node
+    ldr x11, [x0] // next node
+    str x11, [x12] // next node is the new head
+    cbnz x11, queue_pop_ptr_unlinked
+    str xzr, [x12, #8] // queue is empty
+queue_pop_ptr_unlinked:
+    ldr x20, [x0, #8] // word the node held
+    mov x1, #16 // node size
+    bl free_heap_ptr
+    mov x0, x20
+    ldp x20, x21, [sp], #16
+    ldp x29, x30, [sp], #16
+    ret
+.data
+.p2align 3
+sched_state:
+    .quad 0, 0, 0, 1, 0, 0
+.section .rodata
+sched_deadlock_message:
+    .ascii "deadlock: every coroutine is waiting on a channel\012"
+.text
+.p2align 2
+.global sched_next_ptr
+sched_next_ptr:
+sched_next_ptr_pick:
+    adrp x0, sched_state
+    add x0, x0, :lo12:sched_state
+    ldr x9, [x0] // anything to run?
+    cbz x9, sched_next_ptr_idle
+    bl queue_pop_ptr // env_end of the next coroutine
+    ldr x9, [x0, #0] // load its entry point
+    br x9
+sched_next_ptr_idle:
+    ldr x9, [x0, #16] // anyone parked?
+    cbnz x9, sched_next_ptr_deadlock
+    mov x0, #0 // exit code
+    bl exit // call libc exit to flush buffers
+sched_next_ptr_deadlock:
+    mov x0, #2 // stderr
+    adrp x1, sched_deadlock_message
+    add x1, x1, :lo12:sched_deadlock_message
+    mov x2, #50 // message length
+    mov x8, #64 // write syscall
+    svc #0
+    mov x0, #1 // exit code
+    bl exit
+.global sched_ready_ptr
+sched_ready_ptr:
+    mov x1, x0
+    adrp x0, sched_state
+    add x0, x0, :lo12:sched_state
+    b queue_push_ptr
+.global chan_new_ptr
+chan_new_ptr:
+    stp x29, x30, [sp, #-16]! // save caller frame
+    mov x29, sp // establish frame
+    stp x20, x21, [sp, #-16]! // preserve callee-saved registers
+    mov x20, x0 // value class
+    mov x0, #48 // channel size
+    bl alloc_heap_ptr
+    mov x9, #1 // one reference
+    str x9, [x0, #0]
+    str x20, [x0, #8]
+    stp xzr, xzr, [x0, #16] // empty queue
+    stp xzr, xzr, [x0, #32] // empty queue
+    ldp x20, x21, [sp], #16
+    ldp x29, x30, [sp], #16
+    ret
+.global chan_send_ptr
+chan_send_ptr:
+    stp x29, x30, [sp, #-16]! // save caller frame
+    mov x29, sp // establish frame
+    stp x20, x21, [sp, #-16]! // preserve callee-saved registers
+    mov x20, x0 // channel
+    mov x21, x1 // value
+    ldr x9, [x20, #32] // anyone receiving?
+    cbz x9, chan_send_ptr_queue
+    add x0, x20, #32
+    bl queue_pop_ptr // the receiver's continuation
+    stur x21, [x0, #-16] // pass the value
+    stur x20, [x0, #-8] // and the channel
+    bl sched_ready_ptr // the receiver can run again
+    adrp x11, sched_state
+    add x11, x11, :lo12:sched_state
+    ldr x9, [x11, #16]
+    sub x9, x9, #1 // one coroutine fewer parked
+    str x9, [x11, #16]
+    b chan_send_ptr_done
+chan_send_ptr_queue:
+    add x0, x20, #16
+    mov x1, x21
+    bl queue_push_ptr // keep it for the next receiver
+chan_send_ptr_done:
+    ldp x20, x21, [sp], #16
+    ldp x29, x30, [sp], #16
+    ret
+.global chan_recv_ptr
+chan_recv_ptr:
+    stp x29, x30, [sp, #-16]! // save caller frame
+    mov x29, sp // establish frame
+    ldr x9, [x0, #16] // any value queued?
+    cbz x9, chan_recv_ptr_park
+    add x0, x0, #16
+    bl queue_pop_ptr
+    mov x1, #1 // got a value
+    ldp x29, x30, [sp], #16
+    ret
+chan_recv_ptr_park:
+    add x0, x0, #32
+    bl queue_push_ptr // wait for a sender
+    adrp x11, sched_state
+    add x11, x11, :lo12:sched_state
+    ldr x9, [x11, #16]
+    add x9, x9, #1 // one more coroutine parked
+    str x9, [x11, #16]
+    mov x1, #0 // parked
+    ldp x29, x30, [sp], #16
+    ret
+.global chan_release_ptr
+chan_release_ptr:
+    ldr x9, [x0, #0]
+    subs x9, x9, #1 // drop a reference
+    str x9, [x0, #0]
+    b.ne chan_release_ptr_done
+    stp x29, x30, [sp, #-16]! // save caller frame
+    mov x29, sp // establish frame
+    str x0, [sp, #-16]! // channel
+chan_release_ptr_loop:
+    ldr x12, [sp]
+    ldr x9, [x12, #16] // values left?
+    cbz x9, chan_release_ptr_free
+    add x0, x12, #16
+    bl queue_pop_ptr // value
+    ldr x12, [sp]
+    ldr x1, [x12, #8] // value class
+    cmp x1, #3 // environment?
+    b.eq chan_release_ptr_env
+    bl release_array_elem_ptr
+    b chan_release_ptr_loop
+chan_release_ptr_env:
+    ldr x10, [x0, #8] // its release helper
+    blr x10
+    b chan_release_ptr_loop
+chan_release_ptr_free:
+    ldr x0, [sp], #16
+    mov x1, #48 // channel size
+    ldp x29, x30, [sp], #16
+    b free_heap_ptr // return the channel to the allocator
+chan_release_ptr_done:
+    ret
+.global chan_copy_ptr
+chan_copy_ptr:
+    ldr x9, [x0, #0]
+    add x9, x9, #1 // one more reference
+    str x9, [x0, #0]
+    ret
+.global _30_produce
+_30_produce:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store rest arg in frame
+    ldur x0, [x29, #-8] // load operand
+    bl chan_release_ptr // drop a reference to rest channel
+    mov sp, x29 // unwind before switching
+    ldp x29, x30, [sp], #16
+    b sched_next_ptr // run the next coroutine
+.global release_heap_ptr
+release_heap_ptr:
+    stp x29, x30, [sp, #-16]! // save caller frame
+    mov x29, sp // establish frame
+    ldr x11, [x0, #24] // load env size metadata
+    ldr x12, [x0, #32] // load heap size metadata
+    sub x0, x0, x11 // compute env base pointer
+    mov x1, x12 // heap size to free
+    bl free_heap_ptr // return env to the allocator
+    ldp x29, x30, [sp], #16
+    ret
+.global _30_produce_unwrapper
+_30_produce_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-8] // load rest env field
+    stur x9, [x29, #-16] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _30_produce
+.global _30_produce_deep_release
+_30_produce_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _30_produce_release_skip_0
+    ldur x9, [x19, #-8] // load _30_produce_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl chan_release_ptr // drop a reference to _30_produce_release_field_0 channel
+_30_produce_release_skip_0:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _30_produce_deepcopy
+_30_produce_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _30_produce_deepcopy_skip_0
+    ldur x0, [x19, #-8] // load field pointer
+    bl chan_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_30_produce_deepcopy_skip_0:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _27_produce
+_27_produce:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store c arg in frame
+    mov x0, #56 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #8 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #8 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #56 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _30_produce_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_30_produce_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _30_produce_deep_release // load release helper entry point
+    add x9, x9, :lo12:_30_produce_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _30_produce_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_30_produce_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _30_produce closure env_end to x9
+    stur x9, [x29, #-16] // store value
+    ldur x21, [x29, #-8] // load operand
+    mov x1, #0 // operand literal
+    mov x0, x21
+    bl chan_send_ptr // hand the value over or queue it
+    ldur x19, [x29, #-16] // load continuation env_end pointer
+    stur x21, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _27_produce_unwrapper
+_27_produce_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-8] // load c env field
+    stur x9, [x29, #-16] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _27_produce
+.global _27_produce_deep_release
+_27_produce_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _27_produce_release_skip_0
+    ldur x9, [x19, #-8] // load _27_produce_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl chan_release_ptr // drop a reference to _27_produce_release_field_0 channel
+_27_produce_release_skip_0:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _27_produce_deepcopy
+_27_produce_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _27_produce_deepcopy_skip_0
+    ldur x0, [x19, #-8] // load field pointer
+    bl chan_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_27_produce_deepcopy_skip_0:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _44_produce
+_44_produce:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store produce arg in frame
+    stur x1, [x29, #-16] // store rest arg in frame
+    stur x2, [x29, #-24] // store last arg in frame
+    stur x3, [x29, #-32] // store n arg in frame
+    ldur x20, [x29, #-8] // load produce closure env_end pointer
+    ldur x9, [x29, #-16] // load operand
+    stur x9, [x20, #-24] // store env field
+    ldur x9, [x29, #-32] // load operand
+    stur x9, [x20, #-16] // store env field
+    ldur x9, [x29, #-24] // load operand
+    stur x9, [x20, #-8] // store env field
+    mov x0, x20 // pass env_end pointer to closure
+    ldr x9, [x0, #0] // load closure unwrapper entry point
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9 // tail call into closure
+.global _44_produce_unwrapper
+_44_produce_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-32] // load produce env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-24] // load rest env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-16] // load last env field
+    stur x9, [x29, #-32] // store value
+    ldur x9, [x19, #-8] // load n env field
+    stur x9, [x29, #-40] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    ldur x3, [x29, #-40] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _44_produce
+.global _44_produce_deep_release
+_44_produce_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #3 // operand literal
+    cmp x9, x20
+    b.gt _44_produce_release_skip_0
+    ldur x9, [x19, #-32] // load _44_produce_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _44_produce_release_field_0 release helper
+    blr x10 // release _44_produce_release_field_0 and what it holds
+    ldp x19, x22, [sp], #16
+_44_produce_release_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _44_produce_release_skip_1
+    ldur x9, [x19, #-24] // load _44_produce_release_field_1 env field
+    stur x9, [x29, #-32] // store value
+    ldur x0, [x29, #-32] // load operand
+    bl chan_release_ptr // drop a reference to _44_produce_release_field_1 channel
+_44_produce_release_skip_1:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global deepcopy_heap_ptr
+deepcopy_heap_ptr:
+    stp x29, x30, [sp, #-16]! // prologue: save executor frame pointer
+    mov x29, sp // prologue: establish new frame
+    stp x19, x20, [sp, #-16]! // preserve callee-saved registers
+    stp x21, x22, [sp, #-16]!
+    str x23, [sp, #-16]!
+    mov x19, x0 // capture env_end pointer
+    ldr x21, [x19, #24] // load env size metadata
+    ldr x23, [x19, #32] // load heap size metadata
+    sub x20, x19, x21 // compute env base pointer
+    mov x0, x23 // length = heap size
+    bl alloc_heap_ptr // allocate new closure env
+    mov x22, x0 // new env base pointer
+    mov x1, x20 // memcpy src
+    mov x2, x23 // memcpy length
+    bl memcpy_helper // copy env contents
+    add x23, x22, x21 // new env_end pointer
+    ldr x9, [x23, #16] // load deep copy helper entry
+    mov x0, x23 // pass new env_end pointer
+    blr x9 // invoke helper
+    mov x0, x23 // return new env_end pointer
+    ldr x23, [sp], #16
+    ldp x21, x22, [sp], #16
+    ldp x19, x20, [sp], #16
+    ldp x29, x30, [sp], #16
+    ret
+.global memcpy_helper
+memcpy_helper:
+    mov x11, #0 // counter = 0
+internal_memcpy_loop:
+    cmp x11, x2 // counter < count?
+    b.ge internal_memcpy_done
+    ldr x9, [x1, x11] // load 8 bytes from source
+    str x9, [x0, x11] // store 8 bytes to destination
+    add x11, x11, #8 // advance counter by 8
+    b internal_memcpy_loop
+internal_memcpy_done:
+    ret
+.global _44_produce_deepcopy
+_44_produce_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #3 // operand literal
+    cmp x9, x20
+    b.gt _44_produce_deepcopy_skip_0
+    ldur x0, [x19, #-32] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-32] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_44_produce_deepcopy_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _44_produce_deepcopy_skip_1
+    ldur x0, [x19, #-24] // load field pointer
+    bl chan_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-24] // store duplicated pointer
+    stur x9, [x29, #-32] // store value
+_44_produce_deepcopy_skip_1:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _41_produce
+_41_produce:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store next arg in frame
+    stur x1, [x29, #-16] // store produce arg in frame
+    stur x2, [x29, #-24] // store last arg in frame
+    stur x3, [x29, #-32] // store rest arg in frame
+    mov x0, #80 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-32] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #16] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #32 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #32 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #80 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _44_produce_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_44_produce_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _44_produce_deep_release // load release helper entry point
+    add x9, x9, :lo12:_44_produce_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _44_produce_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_44_produce_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _45_produce closure env_end to x9
+    stur x9, [x29, #-40] // store value
+    ldur x9, [x29, #-8] // load operand
+    mov x20, #1 // operand literal
+    add x9, x9, x20 // add second integer
+    ldur x19, [x29, #-40] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _41_produce_unwrapper
+_41_produce_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-32] // load next env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-24] // load produce env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-16] // load last env field
+    stur x9, [x29, #-32] // store value
+    ldur x9, [x19, #-8] // load rest env field
+    stur x9, [x29, #-40] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    ldur x3, [x29, #-40] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _41_produce
+.global _41_produce_deep_release
+_41_produce_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _41_produce_release_skip_1
+    ldur x9, [x19, #-24] // load _41_produce_release_field_1 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _41_produce_release_field_1 release helper
+    blr x10 // release _41_produce_release_field_1 and what it holds
+    ldp x19, x22, [sp], #16
+_41_produce_release_skip_1:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _41_produce_release_skip_3
+    ldur x9, [x19, #-8] // load _41_produce_release_field_3 env field
+    stur x9, [x29, #-32] // store value
+    ldur x0, [x29, #-32] // load operand
+    bl chan_release_ptr // drop a reference to _41_produce_release_field_3 channel
+_41_produce_release_skip_3:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _41_produce_deepcopy
+_41_produce_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _41_produce_deepcopy_skip_1
+    ldur x0, [x19, #-24] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-24] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_41_produce_deepcopy_skip_1:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _41_produce_deepcopy_skip_3
+    ldur x0, [x19, #-8] // load field pointer
+    bl chan_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-32] // store value
+_41_produce_deepcopy_skip_3:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _39_produce
+_39_produce:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store c arg in frame
+    stur x1, [x29, #-16] // store next arg in frame
+    stur x2, [x29, #-24] // store produce arg in frame
+    stur x3, [x29, #-32] // store last arg in frame
+    mov x0, #80 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-32] // load operand
+    str x9, [x20, #16] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #32 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #32 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #80 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _41_produce_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_41_produce_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _41_produce_deep_release // load release helper entry point
+    add x9, x9, :lo12:_41_produce_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _41_produce_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_41_produce_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _46_produce closure env_end to x9
+    stur x9, [x29, #-40] // store value
+    ldur x21, [x29, #-8] // load operand
+    ldur x1, [x29, #-16] // load operand
+    mov x0, x21
+    bl chan_send_ptr // hand the value over or queue it
+    ldur x19, [x29, #-40] // load continuation env_end pointer
+    stur x21, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _39_produce_unwrapper
+_39_produce_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-32] // load c env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-24] // load next env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-16] // load produce env field
+    stur x9, [x29, #-32] // store value
+    ldur x9, [x19, #-8] // load last env field
+    stur x9, [x29, #-40] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    ldur x3, [x29, #-40] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _39_produce
+.global _39_produce_deep_release
+_39_produce_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #3 // operand literal
+    cmp x9, x20
+    b.gt _39_produce_release_skip_0
+    ldur x9, [x19, #-32] // load _39_produce_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl chan_release_ptr // drop a reference to _39_produce_release_field_0 channel
+_39_produce_release_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _39_produce_release_skip_2
+    ldur x9, [x19, #-16] // load _39_produce_release_field_2 env field
+    stur x9, [x29, #-32] // store value
+    ldur x0, [x29, #-32] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _39_produce_release_field_2 release helper
+    blr x10 // release _39_produce_release_field_2 and what it holds
+    ldp x19, x22, [sp], #16
+_39_produce_release_skip_2:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _39_produce_deepcopy
+_39_produce_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #3 // operand literal
+    cmp x9, x20
+    b.gt _39_produce_deepcopy_skip_0
+    ldur x0, [x19, #-32] // load field pointer
+    bl chan_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-32] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_39_produce_deepcopy_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _39_produce_deepcopy_skip_2
+    ldur x0, [x19, #-16] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-16] // store duplicated pointer
+    stur x9, [x29, #-32] // store value
+_39_produce_deepcopy_skip_2:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _37_produce
+_37_produce:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store c arg in frame
+    stur x1, [x29, #-16] // store next arg in frame
+    stur x2, [x29, #-24] // store produce arg in frame
+    stur x3, [x29, #-32] // store last arg in frame
+    stur x4, [x29, #-40] // store s arg in frame
+    mov x0, #80 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #16] // capture arg into env
+    ldur x9, [x29, #-32] // load operand
+    str x9, [x20, #24] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #32 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #32 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #80 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _39_produce_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_39_produce_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _39_produce_deep_release // load release helper entry point
+    add x9, x9, :lo12:_39_produce_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _39_produce_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_39_produce_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _47_produce closure env_end to x9
+    stur x9, [x29, #-48] // store value
+    ldur x13, [x29, #-40] // load operand
+    ldr x2, [x13, #-8] // length to write
+    mov x1, x13 // buffer start
+    mov x0, #1 // stdout fd
+    bl write // invoke libc write
+    ldur x0, [x29, #-40] // load operand
+    bl release_str_ptr // release s string
+    ldur x19, [x29, #-48] // load continuation env_end pointer
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _37_produce_unwrapper
+_37_produce_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-40] // load c env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-32] // load next env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-24] // load produce env field
+    stur x9, [x29, #-32] // store value
+    ldur x9, [x19, #-16] // load last env field
+    stur x9, [x29, #-40] // store value
+    ldur x9, [x19, #-8] // load s env field
+    stur x9, [x29, #-48] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    ldur x3, [x29, #-40] // load operand
+    ldur x4, [x29, #-48] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _37_produce
+.global _37_produce_deep_release
+_37_produce_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #4 // operand literal
+    cmp x9, x20
+    b.gt _37_produce_release_skip_0
+    ldur x9, [x19, #-40] // load _37_produce_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl chan_release_ptr // drop a reference to _37_produce_release_field_0 channel
+_37_produce_release_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _37_produce_release_skip_2
+    ldur x9, [x19, #-24] // load _37_produce_release_field_2 env field
+    stur x9, [x29, #-32] // store value
+    ldur x0, [x29, #-32] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _37_produce_release_field_2 release helper
+    blr x10 // release _37_produce_release_field_2 and what it holds
+    ldp x19, x22, [sp], #16
+_37_produce_release_skip_2:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _37_produce_release_skip_4
+    ldur x9, [x19, #-8] // load _37_produce_release_field_4 env field
+    stur x9, [x29, #-40] // store value
+    ldur x0, [x29, #-40] // load operand
+    bl release_str_ptr // release _37_produce_release_field_4 string
+_37_produce_release_skip_4:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global copy_str_ptr
+copy_str_ptr:
+    cbz x0, copy_str_ptr_done
+    ldr x1, [x0, #-16] // allocation size header
+    cbz x1, copy_str_ptr_done // literals are never freed
+    stp x29, x30, [sp, #-16]! // save caller frame
+    mov x29, sp // establish frame
+    stp x20, x21, [sp, #-16]! // preserve callee-saved registers
+    sub x20, x0, #16 // source block, header included
+    mov x21, x1 // allocation size
+    mov x0, x1
+    bl alloc_heap_ptr // allocate the copy
+    mov x1, x20 // memcpy src
+    mov x2, x21 // memcpy length
+    mov x20, x0 // copy block
+    bl memcpy_helper // duplicate header and bytes
+    add x0, x20, #16 // copied string starts after its header
+    ldp x20, x21, [sp], #16
+    ldp x29, x30, [sp], #16
+copy_str_ptr_done:
+    ret
+.global _37_produce_deepcopy
+_37_produce_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #4 // operand literal
+    cmp x9, x20
+    b.gt _37_produce_deepcopy_skip_0
+    ldur x0, [x19, #-40] // load field pointer
+    bl chan_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-40] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_37_produce_deepcopy_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _37_produce_deepcopy_skip_2
+    ldur x0, [x19, #-24] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-24] // store duplicated pointer
+    stur x9, [x29, #-32] // store value
+_37_produce_deepcopy_skip_2:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _37_produce_deepcopy_skip_4
+    ldur x0, [x19, #-8] // load field pointer
+    bl copy_str_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-40] // store value
+_37_produce_deepcopy_skip_4:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _34_produce
+_34_produce:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store next arg in frame
+    stur x1, [x29, #-16] // store c arg in frame
+    stur x2, [x29, #-24] // store produce arg in frame
+    stur x3, [x29, #-32] // store last arg in frame
+    mov x0, #88 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #16] // capture arg into env
+    ldur x9, [x29, #-32] // load operand
+    str x9, [x20, #24] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #40 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #40 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #88 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _37_produce_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_37_produce_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _37_produce_deep_release // load release helper entry point
+    add x9, x9, :lo12:_37_produce_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _37_produce_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_37_produce_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _48_produce closure env_end to x9
+    stur x9, [x29, #-40] // store value
+    adrp x2, _35 // point to string literal
+    add x2, x2, :lo12:_35
+    ldur x3, [x29, #-8] // load operand
+    mov x0, #0 // measure only
+    mov x1, #0
+    bl snprintf // invoke libc snprintf
+    mov x20, x0 // formatted length
+    add x0, x20, #17 // header, bytes and terminator
+    bl alloc_heap_ptr // allocate the string
+    mov x21, x0 // string block
+    add x9, x20, #17
+    str x9, [x21] // allocation size header
+    str x20, [x21, #8] // length header
+    adrp x2, _35 // point to string literal
+    add x2, x2, :lo12:_35
+    ldur x3, [x29, #-8] // load operand
+    add x0, x21, #16 // string bytes follow the header
+    add x1, x20, #1 // room for the terminator
+    bl snprintf // invoke libc snprintf
+    add x9, x21, #16 // return formatted string pointer
+    ldur x19, [x29, #-40] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _34_produce_unwrapper
+_34_produce_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-32] // load next env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-24] // load c env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-16] // load produce env field
+    stur x9, [x29, #-32] // store value
+    ldur x9, [x19, #-8] // load last env field
+    stur x9, [x29, #-40] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    ldur x3, [x29, #-40] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _34_produce
+.global _34_produce_deep_release
+_34_produce_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _34_produce_release_skip_1
+    ldur x9, [x19, #-24] // load _34_produce_release_field_1 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl chan_release_ptr // drop a reference to _34_produce_release_field_1 channel
+_34_produce_release_skip_1:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _34_produce_release_skip_2
+    ldur x9, [x19, #-16] // load _34_produce_release_field_2 env field
+    stur x9, [x29, #-32] // store value
+    ldur x0, [x29, #-32] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _34_produce_release_field_2 release helper
+    blr x10 // release _34_produce_release_field_2 and what it holds
+    ldp x19, x22, [sp], #16
+_34_produce_release_skip_2:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _34_produce_deepcopy
+_34_produce_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _34_produce_deepcopy_skip_1
+    ldur x0, [x19, #-24] // load field pointer
+    bl chan_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-24] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_34_produce_deepcopy_skip_1:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _34_produce_deepcopy_skip_2
+    ldur x0, [x19, #-16] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-16] // store duplicated pointer
+    stur x9, [x29, #-32] // store value
+_34_produce_deepcopy_skip_2:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global produce
+produce:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #64 // reserve stack space for locals
+    stur x0, [x29, #-8] // store c arg in frame
+    stur x1, [x29, #-16] // store next arg in frame
+    stur x2, [x29, #-24] // store last arg in frame
+    ldur x0, [x29, #-8] // load operand
+    bl chan_copy_ptr // another reference to c channel
+    mov x9, x0
+    stur x9, [x29, #-32] // store value
+    mov x0, #56 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-32] // load operand
+    str x9, [x20, #0] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #8 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #8 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #56 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _27_produce_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_27_produce_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _27_produce_deep_release // load release helper entry point
+    add x9, x9, :lo12:_27_produce_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _27_produce_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_27_produce_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _32_produce closure env_end to x9
+    stur x9, [x29, #-40] // store value
+    mov x0, #72 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #24 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #24 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #72 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, produce_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:produce_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, produce_deep_release // load release helper entry point
+    add x9, x9, :lo12:produce_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, produce_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:produce_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #3 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy produce closure env_end to x9
+    stur x9, [x29, #-48] // store value
+    mov x0, #80 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-48] // load operand
+    str x9, [x20, #16] // capture arg into env
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #24] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #32 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #32 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #80 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _34_produce_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_34_produce_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _34_produce_deep_release // load release helper entry point
+    add x9, x9, :lo12:_34_produce_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _34_produce_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_34_produce_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _49_produce closure env_end to x9
+    stur x9, [x29, #-56] // store value
+    ldur x9, [x29, #-16] // load operand
+    ldur x20, [x29, #-24] // load operand
+    cmp x9, x20
+    b.gt gt__32_produce_true_0_0
+gt__49_produce_false_0_0:
+    ldur x0, [x29, #-40] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _32_produce release helper
+    blr x10 // release _32_produce and what it holds
+    ldp x19, x22, [sp], #16
+    ldur x20, [x29, #-56] // load _49_produce closure env_end pointer
+    mov x0, x20 // pass env_end pointer to closure
+    ldr x9, [x0, #0] // load closure unwrapper entry point
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9 // tail call into closure
+gt__32_produce_true_0_0:
+    ldur x0, [x29, #-56] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _49_produce release helper
+    blr x10 // release _49_produce and what it holds
+    ldp x19, x22, [sp], #16
+    ldur x20, [x29, #-40] // load _32_produce closure env_end pointer
+    mov x0, x20 // pass env_end pointer to closure
+    ldr x9, [x0, #0] // load closure unwrapper entry point
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9 // tail call into closure
+.global produce_unwrapper
+produce_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-24] // load c env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-16] // load next env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-8] // load last env field
+    stur x9, [x29, #-32] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b produce
+.global produce_deep_release
+produce_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt produce_release_skip_0
+    ldur x9, [x19, #-24] // load produce_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl chan_release_ptr // drop a reference to produce_release_field_0 channel
+produce_release_skip_0:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global produce_deepcopy
+produce_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt produce_deepcopy_skip_0
+    ldur x0, [x19, #-24] // load field pointer
+    bl chan_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-24] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+produce_deepcopy_skip_0:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _92_main
+_92_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store c arg in frame
+    ldur x0, [x29, #-8] // load operand
+    mov x1, #1 // operand literal
+    mov x2, #3 // operand literal
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b produce
+.global _92_main_unwrapper
+_92_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-8] // load c env field
+    stur x9, [x29, #-16] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _92_main
+.global _92_main_deep_release
+_92_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _92_main_release_skip_0
+    ldur x9, [x19, #-8] // load _92_main_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl chan_release_ptr // drop a reference to _92_main_release_field_0 channel
+_92_main_release_skip_0:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _92_main_deepcopy
+_92_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _92_main_deepcopy_skip_0
+    ldur x0, [x19, #-8] // load field pointer
+    bl chan_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_92_main_deepcopy_skip_0:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _111_main
+_111_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    mov sp, x29 // unwind before switching
+    ldp x29, x30, [sp], #16
+    b sched_next_ptr // run the next coroutine
+.global _111_main_unwrapper
+_111_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _111_main
+.global _111_main_deep_release
+_111_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _111_main_deepcopy
+_111_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _83_ping
+_83_ping:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store ping arg in frame
+    stur x1, [x29, #-16] // store name arg in frame
+    stur x2, [x29, #-24] // store ok arg in frame
+    stur x3, [x29, #-32] // store m arg in frame
+    ldur x20, [x29, #-8] // load ping closure env_end pointer
+    ldur x9, [x29, #-16] // load operand
+    stur x9, [x20, #-24] // store env field
+    ldur x9, [x29, #-32] // load operand
+    stur x9, [x20, #-16] // store env field
+    ldur x9, [x29, #-24] // load operand
+    stur x9, [x20, #-8] // store env field
+    mov x0, x20 // pass env_end pointer to closure
+    ldr x9, [x0, #0] // load closure unwrapper entry point
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9 // tail call into closure
+.global _83_ping_unwrapper
+_83_ping_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-32] // load ping env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-24] // load name env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-16] // load ok env field
+    stur x9, [x29, #-32] // store value
+    ldur x9, [x19, #-8] // load m env field
+    stur x9, [x29, #-40] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    ldur x3, [x29, #-40] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _83_ping
+.global _83_ping_deep_release
+_83_ping_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #3 // operand literal
+    cmp x9, x20
+    b.gt _83_ping_release_skip_0
+    ldur x9, [x19, #-32] // load _83_ping_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _83_ping_release_field_0 release helper
+    blr x10 // release _83_ping_release_field_0 and what it holds
+    ldp x19, x22, [sp], #16
+_83_ping_release_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _83_ping_release_skip_1
+    ldur x9, [x19, #-24] // load _83_ping_release_field_1 env field
+    stur x9, [x29, #-32] // store value
+    ldur x0, [x29, #-32] // load operand
+    bl release_str_ptr // release _83_ping_release_field_1 string
+_83_ping_release_skip_1:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _83_ping_release_skip_2
+    ldur x9, [x19, #-16] // load _83_ping_release_field_2 env field
+    stur x9, [x29, #-40] // store value
+    ldur x0, [x29, #-40] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _83_ping_release_field_2 release helper
+    blr x10 // release _83_ping_release_field_2 and what it holds
+    ldp x19, x22, [sp], #16
+_83_ping_release_skip_2:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _83_ping_deepcopy
+_83_ping_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #3 // operand literal
+    cmp x9, x20
+    b.gt _83_ping_deepcopy_skip_0
+    ldur x0, [x19, #-32] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-32] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_83_ping_deepcopy_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _83_ping_deepcopy_skip_1
+    ldur x0, [x19, #-24] // load field pointer
+    bl copy_str_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-24] // store duplicated pointer
+    stur x9, [x29, #-32] // store value
+_83_ping_deepcopy_skip_1:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _83_ping_deepcopy_skip_2
+    ldur x0, [x19, #-16] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-16] // store duplicated pointer
+    stur x9, [x29, #-40] // store value
+_83_ping_deepcopy_skip_2:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _80_ping
+_80_ping:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store n arg in frame
+    stur x1, [x29, #-16] // store ping arg in frame
+    stur x2, [x29, #-24] // store name arg in frame
+    stur x3, [x29, #-32] // store ok arg in frame
+    mov x0, #80 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-32] // load operand
+    str x9, [x20, #16] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #32 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #32 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #80 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _83_ping_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_83_ping_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _83_ping_deep_release // load release helper entry point
+    add x9, x9, :lo12:_83_ping_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _83_ping_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_83_ping_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _84_ping closure env_end to x9
+    stur x9, [x29, #-40] // store value
+    ldur x9, [x29, #-8] // load operand
+    mov x20, #1 // operand literal
+    sub x9, x9, x20 // subtract subtrahend
+    ldur x19, [x29, #-40] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _80_ping_unwrapper
+_80_ping_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-32] // load n env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-24] // load ping env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-16] // load name env field
+    stur x9, [x29, #-32] // store value
+    ldur x9, [x19, #-8] // load ok env field
+    stur x9, [x29, #-40] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    ldur x3, [x29, #-40] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _80_ping
+.global _80_ping_deep_release
+_80_ping_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _80_ping_release_skip_1
+    ldur x9, [x19, #-24] // load _80_ping_release_field_1 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _80_ping_release_field_1 release helper
+    blr x10 // release _80_ping_release_field_1 and what it holds
+    ldp x19, x22, [sp], #16
+_80_ping_release_skip_1:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _80_ping_release_skip_2
+    ldur x9, [x19, #-16] // load _80_ping_release_field_2 env field
+    stur x9, [x29, #-32] // store value
+    ldur x0, [x29, #-32] // load operand
+    bl release_str_ptr // release _80_ping_release_field_2 string
+_80_ping_release_skip_2:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _80_ping_release_skip_3
+    ldur x9, [x19, #-8] // load _80_ping_release_field_3 env field
+    stur x9, [x29, #-40] // store value
+    ldur x0, [x29, #-40] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _80_ping_release_field_3 release helper
+    blr x10 // release _80_ping_release_field_3 and what it holds
+    ldp x19, x22, [sp], #16
+_80_ping_release_skip_3:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _80_ping_deepcopy
+_80_ping_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _80_ping_deepcopy_skip_1
+    ldur x0, [x19, #-24] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-24] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_80_ping_deepcopy_skip_1:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _80_ping_deepcopy_skip_2
+    ldur x0, [x19, #-16] // load field pointer
+    bl copy_str_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-16] // store duplicated pointer
+    stur x9, [x29, #-32] // store value
+_80_ping_deepcopy_skip_2:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _80_ping_deepcopy_skip_3
+    ldur x0, [x19, #-8] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-40] // store value
+_80_ping_deepcopy_skip_3:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _78_ping
+_78_ping:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store n arg in frame
+    stur x1, [x29, #-16] // store ping arg in frame
+    stur x2, [x29, #-24] // store name arg in frame
+    stur x3, [x29, #-32] // store ok arg in frame
+    mov x0, #80 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #16] // capture arg into env
+    ldur x9, [x29, #-32] // load operand
+    str x9, [x20, #24] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #32 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #32 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #80 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _80_ping_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_80_ping_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _80_ping_deep_release // load release helper entry point
+    add x9, x9, :lo12:_80_ping_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _80_ping_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_80_ping_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _85_ping closure env_end to x9
+    stur x9, [x29, #-40] // store value
+    ldur x0, [x29, #-40] // load operand
+    bl sched_ready_ptr // run _85_ping later
+    mov sp, x29 // unwind before switching
+    ldp x29, x30, [sp], #16
+    b sched_next_ptr // run the next coroutine
+.global _78_ping_unwrapper
+_78_ping_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-32] // load n env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-24] // load ping env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-16] // load name env field
+    stur x9, [x29, #-32] // store value
+    ldur x9, [x19, #-8] // load ok env field
+    stur x9, [x29, #-40] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    ldur x3, [x29, #-40] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _78_ping
+.global _78_ping_deep_release
+_78_ping_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _78_ping_release_skip_1
+    ldur x9, [x19, #-24] // load _78_ping_release_field_1 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _78_ping_release_field_1 release helper
+    blr x10 // release _78_ping_release_field_1 and what it holds
+    ldp x19, x22, [sp], #16
+_78_ping_release_skip_1:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _78_ping_release_skip_2
+    ldur x9, [x19, #-16] // load _78_ping_release_field_2 env field
+    stur x9, [x29, #-32] // store value
+    ldur x0, [x29, #-32] // load operand
+    bl release_str_ptr // release _78_ping_release_field_2 string
+_78_ping_release_skip_2:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _78_ping_release_skip_3
+    ldur x9, [x19, #-8] // load _78_ping_release_field_3 env field
+    stur x9, [x29, #-40] // store value
+    ldur x0, [x29, #-40] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _78_ping_release_field_3 release helper
+    blr x10 // release _78_ping_release_field_3 and what it holds
+    ldp x19, x22, [sp], #16
+_78_ping_release_skip_3:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _78_ping_deepcopy
+_78_ping_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _78_ping_deepcopy_skip_1
+    ldur x0, [x19, #-24] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-24] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_78_ping_deepcopy_skip_1:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _78_ping_deepcopy_skip_2
+    ldur x0, [x19, #-16] // load field pointer
+    bl copy_str_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-16] // store duplicated pointer
+    stur x9, [x29, #-32] // store value
+_78_ping_deepcopy_skip_2:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _78_ping_deepcopy_skip_3
+    ldur x0, [x19, #-8] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-40] // store value
+_78_ping_deepcopy_skip_3:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _76_ping
+_76_ping:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store n arg in frame
+    stur x1, [x29, #-16] // store ping arg in frame
+    stur x2, [x29, #-24] // store name arg in frame
+    stur x3, [x29, #-32] // store ok arg in frame
+    stur x4, [x29, #-40] // store s arg in frame
+    mov x0, #80 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #16] // capture arg into env
+    ldur x9, [x29, #-32] // load operand
+    str x9, [x20, #24] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #32 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #32 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #80 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _78_ping_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_78_ping_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _78_ping_deep_release // load release helper entry point
+    add x9, x9, :lo12:_78_ping_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _78_ping_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_78_ping_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _86_ping closure env_end to x9
+    stur x9, [x29, #-48] // store value
+    ldur x13, [x29, #-40] // load operand
+    ldr x2, [x13, #-8] // length to write
+    mov x1, x13 // buffer start
+    mov x0, #1 // stdout fd
+    bl write // invoke libc write
+    ldur x0, [x29, #-40] // load operand
+    bl release_str_ptr // release s string
+    ldur x19, [x29, #-48] // load continuation env_end pointer
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _76_ping_unwrapper
+_76_ping_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-40] // load n env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-32] // load ping env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-24] // load name env field
+    stur x9, [x29, #-32] // store value
+    ldur x9, [x19, #-16] // load ok env field
+    stur x9, [x29, #-40] // store value
+    ldur x9, [x19, #-8] // load s env field
+    stur x9, [x29, #-48] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    ldur x3, [x29, #-40] // load operand
+    ldur x4, [x29, #-48] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _76_ping
+.global _76_ping_deep_release
+_76_ping_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #3 // operand literal
+    cmp x9, x20
+    b.gt _76_ping_release_skip_1
+    ldur x9, [x19, #-32] // load _76_ping_release_field_1 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _76_ping_release_field_1 release helper
+    blr x10 // release _76_ping_release_field_1 and what it holds
+    ldp x19, x22, [sp], #16
+_76_ping_release_skip_1:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _76_ping_release_skip_2
+    ldur x9, [x19, #-24] // load _76_ping_release_field_2 env field
+    stur x9, [x29, #-32] // store value
+    ldur x0, [x29, #-32] // load operand
+    bl release_str_ptr // release _76_ping_release_field_2 string
+_76_ping_release_skip_2:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _76_ping_release_skip_3
+    ldur x9, [x19, #-16] // load _76_ping_release_field_3 env field
+    stur x9, [x29, #-40] // store value
+    ldur x0, [x29, #-40] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _76_ping_release_field_3 release helper
+    blr x10 // release _76_ping_release_field_3 and what it holds
+    ldp x19, x22, [sp], #16
+_76_ping_release_skip_3:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _76_ping_release_skip_4
+    ldur x9, [x19, #-8] // load _76_ping_release_field_4 env field
+    stur x9, [x29, #-48] // store value
+    ldur x0, [x29, #-48] // load operand
+    bl release_str_ptr // release _76_ping_release_field_4 string
+_76_ping_release_skip_4:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _76_ping_deepcopy
+_76_ping_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #3 // operand literal
+    cmp x9, x20
+    b.gt _76_ping_deepcopy_skip_1
+    ldur x0, [x19, #-32] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-32] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_76_ping_deepcopy_skip_1:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _76_ping_deepcopy_skip_2
+    ldur x0, [x19, #-24] // load field pointer
+    bl copy_str_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-24] // store duplicated pointer
+    stur x9, [x29, #-32] // store value
+_76_ping_deepcopy_skip_2:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _76_ping_deepcopy_skip_3
+    ldur x0, [x19, #-16] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-16] // store duplicated pointer
+    stur x9, [x29, #-40] // store value
+_76_ping_deepcopy_skip_3:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _76_ping_deepcopy_skip_4
+    ldur x0, [x19, #-8] // load field pointer
+    bl copy_str_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-48] // store value
+_76_ping_deepcopy_skip_4:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _73_ping
+_73_ping:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store name arg in frame
+    stur x1, [x29, #-16] // store n arg in frame
+    stur x2, [x29, #-24] // store ping arg in frame
+    stur x3, [x29, #-32] // store ok arg in frame
+    ldur x0, [x29, #-8] // load operand
+    bl copy_str_ptr // copy name string
+    mov x9, x0
+    stur x9, [x29, #-40] // store value
+    mov x0, #88 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-40] // load operand
+    str x9, [x20, #16] // capture arg into env
+    ldur x9, [x29, #-32] // load operand
+    str x9, [x20, #24] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #40 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #40 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #88 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _76_ping_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_76_ping_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _76_ping_deep_release // load release helper entry point
+    add x9, x9, :lo12:_76_ping_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _76_ping_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_76_ping_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _87_ping closure env_end to x9
+    stur x9, [x29, #-48] // store value
+    adrp x2, _74 // point to string literal
+    add x2, x2, :lo12:_74
+    ldur x3, [x29, #-8] // load operand
+    ldur x4, [x29, #-16] // load operand
+    mov x0, #0 // measure only
+    mov x1, #0
+    bl snprintf // invoke libc snprintf
+    mov x20, x0 // formatted length
+    add x0, x20, #17 // header, bytes and terminator
+    bl alloc_heap_ptr // allocate the string
+    mov x21, x0 // string block
+    add x9, x20, #17
+    str x9, [x21] // allocation size header
+    str x20, [x21, #8] // length header
+    adrp x2, _74 // point to string literal
+    add x2, x2, :lo12:_74
+    ldur x3, [x29, #-8] // load operand
+    ldur x4, [x29, #-16] // load operand
+    add x0, x21, #16 // string bytes follow the header
+    add x1, x20, #1 // room for the terminator
+    bl snprintf // invoke libc snprintf
+    add x9, x21, #16 // return formatted string pointer
+    mov x22, x9 // keep result across releases
+    ldur x0, [x29, #-8] // load operand
+    bl release_str_ptr // release name string
+    mov x9, x22 // restore result
+    ldur x19, [x29, #-48] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _73_ping_unwrapper
+_73_ping_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-32] // load name env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-24] // load n env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-16] // load ping env field
+    stur x9, [x29, #-32] // store value
+    ldur x9, [x19, #-8] // load ok env field
+    stur x9, [x29, #-40] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    ldur x3, [x29, #-40] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _73_ping
+.global _73_ping_deep_release
+_73_ping_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #3 // operand literal
+    cmp x9, x20
+    b.gt _73_ping_release_skip_0
+    ldur x9, [x19, #-32] // load _73_ping_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl release_str_ptr // release _73_ping_release_field_0 string
+_73_ping_release_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _73_ping_release_skip_2
+    ldur x9, [x19, #-16] // load _73_ping_release_field_2 env field
+    stur x9, [x29, #-32] // store value
+    ldur x0, [x29, #-32] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _73_ping_release_field_2 release helper
+    blr x10 // release _73_ping_release_field_2 and what it holds
+    ldp x19, x22, [sp], #16
+_73_ping_release_skip_2:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _73_ping_release_skip_3
+    ldur x9, [x19, #-8] // load _73_ping_release_field_3 env field
+    stur x9, [x29, #-40] // store value
+    ldur x0, [x29, #-40] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _73_ping_release_field_3 release helper
+    blr x10 // release _73_ping_release_field_3 and what it holds
+    ldp x19, x22, [sp], #16
+_73_ping_release_skip_3:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _73_ping_deepcopy
+_73_ping_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #3 // operand literal
+    cmp x9, x20
+    b.gt _73_ping_deepcopy_skip_0
+    ldur x0, [x19, #-32] // load field pointer
+    bl copy_str_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-32] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_73_ping_deepcopy_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _73_ping_deepcopy_skip_2
+    ldur x0, [x19, #-16] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-16] // store duplicated pointer
+    stur x9, [x29, #-32] // store value
+_73_ping_deepcopy_skip_2:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _73_ping_deepcopy_skip_3
+    ldur x0, [x19, #-8] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-40] // store value
+_73_ping_deepcopy_skip_3:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global ping
+ping:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store name arg in frame
+    stur x1, [x29, #-16] // store n arg in frame
+    stur x2, [x29, #-24] // store ok arg in frame
+    mov x0, #72 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #24 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #24 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #72 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, ping_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:ping_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, ping_deep_release // load release helper entry point
+    add x9, x9, :lo12:ping_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, ping_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:ping_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #3 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy ping closure env_end to x9
+    stur x9, [x29, #-32] // store value
+    ldur x20, [x29, #-24] // original closure ok to __ok_copy_0 env_end pointer for clone
+    mov x20, x20 // clone source env_end pointer
+    ldr x21, [x20, #24] // load env size metadata for clone
+    ldr x22, [x20, #32] // load heap size metadata for clone
+    sub x19, x20, x21 // env base pointer for clone source
+    mov x0, x22 // length for cloned environment
+    bl alloc_heap_ptr // allocate cloned env block
+    mov x23, x0 // cloned closure env base pointer
+    mov x11, #0 // bytes copied
+ping_clone_copy_loop_0:
+    cmp x11, x22
+    b.ge ping_clone_copy_done_1
+    ldrb w12, [x19, x11] // duplicate closure env data
+    strb w12, [x23, x11]
+    add x11, x11, #1
+    b ping_clone_copy_loop_0
+ping_clone_copy_done_1:
+    add x20, x23, x21 // compute cloned env_end pointer
+    mov x19, x20 // cloned env_end pointer
+    ldr x9, [x19, #16] // load deepcopy helper entry point
+    str x19, [sp, #-16]! // preserve cloned env_end pointer
+    mov x0, x19 // pass env_end pointer to deepcopy helper
+    blr x9 // deepcopy reference fields
+    ldr x19, [sp], #16 // restore cloned env_end pointer
+    mov x9, x19 // copy cloned env_end pointer
+    stur x9, [x29, #-40] // store value
+    mov x0, #80 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-32] // load operand
+    str x9, [x20, #16] // capture arg into env
+    ldur x9, [x29, #-40] // load operand
+    str x9, [x20, #24] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #32 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #32 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #80 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _73_ping_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_73_ping_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _73_ping_deep_release // load release helper entry point
+    add x9, x9, :lo12:_73_ping_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _73_ping_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_73_ping_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _88_ping closure env_end to x9
+    stur x9, [x29, #-48] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt gt__88_ping_true_0_0
+gt_ok_false_0_0:
+    ldur x0, [x29, #-48] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _88_ping release helper
+    blr x10 // release _88_ping and what it holds
+    ldp x19, x22, [sp], #16
+    ldur x20, [x29, #-24] // load ok closure env_end pointer
+    mov x0, x20 // pass env_end pointer to closure
+    ldr x9, [x0, #0] // load closure unwrapper entry point
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9 // tail call into closure
+gt__88_ping_true_0_0:
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load ok release helper
+    blr x10 // release ok and what it holds
+    ldp x19, x22, [sp], #16
+    ldur x20, [x29, #-48] // load _88_ping closure env_end pointer
+    mov x0, x20 // pass env_end pointer to closure
+    ldr x9, [x0, #0] // load closure unwrapper entry point
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9 // tail call into closure
+.global ping_unwrapper
+ping_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-24] // load name env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-16] // load n env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-8] // load ok env field
+    stur x9, [x29, #-32] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b ping
+.global ping_deep_release
+ping_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt ping_release_skip_0
+    ldur x9, [x19, #-24] // load ping_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl release_str_ptr // release ping_release_field_0 string
+ping_release_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt ping_release_skip_2
+    ldur x9, [x19, #-8] // load ping_release_field_2 env field
+    stur x9, [x29, #-32] // store value
+    ldur x0, [x29, #-32] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load ping_release_field_2 release helper
+    blr x10 // release ping_release_field_2 and what it holds
+    ldp x19, x22, [sp], #16
+ping_release_skip_2:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global ping_deepcopy
+ping_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt ping_deepcopy_skip_0
+    ldur x0, [x19, #-24] // load field pointer
+    bl copy_str_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-24] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+ping_deepcopy_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt ping_deepcopy_skip_2
+    ldur x0, [x19, #-8] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-32] // store value
+ping_deepcopy_skip_2:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _107_main
+_107_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    mov x0, #48 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x9, #0 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #48 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _111_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_111_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _111_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_111_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _111_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_111_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _111_main closure env_end to x9
+    stur x9, [x29, #-8] // store value
+    adrp x0, _108 // point to string literal
+    add x0, x0, :lo12:_108
+    mov x1, #2 // operand literal
+    ldur x2, [x29, #-8] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b ping
+.global _107_main_unwrapper
+_107_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _107_main
+.global _107_main_deep_release
+_107_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _107_main_deepcopy
+_107_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _139_main
+_139_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    mov sp, x29 // unwind before switching
+    ldp x29, x30, [sp], #16
+    b sched_next_ptr // run the next coroutine
+.global _139_main_unwrapper
+_139_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _139_main
+.global _139_main_deep_release
+_139_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _139_main_deepcopy
+_139_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _137_main
+_137_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store s arg in frame
+    mov x0, #48 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x9, #0 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #48 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _139_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_139_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _139_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_139_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _139_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_139_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _139_main closure env_end to x9
+    stur x9, [x29, #-16] // store value
+    ldur x13, [x29, #-8] // load operand
+    ldr x2, [x13, #-8] // length to write
+    mov x1, x13 // buffer start
+    mov x0, #1 // stdout fd
+    bl write // invoke libc write
+    ldur x0, [x29, #-8] // load operand
+    bl release_str_ptr // release s string
+    ldur x19, [x29, #-16] // load continuation env_end pointer
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _137_main_unwrapper
+_137_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-8] // load s env field
+    stur x9, [x29, #-16] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _137_main
+.global _137_main_deep_release
+_137_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _137_main_release_skip_0
+    ldur x9, [x19, #-8] // load _137_main_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl release_str_ptr // release _137_main_release_field_0 string
+_137_main_release_skip_0:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _137_main_deepcopy
+_137_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _137_main_deepcopy_skip_0
+    ldur x0, [x19, #-8] // load field pointer
+    bl copy_str_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_137_main_deepcopy_skip_0:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _134_main
+_134_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store x arg in frame
+    mov x0, #56 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #8 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #8 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #56 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _137_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_137_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _137_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_137_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _137_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_137_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _137_main closure env_end to x9
+    stur x9, [x29, #-16] // store value
+    adrp x2, _135 // point to string literal
+    add x2, x2, :lo12:_135
+    ldur x3, [x29, #-8] // load operand
+    mov x0, #0 // measure only
+    mov x1, #0
+    bl snprintf // invoke libc snprintf
+    mov x20, x0 // formatted length
+    add x0, x20, #17 // header, bytes and terminator
+    bl alloc_heap_ptr // allocate the string
+    mov x21, x0 // string block
+    add x9, x20, #17
+    str x9, [x21] // allocation size header
+    str x20, [x21, #8] // length header
+    adrp x2, _135 // point to string literal
+    add x2, x2, :lo12:_135
+    ldur x3, [x29, #-8] // load operand
+    add x0, x21, #16 // string bytes follow the header
+    add x1, x20, #1 // room for the terminator
+    bl snprintf // invoke libc snprintf
+    add x9, x21, #16 // return formatted string pointer
+    ldur x19, [x29, #-16] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _134_main_unwrapper
+_134_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-8] // load x env field
+    stur x9, [x29, #-16] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _134_main
+.global _134_main_deep_release
+_134_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _134_main_deepcopy
+_134_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _155_main
+_155_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store f arg in frame
+    stur x1, [x29, #-16] // store fc3 arg in frame
+    ldur x0, [x29, #-16] // load operand
+    bl chan_release_ptr // drop a reference to fc3 channel
+    ldur x20, [x29, #-8] // load f closure env_end pointer
+    mov x9, #42 // operand literal
+    stur x9, [x20, #-8] // store env field
+    mov x0, x20 // pass env_end pointer to closure
+    ldr x9, [x0, #0] // load closure unwrapper entry point
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9 // tail call into closure
+.global _155_main_unwrapper
+_155_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-16] // load f env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-8] // load fc3 env field
+    stur x9, [x29, #-24] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _155_main
+.global _155_main_deep_release
+_155_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _155_main_release_skip_0
+    ldur x9, [x19, #-16] // load _155_main_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _155_main_release_field_0 release helper
+    blr x10 // release _155_main_release_field_0 and what it holds
+    ldp x19, x22, [sp], #16
+_155_main_release_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _155_main_release_skip_1
+    ldur x9, [x19, #-8] // load _155_main_release_field_1 env field
+    stur x9, [x29, #-32] // store value
+    ldur x0, [x29, #-32] // load operand
+    bl chan_release_ptr // drop a reference to _155_main_release_field_1 channel
+_155_main_release_skip_1:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _155_main_deepcopy
+_155_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _155_main_deepcopy_skip_0
+    ldur x0, [x19, #-16] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-16] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_155_main_deepcopy_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _155_main_deepcopy_skip_1
+    ldur x0, [x19, #-8] // load field pointer
+    bl chan_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-32] // store value
+_155_main_deepcopy_skip_1:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _147_main
+_147_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store fc2 arg in frame
+    mov x0, #64 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #16 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #16 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #64 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _155_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_155_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _155_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_155_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _155_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_155_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #2 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _155_main closure env_end to x9
+    stur x9, [x29, #-16] // store value
+    ldur x21, [x29, #-8] // load operand
+    ldur x1, [x29, #-16] // load operand
+    mov x0, x21
+    bl chan_recv_ptr // take a queued value or park
+    cbz x1, _147_main_recv_park_0 // parked?
+    mov x9, x0
+    ldur x19, [x29, #-16] // load continuation env_end pointer
+    stur x9, [x19, #-16] // store env field
+    stur x21, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+_147_main_recv_park_0:
+    mov sp, x29 // unwind before switching
+    ldp x29, x30, [sp], #16
+    b sched_next_ptr // run the next coroutine
+.global _147_main_unwrapper
+_147_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-8] // load fc2 env field
+    stur x9, [x29, #-16] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _147_main
+.global _147_main_deep_release
+_147_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _147_main_release_skip_0
+    ldur x9, [x19, #-8] // load _147_main_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl chan_release_ptr // drop a reference to _147_main_release_field_0 channel
+_147_main_release_skip_0:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _147_main_deepcopy
+_147_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _147_main_deepcopy_skip_0
+    ldur x0, [x19, #-8] // load field pointer
+    bl chan_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_147_main_deepcopy_skip_0:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _132_main
+_132_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store fc arg in frame
+    mov x0, #56 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #8 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #8 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #56 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _134_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_134_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _134_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_134_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _134_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_134_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _134_main closure env_end to x9
+    stur x9, [x29, #-16] // store value
+    mov x0, #56 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #8 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #8 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #56 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _147_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_147_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _147_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_147_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _147_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_147_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _147_main closure env_end to x9
+    stur x9, [x29, #-24] // store value
+    ldur x21, [x29, #-8] // load operand
+    ldur x1, [x29, #-16] // load operand
+    mov x0, x21
+    bl chan_send_ptr // hand the value over or queue it
+    ldur x19, [x29, #-24] // load continuation env_end pointer
+    stur x21, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _132_main_unwrapper
+_132_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-8] // load fc env field
+    stur x9, [x29, #-16] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _132_main
+.global _132_main_deep_release
+_132_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _132_main_release_skip_0
+    ldur x9, [x19, #-8] // load _132_main_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl chan_release_ptr // drop a reference to _132_main_release_field_0 channel
+_132_main_release_skip_0:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _132_main_deepcopy
+_132_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _132_main_deepcopy_skip_0
+    ldur x0, [x19, #-8] // load field pointer
+    bl chan_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_132_main_deepcopy_skip_0:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _127_main
+_127_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store left arg in frame
+    mov x0, #56 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #8 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #8 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #56 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _132_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_132_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _132_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_132_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _132_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_132_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _132_main closure env_end to x9
+    stur x9, [x29, #-16] // store value
+    ldur x0, [x29, #-8] // load operand
+    bl chan_release_ptr // drop a reference to left channel
+    mov x0, #3 // class of the values
+    bl chan_new_ptr // make the channel
+    mov x9, x0
+    ldur x19, [x29, #-16] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _127_main_unwrapper
+_127_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-8] // load left env field
+    stur x9, [x29, #-16] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _127_main
+.global _127_main_deep_release
+_127_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _127_main_release_skip_0
+    ldur x9, [x19, #-8] // load _127_main_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl chan_release_ptr // drop a reference to _127_main_release_field_0 channel
+_127_main_release_skip_0:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _127_main_deepcopy
+_127_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _127_main_deepcopy_skip_0
+    ldur x0, [x19, #-8] // load field pointer
+    bl chan_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_127_main_deepcopy_skip_0:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _125_main
+_125_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store sc arg in frame
+    stur x1, [x29, #-16] // store s arg in frame
+    mov x0, #56 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #8 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #8 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #56 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _127_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_127_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _127_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_127_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _127_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_127_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _127_main closure env_end to x9
+    stur x9, [x29, #-24] // store value
+    ldur x21, [x29, #-8] // load operand
+    ldur x1, [x29, #-16] // load operand
+    mov x0, x21
+    bl chan_send_ptr // hand the value over or queue it
+    ldur x19, [x29, #-24] // load continuation env_end pointer
+    stur x21, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _125_main_unwrapper
+_125_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-16] // load sc env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-8] // load s env field
+    stur x9, [x29, #-24] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _125_main
+.global _125_main_deep_release
+_125_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _125_main_release_skip_0
+    ldur x9, [x19, #-16] // load _125_main_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl chan_release_ptr // drop a reference to _125_main_release_field_0 channel
+_125_main_release_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _125_main_release_skip_1
+    ldur x9, [x19, #-8] // load _125_main_release_field_1 env field
+    stur x9, [x29, #-32] // store value
+    ldur x0, [x29, #-32] // load operand
+    bl release_str_ptr // release _125_main_release_field_1 string
+_125_main_release_skip_1:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _125_main_deepcopy
+_125_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _125_main_deepcopy_skip_0
+    ldur x0, [x19, #-16] // load field pointer
+    bl chan_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-16] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_125_main_deepcopy_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _125_main_deepcopy_skip_1
+    ldur x0, [x19, #-8] // load field pointer
+    bl copy_str_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-32] // store value
+_125_main_deepcopy_skip_1:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _121_main
+_121_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store sc arg in frame
+    mov x0, #64 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #0] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #16 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #16 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #64 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _125_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_125_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _125_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_125_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _125_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_125_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _161_main closure env_end to x9
+    stur x9, [x29, #-16] // store value
+    adrp x2, _122 // point to string literal
+    add x2, x2, :lo12:_122
+    adrp x3, _123 // point to string literal
+    add x3, x3, :lo12:_123
+    mov x0, #0 // measure only
+    mov x1, #0
+    bl snprintf // invoke libc snprintf
+    mov x20, x0 // formatted length
+    add x0, x20, #17 // header, bytes and terminator
+    bl alloc_heap_ptr // allocate the string
+    mov x21, x0 // string block
+    add x9, x20, #17
+    str x9, [x21] // allocation size header
+    str x20, [x21, #8] // length header
+    adrp x2, _122 // point to string literal
+    add x2, x2, :lo12:_122
+    adrp x3, _123 // point to string literal
+    add x3, x3, :lo12:_123
+    add x0, x21, #16 // string bytes follow the header
+    add x1, x20, #1 // room for the terminator
+    bl snprintf // invoke libc snprintf
+    add x9, x21, #16 // return formatted string pointer
+    ldur x19, [x29, #-16] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _121_main_unwrapper
+_121_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-8] // load sc env field
+    stur x9, [x29, #-16] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _121_main
+.global _121_main_deep_release
+_121_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _121_main_release_skip_0
+    ldur x9, [x19, #-8] // load _121_main_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl chan_release_ptr // drop a reference to _121_main_release_field_0 channel
+_121_main_release_skip_0:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _121_main_deepcopy
+_121_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _121_main_deepcopy_skip_0
+    ldur x0, [x19, #-8] // load field pointer
+    bl chan_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_121_main_deepcopy_skip_0:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _119_main
+_119_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    mov x0, #56 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #8 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #8 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #56 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _121_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_121_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _121_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_121_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _121_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_121_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _121_main closure env_end to x9
+    stur x9, [x29, #-8] // store value
+    mov x0, #1 // class of the values
+    bl chan_new_ptr // make the channel
+    mov x9, x0
+    ldur x19, [x29, #-8] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _119_main_unwrapper
+_119_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _119_main
+.global _119_main_deep_release
+_119_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _119_main_deepcopy
+_119_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _115_main
+_115_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    mov x0, #48 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x9, #0 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #48 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _119_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_119_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _119_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_119_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _119_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_119_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _119_main closure env_end to x9
+    stur x9, [x29, #-8] // store value
+    adrp x0, _116 // point to string literal
+    add x0, x0, :lo12:_116
+    mov x1, #2 // operand literal
+    ldur x2, [x29, #-8] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b ping
+.global _115_main_unwrapper
+_115_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _115_main
+.global _115_main_deep_release
+_115_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _115_main_deepcopy
+_115_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _105_main
+_105_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    mov x0, #48 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x9, #0 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #48 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _107_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_107_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _107_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_107_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _107_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_107_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _107_main closure env_end to x9
+    stur x9, [x29, #-8] // store value
+    mov x0, #48 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x9, #0 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #48 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _115_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_115_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _115_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_115_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _115_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_115_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _115_main closure env_end to x9
+    stur x9, [x29, #-16] // store value
+    ldur x0, [x29, #-8] // load operand
+    bl sched_ready_ptr // run _107_main later
+    ldur x19, [x29, #-16] // load continuation env_end pointer
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _105_main_unwrapper
+_105_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _105_main
+.global _105_main_deep_release
+_105_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _105_main_deepcopy
+_105_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _103_main
+_103_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store s arg in frame
+    mov x0, #48 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x9, #0 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #48 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _105_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_105_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _105_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_105_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _105_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_105_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _105_main closure env_end to x9
+    stur x9, [x29, #-16] // store value
+    ldur x13, [x29, #-8] // load operand
+    ldr x2, [x13, #-8] // length to write
+    mov x1, x13 // buffer start
+    mov x0, #1 // stdout fd
+    bl write // invoke libc write
+    ldur x0, [x29, #-8] // load operand
+    bl release_str_ptr // release s string
+    ldur x19, [x29, #-16] // load continuation env_end pointer
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _103_main_unwrapper
+_103_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-8] // load s env field
+    stur x9, [x29, #-16] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _103_main
+.global _103_main_deep_release
+_103_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _103_main_release_skip_0
+    ldur x9, [x19, #-8] // load _103_main_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl release_str_ptr // release _103_main_release_field_0 string
+_103_main_release_skip_0:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _103_main_deepcopy
+_103_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _103_main_deepcopy_skip_0
+    ldur x0, [x19, #-8] // load field pointer
+    bl copy_str_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_103_main_deepcopy_skip_0:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _100_main
+_100_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store total arg in frame
+    mov x0, #56 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #8 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #8 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #56 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _103_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_103_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _103_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_103_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _103_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_103_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _103_main closure env_end to x9
+    stur x9, [x29, #-16] // store value
+    adrp x2, _101 // point to string literal
+    add x2, x2, :lo12:_101
+    ldur x3, [x29, #-8] // load operand
+    mov x0, #0 // measure only
+    mov x1, #0
+    bl snprintf // invoke libc snprintf
+    mov x20, x0 // formatted length
+    add x0, x20, #17 // header, bytes and terminator
+    bl alloc_heap_ptr // allocate the string
+    mov x21, x0 // string block
+    add x9, x20, #17
+    str x9, [x21] // allocation size header
+    str x20, [x21, #8] // length header
+    adrp x2, _101 // point to string literal
+    add x2, x2, :lo12:_101
+    ldur x3, [x29, #-8] // load operand
+    add x0, x21, #16 // string bytes follow the header
+    add x1, x20, #1 // room for the terminator
+    bl snprintf // invoke libc snprintf
+    add x9, x21, #16 // return formatted string pointer
+    ldur x19, [x29, #-16] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _100_main_unwrapper
+_100_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-8] // load total env field
+    stur x9, [x29, #-16] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _100_main
+.global _100_main_deep_release
+_100_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _100_main_deepcopy
+_100_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _55_consume
+_55_consume:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store ok arg in frame
+    stur x1, [x29, #-16] // store total arg in frame
+    ldur x20, [x29, #-8] // load ok closure env_end pointer
+    ldur x9, [x29, #-16] // load operand
+    stur x9, [x20, #-8] // store env field
+    mov x0, x20 // pass env_end pointer to closure
+    ldr x9, [x0, #0] // load closure unwrapper entry point
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9 // tail call into closure
+.global _55_consume_unwrapper
+_55_consume_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-16] // load ok env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-8] // load total env field
+    stur x9, [x29, #-24] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _55_consume
+.global _55_consume_deep_release
+_55_consume_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _55_consume_release_skip_0
+    ldur x9, [x19, #-16] // load _55_consume_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _55_consume_release_field_0 release helper
+    blr x10 // release _55_consume_release_field_0 and what it holds
+    ldp x19, x22, [sp], #16
+_55_consume_release_skip_0:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _55_consume_deepcopy
+_55_consume_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _55_consume_deepcopy_skip_0
+    ldur x0, [x19, #-16] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-16] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_55_consume_deepcopy_skip_0:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _65_consume
+_65_consume:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store consume arg in frame
+    stur x1, [x29, #-16] // store rest arg in frame
+    stur x2, [x29, #-24] // store ok arg in frame
+    stur x3, [x29, #-32] // store t arg in frame
+    ldur x20, [x29, #-8] // load consume closure env_end pointer
+    ldur x9, [x29, #-16] // load operand
+    stur x9, [x20, #-24] // store env field
+    ldur x9, [x29, #-32] // load operand
+    stur x9, [x20, #-16] // store env field
+    ldur x9, [x29, #-24] // load operand
+    stur x9, [x20, #-8] // store env field
+    mov x0, x20 // pass env_end pointer to closure
+    ldr x9, [x0, #0] // load closure unwrapper entry point
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9 // tail call into closure
+.global _65_consume_unwrapper
+_65_consume_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-32] // load consume env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-24] // load rest env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-16] // load ok env field
+    stur x9, [x29, #-32] // store value
+    ldur x9, [x19, #-8] // load t env field
+    stur x9, [x29, #-40] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    ldur x3, [x29, #-40] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _65_consume
+.global _65_consume_deep_release
+_65_consume_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #3 // operand literal
+    cmp x9, x20
+    b.gt _65_consume_release_skip_0
+    ldur x9, [x19, #-32] // load _65_consume_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _65_consume_release_field_0 release helper
+    blr x10 // release _65_consume_release_field_0 and what it holds
+    ldp x19, x22, [sp], #16
+_65_consume_release_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _65_consume_release_skip_1
+    ldur x9, [x19, #-24] // load _65_consume_release_field_1 env field
+    stur x9, [x29, #-32] // store value
+    ldur x0, [x29, #-32] // load operand
+    bl chan_release_ptr // drop a reference to _65_consume_release_field_1 channel
+_65_consume_release_skip_1:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _65_consume_release_skip_2
+    ldur x9, [x19, #-16] // load _65_consume_release_field_2 env field
+    stur x9, [x29, #-40] // store value
+    ldur x0, [x29, #-40] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _65_consume_release_field_2 release helper
+    blr x10 // release _65_consume_release_field_2 and what it holds
+    ldp x19, x22, [sp], #16
+_65_consume_release_skip_2:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _65_consume_deepcopy
+_65_consume_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #3 // operand literal
+    cmp x9, x20
+    b.gt _65_consume_deepcopy_skip_0
+    ldur x0, [x19, #-32] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-32] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_65_consume_deepcopy_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _65_consume_deepcopy_skip_1
+    ldur x0, [x19, #-24] // load field pointer
+    bl chan_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-24] // store duplicated pointer
+    stur x9, [x29, #-32] // store value
+_65_consume_deepcopy_skip_1:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _65_consume_deepcopy_skip_2
+    ldur x0, [x19, #-16] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-16] // store duplicated pointer
+    stur x9, [x29, #-40] // store value
+_65_consume_deepcopy_skip_2:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _63_consume
+_63_consume:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store total arg in frame
+    stur x1, [x29, #-16] // store v arg in frame
+    stur x2, [x29, #-24] // store consume arg in frame
+    stur x3, [x29, #-32] // store rest arg in frame
+    stur x4, [x29, #-40] // store ok arg in frame
+    mov x0, #80 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-32] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-40] // load operand
+    str x9, [x20, #16] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #32 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #32 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #80 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _65_consume_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_65_consume_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _65_consume_deep_release // load release helper entry point
+    add x9, x9, :lo12:_65_consume_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _65_consume_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_65_consume_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _66_consume closure env_end to x9
+    stur x9, [x29, #-48] // store value
+    ldur x9, [x29, #-8] // load operand
+    ldur x20, [x29, #-16] // load operand
+    add x9, x9, x20 // add second integer
+    ldur x19, [x29, #-48] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _63_consume_unwrapper
+_63_consume_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-40] // load total env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-32] // load v env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-24] // load consume env field
+    stur x9, [x29, #-32] // store value
+    ldur x9, [x19, #-16] // load rest env field
+    stur x9, [x29, #-40] // store value
+    ldur x9, [x19, #-8] // load ok env field
+    stur x9, [x29, #-48] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    ldur x3, [x29, #-40] // load operand
+    ldur x4, [x29, #-48] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _63_consume
+.global _63_consume_deep_release
+_63_consume_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _63_consume_release_skip_2
+    ldur x9, [x19, #-24] // load _63_consume_release_field_2 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _63_consume_release_field_2 release helper
+    blr x10 // release _63_consume_release_field_2 and what it holds
+    ldp x19, x22, [sp], #16
+_63_consume_release_skip_2:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _63_consume_release_skip_3
+    ldur x9, [x19, #-16] // load _63_consume_release_field_3 env field
+    stur x9, [x29, #-32] // store value
+    ldur x0, [x29, #-32] // load operand
+    bl chan_release_ptr // drop a reference to _63_consume_release_field_3 channel
+_63_consume_release_skip_3:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _63_consume_release_skip_4
+    ldur x9, [x19, #-8] // load _63_consume_release_field_4 env field
+    stur x9, [x29, #-40] // store value
+    ldur x0, [x29, #-40] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _63_consume_release_field_4 release helper
+    blr x10 // release _63_consume_release_field_4 and what it holds
+    ldp x19, x22, [sp], #16
+_63_consume_release_skip_4:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _63_consume_deepcopy
+_63_consume_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _63_consume_deepcopy_skip_2
+    ldur x0, [x19, #-24] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-24] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_63_consume_deepcopy_skip_2:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _63_consume_deepcopy_skip_3
+    ldur x0, [x19, #-16] // load field pointer
+    bl chan_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-16] // store duplicated pointer
+    stur x9, [x29, #-32] // store value
+_63_consume_deepcopy_skip_3:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _63_consume_deepcopy_skip_4
+    ldur x0, [x19, #-8] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-40] // store value
+_63_consume_deepcopy_skip_4:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _61_consume
+_61_consume:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #64 // reserve stack space for locals
+    stur x0, [x29, #-8] // store total arg in frame
+    stur x1, [x29, #-16] // store v arg in frame
+    stur x2, [x29, #-24] // store consume arg in frame
+    stur x3, [x29, #-32] // store rest arg in frame
+    stur x4, [x29, #-40] // store ok arg in frame
+    stur x5, [x29, #-48] // store s arg in frame
+    mov x0, #88 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #16] // capture arg into env
+    ldur x9, [x29, #-32] // load operand
+    str x9, [x20, #24] // capture arg into env
+    ldur x9, [x29, #-40] // load operand
+    str x9, [x20, #32] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #40 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #40 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #88 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _63_consume_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_63_consume_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _63_consume_deep_release // load release helper entry point
+    add x9, x9, :lo12:_63_consume_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _63_consume_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_63_consume_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _67_consume closure env_end to x9
+    stur x9, [x29, #-56] // store value
+    ldur x13, [x29, #-48] // load operand
+    ldr x2, [x13, #-8] // length to write
+    mov x1, x13 // buffer start
+    mov x0, #1 // stdout fd
+    bl write // invoke libc write
+    ldur x0, [x29, #-48] // load operand
+    bl release_str_ptr // release s string
+    ldur x19, [x29, #-56] // load continuation env_end pointer
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _61_consume_unwrapper
+_61_consume_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #64 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-48] // load total env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-40] // load v env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-32] // load consume env field
+    stur x9, [x29, #-32] // store value
+    ldur x9, [x19, #-24] // load rest env field
+    stur x9, [x29, #-40] // store value
+    ldur x9, [x19, #-16] // load ok env field
+    stur x9, [x29, #-48] // store value
+    ldur x9, [x19, #-8] // load s env field
+    stur x9, [x29, #-56] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    ldur x3, [x29, #-40] // load operand
+    ldur x4, [x29, #-48] // load operand
+    ldur x5, [x29, #-56] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _61_consume
+.global _61_consume_deep_release
+_61_consume_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #3 // operand literal
+    cmp x9, x20
+    b.gt _61_consume_release_skip_2
+    ldur x9, [x19, #-32] // load _61_consume_release_field_2 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _61_consume_release_field_2 release helper
+    blr x10 // release _61_consume_release_field_2 and what it holds
+    ldp x19, x22, [sp], #16
+_61_consume_release_skip_2:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _61_consume_release_skip_3
+    ldur x9, [x19, #-24] // load _61_consume_release_field_3 env field
+    stur x9, [x29, #-32] // store value
+    ldur x0, [x29, #-32] // load operand
+    bl chan_release_ptr // drop a reference to _61_consume_release_field_3 channel
+_61_consume_release_skip_3:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _61_consume_release_skip_4
+    ldur x9, [x19, #-16] // load _61_consume_release_field_4 env field
+    stur x9, [x29, #-40] // store value
+    ldur x0, [x29, #-40] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _61_consume_release_field_4 release helper
+    blr x10 // release _61_consume_release_field_4 and what it holds
+    ldp x19, x22, [sp], #16
+_61_consume_release_skip_4:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _61_consume_release_skip_5
+    ldur x9, [x19, #-8] // load _61_consume_release_field_5 env field
+    stur x9, [x29, #-48] // store value
+    ldur x0, [x29, #-48] // load operand
+    bl release_str_ptr // release _61_consume_release_field_5 string
+_61_consume_release_skip_5:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _61_consume_deepcopy
+_61_consume_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #3 // operand literal
+    cmp x9, x20
+    b.gt _61_consume_deepcopy_skip_2
+    ldur x0, [x19, #-32] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-32] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_61_consume_deepcopy_skip_2:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _61_consume_deepcopy_skip_3
+    ldur x0, [x19, #-24] // load field pointer
+    bl chan_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-24] // store duplicated pointer
+    stur x9, [x29, #-32] // store value
+_61_consume_deepcopy_skip_3:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _61_consume_deepcopy_skip_4
+    ldur x0, [x19, #-16] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-16] // store duplicated pointer
+    stur x9, [x29, #-40] // store value
+_61_consume_deepcopy_skip_4:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _61_consume_deepcopy_skip_5
+    ldur x0, [x19, #-8] // load field pointer
+    bl copy_str_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-48] // store value
+_61_consume_deepcopy_skip_5:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _58_consume
+_58_consume:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store v arg in frame
+    stur x1, [x29, #-16] // store total arg in frame
+    stur x2, [x29, #-24] // store consume arg in frame
+    stur x3, [x29, #-32] // store rest arg in frame
+    stur x4, [x29, #-40] // store ok arg in frame
+    mov x0, #96 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #16] // capture arg into env
+    ldur x9, [x29, #-32] // load operand
+    str x9, [x20, #24] // capture arg into env
+    ldur x9, [x29, #-40] // load operand
+    str x9, [x20, #32] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #48 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #48 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #96 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _61_consume_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_61_consume_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _61_consume_deep_release // load release helper entry point
+    add x9, x9, :lo12:_61_consume_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _61_consume_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_61_consume_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _68_consume closure env_end to x9
+    stur x9, [x29, #-48] // store value
+    adrp x2, _59 // point to string literal
+    add x2, x2, :lo12:_59
+    ldur x3, [x29, #-8] // load operand
+    mov x0, #0 // measure only
+    mov x1, #0
+    bl snprintf // invoke libc snprintf
+    mov x20, x0 // formatted length
+    add x0, x20, #17 // header, bytes and terminator
+    bl alloc_heap_ptr // allocate the string
+    mov x21, x0 // string block
+    add x9, x20, #17
+    str x9, [x21] // allocation size header
+    str x20, [x21, #8] // length header
+    adrp x2, _59 // point to string literal
+    add x2, x2, :lo12:_59
+    ldur x3, [x29, #-8] // load operand
+    add x0, x21, #16 // string bytes follow the header
+    add x1, x20, #1 // room for the terminator
+    bl snprintf // invoke libc snprintf
+    add x9, x21, #16 // return formatted string pointer
+    ldur x19, [x29, #-48] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _58_consume_unwrapper
+_58_consume_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-40] // load v env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-32] // load total env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-24] // load consume env field
+    stur x9, [x29, #-32] // store value
+    ldur x9, [x19, #-16] // load rest env field
+    stur x9, [x29, #-40] // store value
+    ldur x9, [x19, #-8] // load ok env field
+    stur x9, [x29, #-48] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    ldur x3, [x29, #-40] // load operand
+    ldur x4, [x29, #-48] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _58_consume
+.global _58_consume_deep_release
+_58_consume_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _58_consume_release_skip_2
+    ldur x9, [x19, #-24] // load _58_consume_release_field_2 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _58_consume_release_field_2 release helper
+    blr x10 // release _58_consume_release_field_2 and what it holds
+    ldp x19, x22, [sp], #16
+_58_consume_release_skip_2:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _58_consume_release_skip_3
+    ldur x9, [x19, #-16] // load _58_consume_release_field_3 env field
+    stur x9, [x29, #-32] // store value
+    ldur x0, [x29, #-32] // load operand
+    bl chan_release_ptr // drop a reference to _58_consume_release_field_3 channel
+_58_consume_release_skip_3:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _58_consume_release_skip_4
+    ldur x9, [x19, #-8] // load _58_consume_release_field_4 env field
+    stur x9, [x29, #-40] // store value
+    ldur x0, [x29, #-40] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _58_consume_release_field_4 release helper
+    blr x10 // release _58_consume_release_field_4 and what it holds
+    ldp x19, x22, [sp], #16
+_58_consume_release_skip_4:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _58_consume_deepcopy
+_58_consume_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _58_consume_deepcopy_skip_2
+    ldur x0, [x19, #-24] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-24] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_58_consume_deepcopy_skip_2:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _58_consume_deepcopy_skip_3
+    ldur x0, [x19, #-16] // load field pointer
+    bl chan_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-16] // store duplicated pointer
+    stur x9, [x29, #-32] // store value
+_58_consume_deepcopy_skip_3:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _58_consume_deepcopy_skip_4
+    ldur x0, [x19, #-8] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-40] // store value
+_58_consume_deepcopy_skip_4:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _52_consume
+_52_consume:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #64 // reserve stack space for locals
+    stur x0, [x29, #-8] // store ok arg in frame
+    stur x1, [x29, #-16] // store total arg in frame
+    stur x2, [x29, #-24] // store consume arg in frame
+    stur x3, [x29, #-32] // store v arg in frame
+    stur x4, [x29, #-40] // store rest arg in frame
+    ldur x20, [x29, #-8] // original closure ok to __ok_copy_0 env_end pointer for clone
+    mov x20, x20 // clone source env_end pointer
+    ldr x21, [x20, #24] // load env size metadata for clone
+    ldr x22, [x20, #32] // load heap size metadata for clone
+    sub x19, x20, x21 // env base pointer for clone source
+    mov x0, x22 // length for cloned environment
+    bl alloc_heap_ptr // allocate cloned env block
+    mov x23, x0 // cloned closure env base pointer
+    mov x11, #0 // bytes copied
+_52_consume_clone_copy_loop_0:
+    cmp x11, x22
+    b.ge _52_consume_clone_copy_done_1
+    ldrb w12, [x19, x11] // duplicate closure env data
+    strb w12, [x23, x11]
+    add x11, x11, #1
+    b _52_consume_clone_copy_loop_0
+_52_consume_clone_copy_done_1:
+    add x20, x23, x21 // compute cloned env_end pointer
+    mov x19, x20 // cloned env_end pointer
+    ldr x9, [x19, #16] // load deepcopy helper entry point
+    str x19, [sp, #-16]! // preserve cloned env_end pointer
+    mov x0, x19 // pass env_end pointer to deepcopy helper
+    blr x9 // deepcopy reference fields
+    ldr x19, [sp], #16 // restore cloned env_end pointer
+    mov x9, x19 // copy cloned env_end pointer
+    stur x9, [x29, #-48] // store value
+    mov x0, #64 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-48] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #8] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #16 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #16 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #64 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _55_consume_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_55_consume_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _55_consume_deep_release // load release helper entry point
+    add x9, x9, :lo12:_55_consume_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _55_consume_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_55_consume_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _56_consume closure env_end to x9
+    stur x9, [x29, #-56] // store value
+    mov x0, #88 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-32] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #16] // capture arg into env
+    ldur x9, [x29, #-40] // load operand
+    str x9, [x20, #24] // capture arg into env
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #32] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #40 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #40 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #88 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _58_consume_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_58_consume_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _58_consume_deep_release // load release helper entry point
+    add x9, x9, :lo12:_58_consume_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _58_consume_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_58_consume_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _69_consume closure env_end to x9
+    stur x9, [x29, #-64] // store value
+    ldur x9, [x29, #-32] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.eq eqi__56_consume_true_0_0
+eqi__69_consume_false_0_0:
+    ldur x0, [x29, #-56] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _56_consume release helper
+    blr x10 // release _56_consume and what it holds
+    ldp x19, x22, [sp], #16
+    ldur x20, [x29, #-64] // load _69_consume closure env_end pointer
+    mov x0, x20 // pass env_end pointer to closure
+    ldr x9, [x0, #0] // load closure unwrapper entry point
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9 // tail call into closure
+eqi__56_consume_true_0_0:
+    ldur x0, [x29, #-64] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _69_consume release helper
+    blr x10 // release _69_consume and what it holds
+    ldp x19, x22, [sp], #16
+    ldur x20, [x29, #-56] // load _56_consume closure env_end pointer
+    mov x0, x20 // pass env_end pointer to closure
+    ldr x9, [x0, #0] // load closure unwrapper entry point
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9 // tail call into closure
+.global _52_consume_unwrapper
+_52_consume_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-40] // load ok env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-32] // load total env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-24] // load consume env field
+    stur x9, [x29, #-32] // store value
+    ldur x9, [x19, #-16] // load v env field
+    stur x9, [x29, #-40] // store value
+    ldur x9, [x19, #-8] // load rest env field
+    stur x9, [x29, #-48] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    ldur x3, [x29, #-40] // load operand
+    ldur x4, [x29, #-48] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _52_consume
+.global _52_consume_deep_release
+_52_consume_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #4 // operand literal
+    cmp x9, x20
+    b.gt _52_consume_release_skip_0
+    ldur x9, [x19, #-40] // load _52_consume_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _52_consume_release_field_0 release helper
+    blr x10 // release _52_consume_release_field_0 and what it holds
+    ldp x19, x22, [sp], #16
+_52_consume_release_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _52_consume_release_skip_2
+    ldur x9, [x19, #-24] // load _52_consume_release_field_2 env field
+    stur x9, [x29, #-32] // store value
+    ldur x0, [x29, #-32] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _52_consume_release_field_2 release helper
+    blr x10 // release _52_consume_release_field_2 and what it holds
+    ldp x19, x22, [sp], #16
+_52_consume_release_skip_2:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _52_consume_release_skip_4
+    ldur x9, [x19, #-8] // load _52_consume_release_field_4 env field
+    stur x9, [x29, #-40] // store value
+    ldur x0, [x29, #-40] // load operand
+    bl chan_release_ptr // drop a reference to _52_consume_release_field_4 channel
+_52_consume_release_skip_4:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _52_consume_deepcopy
+_52_consume_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #4 // operand literal
+    cmp x9, x20
+    b.gt _52_consume_deepcopy_skip_0
+    ldur x0, [x19, #-40] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-40] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_52_consume_deepcopy_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _52_consume_deepcopy_skip_2
+    ldur x0, [x19, #-24] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-24] // store duplicated pointer
+    stur x9, [x29, #-32] // store value
+_52_consume_deepcopy_skip_2:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _52_consume_deepcopy_skip_4
+    ldur x0, [x19, #-8] // load field pointer
+    bl chan_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-40] // store value
+_52_consume_deepcopy_skip_4:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global consume
+consume:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store c arg in frame
+    stur x1, [x29, #-16] // store total arg in frame
+    stur x2, [x29, #-24] // store ok arg in frame
+    mov x0, #72 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #24 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #24 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #72 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, consume_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:consume_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, consume_deep_release // load release helper entry point
+    add x9, x9, :lo12:consume_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, consume_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:consume_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #3 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy consume closure env_end to x9
+    stur x9, [x29, #-32] // store value
+    mov x0, #88 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-32] // load operand
+    str x9, [x20, #16] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #40 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #40 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #88 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _52_consume_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_52_consume_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _52_consume_deep_release // load release helper entry point
+    add x9, x9, :lo12:_52_consume_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _52_consume_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_52_consume_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #2 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _70_consume closure env_end to x9
+    stur x9, [x29, #-40] // store value
+    ldur x21, [x29, #-8] // load operand
+    ldur x1, [x29, #-40] // load operand
+    mov x0, x21
+    bl chan_recv_ptr // take a queued value or park
+    cbz x1, consume_recv_park_0 // parked?
+    mov x9, x0
+    ldur x19, [x29, #-40] // load continuation env_end pointer
+    stur x9, [x19, #-16] // store env field
+    stur x21, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+consume_recv_park_0:
+    mov sp, x29 // unwind before switching
+    ldp x29, x30, [sp], #16
+    b sched_next_ptr // run the next coroutine
+.global consume_unwrapper
+consume_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-24] // load c env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-16] // load total env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-8] // load ok env field
+    stur x9, [x29, #-32] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b consume
+.global consume_deep_release
+consume_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt consume_release_skip_0
+    ldur x9, [x19, #-24] // load consume_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl chan_release_ptr // drop a reference to consume_release_field_0 channel
+consume_release_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt consume_release_skip_2
+    ldur x9, [x19, #-8] // load consume_release_field_2 env field
+    stur x9, [x29, #-32] // store value
+    ldur x0, [x29, #-32] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load consume_release_field_2 release helper
+    blr x10 // release consume_release_field_2 and what it holds
+    ldp x19, x22, [sp], #16
+consume_release_skip_2:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global consume_deepcopy
+consume_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt consume_deepcopy_skip_0
+    ldur x0, [x19, #-24] // load field pointer
+    bl chan_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-24] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+consume_deepcopy_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt consume_deepcopy_skip_2
+    ldur x0, [x19, #-8] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-32] // store value
+consume_deepcopy_skip_2:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _97_main
+_97_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store c arg in frame
+    mov x0, #56 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #8 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #8 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #56 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _100_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_100_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _100_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_100_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _100_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_100_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _100_main closure env_end to x9
+    stur x9, [x29, #-16] // store value
+    ldur x0, [x29, #-8] // load operand
+    mov x1, #0 // operand literal
+    ldur x2, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b consume
+.global _97_main_unwrapper
+_97_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-8] // load c env field
+    stur x9, [x29, #-16] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _97_main
+.global _97_main_deep_release
+_97_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _97_main_release_skip_0
+    ldur x9, [x19, #-8] // load _97_main_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl chan_release_ptr // drop a reference to _97_main_release_field_0 channel
+_97_main_release_skip_0:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _97_main_deepcopy
+_97_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _97_main_deepcopy_skip_0
+    ldur x0, [x19, #-8] // load field pointer
+    bl chan_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_97_main_deepcopy_skip_0:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _90_main
+_90_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store c arg in frame
+    ldur x0, [x29, #-8] // load operand
+    bl chan_copy_ptr // another reference to c channel
+    mov x9, x0
+    stur x9, [x29, #-16] // store value
+    mov x0, #56 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #0] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #8 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #8 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #56 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _92_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_92_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _92_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_92_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _92_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_92_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _95_main closure env_end to x9
+    stur x9, [x29, #-24] // store value
+    mov x0, #56 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #0] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #8 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #8 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #56 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _97_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_97_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _97_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_97_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _97_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_97_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _168_main closure env_end to x9
+    stur x9, [x29, #-32] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl sched_ready_ptr // run _95_main later
+    ldur x19, [x29, #-32] // load continuation env_end pointer
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _90_main_unwrapper
+_90_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-8] // load c env field
+    stur x9, [x29, #-16] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _90_main
+.global _90_main_deep_release
+_90_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _90_main_release_skip_0
+    ldur x9, [x19, #-8] // load _90_main_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl chan_release_ptr // drop a reference to _90_main_release_field_0 channel
+_90_main_release_skip_0:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _90_main_deepcopy
+_90_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _90_main_deepcopy_skip_0
+    ldur x0, [x19, #-8] // load field pointer
+    bl chan_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_90_main_deepcopy_skip_0:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global main
+main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    mov x0, #56 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #8 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #8 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #56 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _90_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_90_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _90_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_90_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _90_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_90_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _90_main closure env_end to x9
+    stur x9, [x29, #-8] // store value
+    mov x0, #0 // class of the values
+    bl chan_new_ptr // make the channel
+    mov x9, x0
+    ldur x19, [x29, #-8] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global main_unwrapper
+main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b main
+.global main_deep_release
+main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global main_deepcopy
+main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _start
+_start:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b main
+.section .rodata
+.p2align 3
+    .quad 0, 8 // static strings have no allocation to free
+_35:
+    .asciz "send %d\012"
+.p2align 3
+    .quad 0, 6 // static strings have no allocation to free
+_74:
+    .asciz "%s %d\012"
+.p2align 3
+    .quad 0, 1 // static strings have no allocation to free
+_108:
+    .asciz "b"
+.p2align 3
+    .quad 0, 7 // static strings have no allocation to free
+_135:
+    .asciz "got %d\012"
+.p2align 3
+    .quad 0, 2 // static strings have no allocation to free
+_122:
+    .asciz "%s"
+.p2align 3
+    .quad 0, 14 // static strings have no allocation to free
+_123:
+    .asciz "never received"
+.p2align 3
+    .quad 0, 1 // static strings have no allocation to free
+_116:
+    .asciz "a"
+.p2align 3
+    .quad 0, 9 // static strings have no allocation to free
+_101:
+    .asciz "total %d\012"
+.p2align 3
+    .quad 0, 8 // static strings have no allocation to free
+_59:
+    .asciz "recv %d\012"
@@ -0,0 +1,5578 @@
+bits 64
+default rel
+section .text
+global alloc_heap_ptr
+alloc_heap_ptr:
+    add rdi, 7 ; round size up to whole words
+    and rdi, -8
+    cmp rdi, 1024 ; small block?
+    ja alloc_heap_ptr_large
+    lea rcx, [heap_free_lists] ; free list heads by size
+    mov rax, [rcx+rdi] ; head of this size's free list
+    test rax, rax
+    jz alloc_heap_ptr_bump
+    mov rdx, [rax] ; next free block
+    mov [rcx+rdi], rdx ; pop the head
+    ret
+alloc_heap_ptr_bump:
+    lea rcx, [heap_arena] ; arena next and end pointers
+    mov rax, [rcx] ; next free byte of the arena
+    mov rdx, rax
+    add rdx, rdi ; end of the new block
+    mov r8, [rcx+8] ; end of the arena
+    cmp rdx, r8
+    ja alloc_heap_ptr_refill
+    mov [rcx], rdx ; bump past the block
+    ret
+alloc_heap_ptr_refill:
+    push rdi ; keep block size across the syscall
+    mov rsi, 1048576 ; arena size
+    xor rdi, rdi ; addr hint so kernel picks mmap base
+    mov rdx, 3 ; prot = read/write
+    mov r10, 34 ; flags = private & anonymous
+    mov r8, -1 ; fd = -1
+    xor r9, r9 ; offset = 0
+    mov rax, 9 ; mmap syscall
+    syscall ; map a new arena
+    pop rdi
+    cmp rax, -4095 ; mmap failed?
+    jae alloc_heap_ptr_failed
+    lea rcx, [heap_arena] ; arena next and end pointers
+    lea rdx, [rax+rdi] ; block is the start of the arena
+    mov [rcx], rdx
+    mov rdx, rax
+    add rdx, 1048576 ; end of the arena
+    mov [rcx+8], rdx
+    ret
+alloc_heap_ptr_large:
+    mov rsi, rdi ; length = block size
+    xor rdi, rdi ; addr hint so kernel picks mmap base
+    mov rdx, 3 ; prot = read/write
+    mov r10, 34 ; flags = private & anonymous
+    mov r8, -1 ; fd = -1
+    xor r9, r9 ; offset = 0
+    mov rax, 9 ; mmap syscall
+    syscall ; map a large block on its own
+    cmp rax, -4095 ; mmap failed?
+    jae alloc_heap_ptr_failed
+    ret
+alloc_heap_ptr_failed:
+    xor eax, eax ; no memory left
+    ret
+section .data
+heap_free_lists:
+    times 129 dq 0
+heap_arena:
+    dq 0, 0
+section .text
+global free_heap_ptr
+free_heap_ptr:
+    add rsi, 7 ; round size up to whole words
+    and rsi, -8
+    cmp rsi, 1024 ; small block?
+    ja free_heap_ptr_large
+    lea rcx, [heap_free_lists] ; free list heads by size
+    mov rax, [rcx+rsi] ; current head
+    mov [rdi], rax ; link it behind the block
+    mov [rcx+rsi], rdi ; block is the new head
+    ret
+free_heap_ptr_large:
+    mov rax, 11 ; munmap syscall
+    syscall
+    ret
+global release_str_ptr
+release_str_ptr:
+    test rdi, rdi ; nothing to free
+    jz release_str_ptr_done
+    mov rsi, [rdi-16] ; allocation size header
+    test rsi, rsi ; literals have no allocation
+    jz release_str_ptr_done
+    sub rdi, 16 ; block starts at the header
+    jmp free_heap_ptr ; return string to the allocator
+release_str_ptr_done:
+    ret
+global release_array_ptr
+release_array_ptr:
+    test rdi, rdi ; nothing to free
+    jz release_array_ptr_done
+    push rbx ; preserve callee-saved registers
+    push r13
+    push r14
+    mov rbx, rdi ; array
+    mov r13, [rbx-8] ; elements left to release
+    mov r14, [rbx-16] ; element class
+    test r14, r14 ; plain elements own nothing
+    jz release_array_ptr_free
+release_array_ptr_loop:
+    test r13, r13
+    jz release_array_ptr_free
+    dec r13
+    mov rcx, r13
+    shl rcx, 3 ; element offset
+    mov rdi, [rbx+rcx]
+    mov rsi, r14
+    call release_array_elem_ptr
+    jmp release_array_ptr_loop
+release_array_ptr_free:
+    mov rsi, [rbx-8] ; length
+    shl rsi, 3
+    add rsi, 16 ; block size, header included
+    lea rdi, [rbx-16] ; block starts at the header
+    pop r14
+    pop r13
+    pop rbx
+    jmp free_heap_ptr ; return the array to the allocator
+release_array_ptr_done:
+    ret
+global release_array_elem_ptr
+release_array_elem_ptr:
+    cmp rsi, 1 ; string element?
+    je release_str_ptr
+    cmp rsi, 2 ; array element?
+    je release_array_ptr
+    ret
+global queue_push_ptr
+queue_push_ptr:
+    push rbx ; preserve callee-saved registers
+    push r13
+    mov rbx, rdi ; queue
+    mov r13, rsi ; word to queue
+    mov rdi, 16 ; node size
+    call alloc_heap_ptr
+    mov qword [rax], 0 ; last node
+    mov [rax+8], r13
+    mov rcx, [rbx+8] ; tail
+    test rcx, rcx
+    jz queue_push_ptr_empty
+    mov [rcx], rax ; link behind the tail
+    jmp queue_push_ptr_linked
+queue_push_ptr_empty:
+    mov [rbx], rax ; node is the head too
+queue_push_ptr_linked:
+    mov [rbx+8], rax ; node is the new tail
+    pop r13
+    pop rbx
+    ret
+global queue_pop_ptr
+queue_pop_ptr:
+    push rbx ; preserve callee-saved registers
+    mov rdx, rdi ; queue
+    mov rdi, [rdx] ; head node
+    mov rcx, [rdi] ; next node
+    mov [rdx], rcx ; next node is the new head
+    test rcx, rcx
+    jnz queue_pop_ptr_unlinked
+    mov qword [rdx+8], 0 ; queue is empty
+queue_pop_ptr_unlinked:
+    mov rbx, [rdi+8] ; word the node held
+    mov rsi, 16 ; node size
+    call free_heap_ptr
+    mov rax, rbx
+    pop rbx
+    ret
+section .data
+sched_state:
+    dq 0, 0, 0, 1, 0, 0
+section .rodata
+sched_deadlock_message:
+    db "deadlock: every coroutine is waiting on a channel", 10
+section .text
+global sched_next_ptr
+sched_next_ptr:
+sched_next_ptr_pick:
+    lea rdi, [sched_state] ; run queue
+    cmp qword [rdi], 0 ; anything to run?
+    je sched_next_ptr_idle
+    call queue_pop_ptr
+    mov rdi, rax ; env_end of the next coroutine
+    mov rax, [rdi+0] ; load its entry point
+    jmp rax
+sched_next_ptr_idle:
+    and rsp, -16 ; align the stack for libc
+    cmp qword [rdi+16], 0 ; anyone parked?
+    jne sched_next_ptr_deadlock
+    mov rdi, 0 ; exit code
+    call exit ; call libc exit to flush buffers
+sched_next_ptr_deadlock:
+    mov rax, 1 ; write syscall
+    mov rdi, 2 ; stderr
+    lea rsi, [sched_deadlock_message]
+    mov rdx, 50 ; message length
+    syscall
+    mov rdi, 1 ; exit code
+    call exit
+global sched_ready_ptr
+sched_ready_ptr:
+    mov rsi, rdi
+    lea rdi, [sched_state] ; run queue
+    jmp queue_push_ptr
+global chan_new_ptr
+chan_new_ptr:
+    push rbx ; preserve callee-saved registers
+    mov rbx, rdi ; value class
+    mov rdi, 48 ; channel size
+    call alloc_heap_ptr
+    mov qword [rax+0], 1 ; one reference
+    mov [rax+8], rbx
+    mov qword [rax+16], 0 ; empty queue
+    mov qword [rax+24], 0
+    mov qword [rax+32], 0 ; empty queue
+    mov qword [rax+40], 0
+    pop rbx
+    ret
+global chan_send_ptr
+chan_send_ptr:
+    push rbx ; preserve callee-saved registers
+    push r13
+    mov rbx, rdi ; channel
+    mov r13, rsi ; value
+    cmp qword [rbx+32], 0 ; anyone receiving?
+    je chan_send_ptr_queue
+    lea rdi, [rbx+32]
+    call queue_pop_ptr ; the receiver's continuation
+    mov [rax-16], r13 ; pass the value
+    mov [rax-8], rbx ; and the channel
+    mov rdi, rax
+    call sched_ready_ptr ; the receiver can run again
+    lea rcx, [sched_state]
+    dec qword [rcx+16] ; one coroutine fewer parked
+    jmp chan_send_ptr_done
+chan_send_ptr_queue:
+    lea rdi, [rbx+16]
+    mov rsi, r13
+    call queue_push_ptr ; keep it for the next receiver
+chan_send_ptr_done:
+    pop r13
+    pop rbx
+    ret
+global chan_recv_ptr
+chan_recv_ptr:
+    cmp qword [rdi+16], 0 ; any value queued?
+    je chan_recv_ptr_park
+    add rdi, 16
+    call queue_pop_ptr
+    mov rdx, 1 ; got a value
+    ret
+chan_recv_ptr_park:
+    add rdi, 32
+    call queue_push_ptr ; wait for a sender
+    lea rcx, [sched_state]
+    inc qword [rcx+16] ; one more coroutine parked
+    mov rdx, 0 ; parked
+    ret
+global chan_release_ptr
+chan_release_ptr:
+    dec qword [rdi+0] ; drop a reference
+    jnz chan_release_ptr_done
+    push rdi ; channel
+chan_release_ptr_loop:
+    mov rdi, [rsp]
+    cmp qword [rdi+16], 0 ; values left?
+    je chan_release_ptr_free
+    add rdi, 16
+    call queue_pop_ptr
+    mov rdi, rax ; value
+    mov rcx, [rsp]
+    mov rsi, [rcx+8] ; value class
+    cmp rsi, 3 ; environment?
+    je chan_release_ptr_env
+    call release_array_elem_ptr
+    jmp chan_release_ptr_loop
+chan_release_ptr_env:
+    mov rax, [rdi+8] ; its release helper
+    call rax
+    jmp chan_release_ptr_loop
+chan_release_ptr_free:
+    pop rdi
+    mov rsi, 48 ; channel size
+    jmp free_heap_ptr ; return the channel to the allocator
+chan_release_ptr_done:
+    ret
+global chan_copy_ptr
+chan_copy_ptr:
+    inc qword [rdi+0] ; one more reference
+    mov rax, rdi
+    ret
+global _30_produce
+_30_produce:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store rest arg in frame
+    mov rdi, [rbp-8] ; load operand
+    call chan_release_ptr ; drop a reference to rest channel
+    leave ; unwind before switching
+    jmp sched_next_ptr ; run the next coroutine
+global release_heap_ptr
+release_heap_ptr:
+    push rbp ; save caller frame
+    mov rbp, rsp ; establish frame
+    push rbx ; preserve rbx
+    mov rbx, rdi ; keep env_end pointer
+    mov rcx, [rbx+24] ; load env size metadata
+    mov rdx, [rbx+32] ; load heap size metadata
+    mov rdi, rbx
+    sub rdi, rcx ; compute env base pointer
+    mov rsi, rdx ; heap size to free
+    call free_heap_ptr ; return env to the allocator
+    pop rbx
+    pop rbp
+    ret
+global _30_produce_unwrapper
+_30_produce_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-8] ; load rest env field
+    mov [rbp-16], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    mov rax, [rbp-16] ; load operand
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    leave ; unwind before named jump
+    jmp _30_produce
+global _30_produce_deep_release
+_30_produce_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load __num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _30_produce_release_skip_0
+    mov rax, [r12-8] ; load _30_produce_release_field_0 env field
+    mov [rbp-24], rax ; store value
+    mov rdi, [rbp-24] ; load operand
+    call chan_release_ptr ; drop a reference to _30_produce_release_field_0 channel
+_30_produce_release_skip_0:
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _30_produce_deepcopy
+_30_produce_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _30_produce_deepcopy_skip_0
+    mov rcx, [r12-8] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call chan_copy_ptr ; duplicate heap pointer
+    mov [r12-8], rax ; store duplicated pointer
+    mov [rbp-24], rax ; store value
+_30_produce_deepcopy_skip_0:
+    leave
+    ret
+
+global _27_produce
+_27_produce:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store c arg in frame
+    mov rdi, 56 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 8 ; move pointer past env payload
+    mov rax, 8 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 56 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_30_produce_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_30_produce_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_30_produce_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 1 ; store num_remaining
+    mov rax, r12 ; copy _30_produce closure env_end to rax
+    mov [rbp-16], rax ; store value
+    mov r13, [rbp-8] ; load operand
+    mov rsi, 0 ; operand literal
+    mov rdi, r13
+    call chan_send_ptr ; hand the value over or queue it
+    mov r12, [rbp-16] ; load continuation env_end pointer
+    mov [r12-8], r13 ; store env field
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+global _27_produce_unwrapper
+_27_produce_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-8] ; load c env field
+    mov [rbp-16], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    mov rax, [rbp-16] ; load operand
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    leave ; unwind before named jump
+    jmp _27_produce
+global _27_produce_deep_release
+_27_produce_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load __num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _27_produce_release_skip_0
+    mov rax, [r12-8] ; load _27_produce_release_field_0 env field
+    mov [rbp-24], rax ; store value
+    mov rdi, [rbp-24] ; load operand
+    call chan_release_ptr ; drop a reference to _27_produce_release_field_0 channel
+_27_produce_release_skip_0:
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _27_produce_deepcopy
+_27_produce_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _27_produce_deepcopy_skip_0
+    mov rcx, [r12-8] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call chan_copy_ptr ; duplicate heap pointer
+    mov [r12-8], rax ; store duplicated pointer
+    mov [rbp-24], rax ; store value
+_27_produce_deepcopy_skip_0:
+    leave
+    ret
+
+global _44_produce
+_44_produce:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store produce arg in frame
+    mov [rbp-16], rsi ; store rest arg in frame
+    mov [rbp-24], rdx ; store last arg in frame
+    mov [rbp-32], rcx ; store n arg in frame
+    mov rbx, [rbp-8] ; load produce closure env_end pointer
+    mov rax, [rbp-16] ; load operand
+    mov [rbx-24], rax ; store env field
+    mov rax, [rbp-32] ; load operand
+    mov [rbx-16], rax ; store env field
+    mov rax, [rbp-24] ; load operand
+    mov [rbx-8], rax ; store env field
+    mov rdi, rbx ; pass env_end pointer to closure
+    mov rax, [rdi+0] ; load closure unwrapper entry point
+    leave ; unwind before jumping
+    jmp rax ; tail call into closure
+global _44_produce_unwrapper
+_44_produce_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-32] ; load produce env field
+    mov [rbp-16], rax ; store value
+    mov rax, [r12-24] ; load rest env field
+    mov [rbp-24], rax ; store value
+    mov rax, [r12-16] ; load last env field
+    mov [rbp-32], rax ; store value
+    mov rax, [r12-8] ; load n env field
+    mov [rbp-40], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    mov rax, [rbp-40] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-32] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-24] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-16] ; load operand
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    pop rdx ; restore arg into register
+    pop rcx ; restore arg into register
+    leave ; unwind before named jump
+    jmp _44_produce
+global _44_produce_deep_release
+_44_produce_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load __num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 3 ; operand literal
+    cmp rax, rbx
+    jg _44_produce_release_skip_0
+    mov rax, [r12-32] ; load _44_produce_release_field_0 env field
+    mov [rbp-24], rax ; store value
+    mov rdi, [rbp-24] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _44_produce_release_field_0 release helper
+    call rax ; release _44_produce_release_field_0 and what it holds
+    pop rbx
+    pop r12
+_44_produce_release_skip_0:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 2 ; operand literal
+    cmp rax, rbx
+    jg _44_produce_release_skip_1
+    mov rax, [r12-24] ; load _44_produce_release_field_1 env field
+    mov [rbp-32], rax ; store value
+    mov rdi, [rbp-32] ; load operand
+    call chan_release_ptr ; drop a reference to _44_produce_release_field_1 channel
+_44_produce_release_skip_1:
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global deepcopy_heap_ptr
+deepcopy_heap_ptr:
+    push rbp ; prologue: save executor frame pointer
+    mov rbp, rsp ; prologue: establish new frame
+    push rbx ; preserve callee-saved registers
+    push r12
+    push r13
+    push r14
+    push r15
+    mov r12, rdi ; capture env_end pointer
+    mov r14, [r12+24] ; load env size metadata
+    mov r15, [r12+32] ; load heap size metadata
+    mov rbx, r12 ; keep env_end pointer
+    sub rbx, r14 ; compute env base pointer
+    mov rdi, r15 ; length = heap size
+    call alloc_heap_ptr ; allocate new closure env
+    mov r13, rax ; new env base pointer
+    mov rdi, r13 ; memcpy dest
+    mov rsi, rbx ; memcpy src
+    mov rdx, r15 ; memcpy length
+    call memcpy_helper ; copy env contents
+    mov rax, r13 ; compute new env_end pointer
+    add rax, r14
+    mov r15, rax ; preserve new env_end pointer
+    mov rax, [r15+16] ; load deep copy helper entry
+    mov rdi, r15 ; pass new env_end pointer
+    call rax ; invoke helper
+    mov rax, r15 ; return new env_end pointer
+    pop r15
+    pop r14
+    pop r13
+    pop r12
+    pop rbx
+    pop rbp
+    ret
+global memcpy_helper
+memcpy_helper:
+    push rbp ; prologue
+    mov rbp, rsp
+    xor rcx, rcx ; counter = 0
+internal_memcpy_loop:
+    cmp rcx, rdx ; counter < count?
+    jge internal_memcpy_done
+    mov rax, [rsi+rcx] ; load 8 bytes from source
+    mov [rdi+rcx], rax ; store 8 bytes to destination
+    add rcx, 8 ; advance counter by 8
+    jmp internal_memcpy_loop
+internal_memcpy_done:
+    pop rbp
+    ret
+global _44_produce_deepcopy
+_44_produce_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 3 ; operand literal
+    cmp rax, rbx
+    jg _44_produce_deepcopy_skip_0
+    mov rcx, [r12-32] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call deepcopy_heap_ptr ; duplicate heap pointer
+    mov [r12-32], rax ; store duplicated pointer
+    mov [rbp-24], rax ; store value
+_44_produce_deepcopy_skip_0:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 2 ; operand literal
+    cmp rax, rbx
+    jg _44_produce_deepcopy_skip_1
+    mov rcx, [r12-24] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call chan_copy_ptr ; duplicate heap pointer
+    mov [r12-24], rax ; store duplicated pointer
+    mov [rbp-32], rax ; store value
+_44_produce_deepcopy_skip_1:
+    leave
+    ret
+
+global _41_produce
+_41_produce:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store next arg in frame
+    mov [rbp-16], rsi ; store produce arg in frame
+    mov [rbp-24], rdx ; store last arg in frame
+    mov [rbp-32], rcx ; store rest arg in frame
+    mov rdi, 80 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov rax, [rbp-16] ; load operand
+    mov [rbx+0], rax ; capture arg into env
+    mov rax, [rbp-32] ; load operand
+    mov [rbx+8], rax ; capture arg into env
+    mov rax, [rbp-24] ; load operand
+    mov [rbx+16], rax ; capture arg into env
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 32 ; move pointer past env payload
+    mov rax, 32 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 80 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_44_produce_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_44_produce_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_44_produce_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 1 ; store num_remaining
+    mov rax, r12 ; copy _45_produce closure env_end to rax
+    mov [rbp-40], rax ; store value
+    mov rax, [rbp-8] ; load operand
+    mov rbx, 1 ; operand literal
+    add rax, rbx ; add second integer
+    mov r12, [rbp-40] ; load continuation env_end pointer
+    mov [r12-8], rax ; store env field
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+global _41_produce_unwrapper
+_41_produce_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-32] ; load next env field
+    mov [rbp-16], rax ; store value
+    mov rax, [r12-24] ; load produce env field
+    mov [rbp-24], rax ; store value
+    mov rax, [r12-16] ; load last env field
+    mov [rbp-32], rax ; store value
+    mov rax, [r12-8] ; load rest env field
+    mov [rbp-40], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    mov rax, [rbp-40] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-32] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-24] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-16] ; load operand
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    pop rdx ; restore arg into register
+    pop rcx ; restore arg into register
+    leave ; unwind before named jump
+    jmp _41_produce
+global _41_produce_deep_release
+_41_produce_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load __num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 2 ; operand literal
+    cmp rax, rbx
+    jg _41_produce_release_skip_1
+    mov rax, [r12-24] ; load _41_produce_release_field_1 env field
+    mov [rbp-24], rax ; store value
+    mov rdi, [rbp-24] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _41_produce_release_field_1 release helper
+    call rax ; release _41_produce_release_field_1 and what it holds
+    pop rbx
+    pop r12
+_41_produce_release_skip_1:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _41_produce_release_skip_3
+    mov rax, [r12-8] ; load _41_produce_release_field_3 env field
+    mov [rbp-32], rax ; store value
+    mov rdi, [rbp-32] ; load operand
+    call chan_release_ptr ; drop a reference to _41_produce_release_field_3 channel
+_41_produce_release_skip_3:
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _41_produce_deepcopy
+_41_produce_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 2 ; operand literal
+    cmp rax, rbx
+    jg _41_produce_deepcopy_skip_1
+    mov rcx, [r12-24] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call deepcopy_heap_ptr ; duplicate heap pointer
+    mov [r12-24], rax ; store duplicated pointer
+    mov [rbp-24], rax ; store value
+_41_produce_deepcopy_skip_1:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _41_produce_deepcopy_skip_3
+    mov rcx, [r12-8] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call chan_copy_ptr ; duplicate heap pointer
+    mov [r12-8], rax ; store duplicated pointer
+    mov [rbp-32], rax ; store value
+_41_produce_deepcopy_skip_3:
+    leave
+    ret
+
+global _39_produce
+_39_produce:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store c arg in frame
+    mov [rbp-16], rsi ; store next arg in frame
+    mov [rbp-24], rdx ; store produce arg in frame
+    mov [rbp-32], rcx ; store last arg in frame
+    mov rdi, 80 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov rax, [rbp-16] ; load operand
+    mov [rbx+0], rax ; capture arg into env
+    mov rax, [rbp-24] ; load operand
+    mov [rbx+8], rax ; capture arg into env
+    mov rax, [rbp-32] ; load operand
+    mov [rbx+16], rax ; capture arg into env
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 32 ; move pointer past env payload
+    mov rax, 32 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 80 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_41_produce_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_41_produce_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_41_produce_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 1 ; store num_remaining
+    mov rax, r12 ; copy _46_produce closure env_end to rax
+    mov [rbp-40], rax ; store value
+    mov r13, [rbp-8] ; load operand
+    mov rsi, [rbp-16] ; load operand
+    mov rdi, r13
+    call chan_send_ptr ; hand the value over or queue it
+    mov r12, [rbp-40] ; load continuation env_end pointer
+    mov [r12-8], r13 ; store env field
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+global _39_produce_unwrapper
+_39_produce_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-32] ; load c env field
+    mov [rbp-16], rax ; store value
+    mov rax, [r12-24] ; load next env field
+    mov [rbp-24], rax ; store value
+    mov rax, [r12-16] ; load produce env field
+    mov [rbp-32], rax ; store value
+    mov rax, [r12-8] ; load last env field
+    mov [rbp-40], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    mov rax, [rbp-40] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-32] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-24] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-16] ; load operand
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    pop rdx ; restore arg into register
+    pop rcx ; restore arg into register
+    leave ; unwind before named jump
+    jmp _39_produce
+global _39_produce_deep_release
+_39_produce_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load __num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 3 ; operand literal
+    cmp rax, rbx
+    jg _39_produce_release_skip_0
+    mov rax, [r12-32] ; load _39_produce_release_field_0 env field
+    mov [rbp-24], rax ; store value
+    mov rdi, [rbp-24] ; load operand
+    call chan_release_ptr ; drop a reference to _39_produce_release_field_0 channel
+_39_produce_release_skip_0:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 1 ; operand literal
+    cmp rax, rbx
+    jg _39_produce_release_skip_2
+    mov rax, [r12-16] ; load _39_produce_release_field_2 env field
+    mov [rbp-32], rax ; store value
+    mov rdi, [rbp-32] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _39_produce_release_field_2 release helper
+    call rax ; release _39_produce_release_field_2 and what it holds
+    pop rbx
+    pop r12
+_39_produce_release_skip_2:
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _39_produce_deepcopy
+_39_produce_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 3 ; operand literal
+    cmp rax, rbx
+    jg _39_produce_deepcopy_skip_0
+    mov rcx, [r12-32] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call chan_copy_ptr ; duplicate heap pointer
+    mov [r12-32], rax ; store duplicated pointer
+    mov [rbp-24], rax ; store value
+_39_produce_deepcopy_skip_0:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 1 ; operand literal
+    cmp rax, rbx
+    jg _39_produce_deepcopy_skip_2
+    mov rcx, [r12-16] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call deepcopy_heap_ptr ; duplicate heap pointer
+    mov [r12-16], rax ; store duplicated pointer
+    mov [rbp-32], rax ; store value
+_39_produce_deepcopy_skip_2:
+    leave
+    ret
+
+global _37_produce
+_37_produce:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store c arg in frame
+    mov [rbp-16], rsi ; store next arg in frame
+    mov [rbp-24], rdx ; store produce arg in frame
+    mov [rbp-32], rcx ; store last arg in frame
+    mov [rbp-40], r8 ; store s arg in frame
+    mov rdi, 80 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov rax, [rbp-8] ; load operand
+    mov [rbx+0], rax ; capture arg into env
+    mov rax, [rbp-16] ; load operand
+    mov [rbx+8], rax ; capture arg into env
+    mov rax, [rbp-24] ; load operand
+    mov [rbx+16], rax ; capture arg into env
+    mov rax, [rbp-32] ; load operand
+    mov [rbx+24], rax ; capture arg into env
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 32 ; move pointer past env payload
+    mov rax, 32 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 80 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_39_produce_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_39_produce_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_39_produce_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 0 ; store num_remaining
+    mov rax, r12 ; copy _47_produce closure env_end to rax
+    mov [rbp-48], rax ; store value
+    mov rax, [rbp-40] ; load operand
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    mov rdx, [rdi-8] ; length to write
+    mov rsi, rdi ; buffer start
+    mov rdi, 1 ; stdout fd
+    call write ; invoke libc write
+    mov rdi, [rbp-40] ; load operand
+    call release_str_ptr ; release s string
+    mov r12, [rbp-48] ; load continuation env_end pointer
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+global _37_produce_unwrapper
+_37_produce_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-40] ; load c env field
+    mov [rbp-16], rax ; store value
+    mov rax, [r12-32] ; load next env field
+    mov [rbp-24], rax ; store value
+    mov rax, [r12-24] ; load produce env field
+    mov [rbp-32], rax ; store value
+    mov rax, [r12-16] ; load last env field
+    mov [rbp-40], rax ; store value
+    mov rax, [r12-8] ; load s env field
+    mov [rbp-48], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    mov rax, [rbp-48] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-40] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-32] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-24] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-16] ; load operand
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    pop rdx ; restore arg into register
+    pop rcx ; restore arg into register
+    pop r8 ; restore arg into register
+    leave ; unwind before named jump
+    jmp _37_produce
+global _37_produce_deep_release
+_37_produce_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load __num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 4 ; operand literal
+    cmp rax, rbx
+    jg _37_produce_release_skip_0
+    mov rax, [r12-40] ; load _37_produce_release_field_0 env field
+    mov [rbp-24], rax ; store value
+    mov rdi, [rbp-24] ; load operand
+    call chan_release_ptr ; drop a reference to _37_produce_release_field_0 channel
+_37_produce_release_skip_0:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 2 ; operand literal
+    cmp rax, rbx
+    jg _37_produce_release_skip_2
+    mov rax, [r12-24] ; load _37_produce_release_field_2 env field
+    mov [rbp-32], rax ; store value
+    mov rdi, [rbp-32] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _37_produce_release_field_2 release helper
+    call rax ; release _37_produce_release_field_2 and what it holds
+    pop rbx
+    pop r12
+_37_produce_release_skip_2:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _37_produce_release_skip_4
+    mov rax, [r12-8] ; load _37_produce_release_field_4 env field
+    mov [rbp-40], rax ; store value
+    mov rdi, [rbp-40] ; load operand
+    call release_str_ptr ; release _37_produce_release_field_4 string
+_37_produce_release_skip_4:
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global copy_str_ptr
+copy_str_ptr:
+    mov rax, rdi ; share the pointer unless it owns memory
+    test rdi, rdi
+    jz copy_str_ptr_done
+    mov rsi, [rdi-16] ; allocation size header
+    test rsi, rsi ; literals are never freed
+    jz copy_str_ptr_done
+    push rbx ; preserve callee-saved registers
+    push r13
+    push r14
+    mov rbx, rdi ; source string
+    mov r13, rsi ; allocation size
+    mov rdi, rsi
+    call alloc_heap_ptr ; allocate the copy
+    mov r14, rax ; copy block
+    mov rdi, rax ; destination block
+    lea rsi, [rbx-16] ; source block, header included
+    mov rcx, r13 ; bytes to copy
+    cld ; ensure forward copy
+    rep movsb ; duplicate header and bytes
+    lea rax, [r14+16] ; copied string starts after its header
+    pop r14
+    pop r13
+    pop rbx
+copy_str_ptr_done:
+    ret
+global _37_produce_deepcopy
+_37_produce_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 4 ; operand literal
+    cmp rax, rbx
+    jg _37_produce_deepcopy_skip_0
+    mov rcx, [r12-40] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call chan_copy_ptr ; duplicate heap pointer
+    mov [r12-40], rax ; store duplicated pointer
+    mov [rbp-24], rax ; store value
+_37_produce_deepcopy_skip_0:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 2 ; operand literal
+    cmp rax, rbx
+    jg _37_produce_deepcopy_skip_2
+    mov rcx, [r12-24] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call deepcopy_heap_ptr ; duplicate heap pointer
+    mov [r12-24], rax ; store duplicated pointer
+    mov [rbp-32], rax ; store value
+_37_produce_deepcopy_skip_2:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _37_produce_deepcopy_skip_4
+    mov rcx, [r12-8] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call copy_str_ptr ; duplicate heap pointer
+    mov [r12-8], rax ; store duplicated pointer
+    mov [rbp-40], rax ; store value
+_37_produce_deepcopy_skip_4:
+    leave
+    ret
+
+global _34_produce
+_34_produce:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store next arg in frame
+    mov [rbp-16], rsi ; store c arg in frame
+    mov [rbp-24], rdx ; store produce arg in frame
+    mov [rbp-32], rcx ; store last arg in frame
+    mov rdi, 88 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov rax, [rbp-16] ; load operand
+    mov [rbx+0], rax ; capture arg into env
+    mov rax, [rbp-8] ; load operand
+    mov [rbx+8], rax ; capture arg into env
+    mov rax, [rbp-24] ; load operand
+    mov [rbx+16], rax ; capture arg into env
+    mov rax, [rbp-32] ; load operand
+    mov [rbx+24], rax ; capture arg into env
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 40 ; move pointer past env payload
+    mov rax, 40 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 88 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_37_produce_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_37_produce_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_37_produce_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 1 ; store num_remaining
+    mov rax, r12 ; copy _48_produce closure env_end to rax
+    mov [rbp-40], rax ; store value
+    mov rax, [rbp-8] ; load operand
+    push rax ; stack arg
+    lea rax, [rel _35] ; point to string literal
+    push rax ; stack arg
+    mov r13, rsp ; sprintf args in order
+    xor rdi, rdi ; measure only
+    xor rsi, rsi
+    mov r15, rsp ; restore point after snprintf
+    and rsp, -16 ; align stack for variadic call
+    mov rdx, [r13+0] ; snprintf arg 0
+    mov rcx, [r13+8] ; snprintf arg 1
+    mov eax, 0 ; vector registers holding args
+    call snprintf ; invoke libc snprintf
+    mov rsp, r15
+    mov rbx, rax ; formatted length
+    lea rdi, [rbx+17] ; header, bytes and terminator
+    call alloc_heap_ptr ; allocate the string
+    mov r14, rax ; string block
+    lea rax, [rbx+17]
+    mov [r14], rax ; allocation size header
+    mov [r14+8], rbx ; length header
+    lea rdi, [r14+16] ; string bytes follow the header
+    lea rsi, [rbx+1] ; room for the terminator
+    mov r15, rsp ; restore point after snprintf
+    and rsp, -16 ; align stack for variadic call
+    mov rdx, [r13+0] ; snprintf arg 0
+    mov rcx, [r13+8] ; snprintf arg 1
+    mov eax, 0 ; vector registers holding args
+    call snprintf ; invoke libc snprintf
+    mov rsp, r15
+    lea rax, [r14+16] ; return formatted string pointer
+    add rsp, 16 ; pop stack args after libc call
+    mov r12, [rbp-40] ; load continuation env_end pointer
+    mov [r12-8], rax ; store env field
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+global _34_produce_unwrapper
+_34_produce_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-32] ; load next env field
+    mov [rbp-16], rax ; store value
+    mov rax, [r12-24] ; load c env field
+    mov [rbp-24], rax ; store value
+    mov rax, [r12-16] ; load produce env field
+    mov [rbp-32], rax ; store value
+    mov rax, [r12-8] ; load last env field
+    mov [rbp-40], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    mov rax, [rbp-40] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-32] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-24] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-16] ; load operand
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    pop rdx ; restore arg into register
+    pop rcx ; restore arg into register
+    leave ; unwind before named jump
+    jmp _34_produce
+global _34_produce_deep_release
+_34_produce_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load __num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 2 ; operand literal
+    cmp rax, rbx
+    jg _34_produce_release_skip_1
+    mov rax, [r12-24] ; load _34_produce_release_field_1 env field
+    mov [rbp-24], rax ; store value
+    mov rdi, [rbp-24] ; load operand
+    call chan_release_ptr ; drop a reference to _34_produce_release_field_1 channel
+_34_produce_release_skip_1:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 1 ; operand literal
+    cmp rax, rbx
+    jg _34_produce_release_skip_2
+    mov rax, [r12-16] ; load _34_produce_release_field_2 env field
+    mov [rbp-32], rax ; store value
+    mov rdi, [rbp-32] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _34_produce_release_field_2 release helper
+    call rax ; release _34_produce_release_field_2 and what it holds
+    pop rbx
+    pop r12
+_34_produce_release_skip_2:
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _34_produce_deepcopy
+_34_produce_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 2 ; operand literal
+    cmp rax, rbx
+    jg _34_produce_deepcopy_skip_1
+    mov rcx, [r12-24] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call chan_copy_ptr ; duplicate heap pointer
+    mov [r12-24], rax ; store duplicated pointer
+    mov [rbp-24], rax ; store value
+_34_produce_deepcopy_skip_1:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 1 ; operand literal
+    cmp rax, rbx
+    jg _34_produce_deepcopy_skip_2
+    mov rcx, [r12-16] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call deepcopy_heap_ptr ; duplicate heap pointer
+    mov [r12-16], rax ; store duplicated pointer
+    mov [rbp-32], rax ; store value
+_34_produce_deepcopy_skip_2:
+    leave
+    ret
+
+global produce
+produce:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 64 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store c arg in frame
+    mov [rbp-16], rsi ; store next arg in frame
+    mov [rbp-24], rdx ; store last arg in frame
+    mov rdi, [rbp-8] ; load operand
+    call chan_copy_ptr ; another reference to c channel
+    mov [rbp-32], rax ; store value
+    mov rdi, 56 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov rax, [rbp-32] ; load operand
+    mov [rbx+0], rax ; capture arg into env
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 8 ; move pointer past env payload
+    mov rax, 8 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 56 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_27_produce_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_27_produce_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_27_produce_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 0 ; store num_remaining
+    mov rax, r12 ; copy _32_produce closure env_end to rax
+    mov [rbp-40], rax ; store value
+    mov rdi, 72 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 24 ; move pointer past env payload
+    mov rax, 24 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 72 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [produce_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [produce_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [produce_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 3 ; store num_remaining
+    mov rax, r12 ; copy produce closure env_end to rax
+    mov [rbp-48], rax ; store value
+    mov rdi, 80 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov rax, [rbp-16] ; load operand
+    mov [rbx+0], rax ; capture arg into env
+    mov rax, [rbp-8] ; load operand
+    mov [rbx+8], rax ; capture arg into env
+    mov rax, [rbp-48] ; load operand
+    mov [rbx+16], rax ; capture arg into env
+    mov rax, [rbp-24] ; load operand
+    mov [rbx+24], rax ; capture arg into env
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 32 ; move pointer past env payload
+    mov rax, 32 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 80 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_34_produce_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_34_produce_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_34_produce_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 0 ; store num_remaining
+    mov rax, r12 ; copy _49_produce closure env_end to rax
+    mov [rbp-56], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, [rbp-24] ; load operand
+    cmp rax, rbx
+    jg gt__32_produce_true_0_0
+gt__49_produce_false_0_0:
+    mov rdi, [rbp-40] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _32_produce release helper
+    call rax ; release _32_produce and what it holds
+    pop rbx
+    pop r12
+    mov rbx, [rbp-56] ; load _49_produce closure env_end pointer
+    mov rdi, rbx ; pass env_end pointer to closure
+    mov rax, [rdi+0] ; load closure unwrapper entry point
+    leave ; unwind before jumping
+    jmp rax ; tail call into closure
+gt__32_produce_true_0_0:
+    mov rdi, [rbp-56] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _49_produce release helper
+    call rax ; release _49_produce and what it holds
+    pop rbx
+    pop r12
+    mov rbx, [rbp-40] ; load _32_produce closure env_end pointer
+    mov rdi, rbx ; pass env_end pointer to closure
+    mov rax, [rdi+0] ; load closure unwrapper entry point
+    leave ; unwind before jumping
+    jmp rax ; tail call into closure
+global produce_unwrapper
+produce_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-24] ; load c env field
+    mov [rbp-16], rax ; store value
+    mov rax, [r12-16] ; load next env field
+    mov [rbp-24], rax ; store value
+    mov rax, [r12-8] ; load last env field
+    mov [rbp-32], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    mov rax, [rbp-32] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-24] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-16] ; load operand
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    pop rdx ; restore arg into register
+    leave ; unwind before named jump
+    jmp produce
+global produce_deep_release
+produce_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load __num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 2 ; operand literal
+    cmp rax, rbx
+    jg produce_release_skip_0
+    mov rax, [r12-24] ; load produce_release_field_0 env field
+    mov [rbp-24], rax ; store value
+    mov rdi, [rbp-24] ; load operand
+    call chan_release_ptr ; drop a reference to produce_release_field_0 channel
+produce_release_skip_0:
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global produce_deepcopy
+produce_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 2 ; operand literal
+    cmp rax, rbx
+    jg produce_deepcopy_skip_0
+    mov rcx, [r12-24] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call chan_copy_ptr ; duplicate heap pointer
+    mov [r12-24], rax ; store duplicated pointer
+    mov [rbp-24], rax ; store value
+produce_deepcopy_skip_0:
+    leave
+    ret
+
+global _92_main
+_92_main:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store c arg in frame
+    mov rax, 3 ; operand literal
+    push rax ; stack arg
+    mov rax, 1 ; operand literal
+    push rax ; stack arg
+    mov rax, [rbp-8] ; load operand
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    pop rdx ; restore arg into register
+    leave ; unwind before named jump
+    jmp produce
+global _92_main_unwrapper
+_92_main_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-8] ; load c env field
+    mov [rbp-16], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    mov rax, [rbp-16] ; load operand
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    leave ; unwind before named jump
+    jmp _92_main
+global _92_main_deep_release
+_92_main_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load __num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _92_main_release_skip_0
+    mov rax, [r12-8] ; load _92_main_release_field_0 env field
+    mov [rbp-24], rax ; store value
+    mov rdi, [rbp-24] ; load operand
+    call chan_release_ptr ; drop a reference to _92_main_release_field_0 channel
+_92_main_release_skip_0:
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _92_main_deepcopy
+_92_main_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _92_main_deepcopy_skip_0
+    mov rcx, [r12-8] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call chan_copy_ptr ; duplicate heap pointer
+    mov [r12-8], rax ; store duplicated pointer
+    mov [rbp-24], rax ; store value
+_92_main_deepcopy_skip_0:
+    leave
+    ret
+
+global _111_main
+_111_main:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    leave ; unwind before switching
+    jmp sched_next_ptr ; run the next coroutine
+global _111_main_unwrapper
+_111_main_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave ; unwind before named jump
+    jmp _111_main
+global _111_main_deep_release
+_111_main_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _111_main_deepcopy
+_111_main_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    leave
+    ret
+
+global _83_ping
+_83_ping:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store ping arg in frame
+    mov [rbp-16], rsi ; store name arg in frame
+    mov [rbp-24], rdx ; store ok arg in frame
+    mov [rbp-32], rcx ; store m arg in frame
+    mov rbx, [rbp-8] ; load ping closure env_end pointer
+    mov rax, [rbp-16] ; load operand
+    mov [rbx-24], rax ; store env field
+    mov rax, [rbp-32] ; load operand
+    mov [rbx-16], rax ; store env field
+    mov rax, [rbp-24] ; load operand
+    mov [rbx-8], rax ; store env field
+    mov rdi, rbx ; pass env_end pointer to closure
+    mov rax, [rdi+0] ; load closure unwrapper entry point
+    leave ; unwind before jumping
+    jmp rax ; tail call into closure
+global _83_ping_unwrapper
+_83_ping_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-32] ; load ping env field
+    mov [rbp-16], rax ; store value
+    mov rax, [r12-24] ; load name env field
+    mov [rbp-24], rax ; store value
+    mov rax, [r12-16] ; load ok env field
+    mov [rbp-32], rax ; store value
+    mov rax, [r12-8] ; load m env field
+    mov [rbp-40], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    mov rax, [rbp-40] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-32] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-24] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-16] ; load operand
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    pop rdx ; restore arg into register
+    pop rcx ; restore arg into register
+    leave ; unwind before named jump
+    jmp _83_ping
+global _83_ping_deep_release
+_83_ping_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load __num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 3 ; operand literal
+    cmp rax, rbx
+    jg _83_ping_release_skip_0
+    mov rax, [r12-32] ; load _83_ping_release_field_0 env field
+    mov [rbp-24], rax ; store value
+    mov rdi, [rbp-24] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _83_ping_release_field_0 release helper
+    call rax ; release _83_ping_release_field_0 and what it holds
+    pop rbx
+    pop r12
+_83_ping_release_skip_0:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 2 ; operand literal
+    cmp rax, rbx
+    jg _83_ping_release_skip_1
+    mov rax, [r12-24] ; load _83_ping_release_field_1 env field
+    mov [rbp-32], rax ; store value
+    mov rdi, [rbp-32] ; load operand
+    call release_str_ptr ; release _83_ping_release_field_1 string
+_83_ping_release_skip_1:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 1 ; operand literal
+    cmp rax, rbx
+    jg _83_ping_release_skip_2
+    mov rax, [r12-16] ; load _83_ping_release_field_2 env field
+    mov [rbp-40], rax ; store value
+    mov rdi, [rbp-40] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _83_ping_release_field_2 release helper
+    call rax ; release _83_ping_release_field_2 and what it holds
+    pop rbx
+    pop r12
+_83_ping_release_skip_2:
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _83_ping_deepcopy
+_83_ping_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 3 ; operand literal
+    cmp rax, rbx
+    jg _83_ping_deepcopy_skip_0
+    mov rcx, [r12-32] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call deepcopy_heap_ptr ; duplicate heap pointer
+    mov [r12-32], rax ; store duplicated pointer
+    mov [rbp-24], rax ; store value
+_83_ping_deepcopy_skip_0:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 2 ; operand literal
+    cmp rax, rbx
+    jg _83_ping_deepcopy_skip_1
+    mov rcx, [r12-24] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call copy_str_ptr ; duplicate heap pointer
+    mov [r12-24], rax ; store duplicated pointer
+    mov [rbp-32], rax ; store value
+_83_ping_deepcopy_skip_1:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 1 ; operand literal
+    cmp rax, rbx
+    jg _83_ping_deepcopy_skip_2
+    mov rcx, [r12-16] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call deepcopy_heap_ptr ; duplicate heap pointer
+    mov [r12-16], rax ; store duplicated pointer
+    mov [rbp-40], rax ; store value
+_83_ping_deepcopy_skip_2:
+    leave
+    ret
+
+global _80_ping
+_80_ping:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store n arg in frame
+    mov [rbp-16], rsi ; store ping arg in frame
+    mov [rbp-24], rdx ; store name arg in frame
+    mov [rbp-32], rcx ; store ok arg in frame
+    mov rdi, 80 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov rax, [rbp-16] ; load operand
+    mov [rbx+0], rax ; capture arg into env
+    mov rax, [rbp-24] ; load operand
+    mov [rbx+8], rax ; capture arg into env
+    mov rax, [rbp-32] ; load operand
+    mov [rbx+16], rax ; capture arg into env
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 32 ; move pointer past env payload
+    mov rax, 32 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 80 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_83_ping_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_83_ping_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_83_ping_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 1 ; store num_remaining
+    mov rax, r12 ; copy _84_ping closure env_end to rax
+    mov [rbp-40], rax ; store value
+    mov rax, [rbp-8] ; load operand
+    mov rbx, 1 ; operand literal
+    sub rax, rbx ; subtract subtrahend
+    mov r12, [rbp-40] ; load continuation env_end pointer
+    mov [r12-8], rax ; store env field
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+global _80_ping_unwrapper
+_80_ping_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-32] ; load n env field
+    mov [rbp-16], rax ; store value
+    mov rax, [r12-24] ; load ping env field
+    mov [rbp-24], rax ; store value
+    mov rax, [r12-16] ; load name env field
+    mov [rbp-32], rax ; store value
+    mov rax, [r12-8] ; load ok env field
+    mov [rbp-40], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    mov rax, [rbp-40] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-32] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-24] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-16] ; load operand
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    pop rdx ; restore arg into register
+    pop rcx ; restore arg into register
+    leave ; unwind before named jump
+    jmp _80_ping
+global _80_ping_deep_release
+_80_ping_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load __num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 2 ; operand literal
+    cmp rax, rbx
+    jg _80_ping_release_skip_1
+    mov rax, [r12-24] ; load _80_ping_release_field_1 env field
+    mov [rbp-24], rax ; store value
+    mov rdi, [rbp-24] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _80_ping_release_field_1 release helper
+    call rax ; release _80_ping_release_field_1 and what it holds
+    pop rbx
+    pop r12
+_80_ping_release_skip_1:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 1 ; operand literal
+    cmp rax, rbx
+    jg _80_ping_release_skip_2
+    mov rax, [r12-16] ; load _80_ping_release_field_2 env field
+    mov [rbp-32], rax ; store value
+    mov rdi, [rbp-32] ; load operand
+    call release_str_ptr ; release _80_ping_release_field_2 string
+_80_ping_release_skip_2:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _80_ping_release_skip_3
+    mov rax, [r12-8] ; load _80_ping_release_field_3 env field
+    mov [rbp-40], rax ; store value
+    mov rdi, [rbp-40] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _80_ping_release_field_3 release helper
+    call rax ; release _80_ping_release_field_3 and what it holds
+    pop rbx
+    pop r12
+_80_ping_release_skip_3:
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _80_ping_deepcopy
+_80_ping_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 2 ; operand literal
+    cmp rax, rbx
+    jg _80_ping_deepcopy_skip_1
+    mov rcx, [r12-24] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call deepcopy_heap_ptr ; duplicate heap pointer
+    mov [r12-24], rax ; store duplicated pointer
+    mov [rbp-24], rax ; store value
+_80_ping_deepcopy_skip_1:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 1 ; operand literal
+    cmp rax, rbx
+    jg _80_ping_deepcopy_skip_2
+    mov rcx, [r12-16] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call copy_str_ptr ; duplicate heap pointer
+    mov [r12-16], rax ; store duplicated pointer
+    mov [rbp-32], rax ; store value
+_80_ping_deepcopy_skip_2:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _80_ping_deepcopy_skip_3
+    mov rcx, [r12-8] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call deepcopy_heap_ptr ; duplicate heap pointer
+    mov [r12-8], rax ; store duplicated pointer
+    mov [rbp-40], rax ; store value
+_80_ping_deepcopy_skip_3:
+    leave
+    ret
+
+global _78_ping
+_78_ping:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store n arg in frame
+    mov [rbp-16], rsi ; store ping arg in frame
+    mov [rbp-24], rdx ; store name arg in frame
+    mov [rbp-32], rcx ; store ok arg in frame
+    mov rdi, 80 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov rax, [rbp-8] ; load operand
+    mov [rbx+0], rax ; capture arg into env
+    mov rax, [rbp-16] ; load operand
+    mov [rbx+8], rax ; capture arg into env
+    mov rax, [rbp-24] ; load operand
+    mov [rbx+16], rax ; capture arg into env
+    mov rax, [rbp-32] ; load operand
+    mov [rbx+24], rax ; capture arg into env
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 32 ; move pointer past env payload
+    mov rax, 32 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 80 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_80_ping_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_80_ping_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_80_ping_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 0 ; store num_remaining
+    mov rax, r12 ; copy _85_ping closure env_end to rax
+    mov [rbp-40], rax ; store value
+    mov rdi, [rbp-40] ; load operand
+    call sched_ready_ptr ; run _85_ping later
+    leave ; unwind before switching
+    jmp sched_next_ptr ; run the next coroutine
+global _78_ping_unwrapper
+_78_ping_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-32] ; load n env field
+    mov [rbp-16], rax ; store value
+    mov rax, [r12-24] ; load ping env field
+    mov [rbp-24], rax ; store value
+    mov rax, [r12-16] ; load name env field
+    mov [rbp-32], rax ; store value
+    mov rax, [r12-8] ; load ok env field
+    mov [rbp-40], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    mov rax, [rbp-40] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-32] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-24] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-16] ; load operand
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    pop rdx ; restore arg into register
+    pop rcx ; restore arg into register
+    leave ; unwind before named jump
+    jmp _78_ping
+global _78_ping_deep_release
+_78_ping_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load __num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 2 ; operand literal
+    cmp rax, rbx
+    jg _78_ping_release_skip_1
+    mov rax, [r12-24] ; load _78_ping_release_field_1 env field
+    mov [rbp-24], rax ; store value
+    mov rdi, [rbp-24] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _78_ping_release_field_1 release helper
+    call rax ; release _78_ping_release_field_1 and what it holds
+    pop rbx
+    pop r12
+_78_ping_release_skip_1:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 1 ; operand literal
+    cmp rax, rbx
+    jg _78_ping_release_skip_2
+    mov rax, [r12-16] ; load _78_ping_release_field_2 env field
+    mov [rbp-32], rax ; store value
+    mov rdi, [rbp-32] ; load operand
+    call release_str_ptr ; release _78_ping_release_field_2 string
+_78_ping_release_skip_2:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _78_ping_release_skip_3
+    mov rax, [r12-8] ; load _78_ping_release_field_3 env field
+    mov [rbp-40], rax ; store value
+    mov rdi, [rbp-40] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _78_ping_release_field_3 release helper
+    call rax ; release _78_ping_release_field_3 and what it holds
+    pop rbx
+    pop r12
+_78_ping_release_skip_3:
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _78_ping_deepcopy
+_78_ping_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 2 ; operand literal
+    cmp rax, rbx
+    jg _78_ping_deepcopy_skip_1
+    mov rcx, [r12-24] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call deepcopy_heap_ptr ; duplicate heap pointer
+    mov [r12-24], rax ; store duplicated pointer
+    mov [rbp-24], rax ; store value
+_78_ping_deepcopy_skip_1:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 1 ; operand literal
+    cmp rax, rbx
+    jg _78_ping_deepcopy_skip_2
+    mov rcx, [r12-16] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call copy_str_ptr ; duplicate heap pointer
+    mov [r12-16], rax ; store duplicated pointer
+    mov [rbp-32], rax ; store value
+_78_ping_deepcopy_skip_2:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _78_ping_deepcopy_skip_3
+    mov rcx, [r12-8] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call deepcopy_heap_ptr ; duplicate heap pointer
+    mov [r12-8], rax ; store duplicated pointer
+    mov [rbp-40], rax ; store value
+_78_ping_deepcopy_skip_3:
+    leave
+    ret
+
+global _76_ping
+_76_ping:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store n arg in frame
+    mov [rbp-16], rsi ; store ping arg in frame
+    mov [rbp-24], rdx ; store name arg in frame
+    mov [rbp-32], rcx ; store ok arg in frame
+    mov [rbp-40], r8 ; store s arg in frame
+    mov rdi, 80 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov rax, [rbp-8] ; load operand
+    mov [rbx+0], rax ; capture arg into env
+    mov rax, [rbp-16] ; load operand
+    mov [rbx+8], rax ; capture arg into env
+    mov rax, [rbp-24] ; load operand
+    mov [rbx+16], rax ; capture arg into env
+    mov rax, [rbp-32] ; load operand
+    mov [rbx+24], rax ; capture arg into env
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 32 ; move pointer past env payload
+    mov rax, 32 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 80 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_78_ping_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_78_ping_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_78_ping_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 0 ; store num_remaining
+    mov rax, r12 ; copy _86_ping closure env_end to rax
+    mov [rbp-48], rax ; store value
+    mov rax, [rbp-40] ; load operand
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    mov rdx, [rdi-8] ; length to write
+    mov rsi, rdi ; buffer start
+    mov rdi, 1 ; stdout fd
+    call write ; invoke libc write
+    mov rdi, [rbp-40] ; load operand
+    call release_str_ptr ; release s string
+    mov r12, [rbp-48] ; load continuation env_end pointer
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+global _76_ping_unwrapper
+_76_ping_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-40] ; load n env field
+    mov [rbp-16], rax ; store value
+    mov rax, [r12-32] ; load ping env field
+    mov [rbp-24], rax ; store value
+    mov rax, [r12-24] ; load name env field
+    mov [rbp-32], rax ; store value
+    mov rax, [r12-16] ; load ok env field
+    mov [rbp-40], rax ; store value
+    mov rax, [r12-8] ; load s env field
+    mov [rbp-48], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    mov rax, [rbp-48] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-40] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-32] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-24] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-16] ; load operand
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    pop rdx ; restore arg into register
+    pop rcx ; restore arg into register
+    pop r8 ; restore arg into register
+    leave ; unwind before named jump
+    jmp _76_ping
+global _76_ping_deep_release
+_76_ping_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load __num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 3 ; operand literal
+    cmp rax, rbx
+    jg _76_ping_release_skip_1
+    mov rax, [r12-32] ; load _76_ping_release_field_1 env field
+    mov [rbp-24], rax ; store value
+    mov rdi, [rbp-24] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _76_ping_release_field_1 release helper
+    call rax ; release _76_ping_release_field_1 and what it holds
+    pop rbx
+    pop r12
+_76_ping_release_skip_1:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 2 ; operand literal
+    cmp rax, rbx
+    jg _76_ping_release_skip_2
+    mov rax, [r12-24] ; load _76_ping_release_field_2 env field
+    mov [rbp-32], rax ; store value
+    mov rdi, [rbp-32] ; load operand
+    call release_str_ptr ; release _76_ping_release_field_2 string
+_76_ping_release_skip_2:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 1 ; operand literal
+    cmp rax, rbx
+    jg _76_ping_release_skip_3
+    mov rax, [r12-16] ; load _76_ping_release_field_3 env field
+    mov [rbp-40], rax ; store value
+    mov rdi, [rbp-40] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _76_ping_release_field_3 release helper
+    call rax ; release _76_ping_release_field_3 and what it holds
+    pop rbx
+    pop r12
+_76_ping_release_skip_3:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _76_ping_release_skip_4
+    mov rax, [r12-8] ; load _76_ping_release_field_4 env field
+    mov [rbp-48], rax ; store value
+    mov rdi, [rbp-48] ; load operand
+    call release_str_ptr ; release _76_ping_release_field_4 string
+_76_ping_release_skip_4:
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _76_ping_deepcopy
+_76_ping_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 3 ; operand literal
+    cmp rax, rbx
+    jg _76_ping_deepcopy_skip_1
+    mov rcx, [r12-32] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call deepcopy_heap_ptr ; duplicate heap pointer
+    mov [r12-32], rax ; store duplicated pointer
+    mov [rbp-24], rax ; store value
+_76_ping_deepcopy_skip_1:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 2 ; operand literal
+    cmp rax, rbx
+    jg _76_ping_deepcopy_skip_2
+    mov rcx, [r12-24] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call copy_str_ptr ; duplicate heap pointer
+    mov [r12-24], rax ; store duplicated pointer
+    mov [rbp-32], rax ; store value
+_76_ping_deepcopy_skip_2:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 1 ; operand literal
+    cmp rax, rbx
+    jg _76_ping_deepcopy_skip_3
+    mov rcx, [r12-16] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call deepcopy_heap_ptr ; duplicate heap pointer
+    mov [r12-16], rax ; store duplicated pointer
+    mov [rbp-40], rax ; store value
+_76_ping_deepcopy_skip_3:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _76_ping_deepcopy_skip_4
+    mov rcx, [r12-8] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call copy_str_ptr ; duplicate heap pointer
+    mov [r12-8], rax ; store duplicated pointer
+    mov [rbp-48], rax ; store value
+_76_ping_deepcopy_skip_4:
+    leave
+    ret
+
+global _73_ping
+_73_ping:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store name arg in frame
+    mov [rbp-16], rsi ; store n arg in frame
+    mov [rbp-24], rdx ; store ping arg in frame
+    mov [rbp-32], rcx ; store ok arg in frame
+    mov rdi, [rbp-8] ; load operand
+    call copy_str_ptr ; copy name string
+    mov [rbp-40], rax ; store value
+    mov rdi, 88 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov rax, [rbp-16] ; load operand
+    mov [rbx+0], rax ; capture arg into env
+    mov rax, [rbp-24] ; load operand
+    mov [rbx+8], rax ; capture arg into env
+    mov rax, [rbp-40] ; load operand
+    mov [rbx+16], rax ; capture arg into env
+    mov rax, [rbp-32] ; load operand
+    mov [rbx+24], rax ; capture arg into env
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 40 ; move pointer past env payload
+    mov rax, 40 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 88 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_76_ping_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_76_ping_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_76_ping_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 1 ; store num_remaining
+    mov rax, r12 ; copy _87_ping closure env_end to rax
+    mov [rbp-48], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-8] ; load operand
+    push rax ; stack arg
+    lea rax, [rel _74] ; point to string literal
+    push rax ; stack arg
+    mov r13, rsp ; sprintf args in order
+    xor rdi, rdi ; measure only
+    xor rsi, rsi
+    mov r15, rsp ; restore point after snprintf
+    and rsp, -16 ; align stack for variadic call
+    mov rdx, [r13+0] ; snprintf arg 0
+    mov rcx, [r13+8] ; snprintf arg 1
+    mov r8, [r13+16] ; snprintf arg 2
+    mov eax, 0 ; vector registers holding args
+    call snprintf ; invoke libc snprintf
+    mov rsp, r15
+    mov rbx, rax ; formatted length
+    lea rdi, [rbx+17] ; header, bytes and terminator
+    call alloc_heap_ptr ; allocate the string
+    mov r14, rax ; string block
+    lea rax, [rbx+17]
+    mov [r14], rax ; allocation size header
+    mov [r14+8], rbx ; length header
+    lea rdi, [r14+16] ; string bytes follow the header
+    lea rsi, [rbx+1] ; room for the terminator
+    mov r15, rsp ; restore point after snprintf
+    and rsp, -16 ; align stack for variadic call
+    mov rdx, [r13+0] ; snprintf arg 0
+    mov rcx, [r13+8] ; snprintf arg 1
+    mov r8, [r13+16] ; snprintf arg 2
+    mov eax, 0 ; vector registers holding args
+    call snprintf ; invoke libc snprintf
+    mov rsp, r15
+    lea rax, [r14+16] ; return formatted string pointer
+    add rsp, 24 ; pop stack args after libc call
+    mov rbx, rax ; keep result across releases
+    mov rdi, [rbp-8] ; load operand
+    call release_str_ptr ; release name string
+    mov rax, rbx ; restore result
+    mov r12, [rbp-48] ; load continuation env_end pointer
+    mov [r12-8], rax ; store env field
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+global _73_ping_unwrapper
+_73_ping_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-32] ; load name env field
+    mov [rbp-16], rax ; store value
+    mov rax, [r12-24] ; load n env field
+    mov [rbp-24], rax ; store value
+    mov rax, [r12-16] ; load ping env field
+    mov [rbp-32], rax ; store value
+    mov rax, [r12-8] ; load ok env field
+    mov [rbp-40], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    mov rax, [rbp-40] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-32] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-24] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-16] ; load operand
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    pop rdx ; restore arg into register
+    pop rcx ; restore arg into register
+    leave ; unwind before named jump
+    jmp _73_ping
+global _73_ping_deep_release
+_73_ping_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load __num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 3 ; operand literal
+    cmp rax, rbx
+    jg _73_ping_release_skip_0
+    mov rax, [r12-32] ; load _73_ping_release_field_0 env field
+    mov [rbp-24], rax ; store value
+    mov rdi, [rbp-24] ; load operand
+    call release_str_ptr ; release _73_ping_release_field_0 string
+_73_ping_release_skip_0:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 1 ; operand literal
+    cmp rax, rbx
+    jg _73_ping_release_skip_2
+    mov rax, [r12-16] ; load _73_ping_release_field_2 env field
+    mov [rbp-32], rax ; store value
+    mov rdi, [rbp-32] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _73_ping_release_field_2 release helper
+    call rax ; release _73_ping_release_field_2 and what it holds
+    pop rbx
+    pop r12
+_73_ping_release_skip_2:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _73_ping_release_skip_3
+    mov rax, [r12-8] ; load _73_ping_release_field_3 env field
+    mov [rbp-40], rax ; store value
+    mov rdi, [rbp-40] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _73_ping_release_field_3 release helper
+    call rax ; release _73_ping_release_field_3 and what it holds
+    pop rbx
+    pop r12
+_73_ping_release_skip_3:
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _73_ping_deepcopy
+_73_ping_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 3 ; operand literal
+    cmp rax, rbx
+    jg _73_ping_deepcopy_skip_0
+    mov rcx, [r12-32] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call copy_str_ptr ; duplicate heap pointer
+    mov [r12-32], rax ; store duplicated pointer
+    mov [rbp-24], rax ; store value
+_73_ping_deepcopy_skip_0:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 1 ; operand literal
+    cmp rax, rbx
+    jg _73_ping_deepcopy_skip_2
+    mov rcx, [r12-16] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call deepcopy_heap_ptr ; duplicate heap pointer
+    mov [r12-16], rax ; store duplicated pointer
+    mov [rbp-32], rax ; store value
+_73_ping_deepcopy_skip_2:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _73_ping_deepcopy_skip_3
+    mov rcx, [r12-8] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call deepcopy_heap_ptr ; duplicate heap pointer
+    mov [r12-8], rax ; store duplicated pointer
+    mov [rbp-40], rax ; store value
+_73_ping_deepcopy_skip_3:
+    leave
+    ret
+
+global ping
+ping:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store name arg in frame
+    mov [rbp-16], rsi ; store n arg in frame
+    mov [rbp-24], rdx ; store ok arg in frame
+    mov rdi, 72 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 24 ; move pointer past env payload
+    mov rax, 24 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 72 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [ping_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [ping_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [ping_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 3 ; store num_remaining
+    mov rax, r12 ; copy ping closure env_end to rax
+    mov [rbp-32], rax ; store value
+    mov rbx, [rbp-24] ; original closure ok to __ok_copy_0 env_end pointer for clone
+    mov rbx, rbx ; clone source env_end pointer
+    mov r13, [rbx+24] ; load env size metadata for clone
+    mov r14, [rbx+32] ; load heap size metadata for clone
+    mov r12, rbx ; compute env base pointer for clone
+    sub r12, r13 ; env base pointer for clone source
+    mov rdi, r14 ; length for cloned environment
+    call alloc_heap_ptr ; allocate cloned env block
+    mov r15, rax ; cloned closure env base pointer
+    mov rsi, r12 ; source env base for clone copy
+    mov rdi, r15 ; destination env base for clone copy
+    mov rcx, r14 ; bytes to copy for cloned env
+    cld ; ensure forward copy for env clone
+    rep movsb ; duplicate closure env data
+    mov rbx, r15 ; start from cloned env base
+    add rbx, r13 ; compute cloned env_end pointer
+    mov r12, rbx ; cloned env_end pointer
+    mov rax, [r12+16] ; load deepcopy helper entry point
+    push r12 ; preserve cloned env_end pointer
+    mov rdi, r12 ; pass env_end pointer to deepcopy helper
+    call rax ; deepcopy reference fields
+    pop r12 ; restore cloned env_end pointer
+    mov rax, r12 ; copy cloned env_end pointer
+    mov [rbp-40], rax ; store value
+    mov rdi, 80 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov rax, [rbp-8] ; load operand
+    mov [rbx+0], rax ; capture arg into env
+    mov rax, [rbp-16] ; load operand
+    mov [rbx+8], rax ; capture arg into env
+    mov rax, [rbp-32] ; load operand
+    mov [rbx+16], rax ; capture arg into env
+    mov rax, [rbp-40] ; load operand
+    mov [rbx+24], rax ; capture arg into env
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 32 ; move pointer past env payload
+    mov rax, 32 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 80 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_73_ping_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_73_ping_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_73_ping_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 0 ; store num_remaining
+    mov rax, r12 ; copy _88_ping closure env_end to rax
+    mov [rbp-48], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg gt__88_ping_true_0_0
+gt_ok_false_0_0:
+    mov rdi, [rbp-48] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _88_ping release helper
+    call rax ; release _88_ping and what it holds
+    pop rbx
+    pop r12
+    mov rbx, [rbp-24] ; load ok closure env_end pointer
+    mov rdi, rbx ; pass env_end pointer to closure
+    mov rax, [rdi+0] ; load closure unwrapper entry point
+    leave ; unwind before jumping
+    jmp rax ; tail call into closure
+gt__88_ping_true_0_0:
+    mov rdi, [rbp-24] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load ok release helper
+    call rax ; release ok and what it holds
+    pop rbx
+    pop r12
+    mov rbx, [rbp-48] ; load _88_ping closure env_end pointer
+    mov rdi, rbx ; pass env_end pointer to closure
+    mov rax, [rdi+0] ; load closure unwrapper entry point
+    leave ; unwind before jumping
+    jmp rax ; tail call into closure
+global ping_unwrapper
+ping_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-24] ; load name env field
+    mov [rbp-16], rax ; store value
+    mov rax, [r12-16] ; load n env field
+    mov [rbp-24], rax ; store value
+    mov rax, [r12-8] ; load ok env field
+    mov [rbp-32], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    mov rax, [rbp-32] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-24] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-16] ; load operand
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    pop rdx ; restore arg into register
+    leave ; unwind before named jump
+    jmp ping
+global ping_deep_release
+ping_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load __num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 2 ; operand literal
+    cmp rax, rbx
+    jg ping_release_skip_0
+    mov rax, [r12-24] ; load ping_release_field_0 env field
+    mov [rbp-24], rax ; store value
+    mov rdi, [rbp-24] ; load operand
+    call release_str_ptr ; release ping_release_field_0 string
+ping_release_skip_0:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg ping_release_skip_2
+    mov rax, [r12-8] ; load ping_release_field_2 env field
+    mov [rbp-32], rax ; store value
+    mov rdi, [rbp-32] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load ping_release_field_2 release helper
+    call rax ; release ping_release_field_2 and what it holds
+    pop rbx
+    pop r12
+ping_release_skip_2:
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global ping_deepcopy
+ping_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 2 ; operand literal
+    cmp rax, rbx
+    jg ping_deepcopy_skip_0
+    mov rcx, [r12-24] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call copy_str_ptr ; duplicate heap pointer
+    mov [r12-24], rax ; store duplicated pointer
+    mov [rbp-24], rax ; store value
+ping_deepcopy_skip_0:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg ping_deepcopy_skip_2
+    mov rcx, [r12-8] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call deepcopy_heap_ptr ; duplicate heap pointer
+    mov [r12-8], rax ; store duplicated pointer
+    mov [rbp-32], rax ; store value
+ping_deepcopy_skip_2:
+    leave
+    ret
+
+global _107_main
+_107_main:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov rdi, 48 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov r12, rbx ; env_end pointer before metadata
+    mov rax, 0 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 48 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_111_main_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_111_main_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_111_main_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 0 ; store num_remaining
+    mov rax, r12 ; copy _111_main closure env_end to rax
+    mov [rbp-8], rax ; store value
+    mov rax, [rbp-8] ; load operand
+    push rax ; stack arg
+    mov rax, 2 ; operand literal
+    push rax ; stack arg
+    lea rax, [rel _108] ; point to string literal
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    pop rdx ; restore arg into register
+    leave ; unwind before named jump
+    jmp ping
+global _107_main_unwrapper
+_107_main_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave ; unwind before named jump
+    jmp _107_main
+global _107_main_deep_release
+_107_main_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _107_main_deepcopy
+_107_main_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    leave
+    ret
+
+global _139_main
+_139_main:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    leave ; unwind before switching
+    jmp sched_next_ptr ; run the next coroutine
+global _139_main_unwrapper
+_139_main_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave ; unwind before named jump
+    jmp _139_main
+global _139_main_deep_release
+_139_main_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _139_main_deepcopy
+_139_main_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    leave
+    ret
+
+global _137_main
+_137_main:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store s arg in frame
+    mov rdi, 48 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov r12, rbx ; env_end pointer before metadata
+    mov rax, 0 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 48 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_139_main_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_139_main_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_139_main_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 0 ; store num_remaining
+    mov rax, r12 ; copy _139_main closure env_end to rax
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-8] ; load operand
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    mov rdx, [rdi-8] ; length to write
+    mov rsi, rdi ; buffer start
+    mov rdi, 1 ; stdout fd
+    call write ; invoke libc write
+    mov rdi, [rbp-8] ; load operand
+    call release_str_ptr ; release s string
+    mov r12, [rbp-16] ; load continuation env_end pointer
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+global _137_main_unwrapper
+_137_main_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-8] ; load s env field
+    mov [rbp-16], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    mov rax, [rbp-16] ; load operand
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    leave ; unwind before named jump
+    jmp _137_main
+global _137_main_deep_release
+_137_main_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load __num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _137_main_release_skip_0
+    mov rax, [r12-8] ; load _137_main_release_field_0 env field
+    mov [rbp-24], rax ; store value
+    mov rdi, [rbp-24] ; load operand
+    call release_str_ptr ; release _137_main_release_field_0 string
+_137_main_release_skip_0:
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _137_main_deepcopy
+_137_main_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _137_main_deepcopy_skip_0
+    mov rcx, [r12-8] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call copy_str_ptr ; duplicate heap pointer
+    mov [r12-8], rax ; store duplicated pointer
+    mov [rbp-24], rax ; store value
+_137_main_deepcopy_skip_0:
+    leave
+    ret
+
+global _134_main
+_134_main:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store x arg in frame
+    mov rdi, 56 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 8 ; move pointer past env payload
+    mov rax, 8 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 56 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_137_main_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_137_main_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_137_main_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 1 ; store num_remaining
+    mov rax, r12 ; copy _137_main closure env_end to rax
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-8] ; load operand
+    push rax ; stack arg
+    lea rax, [rel _135] ; point to string literal
+    push rax ; stack arg
+    mov r13, rsp ; sprintf args in order
+    xor rdi, rdi ; measure only
+    xor rsi, rsi
+    mov r15, rsp ; restore point after snprintf
+    and rsp, -16 ; align stack for variadic call
+    mov rdx, [r13+0] ; snprintf arg 0
+    mov rcx, [r13+8] ; snprintf arg 1
+    mov eax, 0 ; vector registers holding args
+    call snprintf ; invoke libc snprintf
+    mov rsp, r15
+    mov rbx, rax ; formatted length
+    lea rdi, [rbx+17] ; header, bytes and terminator
+    call alloc_heap_ptr ; allocate the string
+    mov r14, rax ; string block
+    lea rax, [rbx+17]
+    mov [r14], rax ; allocation size header
+    mov [r14+8], rbx ; length header
+    lea rdi, [r14+16] ; string bytes follow the header
+    lea rsi, [rbx+1] ; room for the terminator
+    mov r15, rsp ; restore point after snprintf
+    and rsp, -16 ; align stack for variadic call
+    mov rdx, [r13+0] ; snprintf arg 0
+    mov rcx, [r13+8] ; snprintf arg 1
+    mov eax, 0 ; vector registers holding args
+    call snprintf ; invoke libc snprintf
+    mov rsp, r15
+    lea rax, [r14+16] ; return formatted string pointer
+    add rsp, 16 ; pop stack args after libc call
+    mov r12, [rbp-16] ; load continuation env_end pointer
+    mov [r12-8], rax ; store env field
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+global _134_main_unwrapper
+_134_main_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-8] ; load x env field
+    mov [rbp-16], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    mov rax, [rbp-16] ; load operand
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    leave ; unwind before named jump
+    jmp _134_main
+global _134_main_deep_release
+_134_main_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _134_main_deepcopy
+_134_main_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    leave
+    ret
+
+global _155_main
+_155_main:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store f arg in frame
+    mov [rbp-16], rsi ; store fc3 arg in frame
+    mov rdi, [rbp-16] ; load operand
+    call chan_release_ptr ; drop a reference to fc3 channel
+    mov rbx, [rbp-8] ; load f closure env_end pointer
+    mov rax, 42 ; operand literal
+    mov [rbx-8], rax ; store env field
+    mov rdi, rbx ; pass env_end pointer to closure
+    mov rax, [rdi+0] ; load closure unwrapper entry point
+    leave ; unwind before jumping
+    jmp rax ; tail call into closure
+global _155_main_unwrapper
+_155_main_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-16] ; load f env field
+    mov [rbp-16], rax ; store value
+    mov rax, [r12-8] ; load fc3 env field
+    mov [rbp-24], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    mov rax, [rbp-24] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-16] ; load operand
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    leave ; unwind before named jump
+    jmp _155_main
+global _155_main_deep_release
+_155_main_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load __num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 1 ; operand literal
+    cmp rax, rbx
+    jg _155_main_release_skip_0
+    mov rax, [r12-16] ; load _155_main_release_field_0 env field
+    mov [rbp-24], rax ; store value
+    mov rdi, [rbp-24] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _155_main_release_field_0 release helper
+    call rax ; release _155_main_release_field_0 and what it holds
+    pop rbx
+    pop r12
+_155_main_release_skip_0:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _155_main_release_skip_1
+    mov rax, [r12-8] ; load _155_main_release_field_1 env field
+    mov [rbp-32], rax ; store value
+    mov rdi, [rbp-32] ; load operand
+    call chan_release_ptr ; drop a reference to _155_main_release_field_1 channel
+_155_main_release_skip_1:
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _155_main_deepcopy
+_155_main_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 1 ; operand literal
+    cmp rax, rbx
+    jg _155_main_deepcopy_skip_0
+    mov rcx, [r12-16] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call deepcopy_heap_ptr ; duplicate heap pointer
+    mov [r12-16], rax ; store duplicated pointer
+    mov [rbp-24], rax ; store value
+_155_main_deepcopy_skip_0:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _155_main_deepcopy_skip_1
+    mov rcx, [r12-8] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call chan_copy_ptr ; duplicate heap pointer
+    mov [r12-8], rax ; store duplicated pointer
+    mov [rbp-32], rax ; store value
+_155_main_deepcopy_skip_1:
+    leave
+    ret
+
+global _147_main
+_147_main:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store fc2 arg in frame
+    mov rdi, 64 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 16 ; move pointer past env payload
+    mov rax, 16 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 64 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_155_main_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_155_main_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_155_main_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 2 ; store num_remaining
+    mov rax, r12 ; copy _155_main closure env_end to rax
+    mov [rbp-16], rax ; store value
+    mov r13, [rbp-8] ; load operand
+    mov rsi, [rbp-16] ; load operand
+    mov rdi, r13
+    call chan_recv_ptr ; take a queued value or park
+    test rdx, rdx ; was a value waiting?
+    jz _147_main_recv_park_0
+    mov r12, [rbp-16] ; load continuation env_end pointer
+    mov [r12-16], rax ; store env field
+    mov [r12-8], r13 ; store env field
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+_147_main_recv_park_0:
+    leave ; unwind before switching
+    jmp sched_next_ptr ; run the next coroutine
+global _147_main_unwrapper
+_147_main_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-8] ; load fc2 env field
+    mov [rbp-16], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    mov rax, [rbp-16] ; load operand
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    leave ; unwind before named jump
+    jmp _147_main
+global _147_main_deep_release
+_147_main_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load __num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _147_main_release_skip_0
+    mov rax, [r12-8] ; load _147_main_release_field_0 env field
+    mov [rbp-24], rax ; store value
+    mov rdi, [rbp-24] ; load operand
+    call chan_release_ptr ; drop a reference to _147_main_release_field_0 channel
+_147_main_release_skip_0:
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _147_main_deepcopy
+_147_main_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _147_main_deepcopy_skip_0
+    mov rcx, [r12-8] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call chan_copy_ptr ; duplicate heap pointer
+    mov [r12-8], rax ; store duplicated pointer
+    mov [rbp-24], rax ; store value
+_147_main_deepcopy_skip_0:
+    leave
+    ret
+
+global _132_main
+_132_main:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store fc arg in frame
+    mov rdi, 56 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 8 ; move pointer past env payload
+    mov rax, 8 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 56 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_134_main_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_134_main_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_134_main_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 1 ; store num_remaining
+    mov rax, r12 ; copy _134_main closure env_end to rax
+    mov [rbp-16], rax ; store value
+    mov rdi, 56 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 8 ; move pointer past env payload
+    mov rax, 8 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 56 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_147_main_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_147_main_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_147_main_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 1 ; store num_remaining
+    mov rax, r12 ; copy _147_main closure env_end to rax
+    mov [rbp-24], rax ; store value
+    mov r13, [rbp-8] ; load operand
+    mov rsi, [rbp-16] ; load operand
+    mov rdi, r13
+    call chan_send_ptr ; hand the value over or queue it
+    mov r12, [rbp-24] ; load continuation env_end pointer
+    mov [r12-8], r13 ; store env field
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+global _132_main_unwrapper
+_132_main_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-8] ; load fc env field
+    mov [rbp-16], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    mov rax, [rbp-16] ; load operand
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    leave ; unwind before named jump
+    jmp _132_main
+global _132_main_deep_release
+_132_main_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load __num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _132_main_release_skip_0
+    mov rax, [r12-8] ; load _132_main_release_field_0 env field
+    mov [rbp-24], rax ; store value
+    mov rdi, [rbp-24] ; load operand
+    call chan_release_ptr ; drop a reference to _132_main_release_field_0 channel
+_132_main_release_skip_0:
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _132_main_deepcopy
+_132_main_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _132_main_deepcopy_skip_0
+    mov rcx, [r12-8] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call chan_copy_ptr ; duplicate heap pointer
+    mov [r12-8], rax ; store duplicated pointer
+    mov [rbp-24], rax ; store value
+_132_main_deepcopy_skip_0:
+    leave
+    ret
+
+global _127_main
+_127_main:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store left arg in frame
+    mov rdi, 56 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 8 ; move pointer past env payload
+    mov rax, 8 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 56 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_132_main_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_132_main_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_132_main_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 1 ; store num_remaining
+    mov rax, r12 ; copy _132_main closure env_end to rax
+    mov [rbp-16], rax ; store value
+    mov rdi, [rbp-8] ; load operand
+    call chan_release_ptr ; drop a reference to left channel
+    mov rdi, 3 ; class of the values
+    call chan_new_ptr ; make the channel
+    mov r12, [rbp-16] ; load continuation env_end pointer
+    mov [r12-8], rax ; store env field
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+global _127_main_unwrapper
+_127_main_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-8] ; load left env field
+    mov [rbp-16], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    mov rax, [rbp-16] ; load operand
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    leave ; unwind before named jump
+    jmp _127_main
+global _127_main_deep_release
+_127_main_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load __num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _127_main_release_skip_0
+    mov rax, [r12-8] ; load _127_main_release_field_0 env field
+    mov [rbp-24], rax ; store value
+    mov rdi, [rbp-24] ; load operand
+    call chan_release_ptr ; drop a reference to _127_main_release_field_0 channel
+_127_main_release_skip_0:
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _127_main_deepcopy
+_127_main_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _127_main_deepcopy_skip_0
+    mov rcx, [r12-8] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call chan_copy_ptr ; duplicate heap pointer
+    mov [r12-8], rax ; store duplicated pointer
+    mov [rbp-24], rax ; store value
+_127_main_deepcopy_skip_0:
+    leave
+    ret
+
+global _125_main
+_125_main:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store sc arg in frame
+    mov [rbp-16], rsi ; store s arg in frame
+    mov rdi, 56 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 8 ; move pointer past env payload
+    mov rax, 8 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 56 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_127_main_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_127_main_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_127_main_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 1 ; store num_remaining
+    mov rax, r12 ; copy _127_main closure env_end to rax
+    mov [rbp-24], rax ; store value
+    mov r13, [rbp-8] ; load operand
+    mov rsi, [rbp-16] ; load operand
+    mov rdi, r13
+    call chan_send_ptr ; hand the value over or queue it
+    mov r12, [rbp-24] ; load continuation env_end pointer
+    mov [r12-8], r13 ; store env field
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+global _125_main_unwrapper
+_125_main_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-16] ; load sc env field
+    mov [rbp-16], rax ; store value
+    mov rax, [r12-8] ; load s env field
+    mov [rbp-24], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    mov rax, [rbp-24] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-16] ; load operand
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    leave ; unwind before named jump
+    jmp _125_main
+global _125_main_deep_release
+_125_main_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load __num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 1 ; operand literal
+    cmp rax, rbx
+    jg _125_main_release_skip_0
+    mov rax, [r12-16] ; load _125_main_release_field_0 env field
+    mov [rbp-24], rax ; store value
+    mov rdi, [rbp-24] ; load operand
+    call chan_release_ptr ; drop a reference to _125_main_release_field_0 channel
+_125_main_release_skip_0:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _125_main_release_skip_1
+    mov rax, [r12-8] ; load _125_main_release_field_1 env field
+    mov [rbp-32], rax ; store value
+    mov rdi, [rbp-32] ; load operand
+    call release_str_ptr ; release _125_main_release_field_1 string
+_125_main_release_skip_1:
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _125_main_deepcopy
+_125_main_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 1 ; operand literal
+    cmp rax, rbx
+    jg _125_main_deepcopy_skip_0
+    mov rcx, [r12-16] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call chan_copy_ptr ; duplicate heap pointer
+    mov [r12-16], rax ; store duplicated pointer
+    mov [rbp-24], rax ; store value
+_125_main_deepcopy_skip_0:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _125_main_deepcopy_skip_1
+    mov rcx, [r12-8] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call copy_str_ptr ; duplicate heap pointer
+    mov [r12-8], rax ; store duplicated pointer
+    mov [rbp-32], rax ; store value
+_125_main_deepcopy_skip_1:
+    leave
+    ret
+
+global _121_main
+_121_main:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store sc arg in frame
+    mov rdi, 64 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov rax, [rbp-8] ; load operand
+    mov [rbx+0], rax ; capture arg into env
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 16 ; move pointer past env payload
+    mov rax, 16 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 64 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_125_main_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_125_main_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_125_main_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 1 ; store num_remaining
+    mov rax, r12 ; copy _161_main closure env_end to rax
+    mov [rbp-16], rax ; store value
+    lea rax, [rel _123] ; point to string literal
+    push rax ; stack arg
+    lea rax, [rel _122] ; point to string literal
+    push rax ; stack arg
+    mov r13, rsp ; sprintf args in order
+    xor rdi, rdi ; measure only
+    xor rsi, rsi
+    mov r15, rsp ; restore point after snprintf
+    and rsp, -16 ; align stack for variadic call
+    mov rdx, [r13+0] ; snprintf arg 0
+    mov rcx, [r13+8] ; snprintf arg 1
+    mov eax, 0 ; vector registers holding args
+    call snprintf ; invoke libc snprintf
+    mov rsp, r15
+    mov rbx, rax ; formatted length
+    lea rdi, [rbx+17] ; header, bytes and terminator
+    call alloc_heap_ptr ; allocate the string
+    mov r14, rax ; string block
+    lea rax, [rbx+17]
+    mov [r14], rax ; allocation size header
+    mov [r14+8], rbx ; length header
+    lea rdi, [r14+16] ; string bytes follow the header
+    lea rsi, [rbx+1] ; room for the terminator
+    mov r15, rsp ; restore point after snprintf
+    and rsp, -16 ; align stack for variadic call
+    mov rdx, [r13+0] ; snprintf arg 0
+    mov rcx, [r13+8] ; snprintf arg 1
+    mov eax, 0 ; vector registers holding args
+    call snprintf ; invoke libc snprintf
+    mov rsp, r15
+    lea rax, [r14+16] ; return formatted string pointer
+    add rsp, 16 ; pop stack args after libc call
+    mov r12, [rbp-16] ; load continuation env_end pointer
+    mov [r12-8], rax ; store env field
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+global _121_main_unwrapper
+_121_main_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-8] ; load sc env field
+    mov [rbp-16], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    mov rax, [rbp-16] ; load operand
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    leave ; unwind before named jump
+    jmp _121_main
+global _121_main_deep_release
+_121_main_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load __num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _121_main_release_skip_0
+    mov rax, [r12-8] ; load _121_main_release_field_0 env field
+    mov [rbp-24], rax ; store value
+    mov rdi, [rbp-24] ; load operand
+    call chan_release_ptr ; drop a reference to _121_main_release_field_0 channel
+_121_main_release_skip_0:
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _121_main_deepcopy
+_121_main_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _121_main_deepcopy_skip_0
+    mov rcx, [r12-8] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call chan_copy_ptr ; duplicate heap pointer
+    mov [r12-8], rax ; store duplicated pointer
+    mov [rbp-24], rax ; store value
+_121_main_deepcopy_skip_0:
+    leave
+    ret
+
+global _119_main
+_119_main:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov rdi, 56 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 8 ; move pointer past env payload
+    mov rax, 8 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 56 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_121_main_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_121_main_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_121_main_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 1 ; store num_remaining
+    mov rax, r12 ; copy _121_main closure env_end to rax
+    mov [rbp-8], rax ; store value
+    mov rdi, 1 ; class of the values
+    call chan_new_ptr ; make the channel
+    mov r12, [rbp-8] ; load continuation env_end pointer
+    mov [r12-8], rax ; store env field
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+global _119_main_unwrapper
+_119_main_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave ; unwind before named jump
+    jmp _119_main
+global _119_main_deep_release
+_119_main_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _119_main_deepcopy
+_119_main_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    leave
+    ret
+
+global _115_main
+_115_main:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov rdi, 48 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov r12, rbx ; env_end pointer before metadata
+    mov rax, 0 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 48 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_119_main_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_119_main_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_119_main_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 0 ; store num_remaining
+    mov rax, r12 ; copy _119_main closure env_end to rax
+    mov [rbp-8], rax ; store value
+    mov rax, [rbp-8] ; load operand
+    push rax ; stack arg
+    mov rax, 2 ; operand literal
+    push rax ; stack arg
+    lea rax, [rel _116] ; point to string literal
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    pop rdx ; restore arg into register
+    leave ; unwind before named jump
+    jmp ping
+global _115_main_unwrapper
+_115_main_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave ; unwind before named jump
+    jmp _115_main
+global _115_main_deep_release
+_115_main_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _115_main_deepcopy
+_115_main_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    leave
+    ret
+
+global _105_main
+_105_main:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov rdi, 48 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov r12, rbx ; env_end pointer before metadata
+    mov rax, 0 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 48 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_107_main_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_107_main_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_107_main_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 0 ; store num_remaining
+    mov rax, r12 ; copy _107_main closure env_end to rax
+    mov [rbp-8], rax ; store value
+    mov rdi, 48 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov r12, rbx ; env_end pointer before metadata
+    mov rax, 0 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 48 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_115_main_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_115_main_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_115_main_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 0 ; store num_remaining
+    mov rax, r12 ; copy _115_main closure env_end to rax
+    mov [rbp-16], rax ; store value
+    mov rdi, [rbp-8] ; load operand
+    call sched_ready_ptr ; run _107_main later
+    mov r12, [rbp-16] ; load continuation env_end pointer
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+global _105_main_unwrapper
+_105_main_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave ; unwind before named jump
+    jmp _105_main
+global _105_main_deep_release
+_105_main_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _105_main_deepcopy
+_105_main_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    leave
+    ret
+
+global _103_main
+_103_main:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store s arg in frame
+    mov rdi, 48 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov r12, rbx ; env_end pointer before metadata
+    mov rax, 0 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 48 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_105_main_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_105_main_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_105_main_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 0 ; store num_remaining
+    mov rax, r12 ; copy _105_main closure env_end to rax
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-8] ; load operand
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    mov rdx, [rdi-8] ; length to write
+    mov rsi, rdi ; buffer start
+    mov rdi, 1 ; stdout fd
+    call write ; invoke libc write
+    mov rdi, [rbp-8] ; load operand
+    call release_str_ptr ; release s string
+    mov r12, [rbp-16] ; load continuation env_end pointer
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+global _103_main_unwrapper
+_103_main_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-8] ; load s env field
+    mov [rbp-16], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    mov rax, [rbp-16] ; load operand
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    leave ; unwind before named jump
+    jmp _103_main
+global _103_main_deep_release
+_103_main_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load __num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _103_main_release_skip_0
+    mov rax, [r12-8] ; load _103_main_release_field_0 env field
+    mov [rbp-24], rax ; store value
+    mov rdi, [rbp-24] ; load operand
+    call release_str_ptr ; release _103_main_release_field_0 string
+_103_main_release_skip_0:
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _103_main_deepcopy
+_103_main_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _103_main_deepcopy_skip_0
+    mov rcx, [r12-8] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call copy_str_ptr ; duplicate heap pointer
+    mov [r12-8], rax ; store duplicated pointer
+    mov [rbp-24], rax ; store value
+_103_main_deepcopy_skip_0:
+    leave
+    ret
+
+global _100_main
+_100_main:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store total arg in frame
+    mov rdi, 56 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 8 ; move pointer past env payload
+    mov rax, 8 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 56 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_103_main_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_103_main_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_103_main_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 1 ; store num_remaining
+    mov rax, r12 ; copy _103_main closure env_end to rax
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-8] ; load operand
+    push rax ; stack arg
+    lea rax, [rel _101] ; point to string literal
+    push rax ; stack arg
+    mov r13, rsp ; sprintf args in order
+    xor rdi, rdi ; measure only
+    xor rsi, rsi
+    mov r15, rsp ; restore point after snprintf
+    and rsp, -16 ; align stack for variadic call
+    mov rdx, [r13+0] ; snprintf arg 0
+    mov rcx, [r13+8] ; snprintf arg 1
+    mov eax, 0 ; vector registers holding args
+    call snprintf ; invoke libc snprintf
+    mov rsp, r15
+    mov rbx, rax ; formatted length
+    lea rdi, [rbx+17] ; header, bytes and terminator
+    call alloc_heap_ptr ; allocate the string
+    mov r14, rax ; string block
+    lea rax, [rbx+17]
+    mov [r14], rax ; allocation size header
+    mov [r14+8], rbx ; length header
+    lea rdi, [r14+16] ; string bytes follow the header
+    lea rsi, [rbx+1] ; room for the terminator
+    mov r15, rsp ; restore point after snprintf
+    and rsp, -16 ; align stack for variadic call
+    mov rdx, [r13+0] ; snprintf arg 0
+    mov rcx, [r13+8] ; snprintf arg 1
+    mov eax, 0 ; vector registers holding args
+    call snprintf ; invoke libc snprintf
+    mov rsp, r15
+    lea rax, [r14+16] ; return formatted string pointer
+    add rsp, 16 ; pop stack args after libc call
+    mov r12, [rbp-16] ; load continuation env_end pointer
+    mov [r12-8], rax ; store env field
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+global _100_main_unwrapper
+_100_main_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-8] ; load total env field
+    mov [rbp-16], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    mov rax, [rbp-16] ; load operand
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    leave ; unwind before named jump
+    jmp _100_main
+global _100_main_deep_release
+_100_main_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _100_main_deepcopy
+_100_main_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    leave
+    ret
+
+global _55_consume
+_55_consume:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store ok arg in frame
+    mov [rbp-16], rsi ; store total arg in frame
+    mov rbx, [rbp-8] ; load ok closure env_end pointer
+    mov rax, [rbp-16] ; load operand
+    mov [rbx-8], rax ; store env field
+    mov rdi, rbx ; pass env_end pointer to closure
+    mov rax, [rdi+0] ; load closure unwrapper entry point
+    leave ; unwind before jumping
+    jmp rax ; tail call into closure
+global _55_consume_unwrapper
+_55_consume_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-16] ; load ok env field
+    mov [rbp-16], rax ; store value
+    mov rax, [r12-8] ; load total env field
+    mov [rbp-24], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    mov rax, [rbp-24] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-16] ; load operand
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    leave ; unwind before named jump
+    jmp _55_consume
+global _55_consume_deep_release
+_55_consume_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load __num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 1 ; operand literal
+    cmp rax, rbx
+    jg _55_consume_release_skip_0
+    mov rax, [r12-16] ; load _55_consume_release_field_0 env field
+    mov [rbp-24], rax ; store value
+    mov rdi, [rbp-24] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _55_consume_release_field_0 release helper
+    call rax ; release _55_consume_release_field_0 and what it holds
+    pop rbx
+    pop r12
+_55_consume_release_skip_0:
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _55_consume_deepcopy
+_55_consume_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 1 ; operand literal
+    cmp rax, rbx
+    jg _55_consume_deepcopy_skip_0
+    mov rcx, [r12-16] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call deepcopy_heap_ptr ; duplicate heap pointer
+    mov [r12-16], rax ; store duplicated pointer
+    mov [rbp-24], rax ; store value
+_55_consume_deepcopy_skip_0:
+    leave
+    ret
+
+global _65_consume
+_65_consume:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store consume arg in frame
+    mov [rbp-16], rsi ; store rest arg in frame
+    mov [rbp-24], rdx ; store ok arg in frame
+    mov [rbp-32], rcx ; store t arg in frame
+    mov rbx, [rbp-8] ; load consume closure env_end pointer
+    mov rax, [rbp-16] ; load operand
+    mov [rbx-24], rax ; store env field
+    mov rax, [rbp-32] ; load operand
+    mov [rbx-16], rax ; store env field
+    mov rax, [rbp-24] ; load operand
+    mov [rbx-8], rax ; store env field
+    mov rdi, rbx ; pass env_end pointer to closure
+    mov rax, [rdi+0] ; load closure unwrapper entry point
+    leave ; unwind before jumping
+    jmp rax ; tail call into closure
+global _65_consume_unwrapper
+_65_consume_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-32] ; load consume env field
+    mov [rbp-16], rax ; store value
+    mov rax, [r12-24] ; load rest env field
+    mov [rbp-24], rax ; store value
+    mov rax, [r12-16] ; load ok env field
+    mov [rbp-32], rax ; store value
+    mov rax, [r12-8] ; load t env field
+    mov [rbp-40], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    mov rax, [rbp-40] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-32] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-24] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-16] ; load operand
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    pop rdx ; restore arg into register
+    pop rcx ; restore arg into register
+    leave ; unwind before named jump
+    jmp _65_consume
+global _65_consume_deep_release
+_65_consume_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load __num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 3 ; operand literal
+    cmp rax, rbx
+    jg _65_consume_release_skip_0
+    mov rax, [r12-32] ; load _65_consume_release_field_0 env field
+    mov [rbp-24], rax ; store value
+    mov rdi, [rbp-24] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _65_consume_release_field_0 release helper
+    call rax ; release _65_consume_release_field_0 and what it holds
+    pop rbx
+    pop r12
+_65_consume_release_skip_0:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 2 ; operand literal
+    cmp rax, rbx
+    jg _65_consume_release_skip_1
+    mov rax, [r12-24] ; load _65_consume_release_field_1 env field
+    mov [rbp-32], rax ; store value
+    mov rdi, [rbp-32] ; load operand
+    call chan_release_ptr ; drop a reference to _65_consume_release_field_1 channel
+_65_consume_release_skip_1:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 1 ; operand literal
+    cmp rax, rbx
+    jg _65_consume_release_skip_2
+    mov rax, [r12-16] ; load _65_consume_release_field_2 env field
+    mov [rbp-40], rax ; store value
+    mov rdi, [rbp-40] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _65_consume_release_field_2 release helper
+    call rax ; release _65_consume_release_field_2 and what it holds
+    pop rbx
+    pop r12
+_65_consume_release_skip_2:
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _65_consume_deepcopy
+_65_consume_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 3 ; operand literal
+    cmp rax, rbx
+    jg _65_consume_deepcopy_skip_0
+    mov rcx, [r12-32] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call deepcopy_heap_ptr ; duplicate heap pointer
+    mov [r12-32], rax ; store duplicated pointer
+    mov [rbp-24], rax ; store value
+_65_consume_deepcopy_skip_0:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 2 ; operand literal
+    cmp rax, rbx
+    jg _65_consume_deepcopy_skip_1
+    mov rcx, [r12-24] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call chan_copy_ptr ; duplicate heap pointer
+    mov [r12-24], rax ; store duplicated pointer
+    mov [rbp-32], rax ; store value
+_65_consume_deepcopy_skip_1:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 1 ; operand literal
+    cmp rax, rbx
+    jg _65_consume_deepcopy_skip_2
+    mov rcx, [r12-16] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call deepcopy_heap_ptr ; duplicate heap pointer
+    mov [r12-16], rax ; store duplicated pointer
+    mov [rbp-40], rax ; store value
+_65_consume_deepcopy_skip_2:
+    leave
+    ret
+
+global _63_consume
+_63_consume:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store total arg in frame
+    mov [rbp-16], rsi ; store v arg in frame
+    mov [rbp-24], rdx ; store consume arg in frame
+    mov [rbp-32], rcx ; store rest arg in frame
+    mov [rbp-40], r8 ; store ok arg in frame
+    mov rdi, 80 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov rax, [rbp-24] ; load operand
+    mov [rbx+0], rax ; capture arg into env
+    mov rax, [rbp-32] ; load operand
+    mov [rbx+8], rax ; capture arg into env
+    mov rax, [rbp-40] ; load operand
+    mov [rbx+16], rax ; capture arg into env
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 32 ; move pointer past env payload
+    mov rax, 32 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 80 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_65_consume_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_65_consume_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_65_consume_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 1 ; store num_remaining
+    mov rax, r12 ; copy _66_consume closure env_end to rax
+    mov [rbp-48], rax ; store value
+    mov rax, [rbp-8] ; load operand
+    mov rbx, [rbp-16] ; load operand
+    add rax, rbx ; add second integer
+    mov r12, [rbp-48] ; load continuation env_end pointer
+    mov [r12-8], rax ; store env field
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+global _63_consume_unwrapper
+_63_consume_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-40] ; load total env field
+    mov [rbp-16], rax ; store value
+    mov rax, [r12-32] ; load v env field
+    mov [rbp-24], rax ; store value
+    mov rax, [r12-24] ; load consume env field
+    mov [rbp-32], rax ; store value
+    mov rax, [r12-16] ; load rest env field
+    mov [rbp-40], rax ; store value
+    mov rax, [r12-8] ; load ok env field
+    mov [rbp-48], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    mov rax, [rbp-48] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-40] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-32] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-24] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-16] ; load operand
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    pop rdx ; restore arg into register
+    pop rcx ; restore arg into register
+    pop r8 ; restore arg into register
+    leave ; unwind before named jump
+    jmp _63_consume
+global _63_consume_deep_release
+_63_consume_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load __num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 2 ; operand literal
+    cmp rax, rbx
+    jg _63_consume_release_skip_2
+    mov rax, [r12-24] ; load _63_consume_release_field_2 env field
+    mov [rbp-24], rax ; store value
+    mov rdi, [rbp-24] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _63_consume_release_field_2 release helper
+    call rax ; release _63_consume_release_field_2 and what it holds
+    pop rbx
+    pop r12
+_63_consume_release_skip_2:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 1 ; operand literal
+    cmp rax, rbx
+    jg _63_consume_release_skip_3
+    mov rax, [r12-16] ; load _63_consume_release_field_3 env field
+    mov [rbp-32], rax ; store value
+    mov rdi, [rbp-32] ; load operand
+    call chan_release_ptr ; drop a reference to _63_consume_release_field_3 channel
+_63_consume_release_skip_3:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _63_consume_release_skip_4
+    mov rax, [r12-8] ; load _63_consume_release_field_4 env field
+    mov [rbp-40], rax ; store value
+    mov rdi, [rbp-40] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _63_consume_release_field_4 release helper
+    call rax ; release _63_consume_release_field_4 and what it holds
+    pop rbx
+    pop r12
+_63_consume_release_skip_4:
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _63_consume_deepcopy
+_63_consume_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 2 ; operand literal
+    cmp rax, rbx
+    jg _63_consume_deepcopy_skip_2
+    mov rcx, [r12-24] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call deepcopy_heap_ptr ; duplicate heap pointer
+    mov [r12-24], rax ; store duplicated pointer
+    mov [rbp-24], rax ; store value
+_63_consume_deepcopy_skip_2:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 1 ; operand literal
+    cmp rax, rbx
+    jg _63_consume_deepcopy_skip_3
+    mov rcx, [r12-16] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call chan_copy_ptr ; duplicate heap pointer
+    mov [r12-16], rax ; store duplicated pointer
+    mov [rbp-32], rax ; store value
+_63_consume_deepcopy_skip_3:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _63_consume_deepcopy_skip_4
+    mov rcx, [r12-8] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call deepcopy_heap_ptr ; duplicate heap pointer
+    mov [r12-8], rax ; store duplicated pointer
+    mov [rbp-40], rax ; store value
+_63_consume_deepcopy_skip_4:
+    leave
+    ret
+
+global _61_consume
+_61_consume:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 64 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store total arg in frame
+    mov [rbp-16], rsi ; store v arg in frame
+    mov [rbp-24], rdx ; store consume arg in frame
+    mov [rbp-32], rcx ; store rest arg in frame
+    mov [rbp-40], r8 ; store ok arg in frame
+    mov [rbp-48], r9 ; store s arg in frame
+    mov rdi, 88 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov rax, [rbp-8] ; load operand
+    mov [rbx+0], rax ; capture arg into env
+    mov rax, [rbp-16] ; load operand
+    mov [rbx+8], rax ; capture arg into env
+    mov rax, [rbp-24] ; load operand
+    mov [rbx+16], rax ; capture arg into env
+    mov rax, [rbp-32] ; load operand
+    mov [rbx+24], rax ; capture arg into env
+    mov rax, [rbp-40] ; load operand
+    mov [rbx+32], rax ; capture arg into env
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 40 ; move pointer past env payload
+    mov rax, 40 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 88 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_63_consume_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_63_consume_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_63_consume_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 0 ; store num_remaining
+    mov rax, r12 ; copy _67_consume closure env_end to rax
+    mov [rbp-56], rax ; store value
+    mov rax, [rbp-48] ; load operand
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    mov rdx, [rdi-8] ; length to write
+    mov rsi, rdi ; buffer start
+    mov rdi, 1 ; stdout fd
+    call write ; invoke libc write
+    mov rdi, [rbp-48] ; load operand
+    call release_str_ptr ; release s string
+    mov r12, [rbp-56] ; load continuation env_end pointer
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+global _61_consume_unwrapper
+_61_consume_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 64 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-48] ; load total env field
+    mov [rbp-16], rax ; store value
+    mov rax, [r12-40] ; load v env field
+    mov [rbp-24], rax ; store value
+    mov rax, [r12-32] ; load consume env field
+    mov [rbp-32], rax ; store value
+    mov rax, [r12-24] ; load rest env field
+    mov [rbp-40], rax ; store value
+    mov rax, [r12-16] ; load ok env field
+    mov [rbp-48], rax ; store value
+    mov rax, [r12-8] ; load s env field
+    mov [rbp-56], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    mov rax, [rbp-56] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-48] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-40] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-32] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-24] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-16] ; load operand
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    pop rdx ; restore arg into register
+    pop rcx ; restore arg into register
+    pop r8 ; restore arg into register
+    pop r9 ; restore arg into register
+    leave ; unwind before named jump
+    jmp _61_consume
+global _61_consume_deep_release
+_61_consume_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load __num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 3 ; operand literal
+    cmp rax, rbx
+    jg _61_consume_release_skip_2
+    mov rax, [r12-32] ; load _61_consume_release_field_2 env field
+    mov [rbp-24], rax ; store value
+    mov rdi, [rbp-24] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _61_consume_release_field_2 release helper
+    call rax ; release _61_consume_release_field_2 and what it holds
+    pop rbx
+    pop r12
+_61_consume_release_skip_2:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 2 ; operand literal
+    cmp rax, rbx
+    jg _61_consume_release_skip_3
+    mov rax, [r12-24] ; load _61_consume_release_field_3 env field
+    mov [rbp-32], rax ; store value
+    mov rdi, [rbp-32] ; load operand
+    call chan_release_ptr ; drop a reference to _61_consume_release_field_3 channel
+_61_consume_release_skip_3:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 1 ; operand literal
+    cmp rax, rbx
+    jg _61_consume_release_skip_4
+    mov rax, [r12-16] ; load _61_consume_release_field_4 env field
+    mov [rbp-40], rax ; store value
+    mov rdi, [rbp-40] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _61_consume_release_field_4 release helper
+    call rax ; release _61_consume_release_field_4 and what it holds
+    pop rbx
+    pop r12
+_61_consume_release_skip_4:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _61_consume_release_skip_5
+    mov rax, [r12-8] ; load _61_consume_release_field_5 env field
+    mov [rbp-48], rax ; store value
+    mov rdi, [rbp-48] ; load operand
+    call release_str_ptr ; release _61_consume_release_field_5 string
+_61_consume_release_skip_5:
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _61_consume_deepcopy
+_61_consume_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 3 ; operand literal
+    cmp rax, rbx
+    jg _61_consume_deepcopy_skip_2
+    mov rcx, [r12-32] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call deepcopy_heap_ptr ; duplicate heap pointer
+    mov [r12-32], rax ; store duplicated pointer
+    mov [rbp-24], rax ; store value
+_61_consume_deepcopy_skip_2:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 2 ; operand literal
+    cmp rax, rbx
+    jg _61_consume_deepcopy_skip_3
+    mov rcx, [r12-24] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call chan_copy_ptr ; duplicate heap pointer
+    mov [r12-24], rax ; store duplicated pointer
+    mov [rbp-32], rax ; store value
+_61_consume_deepcopy_skip_3:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 1 ; operand literal
+    cmp rax, rbx
+    jg _61_consume_deepcopy_skip_4
+    mov rcx, [r12-16] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call deepcopy_heap_ptr ; duplicate heap pointer
+    mov [r12-16], rax ; store duplicated pointer
+    mov [rbp-40], rax ; store value
+_61_consume_deepcopy_skip_4:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _61_consume_deepcopy_skip_5
+    mov rcx, [r12-8] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call copy_str_ptr ; duplicate heap pointer
+    mov [r12-8], rax ; store duplicated pointer
+    mov [rbp-48], rax ; store value
+_61_consume_deepcopy_skip_5:
+    leave
+    ret
+
+global _58_consume
+_58_consume:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store v arg in frame
+    mov [rbp-16], rsi ; store total arg in frame
+    mov [rbp-24], rdx ; store consume arg in frame
+    mov [rbp-32], rcx ; store rest arg in frame
+    mov [rbp-40], r8 ; store ok arg in frame
+    mov rdi, 96 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov rax, [rbp-16] ; load operand
+    mov [rbx+0], rax ; capture arg into env
+    mov rax, [rbp-8] ; load operand
+    mov [rbx+8], rax ; capture arg into env
+    mov rax, [rbp-24] ; load operand
+    mov [rbx+16], rax ; capture arg into env
+    mov rax, [rbp-32] ; load operand
+    mov [rbx+24], rax ; capture arg into env
+    mov rax, [rbp-40] ; load operand
+    mov [rbx+32], rax ; capture arg into env
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 48 ; move pointer past env payload
+    mov rax, 48 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 96 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_61_consume_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_61_consume_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_61_consume_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 1 ; store num_remaining
+    mov rax, r12 ; copy _68_consume closure env_end to rax
+    mov [rbp-48], rax ; store value
+    mov rax, [rbp-8] ; load operand
+    push rax ; stack arg
+    lea rax, [rel _59] ; point to string literal
+    push rax ; stack arg
+    mov r13, rsp ; sprintf args in order
+    xor rdi, rdi ; measure only
+    xor rsi, rsi
+    mov r15, rsp ; restore point after snprintf
+    and rsp, -16 ; align stack for variadic call
+    mov rdx, [r13+0] ; snprintf arg 0
+    mov rcx, [r13+8] ; snprintf arg 1
+    mov eax, 0 ; vector registers holding args
+    call snprintf ; invoke libc snprintf
+    mov rsp, r15
+    mov rbx, rax ; formatted length
+    lea rdi, [rbx+17] ; header, bytes and terminator
+    call alloc_heap_ptr ; allocate the string
+    mov r14, rax ; string block
+    lea rax, [rbx+17]
+    mov [r14], rax ; allocation size header
+    mov [r14+8], rbx ; length header
+    lea rdi, [r14+16] ; string bytes follow the header
+    lea rsi, [rbx+1] ; room for the terminator
+    mov r15, rsp ; restore point after snprintf
+    and rsp, -16 ; align stack for variadic call
+    mov rdx, [r13+0] ; snprintf arg 0
+    mov rcx, [r13+8] ; snprintf arg 1
+    mov eax, 0 ; vector registers holding args
+    call snprintf ; invoke libc snprintf
+    mov rsp, r15
+    lea rax, [r14+16] ; return formatted string pointer
+    add rsp, 16 ; pop stack args after libc call
+    mov r12, [rbp-48] ; load continuation env_end pointer
+    mov [r12-8], rax ; store env field
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+global _58_consume_unwrapper
+_58_consume_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-40] ; load v env field
+    mov [rbp-16], rax ; store value
+    mov rax, [r12-32] ; load total env field
+    mov [rbp-24], rax ; store value
+    mov rax, [r12-24] ; load consume env field
+    mov [rbp-32], rax ; store value
+    mov rax, [r12-16] ; load rest env field
+    mov [rbp-40], rax ; store value
+    mov rax, [r12-8] ; load ok env field
+    mov [rbp-48], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    mov rax, [rbp-48] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-40] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-32] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-24] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-16] ; load operand
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    pop rdx ; restore arg into register
+    pop rcx ; restore arg into register
+    pop r8 ; restore arg into register
+    leave ; unwind before named jump
+    jmp _58_consume
+global _58_consume_deep_release
+_58_consume_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load __num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 2 ; operand literal
+    cmp rax, rbx
+    jg _58_consume_release_skip_2
+    mov rax, [r12-24] ; load _58_consume_release_field_2 env field
+    mov [rbp-24], rax ; store value
+    mov rdi, [rbp-24] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _58_consume_release_field_2 release helper
+    call rax ; release _58_consume_release_field_2 and what it holds
+    pop rbx
+    pop r12
+_58_consume_release_skip_2:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 1 ; operand literal
+    cmp rax, rbx
+    jg _58_consume_release_skip_3
+    mov rax, [r12-16] ; load _58_consume_release_field_3 env field
+    mov [rbp-32], rax ; store value
+    mov rdi, [rbp-32] ; load operand
+    call chan_release_ptr ; drop a reference to _58_consume_release_field_3 channel
+_58_consume_release_skip_3:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _58_consume_release_skip_4
+    mov rax, [r12-8] ; load _58_consume_release_field_4 env field
+    mov [rbp-40], rax ; store value
+    mov rdi, [rbp-40] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _58_consume_release_field_4 release helper
+    call rax ; release _58_consume_release_field_4 and what it holds
+    pop rbx
+    pop r12
+_58_consume_release_skip_4:
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _58_consume_deepcopy
+_58_consume_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 2 ; operand literal
+    cmp rax, rbx
+    jg _58_consume_deepcopy_skip_2
+    mov rcx, [r12-24] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call deepcopy_heap_ptr ; duplicate heap pointer
+    mov [r12-24], rax ; store duplicated pointer
+    mov [rbp-24], rax ; store value
+_58_consume_deepcopy_skip_2:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 1 ; operand literal
+    cmp rax, rbx
+    jg _58_consume_deepcopy_skip_3
+    mov rcx, [r12-16] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call chan_copy_ptr ; duplicate heap pointer
+    mov [r12-16], rax ; store duplicated pointer
+    mov [rbp-32], rax ; store value
+_58_consume_deepcopy_skip_3:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _58_consume_deepcopy_skip_4
+    mov rcx, [r12-8] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call deepcopy_heap_ptr ; duplicate heap pointer
+    mov [r12-8], rax ; store duplicated pointer
+    mov [rbp-40], rax ; store value
+_58_consume_deepcopy_skip_4:
+    leave
+    ret
+
+global _52_consume
+_52_consume:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 64 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store ok arg in frame
+    mov [rbp-16], rsi ; store total arg in frame
+    mov [rbp-24], rdx ; store consume arg in frame
+    mov [rbp-32], rcx ; store v arg in frame
+    mov [rbp-40], r8 ; store rest arg in frame
+    mov rbx, [rbp-8] ; original closure ok to __ok_copy_0 env_end pointer for clone
+    mov rbx, rbx ; clone source env_end pointer
+    mov r13, [rbx+24] ; load env size metadata for clone
+    mov r14, [rbx+32] ; load heap size metadata for clone
+    mov r12, rbx ; compute env base pointer for clone
+    sub r12, r13 ; env base pointer for clone source
+    mov rdi, r14 ; length for cloned environment
+    call alloc_heap_ptr ; allocate cloned env block
+    mov r15, rax ; cloned closure env base pointer
+    mov rsi, r12 ; source env base for clone copy
+    mov rdi, r15 ; destination env base for clone copy
+    mov rcx, r14 ; bytes to copy for cloned env
+    cld ; ensure forward copy for env clone
+    rep movsb ; duplicate closure env data
+    mov rbx, r15 ; start from cloned env base
+    add rbx, r13 ; compute cloned env_end pointer
+    mov r12, rbx ; cloned env_end pointer
+    mov rax, [r12+16] ; load deepcopy helper entry point
+    push r12 ; preserve cloned env_end pointer
+    mov rdi, r12 ; pass env_end pointer to deepcopy helper
+    call rax ; deepcopy reference fields
+    pop r12 ; restore cloned env_end pointer
+    mov rax, r12 ; copy cloned env_end pointer
+    mov [rbp-48], rax ; store value
+    mov rdi, 64 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov rax, [rbp-48] ; load operand
+    mov [rbx+0], rax ; capture arg into env
+    mov rax, [rbp-16] ; load operand
+    mov [rbx+8], rax ; capture arg into env
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 16 ; move pointer past env payload
+    mov rax, 16 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 64 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_55_consume_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_55_consume_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_55_consume_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 0 ; store num_remaining
+    mov rax, r12 ; copy _56_consume closure env_end to rax
+    mov [rbp-56], rax ; store value
+    mov rdi, 88 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov rax, [rbp-32] ; load operand
+    mov [rbx+0], rax ; capture arg into env
+    mov rax, [rbp-16] ; load operand
+    mov [rbx+8], rax ; capture arg into env
+    mov rax, [rbp-24] ; load operand
+    mov [rbx+16], rax ; capture arg into env
+    mov rax, [rbp-40] ; load operand
+    mov [rbx+24], rax ; capture arg into env
+    mov rax, [rbp-8] ; load operand
+    mov [rbx+32], rax ; capture arg into env
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 40 ; move pointer past env payload
+    mov rax, 40 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 88 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_58_consume_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_58_consume_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_58_consume_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 0 ; store num_remaining
+    mov rax, r12 ; copy _69_consume closure env_end to rax
+    mov [rbp-64], rax ; store value
+    mov rax, [rbp-32] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    je eqi__56_consume_true_0_0
+eqi__69_consume_false_0_0:
+    mov rdi, [rbp-56] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _56_consume release helper
+    call rax ; release _56_consume and what it holds
+    pop rbx
+    pop r12
+    mov rbx, [rbp-64] ; load _69_consume closure env_end pointer
+    mov rdi, rbx ; pass env_end pointer to closure
+    mov rax, [rdi+0] ; load closure unwrapper entry point
+    leave ; unwind before jumping
+    jmp rax ; tail call into closure
+eqi__56_consume_true_0_0:
+    mov rdi, [rbp-64] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _69_consume release helper
+    call rax ; release _69_consume and what it holds
+    pop rbx
+    pop r12
+    mov rbx, [rbp-56] ; load _56_consume closure env_end pointer
+    mov rdi, rbx ; pass env_end pointer to closure
+    mov rax, [rdi+0] ; load closure unwrapper entry point
+    leave ; unwind before jumping
+    jmp rax ; tail call into closure
+global _52_consume_unwrapper
+_52_consume_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-40] ; load ok env field
+    mov [rbp-16], rax ; store value
+    mov rax, [r12-32] ; load total env field
+    mov [rbp-24], rax ; store value
+    mov rax, [r12-24] ; load consume env field
+    mov [rbp-32], rax ; store value
+    mov rax, [r12-16] ; load v env field
+    mov [rbp-40], rax ; store value
+    mov rax, [r12-8] ; load rest env field
+    mov [rbp-48], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    mov rax, [rbp-48] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-40] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-32] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-24] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-16] ; load operand
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    pop rdx ; restore arg into register
+    pop rcx ; restore arg into register
+    pop r8 ; restore arg into register
+    leave ; unwind before named jump
+    jmp _52_consume
+global _52_consume_deep_release
+_52_consume_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load __num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 4 ; operand literal
+    cmp rax, rbx
+    jg _52_consume_release_skip_0
+    mov rax, [r12-40] ; load _52_consume_release_field_0 env field
+    mov [rbp-24], rax ; store value
+    mov rdi, [rbp-24] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _52_consume_release_field_0 release helper
+    call rax ; release _52_consume_release_field_0 and what it holds
+    pop rbx
+    pop r12
+_52_consume_release_skip_0:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 2 ; operand literal
+    cmp rax, rbx
+    jg _52_consume_release_skip_2
+    mov rax, [r12-24] ; load _52_consume_release_field_2 env field
+    mov [rbp-32], rax ; store value
+    mov rdi, [rbp-32] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _52_consume_release_field_2 release helper
+    call rax ; release _52_consume_release_field_2 and what it holds
+    pop rbx
+    pop r12
+_52_consume_release_skip_2:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _52_consume_release_skip_4
+    mov rax, [r12-8] ; load _52_consume_release_field_4 env field
+    mov [rbp-40], rax ; store value
+    mov rdi, [rbp-40] ; load operand
+    call chan_release_ptr ; drop a reference to _52_consume_release_field_4 channel
+_52_consume_release_skip_4:
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _52_consume_deepcopy
+_52_consume_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 4 ; operand literal
+    cmp rax, rbx
+    jg _52_consume_deepcopy_skip_0
+    mov rcx, [r12-40] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call deepcopy_heap_ptr ; duplicate heap pointer
+    mov [r12-40], rax ; store duplicated pointer
+    mov [rbp-24], rax ; store value
+_52_consume_deepcopy_skip_0:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 2 ; operand literal
+    cmp rax, rbx
+    jg _52_consume_deepcopy_skip_2
+    mov rcx, [r12-24] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call deepcopy_heap_ptr ; duplicate heap pointer
+    mov [r12-24], rax ; store duplicated pointer
+    mov [rbp-32], rax ; store value
+_52_consume_deepcopy_skip_2:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _52_consume_deepcopy_skip_4
+    mov rcx, [r12-8] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call chan_copy_ptr ; duplicate heap pointer
+    mov [r12-8], rax ; store duplicated pointer
+    mov [rbp-40], rax ; store value
+_52_consume_deepcopy_skip_4:
+    leave
+    ret
+
+global consume
+consume:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store c arg in frame
+    mov [rbp-16], rsi ; store total arg in frame
+    mov [rbp-24], rdx ; store ok arg in frame
+    mov rdi, 72 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 24 ; move pointer past env payload
+    mov rax, 24 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 72 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [consume_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [consume_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [consume_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 3 ; store num_remaining
+    mov rax, r12 ; copy consume closure env_end to rax
+    mov [rbp-32], rax ; store value
+    mov rdi, 88 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov rax, [rbp-24] ; load operand
+    mov [rbx+0], rax ; capture arg into env
+    mov rax, [rbp-16] ; load operand
+    mov [rbx+8], rax ; capture arg into env
+    mov rax, [rbp-32] ; load operand
+    mov [rbx+16], rax ; capture arg into env
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 40 ; move pointer past env payload
+    mov rax, 40 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 88 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_52_consume_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_52_consume_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_52_consume_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 2 ; store num_remaining
+    mov rax, r12 ; copy _70_consume closure env_end to rax
+    mov [rbp-40], rax ; store value
+    mov r13, [rbp-8] ; load operand
+    mov rsi, [rbp-40] ; load operand
+    mov rdi, r13
+    call chan_recv_ptr ; take a queued value or park
+    test rdx, rdx ; was a value waiting?
+    jz consume_recv_park_0
+    mov r12, [rbp-40] ; load continuation env_end pointer
+    mov [r12-16], rax ; store env field
+    mov [r12-8], r13 ; store env field
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+consume_recv_park_0:
+    leave ; unwind before switching
+    jmp sched_next_ptr ; run the next coroutine
+global consume_unwrapper
+consume_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-24] ; load c env field
+    mov [rbp-16], rax ; store value
+    mov rax, [r12-16] ; load total env field
+    mov [rbp-24], rax ; store value
+    mov rax, [r12-8] ; load ok env field
+    mov [rbp-32], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    mov rax, [rbp-32] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-24] ; load operand
+    push rax ; stack arg
+    mov rax, [rbp-16] ; load operand
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    pop rdx ; restore arg into register
+    leave ; unwind before named jump
+    jmp consume
+global consume_deep_release
+consume_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load __num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 2 ; operand literal
+    cmp rax, rbx
+    jg consume_release_skip_0
+    mov rax, [r12-24] ; load consume_release_field_0 env field
+    mov [rbp-24], rax ; store value
+    mov rdi, [rbp-24] ; load operand
+    call chan_release_ptr ; drop a reference to consume_release_field_0 channel
+consume_release_skip_0:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg consume_release_skip_2
+    mov rax, [r12-8] ; load consume_release_field_2 env field
+    mov [rbp-32], rax ; store value
+    mov rdi, [rbp-32] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load consume_release_field_2 release helper
+    call rax ; release consume_release_field_2 and what it holds
+    pop rbx
+    pop r12
+consume_release_skip_2:
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global consume_deepcopy
+consume_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 2 ; operand literal
+    cmp rax, rbx
+    jg consume_deepcopy_skip_0
+    mov rcx, [r12-24] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call chan_copy_ptr ; duplicate heap pointer
+    mov [r12-24], rax ; store duplicated pointer
+    mov [rbp-24], rax ; store value
+consume_deepcopy_skip_0:
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg consume_deepcopy_skip_2
+    mov rcx, [r12-8] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call deepcopy_heap_ptr ; duplicate heap pointer
+    mov [r12-8], rax ; store duplicated pointer
+    mov [rbp-32], rax ; store value
+consume_deepcopy_skip_2:
+    leave
+    ret
+
+global _97_main
+_97_main:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store c arg in frame
+    mov rdi, 56 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 8 ; move pointer past env payload
+    mov rax, 8 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 56 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_100_main_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_100_main_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_100_main_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 1 ; store num_remaining
+    mov rax, r12 ; copy _100_main closure env_end to rax
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    push rax ; stack arg
+    mov rax, 0 ; operand literal
+    push rax ; stack arg
+    mov rax, [rbp-8] ; load operand
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    pop rdx ; restore arg into register
+    leave ; unwind before named jump
+    jmp consume
+global _97_main_unwrapper
+_97_main_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-8] ; load c env field
+    mov [rbp-16], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    mov rax, [rbp-16] ; load operand
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    leave ; unwind before named jump
+    jmp _97_main
+global _97_main_deep_release
+_97_main_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load __num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _97_main_release_skip_0
+    mov rax, [r12-8] ; load _97_main_release_field_0 env field
+    mov [rbp-24], rax ; store value
+    mov rdi, [rbp-24] ; load operand
+    call chan_release_ptr ; drop a reference to _97_main_release_field_0 channel
+_97_main_release_skip_0:
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _97_main_deepcopy
+_97_main_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _97_main_deepcopy_skip_0
+    mov rcx, [r12-8] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call chan_copy_ptr ; duplicate heap pointer
+    mov [r12-8], rax ; store duplicated pointer
+    mov [rbp-24], rax ; store value
+_97_main_deepcopy_skip_0:
+    leave
+    ret
+
+global _90_main
+_90_main:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store c arg in frame
+    mov rdi, [rbp-8] ; load operand
+    call chan_copy_ptr ; another reference to c channel
+    mov [rbp-16], rax ; store value
+    mov rdi, 56 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov rax, [rbp-16] ; load operand
+    mov [rbx+0], rax ; capture arg into env
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 8 ; move pointer past env payload
+    mov rax, 8 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 56 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_92_main_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_92_main_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_92_main_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 0 ; store num_remaining
+    mov rax, r12 ; copy _95_main closure env_end to rax
+    mov [rbp-24], rax ; store value
+    mov rdi, 56 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov rax, [rbp-8] ; load operand
+    mov [rbx+0], rax ; capture arg into env
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 8 ; move pointer past env payload
+    mov rax, 8 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 56 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_97_main_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_97_main_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_97_main_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 0 ; store num_remaining
+    mov rax, r12 ; copy _168_main closure env_end to rax
+    mov [rbp-32], rax ; store value
+    mov rdi, [rbp-24] ; load operand
+    call sched_ready_ptr ; run _95_main later
+    mov r12, [rbp-32] ; load continuation env_end pointer
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+global _90_main_unwrapper
+_90_main_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-8] ; load c env field
+    mov [rbp-16], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    mov rax, [rbp-16] ; load operand
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    leave ; unwind before named jump
+    jmp _90_main
+global _90_main_deep_release
+_90_main_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load __num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _90_main_release_skip_0
+    mov rax, [r12-8] ; load _90_main_release_field_0 env field
+    mov [rbp-24], rax ; store value
+    mov rdi, [rbp-24] ; load operand
+    call chan_release_ptr ; drop a reference to _90_main_release_field_0 channel
+_90_main_release_skip_0:
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _90_main_deepcopy
+_90_main_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _90_main_deepcopy_skip_0
+    mov rcx, [r12-8] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call chan_copy_ptr ; duplicate heap pointer
+    mov [r12-8], rax ; store duplicated pointer
+    mov [rbp-24], rax ; store value
+_90_main_deepcopy_skip_0:
+    leave
+    ret
+
+global main
+main:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov rdi, 56 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 8 ; move pointer past env payload
+    mov rax, 8 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 56 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_90_main_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_90_main_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_90_main_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 1 ; store num_remaining
+    mov rax, r12 ; copy _90_main closure env_end to rax
+    mov [rbp-8], rax ; store value
+    mov rdi, 0 ; class of the values
+    call chan_new_ptr ; make the channel
+    mov r12, [rbp-8] ; load continuation env_end pointer
+    mov [r12-8], rax ; store env field
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+global main_unwrapper
+main_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave ; unwind before named jump
+    jmp main
+global main_deep_release
+main_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global main_deepcopy
+main_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    leave
+    ret
+
+global _start
+_start:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    leave ; unwind before named jump
+    jmp main
+extern exit
+extern snprintf
+extern write
+section .rodata
+    dq 0, 8 ; static strings have no allocation to free
+_35:
+    db "send %d", 10, 0
+    dq 0, 6 ; static strings have no allocation to free
+_74:
+    db "%s %d", 10, 0
+    dq 0, 1 ; static strings have no allocation to free
+_108:
+    db "b", 0
+    dq 0, 7 ; static strings have no allocation to free
+_135:
+    db "got %d", 10, 0
+    dq 0, 2 ; static strings have no allocation to free
+_122:
+    db "%s", 0
+    dq 0, 14 ; static strings have no allocation to free
+_123:
+    db "never received", 0
+    dq 0, 1 ; static strings have no allocation to free
+_116:
+    db "a", 0
+    dq 0, 9 ; static strings have no allocation to free
+_101:
+    db "total %d", 10, 0
+    dq 0, 8 ; static strings have no allocation to free
+_59:
+    db "recv %d", 10, 0
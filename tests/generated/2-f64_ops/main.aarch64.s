@@ -0,0 +1,3532 @@
+.text
+.p2align 2
+.global _111_main
+_111_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    // load exit code
+    mov x0, #0 // operand literal
+    bl exit // call libc exit to flush buffers
+.global alloc_heap_ptr
+alloc_heap_ptr:
+    add x0, x0, #7 // round size up to whole words
+    and x0, x0, #-8
+    cmp x0, #1024 // small block?
+    b.hi alloc_heap_ptr_large
+    adrp x9, heap_free_lists
+    add x9, x9, :lo12:heap_free_lists
+    ldr x10, [x9, x0] // head of this size's free list
+    cbz x10, alloc_heap_ptr_bump
+    ldr x11, [x10] // next free block
+    str x11, [x9, x0] // pop the head
+    mov x0, x10
+    ret
+alloc_heap_ptr_bump:
+    adrp x9, heap_arena
+    add x9, x9, :lo12:heap_arena
+    ldp x10, x11, [x9] // arena next and end
+    add x12, x10, x0 // end of the new block
+    cmp x12, x11
+    b.hi alloc_heap_ptr_refill
+    str x12, [x9] // bump past the block
+    mov x0, x10
+    ret
+alloc_heap_ptr_refill:
+    mov x13, x0 // keep block size across the syscall
+    mov x1, #1048576 // arena size
+    mov x0, #0 // addr hint so kernel picks mmap base
+    mov x2, #3 // prot = read/write
+    mov x3, #34 // flags = private & anonymous
+    mov x4, #-1 // fd = -1
+    mov x5, #0 // offset = 0
+    mov x8, #222 // mmap syscall
+    svc #0 // map a new arena
+    cmn x0, #4095 // mmap failed?
+    b.hs alloc_heap_ptr_failed
+    add x12, x0, x13 // block is the start of the arena
+    mov x11, #1048576
+    add x11, x0, x11 // end of the arena
+    stp x12, x11, [x9]
+    ret
+alloc_heap_ptr_large:
+    mov x1, x0 // length = block size
+    mov x0, #0 // addr hint so kernel picks mmap base
+    mov x2, #3 // prot = read/write
+    mov x3, #34 // flags = private & anonymous
+    mov x4, #-1 // fd = -1
+    mov x5, #0 // offset = 0
+    mov x8, #222 // mmap syscall
+    svc #0 // map a large block on its own
+    cmn x0, #4095 // mmap failed?
+    b.hs alloc_heap_ptr_failed
+    ret
+alloc_heap_ptr_failed:
+    mov x0, #0 // no memory left
+    ret
+.data
+.p2align 3
+heap_free_lists:
+    .zero 1032
+heap_arena:
+    .quad 0, 0
+.text
+.global free_heap_ptr
+free_heap_ptr:
+    add x1, x1, #7 // round size up to whole words
+    and x1, x1, #-8
+    cmp x1, #1024 // small block?
+    b.hi free_heap_ptr_large
+    adrp x9, heap_free_lists
+    add x9, x9, :lo12:heap_free_lists
+    ldr x10, [x9, x1] // current head
+    str x10, [x0] // link it behind the block
+    str x0, [x9, x1] // block is the new head
+    ret
+free_heap_ptr_large:
+    mov x8, #215 // munmap syscall
+    svc #0
+    ret
+.global release_heap_ptr
+release_heap_ptr:
+    stp x29, x30, [sp, #-16]! // save caller frame
+    mov x29, sp // establish frame
+    ldr x11, [x0, #24] // load env size metadata
+    ldr x12, [x0, #32] // load heap size metadata
+    sub x0, x0, x11 // compute env base pointer
+    mov x1, x12 // heap size to free
+    bl free_heap_ptr // return env to the allocator
+    ldp x29, x30, [sp], #16
+    ret
+.global _111_main_unwrapper
+_111_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _111_main
+.global _111_main_deep_release
+_111_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _111_main_deepcopy
+_111_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global release_str_ptr
+release_str_ptr:
+    cbz x0, release_str_ptr_done // nothing to free
+    ldr x1, [x0, #-16] // allocation size header
+    cbz x1, release_str_ptr_done // literals have no allocation
+    sub x0, x0, #16 // block starts at the header
+    b free_heap_ptr // return string to the allocator
+release_str_ptr_done:
+    ret
+.global _97_main
+_97_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store s arg in frame
+    mov x0, #48 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x9, #0 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #48 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _111_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_111_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _111_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_111_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _111_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_111_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _111_main closure env_end to x9
+    stur x9, [x29, #-16] // store value
+    sub sp, sp, #16 // reserve stack args
+    movz x9, #0x0 // load literal float bits
+    movk x9, #0x4021, lsl #48
+    str x9, [sp, #0] // stack arg
+    movz x9, #0x0 // load literal float bits
+    movk x9, #0x4023, lsl #48
+    str x9, [sp, #8] // stack arg
+    adrp x0, _98 // point to string literal
+    add x0, x0, :lo12:_98
+    ldur x1, [x29, #-8] // load operand
+    movz x9, #0x0 // load literal float bits
+    movk x9, #0x3fe0, lsl #48
+    fmov d0, x9 // load float literal
+    movz x9, #0x0 // load literal float bits
+    movk x9, #0x3ff8, lsl #48
+    fmov d1, x9 // load float literal
+    movz x9, #0x0 // load literal float bits
+    movk x9, #0x4004, lsl #48
+    fmov d2, x9 // load float literal
+    movz x9, #0x0 // load literal float bits
+    movk x9, #0x400c, lsl #48
+    fmov d3, x9 // load float literal
+    movz x9, #0x0 // load literal float bits
+    movk x9, #0x4012, lsl #48
+    fmov d4, x9 // load float literal
+    movz x9, #0x0 // load literal float bits
+    movk x9, #0x4016, lsl #48
+    fmov d5, x9 // load float literal
+    movz x9, #0x0 // load literal float bits
+    movk x9, #0x401a, lsl #48
+    fmov d6, x9 // load float literal
+    movz x9, #0x0 // load literal float bits
+    movk x9, #0x401e, lsl #48
+    fmov d7, x9 // load float literal
+    mov x2, #10 // operand literal
+    bl printf // invoke libc printf
+    add sp, sp, #16 // pop stack args after libc call
+    ldur x0, [x29, #-8] // load operand
+    bl release_str_ptr // release s string
+    ldur x19, [x29, #-16] // load continuation env_end pointer
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _97_main_unwrapper
+_97_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-8] // load s env field
+    stur x9, [x29, #-16] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _97_main
+.global _97_main_deep_release
+_97_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _97_main_release_skip_0
+    ldur x9, [x19, #-8] // load _97_main_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl release_str_ptr // release _97_main_release_field_0 string
+_97_main_release_skip_0:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global copy_str_ptr
+copy_str_ptr:
+    cbz x0, copy_str_ptr_done
+    ldr x1, [x0, #-16] // allocation size header
+    cbz x1, copy_str_ptr_done // literals are never freed
+    stp x29, x30, [sp, #-16]! // save caller frame
+    mov x29, sp // establish frame
+    stp x20, x21, [sp, #-16]! // preserve callee-saved registers
+    sub x20, x0, #16 // source block, header included
+    mov x21, x1 // allocation size
+    mov x0, x1
+    bl alloc_heap_ptr // allocate the copy
+    mov x1, x20 // memcpy src
+    mov x2, x21 // memcpy length
+    mov x20, x0 // copy block
+    bl memcpy_helper // duplicate header and bytes
+    add x0, x20, #16 // copied string starts after its header
+    ldp x20, x21, [sp], #16
+    ldp x29, x30, [sp], #16
+copy_str_ptr_done:
+    ret
+.global memcpy_helper
+memcpy_helper:
+    mov x11, #0 // counter = 0
+internal_memcpy_loop:
+    cmp x11, x2 // counter < count?
+    b.ge internal_memcpy_done
+    ldr x9, [x1, x11] // load 8 bytes from source
+    str x9, [x0, x11] // store 8 bytes to destination
+    add x11, x11, #8 // advance counter by 8
+    b internal_memcpy_loop
+internal_memcpy_done:
+    ret
+.global _97_main_deepcopy
+_97_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _97_main_deepcopy_skip_0
+    ldur x0, [x19, #-8] // load field pointer
+    bl copy_str_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_97_main_deepcopy_skip_0:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _84_main
+_84_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    mov x0, #56 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #8 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #8 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #56 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _97_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_97_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _97_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_97_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _97_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_97_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _97_main closure env_end to x9
+    stur x9, [x29, #-8] // store value
+    adrp x2, _85 // point to string literal
+    add x2, x2, :lo12:_85
+    mov x3, #1 // operand literal
+    movz x9, #0x0 // load literal float bits
+    movk x9, #0x3ff8, lsl #48
+    fmov d0, x9 // load float literal
+    mov x4, #2 // operand literal
+    movz x9, #0x0 // load literal float bits
+    movk x9, #0x4004, lsl #48
+    fmov d1, x9 // load float literal
+    mov x5, #3 // operand literal
+    movz x9, #0x0 // load literal float bits
+    movk x9, #0x400c, lsl #48
+    fmov d2, x9 // load float literal
+    mov x6, #4 // operand literal
+    movz x9, #0x0 // load literal float bits
+    movk x9, #0x4012, lsl #48
+    fmov d3, x9 // load float literal
+    mov x7, #5 // operand literal
+    movz x9, #0x0 // load literal float bits
+    movk x9, #0x4016, lsl #48
+    fmov d4, x9 // load float literal
+    mov x0, #0 // measure only
+    mov x1, #0
+    bl snprintf // invoke libc snprintf
+    mov x20, x0 // formatted length
+    add x0, x20, #17 // header, bytes and terminator
+    bl alloc_heap_ptr // allocate the string
+    mov x21, x0 // string block
+    add x9, x20, #17
+    str x9, [x21] // allocation size header
+    str x20, [x21, #8] // length header
+    adrp x2, _85 // point to string literal
+    add x2, x2, :lo12:_85
+    mov x3, #1 // operand literal
+    movz x9, #0x0 // load literal float bits
+    movk x9, #0x3ff8, lsl #48
+    fmov d0, x9 // load float literal
+    mov x4, #2 // operand literal
+    movz x9, #0x0 // load literal float bits
+    movk x9, #0x4004, lsl #48
+    fmov d1, x9 // load float literal
+    mov x5, #3 // operand literal
+    movz x9, #0x0 // load literal float bits
+    movk x9, #0x400c, lsl #48
+    fmov d2, x9 // load float literal
+    mov x6, #4 // operand literal
+    movz x9, #0x0 // load literal float bits
+    movk x9, #0x4012, lsl #48
+    fmov d3, x9 // load float literal
+    mov x7, #5 // operand literal
+    movz x9, #0x0 // load literal float bits
+    movk x9, #0x4016, lsl #48
+    fmov d4, x9 // load float literal
+    add x0, x21, #16 // string bytes follow the header
+    add x1, x20, #1 // room for the terminator
+    bl snprintf // invoke libc snprintf
+    add x9, x21, #16 // return formatted string pointer
+    ldur x19, [x29, #-8] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _84_main_unwrapper
+_84_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _84_main
+.global _84_main_deep_release
+_84_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _84_main_deepcopy
+_84_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _81_main
+_81_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    mov x0, #48 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x9, #0 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #48 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _84_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_84_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _84_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_84_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _84_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_84_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _84_main closure env_end to x9
+    stur x9, [x29, #-8] // store value
+    adrp x0, _82 // point to string literal
+    add x0, x0, :lo12:_82
+    bl printf // invoke libc printf
+    ldur x19, [x29, #-8] // load continuation env_end pointer
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _81_main_unwrapper
+_81_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _81_main
+.global _81_main_deep_release
+_81_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _81_main_deepcopy
+_81_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _121_main
+_121_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    // load exit code
+    mov x0, #1 // operand literal
+    bl exit // call libc exit to flush buffers
+.global _121_main_unwrapper
+_121_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _121_main
+.global _121_main_deep_release
+_121_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _121_main_deepcopy
+_121_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _118_main
+_118_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store u arg in frame
+    mov x0, #48 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x9, #0 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #48 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _121_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_121_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _121_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_121_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _121_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_121_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _121_main closure env_end to x9
+    stur x9, [x29, #-16] // store value
+    adrp x0, _119 // point to string literal
+    add x0, x0, :lo12:_119
+    ldur x1, [x29, #-8] // load operand
+    bl printf // invoke libc printf
+    ldur x19, [x29, #-16] // load continuation env_end pointer
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _118_main_unwrapper
+_118_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-8] // load u env field
+    stur x9, [x29, #-16] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _118_main
+.global _118_main_deep_release
+_118_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _118_main_deepcopy
+_118_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _79_main
+_79_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store huge arg in frame
+    mov x0, #48 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x9, #0 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #48 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _81_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_81_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _81_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_81_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _81_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_81_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _81_main closure env_end to x9
+    stur x9, [x29, #-16] // store value
+    mov x0, #56 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #8 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #8 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #56 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _118_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_118_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _118_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_118_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _118_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_118_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _118_main closure env_end to x9
+    stur x9, [x29, #-24] // store value
+    ldur d0, [x29, #-8] // load float operand
+    movz x10, #0x0 // smallest int as a float
+    movk x10, #0xc3e0, lsl #48
+    fmov d1, x10
+    fcmp d0, d1
+    b.lt _79_main_ftoi_err_0 // below the range, or NaN
+    movz x10, #0x0 // first float past the range
+    movk x10, #0x43e0, lsl #48
+    fmov d1, x10
+    fcmp d0, d1
+    b.ge _79_main_ftoi_err_0
+    fcvtzs x9, d0 // truncate toward zero
+    mov x22, x9 // keep result across releases
+    ldur x0, [x29, #-16] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _81_main release helper
+    blr x10 // release _81_main and what it holds
+    ldp x19, x22, [sp], #16
+    mov x9, x22 // restore result
+    ldur x19, [x29, #-24] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+_79_main_ftoi_err_0:
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _118_main release helper
+    blr x10 // release _118_main and what it holds
+    ldp x19, x22, [sp], #16
+    ldur x19, [x29, #-16] // load continuation env_end pointer
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _79_main_unwrapper
+_79_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-8] // load huge env field
+    stur x9, [x29, #-16] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _79_main
+.global _79_main_deep_release
+_79_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _79_main_deepcopy
+_79_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _76_main
+_76_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store huge arg in frame
+    mov x0, #56 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #0] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #8 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #8 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #56 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _79_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_79_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _79_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_79_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _79_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_79_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _125_main closure env_end to x9
+    stur x9, [x29, #-16] // store value
+    adrp x0, _77 // point to string literal
+    add x0, x0, :lo12:_77
+    bl printf // invoke libc printf
+    ldur x19, [x29, #-16] // load continuation env_end pointer
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _76_main_unwrapper
+_76_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-8] // load huge env field
+    stur x9, [x29, #-16] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _76_main
+.global _76_main_deep_release
+_76_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _76_main_deepcopy
+_76_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _131_main
+_131_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    // load exit code
+    mov x0, #1 // operand literal
+    bl exit // call libc exit to flush buffers
+.global _131_main_unwrapper
+_131_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _131_main
+.global _131_main_deep_release
+_131_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _131_main_deepcopy
+_131_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _128_main
+_128_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store u arg in frame
+    mov x0, #48 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x9, #0 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #48 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _131_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_131_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _131_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_131_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _131_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_131_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _131_main closure env_end to x9
+    stur x9, [x29, #-16] // store value
+    adrp x0, _129 // point to string literal
+    add x0, x0, :lo12:_129
+    ldur x1, [x29, #-8] // load operand
+    bl printf // invoke libc printf
+    ldur x19, [x29, #-16] // load continuation env_end pointer
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _128_main_unwrapper
+_128_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-8] // load u env field
+    stur x9, [x29, #-16] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _128_main
+.global _128_main_deep_release
+_128_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _128_main_deepcopy
+_128_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _74_main
+_74_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store nan arg in frame
+    stur x1, [x29, #-16] // store huge arg in frame
+    mov x0, #56 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #0] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #8 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #8 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #56 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _76_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_76_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _76_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_76_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _76_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_76_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _126_main closure env_end to x9
+    stur x9, [x29, #-24] // store value
+    mov x0, #56 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #8 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #8 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #56 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _128_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_128_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _128_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_128_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _128_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_128_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _128_main closure env_end to x9
+    stur x9, [x29, #-32] // store value
+    ldur d0, [x29, #-8] // load float operand
+    movz x10, #0x0 // smallest int as a float
+    movk x10, #0xc3e0, lsl #48
+    fmov d1, x10
+    fcmp d0, d1
+    b.lt _74_main_ftoi_err_0 // below the range, or NaN
+    movz x10, #0x0 // first float past the range
+    movk x10, #0x43e0, lsl #48
+    fmov d1, x10
+    fcmp d0, d1
+    b.ge _74_main_ftoi_err_0
+    fcvtzs x9, d0 // truncate toward zero
+    mov x22, x9 // keep result across releases
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _126_main release helper
+    blr x10 // release _126_main and what it holds
+    ldp x19, x22, [sp], #16
+    mov x9, x22 // restore result
+    ldur x19, [x29, #-32] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+_74_main_ftoi_err_0:
+    ldur x0, [x29, #-32] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _128_main release helper
+    blr x10 // release _128_main and what it holds
+    ldp x19, x22, [sp], #16
+    ldur x19, [x29, #-24] // load continuation env_end pointer
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _74_main_unwrapper
+_74_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-16] // load nan env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-8] // load huge env field
+    stur x9, [x29, #-24] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _74_main
+.global _74_main_deep_release
+_74_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _74_main_deepcopy
+_74_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _71_main
+_71_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store diff arg in frame
+    stur x1, [x29, #-16] // store nan arg in frame
+    stur x2, [x29, #-24] // store huge arg in frame
+    stur x3, [x29, #-32] // store t arg in frame
+    mov x0, #64 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #8] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #16 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #16 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #64 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _74_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_74_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _74_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_74_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _74_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_74_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _135_main closure env_end to x9
+    stur x9, [x29, #-40] // store value
+    adrp x0, _72 // point to string literal
+    add x0, x0, :lo12:_72
+    ldur d0, [x29, #-8] // load float operand
+    ldur x1, [x29, #-32] // load operand
+    bl printf // invoke libc printf
+    ldur x19, [x29, #-40] // load continuation env_end pointer
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _71_main_unwrapper
+_71_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-32] // load diff env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-24] // load nan env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-16] // load huge env field
+    stur x9, [x29, #-32] // store value
+    ldur x9, [x19, #-8] // load t env field
+    stur x9, [x29, #-40] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    ldur x3, [x29, #-40] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _71_main
+.global _71_main_deep_release
+_71_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _71_main_deepcopy
+_71_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _66_main
+_66_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    // load exit code
+    mov x0, #1 // operand literal
+    bl exit // call libc exit to flush buffers
+.global _66_main_unwrapper
+_66_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _66_main
+.global _66_main_deep_release
+_66_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _66_main_deepcopy
+_66_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _63_main
+_63_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    mov x0, #48 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x9, #0 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #48 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _66_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_66_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _66_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_66_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _66_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_66_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _66_main closure env_end to x9
+    stur x9, [x29, #-8] // store value
+    adrp x0, _64 // point to string literal
+    add x0, x0, :lo12:_64
+    bl printf // invoke libc printf
+    ldur x19, [x29, #-8] // load continuation env_end pointer
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _63_main_unwrapper
+_63_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _63_main
+.global _63_main_deep_release
+_63_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _63_main_deepcopy
+_63_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _61_main
+_61_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store diff arg in frame
+    stur x1, [x29, #-16] // store nan arg in frame
+    stur x2, [x29, #-24] // store huge arg in frame
+    mov x0, #80 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #16] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #32 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #32 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #80 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _71_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_71_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _71_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_71_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _71_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_71_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _136_main closure env_end to x9
+    stur x9, [x29, #-32] // store value
+    mov x0, #48 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x9, #0 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #48 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _63_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_63_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _63_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_63_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _63_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_63_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _63_main closure env_end to x9
+    stur x9, [x29, #-40] // store value
+    ldur d0, [x29, #-8] // load float operand
+    movz x10, #0x0 // smallest int as a float
+    movk x10, #0xc3e0, lsl #48
+    fmov d1, x10
+    fcmp d0, d1
+    b.lt _61_main_ftoi_err_0 // below the range, or NaN
+    movz x10, #0x0 // first float past the range
+    movk x10, #0x43e0, lsl #48
+    fmov d1, x10
+    fcmp d0, d1
+    b.ge _61_main_ftoi_err_0
+    fcvtzs x9, d0 // truncate toward zero
+    mov x22, x9 // keep result across releases
+    ldur x0, [x29, #-40] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _63_main release helper
+    blr x10 // release _63_main and what it holds
+    ldp x19, x22, [sp], #16
+    mov x9, x22 // restore result
+    ldur x19, [x29, #-32] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+_61_main_ftoi_err_0:
+    ldur x0, [x29, #-32] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _136_main release helper
+    blr x10 // release _136_main and what it holds
+    ldp x19, x22, [sp], #16
+    ldur x19, [x29, #-40] // load continuation env_end pointer
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _61_main_unwrapper
+_61_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-24] // load diff env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-16] // load nan env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-8] // load huge env field
+    stur x9, [x29, #-32] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _61_main
+.global _61_main_deep_release
+_61_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _61_main_deepcopy
+_61_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _1_describe
+_1_describe:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store x arg in frame
+    stur x1, [x29, #-16] // store y arg in frame
+    stur x2, [x29, #-24] // store next arg in frame
+    adrp x0, _2 // point to string literal
+    add x0, x0, :lo12:_2
+    ldur d0, [x29, #-8] // load float operand
+    ldur d1, [x29, #-16] // load float operand
+    bl printf // invoke libc printf
+    ldur x19, [x29, #-24] // load continuation env_end pointer
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _1_describe_unwrapper
+_1_describe_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-24] // load x env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-16] // load y env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-8] // load next env field
+    stur x9, [x29, #-32] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _1_describe
+.global _1_describe_deep_release
+_1_describe_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _1_describe_release_skip_2
+    ldur x9, [x19, #-8] // load _1_describe_release_field_2 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _1_describe_release_field_2 release helper
+    blr x10 // release _1_describe_release_field_2 and what it holds
+    ldp x19, x22, [sp], #16
+_1_describe_release_skip_2:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global deepcopy_heap_ptr
+deepcopy_heap_ptr:
+    stp x29, x30, [sp, #-16]! // prologue: save executor frame pointer
+    mov x29, sp // prologue: establish new frame
+    stp x19, x20, [sp, #-16]! // preserve callee-saved registers
+    stp x21, x22, [sp, #-16]!
+    str x23, [sp, #-16]!
+    mov x19, x0 // capture env_end pointer
+    ldr x21, [x19, #24] // load env size metadata
+    ldr x23, [x19, #32] // load heap size metadata
+    sub x20, x19, x21 // compute env base pointer
+    mov x0, x23 // length = heap size
+    bl alloc_heap_ptr // allocate new closure env
+    mov x22, x0 // new env base pointer
+    mov x1, x20 // memcpy src
+    mov x2, x23 // memcpy length
+    bl memcpy_helper // copy env contents
+    add x23, x22, x21 // new env_end pointer
+    ldr x9, [x23, #16] // load deep copy helper entry
+    mov x0, x23 // pass new env_end pointer
+    blr x9 // invoke helper
+    mov x0, x23 // return new env_end pointer
+    ldr x23, [sp], #16
+    ldp x21, x22, [sp], #16
+    ldp x19, x20, [sp], #16
+    ldp x29, x30, [sp], #16
+    ret
+.global _1_describe_deepcopy
+_1_describe_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _1_describe_deepcopy_skip_2
+    ldur x0, [x19, #-8] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_1_describe_deepcopy_skip_2:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _7_describe
+_7_describe:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store x arg in frame
+    stur x1, [x29, #-16] // store y arg in frame
+    stur x2, [x29, #-24] // store next arg in frame
+    adrp x0, _8 // point to string literal
+    add x0, x0, :lo12:_8
+    ldur d0, [x29, #-8] // load float operand
+    ldur d1, [x29, #-16] // load float operand
+    bl printf // invoke libc printf
+    ldur x19, [x29, #-24] // load continuation env_end pointer
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _7_describe_unwrapper
+_7_describe_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-24] // load x env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-16] // load y env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-8] // load next env field
+    stur x9, [x29, #-32] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _7_describe
+.global _7_describe_deep_release
+_7_describe_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _7_describe_release_skip_2
+    ldur x9, [x19, #-8] // load _7_describe_release_field_2 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _7_describe_release_field_2 release helper
+    blr x10 // release _7_describe_release_field_2 and what it holds
+    ldp x19, x22, [sp], #16
+_7_describe_release_skip_2:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _7_describe_deepcopy
+_7_describe_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _7_describe_deepcopy_skip_2
+    ldur x0, [x19, #-8] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_7_describe_deepcopy_skip_2:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _13_describe
+_13_describe:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store x arg in frame
+    stur x1, [x29, #-16] // store y arg in frame
+    stur x2, [x29, #-24] // store next arg in frame
+    adrp x0, _14 // point to string literal
+    add x0, x0, :lo12:_14
+    ldur d0, [x29, #-8] // load float operand
+    ldur d1, [x29, #-16] // load float operand
+    bl printf // invoke libc printf
+    ldur x19, [x29, #-24] // load continuation env_end pointer
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _13_describe_unwrapper
+_13_describe_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-24] // load x env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-16] // load y env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-8] // load next env field
+    stur x9, [x29, #-32] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _13_describe
+.global _13_describe_deep_release
+_13_describe_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _13_describe_release_skip_2
+    ldur x9, [x19, #-8] // load _13_describe_release_field_2 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _13_describe_release_field_2 release helper
+    blr x10 // release _13_describe_release_field_2 and what it holds
+    ldp x19, x22, [sp], #16
+_13_describe_release_skip_2:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _13_describe_deepcopy
+_13_describe_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _13_describe_deepcopy_skip_2
+    ldur x0, [x19, #-8] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_13_describe_deepcopy_skip_2:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _17_describe
+_17_describe:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store y arg in frame
+    stur x1, [x29, #-16] // store next arg in frame
+    adrp x0, _18 // point to string literal
+    add x0, x0, :lo12:_18
+    ldur d0, [x29, #-8] // load float operand
+    bl printf // invoke libc printf
+    ldur x19, [x29, #-16] // load continuation env_end pointer
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _17_describe_unwrapper
+_17_describe_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-16] // load y env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-8] // load next env field
+    stur x9, [x29, #-24] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _17_describe
+.global _17_describe_deep_release
+_17_describe_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _17_describe_release_skip_1
+    ldur x9, [x19, #-8] // load _17_describe_release_field_1 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _17_describe_release_field_1 release helper
+    blr x10 // release _17_describe_release_field_1 and what it holds
+    ldp x19, x22, [sp], #16
+_17_describe_release_skip_1:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _17_describe_deepcopy
+_17_describe_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _17_describe_deepcopy_skip_1
+    ldur x0, [x19, #-8] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_17_describe_deepcopy_skip_1:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _11_describe
+_11_describe:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store x arg in frame
+    stur x1, [x29, #-16] // store y arg in frame
+    stur x2, [x29, #-24] // store next arg in frame
+    ldur x20, [x29, #-24] // original closure next to __next_copy_0 env_end pointer for clone
+    mov x20, x20 // clone source env_end pointer
+    ldr x21, [x20, #24] // load env size metadata for clone
+    ldr x22, [x20, #32] // load heap size metadata for clone
+    sub x19, x20, x21 // env base pointer for clone source
+    mov x0, x22 // length for cloned environment
+    bl alloc_heap_ptr // allocate cloned env block
+    mov x23, x0 // cloned closure env base pointer
+    mov x11, #0 // bytes copied
+_11_describe_clone_copy_loop_0:
+    cmp x11, x22
+    b.ge _11_describe_clone_copy_done_1
+    ldrb w12, [x19, x11] // duplicate closure env data
+    strb w12, [x23, x11]
+    add x11, x11, #1
+    b _11_describe_clone_copy_loop_0
+_11_describe_clone_copy_done_1:
+    add x20, x23, x21 // compute cloned env_end pointer
+    mov x19, x20 // cloned env_end pointer
+    ldr x9, [x19, #16] // load deepcopy helper entry point
+    str x19, [sp, #-16]! // preserve cloned env_end pointer
+    mov x0, x19 // pass env_end pointer to deepcopy helper
+    blr x9 // deepcopy reference fields
+    ldr x19, [sp], #16 // restore cloned env_end pointer
+    mov x9, x19 // copy cloned env_end pointer
+    stur x9, [x29, #-32] // store value
+    mov x0, #72 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-32] // load operand
+    str x9, [x20, #16] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #24 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #24 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #72 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _13_describe_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_13_describe_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _13_describe_deep_release // load release helper entry point
+    add x9, x9, :lo12:_13_describe_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _13_describe_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_13_describe_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _15_describe closure env_end to x9
+    stur x9, [x29, #-40] // store value
+    mov x0, #64 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #8] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #16 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #16 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #64 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _17_describe_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_17_describe_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _17_describe_deep_release // load release helper entry point
+    add x9, x9, :lo12:_17_describe_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _17_describe_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_17_describe_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _19_describe closure env_end to x9
+    stur x9, [x29, #-48] // store value
+    ldur d0, [x29, #-8] // load float operand
+    ldur d1, [x29, #-16] // load float operand
+    fcmp d0, d1
+    b.eq eqf64__15_describe_true_0_0
+eqf64__19_describe_false_0_0:
+    ldur x0, [x29, #-40] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _15_describe release helper
+    blr x10 // release _15_describe and what it holds
+    ldp x19, x22, [sp], #16
+    ldur x20, [x29, #-48] // load _19_describe closure env_end pointer
+    mov x0, x20 // pass env_end pointer to closure
+    ldr x9, [x0, #0] // load closure unwrapper entry point
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9 // tail call into closure
+eqf64__15_describe_true_0_0:
+    ldur x0, [x29, #-48] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _19_describe release helper
+    blr x10 // release _19_describe and what it holds
+    ldp x19, x22, [sp], #16
+    ldur x20, [x29, #-40] // load _15_describe closure env_end pointer
+    mov x0, x20 // pass env_end pointer to closure
+    ldr x9, [x0, #0] // load closure unwrapper entry point
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9 // tail call into closure
+.global _11_describe_unwrapper
+_11_describe_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-24] // load x env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-16] // load y env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-8] // load next env field
+    stur x9, [x29, #-32] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _11_describe
+.global _11_describe_deep_release
+_11_describe_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _11_describe_release_skip_2
+    ldur x9, [x19, #-8] // load _11_describe_release_field_2 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _11_describe_release_field_2 release helper
+    blr x10 // release _11_describe_release_field_2 and what it holds
+    ldp x19, x22, [sp], #16
+_11_describe_release_skip_2:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _11_describe_deepcopy
+_11_describe_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _11_describe_deepcopy_skip_2
+    ldur x0, [x19, #-8] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_11_describe_deepcopy_skip_2:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _5_describe
+_5_describe:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store x arg in frame
+    stur x1, [x29, #-16] // store y arg in frame
+    stur x2, [x29, #-24] // store next arg in frame
+    ldur x20, [x29, #-24] // original closure next to __next_copy_0 env_end pointer for clone
+    mov x20, x20 // clone source env_end pointer
+    ldr x21, [x20, #24] // load env size metadata for clone
+    ldr x22, [x20, #32] // load heap size metadata for clone
+    sub x19, x20, x21 // env base pointer for clone source
+    mov x0, x22 // length for cloned environment
+    bl alloc_heap_ptr // allocate cloned env block
+    mov x23, x0 // cloned closure env base pointer
+    mov x11, #0 // bytes copied
+_5_describe_clone_copy_loop_0:
+    cmp x11, x22
+    b.ge _5_describe_clone_copy_done_1
+    ldrb w12, [x19, x11] // duplicate closure env data
+    strb w12, [x23, x11]
+    add x11, x11, #1
+    b _5_describe_clone_copy_loop_0
+_5_describe_clone_copy_done_1:
+    add x20, x23, x21 // compute cloned env_end pointer
+    mov x19, x20 // cloned env_end pointer
+    ldr x9, [x19, #16] // load deepcopy helper entry point
+    str x19, [sp, #-16]! // preserve cloned env_end pointer
+    mov x0, x19 // pass env_end pointer to deepcopy helper
+    blr x9 // deepcopy reference fields
+    ldr x19, [sp], #16 // restore cloned env_end pointer
+    mov x9, x19 // copy cloned env_end pointer
+    stur x9, [x29, #-32] // store value
+    mov x0, #72 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-32] // load operand
+    str x9, [x20, #16] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #24 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #24 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #72 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _7_describe_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_7_describe_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _7_describe_deep_release // load release helper entry point
+    add x9, x9, :lo12:_7_describe_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _7_describe_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_7_describe_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _9_describe closure env_end to x9
+    stur x9, [x29, #-40] // store value
+    mov x0, #72 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #16] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #24 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #24 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #72 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _11_describe_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_11_describe_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _11_describe_deep_release // load release helper entry point
+    add x9, x9, :lo12:_11_describe_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _11_describe_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_11_describe_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _20_describe closure env_end to x9
+    stur x9, [x29, #-48] // store value
+    ldur d0, [x29, #-8] // load float operand
+    ldur d1, [x29, #-16] // load float operand
+    fcmp d0, d1
+    b.gt gtf64__9_describe_true_0_0
+gtf64__20_describe_false_0_0:
+    ldur x0, [x29, #-40] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _9_describe release helper
+    blr x10 // release _9_describe and what it holds
+    ldp x19, x22, [sp], #16
+    ldur x20, [x29, #-48] // load _20_describe closure env_end pointer
+    mov x0, x20 // pass env_end pointer to closure
+    ldr x9, [x0, #0] // load closure unwrapper entry point
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9 // tail call into closure
+gtf64__9_describe_true_0_0:
+    ldur x0, [x29, #-48] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _20_describe release helper
+    blr x10 // release _20_describe and what it holds
+    ldp x19, x22, [sp], #16
+    ldur x20, [x29, #-40] // load _9_describe closure env_end pointer
+    mov x0, x20 // pass env_end pointer to closure
+    ldr x9, [x0, #0] // load closure unwrapper entry point
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9 // tail call into closure
+.global _5_describe_unwrapper
+_5_describe_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-24] // load x env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-16] // load y env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-8] // load next env field
+    stur x9, [x29, #-32] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _5_describe
+.global _5_describe_deep_release
+_5_describe_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _5_describe_release_skip_2
+    ldur x9, [x19, #-8] // load _5_describe_release_field_2 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _5_describe_release_field_2 release helper
+    blr x10 // release _5_describe_release_field_2 and what it holds
+    ldp x19, x22, [sp], #16
+_5_describe_release_skip_2:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _5_describe_deepcopy
+_5_describe_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _5_describe_deepcopy_skip_2
+    ldur x0, [x19, #-8] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_5_describe_deepcopy_skip_2:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global describe
+describe:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store x arg in frame
+    stur x1, [x29, #-16] // store y arg in frame
+    stur x2, [x29, #-24] // store next arg in frame
+    ldur x20, [x29, #-24] // original closure next to __next_copy_0 env_end pointer for clone
+    mov x20, x20 // clone source env_end pointer
+    ldr x21, [x20, #24] // load env size metadata for clone
+    ldr x22, [x20, #32] // load heap size metadata for clone
+    sub x19, x20, x21 // env base pointer for clone source
+    mov x0, x22 // length for cloned environment
+    bl alloc_heap_ptr // allocate cloned env block
+    mov x23, x0 // cloned closure env base pointer
+    mov x11, #0 // bytes copied
+describe_clone_copy_loop_0:
+    cmp x11, x22
+    b.ge describe_clone_copy_done_1
+    ldrb w12, [x19, x11] // duplicate closure env data
+    strb w12, [x23, x11]
+    add x11, x11, #1
+    b describe_clone_copy_loop_0
+describe_clone_copy_done_1:
+    add x20, x23, x21 // compute cloned env_end pointer
+    mov x19, x20 // cloned env_end pointer
+    ldr x9, [x19, #16] // load deepcopy helper entry point
+    str x19, [sp, #-16]! // preserve cloned env_end pointer
+    mov x0, x19 // pass env_end pointer to deepcopy helper
+    blr x9 // deepcopy reference fields
+    ldr x19, [sp], #16 // restore cloned env_end pointer
+    mov x9, x19 // copy cloned env_end pointer
+    stur x9, [x29, #-32] // store value
+    mov x0, #72 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-32] // load operand
+    str x9, [x20, #16] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #24 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #24 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #72 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _1_describe_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_1_describe_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _1_describe_deep_release // load release helper entry point
+    add x9, x9, :lo12:_1_describe_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _1_describe_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_1_describe_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _3_describe closure env_end to x9
+    stur x9, [x29, #-40] // store value
+    mov x0, #72 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #16] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #24 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #24 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #72 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _5_describe_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_5_describe_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _5_describe_deep_release // load release helper entry point
+    add x9, x9, :lo12:_5_describe_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _5_describe_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_5_describe_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _21_describe closure env_end to x9
+    stur x9, [x29, #-48] // store value
+    ldur d0, [x29, #-8] // load float operand
+    ldur d1, [x29, #-16] // load float operand
+    fcmp d0, d1
+    b.mi ltf64__3_describe_true_0_0
+ltf64__21_describe_false_0_0:
+    ldur x0, [x29, #-40] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _3_describe release helper
+    blr x10 // release _3_describe and what it holds
+    ldp x19, x22, [sp], #16
+    ldur x20, [x29, #-48] // load _21_describe closure env_end pointer
+    mov x0, x20 // pass env_end pointer to closure
+    ldr x9, [x0, #0] // load closure unwrapper entry point
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9 // tail call into closure
+ltf64__3_describe_true_0_0:
+    ldur x0, [x29, #-48] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _21_describe release helper
+    blr x10 // release _21_describe and what it holds
+    ldp x19, x22, [sp], #16
+    ldur x20, [x29, #-40] // load _3_describe closure env_end pointer
+    mov x0, x20 // pass env_end pointer to closure
+    ldr x9, [x0, #0] // load closure unwrapper entry point
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9 // tail call into closure
+.global describe_unwrapper
+describe_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-24] // load x env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-16] // load y env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-8] // load next env field
+    stur x9, [x29, #-32] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b describe
+.global describe_deep_release
+describe_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt describe_release_skip_2
+    ldur x9, [x19, #-8] // load describe_release_field_2 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load describe_release_field_2 release helper
+    blr x10 // release describe_release_field_2 and what it holds
+    ldp x19, x22, [sp], #16
+describe_release_skip_2:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global describe_deepcopy
+describe_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt describe_deepcopy_skip_2
+    ldur x0, [x19, #-8] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+describe_deepcopy_skip_2:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _58_main
+_58_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store nan arg in frame
+    stur x1, [x29, #-16] // store diff arg in frame
+    stur x2, [x29, #-24] // store huge arg in frame
+    mov x0, #72 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #16] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #24 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #24 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #72 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _61_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_61_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _61_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_61_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _61_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_61_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _137_main closure env_end to x9
+    stur x9, [x29, #-32] // store value
+    ldur x0, [x29, #-8] // load operand
+    movz x1, #0x0 // load literal float bits
+    movk x1, #0x3ff0, lsl #48
+    ldur x2, [x29, #-32] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b describe
+.global _58_main_unwrapper
+_58_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-24] // load nan env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-16] // load diff env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-8] // load huge env field
+    stur x9, [x29, #-32] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _58_main
+.global _58_main_deep_release
+_58_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _58_main_deepcopy
+_58_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _55_main
+_55_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store neg arg in frame
+    stur x1, [x29, #-16] // store nan arg in frame
+    stur x2, [x29, #-24] // store diff arg in frame
+    stur x3, [x29, #-32] // store huge arg in frame
+    mov x0, #72 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-32] // load operand
+    str x9, [x20, #16] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #24 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #24 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #72 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _58_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_58_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _58_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_58_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _58_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_58_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _138_main closure env_end to x9
+    stur x9, [x29, #-40] // store value
+    ldur x0, [x29, #-8] // load operand
+    movz x1, #0x0 // load literal float bits
+    movk x1, #0x4006, lsl #48
+    ldur x2, [x29, #-40] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b describe
+.global _55_main_unwrapper
+_55_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-32] // load neg env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-24] // load nan env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-16] // load diff env field
+    stur x9, [x29, #-32] // store value
+    ldur x9, [x19, #-8] // load huge env field
+    stur x9, [x29, #-40] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    ldur x3, [x29, #-40] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _55_main
+.global _55_main_deep_release
+_55_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _55_main_deepcopy
+_55_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _51_main
+_51_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store neg arg in frame
+    stur x1, [x29, #-16] // store nan arg in frame
+    stur x2, [x29, #-24] // store diff arg in frame
+    stur x3, [x29, #-32] // store huge arg in frame
+    mov x0, #80 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #16] // capture arg into env
+    ldur x9, [x29, #-32] // load operand
+    str x9, [x20, #24] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #32 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #32 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #80 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _55_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_55_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _55_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_55_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _55_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_55_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _139_main closure env_end to x9
+    stur x9, [x29, #-40] // store value
+    movz x0, #0x0 // load literal float bits
+    movk x0, #0x4008, lsl #48
+    movz x1, #0x0 // load literal float bits
+    movk x1, #0x3ff0, lsl #48
+    ldur x2, [x29, #-40] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b describe
+.global _51_main_unwrapper
+_51_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-32] // load neg env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-24] // load nan env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-16] // load diff env field
+    stur x9, [x29, #-32] // store value
+    ldur x9, [x19, #-8] // load huge env field
+    stur x9, [x29, #-40] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    ldur x3, [x29, #-40] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _51_main
+.global _51_main_deep_release
+_51_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _51_main_deepcopy
+_51_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _47_main
+_47_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store neg arg in frame
+    stur x1, [x29, #-16] // store nan arg in frame
+    stur x2, [x29, #-24] // store diff arg in frame
+    stur x3, [x29, #-32] // store huge arg in frame
+    mov x0, #80 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #16] // capture arg into env
+    ldur x9, [x29, #-32] // load operand
+    str x9, [x20, #24] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #32 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #32 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #80 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _51_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_51_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _51_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_51_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _51_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_51_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _140_main closure env_end to x9
+    stur x9, [x29, #-40] // store value
+    movz x0, #0x0 // load literal float bits
+    movk x0, #0x3ff8, lsl #48
+    movz x1, #0x0 // load literal float bits
+    movk x1, #0x4004, lsl #48
+    ldur x2, [x29, #-40] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b describe
+.global _47_main_unwrapper
+_47_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-32] // load neg env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-24] // load nan env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-16] // load diff env field
+    stur x9, [x29, #-32] // store value
+    ldur x9, [x19, #-8] // load huge env field
+    stur x9, [x29, #-40] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    ldur x3, [x29, #-40] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _47_main
+.global _47_main_deep_release
+_47_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _47_main_deepcopy
+_47_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _41_main
+_41_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #64 // reserve stack space for locals
+    stur x0, [x29, #-8] // store diff arg in frame
+    stur x1, [x29, #-16] // store neg arg in frame
+    stur x2, [x29, #-24] // store root arg in frame
+    stur x3, [x29, #-32] // store big arg in frame
+    stur x4, [x29, #-40] // store nan arg in frame
+    stur x5, [x29, #-48] // store huge arg in frame
+    mov x0, #80 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-40] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #16] // capture arg into env
+    ldur x9, [x29, #-48] // load operand
+    str x9, [x20, #24] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #32 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #32 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #80 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _47_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_47_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _47_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_47_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _47_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_47_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _141_main closure env_end to x9
+    stur x9, [x29, #-56] // store value
+    adrp x0, _42 // point to string literal
+    add x0, x0, :lo12:_42
+    ldur d0, [x29, #-8] // load float operand
+    ldur d1, [x29, #-16] // load float operand
+    ldur d2, [x29, #-24] // load float operand
+    ldur d3, [x29, #-32] // load float operand
+    mov x1, #42 // operand literal
+    adrp x2, _44 // point to string literal
+    add x2, x2, :lo12:_44
+    movz x9, #0x0 // load literal float bits
+    movk x9, #0x3fe0, lsl #48
+    fmov d4, x9 // load float literal
+    bl printf // invoke libc printf
+    ldur x19, [x29, #-56] // load continuation env_end pointer
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _41_main_unwrapper
+_41_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #64 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-48] // load diff env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-40] // load neg env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-32] // load root env field
+    stur x9, [x29, #-32] // store value
+    ldur x9, [x19, #-24] // load big env field
+    stur x9, [x29, #-40] // store value
+    ldur x9, [x19, #-16] // load nan env field
+    stur x9, [x29, #-48] // store value
+    ldur x9, [x19, #-8] // load huge env field
+    stur x9, [x29, #-56] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    ldur x3, [x29, #-40] // load operand
+    ldur x4, [x29, #-48] // load operand
+    ldur x5, [x29, #-56] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _41_main
+.global _41_main_deep_release
+_41_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _41_main_deepcopy
+_41_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _37_main
+_37_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store diff arg in frame
+    stur x1, [x29, #-16] // store neg arg in frame
+    stur x2, [x29, #-24] // store root arg in frame
+    stur x3, [x29, #-32] // store big arg in frame
+    stur x4, [x29, #-40] // store nan arg in frame
+    mov x0, #96 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #16] // capture arg into env
+    ldur x9, [x29, #-32] // load operand
+    str x9, [x20, #24] // capture arg into env
+    ldur x9, [x29, #-40] // load operand
+    str x9, [x20, #32] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #48 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #48 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #96 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _41_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_41_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _41_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_41_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _41_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_41_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _142_main closure env_end to x9
+    stur x9, [x29, #-48] // store value
+    movz x9, #0x0 // load literal float bits
+    movk x9, #0x2000, lsl #16
+    movk x9, #0xa05f, lsl #32
+    movk x9, #0x4202, lsl #48
+    fmov d0, x9 // load float literal
+    movz x9, #0x0 // load literal float bits
+    movk x9, #0x2000, lsl #16
+    movk x9, #0xa05f, lsl #32
+    movk x9, #0x4202, lsl #48
+    fmov d1, x9 // load float literal
+    fmul d0, d0, d1 // multiply by multiplier float
+    fmov x9, d0 // move float result to x9
+    ldur x19, [x29, #-48] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _37_main_unwrapper
+_37_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-40] // load diff env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-32] // load neg env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-24] // load root env field
+    stur x9, [x29, #-32] // store value
+    ldur x9, [x19, #-16] // load big env field
+    stur x9, [x29, #-40] // store value
+    ldur x9, [x19, #-8] // load nan env field
+    stur x9, [x29, #-48] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    ldur x3, [x29, #-40] // load operand
+    ldur x4, [x29, #-48] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _37_main
+.global _37_main_deep_release
+_37_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _37_main_deepcopy
+_37_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _33_main
+_33_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store diff arg in frame
+    stur x1, [x29, #-16] // store neg arg in frame
+    stur x2, [x29, #-24] // store root arg in frame
+    stur x3, [x29, #-32] // store big arg in frame
+    mov x0, #88 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #16] // capture arg into env
+    ldur x9, [x29, #-32] // load operand
+    str x9, [x20, #24] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #40 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #40 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #88 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _37_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_37_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _37_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_37_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _37_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_37_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _143_main closure env_end to x9
+    stur x9, [x29, #-40] // store value
+    mov x9, #0 // load literal float bits
+    fmov d0, x9 // load float literal
+    mov x9, #0 // load literal float bits
+    fmov d1, x9 // load float literal
+    fdiv d0, d0, d1 // divide by divisor float
+    fmov x9, d0 // move float result to x9
+    ldur x19, [x29, #-40] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _33_main_unwrapper
+_33_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-32] // load diff env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-24] // load neg env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-16] // load root env field
+    stur x9, [x29, #-32] // store value
+    ldur x9, [x19, #-8] // load big env field
+    stur x9, [x29, #-40] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    ldur x3, [x29, #-40] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _33_main
+.global _33_main_deep_release
+_33_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _33_main_deepcopy
+_33_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _30_main
+_30_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store diff arg in frame
+    stur x1, [x29, #-16] // store neg arg in frame
+    stur x2, [x29, #-24] // store root arg in frame
+    mov x0, #80 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #16] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #32 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #32 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #80 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _33_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_33_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _33_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_33_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _33_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_33_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _144_main closure env_end to x9
+    stur x9, [x29, #-32] // store value
+    movz x9, #0xcd15 // operand literal
+    movk x9, #0x75b, lsl #16
+    scvtf d0, x9 // convert int to float
+    fmov x9, d0 // move float result to x9
+    ldur x19, [x29, #-32] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _30_main_unwrapper
+_30_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-24] // load diff env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-16] // load neg env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-8] // load root env field
+    stur x9, [x29, #-32] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _30_main
+.global _30_main_deep_release
+_30_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _30_main_deepcopy
+_30_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _27_main
+_27_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store diff arg in frame
+    stur x1, [x29, #-16] // store neg arg in frame
+    mov x0, #72 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #8] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #24 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #24 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #72 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _30_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_30_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _30_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_30_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _30_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_30_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _145_main closure env_end to x9
+    stur x9, [x29, #-24] // store value
+    movz x9, #0x0 // load literal float bits
+    movk x9, #0x4000, lsl #48
+    fmov d0, x9 // load float literal
+    fsqrt d0, d0 // square root
+    fmov x9, d0 // move float result to x9
+    ldur x19, [x29, #-24] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _27_main_unwrapper
+_27_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-16] // load diff env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-8] // load neg env field
+    stur x9, [x29, #-24] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _27_main
+.global _27_main_deep_release
+_27_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _27_main_deepcopy
+_27_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _25_main
+_25_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store diff arg in frame
+    mov x0, #64 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #0] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #16 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #16 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #64 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _27_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_27_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _27_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_27_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _27_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_27_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _146_main closure env_end to x9
+    stur x9, [x29, #-16] // store value
+    ldur d0, [x29, #-8] // load float operand
+    fneg d0, d0 // flip the sign
+    fmov x9, d0 // move float result to x9
+    ldur x19, [x29, #-16] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _25_main_unwrapper
+_25_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-8] // load diff env field
+    stur x9, [x29, #-16] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _25_main
+.global _25_main_deep_release
+_25_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _25_main_deepcopy
+_25_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global main
+main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    mov x0, #56 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #8 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #8 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #56 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _25_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_25_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _25_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_25_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _25_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_25_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _25_main closure env_end to x9
+    stur x9, [x29, #-8] // store value
+    movz x9, #0x0 // load literal float bits
+    movk x9, #0x401e, lsl #48
+    fmov d0, x9 // load float literal
+    movz x9, #0x0 // load literal float bits
+    movk x9, #0x8000, lsl #32
+    movk x9, #0x4024, lsl #48
+    fmov d1, x9 // load float literal
+    fsub d0, d0, d1 // subtract subtrahend float
+    fmov x9, d0 // move float result to x9
+    ldur x19, [x29, #-8] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global main_unwrapper
+main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b main
+.global main_deep_release
+main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global main_deepcopy
+main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _start
+_start:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b main
+.section .rodata
+.p2align 3
+    .quad 0, 56 // static strings have no allocation to free
+_98:
+    .asciz "%s\012%.1f %.1f %.1f %.1f %.1f %.1f %.1f %.1f %.1f %.1f %d\012"
+.p2align 3
+    .quad 0, 39 // static strings have no allocation to free
+_85:
+    .asciz "%d:%.3f:%d:%.3f:%d:%.3f:%d:%.3f:%d:%.3f"
+.p2align 3
+    .quad 0, 17 // static strings have no allocation to free
+_82:
+    .asciz "ftoi(huge) fails\012"
+.p2align 3
+    .quad 0, 15 // static strings have no allocation to free
+_119:
+    .asciz "unreachable %d\012"
+.p2align 3
+    .quad 0, 16 // static strings have no allocation to free
+_77:
+    .asciz "ftoi(nan) fails\012"
+.p2align 3
+    .quad 0, 15 // static strings have no allocation to free
+_129:
+    .asciz "unreachable %d\012"
+.p2align 3
+    .quad 0, 16 // static strings have no allocation to free
+_72:
+    .asciz "ftoi(%.2f) = %d\012"
+.p2align 3
+    .quad 0, 12 // static strings have no allocation to free
+_64:
+    .asciz "unreachable\012"
+.p2align 3
+    .quad 0, 12 // static strings have no allocation to free
+_2:
+    .asciz "%.2f < %.2f\012"
+.p2align 3
+    .quad 0, 12 // static strings have no allocation to free
+_8:
+    .asciz "%.2f > %.2f\012"
+.p2align 3
+    .quad 0, 13 // static strings have no allocation to free
+_14:
+    .asciz "%.2f == %.2f\012"
+.p2align 3
+    .quad 0, 20 // static strings have no allocation to free
+_18:
+    .asciz "unordered with %.2f\012"
+.p2align 3
+    .quad 0, 25 // static strings have no allocation to free
+_42:
+    .asciz "%f %f %.6f %.1f %d %s %g\012"
+.p2align 3
+    .quad 0, 5 // static strings have no allocation to free
+_44:
+    .asciz "mixed"
@@ -0,0 +1,3672 @@
+bits 64
+default rel
+section .text
+global _111_main
+_111_main:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    ; load exit code
+    mov rdi, 0 ; operand literal
+    call exit ; call libc exit to flush buffers
+global alloc_heap_ptr
+alloc_heap_ptr:
+    add rdi, 7 ; round size up to whole words
+    and rdi, -8
+    cmp rdi, 1024 ; small block?
+    ja alloc_heap_ptr_large
+    lea rcx, [heap_free_lists] ; free list heads by size
+    mov rax, [rcx+rdi] ; head of this size's free list
+    test rax, rax
+    jz alloc_heap_ptr_bump
+    mov rdx, [rax] ; next free block
+    mov [rcx+rdi], rdx ; pop the head
+    ret
+alloc_heap_ptr_bump:
+    lea rcx, [heap_arena] ; arena next and end pointers
+    mov rax, [rcx] ; next free byte of the arena
+    mov rdx, rax
+    add rdx, rdi ; end of the new block
+    mov r8, [rcx+8] ; end of the arena
+    cmp rdx, r8
+    ja alloc_heap_ptr_refill
+    mov [rcx], rdx ; bump past the block
+    ret
+alloc_heap_ptr_refill:
+    push rdi ; keep block size across the syscall
+    mov rsi, 1048576 ; arena size
+    xor rdi, rdi ; addr hint so kernel picks mmap base
+    mov rdx, 3 ; prot = read/write
+    mov r10, 34 ; flags = private & anonymous
+    mov r8, -1 ; fd = -1
+    xor r9, r9 ; offset = 0
+    mov rax, 9 ; mmap syscall
+    syscall ; map a new arena
+    pop rdi
+    cmp rax, -4095 ; mmap failed?
+    jae alloc_heap_ptr_failed
+    lea rcx, [heap_arena] ; arena next and end pointers
+    lea rdx, [rax+rdi] ; block is the start of the arena
+    mov [rcx], rdx
+    mov rdx, rax
+    add rdx, 1048576 ; end of the arena
+    mov [rcx+8], rdx
+    ret
+alloc_heap_ptr_large:
+    mov rsi, rdi ; length = block size
+    xor rdi, rdi ; addr hint so kernel picks mmap base
+    mov rdx, 3 ; prot = read/write
+    mov r10, 34 ; flags = private & anonymous
+    mov r8, -1 ; fd = -1
+    xor r9, r9 ; offset = 0
+    mov rax, 9 ; mmap syscall
+    syscall ; map a large block on its own
+    cmp rax, -4095 ; mmap failed?
+    jae alloc_heap_ptr_failed
+    ret
+alloc_heap_ptr_failed:
+    xor eax, eax ; no memory left
+    ret
+section .data
+heap_free_lists:
+    times 129 dq 0
+heap_arena:
+    dq 0, 0
+section .text
+global free_heap_ptr
+free_heap_ptr:
+    add rsi, 7 ; round size up to whole words
+    and rsi, -8
+    cmp rsi, 1024 ; small block?
+    ja free_heap_ptr_large
+    lea rcx, [heap_free_lists] ; free list heads by size
+    mov rax, [rcx+rsi] ; current head
+    mov [rdi], rax ; link it behind the block
+    mov [rcx+rsi], rdi ; block is the new head
+    ret
+free_heap_ptr_large:
+    mov rax, 11 ; munmap syscall
+    syscall
+    ret
+global release_heap_ptr
+release_heap_ptr:
+    push rbp ; save caller frame
+    mov rbp, rsp ; establish frame
+    push rbx ; preserve rbx
+    mov rbx, rdi ; keep env_end pointer
+    mov rcx, [rbx+24] ; load env size metadata
+    mov rdx, [rbx+32] ; load heap size metadata
+    mov rdi, rbx
+    sub rdi, rcx ; compute env base pointer
+    mov rsi, rdx ; heap size to free
+    call free_heap_ptr ; return env to the allocator
+    pop rbx
+    pop rbp
+    ret
+global _111_main_unwrapper
+_111_main_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave ; unwind before named jump
+    jmp _111_main
+global _111_main_deep_release
+_111_main_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _111_main_deepcopy
+_111_main_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    leave
+    ret
+
+global release_str_ptr
+release_str_ptr:
+    test rdi, rdi ; nothing to free
+    jz release_str_ptr_done
+    mov rsi, [rdi-16] ; allocation size header
+    test rsi, rsi ; literals have no allocation
+    jz release_str_ptr_done
+    sub rdi, 16 ; block starts at the header
+    jmp free_heap_ptr ; return string to the allocator
+release_str_ptr_done:
+    ret
+global _97_main
+_97_main:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store s arg in frame
+    mov rdi, 48 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov r12, rbx ; env_end pointer before metadata
+    mov rax, 0 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 48 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_111_main_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_111_main_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_111_main_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 0 ; store num_remaining
+    mov rax, r12 ; copy _111_main closure env_end to rax
+    mov [rbp-16], rax ; store value
+    mov rax, 10 ; operand literal
+    push rax ; stack arg
+    mov rax, 0x4023000000000000 ; load literal float bits
+    push rax ; stack arg
+    mov rax, 0x4021000000000000 ; load literal float bits
+    push rax ; stack arg
+    mov rax, 0x401e000000000000 ; load literal float bits
+    push rax ; stack arg
+    mov rax, 0x401a000000000000 ; load literal float bits
+    push rax ; stack arg
+    mov rax, 0x4016000000000000 ; load literal float bits
+    push rax ; stack arg
+    mov rax, 0x4012000000000000 ; load literal float bits
+    push rax ; stack arg
+    mov rax, 0x400c000000000000 ; load literal float bits
+    push rax ; stack arg
+    mov rax, 0x4004000000000000 ; load literal float bits
+    push rax ; stack arg
+    mov rax, 0x3ff8000000000000 ; load literal float bits
+    push rax ; stack arg
+    mov rax, 0x3fe0000000000000 ; load literal float bits
+    push rax ; stack arg
+    mov rax, [rbp-8] ; load operand
+    push rax ; stack arg
+    lea rax, [rel _98] ; point to string literal
+    push rax ; stack arg
+    mov r13, rsp ; printf args in order
+    mov r15, rsp ; restore point after printf
+    sub rsp, 16 ; room for stack args
+    and rsp, -16 ; align stack for variadic call
+    mov rax, [r13+80] ; stack arg 0
+    mov [rsp+0], rax
+    mov rax, [r13+88] ; stack arg 1
+    mov [rsp+8], rax
+    mov rdi, [r13+0] ; printf arg 0
+    mov rsi, [r13+8] ; printf arg 1
+    movsd xmm0, [r13+16] ; printf arg 2
+    movsd xmm1, [r13+24] ; printf arg 3
+    movsd xmm2, [r13+32] ; printf arg 4
+    movsd xmm3, [r13+40] ; printf arg 5
+    movsd xmm4, [r13+48] ; printf arg 6
+    movsd xmm5, [r13+56] ; printf arg 7
+    movsd xmm6, [r13+64] ; printf arg 8
+    movsd xmm7, [r13+72] ; printf arg 9
+    mov rdx, [r13+96] ; printf arg 12
+    mov eax, 8 ; vector registers holding args
+    call printf ; invoke libc printf
+    mov rsp, r15
+    add rsp, 104 ; pop stack args after libc call
+    mov rdi, [rbp-8] ; load operand
+    call release_str_ptr ; release s string
+    mov r12, [rbp-16] ; load continuation env_end pointer
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+global _97_main_unwrapper
+_97_main_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-8] ; load s env field
+    mov [rbp-16], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    mov rax, [rbp-16] ; load operand
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    leave ; unwind before named jump
+    jmp _97_main
+global _97_main_deep_release
+_97_main_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load __num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _97_main_release_skip_0
+    mov rax, [r12-8] ; load _97_main_release_field_0 env field
+    mov [rbp-24], rax ; store value
+    mov rdi, [rbp-24] ; load operand
+    call release_str_ptr ; release _97_main_release_field_0 string
+_97_main_release_skip_0:
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global copy_str_ptr
+copy_str_ptr:
+    mov rax, rdi ; share the pointer unless it owns memory
+    test rdi, rdi
+    jz copy_str_ptr_done
+    mov rsi, [rdi-16] ; allocation size header
+    test rsi, rsi ; literals are never freed
+    jz copy_str_ptr_done
+    push rbx ; preserve callee-saved registers
+    push r13
+    push r14
+    mov rbx, rdi ; source string
+    mov r13, rsi ; allocation size
+    mov rdi, rsi
+    call alloc_heap_ptr ; allocate the copy
+    mov r14, rax ; copy block
+    mov rdi, rax ; destination block
+    lea rsi, [rbx-16] ; source block, header included
+    mov rcx, r13 ; bytes to copy
+    cld ; ensure forward copy
+    rep movsb ; duplicate header and bytes
+    lea rax, [r14+16] ; copied string starts after its header
+    pop r14
+    pop r13
+    pop rbx
+copy_str_ptr_done:
+    ret
+global _97_main_deepcopy
+_97_main_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _97_main_deepcopy_skip_0
+    mov rcx, [r12-8] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call copy_str_ptr ; duplicate heap pointer
+    mov [r12-8], rax ; store duplicated pointer
+    mov [rbp-24], rax ; store value
+_97_main_deepcopy_skip_0:
+    leave
+    ret
+
+global _84_main
+_84_main:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov rdi, 56 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 8 ; move pointer past env payload
+    mov rax, 8 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 56 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_97_main_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_97_main_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_97_main_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 1 ; store num_remaining
+    mov rax, r12 ; copy _97_main closure env_end to rax
+    mov [rbp-8], rax ; store value
+    mov rax, 0x4016000000000000 ; load literal float bits
+    push rax ; stack arg
+    mov rax, 5 ; operand literal
+    push rax ; stack arg
+    mov rax, 0x4012000000000000 ; load literal float bits
+    push rax ; stack arg
+    mov rax, 4 ; operand literal
+    push rax ; stack arg
+    mov rax, 0x400c000000000000 ; load literal float bits
+    push rax ; stack arg
+    mov rax, 3 ; operand literal
+    push rax ; stack arg
+    mov rax, 0x4004000000000000 ; load literal float bits
+    push rax ; stack arg
+    mov rax, 2 ; operand literal
+    push rax ; stack arg
+    mov rax, 0x3ff8000000000000 ; load literal float bits
+    push rax ; stack arg
+    mov rax, 1 ; operand literal
+    push rax ; stack arg
+    lea rax, [rel _85] ; point to string literal
+    push rax ; stack arg
+    mov r13, rsp ; sprintf args in order
+    xor rdi, rdi ; measure only
+    xor rsi, rsi
+    mov r15, rsp ; restore point after snprintf
+    sub rsp, 16 ; room for stack args
+    and rsp, -16 ; align stack for variadic call
+    mov rax, [r13+56] ; stack arg 0
+    mov [rsp+0], rax
+    mov rax, [r13+72] ; stack arg 1
+    mov [rsp+8], rax
+    mov rdx, [r13+0] ; snprintf arg 0
+    mov rcx, [r13+8] ; snprintf arg 1
+    movsd xmm0, [r13+16] ; snprintf arg 2
+    mov r8, [r13+24] ; snprintf arg 3
+    movsd xmm1, [r13+32] ; snprintf arg 4
+    mov r9, [r13+40] ; snprintf arg 5
+    movsd xmm2, [r13+48] ; snprintf arg 6
+    movsd xmm3, [r13+64] ; snprintf arg 8
+    movsd xmm4, [r13+80] ; snprintf arg 10
+    mov eax, 5 ; vector registers holding args
+    call snprintf ; invoke libc snprintf
+    mov rsp, r15
+    mov rbx, rax ; formatted length
+    lea rdi, [rbx+17] ; header, bytes and terminator
+    call alloc_heap_ptr ; allocate the string
+    mov r14, rax ; string block
+    lea rax, [rbx+17]
+    mov [r14], rax ; allocation size header
+    mov [r14+8], rbx ; length header
+    lea rdi, [r14+16] ; string bytes follow the header
+    lea rsi, [rbx+1] ; room for the terminator
+    mov r15, rsp ; restore point after snprintf
+    sub rsp, 16 ; room for stack args
+    and rsp, -16 ; align stack for variadic call
+    mov rax, [r13+56] ; stack arg 0
+    mov [rsp+0], rax
+    mov rax, [r13+72] ; stack arg 1
+    mov [rsp+8], rax
+    mov rdx, [r13+0] ; snprintf arg 0
+    mov rcx, [r13+8] ; snprintf arg 1
+    movsd xmm0, [r13+16] ; snprintf arg 2
+    mov r8, [r13+24] ; snprintf arg 3
+    movsd xmm1, [r13+32] ; snprintf arg 4
+    mov r9, [r13+40] ; snprintf arg 5
+    movsd xmm2, [r13+48] ; snprintf arg 6
+    movsd xmm3, [r13+64] ; snprintf arg 8
+    movsd xmm4, [r13+80] ; snprintf arg 10
+    mov eax, 5 ; vector registers holding args
+    call snprintf ; invoke libc snprintf
+    mov rsp, r15
+    lea rax, [r14+16] ; return formatted string pointer
+    add rsp, 88 ; pop stack args after libc call
+    mov r12, [rbp-8] ; load continuation env_end pointer
+    mov [r12-8], rax ; store env field
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+global _84_main_unwrapper
+_84_main_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave ; unwind before named jump
+    jmp _84_main
+global _84_main_deep_release
+_84_main_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _84_main_deepcopy
+_84_main_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    leave
+    ret
+
+global _81_main
+_81_main:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov rdi, 48 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov r12, rbx ; env_end pointer before metadata
+    mov rax, 0 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 48 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_84_main_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_84_main_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_84_main_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 0 ; store num_remaining
+    mov rax, r12 ; copy _84_main closure env_end to rax
+    mov [rbp-8], rax ; store value
+    lea rax, [rel _82] ; point to string literal
+    push rax ; stack arg
+    mov r13, rsp ; printf args in order
+    mov r15, rsp ; restore point after printf
+    and rsp, -16 ; align stack for variadic call
+    mov rdi, [r13+0] ; printf arg 0
+    mov eax, 0 ; vector registers holding args
+    call printf ; invoke libc printf
+    mov rsp, r15
+    add rsp, 8 ; pop stack args after libc call
+    mov r12, [rbp-8] ; load continuation env_end pointer
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+global _81_main_unwrapper
+_81_main_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave ; unwind before named jump
+    jmp _81_main
+global _81_main_deep_release
+_81_main_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _81_main_deepcopy
+_81_main_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    leave
+    ret
+
+global _121_main
+_121_main:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    ; load exit code
+    mov rdi, 1 ; operand literal
+    call exit ; call libc exit to flush buffers
+global _121_main_unwrapper
+_121_main_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave ; unwind before named jump
+    jmp _121_main
+global _121_main_deep_release
+_121_main_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _121_main_deepcopy
+_121_main_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    leave
+    ret
+
+global _118_main
+_118_main:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store u arg in frame
+    mov rdi, 48 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov r12, rbx ; env_end pointer before metadata
+    mov rax, 0 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 48 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_121_main_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_121_main_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_121_main_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 0 ; store num_remaining
+    mov rax, r12 ; copy _121_main closure env_end to rax
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-8] ; load operand
+    push rax ; stack arg
+    lea rax, [rel _119] ; point to string literal
+    push rax ; stack arg
+    mov r13, rsp ; printf args in order
+    mov r15, rsp ; restore point after printf
+    and rsp, -16 ; align stack for variadic call
+    mov rdi, [r13+0] ; printf arg 0
+    mov rsi, [r13+8] ; printf arg 1
+    mov eax, 0 ; vector registers holding args
+    call printf ; invoke libc printf
+    mov rsp, r15
+    add rsp, 16 ; pop stack args after libc call
+    mov r12, [rbp-16] ; load continuation env_end pointer
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+global _118_main_unwrapper
+_118_main_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-8] ; load u env field
+    mov [rbp-16], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    mov rax, [rbp-16] ; load operand
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    leave ; unwind before named jump
+    jmp _118_main
+global _118_main_deep_release
+_118_main_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _118_main_deepcopy
+_118_main_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    leave
+    ret
+
+global _79_main
+_79_main:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store huge arg in frame
+    mov rdi, 48 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov r12, rbx ; env_end pointer before metadata
+    mov rax, 0 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 48 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_81_main_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_81_main_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_81_main_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 0 ; store num_remaining
+    mov rax, r12 ; copy _81_main closure env_end to rax
+    mov [rbp-16], rax ; store value
+    mov rdi, 56 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 8 ; move pointer past env payload
+    mov rax, 8 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 56 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_118_main_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_118_main_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_118_main_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 1 ; store num_remaining
+    mov rax, r12 ; copy _118_main closure env_end to rax
+    mov [rbp-24], rax ; store value
+    movsd xmm0, [rbp-8] ; load float operand
+    cvttsd2si rax, xmm0 ; truncate toward zero
+    mov rcx, 0x8000000000000000 ; integer indefinite
+    cmp rax, rcx
+    jne _79_main_ftoi_ok_0
+    mov rcx, 0xc3e0000000000000 ; -2^63 as a float
+    movq xmm1, rcx
+    ucomisd xmm0, xmm1
+    jp _79_main_ftoi_err_1 ; NaN has no int
+    je _79_main_ftoi_ok_0
+_79_main_ftoi_err_1:
+    mov rdi, [rbp-24] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _118_main release helper
+    call rax ; release _118_main and what it holds
+    pop rbx
+    pop r12
+    mov r12, [rbp-16] ; load continuation env_end pointer
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+_79_main_ftoi_ok_0:
+    mov rbx, rax ; keep result across releases
+    mov rdi, [rbp-16] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _81_main release helper
+    call rax ; release _81_main and what it holds
+    pop rbx
+    pop r12
+    mov rax, rbx ; restore result
+    mov r12, [rbp-24] ; load continuation env_end pointer
+    mov [r12-8], rax ; store env field
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+global _79_main_unwrapper
+_79_main_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-8] ; load huge env field
+    mov [rbp-16], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    movsd xmm0, [rbp-16] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    leave ; unwind before named jump
+    jmp _79_main
+global _79_main_deep_release
+_79_main_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _79_main_deepcopy
+_79_main_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    leave
+    ret
+
+global _76_main
+_76_main:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store huge arg in frame
+    mov rdi, 56 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    movsd xmm0, [rbp-8] ; load float operand
+    movq rax, xmm0
+    mov [rbx+0], rax ; capture arg into env
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 8 ; move pointer past env payload
+    mov rax, 8 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 56 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_79_main_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_79_main_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_79_main_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 0 ; store num_remaining
+    mov rax, r12 ; copy _125_main closure env_end to rax
+    mov [rbp-16], rax ; store value
+    lea rax, [rel _77] ; point to string literal
+    push rax ; stack arg
+    mov r13, rsp ; printf args in order
+    mov r15, rsp ; restore point after printf
+    and rsp, -16 ; align stack for variadic call
+    mov rdi, [r13+0] ; printf arg 0
+    mov eax, 0 ; vector registers holding args
+    call printf ; invoke libc printf
+    mov rsp, r15
+    add rsp, 8 ; pop stack args after libc call
+    mov r12, [rbp-16] ; load continuation env_end pointer
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+global _76_main_unwrapper
+_76_main_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-8] ; load huge env field
+    mov [rbp-16], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    movsd xmm0, [rbp-16] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    leave ; unwind before named jump
+    jmp _76_main
+global _76_main_deep_release
+_76_main_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _76_main_deepcopy
+_76_main_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    leave
+    ret
+
+global _131_main
+_131_main:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    ; load exit code
+    mov rdi, 1 ; operand literal
+    call exit ; call libc exit to flush buffers
+global _131_main_unwrapper
+_131_main_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave ; unwind before named jump
+    jmp _131_main
+global _131_main_deep_release
+_131_main_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _131_main_deepcopy
+_131_main_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    leave
+    ret
+
+global _128_main
+_128_main:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store u arg in frame
+    mov rdi, 48 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov r12, rbx ; env_end pointer before metadata
+    mov rax, 0 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 48 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_131_main_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_131_main_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_131_main_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 0 ; store num_remaining
+    mov rax, r12 ; copy _131_main closure env_end to rax
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-8] ; load operand
+    push rax ; stack arg
+    lea rax, [rel _129] ; point to string literal
+    push rax ; stack arg
+    mov r13, rsp ; printf args in order
+    mov r15, rsp ; restore point after printf
+    and rsp, -16 ; align stack for variadic call
+    mov rdi, [r13+0] ; printf arg 0
+    mov rsi, [r13+8] ; printf arg 1
+    mov eax, 0 ; vector registers holding args
+    call printf ; invoke libc printf
+    mov rsp, r15
+    add rsp, 16 ; pop stack args after libc call
+    mov r12, [rbp-16] ; load continuation env_end pointer
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+global _128_main_unwrapper
+_128_main_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-8] ; load u env field
+    mov [rbp-16], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    mov rax, [rbp-16] ; load operand
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    leave ; unwind before named jump
+    jmp _128_main
+global _128_main_deep_release
+_128_main_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _128_main_deepcopy
+_128_main_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    leave
+    ret
+
+global _74_main
+_74_main:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store nan arg in frame
+    mov [rbp-16], rsi ; store huge arg in frame
+    mov rdi, 56 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    movsd xmm0, [rbp-16] ; load float operand
+    movq rax, xmm0
+    mov [rbx+0], rax ; capture arg into env
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 8 ; move pointer past env payload
+    mov rax, 8 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 56 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_76_main_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_76_main_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_76_main_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 0 ; store num_remaining
+    mov rax, r12 ; copy _126_main closure env_end to rax
+    mov [rbp-24], rax ; store value
+    mov rdi, 56 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 8 ; move pointer past env payload
+    mov rax, 8 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 56 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_128_main_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_128_main_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_128_main_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 1 ; store num_remaining
+    mov rax, r12 ; copy _128_main closure env_end to rax
+    mov [rbp-32], rax ; store value
+    movsd xmm0, [rbp-8] ; load float operand
+    cvttsd2si rax, xmm0 ; truncate toward zero
+    mov rcx, 0x8000000000000000 ; integer indefinite
+    cmp rax, rcx
+    jne _74_main_ftoi_ok_0
+    mov rcx, 0xc3e0000000000000 ; -2^63 as a float
+    movq xmm1, rcx
+    ucomisd xmm0, xmm1
+    jp _74_main_ftoi_err_1 ; NaN has no int
+    je _74_main_ftoi_ok_0
+_74_main_ftoi_err_1:
+    mov rdi, [rbp-32] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _128_main release helper
+    call rax ; release _128_main and what it holds
+    pop rbx
+    pop r12
+    mov r12, [rbp-24] ; load continuation env_end pointer
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+_74_main_ftoi_ok_0:
+    mov rbx, rax ; keep result across releases
+    mov rdi, [rbp-24] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _126_main release helper
+    call rax ; release _126_main and what it holds
+    pop rbx
+    pop r12
+    mov rax, rbx ; restore result
+    mov r12, [rbp-32] ; load continuation env_end pointer
+    mov [r12-8], rax ; store env field
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+global _74_main_unwrapper
+_74_main_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-16] ; load nan env field
+    mov [rbp-16], rax ; store value
+    mov rax, [r12-8] ; load huge env field
+    mov [rbp-24], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    movsd xmm0, [rbp-24] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    movsd xmm0, [rbp-16] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    leave ; unwind before named jump
+    jmp _74_main
+global _74_main_deep_release
+_74_main_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _74_main_deepcopy
+_74_main_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    leave
+    ret
+
+global _71_main
+_71_main:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store diff arg in frame
+    mov [rbp-16], rsi ; store nan arg in frame
+    mov [rbp-24], rdx ; store huge arg in frame
+    mov [rbp-32], rcx ; store t arg in frame
+    mov rdi, 64 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    movsd xmm0, [rbp-16] ; load float operand
+    movq rax, xmm0
+    mov [rbx+0], rax ; capture arg into env
+    movsd xmm0, [rbp-24] ; load float operand
+    movq rax, xmm0
+    mov [rbx+8], rax ; capture arg into env
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 16 ; move pointer past env payload
+    mov rax, 16 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 64 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_74_main_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_74_main_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_74_main_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 0 ; store num_remaining
+    mov rax, r12 ; copy _135_main closure env_end to rax
+    mov [rbp-40], rax ; store value
+    mov rax, [rbp-32] ; load operand
+    push rax ; stack arg
+    movsd xmm0, [rbp-8] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    lea rax, [rel _72] ; point to string literal
+    push rax ; stack arg
+    mov r13, rsp ; printf args in order
+    mov r15, rsp ; restore point after printf
+    and rsp, -16 ; align stack for variadic call
+    mov rdi, [r13+0] ; printf arg 0
+    movsd xmm0, [r13+8] ; printf arg 1
+    mov rsi, [r13+16] ; printf arg 2
+    mov eax, 1 ; vector registers holding args
+    call printf ; invoke libc printf
+    mov rsp, r15
+    add rsp, 24 ; pop stack args after libc call
+    mov r12, [rbp-40] ; load continuation env_end pointer
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+global _71_main_unwrapper
+_71_main_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-32] ; load diff env field
+    mov [rbp-16], rax ; store value
+    mov rax, [r12-24] ; load nan env field
+    mov [rbp-24], rax ; store value
+    mov rax, [r12-16] ; load huge env field
+    mov [rbp-32], rax ; store value
+    mov rax, [r12-8] ; load t env field
+    mov [rbp-40], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    mov rax, [rbp-40] ; load operand
+    push rax ; stack arg
+    movsd xmm0, [rbp-32] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    movsd xmm0, [rbp-24] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    movsd xmm0, [rbp-16] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    pop rdx ; restore arg into register
+    pop rcx ; restore arg into register
+    leave ; unwind before named jump
+    jmp _71_main
+global _71_main_deep_release
+_71_main_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _71_main_deepcopy
+_71_main_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    leave
+    ret
+
+global _66_main
+_66_main:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    ; load exit code
+    mov rdi, 1 ; operand literal
+    call exit ; call libc exit to flush buffers
+global _66_main_unwrapper
+_66_main_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave ; unwind before named jump
+    jmp _66_main
+global _66_main_deep_release
+_66_main_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _66_main_deepcopy
+_66_main_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    leave
+    ret
+
+global _63_main
+_63_main:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov rdi, 48 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov r12, rbx ; env_end pointer before metadata
+    mov rax, 0 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 48 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_66_main_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_66_main_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_66_main_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 0 ; store num_remaining
+    mov rax, r12 ; copy _66_main closure env_end to rax
+    mov [rbp-8], rax ; store value
+    lea rax, [rel _64] ; point to string literal
+    push rax ; stack arg
+    mov r13, rsp ; printf args in order
+    mov r15, rsp ; restore point after printf
+    and rsp, -16 ; align stack for variadic call
+    mov rdi, [r13+0] ; printf arg 0
+    mov eax, 0 ; vector registers holding args
+    call printf ; invoke libc printf
+    mov rsp, r15
+    add rsp, 8 ; pop stack args after libc call
+    mov r12, [rbp-8] ; load continuation env_end pointer
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+global _63_main_unwrapper
+_63_main_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave ; unwind before named jump
+    jmp _63_main
+global _63_main_deep_release
+_63_main_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _63_main_deepcopy
+_63_main_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    leave
+    ret
+
+global _61_main
+_61_main:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store diff arg in frame
+    mov [rbp-16], rsi ; store nan arg in frame
+    mov [rbp-24], rdx ; store huge arg in frame
+    mov rdi, 80 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    movsd xmm0, [rbp-8] ; load float operand
+    movq rax, xmm0
+    mov [rbx+0], rax ; capture arg into env
+    movsd xmm0, [rbp-16] ; load float operand
+    movq rax, xmm0
+    mov [rbx+8], rax ; capture arg into env
+    movsd xmm0, [rbp-24] ; load float operand
+    movq rax, xmm0
+    mov [rbx+16], rax ; capture arg into env
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 32 ; move pointer past env payload
+    mov rax, 32 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 80 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_71_main_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_71_main_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_71_main_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 1 ; store num_remaining
+    mov rax, r12 ; copy _136_main closure env_end to rax
+    mov [rbp-32], rax ; store value
+    mov rdi, 48 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov r12, rbx ; env_end pointer before metadata
+    mov rax, 0 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 48 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_63_main_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_63_main_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_63_main_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 0 ; store num_remaining
+    mov rax, r12 ; copy _63_main closure env_end to rax
+    mov [rbp-40], rax ; store value
+    movsd xmm0, [rbp-8] ; load float operand
+    cvttsd2si rax, xmm0 ; truncate toward zero
+    mov rcx, 0x8000000000000000 ; integer indefinite
+    cmp rax, rcx
+    jne _61_main_ftoi_ok_0
+    mov rcx, 0xc3e0000000000000 ; -2^63 as a float
+    movq xmm1, rcx
+    ucomisd xmm0, xmm1
+    jp _61_main_ftoi_err_1 ; NaN has no int
+    je _61_main_ftoi_ok_0
+_61_main_ftoi_err_1:
+    mov rdi, [rbp-32] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _136_main release helper
+    call rax ; release _136_main and what it holds
+    pop rbx
+    pop r12
+    mov r12, [rbp-40] ; load continuation env_end pointer
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+_61_main_ftoi_ok_0:
+    mov rbx, rax ; keep result across releases
+    mov rdi, [rbp-40] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _63_main release helper
+    call rax ; release _63_main and what it holds
+    pop rbx
+    pop r12
+    mov rax, rbx ; restore result
+    mov r12, [rbp-32] ; load continuation env_end pointer
+    mov [r12-8], rax ; store env field
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+global _61_main_unwrapper
+_61_main_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-24] ; load diff env field
+    mov [rbp-16], rax ; store value
+    mov rax, [r12-16] ; load nan env field
+    mov [rbp-24], rax ; store value
+    mov rax, [r12-8] ; load huge env field
+    mov [rbp-32], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    movsd xmm0, [rbp-32] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    movsd xmm0, [rbp-24] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    movsd xmm0, [rbp-16] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    pop rdx ; restore arg into register
+    leave ; unwind before named jump
+    jmp _61_main
+global _61_main_deep_release
+_61_main_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _61_main_deepcopy
+_61_main_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    leave
+    ret
+
+global _1_describe
+_1_describe:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store x arg in frame
+    mov [rbp-16], rsi ; store y arg in frame
+    mov [rbp-24], rdx ; store next arg in frame
+    movsd xmm0, [rbp-16] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    movsd xmm0, [rbp-8] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    lea rax, [rel _2] ; point to string literal
+    push rax ; stack arg
+    mov r13, rsp ; printf args in order
+    mov r15, rsp ; restore point after printf
+    and rsp, -16 ; align stack for variadic call
+    mov rdi, [r13+0] ; printf arg 0
+    movsd xmm0, [r13+8] ; printf arg 1
+    movsd xmm1, [r13+16] ; printf arg 2
+    mov eax, 2 ; vector registers holding args
+    call printf ; invoke libc printf
+    mov rsp, r15
+    add rsp, 24 ; pop stack args after libc call
+    mov r12, [rbp-24] ; load continuation env_end pointer
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+global _1_describe_unwrapper
+_1_describe_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-24] ; load x env field
+    mov [rbp-16], rax ; store value
+    mov rax, [r12-16] ; load y env field
+    mov [rbp-24], rax ; store value
+    mov rax, [r12-8] ; load next env field
+    mov [rbp-32], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    mov rax, [rbp-32] ; load operand
+    push rax ; stack arg
+    movsd xmm0, [rbp-24] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    movsd xmm0, [rbp-16] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    pop rdx ; restore arg into register
+    leave ; unwind before named jump
+    jmp _1_describe
+global _1_describe_deep_release
+_1_describe_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load __num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _1_describe_release_skip_2
+    mov rax, [r12-8] ; load _1_describe_release_field_2 env field
+    mov [rbp-24], rax ; store value
+    mov rdi, [rbp-24] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _1_describe_release_field_2 release helper
+    call rax ; release _1_describe_release_field_2 and what it holds
+    pop rbx
+    pop r12
+_1_describe_release_skip_2:
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global deepcopy_heap_ptr
+deepcopy_heap_ptr:
+    push rbp ; prologue: save executor frame pointer
+    mov rbp, rsp ; prologue: establish new frame
+    push rbx ; preserve callee-saved registers
+    push r12
+    push r13
+    push r14
+    push r15
+    mov r12, rdi ; capture env_end pointer
+    mov r14, [r12+24] ; load env size metadata
+    mov r15, [r12+32] ; load heap size metadata
+    mov rbx, r12 ; keep env_end pointer
+    sub rbx, r14 ; compute env base pointer
+    mov rdi, r15 ; length = heap size
+    call alloc_heap_ptr ; allocate new closure env
+    mov r13, rax ; new env base pointer
+    mov rdi, r13 ; memcpy dest
+    mov rsi, rbx ; memcpy src
+    mov rdx, r15 ; memcpy length
+    call memcpy_helper ; copy env contents
+    mov rax, r13 ; compute new env_end pointer
+    add rax, r14
+    mov r15, rax ; preserve new env_end pointer
+    mov rax, [r15+16] ; load deep copy helper entry
+    mov rdi, r15 ; pass new env_end pointer
+    call rax ; invoke helper
+    mov rax, r15 ; return new env_end pointer
+    pop r15
+    pop r14
+    pop r13
+    pop r12
+    pop rbx
+    pop rbp
+    ret
+global memcpy_helper
+memcpy_helper:
+    push rbp ; prologue
+    mov rbp, rsp
+    xor rcx, rcx ; counter = 0
+internal_memcpy_loop:
+    cmp rcx, rdx ; counter < count?
+    jge internal_memcpy_done
+    mov rax, [rsi+rcx] ; load 8 bytes from source
+    mov [rdi+rcx], rax ; store 8 bytes to destination
+    add rcx, 8 ; advance counter by 8
+    jmp internal_memcpy_loop
+internal_memcpy_done:
+    pop rbp
+    ret
+global _1_describe_deepcopy
+_1_describe_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _1_describe_deepcopy_skip_2
+    mov rcx, [r12-8] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call deepcopy_heap_ptr ; duplicate heap pointer
+    mov [r12-8], rax ; store duplicated pointer
+    mov [rbp-24], rax ; store value
+_1_describe_deepcopy_skip_2:
+    leave
+    ret
+
+global _7_describe
+_7_describe:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store x arg in frame
+    mov [rbp-16], rsi ; store y arg in frame
+    mov [rbp-24], rdx ; store next arg in frame
+    movsd xmm0, [rbp-16] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    movsd xmm0, [rbp-8] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    lea rax, [rel _8] ; point to string literal
+    push rax ; stack arg
+    mov r13, rsp ; printf args in order
+    mov r15, rsp ; restore point after printf
+    and rsp, -16 ; align stack for variadic call
+    mov rdi, [r13+0] ; printf arg 0
+    movsd xmm0, [r13+8] ; printf arg 1
+    movsd xmm1, [r13+16] ; printf arg 2
+    mov eax, 2 ; vector registers holding args
+    call printf ; invoke libc printf
+    mov rsp, r15
+    add rsp, 24 ; pop stack args after libc call
+    mov r12, [rbp-24] ; load continuation env_end pointer
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+global _7_describe_unwrapper
+_7_describe_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-24] ; load x env field
+    mov [rbp-16], rax ; store value
+    mov rax, [r12-16] ; load y env field
+    mov [rbp-24], rax ; store value
+    mov rax, [r12-8] ; load next env field
+    mov [rbp-32], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    mov rax, [rbp-32] ; load operand
+    push rax ; stack arg
+    movsd xmm0, [rbp-24] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    movsd xmm0, [rbp-16] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    pop rdx ; restore arg into register
+    leave ; unwind before named jump
+    jmp _7_describe
+global _7_describe_deep_release
+_7_describe_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load __num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _7_describe_release_skip_2
+    mov rax, [r12-8] ; load _7_describe_release_field_2 env field
+    mov [rbp-24], rax ; store value
+    mov rdi, [rbp-24] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _7_describe_release_field_2 release helper
+    call rax ; release _7_describe_release_field_2 and what it holds
+    pop rbx
+    pop r12
+_7_describe_release_skip_2:
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _7_describe_deepcopy
+_7_describe_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _7_describe_deepcopy_skip_2
+    mov rcx, [r12-8] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call deepcopy_heap_ptr ; duplicate heap pointer
+    mov [r12-8], rax ; store duplicated pointer
+    mov [rbp-24], rax ; store value
+_7_describe_deepcopy_skip_2:
+    leave
+    ret
+
+global _13_describe
+_13_describe:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store x arg in frame
+    mov [rbp-16], rsi ; store y arg in frame
+    mov [rbp-24], rdx ; store next arg in frame
+    movsd xmm0, [rbp-16] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    movsd xmm0, [rbp-8] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    lea rax, [rel _14] ; point to string literal
+    push rax ; stack arg
+    mov r13, rsp ; printf args in order
+    mov r15, rsp ; restore point after printf
+    and rsp, -16 ; align stack for variadic call
+    mov rdi, [r13+0] ; printf arg 0
+    movsd xmm0, [r13+8] ; printf arg 1
+    movsd xmm1, [r13+16] ; printf arg 2
+    mov eax, 2 ; vector registers holding args
+    call printf ; invoke libc printf
+    mov rsp, r15
+    add rsp, 24 ; pop stack args after libc call
+    mov r12, [rbp-24] ; load continuation env_end pointer
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+global _13_describe_unwrapper
+_13_describe_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-24] ; load x env field
+    mov [rbp-16], rax ; store value
+    mov rax, [r12-16] ; load y env field
+    mov [rbp-24], rax ; store value
+    mov rax, [r12-8] ; load next env field
+    mov [rbp-32], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    mov rax, [rbp-32] ; load operand
+    push rax ; stack arg
+    movsd xmm0, [rbp-24] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    movsd xmm0, [rbp-16] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    pop rdx ; restore arg into register
+    leave ; unwind before named jump
+    jmp _13_describe
+global _13_describe_deep_release
+_13_describe_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load __num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _13_describe_release_skip_2
+    mov rax, [r12-8] ; load _13_describe_release_field_2 env field
+    mov [rbp-24], rax ; store value
+    mov rdi, [rbp-24] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _13_describe_release_field_2 release helper
+    call rax ; release _13_describe_release_field_2 and what it holds
+    pop rbx
+    pop r12
+_13_describe_release_skip_2:
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _13_describe_deepcopy
+_13_describe_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _13_describe_deepcopy_skip_2
+    mov rcx, [r12-8] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call deepcopy_heap_ptr ; duplicate heap pointer
+    mov [r12-8], rax ; store duplicated pointer
+    mov [rbp-24], rax ; store value
+_13_describe_deepcopy_skip_2:
+    leave
+    ret
+
+global _17_describe
+_17_describe:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store y arg in frame
+    mov [rbp-16], rsi ; store next arg in frame
+    movsd xmm0, [rbp-8] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    lea rax, [rel _18] ; point to string literal
+    push rax ; stack arg
+    mov r13, rsp ; printf args in order
+    mov r15, rsp ; restore point after printf
+    and rsp, -16 ; align stack for variadic call
+    mov rdi, [r13+0] ; printf arg 0
+    movsd xmm0, [r13+8] ; printf arg 1
+    mov eax, 1 ; vector registers holding args
+    call printf ; invoke libc printf
+    mov rsp, r15
+    add rsp, 16 ; pop stack args after libc call
+    mov r12, [rbp-16] ; load continuation env_end pointer
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+global _17_describe_unwrapper
+_17_describe_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-16] ; load y env field
+    mov [rbp-16], rax ; store value
+    mov rax, [r12-8] ; load next env field
+    mov [rbp-24], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    mov rax, [rbp-24] ; load operand
+    push rax ; stack arg
+    movsd xmm0, [rbp-16] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    leave ; unwind before named jump
+    jmp _17_describe
+global _17_describe_deep_release
+_17_describe_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load __num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _17_describe_release_skip_1
+    mov rax, [r12-8] ; load _17_describe_release_field_1 env field
+    mov [rbp-24], rax ; store value
+    mov rdi, [rbp-24] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _17_describe_release_field_1 release helper
+    call rax ; release _17_describe_release_field_1 and what it holds
+    pop rbx
+    pop r12
+_17_describe_release_skip_1:
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _17_describe_deepcopy
+_17_describe_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _17_describe_deepcopy_skip_1
+    mov rcx, [r12-8] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call deepcopy_heap_ptr ; duplicate heap pointer
+    mov [r12-8], rax ; store duplicated pointer
+    mov [rbp-24], rax ; store value
+_17_describe_deepcopy_skip_1:
+    leave
+    ret
+
+global _11_describe
+_11_describe:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store x arg in frame
+    mov [rbp-16], rsi ; store y arg in frame
+    mov [rbp-24], rdx ; store next arg in frame
+    mov rbx, [rbp-24] ; original closure next to __next_copy_0 env_end pointer for clone
+    mov rbx, rbx ; clone source env_end pointer
+    mov r13, [rbx+24] ; load env size metadata for clone
+    mov r14, [rbx+32] ; load heap size metadata for clone
+    mov r12, rbx ; compute env base pointer for clone
+    sub r12, r13 ; env base pointer for clone source
+    mov rdi, r14 ; length for cloned environment
+    call alloc_heap_ptr ; allocate cloned env block
+    mov r15, rax ; cloned closure env base pointer
+    mov rsi, r12 ; source env base for clone copy
+    mov rdi, r15 ; destination env base for clone copy
+    mov rcx, r14 ; bytes to copy for cloned env
+    cld ; ensure forward copy for env clone
+    rep movsb ; duplicate closure env data
+    mov rbx, r15 ; start from cloned env base
+    add rbx, r13 ; compute cloned env_end pointer
+    mov r12, rbx ; cloned env_end pointer
+    mov rax, [r12+16] ; load deepcopy helper entry point
+    push r12 ; preserve cloned env_end pointer
+    mov rdi, r12 ; pass env_end pointer to deepcopy helper
+    call rax ; deepcopy reference fields
+    pop r12 ; restore cloned env_end pointer
+    mov rax, r12 ; copy cloned env_end pointer
+    mov [rbp-32], rax ; store value
+    mov rdi, 72 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    movsd xmm0, [rbp-8] ; load float operand
+    movq rax, xmm0
+    mov [rbx+0], rax ; capture arg into env
+    movsd xmm0, [rbp-16] ; load float operand
+    movq rax, xmm0
+    mov [rbx+8], rax ; capture arg into env
+    mov rax, [rbp-32] ; load operand
+    mov [rbx+16], rax ; capture arg into env
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 24 ; move pointer past env payload
+    mov rax, 24 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 72 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_13_describe_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_13_describe_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_13_describe_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 0 ; store num_remaining
+    mov rax, r12 ; copy _15_describe closure env_end to rax
+    mov [rbp-40], rax ; store value
+    mov rdi, 64 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    movsd xmm0, [rbp-16] ; load float operand
+    movq rax, xmm0
+    mov [rbx+0], rax ; capture arg into env
+    mov rax, [rbp-24] ; load operand
+    mov [rbx+8], rax ; capture arg into env
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 16 ; move pointer past env payload
+    mov rax, 16 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 64 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_17_describe_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_17_describe_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_17_describe_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 0 ; store num_remaining
+    mov rax, r12 ; copy _19_describe closure env_end to rax
+    mov [rbp-48], rax ; store value
+    movsd xmm0, [rbp-8] ; load float operand
+    movsd xmm1, [rbp-16] ; load float operand
+    ucomisd xmm0, xmm1
+    jp _11_describe_eqf64_skip_0 ; NaN is equal to nothing
+    je eqf64__15_describe_true_0_0
+_11_describe_eqf64_skip_0:
+eqf64__19_describe_false_0_0:
+    mov rdi, [rbp-40] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _15_describe release helper
+    call rax ; release _15_describe and what it holds
+    pop rbx
+    pop r12
+    mov rbx, [rbp-48] ; load _19_describe closure env_end pointer
+    mov rdi, rbx ; pass env_end pointer to closure
+    mov rax, [rdi+0] ; load closure unwrapper entry point
+    leave ; unwind before jumping
+    jmp rax ; tail call into closure
+eqf64__15_describe_true_0_0:
+    mov rdi, [rbp-48] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _19_describe release helper
+    call rax ; release _19_describe and what it holds
+    pop rbx
+    pop r12
+    mov rbx, [rbp-40] ; load _15_describe closure env_end pointer
+    mov rdi, rbx ; pass env_end pointer to closure
+    mov rax, [rdi+0] ; load closure unwrapper entry point
+    leave ; unwind before jumping
+    jmp rax ; tail call into closure
+global _11_describe_unwrapper
+_11_describe_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-24] ; load x env field
+    mov [rbp-16], rax ; store value
+    mov rax, [r12-16] ; load y env field
+    mov [rbp-24], rax ; store value
+    mov rax, [r12-8] ; load next env field
+    mov [rbp-32], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    mov rax, [rbp-32] ; load operand
+    push rax ; stack arg
+    movsd xmm0, [rbp-24] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    movsd xmm0, [rbp-16] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    pop rdx ; restore arg into register
+    leave ; unwind before named jump
+    jmp _11_describe
+global _11_describe_deep_release
+_11_describe_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load __num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _11_describe_release_skip_2
+    mov rax, [r12-8] ; load _11_describe_release_field_2 env field
+    mov [rbp-24], rax ; store value
+    mov rdi, [rbp-24] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _11_describe_release_field_2 release helper
+    call rax ; release _11_describe_release_field_2 and what it holds
+    pop rbx
+    pop r12
+_11_describe_release_skip_2:
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _11_describe_deepcopy
+_11_describe_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _11_describe_deepcopy_skip_2
+    mov rcx, [r12-8] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call deepcopy_heap_ptr ; duplicate heap pointer
+    mov [r12-8], rax ; store duplicated pointer
+    mov [rbp-24], rax ; store value
+_11_describe_deepcopy_skip_2:
+    leave
+    ret
+
+global _5_describe
+_5_describe:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store x arg in frame
+    mov [rbp-16], rsi ; store y arg in frame
+    mov [rbp-24], rdx ; store next arg in frame
+    mov rbx, [rbp-24] ; original closure next to __next_copy_0 env_end pointer for clone
+    mov rbx, rbx ; clone source env_end pointer
+    mov r13, [rbx+24] ; load env size metadata for clone
+    mov r14, [rbx+32] ; load heap size metadata for clone
+    mov r12, rbx ; compute env base pointer for clone
+    sub r12, r13 ; env base pointer for clone source
+    mov rdi, r14 ; length for cloned environment
+    call alloc_heap_ptr ; allocate cloned env block
+    mov r15, rax ; cloned closure env base pointer
+    mov rsi, r12 ; source env base for clone copy
+    mov rdi, r15 ; destination env base for clone copy
+    mov rcx, r14 ; bytes to copy for cloned env
+    cld ; ensure forward copy for env clone
+    rep movsb ; duplicate closure env data
+    mov rbx, r15 ; start from cloned env base
+    add rbx, r13 ; compute cloned env_end pointer
+    mov r12, rbx ; cloned env_end pointer
+    mov rax, [r12+16] ; load deepcopy helper entry point
+    push r12 ; preserve cloned env_end pointer
+    mov rdi, r12 ; pass env_end pointer to deepcopy helper
+    call rax ; deepcopy reference fields
+    pop r12 ; restore cloned env_end pointer
+    mov rax, r12 ; copy cloned env_end pointer
+    mov [rbp-32], rax ; store value
+    mov rdi, 72 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    movsd xmm0, [rbp-8] ; load float operand
+    movq rax, xmm0
+    mov [rbx+0], rax ; capture arg into env
+    movsd xmm0, [rbp-16] ; load float operand
+    movq rax, xmm0
+    mov [rbx+8], rax ; capture arg into env
+    mov rax, [rbp-32] ; load operand
+    mov [rbx+16], rax ; capture arg into env
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 24 ; move pointer past env payload
+    mov rax, 24 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 72 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_7_describe_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_7_describe_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_7_describe_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 0 ; store num_remaining
+    mov rax, r12 ; copy _9_describe closure env_end to rax
+    mov [rbp-40], rax ; store value
+    mov rdi, 72 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    movsd xmm0, [rbp-8] ; load float operand
+    movq rax, xmm0
+    mov [rbx+0], rax ; capture arg into env
+    movsd xmm0, [rbp-16] ; load float operand
+    movq rax, xmm0
+    mov [rbx+8], rax ; capture arg into env
+    mov rax, [rbp-24] ; load operand
+    mov [rbx+16], rax ; capture arg into env
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 24 ; move pointer past env payload
+    mov rax, 24 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 72 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_11_describe_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_11_describe_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_11_describe_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 0 ; store num_remaining
+    mov rax, r12 ; copy _20_describe closure env_end to rax
+    mov [rbp-48], rax ; store value
+    movsd xmm0, [rbp-8] ; load float operand
+    movsd xmm1, [rbp-16] ; load float operand
+    ucomisd xmm0, xmm1
+    ja gtf64__9_describe_true_0_0
+gtf64__20_describe_false_0_0:
+    mov rdi, [rbp-40] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _9_describe release helper
+    call rax ; release _9_describe and what it holds
+    pop rbx
+    pop r12
+    mov rbx, [rbp-48] ; load _20_describe closure env_end pointer
+    mov rdi, rbx ; pass env_end pointer to closure
+    mov rax, [rdi+0] ; load closure unwrapper entry point
+    leave ; unwind before jumping
+    jmp rax ; tail call into closure
+gtf64__9_describe_true_0_0:
+    mov rdi, [rbp-48] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _20_describe release helper
+    call rax ; release _20_describe and what it holds
+    pop rbx
+    pop r12
+    mov rbx, [rbp-40] ; load _9_describe closure env_end pointer
+    mov rdi, rbx ; pass env_end pointer to closure
+    mov rax, [rdi+0] ; load closure unwrapper entry point
+    leave ; unwind before jumping
+    jmp rax ; tail call into closure
+global _5_describe_unwrapper
+_5_describe_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-24] ; load x env field
+    mov [rbp-16], rax ; store value
+    mov rax, [r12-16] ; load y env field
+    mov [rbp-24], rax ; store value
+    mov rax, [r12-8] ; load next env field
+    mov [rbp-32], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    mov rax, [rbp-32] ; load operand
+    push rax ; stack arg
+    movsd xmm0, [rbp-24] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    movsd xmm0, [rbp-16] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    pop rdx ; restore arg into register
+    leave ; unwind before named jump
+    jmp _5_describe
+global _5_describe_deep_release
+_5_describe_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load __num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _5_describe_release_skip_2
+    mov rax, [r12-8] ; load _5_describe_release_field_2 env field
+    mov [rbp-24], rax ; store value
+    mov rdi, [rbp-24] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _5_describe_release_field_2 release helper
+    call rax ; release _5_describe_release_field_2 and what it holds
+    pop rbx
+    pop r12
+_5_describe_release_skip_2:
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _5_describe_deepcopy
+_5_describe_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg _5_describe_deepcopy_skip_2
+    mov rcx, [r12-8] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call deepcopy_heap_ptr ; duplicate heap pointer
+    mov [r12-8], rax ; store duplicated pointer
+    mov [rbp-24], rax ; store value
+_5_describe_deepcopy_skip_2:
+    leave
+    ret
+
+global describe
+describe:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store x arg in frame
+    mov [rbp-16], rsi ; store y arg in frame
+    mov [rbp-24], rdx ; store next arg in frame
+    mov rbx, [rbp-24] ; original closure next to __next_copy_0 env_end pointer for clone
+    mov rbx, rbx ; clone source env_end pointer
+    mov r13, [rbx+24] ; load env size metadata for clone
+    mov r14, [rbx+32] ; load heap size metadata for clone
+    mov r12, rbx ; compute env base pointer for clone
+    sub r12, r13 ; env base pointer for clone source
+    mov rdi, r14 ; length for cloned environment
+    call alloc_heap_ptr ; allocate cloned env block
+    mov r15, rax ; cloned closure env base pointer
+    mov rsi, r12 ; source env base for clone copy
+    mov rdi, r15 ; destination env base for clone copy
+    mov rcx, r14 ; bytes to copy for cloned env
+    cld ; ensure forward copy for env clone
+    rep movsb ; duplicate closure env data
+    mov rbx, r15 ; start from cloned env base
+    add rbx, r13 ; compute cloned env_end pointer
+    mov r12, rbx ; cloned env_end pointer
+    mov rax, [r12+16] ; load deepcopy helper entry point
+    push r12 ; preserve cloned env_end pointer
+    mov rdi, r12 ; pass env_end pointer to deepcopy helper
+    call rax ; deepcopy reference fields
+    pop r12 ; restore cloned env_end pointer
+    mov rax, r12 ; copy cloned env_end pointer
+    mov [rbp-32], rax ; store value
+    mov rdi, 72 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    movsd xmm0, [rbp-8] ; load float operand
+    movq rax, xmm0
+    mov [rbx+0], rax ; capture arg into env
+    movsd xmm0, [rbp-16] ; load float operand
+    movq rax, xmm0
+    mov [rbx+8], rax ; capture arg into env
+    mov rax, [rbp-32] ; load operand
+    mov [rbx+16], rax ; capture arg into env
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 24 ; move pointer past env payload
+    mov rax, 24 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 72 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_1_describe_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_1_describe_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_1_describe_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 0 ; store num_remaining
+    mov rax, r12 ; copy _3_describe closure env_end to rax
+    mov [rbp-40], rax ; store value
+    mov rdi, 72 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    movsd xmm0, [rbp-8] ; load float operand
+    movq rax, xmm0
+    mov [rbx+0], rax ; capture arg into env
+    movsd xmm0, [rbp-16] ; load float operand
+    movq rax, xmm0
+    mov [rbx+8], rax ; capture arg into env
+    mov rax, [rbp-24] ; load operand
+    mov [rbx+16], rax ; capture arg into env
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 24 ; move pointer past env payload
+    mov rax, 24 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 72 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_5_describe_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_5_describe_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_5_describe_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 0 ; store num_remaining
+    mov rax, r12 ; copy _21_describe closure env_end to rax
+    mov [rbp-48], rax ; store value
+    movsd xmm0, [rbp-8] ; load float operand
+    movsd xmm1, [rbp-16] ; load float operand
+    ucomisd xmm1, xmm0
+    ja ltf64__3_describe_true_0_0
+ltf64__21_describe_false_0_0:
+    mov rdi, [rbp-40] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _3_describe release helper
+    call rax ; release _3_describe and what it holds
+    pop rbx
+    pop r12
+    mov rbx, [rbp-48] ; load _21_describe closure env_end pointer
+    mov rdi, rbx ; pass env_end pointer to closure
+    mov rax, [rdi+0] ; load closure unwrapper entry point
+    leave ; unwind before jumping
+    jmp rax ; tail call into closure
+ltf64__3_describe_true_0_0:
+    mov rdi, [rbp-48] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load _21_describe release helper
+    call rax ; release _21_describe and what it holds
+    pop rbx
+    pop r12
+    mov rbx, [rbp-40] ; load _3_describe closure env_end pointer
+    mov rdi, rbx ; pass env_end pointer to closure
+    mov rax, [rdi+0] ; load closure unwrapper entry point
+    leave ; unwind before jumping
+    jmp rax ; tail call into closure
+global describe_unwrapper
+describe_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-24] ; load x env field
+    mov [rbp-16], rax ; store value
+    mov rax, [r12-16] ; load y env field
+    mov [rbp-24], rax ; store value
+    mov rax, [r12-8] ; load next env field
+    mov [rbp-32], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    mov rax, [rbp-32] ; load operand
+    push rax ; stack arg
+    movsd xmm0, [rbp-24] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    movsd xmm0, [rbp-16] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    pop rdx ; restore arg into register
+    leave ; unwind before named jump
+    jmp describe
+global describe_deep_release
+describe_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load __num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg describe_release_skip_2
+    mov rax, [r12-8] ; load describe_release_field_2 env field
+    mov [rbp-24], rax ; store value
+    mov rdi, [rbp-24] ; load operand
+    push r12 ; keep the pinned env across the helper
+    push rbx ; and a kept result
+    mov rax, [rdi+8] ; load describe_release_field_2 release helper
+    call rax ; release describe_release_field_2 and what it holds
+    pop rbx
+    pop r12
+describe_release_skip_2:
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global describe_deepcopy
+describe_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12+40] ; load num_remaining env field
+    mov [rbp-16], rax ; store value
+    mov rax, [rbp-16] ; load operand
+    mov rbx, 0 ; operand literal
+    cmp rax, rbx
+    jg describe_deepcopy_skip_2
+    mov rcx, [r12-8] ; load field pointer
+    mov rdi, rcx ; copy pointer argument for deepcopy
+    call deepcopy_heap_ptr ; duplicate heap pointer
+    mov [r12-8], rax ; store duplicated pointer
+    mov [rbp-24], rax ; store value
+describe_deepcopy_skip_2:
+    leave
+    ret
+
+global _58_main
+_58_main:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store nan arg in frame
+    mov [rbp-16], rsi ; store diff arg in frame
+    mov [rbp-24], rdx ; store huge arg in frame
+    mov rdi, 72 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    movsd xmm0, [rbp-16] ; load float operand
+    movq rax, xmm0
+    mov [rbx+0], rax ; capture arg into env
+    movsd xmm0, [rbp-8] ; load float operand
+    movq rax, xmm0
+    mov [rbx+8], rax ; capture arg into env
+    movsd xmm0, [rbp-24] ; load float operand
+    movq rax, xmm0
+    mov [rbx+16], rax ; capture arg into env
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 24 ; move pointer past env payload
+    mov rax, 24 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 72 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_61_main_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_61_main_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_61_main_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 0 ; store num_remaining
+    mov rax, r12 ; copy _137_main closure env_end to rax
+    mov [rbp-32], rax ; store value
+    mov rax, [rbp-32] ; load operand
+    push rax ; stack arg
+    mov rax, 0x3ff0000000000000 ; load literal float bits
+    push rax ; stack arg
+    movsd xmm0, [rbp-8] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    pop rdx ; restore arg into register
+    leave ; unwind before named jump
+    jmp describe
+global _58_main_unwrapper
+_58_main_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-24] ; load nan env field
+    mov [rbp-16], rax ; store value
+    mov rax, [r12-16] ; load diff env field
+    mov [rbp-24], rax ; store value
+    mov rax, [r12-8] ; load huge env field
+    mov [rbp-32], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    movsd xmm0, [rbp-32] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    movsd xmm0, [rbp-24] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    movsd xmm0, [rbp-16] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    pop rdx ; restore arg into register
+    leave ; unwind before named jump
+    jmp _58_main
+global _58_main_deep_release
+_58_main_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _58_main_deepcopy
+_58_main_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    leave
+    ret
+
+global _55_main
+_55_main:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store neg arg in frame
+    mov [rbp-16], rsi ; store nan arg in frame
+    mov [rbp-24], rdx ; store diff arg in frame
+    mov [rbp-32], rcx ; store huge arg in frame
+    mov rdi, 72 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    movsd xmm0, [rbp-16] ; load float operand
+    movq rax, xmm0
+    mov [rbx+0], rax ; capture arg into env
+    movsd xmm0, [rbp-24] ; load float operand
+    movq rax, xmm0
+    mov [rbx+8], rax ; capture arg into env
+    movsd xmm0, [rbp-32] ; load float operand
+    movq rax, xmm0
+    mov [rbx+16], rax ; capture arg into env
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 24 ; move pointer past env payload
+    mov rax, 24 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 72 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_58_main_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_58_main_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_58_main_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 0 ; store num_remaining
+    mov rax, r12 ; copy _138_main closure env_end to rax
+    mov [rbp-40], rax ; store value
+    mov rax, [rbp-40] ; load operand
+    push rax ; stack arg
+    mov rax, 0x4006000000000000 ; load literal float bits
+    push rax ; stack arg
+    movsd xmm0, [rbp-8] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    pop rdx ; restore arg into register
+    leave ; unwind before named jump
+    jmp describe
+global _55_main_unwrapper
+_55_main_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-32] ; load neg env field
+    mov [rbp-16], rax ; store value
+    mov rax, [r12-24] ; load nan env field
+    mov [rbp-24], rax ; store value
+    mov rax, [r12-16] ; load diff env field
+    mov [rbp-32], rax ; store value
+    mov rax, [r12-8] ; load huge env field
+    mov [rbp-40], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    movsd xmm0, [rbp-40] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    movsd xmm0, [rbp-32] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    movsd xmm0, [rbp-24] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    movsd xmm0, [rbp-16] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    pop rdx ; restore arg into register
+    pop rcx ; restore arg into register
+    leave ; unwind before named jump
+    jmp _55_main
+global _55_main_deep_release
+_55_main_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _55_main_deepcopy
+_55_main_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    leave
+    ret
+
+global _51_main
+_51_main:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store neg arg in frame
+    mov [rbp-16], rsi ; store nan arg in frame
+    mov [rbp-24], rdx ; store diff arg in frame
+    mov [rbp-32], rcx ; store huge arg in frame
+    mov rdi, 80 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    movsd xmm0, [rbp-8] ; load float operand
+    movq rax, xmm0
+    mov [rbx+0], rax ; capture arg into env
+    movsd xmm0, [rbp-16] ; load float operand
+    movq rax, xmm0
+    mov [rbx+8], rax ; capture arg into env
+    movsd xmm0, [rbp-24] ; load float operand
+    movq rax, xmm0
+    mov [rbx+16], rax ; capture arg into env
+    movsd xmm0, [rbp-32] ; load float operand
+    movq rax, xmm0
+    mov [rbx+24], rax ; capture arg into env
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 32 ; move pointer past env payload
+    mov rax, 32 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 80 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_55_main_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_55_main_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_55_main_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 0 ; store num_remaining
+    mov rax, r12 ; copy _139_main closure env_end to rax
+    mov [rbp-40], rax ; store value
+    mov rax, [rbp-40] ; load operand
+    push rax ; stack arg
+    mov rax, 0x3ff0000000000000 ; load literal float bits
+    push rax ; stack arg
+    mov rax, 0x4008000000000000 ; load literal float bits
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    pop rdx ; restore arg into register
+    leave ; unwind before named jump
+    jmp describe
+global _51_main_unwrapper
+_51_main_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-32] ; load neg env field
+    mov [rbp-16], rax ; store value
+    mov rax, [r12-24] ; load nan env field
+    mov [rbp-24], rax ; store value
+    mov rax, [r12-16] ; load diff env field
+    mov [rbp-32], rax ; store value
+    mov rax, [r12-8] ; load huge env field
+    mov [rbp-40], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    movsd xmm0, [rbp-40] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    movsd xmm0, [rbp-32] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    movsd xmm0, [rbp-24] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    movsd xmm0, [rbp-16] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    pop rdx ; restore arg into register
+    pop rcx ; restore arg into register
+    leave ; unwind before named jump
+    jmp _51_main
+global _51_main_deep_release
+_51_main_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _51_main_deepcopy
+_51_main_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    leave
+    ret
+
+global _47_main
+_47_main:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store neg arg in frame
+    mov [rbp-16], rsi ; store nan arg in frame
+    mov [rbp-24], rdx ; store diff arg in frame
+    mov [rbp-32], rcx ; store huge arg in frame
+    mov rdi, 80 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    movsd xmm0, [rbp-8] ; load float operand
+    movq rax, xmm0
+    mov [rbx+0], rax ; capture arg into env
+    movsd xmm0, [rbp-16] ; load float operand
+    movq rax, xmm0
+    mov [rbx+8], rax ; capture arg into env
+    movsd xmm0, [rbp-24] ; load float operand
+    movq rax, xmm0
+    mov [rbx+16], rax ; capture arg into env
+    movsd xmm0, [rbp-32] ; load float operand
+    movq rax, xmm0
+    mov [rbx+24], rax ; capture arg into env
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 32 ; move pointer past env payload
+    mov rax, 32 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 80 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_51_main_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_51_main_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_51_main_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 0 ; store num_remaining
+    mov rax, r12 ; copy _140_main closure env_end to rax
+    mov [rbp-40], rax ; store value
+    mov rax, [rbp-40] ; load operand
+    push rax ; stack arg
+    mov rax, 0x4004000000000000 ; load literal float bits
+    push rax ; stack arg
+    mov rax, 0x3ff8000000000000 ; load literal float bits
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    pop rdx ; restore arg into register
+    leave ; unwind before named jump
+    jmp describe
+global _47_main_unwrapper
+_47_main_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-32] ; load neg env field
+    mov [rbp-16], rax ; store value
+    mov rax, [r12-24] ; load nan env field
+    mov [rbp-24], rax ; store value
+    mov rax, [r12-16] ; load diff env field
+    mov [rbp-32], rax ; store value
+    mov rax, [r12-8] ; load huge env field
+    mov [rbp-40], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    movsd xmm0, [rbp-40] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    movsd xmm0, [rbp-32] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    movsd xmm0, [rbp-24] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    movsd xmm0, [rbp-16] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    pop rdx ; restore arg into register
+    pop rcx ; restore arg into register
+    leave ; unwind before named jump
+    jmp _47_main
+global _47_main_deep_release
+_47_main_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _47_main_deepcopy
+_47_main_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    leave
+    ret
+
+global _41_main
+_41_main:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 64 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store diff arg in frame
+    mov [rbp-16], rsi ; store neg arg in frame
+    mov [rbp-24], rdx ; store root arg in frame
+    mov [rbp-32], rcx ; store big arg in frame
+    mov [rbp-40], r8 ; store nan arg in frame
+    mov [rbp-48], r9 ; store huge arg in frame
+    mov rdi, 80 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    movsd xmm0, [rbp-16] ; load float operand
+    movq rax, xmm0
+    mov [rbx+0], rax ; capture arg into env
+    movsd xmm0, [rbp-40] ; load float operand
+    movq rax, xmm0
+    mov [rbx+8], rax ; capture arg into env
+    movsd xmm0, [rbp-8] ; load float operand
+    movq rax, xmm0
+    mov [rbx+16], rax ; capture arg into env
+    movsd xmm0, [rbp-48] ; load float operand
+    movq rax, xmm0
+    mov [rbx+24], rax ; capture arg into env
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 32 ; move pointer past env payload
+    mov rax, 32 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 80 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_47_main_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_47_main_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_47_main_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 0 ; store num_remaining
+    mov rax, r12 ; copy _141_main closure env_end to rax
+    mov [rbp-56], rax ; store value
+    mov rax, 0x3fe0000000000000 ; load literal float bits
+    push rax ; stack arg
+    lea rax, [rel _44] ; point to string literal
+    push rax ; stack arg
+    mov rax, 42 ; operand literal
+    push rax ; stack arg
+    movsd xmm0, [rbp-32] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    movsd xmm0, [rbp-24] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    movsd xmm0, [rbp-16] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    movsd xmm0, [rbp-8] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    lea rax, [rel _42] ; point to string literal
+    push rax ; stack arg
+    mov r13, rsp ; printf args in order
+    mov r15, rsp ; restore point after printf
+    and rsp, -16 ; align stack for variadic call
+    mov rdi, [r13+0] ; printf arg 0
+    movsd xmm0, [r13+8] ; printf arg 1
+    movsd xmm1, [r13+16] ; printf arg 2
+    movsd xmm2, [r13+24] ; printf arg 3
+    movsd xmm3, [r13+32] ; printf arg 4
+    mov rsi, [r13+40] ; printf arg 5
+    mov rdx, [r13+48] ; printf arg 6
+    movsd xmm4, [r13+56] ; printf arg 7
+    mov eax, 5 ; vector registers holding args
+    call printf ; invoke libc printf
+    mov rsp, r15
+    add rsp, 64 ; pop stack args after libc call
+    mov r12, [rbp-56] ; load continuation env_end pointer
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+global _41_main_unwrapper
+_41_main_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 64 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-48] ; load diff env field
+    mov [rbp-16], rax ; store value
+    mov rax, [r12-40] ; load neg env field
+    mov [rbp-24], rax ; store value
+    mov rax, [r12-32] ; load root env field
+    mov [rbp-32], rax ; store value
+    mov rax, [r12-24] ; load big env field
+    mov [rbp-40], rax ; store value
+    mov rax, [r12-16] ; load nan env field
+    mov [rbp-48], rax ; store value
+    mov rax, [r12-8] ; load huge env field
+    mov [rbp-56], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    movsd xmm0, [rbp-56] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    movsd xmm0, [rbp-48] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    movsd xmm0, [rbp-40] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    movsd xmm0, [rbp-32] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    movsd xmm0, [rbp-24] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    movsd xmm0, [rbp-16] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    pop rdx ; restore arg into register
+    pop rcx ; restore arg into register
+    pop r8 ; restore arg into register
+    pop r9 ; restore arg into register
+    leave ; unwind before named jump
+    jmp _41_main
+global _41_main_deep_release
+_41_main_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _41_main_deepcopy
+_41_main_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    leave
+    ret
+
+global _37_main
+_37_main:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store diff arg in frame
+    mov [rbp-16], rsi ; store neg arg in frame
+    mov [rbp-24], rdx ; store root arg in frame
+    mov [rbp-32], rcx ; store big arg in frame
+    mov [rbp-40], r8 ; store nan arg in frame
+    mov rdi, 96 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    movsd xmm0, [rbp-8] ; load float operand
+    movq rax, xmm0
+    mov [rbx+0], rax ; capture arg into env
+    movsd xmm0, [rbp-16] ; load float operand
+    movq rax, xmm0
+    mov [rbx+8], rax ; capture arg into env
+    movsd xmm0, [rbp-24] ; load float operand
+    movq rax, xmm0
+    mov [rbx+16], rax ; capture arg into env
+    movsd xmm0, [rbp-32] ; load float operand
+    movq rax, xmm0
+    mov [rbx+24], rax ; capture arg into env
+    movsd xmm0, [rbp-40] ; load float operand
+    movq rax, xmm0
+    mov [rbx+32], rax ; capture arg into env
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 48 ; move pointer past env payload
+    mov rax, 48 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 96 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_41_main_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_41_main_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_41_main_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 1 ; store num_remaining
+    mov rax, r12 ; copy _142_main closure env_end to rax
+    mov [rbp-48], rax ; store value
+    mov rax, 0x4202a05f20000000 ; load literal float bits
+    movq xmm0, rax ; load float literal
+    mov rax, 0x4202a05f20000000 ; load literal float bits
+    movq xmm1, rax ; load float literal
+    mulsd xmm0, xmm1 ; multiply by multiplier float
+    movq rax, xmm0 ; move float result to rax
+    mov r12, [rbp-48] ; load continuation env_end pointer
+    mov [r12-8], rax ; store env field
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+global _37_main_unwrapper
+_37_main_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-40] ; load diff env field
+    mov [rbp-16], rax ; store value
+    mov rax, [r12-32] ; load neg env field
+    mov [rbp-24], rax ; store value
+    mov rax, [r12-24] ; load root env field
+    mov [rbp-32], rax ; store value
+    mov rax, [r12-16] ; load big env field
+    mov [rbp-40], rax ; store value
+    mov rax, [r12-8] ; load nan env field
+    mov [rbp-48], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    movsd xmm0, [rbp-48] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    movsd xmm0, [rbp-40] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    movsd xmm0, [rbp-32] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    movsd xmm0, [rbp-24] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    movsd xmm0, [rbp-16] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    pop rdx ; restore arg into register
+    pop rcx ; restore arg into register
+    pop r8 ; restore arg into register
+    leave ; unwind before named jump
+    jmp _37_main
+global _37_main_deep_release
+_37_main_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _37_main_deepcopy
+_37_main_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    leave
+    ret
+
+global _33_main
+_33_main:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store diff arg in frame
+    mov [rbp-16], rsi ; store neg arg in frame
+    mov [rbp-24], rdx ; store root arg in frame
+    mov [rbp-32], rcx ; store big arg in frame
+    mov rdi, 88 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    movsd xmm0, [rbp-8] ; load float operand
+    movq rax, xmm0
+    mov [rbx+0], rax ; capture arg into env
+    movsd xmm0, [rbp-16] ; load float operand
+    movq rax, xmm0
+    mov [rbx+8], rax ; capture arg into env
+    movsd xmm0, [rbp-24] ; load float operand
+    movq rax, xmm0
+    mov [rbx+16], rax ; capture arg into env
+    movsd xmm0, [rbp-32] ; load float operand
+    movq rax, xmm0
+    mov [rbx+24], rax ; capture arg into env
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 40 ; move pointer past env payload
+    mov rax, 40 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 88 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_37_main_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_37_main_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_37_main_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 1 ; store num_remaining
+    mov rax, r12 ; copy _143_main closure env_end to rax
+    mov [rbp-40], rax ; store value
+    mov rax, 0x0 ; load literal float bits
+    movq xmm0, rax ; load float literal
+    mov rax, 0x0 ; load literal float bits
+    movq xmm1, rax ; load float literal
+    divsd xmm0, xmm1 ; divide by divisor float
+    movq rax, xmm0 ; move float result to rax
+    mov r12, [rbp-40] ; load continuation env_end pointer
+    mov [r12-8], rax ; store env field
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+global _33_main_unwrapper
+_33_main_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 48 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-32] ; load diff env field
+    mov [rbp-16], rax ; store value
+    mov rax, [r12-24] ; load neg env field
+    mov [rbp-24], rax ; store value
+    mov rax, [r12-16] ; load root env field
+    mov [rbp-32], rax ; store value
+    mov rax, [r12-8] ; load big env field
+    mov [rbp-40], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    movsd xmm0, [rbp-40] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    movsd xmm0, [rbp-32] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    movsd xmm0, [rbp-24] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    movsd xmm0, [rbp-16] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    pop rdx ; restore arg into register
+    pop rcx ; restore arg into register
+    leave ; unwind before named jump
+    jmp _33_main
+global _33_main_deep_release
+_33_main_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _33_main_deepcopy
+_33_main_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    leave
+    ret
+
+global _30_main
+_30_main:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store diff arg in frame
+    mov [rbp-16], rsi ; store neg arg in frame
+    mov [rbp-24], rdx ; store root arg in frame
+    mov rdi, 80 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    movsd xmm0, [rbp-8] ; load float operand
+    movq rax, xmm0
+    mov [rbx+0], rax ; capture arg into env
+    movsd xmm0, [rbp-16] ; load float operand
+    movq rax, xmm0
+    mov [rbx+8], rax ; capture arg into env
+    movsd xmm0, [rbp-24] ; load float operand
+    movq rax, xmm0
+    mov [rbx+16], rax ; capture arg into env
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 32 ; move pointer past env payload
+    mov rax, 32 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 80 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_33_main_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_33_main_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_33_main_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 1 ; store num_remaining
+    mov rax, r12 ; copy _144_main closure env_end to rax
+    mov [rbp-32], rax ; store value
+    mov rax, 123456789 ; operand literal
+    cvtsi2sd xmm0, rax ; convert int to float
+    movq rax, xmm0 ; move float result to rax
+    mov r12, [rbp-32] ; load continuation env_end pointer
+    mov [r12-8], rax ; store env field
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+global _30_main_unwrapper
+_30_main_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-24] ; load diff env field
+    mov [rbp-16], rax ; store value
+    mov rax, [r12-16] ; load neg env field
+    mov [rbp-24], rax ; store value
+    mov rax, [r12-8] ; load root env field
+    mov [rbp-32], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    movsd xmm0, [rbp-32] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    movsd xmm0, [rbp-24] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    movsd xmm0, [rbp-16] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    pop rdx ; restore arg into register
+    leave ; unwind before named jump
+    jmp _30_main
+global _30_main_deep_release
+_30_main_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _30_main_deepcopy
+_30_main_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    leave
+    ret
+
+global _27_main
+_27_main:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store diff arg in frame
+    mov [rbp-16], rsi ; store neg arg in frame
+    mov rdi, 72 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    movsd xmm0, [rbp-8] ; load float operand
+    movq rax, xmm0
+    mov [rbx+0], rax ; capture arg into env
+    movsd xmm0, [rbp-16] ; load float operand
+    movq rax, xmm0
+    mov [rbx+8], rax ; capture arg into env
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 24 ; move pointer past env payload
+    mov rax, 24 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 72 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_30_main_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_30_main_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_30_main_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 1 ; store num_remaining
+    mov rax, r12 ; copy _145_main closure env_end to rax
+    mov [rbp-24], rax ; store value
+    mov rax, 0x4000000000000000 ; load literal float bits
+    movq xmm0, rax ; load float literal
+    sqrtsd xmm0, xmm0 ; square root
+    movq rax, xmm0 ; move float result to rax
+    mov r12, [rbp-24] ; load continuation env_end pointer
+    mov [r12-8], rax ; store env field
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+global _27_main_unwrapper
+_27_main_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 32 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-16] ; load diff env field
+    mov [rbp-16], rax ; store value
+    mov rax, [r12-8] ; load neg env field
+    mov [rbp-24], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    movsd xmm0, [rbp-24] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    movsd xmm0, [rbp-16] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    pop rsi ; restore arg into register
+    leave ; unwind before named jump
+    jmp _27_main
+global _27_main_deep_release
+_27_main_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _27_main_deepcopy
+_27_main_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    leave
+    ret
+
+global _25_main
+_25_main:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store diff arg in frame
+    mov rdi, 64 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    movsd xmm0, [rbp-8] ; load float operand
+    movq rax, xmm0
+    mov [rbx+0], rax ; capture arg into env
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 16 ; move pointer past env payload
+    mov rax, 16 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 64 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_27_main_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_27_main_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_27_main_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 1 ; store num_remaining
+    mov rax, r12 ; copy _146_main closure env_end to rax
+    mov [rbp-16], rax ; store value
+    movsd xmm0, [rbp-8] ; load float operand
+    movq rax, xmm0
+    mov rcx, 0x8000000000000000 ; float sign bit
+    xor rax, rcx ; flip the sign
+    mov r12, [rbp-16] ; load continuation env_end pointer
+    mov [r12-8], rax ; store env field
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+global _25_main_unwrapper
+_25_main_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rax, [r12-8] ; load diff env field
+    mov [rbp-16], rax ; store value
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    movsd xmm0, [rbp-16] ; load float operand
+    movq rax, xmm0
+    push rax ; stack arg
+    pop rdi ; restore arg into register
+    leave ; unwind before named jump
+    jmp _25_main
+global _25_main_deep_release
+_25_main_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global _25_main_deepcopy
+_25_main_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    leave
+    ret
+
+global main
+main:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov rdi, 56 ; length for allocation
+    call alloc_heap_ptr ; allocate env block
+    mov rbx, rax ; closure env base pointer
+    mov r12, rbx ; env_end pointer before metadata
+    add r12, 8 ; move pointer past env payload
+    mov rax, 8 ; store env size metadata
+    mov qword [r12+24], rax ; env size metadata
+    mov rax, 56 ; store heap size metadata
+    mov qword [r12+32], rax ; heap size metadata
+    lea rax, [_25_main_unwrapper] ; load unwrapper entry point
+    mov qword [r12+0], rax ; store unwrapper entry in metadata
+    lea rax, [_25_main_deep_release] ; load release helper entry point
+    mov qword [r12+8], rax ; store release pointer in metadata
+    lea rax, [_25_main_deepcopy] ; load deep copy helper entry point
+    mov qword [r12+16], rax ; store deep copy pointer in metadata
+    mov qword [r12+40], 1 ; store num_remaining
+    mov rax, r12 ; copy _25_main closure env_end to rax
+    mov [rbp-8], rax ; store value
+    mov rax, 0x401e000000000000 ; load literal float bits
+    movq xmm0, rax ; load float literal
+    mov rax, 0x4024800000000000 ; load literal float bits
+    movq xmm1, rax ; load float literal
+    subsd xmm0, xmm1 ; subtract subtrahend float
+    movq rax, xmm0 ; move float result to rax
+    mov r12, [rbp-8] ; load continuation env_end pointer
+    mov [r12-8], rax ; store env field
+    mov rax, [r12+0] ; load continuation entry point
+    mov rdi, r12 ; pass env_end pointer to continuation
+    leave ; unwind before jumping
+    jmp rax
+global main_unwrapper
+main_unwrapper:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave ; unwind before named jump
+    jmp main
+global main_deep_release
+main_deep_release:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    mov rdi, r12 ; use pinned __env_end env_end pointer
+    call release_heap_ptr ; release __env_end closure environment
+    leave
+    ret
+
+global main_deepcopy
+main_deepcopy:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    sub rsp, 16 ; reserve stack space for locals
+    mov [rbp-8], rdi ; store env_end arg in frame
+    mov r12, [rbp-8] ; load operand
+    leave
+    ret
+
+global _start
+_start:
+    push rbp ; save executor frame pointer
+    mov rbp, rsp ; establish new frame base
+    leave ; unwind before named jump
+    jmp main
+extern exit
+extern printf
+extern snprintf
+section .rodata
+    dq 0, 56 ; static strings have no allocation to free
+_98:
+    db "%s", 10, "%.1f %.1f %.1f %.1f %.1f %.1f %.1f %.1f %.1f %.1f %d", 10, 0
+    dq 0, 39 ; static strings have no allocation to free
+_85:
+    db "%d:%.3f:%d:%.3f:%d:%.3f:%d:%.3f:%d:%.3f", 0
+    dq 0, 17 ; static strings have no allocation to free
+_82:
+    db "ftoi(huge) fails", 10, 0
+    dq 0, 15 ; static strings have no allocation to free
+_119:
+    db "unreachable %d", 10, 0
+    dq 0, 16 ; static strings have no allocation to free
+_77:
+    db "ftoi(nan) fails", 10, 0
+    dq 0, 15 ; static strings have no allocation to free
+_129:
+    db "unreachable %d", 10, 0
+    dq 0, 16 ; static strings have no allocation to free
+_72:
+    db "ftoi(%.2f) = %d", 10, 0
+    dq 0, 12 ; static strings have no allocation to free
+_64:
+    db "unreachable", 10, 0
+    dq 0, 12 ; static strings have no allocation to free
+_2:
+    db "%.2f < %.2f", 10, 0
+    dq 0, 12 ; static strings have no allocation to free
+_8:
+    db "%.2f > %.2f", 10, 0
+    dq 0, 13 ; static strings have no allocation to free
+_14:
+    db "%.2f == %.2f", 10, 0
+    dq 0, 20 ; static strings have no allocation to free
+_18:
+    db "unordered with %.2f", 10, 0
+    dq 0, 25 ; static strings have no allocation to free
+_42:
+    db "%f %f %.6f %.1f %d %s %g", 10, 0
+    dq 0, 5 ; static strings have no allocation to free
+_44:
+    db "mixed", 0
@@ -0,0 +1,6024 @@
+.text
+.p2align 2
+.global _121_main
+_121_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    // load exit code
+    mov x0, #0 // operand literal
+    bl exit // call libc exit to flush buffers
+.global alloc_heap_ptr
+alloc_heap_ptr:
+    add x0, x0, #7 // round size up to whole words
+    and x0, x0, #-8
+    cmp x0, #1024 // small block?
+    b.hi alloc_heap_ptr_large
+    adrp x9, heap_free_lists
+    add x9, x9, :lo12:heap_free_lists
+    ldr x10, [x9, x0] // head of this size's free list
+    cbz x10, alloc_heap_ptr_bump
+    ldr x11, [x10] // next free block
+    str x11, [x9, x0] // pop the head
+    mov x0, x10
+    ret
+alloc_heap_ptr_bump:
+    adrp x9, heap_arena
+    add x9, x9, :lo12:heap_arena
+    ldp x10, x11, [x9] // arena next and end
+    add x12, x10, x0 // end of the new block
+    cmp x12, x11
+    b.hi alloc_heap_ptr_refill
+    str x12, [x9] // bump past the block
+    mov x0, x10
+    ret
+alloc_heap_ptr_refill:
+    mov x13, x0 // keep block size across the syscall
+    mov x1, #1048576 // arena size
+    mov x0, #0 // addr hint so kernel picks mmap base
+    mov x2, #3 // prot = read/write
+    mov x3, #34 // flags = private & anonymous
+    mov x4, #-1 // fd = -1
+    mov x5, #0 // offset = 0
+    mov x8, #222 // mmap syscall
+    svc #0 // map a new arena
+    cmn x0, #4095 // mmap failed?
+    b.hs alloc_heap_ptr_failed
+    add x12, x0, x13 // block is the start of the arena
+    mov x11, #1048576
+    add x11, x0, x11 // end of the arena
+    stp x12, x11, [x9]
+    ret
+alloc_heap_ptr_large:
+    mov x1, x0 // length = block size
+    mov x0, #0 // addr hint so kernel picks mmap base
+    mov x2, #3 // prot = read/write
+    mov x3, #34 // flags = private & anonymous
+    mov x4, #-1 // fd = -1
+    mov x5, #0 // offset = 0
+    mov x8, #222 // mmap syscall
+    svc #0 // map a large block on its own
+    cmn x0, #4095 // mmap failed?
+    b.hs alloc_heap_ptr_failed
+    ret
+alloc_heap_ptr_failed:
+    mov x0, #0 // no memory left
+    ret
+.data
+.p2align 3
+heap_free_lists:
+    .zero 1032
+heap_arena:
+    .quad 0, 0
+.text
+.global free_heap_ptr
+free_heap_ptr:
+    add x1, x1, #7 // round size up to whole words
+    and x1, x1, #-8
+    cmp x1, #1024 // small block?
+    b.hi free_heap_ptr_large
+    adrp x9, heap_free_lists
+    add x9, x9, :lo12:heap_free_lists
+    ldr x10, [x9, x1] // current head
+    str x10, [x0] // link it behind the block
+    str x0, [x9, x1] // block is the new head
+    ret
+free_heap_ptr_large:
+    mov x8, #215 // munmap syscall
+    svc #0
+    ret
+.global release_heap_ptr
+release_heap_ptr:
+    stp x29, x30, [sp, #-16]! // save caller frame
+    mov x29, sp // establish frame
+    ldr x11, [x0, #24] // load env size metadata
+    ldr x12, [x0, #32] // load heap size metadata
+    sub x0, x0, x11 // compute env base pointer
+    mov x1, x12 // heap size to free
+    bl free_heap_ptr // return env to the allocator
+    ldp x29, x30, [sp], #16
+    ret
+.global _121_main_unwrapper
+_121_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _121_main
+.global _121_main_deep_release
+_121_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _121_main_deepcopy
+_121_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global release_str_ptr
+release_str_ptr:
+    cbz x0, release_str_ptr_done // nothing to free
+    ldr x1, [x0, #-16] // allocation size header
+    cbz x1, release_str_ptr_done // literals have no allocation
+    sub x0, x0, #16 // block starts at the header
+    b free_heap_ptr // return string to the allocator
+release_str_ptr_done:
+    ret
+.global _119_main
+_119_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store s arg in frame
+    mov x0, #48 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x9, #0 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #48 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _121_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_121_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _121_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_121_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _121_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_121_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _121_main closure env_end to x9
+    stur x9, [x29, #-16] // store value
+    ldur x13, [x29, #-8] // load operand
+    ldr x2, [x13, #-8] // length to write
+    mov x1, x13 // buffer start
+    mov x0, #1 // stdout fd
+    bl write // invoke libc write
+    ldur x0, [x29, #-8] // load operand
+    bl release_str_ptr // release s string
+    ldur x19, [x29, #-16] // load continuation env_end pointer
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _119_main_unwrapper
+_119_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-8] // load s env field
+    stur x9, [x29, #-16] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _119_main
+.global _119_main_deep_release
+_119_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _119_main_release_skip_0
+    ldur x9, [x19, #-8] // load _119_main_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl release_str_ptr // release _119_main_release_field_0 string
+_119_main_release_skip_0:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global copy_str_ptr
+copy_str_ptr:
+    cbz x0, copy_str_ptr_done
+    ldr x1, [x0, #-16] // allocation size header
+    cbz x1, copy_str_ptr_done // literals are never freed
+    stp x29, x30, [sp, #-16]! // save caller frame
+    mov x29, sp // establish frame
+    stp x20, x21, [sp, #-16]! // preserve callee-saved registers
+    sub x20, x0, #16 // source block, header included
+    mov x21, x1 // allocation size
+    mov x0, x1
+    bl alloc_heap_ptr // allocate the copy
+    mov x1, x20 // memcpy src
+    mov x2, x21 // memcpy length
+    mov x20, x0 // copy block
+    bl memcpy_helper // duplicate header and bytes
+    add x0, x20, #16 // copied string starts after its header
+    ldp x20, x21, [sp], #16
+    ldp x29, x30, [sp], #16
+copy_str_ptr_done:
+    ret
+.global memcpy_helper
+memcpy_helper:
+    mov x11, #0 // counter = 0
+internal_memcpy_loop:
+    cmp x11, x2 // counter < count?
+    b.ge internal_memcpy_done
+    ldr x9, [x1, x11] // load 8 bytes from source
+    str x9, [x0, x11] // store 8 bytes to destination
+    add x11, x11, #8 // advance counter by 8
+    b internal_memcpy_loop
+internal_memcpy_done:
+    ret
+.global _119_main_deepcopy
+_119_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _119_main_deepcopy_skip_0
+    ldur x0, [x19, #-8] // load field pointer
+    bl copy_str_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_119_main_deepcopy_skip_0:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global cmp_str_ptr
+cmp_str_ptr:
+    ldr x9, [x0, #-8] // left length
+    ldr x10, [x1, #-8] // right length
+    cmp x9, x10
+    csel x11, x9, x10, ls // bytes both strings have
+cmp_str_ptr_loop:
+    cbz x11, cmp_str_ptr_lengths
+    ldrb w12, [x0], #1
+    ldrb w13, [x1], #1
+    cmp w12, w13
+    b.lo cmp_str_ptr_less
+    b.hi cmp_str_ptr_greater
+    sub x11, x11, #1
+    b cmp_str_ptr_loop
+cmp_str_ptr_lengths:
+    cmp x9, x10 // a prefix sorts first
+    b.lo cmp_str_ptr_less
+    b.hi cmp_str_ptr_greater
+    mov x0, #0
+    ret
+cmp_str_ptr_less:
+    mov x0, #-1
+    ret
+cmp_str_ptr_greater:
+    mov x0, #1
+    ret
+.global release_array_ptr
+release_array_ptr:
+    cbz x0, release_array_ptr_done // nothing to free
+    stp x29, x30, [sp, #-16]! // save caller frame
+    mov x29, sp // establish frame
+    stp x20, x21, [sp, #-16]! // preserve callee-saved registers
+    stp x22, x23, [sp, #-16]!
+    mov x20, x0 // array
+    ldr x21, [x20, #-8] // elements left to release
+    ldr x22, [x20, #-16] // element class
+    cbz x22, release_array_ptr_free // plain elements own nothing
+release_array_ptr_loop:
+    cbz x21, release_array_ptr_free
+    sub x21, x21, #1
+    ldr x0, [x20, x21, lsl #3]
+    mov x1, x22
+    bl release_array_elem_ptr
+    b release_array_ptr_loop
+release_array_ptr_free:
+    ldr x1, [x20, #-8] // length
+    lsl x1, x1, #3
+    add x1, x1, #16 // block size, header included
+    sub x0, x20, #16 // block starts at the header
+    ldp x22, x23, [sp], #16
+    ldp x20, x21, [sp], #16
+    ldp x29, x30, [sp], #16
+    b free_heap_ptr // return the array to the allocator
+release_array_ptr_done:
+    ret
+.global release_array_elem_ptr
+release_array_elem_ptr:
+    cmp x1, #1 // string element?
+    b.eq release_str_ptr
+    cmp x1, #2 // array element?
+    b.eq release_array_ptr
+    ret
+.global copy_array_ptr
+copy_array_ptr:
+    cbz x0, copy_array_ptr_done // nothing to copy
+    stp x29, x30, [sp, #-16]! // save caller frame
+    mov x29, sp // establish frame
+    stp x20, x21, [sp, #-16]! // preserve callee-saved registers
+    stp x22, x23, [sp, #-16]!
+    mov x20, x0 // source array
+    ldr x21, [x20, #-8] // length
+    lsl x21, x21, #3
+    add x21, x21, #16 // block size, header included
+    mov x0, x21
+    bl alloc_heap_ptr // allocate the copy
+    sub x1, x20, #16 // memcpy src, header included
+    mov x2, x21 // memcpy length
+    mov x20, x0 // copy block
+    bl memcpy_helper // duplicate header and elements
+    add x20, x20, #16 // copied array starts after its header
+    ldr x21, [x20, #-8] // elements left to copy
+    ldr x22, [x20, #-16] // element class
+    cbz x22, copy_array_ptr_copied // plain elements are copied already
+copy_array_ptr_loop:
+    cbz x21, copy_array_ptr_copied
+    sub x21, x21, #1
+    ldr x0, [x20, x21, lsl #3] // element shared with the source
+    cmp x22, #1 // string element?
+    b.eq copy_array_ptr_str
+    bl copy_array_ptr
+    b copy_array_ptr_store
+copy_array_ptr_str:
+    bl copy_str_ptr
+copy_array_ptr_store:
+    str x0, [x20, x21, lsl #3] // element owned by the copy
+    b copy_array_ptr_loop
+copy_array_ptr_copied:
+    mov x0, x20
+    ldp x22, x23, [sp], #16
+    ldp x20, x21, [sp], #16
+    ldp x29, x30, [sp], #16
+copy_array_ptr_done:
+    ret
+.global copy_array_elem_ptr
+copy_array_elem_ptr:
+    cmp x1, #1 // string element?
+    b.eq copy_str_ptr
+    cmp x1, #2 // array element?
+    b.eq copy_array_ptr
+    ret // plain elements are copied by value
+.global map_new_ptr
+map_new_ptr:
+    stp x29, x30, [sp, #-16]! // save caller frame
+    mov x29, sp // establish frame
+    stp x20, x21, [sp, #-16]! // preserve callee-saved registers
+    mov x20, x0 // key class
+    mov x21, x1 // value class
+    mov x0, #160 // header, entries and index
+    bl alloc_heap_ptr
+    add x0, x0, #32 // map starts after its header
+    stur x20, [x0, #-32] // key class header
+    stur x21, [x0, #-24] // value class header
+    mov x9, #8
+    stur x9, [x0, #-16] // capacity header
+    stur xzr, [x0, #-8] // no entries yet
+    add x10, x0, #64 // index follows the entries
+map_new_ptr_clear:
+    str xzr, [x10], #8 // every slot starts empty
+    subs x9, x9, #1
+    b.ne map_new_ptr_clear
+    ldp x20, x21, [sp], #16
+    ldp x29, x30, [sp], #16
+    ret
+.global map_hash_ptr
+map_hash_ptr:
+    cmp x1, #1 // string key?
+    b.eq map_hash_ptr_str
+    movz x9, #0x7c15 // golden ratio multiplier
+    movk x9, #0x7f4a, lsl #16
+    movk x9, #0x79b9, lsl #32
+    movk x9, #0x9e37, lsl #48
+    mul x0, x0, x9
+    eor x0, x0, x0, lsr #32 // fold the well mixed high bits down
+    ret
+map_hash_ptr_str:
+    ldur x10, [x0, #-8] // bytes to hash
+    movz x9, #0x2325 // FNV offset basis
+    movk x9, #0x8422, lsl #16
+    movk x9, #0x9ce4, lsl #32
+    movk x9, #0xcbf2, lsl #48
+    movz x11, #0x1b3 // FNV prime
+    movk x11, #0x100, lsl #32
+map_hash_ptr_loop:
+    cbz x10, map_hash_ptr_done
+    ldrb w12, [x0], #1
+    eor x9, x9, x12
+    mul x9, x9, x11
+    sub x10, x10, #1
+    b map_hash_ptr_loop
+map_hash_ptr_done:
+    mov x0, x9
+    ret
+.global map_find_ptr
+map_find_ptr:
+    stp x29, x30, [sp, #-16]! // save caller frame
+    mov x29, sp // establish frame
+    stp x20, x21, [sp, #-16]! // preserve callee-saved registers
+    stp x22, x23, [sp, #-16]! // preserve callee-saved registers
+    stp x24, x25, [sp, #-16]! // preserve callee-saved registers
+    mov x20, x0 // map
+    mov x21, x1 // key
+    mov x0, x21
+    ldur x1, [x20, #-32] // key class
+    bl map_hash_ptr
+    ldur x22, [x20, #-16] // capacity
+    add x24, x20, x22, lsl #3 // index start
+    sub x22, x22, #1 // slot mask
+    and x23, x0, x22 // first slot to probe
+map_find_ptr_probe:
+    add x1, x24, x23, lsl #3 // slot address
+    ldr x0, [x1] // entry position plus one
+    cbz x0, map_find_ptr_done // no entry; x1 is the empty slot
+    sub x0, x0, #1
+    add x0, x20, x0, lsl #4 // entry address
+    ldur x9, [x20, #-32] // key class
+    cmp x9, #1 // string keys?
+    b.eq map_find_ptr_str
+    ldr x9, [x0] // key of the entry
+    cmp x9, x21
+    b.eq map_find_ptr_done
+    b map_find_ptr_next
+map_find_ptr_str:
+    mov x25, x0 // entry
+    ldr x0, [x25] // key of the entry
+    mov x1, x21
+    bl cmp_str_ptr
+    cbz x0, map_find_ptr_found_str // same bytes?
+map_find_ptr_next:
+    add x23, x23, #1
+    and x23, x23, x22 // wrap around the index
+    b map_find_ptr_probe
+map_find_ptr_found_str:
+    mov x0, x25
+    add x1, x24, x23, lsl #3 // slot address
+map_find_ptr_done:
+    ldp x24, x25, [sp], #16
+    ldp x22, x23, [sp], #16
+    ldp x20, x21, [sp], #16
+    ldp x29, x30, [sp], #16
+    ret
+.global map_insert_ptr
+map_insert_ptr:
+    stp x29, x30, [sp, #-16]! // save caller frame
+    mov x29, sp // establish frame
+    stp x20, x21, [sp, #-16]! // preserve callee-saved registers
+    stp x22, x23, [sp, #-16]! // preserve callee-saved registers
+    mov x20, x0 // map
+    mov x21, x1 // key
+    mov x22, x2 // value
+    bl map_find_ptr
+    cbz x0, map_insert_ptr_absent
+    ldr x9, [x0, #8] // old value
+    str x22, [x0, #8] // store the new one
+    mov x0, x9
+    ldur x1, [x20, #-24] // value class
+    bl release_array_elem_ptr
+    mov x0, x21
+    ldur x1, [x20, #-32] // key class
+    bl release_array_elem_ptr // the entry keeps its own key
+    b map_insert_ptr_done
+map_insert_ptr_absent:
+    mov x23, x1 // empty slot
+    ldur x9, [x20, #-8] // entries
+    ldur x10, [x20, #-16] // capacity
+    cmp x10, x9, lsl #1 // room for another entry?
+    b.gt map_insert_ptr_room
+    mov x0, x20
+    bl map_grow_ptr
+    mov x20, x0
+    mov x1, x21
+    bl map_find_ptr // empty slot in the new index
+    mov x23, x1
+map_insert_ptr_room:
+    ldur x9, [x20, #-8] // entries
+    add x10, x20, x9, lsl #4 // first free entry
+    stp x21, x22, [x10]
+    add x9, x9, #1
+    stur x9, [x20, #-8]
+    str x9, [x23] // slot refers to the entry
+map_insert_ptr_done:
+    mov x0, x20
+    ldp x22, x23, [sp], #16
+    ldp x20, x21, [sp], #16
+    ldp x29, x30, [sp], #16
+    ret
+.global map_grow_ptr
+map_grow_ptr:
+    stp x29, x30, [sp, #-16]! // save caller frame
+    mov x29, sp // establish frame
+    stp x20, x21, [sp, #-16]! // preserve callee-saved registers
+    stp x22, x23, [sp, #-16]! // preserve callee-saved registers
+    mov x20, x0 // old map
+    ldur x21, [x20, #-16] // old capacity
+    lsl x0, x21, #5 // entries and index of twice the slots
+    add x0, x0, #32 // header included
+    bl alloc_heap_ptr
+    add x22, x0, #32 // new map starts after its header
+    ldur x9, [x20, #-32] // key class
+    stur x9, [x22, #-32]
+    ldur x9, [x20, #-24] // value class
+    stur x9, [x22, #-24]
+    lsl x9, x21, #1
+    stur x9, [x22, #-16] // doubled capacity
+    ldur x9, [x20, #-8] // entries
+    stur x9, [x22, #-8]
+    mov x0, x22 // memcpy dst
+    mov x1, x20 // memcpy src
+    lsl x2, x9, #4 // entry bytes
+    bl memcpy_helper // entries keep their order
+    sub x0, x20, #32 // old block starts at the header
+    lsl x1, x21, #4
+    add x1, x1, #32 // old block size
+    bl free_heap_ptr
+    mov x0, x22
+    bl map_reindex_ptr
+    mov x0, x22
+    ldp x22, x23, [sp], #16
+    ldp x20, x21, [sp], #16
+    ldp x29, x30, [sp], #16
+    ret
+.global map_reindex_ptr
+map_reindex_ptr:
+    stp x29, x30, [sp, #-16]! // save caller frame
+    mov x29, sp // establish frame
+    stp x20, x21, [sp, #-16]! // preserve callee-saved registers
+    stp x22, x23, [sp, #-16]! // preserve callee-saved registers
+    mov x20, x0 // map
+    ldur x21, [x20, #-16] // capacity
+    add x23, x20, x21, lsl #3 // index start
+    mov x9, x23
+    mov x10, x21
+map_reindex_ptr_clear:
+    str xzr, [x9], #8 // empty every slot
+    subs x10, x10, #1
+    b.ne map_reindex_ptr_clear
+    mov x22, #0 // entry position
+map_reindex_ptr_loop:
+    ldur x9, [x20, #-8] // entries
+    cmp x22, x9 // past the last entry?
+    b.ge map_reindex_ptr_done
+    add x9, x20, x22, lsl #4
+    ldr x0, [x9] // key of the entry
+    ldur x1, [x20, #-32] // key class
+    bl map_hash_ptr
+    sub x10, x21, #1 // slot mask
+map_reindex_ptr_probe:
+    and x0, x0, x10 // wrap around the index
+    add x11, x23, x0, lsl #3 // slot address
+    ldr x12, [x11]
+    cbz x12, map_reindex_ptr_place // empty slot?
+    add x0, x0, #1
+    b map_reindex_ptr_probe
+map_reindex_ptr_place:
+    add x22, x22, #1
+    str x22, [x11] // entry position plus one
+    b map_reindex_ptr_loop
+map_reindex_ptr_done:
+    ldp x22, x23, [sp], #16
+    ldp x20, x21, [sp], #16
+    ldp x29, x30, [sp], #16
+    ret
+.global map_remove_ptr
+map_remove_ptr:
+    stp x29, x30, [sp, #-16]! // save caller frame
+    mov x29, sp // establish frame
+    stp x20, x21, [sp, #-16]! // preserve callee-saved registers
+    stp x22, x23, [sp, #-16]! // preserve callee-saved registers
+    mov x20, x0 // map
+    mov x21, x1 // key
+    bl map_find_ptr
+    mov x22, x0 // entry, if any
+    mov x0, x21
+    ldur x1, [x20, #-32] // key class
+    bl release_array_elem_ptr // release the key
+    cbz x22, map_remove_ptr_done
+    ldr x0, [x22] // key of the entry
+    ldur x1, [x20, #-32] // key class
+    bl release_array_elem_ptr
+    ldr x0, [x22, #8] // value of the entry
+    ldur x1, [x20, #-24] // value class
+    bl release_array_elem_ptr
+    ldur x9, [x20, #-8] // entries
+    sub x9, x9, #1
+    stur x9, [x20, #-8]
+    add x2, x20, x9, lsl #4 // start of the old last entry
+    sub x2, x2, x22 // bytes after the dropped entry
+    mov x0, x22 // memcpy dst
+    add x1, x22, #16 // memcpy src, the next entry
+    bl memcpy_helper // forward copy moves entries down safely
+    mov x0, x20
+    bl map_reindex_ptr // positions after the entry moved
+map_remove_ptr_done:
+    mov x0, x20
+    ldp x22, x23, [sp], #16
+    ldp x20, x21, [sp], #16
+    ldp x29, x30, [sp], #16
+    ret
+.global map_release_ptr
+map_release_ptr:
+    cbz x0, map_release_ptr_done // nothing to free
+    stp x29, x30, [sp, #-16]! // save caller frame
+    mov x29, sp // establish frame
+    stp x20, x21, [sp, #-16]! // preserve callee-saved registers
+    mov x20, x0 // map
+    ldur x21, [x20, #-8] // entries left to release
+map_release_ptr_loop:
+    cbz x21, map_release_ptr_free
+    sub x21, x21, #1
+    add x9, x20, x21, lsl #4 // entry address
+    ldr x0, [x9, #0]
+    ldur x1, [x20, #-32]
+    bl release_array_elem_ptr
+    add x9, x20, x21, lsl #4 // entry address
+    ldr x0, [x9, #8]
+    ldur x1, [x20, #-24]
+    bl release_array_elem_ptr
+    b map_release_ptr_loop
+map_release_ptr_free:
+    ldur x1, [x20, #-16] // capacity
+    lsl x1, x1, #4
+    add x1, x1, #32 // block size, header included
+    sub x0, x20, #32 // block starts at the header
+    ldp x20, x21, [sp], #16
+    ldp x29, x30, [sp], #16
+    b free_heap_ptr // return the map to the allocator
+map_release_ptr_done:
+    ret
+.global map_copy_ptr
+map_copy_ptr:
+    cbz x0, map_copy_ptr_done // nothing to copy
+    stp x29, x30, [sp, #-16]! // save caller frame
+    mov x29, sp // establish frame
+    stp x20, x21, [sp, #-16]! // preserve callee-saved registers
+    stp x22, x23, [sp, #-16]! // preserve callee-saved registers
+    mov x20, x0 // source map
+    ldur x21, [x20, #-16] // capacity
+    lsl x21, x21, #4
+    add x21, x21, #32 // block size, header included
+    mov x0, x21
+    bl alloc_heap_ptr // allocate the copy
+    sub x1, x20, #32 // memcpy src, header included
+    mov x2, x21 // memcpy length
+    mov x20, x0 // copy block
+    bl memcpy_helper // duplicate header, entries and index
+    add x20, x20, #32 // copied map starts after its header
+    ldur x21, [x20, #-8] // entries left to copy
+map_copy_ptr_loop:
+    cbz x21, map_copy_ptr_copied
+    sub x21, x21, #1
+    add x22, x20, x21, lsl #4 // entry address
+    ldr x0, [x22, #0] // shared with the source
+    ldur x1, [x20, #-32]
+    bl copy_array_elem_ptr
+    str x0, [x22, #0] // owned by the copy
+    ldr x0, [x22, #8] // shared with the source
+    ldur x1, [x20, #-24]
+    bl copy_array_elem_ptr
+    str x0, [x22, #8] // owned by the copy
+    b map_copy_ptr_loop
+map_copy_ptr_copied:
+    mov x0, x20
+    ldp x22, x23, [sp], #16
+    ldp x20, x21, [sp], #16
+    ldp x29, x30, [sp], #16
+map_copy_ptr_done:
+    ret
+.global _116_main
+_116_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store name arg in frame
+    stur x1, [x29, #-16] // store rest arg in frame
+    mov x0, #56 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #8 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #8 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #56 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _119_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_119_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _119_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_119_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _119_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_119_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _119_main closure env_end to x9
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-16] // load operand
+    bl map_release_ptr // release rest map
+    adrp x2, _117 // point to string literal
+    add x2, x2, :lo12:_117
+    ldur x3, [x29, #-8] // load operand
+    mov x0, #0 // measure only
+    mov x1, #0
+    bl snprintf // invoke libc snprintf
+    mov x20, x0 // formatted length
+    add x0, x20, #17 // header, bytes and terminator
+    bl alloc_heap_ptr // allocate the string
+    mov x21, x0 // string block
+    add x9, x20, #17
+    str x9, [x21] // allocation size header
+    str x20, [x21, #8] // length header
+    adrp x2, _117 // point to string literal
+    add x2, x2, :lo12:_117
+    ldur x3, [x29, #-8] // load operand
+    add x0, x21, #16 // string bytes follow the header
+    add x1, x20, #1 // room for the terminator
+    bl snprintf // invoke libc snprintf
+    add x9, x21, #16 // return formatted string pointer
+    mov x22, x9 // keep result across releases
+    ldur x0, [x29, #-8] // load operand
+    bl release_str_ptr // release name string
+    mov x9, x22 // restore result
+    ldur x19, [x29, #-24] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _116_main_unwrapper
+_116_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-16] // load name env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-8] // load rest env field
+    stur x9, [x29, #-24] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _116_main
+.global _116_main_deep_release
+_116_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _116_main_release_skip_0
+    ldur x9, [x19, #-16] // load _116_main_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl release_str_ptr // release _116_main_release_field_0 string
+_116_main_release_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _116_main_release_skip_1
+    ldur x9, [x19, #-8] // load _116_main_release_field_1 env field
+    stur x9, [x29, #-32] // store value
+    ldur x0, [x29, #-32] // load operand
+    bl map_release_ptr // release _116_main_release_field_1 map
+_116_main_release_skip_1:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _116_main_deepcopy
+_116_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _116_main_deepcopy_skip_0
+    ldur x0, [x19, #-16] // load field pointer
+    bl copy_str_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-16] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_116_main_deepcopy_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _116_main_deepcopy_skip_1
+    ldur x0, [x19, #-8] // load field pointer
+    bl map_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-32] // store value
+_116_main_deepcopy_skip_1:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _132_main
+_132_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    // load exit code
+    mov x0, #1 // operand literal
+    bl exit // call libc exit to flush buffers
+.global _132_main_unwrapper
+_132_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _132_main
+.global _132_main_deep_release
+_132_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _132_main_deepcopy
+_132_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _130_main
+_130_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store s arg in frame
+    mov x0, #48 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x9, #0 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #48 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _132_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_132_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _132_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_132_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _132_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_132_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _132_main closure env_end to x9
+    stur x9, [x29, #-16] // store value
+    ldur x13, [x29, #-8] // load operand
+    ldr x2, [x13, #-8] // length to write
+    mov x1, x13 // buffer start
+    mov x0, #1 // stdout fd
+    bl write // invoke libc write
+    ldur x0, [x29, #-8] // load operand
+    bl release_str_ptr // release s string
+    ldur x19, [x29, #-16] // load continuation env_end pointer
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _130_main_unwrapper
+_130_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-8] // load s env field
+    stur x9, [x29, #-16] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _130_main
+.global _130_main_deep_release
+_130_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _130_main_release_skip_0
+    ldur x9, [x19, #-8] // load _130_main_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl release_str_ptr // release _130_main_release_field_0 string
+_130_main_release_skip_0:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _130_main_deepcopy
+_130_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _130_main_deepcopy_skip_0
+    ldur x0, [x19, #-8] // load field pointer
+    bl copy_str_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_130_main_deepcopy_skip_0:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _127_main
+_127_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store rest arg in frame
+    mov x0, #56 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #8 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #8 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #56 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _130_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_130_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _130_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_130_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _130_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_130_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _130_main closure env_end to x9
+    stur x9, [x29, #-16] // store value
+    ldur x0, [x29, #-8] // load operand
+    bl map_release_ptr // release rest map
+    adrp x2, _128 // point to string literal
+    add x2, x2, :lo12:_128
+    mov x0, #0 // measure only
+    mov x1, #0
+    bl snprintf // invoke libc snprintf
+    mov x20, x0 // formatted length
+    add x0, x20, #17 // header, bytes and terminator
+    bl alloc_heap_ptr // allocate the string
+    mov x21, x0 // string block
+    add x9, x20, #17
+    str x9, [x21] // allocation size header
+    str x20, [x21, #8] // length header
+    adrp x2, _128 // point to string literal
+    add x2, x2, :lo12:_128
+    add x0, x21, #16 // string bytes follow the header
+    add x1, x20, #1 // room for the terminator
+    bl snprintf // invoke libc snprintf
+    add x9, x21, #16 // return formatted string pointer
+    ldur x19, [x29, #-16] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _127_main_unwrapper
+_127_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-8] // load rest env field
+    stur x9, [x29, #-16] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _127_main
+.global _127_main_deep_release
+_127_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _127_main_release_skip_0
+    ldur x9, [x19, #-8] // load _127_main_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl map_release_ptr // release _127_main_release_field_0 map
+_127_main_release_skip_0:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _127_main_deepcopy
+_127_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _127_main_deepcopy_skip_0
+    ldur x0, [x19, #-8] // load field pointer
+    bl map_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_127_main_deepcopy_skip_0:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _113_main
+_113_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store many arg in frame
+    mov x0, #64 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #16 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #16 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #64 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _116_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_116_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _116_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_116_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _116_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_116_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #2 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _116_main closure env_end to x9
+    stur x9, [x29, #-16] // store value
+    mov x0, #56 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #8 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #8 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #56 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _127_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_127_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _127_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_127_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _127_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_127_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _127_main closure env_end to x9
+    stur x9, [x29, #-24] // store value
+    ldur x21, [x29, #-8] // load operand
+    mov x23, #17 // operand literal
+    mov x0, x21
+    mov x1, x23
+    bl map_find_ptr // look for the key
+    cbz x0, _113_main_lookup_missing_0
+    ldr x0, [x0, #8] // value of the entry
+    ldur x1, [x21, #-24]
+    bl copy_array_elem_ptr // copy the value
+    mov x20, x0
+    mov x0, x23
+    ldur x1, [x21, #-32]
+    bl release_array_elem_ptr // release the key
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _127_main release helper
+    blr x10 // release _127_main and what it holds
+    ldp x19, x22, [sp], #16
+    ldur x19, [x29, #-16] // load continuation env_end pointer
+    stur x20, [x19, #-16] // store env field
+    stur x21, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+_113_main_lookup_missing_0:
+    mov x0, x23
+    ldur x1, [x21, #-32]
+    bl release_array_elem_ptr // release the key
+    ldur x0, [x29, #-16] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _116_main release helper
+    blr x10 // release _116_main and what it holds
+    ldp x19, x22, [sp], #16
+    ldur x19, [x29, #-24] // load continuation env_end pointer
+    stur x21, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _113_main_unwrapper
+_113_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-8] // load many env field
+    stur x9, [x29, #-16] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _113_main
+.global _113_main_deep_release
+_113_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _113_main_release_skip_0
+    ldur x9, [x19, #-8] // load _113_main_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl map_release_ptr // release _113_main_release_field_0 map
+_113_main_release_skip_0:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _113_main_deepcopy
+_113_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _113_main_deepcopy_skip_0
+    ldur x0, [x19, #-8] // load field pointer
+    bl map_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_113_main_deepcopy_skip_0:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _40_count
+_40_count:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store ok arg in frame
+    stur x1, [x29, #-16] // store s arg in frame
+    ldur x13, [x29, #-16] // load operand
+    ldr x2, [x13, #-8] // length to write
+    mov x1, x13 // buffer start
+    mov x0, #1 // stdout fd
+    bl write // invoke libc write
+    ldur x0, [x29, #-16] // load operand
+    bl release_str_ptr // release s string
+    ldur x19, [x29, #-8] // load continuation env_end pointer
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _40_count_unwrapper
+_40_count_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-16] // load ok env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-8] // load s env field
+    stur x9, [x29, #-24] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _40_count
+.global _40_count_deep_release
+_40_count_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _40_count_release_skip_0
+    ldur x9, [x19, #-16] // load _40_count_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _40_count_release_field_0 release helper
+    blr x10 // release _40_count_release_field_0 and what it holds
+    ldp x19, x22, [sp], #16
+_40_count_release_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _40_count_release_skip_1
+    ldur x9, [x19, #-8] // load _40_count_release_field_1 env field
+    stur x9, [x29, #-32] // store value
+    ldur x0, [x29, #-32] // load operand
+    bl release_str_ptr // release _40_count_release_field_1 string
+_40_count_release_skip_1:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global deepcopy_heap_ptr
+deepcopy_heap_ptr:
+    stp x29, x30, [sp, #-16]! // prologue: save executor frame pointer
+    mov x29, sp // prologue: establish new frame
+    stp x19, x20, [sp, #-16]! // preserve callee-saved registers
+    stp x21, x22, [sp, #-16]!
+    str x23, [sp, #-16]!
+    mov x19, x0 // capture env_end pointer
+    ldr x21, [x19, #24] // load env size metadata
+    ldr x23, [x19, #32] // load heap size metadata
+    sub x20, x19, x21 // compute env base pointer
+    mov x0, x23 // length = heap size
+    bl alloc_heap_ptr // allocate new closure env
+    mov x22, x0 // new env base pointer
+    mov x1, x20 // memcpy src
+    mov x2, x23 // memcpy length
+    bl memcpy_helper // copy env contents
+    add x23, x22, x21 // new env_end pointer
+    ldr x9, [x23, #16] // load deep copy helper entry
+    mov x0, x23 // pass new env_end pointer
+    blr x9 // invoke helper
+    mov x0, x23 // return new env_end pointer
+    ldr x23, [sp], #16
+    ldp x21, x22, [sp], #16
+    ldp x19, x20, [sp], #16
+    ldp x29, x30, [sp], #16
+    ret
+.global _40_count_deepcopy
+_40_count_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _40_count_deepcopy_skip_0
+    ldur x0, [x19, #-16] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-16] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_40_count_deepcopy_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _40_count_deepcopy_skip_1
+    ldur x0, [x19, #-8] // load field pointer
+    bl copy_str_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-32] // store value
+_40_count_deepcopy_skip_1:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _37_count
+_37_count:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store ok arg in frame
+    stur x1, [x29, #-16] // store n arg in frame
+    stur x2, [x29, #-24] // store all arg in frame
+    mov x0, #64 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #0] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #16 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #16 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #64 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _40_count_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_40_count_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _40_count_deep_release // load release helper entry point
+    add x9, x9, :lo12:_40_count_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _40_count_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_40_count_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _41_count closure env_end to x9
+    stur x9, [x29, #-32] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl map_release_ptr // release all map
+    adrp x2, _38 // point to string literal
+    add x2, x2, :lo12:_38
+    ldur x3, [x29, #-16] // load operand
+    mov x0, #0 // measure only
+    mov x1, #0
+    bl snprintf // invoke libc snprintf
+    mov x20, x0 // formatted length
+    add x0, x20, #17 // header, bytes and terminator
+    bl alloc_heap_ptr // allocate the string
+    mov x21, x0 // string block
+    add x9, x20, #17
+    str x9, [x21] // allocation size header
+    str x20, [x21, #8] // length header
+    adrp x2, _38 // point to string literal
+    add x2, x2, :lo12:_38
+    ldur x3, [x29, #-16] // load operand
+    add x0, x21, #16 // string bytes follow the header
+    add x1, x20, #1 // room for the terminator
+    bl snprintf // invoke libc snprintf
+    add x9, x21, #16 // return formatted string pointer
+    ldur x19, [x29, #-32] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _37_count_unwrapper
+_37_count_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-24] // load ok env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-16] // load n env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-8] // load all env field
+    stur x9, [x29, #-32] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _37_count
+.global _37_count_deep_release
+_37_count_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _37_count_release_skip_0
+    ldur x9, [x19, #-24] // load _37_count_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _37_count_release_field_0 release helper
+    blr x10 // release _37_count_release_field_0 and what it holds
+    ldp x19, x22, [sp], #16
+_37_count_release_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _37_count_release_skip_2
+    ldur x9, [x19, #-8] // load _37_count_release_field_2 env field
+    stur x9, [x29, #-32] // store value
+    ldur x0, [x29, #-32] // load operand
+    bl map_release_ptr // release _37_count_release_field_2 map
+_37_count_release_skip_2:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _37_count_deepcopy
+_37_count_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _37_count_deepcopy_skip_0
+    ldur x0, [x19, #-24] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-24] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_37_count_deepcopy_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _37_count_deepcopy_skip_2
+    ldur x0, [x19, #-8] // load field pointer
+    bl map_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-32] // store value
+_37_count_deepcopy_skip_2:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global count
+count:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store names arg in frame
+    stur x1, [x29, #-16] // store ok arg in frame
+    mov x0, #72 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #0] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #24 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #24 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #72 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _37_count_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_37_count_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _37_count_deep_release // load release helper entry point
+    add x9, x9, :lo12:_37_count_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _37_count_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_37_count_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #2 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _42_count closure env_end to x9
+    stur x9, [x29, #-24] // store value
+    ldur x21, [x29, #-8] // load operand
+    ldur x22, [x21, #-8] // number of entries
+    ldur x19, [x29, #-24] // load continuation env_end pointer
+    stur x22, [x19, #-16] // store env field
+    stur x21, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global count_unwrapper
+count_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-16] // load names env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-8] // load ok env field
+    stur x9, [x29, #-24] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b count
+.global count_deep_release
+count_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt count_release_skip_0
+    ldur x9, [x19, #-16] // load count_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl map_release_ptr // release count_release_field_0 map
+count_release_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt count_release_skip_1
+    ldur x9, [x19, #-8] // load count_release_field_1 env field
+    stur x9, [x29, #-32] // store value
+    ldur x0, [x29, #-32] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load count_release_field_1 release helper
+    blr x10 // release count_release_field_1 and what it holds
+    ldp x19, x22, [sp], #16
+count_release_skip_1:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global count_deepcopy
+count_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt count_deepcopy_skip_0
+    ldur x0, [x19, #-16] // load field pointer
+    bl map_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-16] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+count_deepcopy_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt count_deepcopy_skip_1
+    ldur x0, [x19, #-8] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-32] // store value
+count_deepcopy_skip_1:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _111_main
+_111_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store many arg in frame
+    ldur x0, [x29, #-8] // load operand
+    bl map_copy_ptr // copy many map
+    mov x9, x0
+    stur x9, [x29, #-16] // store value
+    mov x0, #56 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #0] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #8 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #8 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #56 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _113_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_113_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _113_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_113_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _113_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_113_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _137_main closure env_end to x9
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-8] // load operand
+    ldur x1, [x29, #-24] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b count
+.global _111_main_unwrapper
+_111_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-8] // load many env field
+    stur x9, [x29, #-16] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _111_main
+.global _111_main_deep_release
+_111_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _111_main_release_skip_0
+    ldur x9, [x19, #-8] // load _111_main_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl map_release_ptr // release _111_main_release_field_0 map
+_111_main_release_skip_0:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _111_main_deepcopy
+_111_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _111_main_deepcopy_skip_0
+    ldur x0, [x19, #-8] // load field pointer
+    bl map_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_111_main_deepcopy_skip_0:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _46_fill
+_46_fill:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store ok arg in frame
+    stur x1, [x29, #-16] // store names arg in frame
+    ldur x20, [x29, #-8] // load ok closure env_end pointer
+    ldur x9, [x29, #-16] // load operand
+    stur x9, [x20, #-8] // store env field
+    mov x0, x20 // pass env_end pointer to closure
+    ldr x9, [x0, #0] // load closure unwrapper entry point
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9 // tail call into closure
+.global _46_fill_unwrapper
+_46_fill_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-16] // load ok env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-8] // load names env field
+    stur x9, [x29, #-24] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _46_fill
+.global _46_fill_deep_release
+_46_fill_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _46_fill_release_skip_0
+    ldur x9, [x19, #-16] // load _46_fill_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _46_fill_release_field_0 release helper
+    blr x10 // release _46_fill_release_field_0 and what it holds
+    ldp x19, x22, [sp], #16
+_46_fill_release_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _46_fill_release_skip_1
+    ldur x9, [x19, #-8] // load _46_fill_release_field_1 env field
+    stur x9, [x29, #-32] // store value
+    ldur x0, [x29, #-32] // load operand
+    bl map_release_ptr // release _46_fill_release_field_1 map
+_46_fill_release_skip_1:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _46_fill_deepcopy
+_46_fill_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _46_fill_deepcopy_skip_0
+    ldur x0, [x19, #-16] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-16] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_46_fill_deepcopy_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _46_fill_deepcopy_skip_1
+    ldur x0, [x19, #-8] // load field pointer
+    bl map_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-32] // store value
+_46_fill_deepcopy_skip_1:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _57_fill
+_57_fill:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store fill arg in frame
+    stur x1, [x29, #-16] // store more arg in frame
+    stur x2, [x29, #-24] // store ok arg in frame
+    stur x3, [x29, #-32] // store after arg in frame
+    ldur x20, [x29, #-8] // load fill closure env_end pointer
+    ldur x9, [x29, #-16] // load operand
+    stur x9, [x20, #-24] // store env field
+    ldur x9, [x29, #-32] // load operand
+    stur x9, [x20, #-16] // store env field
+    ldur x9, [x29, #-24] // load operand
+    stur x9, [x20, #-8] // store env field
+    mov x0, x20 // pass env_end pointer to closure
+    ldr x9, [x0, #0] // load closure unwrapper entry point
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9 // tail call into closure
+.global _57_fill_unwrapper
+_57_fill_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-32] // load fill env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-24] // load more env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-16] // load ok env field
+    stur x9, [x29, #-32] // store value
+    ldur x9, [x19, #-8] // load after env field
+    stur x9, [x29, #-40] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    ldur x3, [x29, #-40] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _57_fill
+.global _57_fill_deep_release
+_57_fill_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #3 // operand literal
+    cmp x9, x20
+    b.gt _57_fill_release_skip_0
+    ldur x9, [x19, #-32] // load _57_fill_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _57_fill_release_field_0 release helper
+    blr x10 // release _57_fill_release_field_0 and what it holds
+    ldp x19, x22, [sp], #16
+_57_fill_release_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _57_fill_release_skip_1
+    ldur x9, [x19, #-24] // load _57_fill_release_field_1 env field
+    stur x9, [x29, #-32] // store value
+    ldur x0, [x29, #-32] // load operand
+    bl map_release_ptr // release _57_fill_release_field_1 map
+_57_fill_release_skip_1:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _57_fill_release_skip_2
+    ldur x9, [x19, #-16] // load _57_fill_release_field_2 env field
+    stur x9, [x29, #-40] // store value
+    ldur x0, [x29, #-40] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _57_fill_release_field_2 release helper
+    blr x10 // release _57_fill_release_field_2 and what it holds
+    ldp x19, x22, [sp], #16
+_57_fill_release_skip_2:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _57_fill_deepcopy
+_57_fill_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #3 // operand literal
+    cmp x9, x20
+    b.gt _57_fill_deepcopy_skip_0
+    ldur x0, [x19, #-32] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-32] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_57_fill_deepcopy_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _57_fill_deepcopy_skip_1
+    ldur x0, [x19, #-24] // load field pointer
+    bl map_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-24] // store duplicated pointer
+    stur x9, [x29, #-32] // store value
+_57_fill_deepcopy_skip_1:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _57_fill_deepcopy_skip_2
+    ldur x0, [x19, #-16] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-16] // store duplicated pointer
+    stur x9, [x29, #-40] // store value
+_57_fill_deepcopy_skip_2:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _54_fill
+_54_fill:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store next arg in frame
+    stur x1, [x29, #-16] // store fill arg in frame
+    stur x2, [x29, #-24] // store ok arg in frame
+    stur x3, [x29, #-32] // store more arg in frame
+    mov x0, #80 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-32] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #16] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #32 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #32 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #80 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _57_fill_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_57_fill_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _57_fill_deep_release // load release helper entry point
+    add x9, x9, :lo12:_57_fill_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _57_fill_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_57_fill_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _58_fill closure env_end to x9
+    stur x9, [x29, #-40] // store value
+    ldur x9, [x29, #-8] // load operand
+    mov x20, #1 // operand literal
+    add x9, x9, x20 // add second integer
+    ldur x19, [x29, #-40] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _54_fill_unwrapper
+_54_fill_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-32] // load next env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-24] // load fill env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-16] // load ok env field
+    stur x9, [x29, #-32] // store value
+    ldur x9, [x19, #-8] // load more env field
+    stur x9, [x29, #-40] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    ldur x3, [x29, #-40] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _54_fill
+.global _54_fill_deep_release
+_54_fill_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _54_fill_release_skip_1
+    ldur x9, [x19, #-24] // load _54_fill_release_field_1 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _54_fill_release_field_1 release helper
+    blr x10 // release _54_fill_release_field_1 and what it holds
+    ldp x19, x22, [sp], #16
+_54_fill_release_skip_1:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _54_fill_release_skip_2
+    ldur x9, [x19, #-16] // load _54_fill_release_field_2 env field
+    stur x9, [x29, #-32] // store value
+    ldur x0, [x29, #-32] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _54_fill_release_field_2 release helper
+    blr x10 // release _54_fill_release_field_2 and what it holds
+    ldp x19, x22, [sp], #16
+_54_fill_release_skip_2:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _54_fill_release_skip_3
+    ldur x9, [x19, #-8] // load _54_fill_release_field_3 env field
+    stur x9, [x29, #-40] // store value
+    ldur x0, [x29, #-40] // load operand
+    bl map_release_ptr // release _54_fill_release_field_3 map
+_54_fill_release_skip_3:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _54_fill_deepcopy
+_54_fill_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _54_fill_deepcopy_skip_1
+    ldur x0, [x19, #-24] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-24] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_54_fill_deepcopy_skip_1:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _54_fill_deepcopy_skip_2
+    ldur x0, [x19, #-16] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-16] // store duplicated pointer
+    stur x9, [x29, #-32] // store value
+_54_fill_deepcopy_skip_2:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _54_fill_deepcopy_skip_3
+    ldur x0, [x19, #-8] // load field pointer
+    bl map_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-40] // store value
+_54_fill_deepcopy_skip_3:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _52_fill
+_52_fill:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store names arg in frame
+    stur x1, [x29, #-16] // store next arg in frame
+    stur x2, [x29, #-24] // store fill arg in frame
+    stur x3, [x29, #-32] // store ok arg in frame
+    stur x4, [x29, #-40] // store name arg in frame
+    mov x0, #80 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-32] // load operand
+    str x9, [x20, #16] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #32 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #32 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #80 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _54_fill_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_54_fill_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _54_fill_deep_release // load release helper entry point
+    add x9, x9, :lo12:_54_fill_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _54_fill_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_54_fill_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _59_fill closure env_end to x9
+    stur x9, [x29, #-48] // store value
+    ldur x0, [x29, #-8] // load operand
+    ldur x1, [x29, #-16] // load operand
+    ldur x2, [x29, #-40] // load operand
+    bl map_insert_ptr // store the entry
+    mov x9, x0
+    ldur x19, [x29, #-48] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _52_fill_unwrapper
+_52_fill_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-40] // load names env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-32] // load next env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-24] // load fill env field
+    stur x9, [x29, #-32] // store value
+    ldur x9, [x19, #-16] // load ok env field
+    stur x9, [x29, #-40] // store value
+    ldur x9, [x19, #-8] // load name env field
+    stur x9, [x29, #-48] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    ldur x3, [x29, #-40] // load operand
+    ldur x4, [x29, #-48] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _52_fill
+.global _52_fill_deep_release
+_52_fill_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #4 // operand literal
+    cmp x9, x20
+    b.gt _52_fill_release_skip_0
+    ldur x9, [x19, #-40] // load _52_fill_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl map_release_ptr // release _52_fill_release_field_0 map
+_52_fill_release_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _52_fill_release_skip_2
+    ldur x9, [x19, #-24] // load _52_fill_release_field_2 env field
+    stur x9, [x29, #-32] // store value
+    ldur x0, [x29, #-32] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _52_fill_release_field_2 release helper
+    blr x10 // release _52_fill_release_field_2 and what it holds
+    ldp x19, x22, [sp], #16
+_52_fill_release_skip_2:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _52_fill_release_skip_3
+    ldur x9, [x19, #-16] // load _52_fill_release_field_3 env field
+    stur x9, [x29, #-40] // store value
+    ldur x0, [x29, #-40] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _52_fill_release_field_3 release helper
+    blr x10 // release _52_fill_release_field_3 and what it holds
+    ldp x19, x22, [sp], #16
+_52_fill_release_skip_3:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _52_fill_release_skip_4
+    ldur x9, [x19, #-8] // load _52_fill_release_field_4 env field
+    stur x9, [x29, #-48] // store value
+    ldur x0, [x29, #-48] // load operand
+    bl release_str_ptr // release _52_fill_release_field_4 string
+_52_fill_release_skip_4:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _52_fill_deepcopy
+_52_fill_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #4 // operand literal
+    cmp x9, x20
+    b.gt _52_fill_deepcopy_skip_0
+    ldur x0, [x19, #-40] // load field pointer
+    bl map_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-40] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_52_fill_deepcopy_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _52_fill_deepcopy_skip_2
+    ldur x0, [x19, #-24] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-24] // store duplicated pointer
+    stur x9, [x29, #-32] // store value
+_52_fill_deepcopy_skip_2:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _52_fill_deepcopy_skip_3
+    ldur x0, [x19, #-16] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-16] // store duplicated pointer
+    stur x9, [x29, #-40] // store value
+_52_fill_deepcopy_skip_3:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _52_fill_deepcopy_skip_4
+    ldur x0, [x19, #-8] // load field pointer
+    bl copy_str_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-48] // store value
+_52_fill_deepcopy_skip_4:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _49_fill
+_49_fill:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store next arg in frame
+    stur x1, [x29, #-16] // store names arg in frame
+    stur x2, [x29, #-24] // store fill arg in frame
+    stur x3, [x29, #-32] // store ok arg in frame
+    mov x0, #88 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #16] // capture arg into env
+    ldur x9, [x29, #-32] // load operand
+    str x9, [x20, #24] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #40 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #40 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #88 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _52_fill_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_52_fill_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _52_fill_deep_release // load release helper entry point
+    add x9, x9, :lo12:_52_fill_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _52_fill_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_52_fill_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _60_fill closure env_end to x9
+    stur x9, [x29, #-40] // store value
+    adrp x2, _50 // point to string literal
+    add x2, x2, :lo12:_50
+    ldur x3, [x29, #-8] // load operand
+    mov x0, #0 // measure only
+    mov x1, #0
+    bl snprintf // invoke libc snprintf
+    mov x20, x0 // formatted length
+    add x0, x20, #17 // header, bytes and terminator
+    bl alloc_heap_ptr // allocate the string
+    mov x21, x0 // string block
+    add x9, x20, #17
+    str x9, [x21] // allocation size header
+    str x20, [x21, #8] // length header
+    adrp x2, _50 // point to string literal
+    add x2, x2, :lo12:_50
+    ldur x3, [x29, #-8] // load operand
+    add x0, x21, #16 // string bytes follow the header
+    add x1, x20, #1 // room for the terminator
+    bl snprintf // invoke libc snprintf
+    add x9, x21, #16 // return formatted string pointer
+    ldur x19, [x29, #-40] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _49_fill_unwrapper
+_49_fill_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-32] // load next env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-24] // load names env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-16] // load fill env field
+    stur x9, [x29, #-32] // store value
+    ldur x9, [x19, #-8] // load ok env field
+    stur x9, [x29, #-40] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    ldur x3, [x29, #-40] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _49_fill
+.global _49_fill_deep_release
+_49_fill_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _49_fill_release_skip_1
+    ldur x9, [x19, #-24] // load _49_fill_release_field_1 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl map_release_ptr // release _49_fill_release_field_1 map
+_49_fill_release_skip_1:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _49_fill_release_skip_2
+    ldur x9, [x19, #-16] // load _49_fill_release_field_2 env field
+    stur x9, [x29, #-32] // store value
+    ldur x0, [x29, #-32] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _49_fill_release_field_2 release helper
+    blr x10 // release _49_fill_release_field_2 and what it holds
+    ldp x19, x22, [sp], #16
+_49_fill_release_skip_2:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _49_fill_release_skip_3
+    ldur x9, [x19, #-8] // load _49_fill_release_field_3 env field
+    stur x9, [x29, #-40] // store value
+    ldur x0, [x29, #-40] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _49_fill_release_field_3 release helper
+    blr x10 // release _49_fill_release_field_3 and what it holds
+    ldp x19, x22, [sp], #16
+_49_fill_release_skip_3:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _49_fill_deepcopy
+_49_fill_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _49_fill_deepcopy_skip_1
+    ldur x0, [x19, #-24] // load field pointer
+    bl map_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-24] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_49_fill_deepcopy_skip_1:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _49_fill_deepcopy_skip_2
+    ldur x0, [x19, #-16] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-16] // store duplicated pointer
+    stur x9, [x29, #-32] // store value
+_49_fill_deepcopy_skip_2:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _49_fill_deepcopy_skip_3
+    ldur x0, [x19, #-8] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-40] // store value
+_49_fill_deepcopy_skip_3:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global fill
+fill:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #64 // reserve stack space for locals
+    stur x0, [x29, #-8] // store names arg in frame
+    stur x1, [x29, #-16] // store next arg in frame
+    stur x2, [x29, #-24] // store ok arg in frame
+    ldur x20, [x29, #-24] // original closure ok to __ok_copy_0 env_end pointer for clone
+    mov x20, x20 // clone source env_end pointer
+    ldr x21, [x20, #24] // load env size metadata for clone
+    ldr x22, [x20, #32] // load heap size metadata for clone
+    sub x19, x20, x21 // env base pointer for clone source
+    mov x0, x22 // length for cloned environment
+    bl alloc_heap_ptr // allocate cloned env block
+    mov x23, x0 // cloned closure env base pointer
+    mov x11, #0 // bytes copied
+fill_clone_copy_loop_0:
+    cmp x11, x22
+    b.ge fill_clone_copy_done_1
+    ldrb w12, [x19, x11] // duplicate closure env data
+    strb w12, [x23, x11]
+    add x11, x11, #1
+    b fill_clone_copy_loop_0
+fill_clone_copy_done_1:
+    add x20, x23, x21 // compute cloned env_end pointer
+    mov x19, x20 // cloned env_end pointer
+    ldr x9, [x19, #16] // load deepcopy helper entry point
+    str x19, [sp, #-16]! // preserve cloned env_end pointer
+    mov x0, x19 // pass env_end pointer to deepcopy helper
+    blr x9 // deepcopy reference fields
+    ldr x19, [sp], #16 // restore cloned env_end pointer
+    mov x9, x19 // copy cloned env_end pointer
+    stur x9, [x29, #-32] // store value
+    ldur x0, [x29, #-8] // load operand
+    bl map_copy_ptr // copy names map
+    mov x9, x0
+    stur x9, [x29, #-40] // store value
+    mov x0, #64 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-32] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-40] // load operand
+    str x9, [x20, #8] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #16 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #16 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #64 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _46_fill_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_46_fill_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _46_fill_deep_release // load release helper entry point
+    add x9, x9, :lo12:_46_fill_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _46_fill_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_46_fill_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _47_fill closure env_end to x9
+    stur x9, [x29, #-48] // store value
+    mov x0, #72 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #24 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #24 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #72 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, fill_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:fill_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, fill_deep_release // load release helper entry point
+    add x9, x9, :lo12:fill_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, fill_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:fill_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #3 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy fill closure env_end to x9
+    stur x9, [x29, #-56] // store value
+    mov x0, #80 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-56] // load operand
+    str x9, [x20, #16] // capture arg into env
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #24] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #32 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #32 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #80 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _49_fill_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_49_fill_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _49_fill_deep_release // load release helper entry point
+    add x9, x9, :lo12:_49_fill_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _49_fill_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_49_fill_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _61_fill closure env_end to x9
+    stur x9, [x29, #-64] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #20 // operand literal
+    cmp x9, x20
+    b.gt gt__47_fill_true_0_0
+gt__61_fill_false_0_0:
+    ldur x0, [x29, #-48] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _47_fill release helper
+    blr x10 // release _47_fill and what it holds
+    ldp x19, x22, [sp], #16
+    ldur x20, [x29, #-64] // load _61_fill closure env_end pointer
+    mov x0, x20 // pass env_end pointer to closure
+    ldr x9, [x0, #0] // load closure unwrapper entry point
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9 // tail call into closure
+gt__47_fill_true_0_0:
+    ldur x0, [x29, #-64] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _61_fill release helper
+    blr x10 // release _61_fill and what it holds
+    ldp x19, x22, [sp], #16
+    ldur x20, [x29, #-48] // load _47_fill closure env_end pointer
+    mov x0, x20 // pass env_end pointer to closure
+    ldr x9, [x0, #0] // load closure unwrapper entry point
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9 // tail call into closure
+.global fill_unwrapper
+fill_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-24] // load names env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-16] // load next env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-8] // load ok env field
+    stur x9, [x29, #-32] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b fill
+.global fill_deep_release
+fill_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt fill_release_skip_0
+    ldur x9, [x19, #-24] // load fill_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl map_release_ptr // release fill_release_field_0 map
+fill_release_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt fill_release_skip_2
+    ldur x9, [x19, #-8] // load fill_release_field_2 env field
+    stur x9, [x29, #-32] // store value
+    ldur x0, [x29, #-32] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load fill_release_field_2 release helper
+    blr x10 // release fill_release_field_2 and what it holds
+    ldp x19, x22, [sp], #16
+fill_release_skip_2:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global fill_deepcopy
+fill_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt fill_deepcopy_skip_0
+    ldur x0, [x19, #-24] // load field pointer
+    bl map_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-24] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+fill_deepcopy_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt fill_deepcopy_skip_2
+    ldur x0, [x19, #-8] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-32] // store value
+fill_deepcopy_skip_2:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _108_main
+_108_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store left arg in frame
+    mov x0, #56 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #8 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #8 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #56 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _111_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_111_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _111_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_111_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _111_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_111_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _111_main closure env_end to x9
+    stur x9, [x29, #-16] // store value
+    ldur x0, [x29, #-8] // load operand
+    mov x1, #4 // operand literal
+    ldur x2, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b fill
+.global _108_main_unwrapper
+_108_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-8] // load left env field
+    stur x9, [x29, #-16] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _108_main
+.global _108_main_deep_release
+_108_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _108_main_release_skip_0
+    ldur x9, [x19, #-8] // load _108_main_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl map_release_ptr // release _108_main_release_field_0 map
+_108_main_release_skip_0:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _108_main_deepcopy
+_108_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _108_main_deepcopy_skip_0
+    ldur x0, [x19, #-8] // load field pointer
+    bl map_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_108_main_deepcopy_skip_0:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _32_dump
+_32_dump:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store dump arg in frame
+    stur x1, [x29, #-16] // store rest arg in frame
+    stur x2, [x29, #-24] // store next arg in frame
+    stur x3, [x29, #-32] // store ok arg in frame
+    ldur x20, [x29, #-8] // load dump closure env_end pointer
+    ldur x9, [x29, #-16] // load operand
+    stur x9, [x20, #-24] // store env field
+    ldur x9, [x29, #-24] // load operand
+    stur x9, [x20, #-16] // store env field
+    ldur x9, [x29, #-32] // load operand
+    stur x9, [x20, #-8] // store env field
+    mov x0, x20 // pass env_end pointer to closure
+    ldr x9, [x0, #0] // load closure unwrapper entry point
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9 // tail call into closure
+.global _32_dump_unwrapper
+_32_dump_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-32] // load dump env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-24] // load rest env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-16] // load next env field
+    stur x9, [x29, #-32] // store value
+    ldur x9, [x19, #-8] // load ok env field
+    stur x9, [x29, #-40] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    ldur x3, [x29, #-40] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _32_dump
+.global _32_dump_deep_release
+_32_dump_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #3 // operand literal
+    cmp x9, x20
+    b.gt _32_dump_release_skip_0
+    ldur x9, [x19, #-32] // load _32_dump_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _32_dump_release_field_0 release helper
+    blr x10 // release _32_dump_release_field_0 and what it holds
+    ldp x19, x22, [sp], #16
+_32_dump_release_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _32_dump_release_skip_1
+    ldur x9, [x19, #-24] // load _32_dump_release_field_1 env field
+    stur x9, [x29, #-32] // store value
+    ldur x0, [x29, #-32] // load operand
+    bl map_release_ptr // release _32_dump_release_field_1 map
+_32_dump_release_skip_1:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _32_dump_release_skip_3
+    ldur x9, [x19, #-8] // load _32_dump_release_field_3 env field
+    stur x9, [x29, #-40] // store value
+    ldur x0, [x29, #-40] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _32_dump_release_field_3 release helper
+    blr x10 // release _32_dump_release_field_3 and what it holds
+    ldp x19, x22, [sp], #16
+_32_dump_release_skip_3:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _32_dump_deepcopy
+_32_dump_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #3 // operand literal
+    cmp x9, x20
+    b.gt _32_dump_deepcopy_skip_0
+    ldur x0, [x19, #-32] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-32] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_32_dump_deepcopy_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _32_dump_deepcopy_skip_1
+    ldur x0, [x19, #-24] // load field pointer
+    bl map_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-24] // store duplicated pointer
+    stur x9, [x29, #-32] // store value
+_32_dump_deepcopy_skip_1:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _32_dump_deepcopy_skip_3
+    ldur x0, [x19, #-8] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-40] // store value
+_32_dump_deepcopy_skip_3:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _30_dump
+_30_dump:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store dump arg in frame
+    stur x1, [x29, #-16] // store rest arg in frame
+    stur x2, [x29, #-24] // store next arg in frame
+    stur x3, [x29, #-32] // store ok arg in frame
+    stur x4, [x29, #-40] // store s arg in frame
+    mov x0, #80 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #16] // capture arg into env
+    ldur x9, [x29, #-32] // load operand
+    str x9, [x20, #24] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #32 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #32 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #80 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _32_dump_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_32_dump_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _32_dump_deep_release // load release helper entry point
+    add x9, x9, :lo12:_32_dump_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _32_dump_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_32_dump_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _33_dump closure env_end to x9
+    stur x9, [x29, #-48] // store value
+    ldur x13, [x29, #-40] // load operand
+    ldr x2, [x13, #-8] // length to write
+    mov x1, x13 // buffer start
+    mov x0, #1 // stdout fd
+    bl write // invoke libc write
+    ldur x0, [x29, #-40] // load operand
+    bl release_str_ptr // release s string
+    ldur x19, [x29, #-48] // load continuation env_end pointer
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _30_dump_unwrapper
+_30_dump_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-40] // load dump env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-32] // load rest env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-24] // load next env field
+    stur x9, [x29, #-32] // store value
+    ldur x9, [x19, #-16] // load ok env field
+    stur x9, [x29, #-40] // store value
+    ldur x9, [x19, #-8] // load s env field
+    stur x9, [x29, #-48] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    ldur x3, [x29, #-40] // load operand
+    ldur x4, [x29, #-48] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _30_dump
+.global _30_dump_deep_release
+_30_dump_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #4 // operand literal
+    cmp x9, x20
+    b.gt _30_dump_release_skip_0
+    ldur x9, [x19, #-40] // load _30_dump_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _30_dump_release_field_0 release helper
+    blr x10 // release _30_dump_release_field_0 and what it holds
+    ldp x19, x22, [sp], #16
+_30_dump_release_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #3 // operand literal
+    cmp x9, x20
+    b.gt _30_dump_release_skip_1
+    ldur x9, [x19, #-32] // load _30_dump_release_field_1 env field
+    stur x9, [x29, #-32] // store value
+    ldur x0, [x29, #-32] // load operand
+    bl map_release_ptr // release _30_dump_release_field_1 map
+_30_dump_release_skip_1:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _30_dump_release_skip_3
+    ldur x9, [x19, #-16] // load _30_dump_release_field_3 env field
+    stur x9, [x29, #-40] // store value
+    ldur x0, [x29, #-40] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _30_dump_release_field_3 release helper
+    blr x10 // release _30_dump_release_field_3 and what it holds
+    ldp x19, x22, [sp], #16
+_30_dump_release_skip_3:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _30_dump_release_skip_4
+    ldur x9, [x19, #-8] // load _30_dump_release_field_4 env field
+    stur x9, [x29, #-48] // store value
+    ldur x0, [x29, #-48] // load operand
+    bl release_str_ptr // release _30_dump_release_field_4 string
+_30_dump_release_skip_4:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _30_dump_deepcopy
+_30_dump_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #4 // operand literal
+    cmp x9, x20
+    b.gt _30_dump_deepcopy_skip_0
+    ldur x0, [x19, #-40] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-40] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_30_dump_deepcopy_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #3 // operand literal
+    cmp x9, x20
+    b.gt _30_dump_deepcopy_skip_1
+    ldur x0, [x19, #-32] // load field pointer
+    bl map_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-32] // store duplicated pointer
+    stur x9, [x29, #-32] // store value
+_30_dump_deepcopy_skip_1:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _30_dump_deepcopy_skip_3
+    ldur x0, [x19, #-16] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-16] // store duplicated pointer
+    stur x9, [x29, #-40] // store value
+_30_dump_deepcopy_skip_3:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _30_dump_deepcopy_skip_4
+    ldur x0, [x19, #-8] // load field pointer
+    bl copy_str_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-48] // store value
+_30_dump_deepcopy_skip_4:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _27_dump
+_27_dump:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #64 // reserve stack space for locals
+    stur x0, [x29, #-8] // store dump arg in frame
+    stur x1, [x29, #-16] // store ok arg in frame
+    stur x2, [x29, #-24] // store id arg in frame
+    stur x3, [x29, #-32] // store name arg in frame
+    stur x4, [x29, #-40] // store next arg in frame
+    stur x5, [x29, #-48] // store rest arg in frame
+    mov x0, #88 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-48] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-40] // load operand
+    str x9, [x20, #16] // capture arg into env
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #24] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #40 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #40 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #88 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _30_dump_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_30_dump_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _30_dump_deep_release // load release helper entry point
+    add x9, x9, :lo12:_30_dump_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _30_dump_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_30_dump_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _34_dump closure env_end to x9
+    stur x9, [x29, #-56] // store value
+    adrp x2, _28 // point to string literal
+    add x2, x2, :lo12:_28
+    ldur x3, [x29, #-24] // load operand
+    ldur x4, [x29, #-32] // load operand
+    mov x0, #0 // measure only
+    mov x1, #0
+    bl snprintf // invoke libc snprintf
+    mov x20, x0 // formatted length
+    add x0, x20, #17 // header, bytes and terminator
+    bl alloc_heap_ptr // allocate the string
+    mov x21, x0 // string block
+    add x9, x20, #17
+    str x9, [x21] // allocation size header
+    str x20, [x21, #8] // length header
+    adrp x2, _28 // point to string literal
+    add x2, x2, :lo12:_28
+    ldur x3, [x29, #-24] // load operand
+    ldur x4, [x29, #-32] // load operand
+    add x0, x21, #16 // string bytes follow the header
+    add x1, x20, #1 // room for the terminator
+    bl snprintf // invoke libc snprintf
+    add x9, x21, #16 // return formatted string pointer
+    mov x22, x9 // keep result across releases
+    ldur x0, [x29, #-32] // load operand
+    bl release_str_ptr // release name string
+    mov x9, x22 // restore result
+    ldur x19, [x29, #-56] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _27_dump_unwrapper
+_27_dump_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #64 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-48] // load dump env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-40] // load ok env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-32] // load id env field
+    stur x9, [x29, #-32] // store value
+    ldur x9, [x19, #-24] // load name env field
+    stur x9, [x29, #-40] // store value
+    ldur x9, [x19, #-16] // load next env field
+    stur x9, [x29, #-48] // store value
+    ldur x9, [x19, #-8] // load rest env field
+    stur x9, [x29, #-56] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    ldur x3, [x29, #-40] // load operand
+    ldur x4, [x29, #-48] // load operand
+    ldur x5, [x29, #-56] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _27_dump
+.global _27_dump_deep_release
+_27_dump_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #5 // operand literal
+    cmp x9, x20
+    b.gt _27_dump_release_skip_0
+    ldur x9, [x19, #-48] // load _27_dump_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _27_dump_release_field_0 release helper
+    blr x10 // release _27_dump_release_field_0 and what it holds
+    ldp x19, x22, [sp], #16
+_27_dump_release_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #4 // operand literal
+    cmp x9, x20
+    b.gt _27_dump_release_skip_1
+    ldur x9, [x19, #-40] // load _27_dump_release_field_1 env field
+    stur x9, [x29, #-32] // store value
+    ldur x0, [x29, #-32] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _27_dump_release_field_1 release helper
+    blr x10 // release _27_dump_release_field_1 and what it holds
+    ldp x19, x22, [sp], #16
+_27_dump_release_skip_1:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _27_dump_release_skip_3
+    ldur x9, [x19, #-24] // load _27_dump_release_field_3 env field
+    stur x9, [x29, #-40] // store value
+    ldur x0, [x29, #-40] // load operand
+    bl release_str_ptr // release _27_dump_release_field_3 string
+_27_dump_release_skip_3:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _27_dump_release_skip_5
+    ldur x9, [x19, #-8] // load _27_dump_release_field_5 env field
+    stur x9, [x29, #-48] // store value
+    ldur x0, [x29, #-48] // load operand
+    bl map_release_ptr // release _27_dump_release_field_5 map
+_27_dump_release_skip_5:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _27_dump_deepcopy
+_27_dump_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #5 // operand literal
+    cmp x9, x20
+    b.gt _27_dump_deepcopy_skip_0
+    ldur x0, [x19, #-48] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-48] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_27_dump_deepcopy_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #4 // operand literal
+    cmp x9, x20
+    b.gt _27_dump_deepcopy_skip_1
+    ldur x0, [x19, #-40] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-40] // store duplicated pointer
+    stur x9, [x29, #-32] // store value
+_27_dump_deepcopy_skip_1:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _27_dump_deepcopy_skip_3
+    ldur x0, [x19, #-24] // load field pointer
+    bl copy_str_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-24] // store duplicated pointer
+    stur x9, [x29, #-40] // store value
+_27_dump_deepcopy_skip_3:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _27_dump_deepcopy_skip_5
+    ldur x0, [x19, #-8] // load field pointer
+    bl map_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-48] // store value
+_27_dump_deepcopy_skip_5:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global dump
+dump:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store names arg in frame
+    stur x1, [x29, #-16] // store cursor arg in frame
+    stur x2, [x29, #-24] // store ok arg in frame
+    mov x0, #72 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #24 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #24 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #72 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, dump_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:dump_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, dump_deep_release // load release helper entry point
+    add x9, x9, :lo12:dump_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, dump_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:dump_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #3 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy dump closure env_end to x9
+    stur x9, [x29, #-32] // store value
+    ldur x20, [x29, #-24] // original closure ok to __ok_copy_0 env_end pointer for clone
+    mov x20, x20 // clone source env_end pointer
+    ldr x21, [x20, #24] // load env size metadata for clone
+    ldr x22, [x20, #32] // load heap size metadata for clone
+    sub x19, x20, x21 // env base pointer for clone source
+    mov x0, x22 // length for cloned environment
+    bl alloc_heap_ptr // allocate cloned env block
+    mov x23, x0 // cloned closure env base pointer
+    mov x11, #0 // bytes copied
+dump_clone_copy_loop_0:
+    cmp x11, x22
+    b.ge dump_clone_copy_done_1
+    ldrb w12, [x19, x11] // duplicate closure env data
+    strb w12, [x23, x11]
+    add x11, x11, #1
+    b dump_clone_copy_loop_0
+dump_clone_copy_done_1:
+    add x20, x23, x21 // compute cloned env_end pointer
+    mov x19, x20 // cloned env_end pointer
+    ldr x9, [x19, #16] // load deepcopy helper entry point
+    str x19, [sp, #-16]! // preserve cloned env_end pointer
+    mov x0, x19 // pass env_end pointer to deepcopy helper
+    blr x9 // deepcopy reference fields
+    ldr x19, [sp], #16 // restore cloned env_end pointer
+    mov x9, x19 // copy cloned env_end pointer
+    stur x9, [x29, #-40] // store value
+    mov x0, #96 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-32] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-40] // load operand
+    str x9, [x20, #8] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #48 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #48 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #96 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _27_dump_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_27_dump_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _27_dump_deep_release // load release helper entry point
+    add x9, x9, :lo12:_27_dump_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _27_dump_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_27_dump_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #4 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _35_dump closure env_end to x9
+    stur x9, [x29, #-48] // store value
+    ldur x21, [x29, #-8] // load operand
+    ldur x20, [x29, #-16] // load operand
+    cmp x20, #0 // cursor before the entries?
+    b.lt dump_mapnext_done_2
+    ldur x10, [x21, #-8] // number of entries
+    cmp x20, x10 // cursor past the entries?
+    b.ge dump_mapnext_done_2
+    add x10, x21, x20, lsl #4 // entry address
+    ldr x0, [x10, #0]
+    ldur x1, [x21, #-32]
+    bl copy_array_elem_ptr // copy the key
+    mov x22, x0
+    add x10, x21, x20, lsl #4 // entry address
+    ldr x0, [x10, #8]
+    ldur x1, [x21, #-24]
+    bl copy_array_elem_ptr // copy the value
+    mov x23, x0
+    add x20, x20, #1 // cursor of the next entry
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load ok release helper
+    blr x10 // release ok and what it holds
+    ldp x19, x22, [sp], #16
+    ldur x19, [x29, #-48] // load continuation env_end pointer
+    stur x22, [x19, #-32] // store env field
+    stur x23, [x19, #-24] // store env field
+    stur x20, [x19, #-16] // store env field
+    stur x21, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+dump_mapnext_done_2:
+    ldur x0, [x29, #-48] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _35_dump release helper
+    blr x10 // release _35_dump and what it holds
+    ldp x19, x22, [sp], #16
+    ldur x19, [x29, #-24] // load continuation env_end pointer
+    stur x21, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global dump_unwrapper
+dump_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-24] // load names env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-16] // load cursor env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-8] // load ok env field
+    stur x9, [x29, #-32] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b dump
+.global dump_deep_release
+dump_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt dump_release_skip_0
+    ldur x9, [x19, #-24] // load dump_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl map_release_ptr // release dump_release_field_0 map
+dump_release_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt dump_release_skip_2
+    ldur x9, [x19, #-8] // load dump_release_field_2 env field
+    stur x9, [x29, #-32] // store value
+    ldur x0, [x29, #-32] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load dump_release_field_2 release helper
+    blr x10 // release dump_release_field_2 and what it holds
+    ldp x19, x22, [sp], #16
+dump_release_skip_2:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global dump_deepcopy
+dump_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt dump_deepcopy_skip_0
+    ldur x0, [x19, #-24] // load field pointer
+    bl map_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-24] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+dump_deepcopy_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt dump_deepcopy_skip_2
+    ldur x0, [x19, #-8] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-32] // store value
+dump_deepcopy_skip_2:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _105_main
+_105_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store fewer arg in frame
+    mov x0, #56 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #8 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #8 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #56 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _108_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_108_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _108_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_108_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _108_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_108_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _108_main closure env_end to x9
+    stur x9, [x29, #-16] // store value
+    ldur x0, [x29, #-8] // load operand
+    mov x1, #0 // operand literal
+    ldur x2, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b dump
+.global _105_main_unwrapper
+_105_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-8] // load fewer env field
+    stur x9, [x29, #-16] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _105_main
+.global _105_main_deep_release
+_105_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _105_main_release_skip_0
+    ldur x9, [x19, #-8] // load _105_main_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl map_release_ptr // release _105_main_release_field_0 map
+_105_main_release_skip_0:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _105_main_deepcopy
+_105_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _105_main_deepcopy_skip_0
+    ldur x0, [x19, #-8] // load field pointer
+    bl map_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_105_main_deepcopy_skip_0:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _102_main
+_102_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store names arg in frame
+    mov x0, #56 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #8 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #8 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #56 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _105_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_105_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _105_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_105_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _105_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_105_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _105_main closure env_end to x9
+    stur x9, [x29, #-16] // store value
+    ldur x0, [x29, #-8] // load operand
+    mov x1, #1 // operand literal
+    bl map_remove_ptr // drop the entry
+    mov x9, x0
+    ldur x19, [x29, #-16] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _102_main_unwrapper
+_102_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-8] // load names env field
+    stur x9, [x29, #-16] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _102_main
+.global _102_main_deep_release
+_102_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _102_main_release_skip_0
+    ldur x9, [x19, #-8] // load _102_main_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl map_release_ptr // release _102_main_release_field_0 map
+_102_main_release_skip_0:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _102_main_deepcopy
+_102_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _102_main_deepcopy_skip_0
+    ldur x0, [x19, #-8] // load field pointer
+    bl map_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_102_main_deepcopy_skip_0:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _92_main
+_92_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store ages8 arg in frame
+    mov x0, #0 // key class
+    mov x1, #1 // value class
+    bl map_new_ptr // allocate the map
+    mov x20, x0
+    mov x1, #3 // operand literal
+    adrp x2, _94 // point to string literal
+    add x2, x2, :lo12:_94
+    mov x0, x20
+    bl map_insert_ptr // add the entry
+    mov x20, x0
+    mov x1, #1 // operand literal
+    adrp x2, _96 // point to string literal
+    add x2, x2, :lo12:_96
+    mov x0, x20
+    bl map_insert_ptr // add the entry
+    mov x20, x0
+    mov x1, #2 // operand literal
+    adrp x2, _98 // point to string literal
+    add x2, x2, :lo12:_98
+    mov x0, x20
+    bl map_insert_ptr // add the entry
+    mov x9, x0
+    stur x9, [x29, #-16] // store value
+    mov x0, #56 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #8 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #8 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #56 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _102_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_102_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _102_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_102_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _102_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_102_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _102_main closure env_end to x9
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-8] // load operand
+    bl map_release_ptr // release ages8 map
+    ldur x0, [x29, #-16] // load operand
+    mov x1, #0 // operand literal
+    ldur x2, [x29, #-24] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b dump
+.global _92_main_unwrapper
+_92_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-8] // load ages8 env field
+    stur x9, [x29, #-16] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _92_main
+.global _92_main_deep_release
+_92_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _92_main_release_skip_0
+    ldur x9, [x19, #-8] // load _92_main_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl map_release_ptr // release _92_main_release_field_0 map
+_92_main_release_skip_0:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _92_main_deepcopy
+_92_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _92_main_deepcopy_skip_0
+    ldur x0, [x19, #-8] // load field pointer
+    bl map_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_92_main_deepcopy_skip_0:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _11_show
+_11_show:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store ok arg in frame
+    stur x1, [x29, #-16] // store found arg in frame
+    ldur x20, [x29, #-8] // load ok closure env_end pointer
+    ldur x9, [x29, #-16] // load operand
+    stur x9, [x20, #-8] // store env field
+    mov x0, x20 // pass env_end pointer to closure
+    ldr x9, [x0, #0] // load closure unwrapper entry point
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9 // tail call into closure
+.global _11_show_unwrapper
+_11_show_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-16] // load ok env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-8] // load found env field
+    stur x9, [x29, #-24] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _11_show
+.global _11_show_deep_release
+_11_show_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _11_show_release_skip_0
+    ldur x9, [x19, #-16] // load _11_show_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _11_show_release_field_0 release helper
+    blr x10 // release _11_show_release_field_0 and what it holds
+    ldp x19, x22, [sp], #16
+_11_show_release_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _11_show_release_skip_1
+    ldur x9, [x19, #-8] // load _11_show_release_field_1 env field
+    stur x9, [x29, #-32] // store value
+    ldur x0, [x29, #-32] // load operand
+    bl map_release_ptr // release _11_show_release_field_1 map
+_11_show_release_skip_1:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _11_show_deepcopy
+_11_show_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _11_show_deepcopy_skip_0
+    ldur x0, [x19, #-16] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-16] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_11_show_deepcopy_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _11_show_deepcopy_skip_1
+    ldur x0, [x19, #-8] // load field pointer
+    bl map_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-32] // store value
+_11_show_deepcopy_skip_1:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _9_show
+_9_show:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store ok arg in frame
+    stur x1, [x29, #-16] // store found arg in frame
+    stur x2, [x29, #-24] // store s arg in frame
+    mov x0, #64 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #8] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #16 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #16 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #64 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _11_show_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_11_show_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _11_show_deep_release // load release helper entry point
+    add x9, x9, :lo12:_11_show_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _11_show_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_11_show_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _12_show closure env_end to x9
+    stur x9, [x29, #-32] // store value
+    ldur x13, [x29, #-24] // load operand
+    ldr x2, [x13, #-8] // length to write
+    mov x1, x13 // buffer start
+    mov x0, #1 // stdout fd
+    bl write // invoke libc write
+    ldur x0, [x29, #-24] // load operand
+    bl release_str_ptr // release s string
+    ldur x19, [x29, #-32] // load continuation env_end pointer
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _9_show_unwrapper
+_9_show_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-24] // load ok env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-16] // load found env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-8] // load s env field
+    stur x9, [x29, #-32] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _9_show
+.global _9_show_deep_release
+_9_show_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _9_show_release_skip_0
+    ldur x9, [x19, #-24] // load _9_show_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _9_show_release_field_0 release helper
+    blr x10 // release _9_show_release_field_0 and what it holds
+    ldp x19, x22, [sp], #16
+_9_show_release_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _9_show_release_skip_1
+    ldur x9, [x19, #-16] // load _9_show_release_field_1 env field
+    stur x9, [x29, #-32] // store value
+    ldur x0, [x29, #-32] // load operand
+    bl map_release_ptr // release _9_show_release_field_1 map
+_9_show_release_skip_1:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _9_show_release_skip_2
+    ldur x9, [x19, #-8] // load _9_show_release_field_2 env field
+    stur x9, [x29, #-40] // store value
+    ldur x0, [x29, #-40] // load operand
+    bl release_str_ptr // release _9_show_release_field_2 string
+_9_show_release_skip_2:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _9_show_deepcopy
+_9_show_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _9_show_deepcopy_skip_0
+    ldur x0, [x19, #-24] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-24] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_9_show_deepcopy_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _9_show_deepcopy_skip_1
+    ldur x0, [x19, #-16] // load field pointer
+    bl map_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-16] // store duplicated pointer
+    stur x9, [x29, #-32] // store value
+_9_show_deepcopy_skip_1:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _9_show_deepcopy_skip_2
+    ldur x0, [x19, #-8] // load field pointer
+    bl copy_str_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-40] // store value
+_9_show_deepcopy_skip_2:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _6_show
+_6_show:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store who arg in frame
+    stur x1, [x29, #-16] // store ok arg in frame
+    stur x2, [x29, #-24] // store age arg in frame
+    stur x3, [x29, #-32] // store found arg in frame
+    mov x0, #72 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-32] // load operand
+    str x9, [x20, #8] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #24 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #24 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #72 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _9_show_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_9_show_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _9_show_deep_release // load release helper entry point
+    add x9, x9, :lo12:_9_show_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _9_show_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_9_show_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _13_show closure env_end to x9
+    stur x9, [x29, #-40] // store value
+    adrp x2, _7 // point to string literal
+    add x2, x2, :lo12:_7
+    ldur x3, [x29, #-8] // load operand
+    ldur x4, [x29, #-24] // load operand
+    mov x0, #0 // measure only
+    mov x1, #0
+    bl snprintf // invoke libc snprintf
+    mov x20, x0 // formatted length
+    add x0, x20, #17 // header, bytes and terminator
+    bl alloc_heap_ptr // allocate the string
+    mov x21, x0 // string block
+    add x9, x20, #17
+    str x9, [x21] // allocation size header
+    str x20, [x21, #8] // length header
+    adrp x2, _7 // point to string literal
+    add x2, x2, :lo12:_7
+    ldur x3, [x29, #-8] // load operand
+    ldur x4, [x29, #-24] // load operand
+    add x0, x21, #16 // string bytes follow the header
+    add x1, x20, #1 // room for the terminator
+    bl snprintf // invoke libc snprintf
+    add x9, x21, #16 // return formatted string pointer
+    mov x22, x9 // keep result across releases
+    ldur x0, [x29, #-8] // load operand
+    bl release_str_ptr // release who string
+    mov x9, x22 // restore result
+    ldur x19, [x29, #-40] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _6_show_unwrapper
+_6_show_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-32] // load who env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-24] // load ok env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-16] // load age env field
+    stur x9, [x29, #-32] // store value
+    ldur x9, [x19, #-8] // load found env field
+    stur x9, [x29, #-40] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    ldur x3, [x29, #-40] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _6_show
+.global _6_show_deep_release
+_6_show_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #3 // operand literal
+    cmp x9, x20
+    b.gt _6_show_release_skip_0
+    ldur x9, [x19, #-32] // load _6_show_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl release_str_ptr // release _6_show_release_field_0 string
+_6_show_release_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _6_show_release_skip_1
+    ldur x9, [x19, #-24] // load _6_show_release_field_1 env field
+    stur x9, [x29, #-32] // store value
+    ldur x0, [x29, #-32] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _6_show_release_field_1 release helper
+    blr x10 // release _6_show_release_field_1 and what it holds
+    ldp x19, x22, [sp], #16
+_6_show_release_skip_1:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _6_show_release_skip_3
+    ldur x9, [x19, #-8] // load _6_show_release_field_3 env field
+    stur x9, [x29, #-40] // store value
+    ldur x0, [x29, #-40] // load operand
+    bl map_release_ptr // release _6_show_release_field_3 map
+_6_show_release_skip_3:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _6_show_deepcopy
+_6_show_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #3 // operand literal
+    cmp x9, x20
+    b.gt _6_show_deepcopy_skip_0
+    ldur x0, [x19, #-32] // load field pointer
+    bl copy_str_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-32] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_6_show_deepcopy_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _6_show_deepcopy_skip_1
+    ldur x0, [x19, #-24] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-24] // store duplicated pointer
+    stur x9, [x29, #-32] // store value
+_6_show_deepcopy_skip_1:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _6_show_deepcopy_skip_3
+    ldur x0, [x19, #-8] // load field pointer
+    bl map_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-40] // store value
+_6_show_deepcopy_skip_3:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _21_show
+_21_show:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store ok arg in frame
+    stur x1, [x29, #-16] // store missing arg in frame
+    ldur x20, [x29, #-8] // load ok closure env_end pointer
+    ldur x9, [x29, #-16] // load operand
+    stur x9, [x20, #-8] // store env field
+    mov x0, x20 // pass env_end pointer to closure
+    ldr x9, [x0, #0] // load closure unwrapper entry point
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9 // tail call into closure
+.global _21_show_unwrapper
+_21_show_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-16] // load ok env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-8] // load missing env field
+    stur x9, [x29, #-24] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _21_show
+.global _21_show_deep_release
+_21_show_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _21_show_release_skip_0
+    ldur x9, [x19, #-16] // load _21_show_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _21_show_release_field_0 release helper
+    blr x10 // release _21_show_release_field_0 and what it holds
+    ldp x19, x22, [sp], #16
+_21_show_release_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _21_show_release_skip_1
+    ldur x9, [x19, #-8] // load _21_show_release_field_1 env field
+    stur x9, [x29, #-32] // store value
+    ldur x0, [x29, #-32] // load operand
+    bl map_release_ptr // release _21_show_release_field_1 map
+_21_show_release_skip_1:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _21_show_deepcopy
+_21_show_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _21_show_deepcopy_skip_0
+    ldur x0, [x19, #-16] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-16] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_21_show_deepcopy_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _21_show_deepcopy_skip_1
+    ldur x0, [x19, #-8] // load field pointer
+    bl map_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-32] // store value
+_21_show_deepcopy_skip_1:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _19_show
+_19_show:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store ok arg in frame
+    stur x1, [x29, #-16] // store missing arg in frame
+    stur x2, [x29, #-24] // store s arg in frame
+    mov x0, #64 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #8] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #16 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #16 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #64 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _21_show_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_21_show_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _21_show_deep_release // load release helper entry point
+    add x9, x9, :lo12:_21_show_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _21_show_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_21_show_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _22_show closure env_end to x9
+    stur x9, [x29, #-32] // store value
+    ldur x13, [x29, #-24] // load operand
+    ldr x2, [x13, #-8] // length to write
+    mov x1, x13 // buffer start
+    mov x0, #1 // stdout fd
+    bl write // invoke libc write
+    ldur x0, [x29, #-24] // load operand
+    bl release_str_ptr // release s string
+    ldur x19, [x29, #-32] // load continuation env_end pointer
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _19_show_unwrapper
+_19_show_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-24] // load ok env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-16] // load missing env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-8] // load s env field
+    stur x9, [x29, #-32] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _19_show
+.global _19_show_deep_release
+_19_show_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _19_show_release_skip_0
+    ldur x9, [x19, #-24] // load _19_show_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _19_show_release_field_0 release helper
+    blr x10 // release _19_show_release_field_0 and what it holds
+    ldp x19, x22, [sp], #16
+_19_show_release_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _19_show_release_skip_1
+    ldur x9, [x19, #-16] // load _19_show_release_field_1 env field
+    stur x9, [x29, #-32] // store value
+    ldur x0, [x29, #-32] // load operand
+    bl map_release_ptr // release _19_show_release_field_1 map
+_19_show_release_skip_1:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _19_show_release_skip_2
+    ldur x9, [x19, #-8] // load _19_show_release_field_2 env field
+    stur x9, [x29, #-40] // store value
+    ldur x0, [x29, #-40] // load operand
+    bl release_str_ptr // release _19_show_release_field_2 string
+_19_show_release_skip_2:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _19_show_deepcopy
+_19_show_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _19_show_deepcopy_skip_0
+    ldur x0, [x19, #-24] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-24] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_19_show_deepcopy_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _19_show_deepcopy_skip_1
+    ldur x0, [x19, #-16] // load field pointer
+    bl map_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-16] // store duplicated pointer
+    stur x9, [x29, #-32] // store value
+_19_show_deepcopy_skip_1:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _19_show_deepcopy_skip_2
+    ldur x0, [x19, #-8] // load field pointer
+    bl copy_str_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-40] // store value
+_19_show_deepcopy_skip_2:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _16_show
+_16_show:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store who arg in frame
+    stur x1, [x29, #-16] // store ok arg in frame
+    stur x2, [x29, #-24] // store missing arg in frame
+    mov x0, #72 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #8] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #24 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #24 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #72 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _19_show_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_19_show_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _19_show_deep_release // load release helper entry point
+    add x9, x9, :lo12:_19_show_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _19_show_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_19_show_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _23_show closure env_end to x9
+    stur x9, [x29, #-32] // store value
+    adrp x2, _17 // point to string literal
+    add x2, x2, :lo12:_17
+    ldur x3, [x29, #-8] // load operand
+    mov x0, #0 // measure only
+    mov x1, #0
+    bl snprintf // invoke libc snprintf
+    mov x20, x0 // formatted length
+    add x0, x20, #17 // header, bytes and terminator
+    bl alloc_heap_ptr // allocate the string
+    mov x21, x0 // string block
+    add x9, x20, #17
+    str x9, [x21] // allocation size header
+    str x20, [x21, #8] // length header
+    adrp x2, _17 // point to string literal
+    add x2, x2, :lo12:_17
+    ldur x3, [x29, #-8] // load operand
+    add x0, x21, #16 // string bytes follow the header
+    add x1, x20, #1 // room for the terminator
+    bl snprintf // invoke libc snprintf
+    add x9, x21, #16 // return formatted string pointer
+    mov x22, x9 // keep result across releases
+    ldur x0, [x29, #-8] // load operand
+    bl release_str_ptr // release who string
+    mov x9, x22 // restore result
+    ldur x19, [x29, #-32] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _16_show_unwrapper
+_16_show_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-24] // load who env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-16] // load ok env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-8] // load missing env field
+    stur x9, [x29, #-32] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _16_show
+.global _16_show_deep_release
+_16_show_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _16_show_release_skip_0
+    ldur x9, [x19, #-24] // load _16_show_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl release_str_ptr // release _16_show_release_field_0 string
+_16_show_release_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _16_show_release_skip_1
+    ldur x9, [x19, #-16] // load _16_show_release_field_1 env field
+    stur x9, [x29, #-32] // store value
+    ldur x0, [x29, #-32] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _16_show_release_field_1 release helper
+    blr x10 // release _16_show_release_field_1 and what it holds
+    ldp x19, x22, [sp], #16
+_16_show_release_skip_1:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _16_show_release_skip_2
+    ldur x9, [x19, #-8] // load _16_show_release_field_2 env field
+    stur x9, [x29, #-40] // store value
+    ldur x0, [x29, #-40] // load operand
+    bl map_release_ptr // release _16_show_release_field_2 map
+_16_show_release_skip_2:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _16_show_deepcopy
+_16_show_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt _16_show_deepcopy_skip_0
+    ldur x0, [x19, #-24] // load field pointer
+    bl copy_str_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-24] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_16_show_deepcopy_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _16_show_deepcopy_skip_1
+    ldur x0, [x19, #-16] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-16] // store duplicated pointer
+    stur x9, [x29, #-32] // store value
+_16_show_deepcopy_skip_1:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _16_show_deepcopy_skip_2
+    ldur x0, [x19, #-8] // load field pointer
+    bl map_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-40] // store value
+_16_show_deepcopy_skip_2:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global show
+show:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #64 // reserve stack space for locals
+    stur x0, [x29, #-8] // store ages arg in frame
+    stur x1, [x29, #-16] // store who arg in frame
+    stur x2, [x29, #-24] // store ok arg in frame
+    ldur x0, [x29, #-16] // load operand
+    bl copy_str_ptr // copy who string
+    mov x9, x0
+    stur x9, [x29, #-32] // store value
+    ldur x20, [x29, #-24] // original closure ok to __ok_copy_1 env_end pointer for clone
+    mov x20, x20 // clone source env_end pointer
+    ldr x21, [x20, #24] // load env size metadata for clone
+    ldr x22, [x20, #32] // load heap size metadata for clone
+    sub x19, x20, x21 // env base pointer for clone source
+    mov x0, x22 // length for cloned environment
+    bl alloc_heap_ptr // allocate cloned env block
+    mov x23, x0 // cloned closure env base pointer
+    mov x11, #0 // bytes copied
+show_clone_copy_loop_0:
+    cmp x11, x22
+    b.ge show_clone_copy_done_1
+    ldrb w12, [x19, x11] // duplicate closure env data
+    strb w12, [x23, x11]
+    add x11, x11, #1
+    b show_clone_copy_loop_0
+show_clone_copy_done_1:
+    add x20, x23, x21 // compute cloned env_end pointer
+    mov x19, x20 // cloned env_end pointer
+    ldr x9, [x19, #16] // load deepcopy helper entry point
+    str x19, [sp, #-16]! // preserve cloned env_end pointer
+    mov x0, x19 // pass env_end pointer to deepcopy helper
+    blr x9 // deepcopy reference fields
+    ldr x19, [sp], #16 // restore cloned env_end pointer
+    mov x9, x19 // copy cloned env_end pointer
+    stur x9, [x29, #-40] // store value
+    mov x0, #80 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-32] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-40] // load operand
+    str x9, [x20, #8] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #32 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #32 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #80 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _6_show_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_6_show_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _6_show_deep_release // load release helper entry point
+    add x9, x9, :lo12:_6_show_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _6_show_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_6_show_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #2 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _14_show closure env_end to x9
+    stur x9, [x29, #-48] // store value
+    ldur x0, [x29, #-16] // load operand
+    bl copy_str_ptr // copy who string
+    mov x9, x0
+    stur x9, [x29, #-56] // store value
+    mov x0, #72 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-56] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #8] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #24 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #24 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #72 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _16_show_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_16_show_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _16_show_deep_release // load release helper entry point
+    add x9, x9, :lo12:_16_show_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _16_show_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_16_show_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _24_show closure env_end to x9
+    stur x9, [x29, #-64] // store value
+    ldur x21, [x29, #-8] // load operand
+    ldur x23, [x29, #-16] // load operand
+    mov x0, x21
+    mov x1, x23
+    bl map_find_ptr // look for the key
+    cbz x0, show_lookup_missing_2
+    ldr x0, [x0, #8] // value of the entry
+    ldur x1, [x21, #-24]
+    bl copy_array_elem_ptr // copy the value
+    mov x20, x0
+    mov x0, x23
+    ldur x1, [x21, #-32]
+    bl release_array_elem_ptr // release the key
+    ldur x0, [x29, #-64] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _24_show release helper
+    blr x10 // release _24_show and what it holds
+    ldp x19, x22, [sp], #16
+    ldur x19, [x29, #-48] // load continuation env_end pointer
+    stur x20, [x19, #-16] // store env field
+    stur x21, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+show_lookup_missing_2:
+    mov x0, x23
+    ldur x1, [x21, #-32]
+    bl release_array_elem_ptr // release the key
+    ldur x0, [x29, #-48] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _14_show release helper
+    blr x10 // release _14_show and what it holds
+    ldp x19, x22, [sp], #16
+    ldur x19, [x29, #-64] // load continuation env_end pointer
+    stur x21, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global show_unwrapper
+show_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-24] // load ages env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-16] // load who env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-8] // load ok env field
+    stur x9, [x29, #-32] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b show
+.global show_deep_release
+show_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt show_release_skip_0
+    ldur x9, [x19, #-24] // load show_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl map_release_ptr // release show_release_field_0 map
+show_release_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt show_release_skip_1
+    ldur x9, [x19, #-16] // load show_release_field_1 env field
+    stur x9, [x29, #-32] // store value
+    ldur x0, [x29, #-32] // load operand
+    bl release_str_ptr // release show_release_field_1 string
+show_release_skip_1:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt show_release_skip_2
+    ldur x9, [x19, #-8] // load show_release_field_2 env field
+    stur x9, [x29, #-40] // store value
+    ldur x0, [x29, #-40] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load show_release_field_2 release helper
+    blr x10 // release show_release_field_2 and what it holds
+    ldp x19, x22, [sp], #16
+show_release_skip_2:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global show_deepcopy
+show_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #2 // operand literal
+    cmp x9, x20
+    b.gt show_deepcopy_skip_0
+    ldur x0, [x19, #-24] // load field pointer
+    bl map_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-24] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+show_deepcopy_skip_0:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt show_deepcopy_skip_1
+    ldur x0, [x19, #-16] // load field pointer
+    bl copy_str_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-16] // store duplicated pointer
+    stur x9, [x29, #-32] // store value
+show_deepcopy_skip_1:
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt show_deepcopy_skip_2
+    ldur x0, [x19, #-8] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-40] // store value
+show_deepcopy_skip_2:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _89_main
+_89_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store ages7 arg in frame
+    mov x0, #56 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #8 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #8 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #56 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _92_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_92_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _92_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_92_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _92_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_92_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _92_main closure env_end to x9
+    stur x9, [x29, #-16] // store value
+    ldur x0, [x29, #-8] // load operand
+    adrp x1, _90 // point to string literal
+    add x1, x1, :lo12:_90
+    ldur x2, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b show
+.global _89_main_unwrapper
+_89_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-8] // load ages7 env field
+    stur x9, [x29, #-16] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _89_main
+.global _89_main_deep_release
+_89_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _89_main_release_skip_0
+    ldur x9, [x19, #-8] // load _89_main_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl map_release_ptr // release _89_main_release_field_0 map
+_89_main_release_skip_0:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _89_main_deepcopy
+_89_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _89_main_deepcopy_skip_0
+    ldur x0, [x19, #-8] // load field pointer
+    bl map_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_89_main_deepcopy_skip_0:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _86_main
+_86_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store ages6 arg in frame
+    mov x0, #56 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #8 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #8 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #56 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _89_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_89_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _89_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_89_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _89_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_89_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _89_main closure env_end to x9
+    stur x9, [x29, #-16] // store value
+    ldur x0, [x29, #-8] // load operand
+    adrp x1, _87 // point to string literal
+    add x1, x1, :lo12:_87
+    bl map_remove_ptr // drop the entry
+    mov x9, x0
+    ldur x19, [x29, #-16] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _86_main_unwrapper
+_86_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-8] // load ages6 env field
+    stur x9, [x29, #-16] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _86_main
+.global _86_main_deep_release
+_86_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _86_main_release_skip_0
+    ldur x9, [x19, #-8] // load _86_main_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl map_release_ptr // release _86_main_release_field_0 map
+_86_main_release_skip_0:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _86_main_deepcopy
+_86_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _86_main_deepcopy_skip_0
+    ldur x0, [x19, #-8] // load field pointer
+    bl map_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_86_main_deepcopy_skip_0:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _83_main
+_83_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store ages5 arg in frame
+    mov x0, #56 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #8 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #8 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #56 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _86_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_86_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _86_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_86_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _86_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_86_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _86_main closure env_end to x9
+    stur x9, [x29, #-16] // store value
+    ldur x0, [x29, #-8] // load operand
+    adrp x1, _84 // point to string literal
+    add x1, x1, :lo12:_84
+    ldur x2, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b show
+.global _83_main_unwrapper
+_83_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-8] // load ages5 env field
+    stur x9, [x29, #-16] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _83_main
+.global _83_main_deep_release
+_83_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _83_main_release_skip_0
+    ldur x9, [x19, #-8] // load _83_main_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl map_release_ptr // release _83_main_release_field_0 map
+_83_main_release_skip_0:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _83_main_deepcopy
+_83_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _83_main_deepcopy_skip_0
+    ldur x0, [x19, #-8] // load field pointer
+    bl map_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_83_main_deepcopy_skip_0:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _80_main
+_80_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store ages4 arg in frame
+    mov x0, #56 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #8 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #8 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #56 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _83_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_83_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _83_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_83_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _83_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_83_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _83_main closure env_end to x9
+    stur x9, [x29, #-16] // store value
+    ldur x0, [x29, #-8] // load operand
+    adrp x1, _81 // point to string literal
+    add x1, x1, :lo12:_81
+    ldur x2, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b show
+.global _80_main_unwrapper
+_80_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-8] // load ages4 env field
+    stur x9, [x29, #-16] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _80_main
+.global _80_main_deep_release
+_80_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _80_main_release_skip_0
+    ldur x9, [x19, #-8] // load _80_main_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl map_release_ptr // release _80_main_release_field_0 map
+_80_main_release_skip_0:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _80_main_deepcopy
+_80_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _80_main_deepcopy_skip_0
+    ldur x0, [x19, #-8] // load field pointer
+    bl map_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_80_main_deepcopy_skip_0:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _76_main
+_76_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store ages3 arg in frame
+    mov x0, #56 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #8 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #8 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #56 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _80_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_80_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _80_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_80_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _80_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_80_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _80_main closure env_end to x9
+    stur x9, [x29, #-16] // store value
+    ldur x0, [x29, #-8] // load operand
+    adrp x1, _77 // point to string literal
+    add x1, x1, :lo12:_77
+    mov x2, #26 // operand literal
+    bl map_insert_ptr // store the entry
+    mov x9, x0
+    ldur x19, [x29, #-16] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _76_main_unwrapper
+_76_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-8] // load ages3 env field
+    stur x9, [x29, #-16] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _76_main
+.global _76_main_deep_release
+_76_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _76_main_release_skip_0
+    ldur x9, [x19, #-8] // load _76_main_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl map_release_ptr // release _76_main_release_field_0 map
+_76_main_release_skip_0:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _76_main_deepcopy
+_76_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _76_main_deepcopy_skip_0
+    ldur x0, [x19, #-8] // load field pointer
+    bl map_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_76_main_deepcopy_skip_0:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _72_main
+_72_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store ages2 arg in frame
+    mov x0, #56 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #8 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #8 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #56 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _76_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_76_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _76_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_76_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _76_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_76_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _76_main closure env_end to x9
+    stur x9, [x29, #-16] // store value
+    ldur x0, [x29, #-8] // load operand
+    adrp x1, _73 // point to string literal
+    add x1, x1, :lo12:_73
+    mov x2, #41 // operand literal
+    bl map_insert_ptr // store the entry
+    mov x9, x0
+    ldur x19, [x29, #-16] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _72_main_unwrapper
+_72_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-8] // load ages2 env field
+    stur x9, [x29, #-16] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _72_main
+.global _72_main_deep_release
+_72_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _72_main_release_skip_0
+    ldur x9, [x19, #-8] // load _72_main_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl map_release_ptr // release _72_main_release_field_0 map
+_72_main_release_skip_0:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _72_main_deepcopy
+_72_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _72_main_deepcopy_skip_0
+    ldur x0, [x19, #-8] // load field pointer
+    bl map_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_72_main_deepcopy_skip_0:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _69_main
+_69_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store ages arg in frame
+    mov x0, #56 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #8 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #8 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #56 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _72_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_72_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _72_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_72_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _72_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_72_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _72_main closure env_end to x9
+    stur x9, [x29, #-16] // store value
+    ldur x0, [x29, #-8] // load operand
+    adrp x1, _70 // point to string literal
+    add x1, x1, :lo12:_70
+    ldur x2, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b show
+.global _69_main_unwrapper
+_69_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-8] // load ages env field
+    stur x9, [x29, #-16] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _69_main
+.global _69_main_deep_release
+_69_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _69_main_release_skip_0
+    ldur x9, [x19, #-8] // load _69_main_release_field_0 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    bl map_release_ptr // release _69_main_release_field_0 map
+_69_main_release_skip_0:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _69_main_deepcopy
+_69_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _69_main_deepcopy_skip_0
+    ldur x0, [x19, #-8] // load field pointer
+    bl map_copy_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_69_main_deepcopy_skip_0:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global main
+main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    mov x0, #1 // key class
+    mov x1, #0 // value class
+    bl map_new_ptr // allocate the map
+    mov x20, x0
+    adrp x1, _62 // point to string literal
+    add x1, x1, :lo12:_62
+    mov x2, #30 // operand literal
+    mov x0, x20
+    bl map_insert_ptr // add the entry
+    mov x20, x0
+    adrp x1, _64 // point to string literal
+    add x1, x1, :lo12:_64
+    mov x2, #25 // operand literal
+    mov x0, x20
+    bl map_insert_ptr // add the entry
+    mov x9, x0
+    stur x9, [x29, #-8] // store value
+    mov x0, #56 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #8 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #8 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #56 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _69_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_69_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _69_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_69_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _69_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_69_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _69_main closure env_end to x9
+    stur x9, [x29, #-16] // store value
+    ldur x0, [x29, #-8] // load operand
+    adrp x1, _67 // point to string literal
+    add x1, x1, :lo12:_67
+    ldur x2, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b show
+.global main_unwrapper
+main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b main
+.global main_deep_release
+main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global main_deepcopy
+main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _start
+_start:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b main
+.section .rodata
+.p2align 3
+    .quad 0, 9 // static strings have no allocation to free
+_117:
+    .asciz "17 is %s\012"
+.p2align 3
+    .quad 0, 14 // static strings have no allocation to free
+_128:
+    .asciz "17 is missing\012"
+.p2align 3
+    .quad 0, 9 // static strings have no allocation to free
+_38:
+    .asciz "%d names\012"
+.p2align 3
+    .quad 0, 6 // static strings have no allocation to free
+_50:
+    .asciz "user%d"
+.p2align 3
+    .quad 0, 9 // static strings have no allocation to free
+_28:
+    .asciz "%d => %s\012"
+.p2align 3
+    .quad 0, 5 // static strings have no allocation to free
+_94:
+    .asciz "three"
+.p2align 3
+    .quad 0, 3 // static strings have no allocation to free
+_96:
+    .asciz "one"
+.p2align 3
+    .quad 0, 3 // static strings have no allocation to free
+_98:
+    .asciz "two"
+.p2align 3
+    .quad 0, 9 // static strings have no allocation to free
+_7:
+    .asciz "%s is %d\012"
+.p2align 3
+    .quad 0, 14 // static strings have no allocation to free
+_17:
+    .asciz "%s is unknown\012"
+.p2align 3
+    .quad 0, 5 // static strings have no allocation to free
+_90:
+    .asciz "alice"
+.p2align 3
+    .quad 0, 5 // static strings have no allocation to free
+_87:
+    .asciz "alice"
+.p2align 3
+    .quad 0, 3 // static strings have no allocation to free
+_84:
+    .asciz "bob"
+.p2align 3
+    .quad 0, 5 // static strings have no allocation to free
+_81:
+    .asciz "carol"
+.p2align 3
+    .quad 0, 3 // static strings have no allocation to free
+_77:
+    .asciz "bob"
+.p2align 3
+    .quad 0, 5 // static strings have no allocation to free
+_73:
+    .asciz "carol"
+.p2align 3
+    .quad 0, 5 // static strings have no allocation to free
+_70:
+    .asciz "carol"
+.p2align 3
+    .quad 0, 5 // static strings have no allocation to free
+_62:
+    .asciz "alice"
+.p2align 3
+    .quad 0, 3 // static strings have no allocation to free
+_64:
+    .asciz "bob"
+.p2align 3
+    .quad 0, 3 // static strings have no allocation to free
+_67:
+    .asciz "bob"
@@ -0,0 +1,4423 @@
+.text
+.p2align 2
+.global _68_division
+_68_division:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    // load exit code
+    mov x0, #0 // operand literal
+    bl exit // call libc exit to flush buffers
+.global alloc_heap_ptr
+alloc_heap_ptr:
+    add x0, x0, #7 // round size up to whole words
+    and x0, x0, #-8
+    cmp x0, #1024 // small block?
+    b.hi alloc_heap_ptr_large
+    adrp x9, heap_free_lists
+    add x9, x9, :lo12:heap_free_lists
+    ldr x10, [x9, x0] // head of this size's free list
+    cbz x10, alloc_heap_ptr_bump
+    ldr x11, [x10] // next free block
+    str x11, [x9, x0] // pop the head
+    mov x0, x10
+    ret
+alloc_heap_ptr_bump:
+    adrp x9, heap_arena
+    add x9, x9, :lo12:heap_arena
+    ldp x10, x11, [x9] // arena next and end
+    add x12, x10, x0 // end of the new block
+    cmp x12, x11
+    b.hi alloc_heap_ptr_refill
+    str x12, [x9] // bump past the block
+    mov x0, x10
+    ret
+alloc_heap_ptr_refill:
+    mov x13, x0 // keep block size across the syscall
+    mov x1, #1048576 // arena size
+    mov x0, #0 // addr hint so kernel picks mmap base
+    mov x2, #3 // prot = read/write
+    mov x3, #34 // flags = private & anonymous
+    mov x4, #-1 // fd = -1
+    mov x5, #0 // offset = 0
+    mov x8, #222 // mmap syscall
+    svc #0 // map a new arena
+    cmn x0, #4095 // mmap failed?
+    b.hs alloc_heap_ptr_failed
+    add x12, x0, x13 // block is the start of the arena
+    mov x11, #1048576
+    add x11, x0, x11 // end of the arena
+    stp x12, x11, [x9]
+    ret
+alloc_heap_ptr_large:
+    mov x1, x0 // length = block size
+    mov x0, #0 // addr hint so kernel picks mmap base
+    mov x2, #3 // prot = read/write
+    mov x3, #34 // flags = private & anonymous
+    mov x4, #-1 // fd = -1
+    mov x5, #0 // offset = 0
+    mov x8, #222 // mmap syscall
+    svc #0 // map a large block on its own
+    cmn x0, #4095 // mmap failed?
+    b.hs alloc_heap_ptr_failed
+    ret
+alloc_heap_ptr_failed:
+    mov x0, #0 // no memory left
+    ret
+.data
+.p2align 3
+heap_free_lists:
+    .zero 1032
+heap_arena:
+    .quad 0, 0
+.text
+.global free_heap_ptr
+free_heap_ptr:
+    add x1, x1, #7 // round size up to whole words
+    and x1, x1, #-8
+    cmp x1, #1024 // small block?
+    b.hi free_heap_ptr_large
+    adrp x9, heap_free_lists
+    add x9, x9, :lo12:heap_free_lists
+    ldr x10, [x9, x1] // current head
+    str x10, [x0] // link it behind the block
+    str x0, [x9, x1] // block is the new head
+    ret
+free_heap_ptr_large:
+    mov x8, #215 // munmap syscall
+    svc #0
+    ret
+.global release_heap_ptr
+release_heap_ptr:
+    stp x29, x30, [sp, #-16]! // save caller frame
+    mov x29, sp // establish frame
+    ldr x11, [x0, #24] // load env size metadata
+    ldr x12, [x0, #32] // load heap size metadata
+    sub x0, x0, x11 // compute env base pointer
+    mov x1, x12 // heap size to free
+    bl free_heap_ptr // return env to the allocator
+    ldp x29, x30, [sp], #16
+    ret
+.global _68_division_unwrapper
+_68_division_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _68_division
+.global _68_division_deep_release
+_68_division_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _68_division_deepcopy
+_68_division_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _65_division
+_65_division:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store e arg in frame
+    mov x0, #48 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x9, #0 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #48 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _68_division_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_68_division_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _68_division_deep_release // load release helper entry point
+    add x9, x9, :lo12:_68_division_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _68_division_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_68_division_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _68_division closure env_end to x9
+    stur x9, [x29, #-16] // store value
+    adrp x0, _66 // point to string literal
+    add x0, x0, :lo12:_66
+    bl printf // invoke libc printf
+    ldur x19, [x29, #-16] // load continuation env_end pointer
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _65_division_unwrapper
+_65_division_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-8] // load e env field
+    stur x9, [x29, #-16] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _65_division
+.global _65_division_deep_release
+_65_division_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _65_division_deepcopy
+_65_division_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _29_unreachable
+_29_unreachable:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    // load exit code
+    mov x0, #1 // operand literal
+    bl exit // call libc exit to flush buffers
+.global _29_unreachable_unwrapper
+_29_unreachable_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _29_unreachable
+.global _29_unreachable_deep_release
+_29_unreachable_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _29_unreachable_deepcopy
+_29_unreachable_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global unreachable
+unreachable:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store value arg in frame
+    mov x0, #48 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x9, #0 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #48 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _29_unreachable_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_29_unreachable_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _29_unreachable_deep_release // load release helper entry point
+    add x9, x9, :lo12:_29_unreachable_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _29_unreachable_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_29_unreachable_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _29_unreachable closure env_end to x9
+    stur x9, [x29, #-16] // store value
+    adrp x0, _27 // point to string literal
+    add x0, x0, :lo12:_27
+    ldur x1, [x29, #-8] // load operand
+    bl printf // invoke libc printf
+    ldur x19, [x29, #-16] // load continuation env_end pointer
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global unreachable_unwrapper
+unreachable_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-8] // load value env field
+    stur x9, [x29, #-16] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b unreachable
+.global unreachable_deep_release
+unreachable_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global unreachable_deepcopy
+unreachable_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _63_division
+_63_division:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store min arg in frame
+    stur x1, [x29, #-16] // store one arg in frame
+    mov x0, #56 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #8 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #8 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #56 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _65_division_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_65_division_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _65_division_deep_release // load release helper entry point
+    add x9, x9, :lo12:_65_division_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _65_division_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_65_division_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _65_division closure env_end to x9
+    stur x9, [x29, #-24] // store value
+    mov x0, #56 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #8 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #8 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #56 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, unreachable_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:unreachable_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, unreachable_deep_release // load release helper entry point
+    add x9, x9, :lo12:unreachable_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, unreachable_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:unreachable_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy unreachable closure env_end to x9
+    stur x9, [x29, #-32] // store value
+    ldur x9, [x29, #-8] // load operand
+    ldur x20, [x29, #-16] // load operand
+    cbz x20, _63_division_div_err_0 // check divisor for division by zero
+    cmn x20, #1
+    b.ne _63_division_div_divide_1
+    movz x10, #0x0 // smallest int
+    movk x10, #0x8000, lsl #48
+    cmp x9, x10
+    b.eq _63_division_div_err_0 // its negation overflows
+_63_division_div_divide_1:
+    sdiv x9, x9, x20 // divide by divisor
+    mov x22, x9 // keep result across releases
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _65_division release helper
+    blr x10 // release _65_division and what it holds
+    ldp x19, x22, [sp], #16
+    mov x9, x22 // restore result
+    ldur x19, [x29, #-32] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+_63_division_div_err_0:
+    ldur x0, [x29, #-32] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load unreachable release helper
+    blr x10 // release unreachable and what it holds
+    ldp x19, x22, [sp], #16
+    ldur x19, [x29, #-24] // load continuation env_end pointer
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _63_division_unwrapper
+_63_division_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-16] // load min env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-8] // load one env field
+    stur x9, [x29, #-24] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _63_division
+.global _63_division_deep_release
+_63_division_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _63_division_deepcopy
+_63_division_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _60_division
+_60_division:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store min arg in frame
+    stur x1, [x29, #-16] // store one arg in frame
+    stur x2, [x29, #-24] // store z arg in frame
+    mov x0, #64 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #8] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #16 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #16 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #64 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _63_division_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_63_division_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _63_division_deep_release // load release helper entry point
+    add x9, x9, :lo12:_63_division_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _63_division_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_63_division_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _72_division closure env_end to x9
+    stur x9, [x29, #-32] // store value
+    adrp x0, _61 // point to string literal
+    add x0, x0, :lo12:_61
+    ldur x1, [x29, #-24] // load operand
+    bl printf // invoke libc printf
+    ldur x19, [x29, #-32] // load continuation env_end pointer
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _60_division_unwrapper
+_60_division_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-24] // load min env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-16] // load one env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-8] // load z env field
+    stur x9, [x29, #-32] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _60_division
+.global _60_division_deep_release
+_60_division_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _60_division_deepcopy
+_60_division_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global division
+division:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store min arg in frame
+    stur x1, [x29, #-16] // store one arg in frame
+    mov x0, #72 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #8] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #24 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #24 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #72 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _60_division_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_60_division_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _60_division_deep_release // load release helper entry point
+    add x9, x9, :lo12:_60_division_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _60_division_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_60_division_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _73_division closure env_end to x9
+    stur x9, [x29, #-24] // store value
+    mov x0, #56 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #8 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #8 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #56 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, unreachable_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:unreachable_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, unreachable_deep_release // load release helper entry point
+    add x9, x9, :lo12:unreachable_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, unreachable_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:unreachable_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy unreachable closure env_end to x9
+    stur x9, [x29, #-32] // store value
+    ldur x9, [x29, #-8] // load operand
+    ldur x20, [x29, #-16] // load operand
+    cbz x20, division_div_err_0 // check divisor for division by zero
+    sdiv x10, x9, x20 // divide by divisor
+    msub x9, x10, x20, x9 // keep the remainder
+    mov x22, x9 // keep result across releases
+    ldur x0, [x29, #-32] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load unreachable release helper
+    blr x10 // release unreachable and what it holds
+    ldp x19, x22, [sp], #16
+    mov x9, x22 // restore result
+    ldur x19, [x29, #-24] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+division_div_err_0:
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _73_division release helper
+    blr x10 // release _73_division and what it holds
+    ldp x19, x22, [sp], #16
+    ldur x19, [x29, #-32] // load continuation env_end pointer
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global division_unwrapper
+division_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-16] // load min env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-8] // load one env field
+    stur x9, [x29, #-24] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b division
+.global division_deep_release
+division_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global division_deepcopy
+division_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _49_checked
+_49_checked:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store min arg in frame
+    stur x1, [x29, #-16] // store next arg in frame
+    stur x2, [x29, #-24] // store u arg in frame
+    adrp x0, _50 // point to string literal
+    add x0, x0, :lo12:_50
+    ldur x1, [x29, #-8] // load operand
+    ldur x2, [x29, #-24] // load operand
+    bl printf // invoke libc printf
+    ldur x19, [x29, #-16] // load continuation env_end pointer
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _49_checked_unwrapper
+_49_checked_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-24] // load min env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-16] // load next env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-8] // load u env field
+    stur x9, [x29, #-32] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _49_checked
+.global _49_checked_deep_release
+_49_checked_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _49_checked_release_skip_1
+    ldur x9, [x19, #-16] // load _49_checked_release_field_1 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _49_checked_release_field_1 release helper
+    blr x10 // release _49_checked_release_field_1 and what it holds
+    ldp x19, x22, [sp], #16
+_49_checked_release_skip_1:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global deepcopy_heap_ptr
+deepcopy_heap_ptr:
+    stp x29, x30, [sp, #-16]! // prologue: save executor frame pointer
+    mov x29, sp // prologue: establish new frame
+    stp x19, x20, [sp, #-16]! // preserve callee-saved registers
+    stp x21, x22, [sp, #-16]!
+    str x23, [sp, #-16]!
+    mov x19, x0 // capture env_end pointer
+    ldr x21, [x19, #24] // load env size metadata
+    ldr x23, [x19, #32] // load heap size metadata
+    sub x20, x19, x21 // compute env base pointer
+    mov x0, x23 // length = heap size
+    bl alloc_heap_ptr // allocate new closure env
+    mov x22, x0 // new env base pointer
+    mov x1, x20 // memcpy src
+    mov x2, x23 // memcpy length
+    bl memcpy_helper // copy env contents
+    add x23, x22, x21 // new env_end pointer
+    ldr x9, [x23, #16] // load deep copy helper entry
+    mov x0, x23 // pass new env_end pointer
+    blr x9 // invoke helper
+    mov x0, x23 // return new env_end pointer
+    ldr x23, [sp], #16
+    ldp x21, x22, [sp], #16
+    ldp x19, x20, [sp], #16
+    ldp x29, x30, [sp], #16
+    ret
+.global memcpy_helper
+memcpy_helper:
+    mov x11, #0 // counter = 0
+internal_memcpy_loop:
+    cmp x11, x2 // counter < count?
+    b.ge internal_memcpy_done
+    ldr x9, [x1, x11] // load 8 bytes from source
+    str x9, [x0, x11] // store 8 bytes to destination
+    add x11, x11, #8 // advance counter by 8
+    b internal_memcpy_loop
+internal_memcpy_done:
+    ret
+.global _49_checked_deepcopy
+_49_checked_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _49_checked_deepcopy_skip_1
+    ldur x0, [x19, #-16] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-16] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_49_checked_deepcopy_skip_1:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _46_checked
+_46_checked:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store min arg in frame
+    stur x1, [x29, #-16] // store next arg in frame
+    stur x2, [x29, #-24] // store t arg in frame
+    mov x0, #72 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #8] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #24 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #24 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #72 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _49_checked_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_49_checked_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _49_checked_deep_release // load release helper entry point
+    add x9, x9, :lo12:_49_checked_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _49_checked_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_49_checked_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _51_checked closure env_end to x9
+    stur x9, [x29, #-32] // store value
+    mov x0, #56 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #8 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #8 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #56 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, unreachable_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:unreachable_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, unreachable_deep_release // load release helper entry point
+    add x9, x9, :lo12:unreachable_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, unreachable_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:unreachable_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy unreachable closure env_end to x9
+    stur x9, [x29, #-40] // store value
+    ldur x9, [x29, #-24] // load operand
+    mov x20, #2 // operand literal
+    subs x9, x9, x20 // subtract subtrahend
+    b.vs _46_checked_overflow_0
+    mov x22, x9 // keep result across releases
+    ldur x0, [x29, #-32] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _51_checked release helper
+    blr x10 // release _51_checked and what it holds
+    ldp x19, x22, [sp], #16
+    mov x9, x22 // restore result
+    ldur x19, [x29, #-40] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+_46_checked_overflow_0:
+    mov x22, x9 // keep result across releases
+    ldur x0, [x29, #-40] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load unreachable release helper
+    blr x10 // release unreachable and what it holds
+    ldp x19, x22, [sp], #16
+    mov x9, x22 // restore result
+    ldur x19, [x29, #-32] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _46_checked_unwrapper
+_46_checked_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-24] // load min env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-16] // load next env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-8] // load t env field
+    stur x9, [x29, #-32] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _46_checked
+.global _46_checked_deep_release
+_46_checked_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _46_checked_release_skip_1
+    ldur x9, [x19, #-16] // load _46_checked_release_field_1 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _46_checked_release_field_1 release helper
+    blr x10 // release _46_checked_release_field_1 and what it holds
+    ldp x19, x22, [sp], #16
+_46_checked_release_skip_1:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _46_checked_deepcopy
+_46_checked_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt _46_checked_deepcopy_skip_1
+    ldur x0, [x19, #-16] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-16] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_46_checked_deepcopy_skip_1:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _43_checked
+_43_checked:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store min arg in frame
+    stur x1, [x29, #-16] // store next arg in frame
+    mov x0, #72 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #8] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #24 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #24 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #72 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _46_checked_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_46_checked_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _46_checked_deep_release // load release helper entry point
+    add x9, x9, :lo12:_46_checked_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _46_checked_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_46_checked_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _52_checked closure env_end to x9
+    stur x9, [x29, #-24] // store value
+    mov x0, #56 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #8 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #8 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #56 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, unreachable_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:unreachable_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, unreachable_deep_release // load release helper entry point
+    add x9, x9, :lo12:unreachable_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, unreachable_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:unreachable_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy unreachable closure env_end to x9
+    stur x9, [x29, #-32] // store value
+    ldur x9, [x29, #-8] // load operand
+    mov x20, #1 // operand literal
+    smulh x10, x9, x20 // high half of the product
+    mul x9, x9, x20 // multiply by multiplier
+    cmp x10, x9, asr #63
+    b.ne _43_checked_overflow_0
+    mov x22, x9 // keep result across releases
+    ldur x0, [x29, #-32] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load unreachable release helper
+    blr x10 // release unreachable and what it holds
+    ldp x19, x22, [sp], #16
+    mov x9, x22 // restore result
+    ldur x19, [x29, #-24] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+_43_checked_overflow_0:
+    mov x22, x9 // keep result across releases
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _52_checked release helper
+    blr x10 // release _52_checked and what it holds
+    ldp x19, x22, [sp], #16
+    mov x9, x22 // restore result
+    ldur x19, [x29, #-32] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _43_checked_unwrapper
+_43_checked_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-16] // load min env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-8] // load next env field
+    stur x9, [x29, #-24] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _43_checked
+.global _43_checked_deep_release
+_43_checked_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _43_checked_release_skip_1
+    ldur x9, [x19, #-8] // load _43_checked_release_field_1 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _43_checked_release_field_1 release helper
+    blr x10 // release _43_checked_release_field_1 and what it holds
+    ldp x19, x22, [sp], #16
+_43_checked_release_skip_1:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _43_checked_deepcopy
+_43_checked_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _43_checked_deepcopy_skip_1
+    ldur x0, [x19, #-8] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_43_checked_deepcopy_skip_1:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global overflows
+overflows:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store name arg in frame
+    stur x1, [x29, #-16] // store next arg in frame
+    stur x2, [x29, #-24] // store wrapped arg in frame
+    adrp x0, _26 // point to string literal
+    add x0, x0, :lo12:_26
+    ldur x1, [x29, #-8] // load operand
+    ldur x2, [x29, #-24] // load operand
+    bl printf // invoke libc printf
+    ldur x19, [x29, #-16] // load continuation env_end pointer
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global overflows_unwrapper
+overflows_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-24] // load name env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-16] // load next env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-8] // load wrapped env field
+    stur x9, [x29, #-32] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b overflows
+.global overflows_deep_release
+overflows_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt overflows_release_skip_1
+    ldur x9, [x19, #-16] // load overflows_release_field_1 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load overflows_release_field_1 release helper
+    blr x10 // release overflows_release_field_1 and what it holds
+    ldp x19, x22, [sp], #16
+overflows_release_skip_1:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global overflows_deepcopy
+overflows_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    cmp x9, x20
+    b.gt overflows_deepcopy_skip_1
+    ldur x0, [x19, #-16] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-16] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+overflows_deepcopy_skip_1:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _39_checked
+_39_checked:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store big arg in frame
+    stur x1, [x29, #-16] // store min arg in frame
+    stur x2, [x29, #-24] // store next arg in frame
+    mov x0, #64 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #8] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #16 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #16 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #64 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _43_checked_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_43_checked_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _43_checked_deep_release // load release helper entry point
+    add x9, x9, :lo12:_43_checked_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _43_checked_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_43_checked_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _53_checked closure env_end to x9
+    stur x9, [x29, #-32] // store value
+    mov x0, #72 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    adrp x9, _41 // point to string literal
+    add x9, x9, :lo12:_41
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-32] // load operand
+    str x9, [x20, #8] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #24 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #24 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #72 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, overflows_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:overflows_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, overflows_deep_release // load release helper entry point
+    add x9, x9, :lo12:overflows_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, overflows_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:overflows_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _54_overflows closure env_end to x9
+    stur x9, [x29, #-40] // store value
+    mov x0, #56 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #8 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #8 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #56 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, unreachable_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:unreachable_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, unreachable_deep_release // load release helper entry point
+    add x9, x9, :lo12:unreachable_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, unreachable_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:unreachable_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy unreachable closure env_end to x9
+    stur x9, [x29, #-48] // store value
+    ldur x9, [x29, #-8] // load operand
+    mov x20, #4 // operand literal
+    smulh x10, x9, x20 // high half of the product
+    mul x9, x9, x20 // multiply by multiplier
+    cmp x10, x9, asr #63
+    b.ne _39_checked_overflow_0
+    mov x22, x9 // keep result across releases
+    ldur x0, [x29, #-40] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _54_overflows release helper
+    blr x10 // release _54_overflows and what it holds
+    ldp x19, x22, [sp], #16
+    mov x9, x22 // restore result
+    ldur x19, [x29, #-48] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+_39_checked_overflow_0:
+    mov x22, x9 // keep result across releases
+    ldur x0, [x29, #-48] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load unreachable release helper
+    blr x10 // release unreachable and what it holds
+    ldp x19, x22, [sp], #16
+    mov x9, x22 // restore result
+    ldur x19, [x29, #-40] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _39_checked_unwrapper
+_39_checked_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-24] // load big env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-16] // load min env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-8] // load next env field
+    stur x9, [x29, #-32] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _39_checked
+.global _39_checked_deep_release
+_39_checked_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _39_checked_release_skip_2
+    ldur x9, [x19, #-8] // load _39_checked_release_field_2 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _39_checked_release_field_2 release helper
+    blr x10 // release _39_checked_release_field_2 and what it holds
+    ldp x19, x22, [sp], #16
+_39_checked_release_skip_2:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _39_checked_deepcopy
+_39_checked_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _39_checked_deepcopy_skip_2
+    ldur x0, [x19, #-8] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_39_checked_deepcopy_skip_2:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _35_checked
+_35_checked:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store min arg in frame
+    stur x1, [x29, #-16] // store big arg in frame
+    stur x2, [x29, #-24] // store next arg in frame
+    mov x0, #72 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #16] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #24 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #24 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #72 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _39_checked_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_39_checked_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _39_checked_deep_release // load release helper entry point
+    add x9, x9, :lo12:_39_checked_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _39_checked_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_39_checked_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _55_checked closure env_end to x9
+    stur x9, [x29, #-32] // store value
+    mov x0, #72 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    adrp x9, _37 // point to string literal
+    add x9, x9, :lo12:_37
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-32] // load operand
+    str x9, [x20, #8] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #24 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #24 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #72 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, overflows_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:overflows_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, overflows_deep_release // load release helper entry point
+    add x9, x9, :lo12:overflows_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, overflows_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:overflows_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _56_overflows closure env_end to x9
+    stur x9, [x29, #-40] // store value
+    mov x0, #56 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #8 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #8 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #56 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, unreachable_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:unreachable_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, unreachable_deep_release // load release helper entry point
+    add x9, x9, :lo12:unreachable_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, unreachable_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:unreachable_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy unreachable closure env_end to x9
+    stur x9, [x29, #-48] // store value
+    ldur x9, [x29, #-8] // load operand
+    mov x20, #1 // operand literal
+    subs x9, x9, x20 // subtract subtrahend
+    b.vs _35_checked_overflow_0
+    mov x22, x9 // keep result across releases
+    ldur x0, [x29, #-40] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _56_overflows release helper
+    blr x10 // release _56_overflows and what it holds
+    ldp x19, x22, [sp], #16
+    mov x9, x22 // restore result
+    ldur x19, [x29, #-48] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+_35_checked_overflow_0:
+    mov x22, x9 // keep result across releases
+    ldur x0, [x29, #-48] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load unreachable release helper
+    blr x10 // release unreachable and what it holds
+    ldp x19, x22, [sp], #16
+    mov x9, x22 // restore result
+    ldur x19, [x29, #-40] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _35_checked_unwrapper
+_35_checked_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-24] // load min env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-16] // load big env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-8] // load next env field
+    stur x9, [x29, #-32] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _35_checked
+.global _35_checked_deep_release
+_35_checked_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _35_checked_release_skip_2
+    ldur x9, [x19, #-8] // load _35_checked_release_field_2 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _35_checked_release_field_2 release helper
+    blr x10 // release _35_checked_release_field_2 and what it holds
+    ldp x19, x22, [sp], #16
+_35_checked_release_skip_2:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _35_checked_deepcopy
+_35_checked_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _35_checked_deepcopy_skip_2
+    ldur x0, [x19, #-8] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_35_checked_deepcopy_skip_2:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global checked
+checked:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #64 // reserve stack space for locals
+    stur x0, [x29, #-8] // store min arg in frame
+    stur x1, [x29, #-16] // store max arg in frame
+    stur x2, [x29, #-24] // store big arg in frame
+    stur x3, [x29, #-32] // store next arg in frame
+    mov x0, #72 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-32] // load operand
+    str x9, [x20, #16] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #24 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #24 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #72 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _35_checked_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_35_checked_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _35_checked_deep_release // load release helper entry point
+    add x9, x9, :lo12:_35_checked_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _35_checked_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_35_checked_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _57_checked closure env_end to x9
+    stur x9, [x29, #-40] // store value
+    mov x0, #72 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    adrp x9, _33 // point to string literal
+    add x9, x9, :lo12:_33
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-40] // load operand
+    str x9, [x20, #8] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #24 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #24 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #72 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, overflows_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:overflows_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, overflows_deep_release // load release helper entry point
+    add x9, x9, :lo12:overflows_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, overflows_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:overflows_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _58_overflows closure env_end to x9
+    stur x9, [x29, #-48] // store value
+    mov x0, #56 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #8 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #8 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #56 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, unreachable_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:unreachable_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, unreachable_deep_release // load release helper entry point
+    add x9, x9, :lo12:unreachable_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, unreachable_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:unreachable_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy unreachable closure env_end to x9
+    stur x9, [x29, #-56] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #1 // operand literal
+    adds x9, x9, x20 // add second integer
+    b.vs checked_overflow_0
+    mov x22, x9 // keep result across releases
+    ldur x0, [x29, #-48] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _58_overflows release helper
+    blr x10 // release _58_overflows and what it holds
+    ldp x19, x22, [sp], #16
+    mov x9, x22 // restore result
+    ldur x19, [x29, #-56] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+checked_overflow_0:
+    mov x22, x9 // keep result across releases
+    ldur x0, [x29, #-56] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load unreachable release helper
+    blr x10 // release unreachable and what it holds
+    ldp x19, x22, [sp], #16
+    mov x9, x22 // restore result
+    ldur x19, [x29, #-48] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global checked_unwrapper
+checked_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-32] // load min env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-24] // load max env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-16] // load big env field
+    stur x9, [x29, #-32] // store value
+    ldur x9, [x19, #-8] // load next env field
+    stur x9, [x29, #-40] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    ldur x3, [x29, #-40] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b checked
+.global checked_deep_release
+checked_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt checked_release_skip_3
+    ldur x9, [x19, #-8] // load checked_release_field_3 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load checked_release_field_3 release helper
+    blr x10 // release checked_release_field_3 and what it holds
+    ldp x19, x22, [sp], #16
+checked_release_skip_3:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global checked_deepcopy
+checked_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt checked_deepcopy_skip_3
+    ldur x0, [x19, #-8] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+checked_deepcopy_skip_3:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _120_main
+_120_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store min arg in frame
+    stur x1, [x29, #-16] // store max arg in frame
+    stur x2, [x29, #-24] // store s arg in frame
+    stur x3, [x29, #-32] // store one arg in frame
+    mov x0, #64 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-32] // load operand
+    str x9, [x20, #8] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #16 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #16 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #64 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, division_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:division_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, division_deep_release // load release helper entry point
+    add x9, x9, :lo12:division_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, division_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:division_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _121_division closure env_end to x9
+    stur x9, [x29, #-40] // store value
+    ldur x0, [x29, #-8] // load operand
+    ldur x1, [x29, #-16] // load operand
+    ldur x2, [x29, #-24] // load operand
+    ldur x3, [x29, #-40] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b checked
+.global _120_main_unwrapper
+_120_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-32] // load min env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-24] // load max env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-16] // load s env field
+    stur x9, [x29, #-32] // store value
+    ldur x9, [x19, #-8] // load one env field
+    stur x9, [x29, #-40] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    ldur x3, [x29, #-40] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _120_main
+.global _120_main_deep_release
+_120_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _120_main_deepcopy
+_120_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _3_compare
+_3_compare:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store x arg in frame
+    stur x1, [x29, #-16] // store y arg in frame
+    stur x2, [x29, #-24] // store next arg in frame
+    adrp x0, _4 // point to string literal
+    add x0, x0, :lo12:_4
+    ldur x1, [x29, #-8] // load operand
+    ldur x2, [x29, #-16] // load operand
+    bl printf // invoke libc printf
+    ldur x19, [x29, #-24] // load continuation env_end pointer
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _3_compare_unwrapper
+_3_compare_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-24] // load x env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-16] // load y env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-8] // load next env field
+    stur x9, [x29, #-32] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _3_compare
+.global _3_compare_deep_release
+_3_compare_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _3_compare_release_skip_2
+    ldur x9, [x19, #-8] // load _3_compare_release_field_2 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _3_compare_release_field_2 release helper
+    blr x10 // release _3_compare_release_field_2 and what it holds
+    ldp x19, x22, [sp], #16
+_3_compare_release_skip_2:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _3_compare_deepcopy
+_3_compare_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _3_compare_deepcopy_skip_2
+    ldur x0, [x19, #-8] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_3_compare_deepcopy_skip_2:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _7_compare
+_7_compare:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store x arg in frame
+    stur x1, [x29, #-16] // store y arg in frame
+    stur x2, [x29, #-24] // store next arg in frame
+    adrp x0, _8 // point to string literal
+    add x0, x0, :lo12:_8
+    ldur x1, [x29, #-8] // load operand
+    ldur x2, [x29, #-16] // load operand
+    bl printf // invoke libc printf
+    ldur x19, [x29, #-24] // load continuation env_end pointer
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _7_compare_unwrapper
+_7_compare_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-24] // load x env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-16] // load y env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-8] // load next env field
+    stur x9, [x29, #-32] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _7_compare
+.global _7_compare_deep_release
+_7_compare_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _7_compare_release_skip_2
+    ldur x9, [x19, #-8] // load _7_compare_release_field_2 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _7_compare_release_field_2 release helper
+    blr x10 // release _7_compare_release_field_2 and what it holds
+    ldp x19, x22, [sp], #16
+_7_compare_release_skip_2:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _7_compare_deepcopy
+_7_compare_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _7_compare_deepcopy_skip_2
+    ldur x0, [x19, #-8] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_7_compare_deepcopy_skip_2:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _1_compare
+_1_compare:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store x arg in frame
+    stur x1, [x29, #-16] // store y arg in frame
+    stur x2, [x29, #-24] // store next arg in frame
+    ldur x20, [x29, #-24] // original closure next to __next_copy_0 env_end pointer for clone
+    mov x20, x20 // clone source env_end pointer
+    ldr x21, [x20, #24] // load env size metadata for clone
+    ldr x22, [x20, #32] // load heap size metadata for clone
+    sub x19, x20, x21 // env base pointer for clone source
+    mov x0, x22 // length for cloned environment
+    bl alloc_heap_ptr // allocate cloned env block
+    mov x23, x0 // cloned closure env base pointer
+    mov x11, #0 // bytes copied
+_1_compare_clone_copy_loop_0:
+    cmp x11, x22
+    b.ge _1_compare_clone_copy_done_1
+    ldrb w12, [x19, x11] // duplicate closure env data
+    strb w12, [x23, x11]
+    add x11, x11, #1
+    b _1_compare_clone_copy_loop_0
+_1_compare_clone_copy_done_1:
+    add x20, x23, x21 // compute cloned env_end pointer
+    mov x19, x20 // cloned env_end pointer
+    ldr x9, [x19, #16] // load deepcopy helper entry point
+    str x19, [sp, #-16]! // preserve cloned env_end pointer
+    mov x0, x19 // pass env_end pointer to deepcopy helper
+    blr x9 // deepcopy reference fields
+    ldr x19, [sp], #16 // restore cloned env_end pointer
+    mov x9, x19 // copy cloned env_end pointer
+    stur x9, [x29, #-32] // store value
+    mov x0, #72 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-32] // load operand
+    str x9, [x20, #16] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #24 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #24 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #72 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _3_compare_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_3_compare_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _3_compare_deep_release // load release helper entry point
+    add x9, x9, :lo12:_3_compare_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _3_compare_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_3_compare_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _5_compare closure env_end to x9
+    stur x9, [x29, #-40] // store value
+    mov x0, #72 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #16] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #24 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #24 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #72 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _7_compare_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_7_compare_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _7_compare_deep_release // load release helper entry point
+    add x9, x9, :lo12:_7_compare_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _7_compare_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_7_compare_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _9_compare closure env_end to x9
+    stur x9, [x29, #-48] // store value
+    ldur x9, [x29, #-8] // load operand
+    ldur x20, [x29, #-16] // load operand
+    cmp x9, x20
+    b.lt ge__9_compare_false_0_0
+ge__5_compare_true_0_0:
+    ldur x0, [x29, #-48] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _9_compare release helper
+    blr x10 // release _9_compare and what it holds
+    ldp x19, x22, [sp], #16
+    ldur x20, [x29, #-40] // load _5_compare closure env_end pointer
+    mov x0, x20 // pass env_end pointer to closure
+    ldr x9, [x0, #0] // load closure unwrapper entry point
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9 // tail call into closure
+ge__9_compare_false_0_0:
+    ldur x0, [x29, #-40] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _5_compare release helper
+    blr x10 // release _5_compare and what it holds
+    ldp x19, x22, [sp], #16
+    ldur x20, [x29, #-48] // load _9_compare closure env_end pointer
+    mov x0, x20 // pass env_end pointer to closure
+    ldr x9, [x0, #0] // load closure unwrapper entry point
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9 // tail call into closure
+.global _1_compare_unwrapper
+_1_compare_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-24] // load x env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-16] // load y env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-8] // load next env field
+    stur x9, [x29, #-32] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _1_compare
+.global _1_compare_deep_release
+_1_compare_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _1_compare_release_skip_2
+    ldur x9, [x19, #-8] // load _1_compare_release_field_2 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _1_compare_release_field_2 release helper
+    blr x10 // release _1_compare_release_field_2 and what it holds
+    ldp x19, x22, [sp], #16
+_1_compare_release_skip_2:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _1_compare_deepcopy
+_1_compare_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _1_compare_deepcopy_skip_2
+    ldur x0, [x19, #-8] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_1_compare_deepcopy_skip_2:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _14_compare
+_14_compare:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store x arg in frame
+    stur x1, [x29, #-16] // store y arg in frame
+    stur x2, [x29, #-24] // store next arg in frame
+    adrp x0, _15 // point to string literal
+    add x0, x0, :lo12:_15
+    ldur x1, [x29, #-8] // load operand
+    ldur x2, [x29, #-16] // load operand
+    bl printf // invoke libc printf
+    ldur x19, [x29, #-24] // load continuation env_end pointer
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _14_compare_unwrapper
+_14_compare_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-24] // load x env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-16] // load y env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-8] // load next env field
+    stur x9, [x29, #-32] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _14_compare
+.global _14_compare_deep_release
+_14_compare_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _14_compare_release_skip_2
+    ldur x9, [x19, #-8] // load _14_compare_release_field_2 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _14_compare_release_field_2 release helper
+    blr x10 // release _14_compare_release_field_2 and what it holds
+    ldp x19, x22, [sp], #16
+_14_compare_release_skip_2:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _14_compare_deepcopy
+_14_compare_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _14_compare_deepcopy_skip_2
+    ldur x0, [x19, #-8] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_14_compare_deepcopy_skip_2:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _21_compare
+_21_compare:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    // load exit code
+    mov x0, #1 // operand literal
+    bl exit // call libc exit to flush buffers
+.global _21_compare_unwrapper
+_21_compare_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _21_compare
+.global _21_compare_deep_release
+_21_compare_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _21_compare_deepcopy
+_21_compare_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _18_compare
+_18_compare:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    mov x0, #48 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x9, #0 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #48 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _21_compare_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_21_compare_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _21_compare_deep_release // load release helper entry point
+    add x9, x9, :lo12:_21_compare_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _21_compare_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_21_compare_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _21_compare closure env_end to x9
+    stur x9, [x29, #-8] // store value
+    adrp x0, _19 // point to string literal
+    add x0, x0, :lo12:_19
+    bl printf // invoke libc printf
+    ldur x19, [x29, #-8] // load continuation env_end pointer
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _18_compare_unwrapper
+_18_compare_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _18_compare
+.global _18_compare_deep_release
+_18_compare_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _18_compare_deepcopy
+_18_compare_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _12_compare
+_12_compare:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store x arg in frame
+    stur x1, [x29, #-16] // store y arg in frame
+    stur x2, [x29, #-24] // store next arg in frame
+    mov x0, #72 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #16] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #24 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #24 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #72 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _14_compare_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_14_compare_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _14_compare_deep_release // load release helper entry point
+    add x9, x9, :lo12:_14_compare_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _14_compare_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_14_compare_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _16_compare closure env_end to x9
+    stur x9, [x29, #-32] // store value
+    mov x0, #48 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x9, #0 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #48 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _18_compare_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_18_compare_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _18_compare_deep_release // load release helper entry point
+    add x9, x9, :lo12:_18_compare_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _18_compare_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_18_compare_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _18_compare closure env_end to x9
+    stur x9, [x29, #-40] // store value
+    ldur x9, [x29, #-8] // load operand
+    ldur x20, [x29, #-16] // load operand
+    cmp x9, x20
+    b.eq ne__18_compare_false_0_0
+ne__16_compare_true_0_0:
+    ldur x0, [x29, #-40] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _18_compare release helper
+    blr x10 // release _18_compare and what it holds
+    ldp x19, x22, [sp], #16
+    ldur x20, [x29, #-32] // load _16_compare closure env_end pointer
+    mov x0, x20 // pass env_end pointer to closure
+    ldr x9, [x0, #0] // load closure unwrapper entry point
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9 // tail call into closure
+ne__18_compare_false_0_0:
+    ldur x0, [x29, #-32] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _16_compare release helper
+    blr x10 // release _16_compare and what it holds
+    ldp x19, x22, [sp], #16
+    ldur x20, [x29, #-40] // load _18_compare closure env_end pointer
+    mov x0, x20 // pass env_end pointer to closure
+    ldr x9, [x0, #0] // load closure unwrapper entry point
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9 // tail call into closure
+.global _12_compare_unwrapper
+_12_compare_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-24] // load x env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-16] // load y env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-8] // load next env field
+    stur x9, [x29, #-32] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _12_compare
+.global _12_compare_deep_release
+_12_compare_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _12_compare_release_skip_2
+    ldur x9, [x19, #-8] // load _12_compare_release_field_2 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _12_compare_release_field_2 release helper
+    blr x10 // release _12_compare_release_field_2 and what it holds
+    ldp x19, x22, [sp], #16
+_12_compare_release_skip_2:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _12_compare_deepcopy
+_12_compare_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt _12_compare_deepcopy_skip_2
+    ldur x0, [x19, #-8] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+_12_compare_deepcopy_skip_2:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global compare
+compare:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store x arg in frame
+    stur x1, [x29, #-16] // store y arg in frame
+    stur x2, [x29, #-24] // store next arg in frame
+    ldur x20, [x29, #-24] // original closure next to __next_copy_0 env_end pointer for clone
+    mov x20, x20 // clone source env_end pointer
+    ldr x21, [x20, #24] // load env size metadata for clone
+    ldr x22, [x20, #32] // load heap size metadata for clone
+    sub x19, x20, x21 // env base pointer for clone source
+    mov x0, x22 // length for cloned environment
+    bl alloc_heap_ptr // allocate cloned env block
+    mov x23, x0 // cloned closure env base pointer
+    mov x11, #0 // bytes copied
+compare_clone_copy_loop_0:
+    cmp x11, x22
+    b.ge compare_clone_copy_done_1
+    ldrb w12, [x19, x11] // duplicate closure env data
+    strb w12, [x23, x11]
+    add x11, x11, #1
+    b compare_clone_copy_loop_0
+compare_clone_copy_done_1:
+    add x20, x23, x21 // compute cloned env_end pointer
+    mov x19, x20 // cloned env_end pointer
+    ldr x9, [x19, #16] // load deepcopy helper entry point
+    str x19, [sp, #-16]! // preserve cloned env_end pointer
+    mov x0, x19 // pass env_end pointer to deepcopy helper
+    blr x9 // deepcopy reference fields
+    ldr x19, [sp], #16 // restore cloned env_end pointer
+    mov x9, x19 // copy cloned env_end pointer
+    stur x9, [x29, #-32] // store value
+    mov x0, #72 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-32] // load operand
+    str x9, [x20, #16] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #24 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #24 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #72 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _1_compare_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_1_compare_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _1_compare_deep_release // load release helper entry point
+    add x9, x9, :lo12:_1_compare_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _1_compare_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_1_compare_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _10_compare closure env_end to x9
+    stur x9, [x29, #-40] // store value
+    mov x0, #72 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #16] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #24 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #24 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #72 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _12_compare_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_12_compare_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _12_compare_deep_release // load release helper entry point
+    add x9, x9, :lo12:_12_compare_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _12_compare_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_12_compare_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _25_compare closure env_end to x9
+    stur x9, [x29, #-48] // store value
+    ldur x9, [x29, #-8] // load operand
+    ldur x20, [x29, #-16] // load operand
+    cmp x9, x20
+    b.gt le__25_compare_false_0_0
+le__10_compare_true_0_0:
+    ldur x0, [x29, #-48] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _25_compare release helper
+    blr x10 // release _25_compare and what it holds
+    ldp x19, x22, [sp], #16
+    ldur x20, [x29, #-40] // load _10_compare closure env_end pointer
+    mov x0, x20 // pass env_end pointer to closure
+    ldr x9, [x0, #0] // load closure unwrapper entry point
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9 // tail call into closure
+le__25_compare_false_0_0:
+    ldur x0, [x29, #-40] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _10_compare release helper
+    blr x10 // release _10_compare and what it holds
+    ldp x19, x22, [sp], #16
+    ldur x20, [x29, #-48] // load _25_compare closure env_end pointer
+    mov x0, x20 // pass env_end pointer to closure
+    ldr x9, [x0, #0] // load closure unwrapper entry point
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9 // tail call into closure
+.global compare_unwrapper
+compare_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-24] // load x env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-16] // load y env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-8] // load next env field
+    stur x9, [x29, #-32] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b compare
+.global compare_deep_release
+compare_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load __num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt compare_release_skip_2
+    ldur x9, [x19, #-8] // load compare_release_field_2 env field
+    stur x9, [x29, #-24] // store value
+    ldur x0, [x29, #-24] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load compare_release_field_2 release helper
+    blr x10 // release compare_release_field_2 and what it holds
+    ldp x19, x22, [sp], #16
+compare_release_skip_2:
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global compare_deepcopy
+compare_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldr x9, [x19, #40] // load num_remaining env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x29, #-16] // load operand
+    mov x20, #0 // operand literal
+    cmp x9, x20
+    b.gt compare_deepcopy_skip_2
+    ldur x0, [x19, #-8] // load field pointer
+    bl deepcopy_heap_ptr // duplicate heap pointer
+    mov x9, x0
+    stur x9, [x19, #-8] // store duplicated pointer
+    stur x9, [x29, #-24] // store value
+compare_deepcopy_skip_2:
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _118_main
+_118_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #64 // reserve stack space for locals
+    stur x0, [x29, #-8] // store m arg in frame
+    stur x1, [x29, #-16] // store r arg in frame
+    stur x2, [x29, #-24] // store min arg in frame
+    stur x3, [x29, #-32] // store max arg in frame
+    stur x4, [x29, #-40] // store s arg in frame
+    stur x5, [x29, #-48] // store one arg in frame
+    mov x0, #80 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-32] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-40] // load operand
+    str x9, [x20, #16] // capture arg into env
+    ldur x9, [x29, #-48] // load operand
+    str x9, [x20, #24] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #32 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #32 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #80 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _120_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_120_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _120_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_120_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _120_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_120_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _122_main closure env_end to x9
+    stur x9, [x29, #-56] // store value
+    ldur x0, [x29, #-8] // load operand
+    ldur x1, [x29, #-16] // load operand
+    ldur x2, [x29, #-56] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b compare
+.global _118_main_unwrapper
+_118_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #64 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-48] // load m env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-40] // load r env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-32] // load min env field
+    stur x9, [x29, #-32] // store value
+    ldur x9, [x19, #-24] // load max env field
+    stur x9, [x29, #-40] // store value
+    ldur x9, [x19, #-16] // load s env field
+    stur x9, [x29, #-48] // store value
+    ldur x9, [x19, #-8] // load one env field
+    stur x9, [x29, #-56] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    ldur x3, [x29, #-40] // load operand
+    ldur x4, [x29, #-48] // load operand
+    ldur x5, [x29, #-56] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _118_main
+.global _118_main_deep_release
+_118_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _118_main_deepcopy
+_118_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _114_main
+_114_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #64 // reserve stack space for locals
+    stur x0, [x29, #-8] // store m arg in frame
+    stur x1, [x29, #-16] // store r arg in frame
+    stur x2, [x29, #-24] // store min arg in frame
+    stur x3, [x29, #-32] // store max arg in frame
+    stur x4, [x29, #-40] // store s arg in frame
+    stur x5, [x29, #-48] // store one arg in frame
+    mov x0, #96 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #16] // capture arg into env
+    ldur x9, [x29, #-32] // load operand
+    str x9, [x20, #24] // capture arg into env
+    ldur x9, [x29, #-40] // load operand
+    str x9, [x20, #32] // capture arg into env
+    ldur x9, [x29, #-48] // load operand
+    str x9, [x20, #40] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #48 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #48 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #96 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _118_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_118_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _118_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_118_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _118_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_118_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _123_main closure env_end to x9
+    stur x9, [x29, #-56] // store value
+    mov x0, #3 // operand literal
+    mov x1, #3 // operand literal
+    ldur x2, [x29, #-56] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b compare
+.global _114_main_unwrapper
+_114_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #64 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-48] // load m env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-40] // load r env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-32] // load min env field
+    stur x9, [x29, #-32] // store value
+    ldur x9, [x19, #-24] // load max env field
+    stur x9, [x29, #-40] // store value
+    ldur x9, [x19, #-16] // load s env field
+    stur x9, [x29, #-48] // store value
+    ldur x9, [x19, #-8] // load one env field
+    stur x9, [x29, #-56] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    ldur x3, [x29, #-40] // load operand
+    ldur x4, [x29, #-48] // load operand
+    ldur x5, [x29, #-56] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _114_main
+.global _114_main_deep_release
+_114_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _114_main_deepcopy
+_114_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _110_main
+_110_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #64 // reserve stack space for locals
+    stur x0, [x29, #-8] // store m arg in frame
+    stur x1, [x29, #-16] // store r arg in frame
+    stur x2, [x29, #-24] // store min arg in frame
+    stur x3, [x29, #-32] // store max arg in frame
+    stur x4, [x29, #-40] // store s arg in frame
+    stur x5, [x29, #-48] // store one arg in frame
+    mov x0, #96 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #16] // capture arg into env
+    ldur x9, [x29, #-32] // load operand
+    str x9, [x20, #24] // capture arg into env
+    ldur x9, [x29, #-40] // load operand
+    str x9, [x20, #32] // capture arg into env
+    ldur x9, [x29, #-48] // load operand
+    str x9, [x20, #40] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #48 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #48 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #96 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _114_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_114_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _114_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_114_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _114_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_114_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _124_main closure env_end to x9
+    stur x9, [x29, #-56] // store value
+    mov x0, #2 // operand literal
+    mov x1, #3 // operand literal
+    ldur x2, [x29, #-56] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b compare
+.global _110_main_unwrapper
+_110_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #64 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-48] // load m env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-40] // load r env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-32] // load min env field
+    stur x9, [x29, #-32] // store value
+    ldur x9, [x19, #-24] // load max env field
+    stur x9, [x29, #-40] // store value
+    ldur x9, [x19, #-16] // load s env field
+    stur x9, [x29, #-48] // store value
+    ldur x9, [x19, #-8] // load one env field
+    stur x9, [x29, #-56] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    ldur x3, [x29, #-40] // load operand
+    ldur x4, [x29, #-48] // load operand
+    ldur x5, [x29, #-56] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _110_main
+.global _110_main_deep_release
+_110_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _110_main_deepcopy
+_110_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _107_main
+_107_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #96 // reserve stack space for locals
+    stur x0, [x29, #-8] // store m arg in frame
+    stur x1, [x29, #-16] // store a arg in frame
+    stur x2, [x29, #-24] // store o arg in frame
+    stur x3, [x29, #-32] // store x arg in frame
+    stur x4, [x29, #-40] // store s arg in frame
+    stur x5, [x29, #-48] // store h arg in frame
+    stur x6, [x29, #-56] // store min arg in frame
+    stur x7, [x29, #-64] // store max arg in frame
+    ldr x9, [x29, #16] // load spilled one arg
+    stur x9, [x29, #-72] // store spilled arg
+    ldr x9, [x29, #24] // load spilled r arg
+    stur x9, [x29, #-80] // store spilled arg
+    mov x0, #96 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-80] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-56] // load operand
+    str x9, [x20, #16] // capture arg into env
+    ldur x9, [x29, #-64] // load operand
+    str x9, [x20, #24] // capture arg into env
+    ldur x9, [x29, #-40] // load operand
+    str x9, [x20, #32] // capture arg into env
+    ldur x9, [x29, #-72] // load operand
+    str x9, [x20, #40] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #48 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #48 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #96 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _110_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_110_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _110_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_110_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _110_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_110_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #0 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _125_main closure env_end to x9
+    stur x9, [x29, #-88] // store value
+    adrp x0, _108 // point to string literal
+    add x0, x0, :lo12:_108
+    ldur x1, [x29, #-8] // load operand
+    ldur x2, [x29, #-80] // load operand
+    ldur x3, [x29, #-16] // load operand
+    ldur x4, [x29, #-24] // load operand
+    ldur x5, [x29, #-32] // load operand
+    ldur x6, [x29, #-40] // load operand
+    ldur x7, [x29, #-48] // load operand
+    bl printf // invoke libc printf
+    ldur x19, [x29, #-88] // load continuation env_end pointer
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _107_main_unwrapper
+_107_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #96 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-80] // load m env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-72] // load a env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-64] // load o env field
+    stur x9, [x29, #-32] // store value
+    ldur x9, [x19, #-56] // load x env field
+    stur x9, [x29, #-40] // store value
+    ldur x9, [x19, #-48] // load s env field
+    stur x9, [x29, #-48] // store value
+    ldur x9, [x19, #-40] // load h env field
+    stur x9, [x29, #-56] // store value
+    ldur x9, [x19, #-32] // load min env field
+    stur x9, [x29, #-64] // store value
+    ldur x9, [x19, #-24] // load max env field
+    stur x9, [x29, #-72] // store value
+    ldur x9, [x19, #-16] // load one env field
+    stur x9, [x29, #-80] // store value
+    ldur x9, [x19, #-8] // load r env field
+    stur x9, [x29, #-88] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    sub sp, sp, #16 // reserve stack args
+    ldur x9, [x29, #-80] // load operand
+    str x9, [sp, #0] // stack arg
+    ldur x9, [x29, #-88] // load operand
+    str x9, [sp, #8] // stack arg
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    ldur x3, [x29, #-40] // load operand
+    ldur x4, [x29, #-48] // load operand
+    ldur x5, [x29, #-56] // load operand
+    ldur x6, [x29, #-64] // load operand
+    ldur x7, [x29, #-72] // load operand
+    ldp x29, x30, [x29] // unwind, keeping stack args
+    b _107_main
+.global _107_main_deep_release
+_107_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _107_main_deepcopy
+_107_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _104_main
+_104_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #96 // reserve stack space for locals
+    stur x0, [x29, #-8] // store m arg in frame
+    stur x1, [x29, #-16] // store a arg in frame
+    stur x2, [x29, #-24] // store o arg in frame
+    stur x3, [x29, #-32] // store x arg in frame
+    stur x4, [x29, #-40] // store s arg in frame
+    stur x5, [x29, #-48] // store h arg in frame
+    stur x6, [x29, #-56] // store min arg in frame
+    stur x7, [x29, #-64] // store one arg in frame
+    ldr x9, [x29, #16] // load spilled max arg
+    stur x9, [x29, #-72] // store spilled arg
+    mov x0, #128 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #16] // capture arg into env
+    ldur x9, [x29, #-32] // load operand
+    str x9, [x20, #24] // capture arg into env
+    ldur x9, [x29, #-40] // load operand
+    str x9, [x20, #32] // capture arg into env
+    ldur x9, [x29, #-48] // load operand
+    str x9, [x20, #40] // capture arg into env
+    ldur x9, [x29, #-56] // load operand
+    str x9, [x20, #48] // capture arg into env
+    ldur x9, [x29, #-72] // load operand
+    str x9, [x20, #56] // capture arg into env
+    ldur x9, [x29, #-64] // load operand
+    str x9, [x20, #64] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #80 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #80 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #128 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _107_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_107_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _107_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_107_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _107_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_107_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _126_main closure env_end to x9
+    stur x9, [x29, #-80] // store value
+    mov x0, #56 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #8 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #8 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #56 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, unreachable_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:unreachable_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, unreachable_deep_release // load release helper entry point
+    add x9, x9, :lo12:unreachable_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, unreachable_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:unreachable_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy unreachable closure env_end to x9
+    stur x9, [x29, #-88] // store value
+    ldur x9, [x29, #-8] // load operand
+    mov x20, #3 // operand literal
+    cbz x20, _104_main_div_err_0 // check divisor for division by zero
+    sdiv x10, x9, x20 // divide by divisor
+    msub x9, x10, x20, x9 // keep the remainder
+    mov x22, x9 // keep result across releases
+    ldur x0, [x29, #-88] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load unreachable release helper
+    blr x10 // release unreachable and what it holds
+    ldp x19, x22, [sp], #16
+    mov x9, x22 // restore result
+    ldur x19, [x29, #-80] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+_104_main_div_err_0:
+    ldur x0, [x29, #-80] // load operand
+    stp x19, x22, [sp, #-16]! // keep the pinned env and a kept result
+    ldr x10, [x0, #8] // load _126_main release helper
+    blr x10 // release _126_main and what it holds
+    ldp x19, x22, [sp], #16
+    ldur x19, [x29, #-88] // load continuation env_end pointer
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _104_main_unwrapper
+_104_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #80 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-72] // load m env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-64] // load a env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-56] // load o env field
+    stur x9, [x29, #-32] // store value
+    ldur x9, [x19, #-48] // load x env field
+    stur x9, [x29, #-40] // store value
+    ldur x9, [x19, #-40] // load s env field
+    stur x9, [x29, #-48] // store value
+    ldur x9, [x19, #-32] // load h env field
+    stur x9, [x29, #-56] // store value
+    ldur x9, [x19, #-24] // load min env field
+    stur x9, [x29, #-64] // store value
+    ldur x9, [x19, #-16] // load one env field
+    stur x9, [x29, #-72] // store value
+    ldur x9, [x19, #-8] // load max env field
+    stur x9, [x29, #-80] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    sub sp, sp, #16 // reserve stack args
+    ldur x9, [x29, #-80] // load operand
+    str x9, [sp, #0] // stack arg
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    ldur x3, [x29, #-40] // load operand
+    ldur x4, [x29, #-48] // load operand
+    ldur x5, [x29, #-56] // load operand
+    ldur x6, [x29, #-64] // load operand
+    ldur x7, [x29, #-72] // load operand
+    ldp x29, x30, [x29] // unwind, keeping stack args
+    b _104_main
+.global _104_main_deep_release
+_104_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _104_main_deepcopy
+_104_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _102_main
+_102_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #80 // reserve stack space for locals
+    stur x0, [x29, #-8] // store min arg in frame
+    stur x1, [x29, #-16] // store m arg in frame
+    stur x2, [x29, #-24] // store a arg in frame
+    stur x3, [x29, #-32] // store o arg in frame
+    stur x4, [x29, #-40] // store x arg in frame
+    stur x5, [x29, #-48] // store s arg in frame
+    stur x6, [x29, #-56] // store h arg in frame
+    stur x7, [x29, #-64] // store one arg in frame
+    mov x0, #120 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-32] // load operand
+    str x9, [x20, #16] // capture arg into env
+    ldur x9, [x29, #-40] // load operand
+    str x9, [x20, #24] // capture arg into env
+    ldur x9, [x29, #-48] // load operand
+    str x9, [x20, #32] // capture arg into env
+    ldur x9, [x29, #-56] // load operand
+    str x9, [x20, #40] // capture arg into env
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #48] // capture arg into env
+    ldur x9, [x29, #-64] // load operand
+    str x9, [x20, #56] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #72 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #72 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #120 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _104_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_104_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _104_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_104_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _104_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_104_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _127_main closure env_end to x9
+    stur x9, [x29, #-72] // store value
+    ldur x9, [x29, #-8] // load operand
+    ldur x20, [x29, #-64] // load operand
+    eor x9, x9, x20 // bitwise xor
+    ldur x19, [x29, #-72] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _102_main_unwrapper
+_102_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #80 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-64] // load min env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-56] // load m env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-48] // load a env field
+    stur x9, [x29, #-32] // store value
+    ldur x9, [x19, #-40] // load o env field
+    stur x9, [x29, #-40] // store value
+    ldur x9, [x19, #-32] // load x env field
+    stur x9, [x29, #-48] // store value
+    ldur x9, [x19, #-24] // load s env field
+    stur x9, [x29, #-56] // store value
+    ldur x9, [x19, #-16] // load h env field
+    stur x9, [x29, #-64] // store value
+    ldur x9, [x19, #-8] // load one env field
+    stur x9, [x29, #-72] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    ldur x3, [x29, #-40] // load operand
+    ldur x4, [x29, #-48] // load operand
+    ldur x5, [x29, #-56] // load operand
+    ldur x6, [x29, #-64] // load operand
+    ldur x7, [x29, #-72] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _102_main
+.global _102_main_deep_release
+_102_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _102_main_deepcopy
+_102_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _99_main
+_99_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #64 // reserve stack space for locals
+    stur x0, [x29, #-8] // store m arg in frame
+    stur x1, [x29, #-16] // store a arg in frame
+    stur x2, [x29, #-24] // store o arg in frame
+    stur x3, [x29, #-32] // store x arg in frame
+    stur x4, [x29, #-40] // store s arg in frame
+    stur x5, [x29, #-48] // store h arg in frame
+    stur x6, [x29, #-56] // store min arg in frame
+    mov x0, #112 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-56] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #16] // capture arg into env
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #24] // capture arg into env
+    ldur x9, [x29, #-32] // load operand
+    str x9, [x20, #32] // capture arg into env
+    ldur x9, [x29, #-40] // load operand
+    str x9, [x20, #40] // capture arg into env
+    ldur x9, [x29, #-48] // load operand
+    str x9, [x20, #48] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #64 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #64 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #112 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _102_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_102_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _102_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_102_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _102_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_102_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _128_main closure env_end to x9
+    stur x9, [x29, #-64] // store value
+    mov x9, #1 // operand literal
+    neg x9, x9 // negate
+    ldur x19, [x29, #-64] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _99_main_unwrapper
+_99_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #64 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-56] // load m env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-48] // load a env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-40] // load o env field
+    stur x9, [x29, #-32] // store value
+    ldur x9, [x19, #-32] // load x env field
+    stur x9, [x29, #-40] // store value
+    ldur x9, [x19, #-24] // load s env field
+    stur x9, [x29, #-48] // store value
+    ldur x9, [x19, #-16] // load h env field
+    stur x9, [x29, #-56] // store value
+    ldur x9, [x19, #-8] // load min env field
+    stur x9, [x29, #-64] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    ldur x3, [x29, #-40] // load operand
+    ldur x4, [x29, #-48] // load operand
+    ldur x5, [x29, #-56] // load operand
+    ldur x6, [x29, #-64] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _99_main
+.global _99_main_deep_release
+_99_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _99_main_deepcopy
+_99_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _95_main
+_95_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #64 // reserve stack space for locals
+    stur x0, [x29, #-8] // store m arg in frame
+    stur x1, [x29, #-16] // store a arg in frame
+    stur x2, [x29, #-24] // store o arg in frame
+    stur x3, [x29, #-32] // store x arg in frame
+    stur x4, [x29, #-40] // store s arg in frame
+    stur x5, [x29, #-48] // store h arg in frame
+    mov x0, #104 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #16] // capture arg into env
+    ldur x9, [x29, #-32] // load operand
+    str x9, [x20, #24] // capture arg into env
+    ldur x9, [x29, #-40] // load operand
+    str x9, [x20, #32] // capture arg into env
+    ldur x9, [x29, #-48] // load operand
+    str x9, [x20, #40] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #56 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #56 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #104 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _99_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_99_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _99_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_99_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _99_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_99_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _129_main closure env_end to x9
+    stur x9, [x29, #-56] // store value
+    mov x9, #1 // operand literal
+    mov x20, #63 // operand literal
+    lsl x9, x9, x20 // shift left
+    ldur x19, [x29, #-56] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _95_main_unwrapper
+_95_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #64 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-48] // load m env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-40] // load a env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-32] // load o env field
+    stur x9, [x29, #-32] // store value
+    ldur x9, [x19, #-24] // load x env field
+    stur x9, [x29, #-40] // store value
+    ldur x9, [x19, #-16] // load s env field
+    stur x9, [x29, #-48] // store value
+    ldur x9, [x19, #-8] // load h env field
+    stur x9, [x29, #-56] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    ldur x3, [x29, #-40] // load operand
+    ldur x4, [x29, #-48] // load operand
+    ldur x5, [x29, #-56] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _95_main
+.global _95_main_deep_release
+_95_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _95_main_deepcopy
+_95_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _92_main
+_92_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store m arg in frame
+    stur x1, [x29, #-16] // store a arg in frame
+    stur x2, [x29, #-24] // store o arg in frame
+    stur x3, [x29, #-32] // store x arg in frame
+    stur x4, [x29, #-40] // store s arg in frame
+    mov x0, #96 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #16] // capture arg into env
+    ldur x9, [x29, #-32] // load operand
+    str x9, [x20, #24] // capture arg into env
+    ldur x9, [x29, #-40] // load operand
+    str x9, [x20, #32] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #48 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #48 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #96 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _95_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_95_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _95_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_95_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _95_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_95_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _130_main closure env_end to x9
+    stur x9, [x29, #-48] // store value
+    ldur x9, [x29, #-8] // load operand
+    mov x20, #1 // operand literal
+    asr x9, x9, x20 // shift right keeping the sign
+    ldur x19, [x29, #-48] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _92_main_unwrapper
+_92_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-40] // load m env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-32] // load a env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-24] // load o env field
+    stur x9, [x29, #-32] // store value
+    ldur x9, [x19, #-16] // load x env field
+    stur x9, [x29, #-40] // store value
+    ldur x9, [x19, #-8] // load s env field
+    stur x9, [x29, #-48] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    ldur x3, [x29, #-40] // load operand
+    ldur x4, [x29, #-48] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _92_main
+.global _92_main_deep_release
+_92_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _92_main_deepcopy
+_92_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _88_main
+_88_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store m arg in frame
+    stur x1, [x29, #-16] // store a arg in frame
+    stur x2, [x29, #-24] // store o arg in frame
+    stur x3, [x29, #-32] // store x arg in frame
+    mov x0, #88 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #16] // capture arg into env
+    ldur x9, [x29, #-32] // load operand
+    str x9, [x20, #24] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #40 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #40 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #88 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _92_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_92_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _92_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_92_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _92_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_92_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _131_main closure env_end to x9
+    stur x9, [x29, #-40] // store value
+    mov x9, #1 // operand literal
+    mov x20, #62 // operand literal
+    lsl x9, x9, x20 // shift left
+    ldur x19, [x29, #-40] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _88_main_unwrapper
+_88_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #48 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-32] // load m env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-24] // load a env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-16] // load o env field
+    stur x9, [x29, #-32] // store value
+    ldur x9, [x19, #-8] // load x env field
+    stur x9, [x29, #-40] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    ldur x3, [x29, #-40] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _88_main
+.global _88_main_deep_release
+_88_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _88_main_deepcopy
+_88_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _84_main
+_84_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store m arg in frame
+    stur x1, [x29, #-16] // store a arg in frame
+    stur x2, [x29, #-24] // store o arg in frame
+    mov x0, #80 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #8] // capture arg into env
+    ldur x9, [x29, #-24] // load operand
+    str x9, [x20, #16] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #32 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #32 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #80 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _88_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_88_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _88_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_88_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _88_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_88_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _132_main closure env_end to x9
+    stur x9, [x29, #-32] // store value
+    mov x9, #12 // operand literal
+    mov x20, #10 // operand literal
+    eor x9, x9, x20 // bitwise xor
+    ldur x19, [x29, #-32] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _84_main_unwrapper
+_84_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-24] // load m env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-16] // load a env field
+    stur x9, [x29, #-24] // store value
+    ldur x9, [x19, #-8] // load o env field
+    stur x9, [x29, #-32] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    ldur x2, [x29, #-32] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _84_main
+.global _84_main_deep_release
+_84_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _84_main_deepcopy
+_84_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _80_main
+_80_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store m arg in frame
+    stur x1, [x29, #-16] // store a arg in frame
+    mov x0, #72 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #0] // capture arg into env
+    ldur x9, [x29, #-16] // load operand
+    str x9, [x20, #8] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #24 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #24 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #72 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _84_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_84_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _84_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_84_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _84_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_84_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _133_main closure env_end to x9
+    stur x9, [x29, #-24] // store value
+    mov x9, #12 // operand literal
+    mov x20, #10 // operand literal
+    orr x9, x9, x20 // bitwise or
+    ldur x19, [x29, #-24] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _80_main_unwrapper
+_80_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #32 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-16] // load m env field
+    stur x9, [x29, #-16] // store value
+    ldur x9, [x19, #-8] // load a env field
+    stur x9, [x29, #-24] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    ldur x1, [x29, #-24] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _80_main
+.global _80_main_deep_release
+_80_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _80_main_deepcopy
+_80_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _76_main
+_76_main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store m arg in frame
+    mov x0, #64 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    ldur x9, [x29, #-8] // load operand
+    str x9, [x20, #0] // capture arg into env
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #16 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #16 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #64 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _80_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_80_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _80_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_80_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _80_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_80_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _134_main closure env_end to x9
+    stur x9, [x29, #-16] // store value
+    mov x9, #12 // operand literal
+    mov x20, #10 // operand literal
+    and x9, x9, x20 // bitwise and
+    ldur x19, [x29, #-16] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global _76_main_unwrapper
+_76_main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    ldur x9, [x19, #-8] // load m env field
+    stur x9, [x29, #-16] // store value
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    ldur x0, [x29, #-16] // load operand
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b _76_main
+.global _76_main_deep_release
+_76_main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _76_main_deepcopy
+_76_main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global main
+main:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    mov x0, #56 // length for allocation
+    bl alloc_heap_ptr // allocate env block
+    mov x9, x0
+    mov x20, x9 // closure env base pointer
+    mov x19, x20 // env_end pointer before metadata
+    mov x16, #8 // env payload size
+    add x19, x19, x16 // move pointer past env payload
+    mov x9, #8 // store env size metadata
+    str x9, [x19, #24] // env size metadata
+    mov x9, #56 // store heap size metadata
+    str x9, [x19, #32] // heap size metadata
+    adrp x9, _76_main_unwrapper // load unwrapper entry point
+    add x9, x9, :lo12:_76_main_unwrapper
+    str x9, [x19, #0] // store unwrapper entry in metadata
+    adrp x9, _76_main_deep_release // load release helper entry point
+    add x9, x9, :lo12:_76_main_deep_release
+    str x9, [x19, #8] // store release pointer in metadata
+    adrp x9, _76_main_deepcopy // load deep copy helper entry point
+    add x9, x9, :lo12:_76_main_deepcopy
+    str x9, [x19, #16] // store deep copy pointer in metadata
+    mov x9, #1 // num_remaining
+    str x9, [x19, #40] // store num_remaining
+    mov x9, x19 // copy _76_main closure env_end to x9
+    stur x9, [x29, #-8] // store value
+    mov x9, #7 // operand literal
+    neg x9, x9 // negate
+    ldur x19, [x29, #-8] // load continuation env_end pointer
+    stur x9, [x19, #-8] // store env field
+    ldr x9, [x19, #0] // load continuation entry point
+    mov x0, x19 // pass env_end pointer to continuation
+    mov sp, x29 // unwind before jumping
+    ldp x29, x30, [sp], #16
+    br x9
+.global main_unwrapper
+main_unwrapper:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b main
+.global main_deep_release
+main_deep_release:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov x0, x19 // use pinned __env_end env_end pointer
+    bl release_heap_ptr // release __env_end closure environment
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global main_deepcopy
+main_deepcopy:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    sub sp, sp, #16 // reserve stack space for locals
+    stur x0, [x29, #-8] // store env_end arg in frame
+    ldur x19, [x29, #-8] // load operand
+    mov sp, x29 // restore caller frame
+    ldp x29, x30, [sp], #16
+    ret
+
+.global _start
+_start:
+    stp x29, x30, [sp, #-16]! // save executor frame pointer
+    mov x29, sp // establish new frame base
+    mov sp, x29 // unwind before named jump
+    ldp x29, x30, [sp], #16
+    b main
+.section .rodata
+.p2align 3
+    .quad 0, 17 // static strings have no allocation to free
+_66:
+    .asciz "min div -1 fails\012"
+.p2align 3
+    .quad 0, 16 // static strings have no allocation to free
+_27:
+    .asciz "unreachable %ld\012"
+.p2align 3
+    .quad 0, 16 // static strings have no allocation to free
+_61:
+    .asciz "min rem -1 = %d\012"
+.p2align 3
+    .quad 0, 25 // static strings have no allocation to free
+_50:
+    .asciz "%ld minus 2 wraps to %ld\012"
+.p2align 3
+    .quad 0, 20 // static strings have no allocation to free
+_26:
+    .asciz "%s overflows to %ld\012"
+.p2align 3
+    .quad 0, 4 // static strings have no allocation to free
+_41:
+    .asciz "mulc"
+.p2align 3
+    .quad 0, 4 // static strings have no allocation to free
+_37:
+    .asciz "subc"
+.p2align 3
+    .quad 0, 4 // static strings have no allocation to free
+_33:
+    .asciz "addc"
+.p2align 3
+    .quad 0, 9 // static strings have no allocation to free
+_4:
+    .asciz "%d == %d\012"
+.p2align 3
+    .quad 0, 8 // static strings have no allocation to free
+_8:
+    .asciz "%d < %d\012"
+.p2align 3
+    .quad 0, 8 // static strings have no allocation to free
+_15:
+    .asciz "%d > %d\012"
+.p2align 3
+    .quad 0, 12 // static strings have no allocation to free
+_19:
+    .asciz "unreachable\012"
+.p2align 3
+    .quad 0, 49 // static strings have no allocation to free
+_108:
+    .asciz "neg %d rem %d and %d or %d xor %d shl %ld shr %d\012"
@@ -1,19 +1,28 @@
 use std::fs;
-use std::io::Cursor;
+use std::io::{self, Write};
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
+use compiler::compiler::air::AirFunction;
+use compiler::compiler::diagnostic::Diagnostics;
 use compiler::compiler::error::{self, Code, Error};
 use compiler::compiler::hir;
+use compiler::compiler::module::ModuleReader;
+use compiler::compiler::source_map::SourceMap;
 use compiler::compiler::span::Span;
+use compiler::compiler::target::Arch;
 use compiler::compiler::{
-    compile, format_air::render_air_functions, format_hir::render_normalized_rgo, lexer::Lexer,
-    parser::Parser,
+    compile_modules, entry_target_items, format_air::render_air_functions,
+    format_hir::render_normalized_rgo, interpreter, lower_modules, ownership,
+    reject_root_execution,
 };
-use compiler::debug_tools::test_helpers::generate_air_functions;
 
 const GENERATED_DIR: &str = "tests/generated";
 const TEST_TARGET: &str = "main";
+/// Stack for the interpreter thread. Cloning a closure recurses on the host
+/// stack once per nested closure, and a debug build's frames are far larger
+/// than the native program's.
+const INTERPRETER_STACK_SIZE: usize = 256 << 20;
 
 #[test]
 fn golden_test() {
@@ -23,12 +32,63 @@ fn golden_test() {
     verify_expected_runtime_outputs(Path::new("tests/golden"), bin_dir);
 }
 
+/// Runs every golden built for AArch64 under qemu-user. It needs the cross
+/// binutils and `qemu-aarch64`, so it only runs when asked for with
+/// `cargo test -- --ignored`, and fails if any of them is missing.
+#[test]
+#[ignore = "needs aarch64-linux-gnu binutils and qemu-aarch64; run with `cargo test -- --ignored`"]
+fn aarch64_golden_test() {
+    let tools = [
+        "aarch64-linux-gnu-as",
+        "aarch64-linux-gnu-ld",
+        "qemu-aarch64",
+    ];
+    if let Some(missing) = tools.iter().find(|tool| !tool_available(tool)) {
+        panic!("cannot run the aarch64 goldens: `{missing}` is not installed");
+    }
+    let bin_dir = Path::new("bin/aarch64");
+    fs::create_dir_all(bin_dir).expect("failed to create bin directory");
+    verify_expected_runtime_outputs_for(Path::new("tests/golden"), bin_dir, Arch::Aarch64);
+}
+
+/// Assembles every golden built for AArch64, so `cargo test` catches backend
+/// output the GNU assembler would reject even where qemu is not installed.
+/// It falls back to `llvm-mc` and is skipped, with a note on stderr, when
+/// neither assembler is around.
+#[test]
+fn aarch64_assemble_test() {
+    let Some(assembler) = aarch64_assembler() else {
+        // Written straight to stderr so the test harness does not swallow it.
+        let _ = writeln!(
+            io::stderr(),
+            "skipping aarch64_assemble_test: neither aarch64-linux-gnu-as nor llvm-mc is installed"
+        );
+        return;
+    };
+    let out_dir = Path::new("bin/aarch64-asm");
+    fs::create_dir_all(out_dir).expect("failed to create bin directory");
+    for test in collect_test_cases(Path::new("tests/golden")) {
+        let asm = compile_source(&test.source, TEST_TARGET, Arch::Aarch64)
+            .unwrap_or_else(|err| panic!("{}: {err}", test.source.display()));
+        let asm_path = out_dir.join(format!("{}.s", test.name));
+        let obj_path = out_dir.join(format!("{}.o", test.name));
+        fs::write(&asm_path, asm).expect("failed to write aarch64 assembly");
+        let mut cmd = assembler.command(&asm_path, &obj_path);
+        run_command(
+            &mut cmd,
+            &format!(
+                "assembling {} with {}",
+                asm_path.display(),
+                assembler.name()
+            ),
+        );
+    }
+}
+
 #[test]
 fn failing_test() {
     generate_failure_snapshots();
-    let bin_dir = Path::new("bin");
-    fs::create_dir_all(bin_dir).expect("failed to create bin directory");
-    verify_expected_compile_errors(Path::new("tests/failing"), bin_dir);
+    verify_expected_compile_errors(Path::new("tests/failing"));
 }
 
 fn generate_golden_snapshots() {
@@ -130,10 +190,18 @@ fn entry_source_path(dir: &Path) -> Option<PathBuf> {
     }
 }
 
-fn compile_source(source: &str, target: &str) -> Result<String, Error> {
+/// Lowers the program at `path` to AIR the way `build` and `run` do.
+fn lower_source(path: &Path, target: &str) -> Result<Vec<AirFunction>, Diagnostics> {
+    let mut sources = SourceMap::new();
+    let reader = ModuleReader::open(path, Vec::new(), &mut sources)?;
+    lower_modules(reader, &mut sources, target)
+}
+
+fn compile_source(path: &Path, target: &str, arch: Arch) -> Result<String, Diagnostics> {
     let mut output = Vec::new();
-    let cursor = Cursor::new(source.as_bytes());
-    compile(cursor, target, &mut output)?;
+    let mut sources = SourceMap::new();
+    let reader = ModuleReader::open(path, Vec::new(), &mut sources)?;
+    compile_modules(reader, &mut sources, target, arch, &mut output)?;
     let asm = String::from_utf8(output).map_err(|err| {
         error::new(
             Code::Codegen,
@@ -144,7 +212,11 @@ fn compile_source(source: &str, target: &str) -> Result<String, Error> {
     Ok(asm) // TODO: This air_module can be done better
 }
 
-fn build_reference_for_path(path: &Path, out_dir: &Path, kind: SnapshotKind) -> Result<(), Error> {
+fn build_reference_for_path(
+    path: &Path,
+    out_dir: &Path,
+    kind: SnapshotKind,
+) -> Result<(), Diagnostics> {
     let stem = path
         .file_stem()
         .and_then(|stem| stem.to_str())
@@ -155,23 +227,34 @@ fn build_reference_for_path(path: &Path, out_dir: &Path, kind: SnapshotKind) ->
                 Span::unknown(),
             )
         })?;
-    let source = fs::read_to_string(path)?;
     match kind {
         SnapshotKind::Success => {
-            let artifacts = generate_artifacts(&source, TEST_TARGET)?;
+            let artifacts = generate_artifacts(path, TEST_TARGET)?;
             let actual_err_path = out_dir.join(format!("{stem}.actual.err"));
             if actual_err_path.exists() {
                 fs::remove_file(&actual_err_path)?;
             }
             write_artifacts(out_dir, stem, &artifacts)?;
+            if !artifacts.ownership.is_empty() {
+                return Err(error::new(
+                    Code::Internal,
+                    format!(
+                        "the ownership checker rejects the AIR; see {stem}.ownership:\n{}",
+                        artifacts.ownership
+                    ),
+                    Span::unknown(),
+                )
+                .into());
+            }
             Ok(())
         }
-        SnapshotKind::Failure => match generate_artifacts(&source, TEST_TARGET) {
+        SnapshotKind::Failure => match generate_artifacts(path, TEST_TARGET) {
             Ok(_) => Err(error::new(
                 Code::Internal,
                 "expected compilation failure but succeeded",
                 Span::unknown(),
-            )),
+            )
+            .into()),
             Err(err) => {
                 let actual_err_path = out_dir.join(format!("{stem}.actual.err"));
                 fs::write(actual_err_path, format!("{err}\n"))?;
@@ -185,7 +268,10 @@ struct GeneratedArtifacts {
     parser_output: String,
     normalized_hir: String,
     air: String,
+    /// What the closure ownership checker reports, one problem per paragraph.
+    ownership: String,
     asm: String,
+    aarch64_asm: String,
 }
 
 #[derive(Copy, Clone)]
@@ -194,17 +280,16 @@ enum SnapshotKind {
     Failure,
 }
 
-fn generate_artifacts(source: &str, target: &str) -> Result<GeneratedArtifacts, Error> {
-    let cursor = Cursor::new(source.as_bytes());
-    let lexer = Lexer::new(cursor);
-    let mut parser = Parser::new(lexer);
+fn generate_artifacts(path: &Path, target: &str) -> Result<GeneratedArtifacts, Diagnostics> {
+    let mut sources = SourceMap::new();
+    let mut reader = ModuleReader::open(path, Vec::new(), &mut sources)?;
     let mut ctx = hir::Context::new();
 
     let mut block_items = Vec::new();
     let mut lowerer = hir::Lowerer::new();
     let mut hir_block_items = Vec::new();
 
-    while let Some(item) = parser.next_block_item()? {
+    while let Some(item) = reader.next_item(&mut sources)? {
         reject_root_execution(&item)?;
         block_items.push(item.clone());
         lowerer.consume(&mut ctx, item)?;
@@ -213,28 +298,52 @@ fn generate_artifacts(source: &str, target: &str) -> Result<GeneratedArtifacts,
         }
     }
 
-    let target_item = target_exec(target);
-    block_items.push(target_item.clone());
-    lowerer.consume(&mut ctx, target_item)?;
-    while let Some(lowered) = lowerer.produce() {
-        hir_block_items.push(lowered.clone());
+    let target_function = hir_block_items.iter().find_map(|item| match item {
+        hir::BlockItem::FunctionDef(function) if function.name == target => Some(function),
+        _ => None,
+    });
+    for target_item in entry_target_items(target, target_function, &ctx)? {
+        block_items.push(target_item.clone());
+        lowerer.consume(&mut ctx, target_item)?;
+        while let Some(lowered) = lowerer.produce() {
+            hir_block_items.push(lowered.clone());
+        }
     }
 
     let normalized_hir = render_normalized_rgo(&hir_block_items);
     let parser_output = format!("{:#?}", block_items);
 
-    let air_functions = generate_air_functions(&hir_block_items)?;
+    let air_functions = lower_source(path, target)?;
     let air = render_air_functions(&air_functions);
+    let ownership = render_ownership(&air_functions);
 
-    let asm = compile_source(source, target)?;
+    let asm = compile_source(path, target, Arch::X86_64)?;
+    let aarch64_asm = compile_source(path, target, Arch::Aarch64)?;
     Ok(GeneratedArtifacts {
         parser_output,
         normalized_hir,
         air,
+        ownership,
         asm,
+        aarch64_asm,
     })
 }
 
+fn render_ownership(functions: &[AirFunction]) -> String {
+    let Err(diagnostics) = ownership::check(functions) else {
+        return String::new();
+    };
+    let mut out = String::new();
+    for err in diagnostics.errors() {
+        out.push_str(&err.message);
+        out.push('\n');
+        for note in &err.notes {
+            out.push_str(&format!("  note: {note}\n"));
+        }
+    }
+    out
+}
+
 fn write_artifacts(
     out_dir: &Path,
     stem: &str,
@@ -249,56 +358,41 @@ fn write_artifacts(
         &artifacts.normalized_hir,
     )?;
     fs::write(out_dir.join(format!("{stem}.air")), &artifacts.air)?;
+    fs::write(
+        out_dir.join(format!("{stem}.ownership")),
+        &artifacts.ownership,
+    )?;
     fs::write(out_dir.join(format!("{stem}.asm")), &artifacts.asm)?;
+    fs::write(
+        out_dir.join(format!("{stem}.aarch64.s")),
+        &artifacts.aarch64_asm,
+    )?;
     Ok(())
 }
 
 fn verify_expected_runtime_outputs(tests_dir: &Path, bin_dir: &Path) {
+    verify_expected_runtime_outputs_for(tests_dir, bin_dir, Arch::X86_64);
+}
+
+fn verify_expected_runtime_outputs_for(tests_dir: &Path, bin_dir: &Path, arch: Arch) {
     for test in collect_test_cases(tests_dir) {
         let expected_path = test.dir.join("expected.out");
         if !expected_path.exists() {
             continue;
         }
 
-        let asm_path = bin_dir.join(format!("{}.asm", test.name));
-        compile_rgo_source(&test.source, &asm_path);
-
-        let obj_path = bin_dir.join(format!("{}.o", test.name));
-        let mut nasm_cmd = Command::new("nasm");
-        nasm_cmd
-            .arg("-felf64")
-            .arg(&asm_path)
-            .arg("-o")
-            .arg(&obj_path);
-        run_command(
-            &mut nasm_cmd,
-            &format!(
-                "nasm -felf64 {} -o {}",
-                asm_path.display(),
-                obj_path.display()
-            ),
-        );
-
         let bin_path = bin_dir.join(&test.name);
-        let mut ld_cmd = Command::new("ld");
-        ld_cmd
-            .arg("-dynamic-linker")
-            .arg("/lib64/ld-linux-x86-64.so.2")
-            .arg("-lc")
-            .arg(&obj_path)
-            .arg("-o")
-            .arg(&bin_path);
-        run_command(
-            &mut ld_cmd,
-            &format!(
-                "ld -dynamic-linker /lib64/ld-linux-x86-64.so.2 -lc {} -o {}",
-                obj_path.display(),
-                bin_path.display()
-            ),
-        );
-
-        let mut run_cmd = Command::new(&bin_path);
-        let actual_output =
+        build_rgo_source(&test.source, &bin_path, arch);
+
+        let mut run_cmd = match arch {
+            Arch::X86_64 => Command::new(&bin_path),
+            Arch::Aarch64 => {
+                let mut cmd = Command::new("qemu-aarch64");
+                cmd.arg("-L").arg("/usr/aarch64-linux-gnu").arg(&bin_path);
+                cmd
+            }
+        };
+        let (actual_output, status) =
             capture_command_output(&mut run_cmd, &format!("running {}", bin_path.display()));
         let expected_output =
             fs::read_to_string(&expected_path).expect("expected output file should be readable");
@@ -309,10 +403,56 @@ fn verify_expected_runtime_outputs(tests_dir: &Path, bin_dir: &Path) {
             "unexpected runtime output for {}",
             test.name
         );
+
+        // The interpreter is the reference for what the native code should do,
+        // down to the exit status.
+        if arch == Arch::X86_64 {
+            assert_eq!(
+                interpret(&test.source),
+                (actual_output, status),
+                "the interpreter and the native binary disagree on {}",
+                test.name
+            );
+        }
     }
 }
 
-fn verify_expected_compile_errors(tests_dir: &Path, bin_dir: &Path) {
+/// Returns what the interpreter printed and the status it exited with.
+fn interpret(path: &Path) -> (String, i32) {
+    let mut sources = SourceMap::new();
+    let functions = ModuleReader::open(path, Vec::new(), &mut sources)
+        .map_err(Diagnostics::from)
+        .and_then(|reader| lower_modules(reader, &mut sources, TEST_TARGET))
+        .unwrap_or_else(|err| panic!("{}: {err}", path.display()));
+    // The program sees its source path as its name, like `rgo run --interp`,
+    // and must not leave a closure environment live at exit.
+    let options = interpreter::Options {
+        check_memory: true,
+        args: vec![path.display().to_string()],
+        ..interpreter::Options::default()
+    };
+    let (result, output) = std::thread::Builder::new()
+        .stack_size(INTERPRETER_STACK_SIZE)
+        .spawn(move || {
+            let mut output = Vec::new();
+            let result = interpreter::run_with(&functions, options, &mut output)
+                .map_err(|err| err.to_string());
+            (result, output)
+        })
+        .expect("interpreter thread should start")
+        .join()
+        .expect("interpreter thread should not panic");
+    let status = result.unwrap_or_else(|err| {
+        panic!(
+            "interpreting {} failed: {err}\noutput:\n{}",
+            path.display(),
+            String::from_utf8_lossy(&output)
+        )
+    });
+    (String::from_utf8_lossy(&output).to_string(), status)
+}
+
+fn verify_expected_compile_errors(tests_dir: &Path) {
     if !tests_dir.exists() {
         return;
     }
@@ -327,16 +467,16 @@ fn verify_expected_compile_errors(tests_dir: &Path, bin_dir: &Path) {
             .expect("expected error file should be readable")
             .trim_end()
             .to_string();
-        let asm_path = bin_dir.join(format!("{}.err.asm", test.name));
         let mut cmd = Command::new("cargo");
         cmd.arg("run")
             .arg("--")
-            .arg(&test.source)
+            .arg("check")
+            .arg("--target")
             .arg(TEST_TARGET)
-            .arg(&asm_path);
+            .arg(&test.source);
         let actual_error = capture_compile_failure_output(
             &mut cmd,
-            &format!("cargo run -- {}", test.source.display()),
+            &format!("cargo run -- check {}", test.source.display()),
         );
 
         assert!(
@@ -349,45 +489,63 @@ fn verify_expected_compile_errors(tests_dir: &Path, bin_dir: &Path) {
     }
 }
 
-fn compile_rgo_source(rgo_path: &Path, asm_path: &Path) {
+fn build_rgo_source(rgo_path: &Path, bin_path: &Path, arch: Arch) {
     let mut cmd = Command::new("cargo");
     cmd.arg("run")
         .arg("--")
-        .arg(rgo_path)
+        .arg("build")
+        .arg("--target")
         .arg(TEST_TARGET)
-        .arg(asm_path);
+        .arg("--triple")
+        .arg(arch.triple())
+        .arg("-o")
+        .arg(bin_path)
+        .arg(rgo_path);
     run_command(
         &mut cmd,
         &format!(
-            "cargo run -- {} {} {}",
-            rgo_path.display(),
+            "cargo run -- build --target {} --triple {} -o {} {}",
             TEST_TARGET,
-            asm_path.display()
+            arch,
+            bin_path.display(),
+            rgo_path.display()
         ),
     );
 }
 
-fn target_exec(target: &str) -> compiler::compiler::ast::BlockItem {
-    compiler::compiler::ast::BlockItem::Ident(compiler::compiler::ast::Ident {
-        name: target.to_string(),
-        args: Vec::new(),
-        span: Span::unknown(),
-    })
+enum Aarch64Assembler {
+    Gnu,
+    LlvmMc,
 }
 
-fn reject_root_execution(item: &compiler::compiler::ast::BlockItem) -> Result<(), Error> {
-    match item {
-        compiler::compiler::ast::BlockItem::Ident(_)
-        | compiler::compiler::ast::BlockItem::Lambda(_)
-        | compiler::compiler::ast::BlockItem::ScopeCapture { .. } => Err(error::new(
-            Code::Parse,
-            "root-level invocation is not supported; choose a target function",
-            item.span(),
-        )),
-        _ => Ok(()),
+impl Aarch64Assembler {
+    fn name(&self) -> &'static str {
+        match self {
+            Aarch64Assembler::Gnu => "aarch64-linux-gnu-as",
+            Aarch64Assembler::LlvmMc => "llvm-mc",
+        }
+    }
+
+    fn command(&self, asm_path: &Path, obj_path: &Path) -> Command {
+        let mut cmd = Command::new(self.name());
+        if let Aarch64Assembler::LlvmMc = self {
+            cmd.arg("-triple=aarch64-linux-gnu").arg("-filetype=obj");
+        }
+        cmd.arg("-o").arg(obj_path).arg(asm_path);
+        cmd
     }
 }
 
+fn aarch64_assembler() -> Option<Aarch64Assembler> {
+    [Aarch64Assembler::Gnu, Aarch64Assembler::LlvmMc]
+        .into_iter()
+        .find(|assembler| tool_available(assembler.name()))
+}
+
+fn tool_available(tool: &str) -> bool {
+    Command::new(tool).arg("--version").output().is_ok()
+}
+
 fn run_command(cmd: &mut Command, description: &str) {
     let output = cmd
         .output()
@@ -420,11 +578,13 @@ fn capture_compile_failure_output(cmd: &mut Command, description: &str) -> Strin
     format!("{stdout}{stderr}")
 }
 
-fn capture_command_output(cmd: &mut Command, description: &str) -> String {
+/// Returns what the command printed and the status it exited with. Programs
+/// may exit with any status, but being killed by a signal is a failure.
+fn capture_command_output(cmd: &mut Command, description: &str) -> (String, i32) {
     let output = cmd
         .output()
         .unwrap_or_else(|err| panic!("{description} failed to start: {err}"));
-    if !output.status.success() {
+    let Some(status) = output.status.code() else {
         panic!(
             "{} failed (status: {}):\nstdout:\n{}\nstderr:\n{}",
             description,
@@ -432,6 +592,6 @@ fn capture_command_output(cmd: &mut Command, description: &str) -> String {
             String::from_utf8_lossy(&output.stdout),
             String::from_utf8_lossy(&output.stderr)
         );
-    }
-    String::from_utf8_lossy(&output.stdout).to_string()
+    };
+    (String::from_utf8_lossy(&output.stdout).to_string(), status)
 }